@@ -4,8 +4,10 @@ use clap::{Parser, Subcommand};
 use monmouth_config::NodeConfig;
 use monmouth_domain::BootstrapConfig;
 use monmouth_rpc::NodeState;
-use monmouth_runner::{ProductionRunner, load_threshold_scheme};
-use monmouth_service::LegacyNodeService;
+use monmouth_runner::{
+    NodeRole, ProductionRunner, load_threshold_scheme, partition_prefix, verify_block_archive,
+};
+use monmouth_service::{LegacyNodeService, Shutdown};
 
 #[derive(Parser, Debug)]
 #[command(name = "monmouth")]
@@ -39,6 +41,28 @@ pub(crate) struct Cli {
 pub(crate) enum Commands {
     Dkg(DkgArgs),
     Validator(ValidatorArgs),
+    /// Inspect and maintain the finalized block archive.
+    #[command(subcommand)]
+    Archive(ArchiveCommands),
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum ArchiveCommands {
+    /// Check the block archive's ordinal index against the stored blocks.
+    ///
+    /// Exits non-zero if problems are found and not repaired.
+    Verify(ArchiveVerifyArgs),
+}
+
+#[derive(clap::Args, Debug)]
+pub(crate) struct ArchiveVerifyArgs {
+    /// Rebuild the ordinal index from the stored blocks.
+    #[arg(long)]
+    pub repair: bool,
+
+    /// Validator index whose partitions to check; defaults to the DKG share index.
+    #[arg(long)]
+    pub index: Option<u32>,
 }
 
 #[derive(clap::Args, Debug)]
@@ -61,6 +85,7 @@ pub(crate) struct ValidatorArgs {
 impl Cli {
     pub(crate) fn load_config(&self) -> eyre::Result<NodeConfig> {
         let mut config = NodeConfig::load(self.config.as_deref())?;
+        config.apply_env_overrides()?;
 
         if let Some(chain_id) = self.chain_id {
             config.chain_id = chain_id;
@@ -82,6 +107,7 @@ impl Cli {
         match &self.command {
             Some(Commands::Dkg(args)) => self.run_dkg(args),
             Some(Commands::Validator(args)) => self.run_validator(args),
+            Some(Commands::Archive(ArchiveCommands::Verify(args))) => self.run_archive_verify(args),
             None => self.run_legacy(),
         }
     }
@@ -111,7 +137,7 @@ impl Cli {
             data_dir: node_config.data_dir.clone(),
             listen_addr: node_config.network.listen_addr.parse()?,
             bootstrap_peers: peers.bootstrappers,
-            timeout: std::time::Duration::from_secs(300),
+            round_timeout: DkgConfig::DEFAULT_ROUND_TIMEOUT,
         };
 
         let ceremony = if args.force_restart {
@@ -165,8 +191,10 @@ impl Cli {
         tracing::info!(allocations = bootstrap.genesis_alloc.len(), "Loaded genesis configuration");
 
         // Create RPC state that will be updated by consensus
-        let rpc_port = 8545 + dkg_output.share_index as u16;
-        let rpc_addr: std::net::SocketAddr = format!("0.0.0.0:{}", rpc_port).parse()?;
+        let rpc_addr: std::net::SocketAddr =
+            config.rpc.http_addr.parse().map_err(|e| {
+                eyre::eyre!("Invalid rpc.http_addr {:?}: {}", config.rpc.http_addr, e)
+            })?;
         let node_state = NodeState::new(config.chain_id, dkg_output.share_index);
 
         let metrics_addr: std::net::SocketAddr = "0.0.0.0:9002".parse()?;
@@ -177,23 +205,88 @@ impl Cli {
             monmouth_config::DEFAULT_GAS_LIMIT,
             bootstrap,
         )
+        .map_err(|e| eyre::eyre!("Invalid genesis: {}", e.0))?
+        .with_node_role(NodeRole::Validator, dkg_output.share_index)
         .with_rpc(node_state, rpc_addr)
         .with_metrics(metrics_addr)
         .with_agent_config(
             config.execution.enable_agent_pool,
             config.execution.confidence_threshold,
-        );
+        )
+        .with_parallel_workers(config.execution.parallel_workers)
+        .with_mempool_config(&config.mempool)
+        .with_checkpoint_interval(config.consensus.checkpoint_interval);
 
         runner.run_standalone(config).map_err(|e| eyre::eyre!("Runner failed: {}", e.0))
     }
 
+    fn run_archive_verify(&self, args: &ArchiveVerifyArgs) -> eyre::Result<()> {
+        let config = self.load_config()?;
+
+        let index = match args.index {
+            Some(index) => index,
+            None if monmouth_dkg::DkgOutput::exists(&config.data_dir) => {
+                monmouth_dkg::DkgOutput::load(&config.data_dir)?.share_index
+            }
+            None => 0,
+        };
+        let prefix = partition_prefix(config.chain_id, NodeRole::Validator, index);
+        tracing::info!(%prefix, repair = args.repair, "Verifying block archive");
+
+        let (report, repaired) = verify_block_archive(&config.data_dir, &prefix, args.repair)
+            .map_err(|e| eyre::eyre!("Archive check failed: {}", e.0))?;
+        tracing::info!(
+            checked = report.verified.len() + report.corrupt.len(),
+            gaps = report.gaps.len(),
+            corrupt = report.corrupt.len(),
+            broken_links = report.broken_links.len(),
+            "Checked block archive"
+        );
+        for (start, end) in &report.gaps {
+            tracing::warn!(start, end, "missing ordinal entries");
+        }
+        for index in &report.corrupt {
+            tracing::warn!(index, "corrupt block");
+        }
+        for index in &report.broken_links {
+            tracing::warn!(index, parent = index - 1, "block does not extend its parent");
+        }
+
+        let report = match repaired {
+            Some(repaired) => {
+                tracing::info!(blocks = repaired.verified.len(), "Rebuilt ordinal index");
+                repaired
+            }
+            None => report,
+        };
+        if !report.is_consistent() {
+            return Err(eyre::eyre!("block archive is inconsistent"));
+        }
+        Ok(())
+    }
+
     fn run_legacy(&self) -> eyre::Result<()> {
         let config = self.load_config()?;
 
+        // With threshold shares on disk the node can take part in consensus.
+        if monmouth_dkg::DkgOutput::exists(&config.data_dir) {
+            return self.run_validator(&ValidatorArgs { peers: None });
+        }
+
         tracing::info!(chain_id = config.chain_id, "Starting node (legacy mode)");
+        tracing::warn!("no DKG output found; legacy mode runs the transport only until `dkg` runs");
         tracing::debug!(?config, "Full configuration");
 
-        LegacyNodeService::new(config).run()
+        let (trigger, shutdown) = Shutdown::channel();
+        #[cfg(unix)]
+        monmouth_cli::ShutdownHandler::install(move |signal| {
+            tracing::info!(signal, "received shutdown signal");
+            trigger.trigger();
+        });
+        #[cfg(not(unix))]
+        let _trigger = trigger;
+
+        LegacyNodeService::new(config).with_shutdown(shutdown).run()
     }
 }
 
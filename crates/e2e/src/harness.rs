@@ -22,16 +22,17 @@ use commonware_utils::{NZU64, NZUsize, TryCollect as _, ordered::Set};
 use futures::{StreamExt as _, channel::mpsc};
 use monmouth_crypto::{ThresholdScheme, threshold_schemes};
 use monmouth_domain::{
-    Block, BlockCfg, ConsensusDigest, FinalizationEvent, LedgerEvent, PublicKey, StateRoot, TxCfg,
+    Block, BlockCfg, ConsensusDigest, FinalizationEvent, FinalizedNotification, LedgerEvent,
+    NotificationDelivery, PublicKey, StateRoot, TxCfg,
 };
 use monmouth_executor::{BlockContext, RevmExecutor};
-use monmouth_ledger::{LedgerService, LedgerView};
+use monmouth_ledger::{BlockSnapshot, LedgerService, LedgerView, TxGossip};
 use monmouth_marshal::{ArchiveInitializer, BroadcastInitializer, PeerInitializer};
 use monmouth_reporters::{BlockContextProvider, FinalizedReporter, SeedReporter};
 use monmouth_simplex::{DEFAULT_MAILBOX_SIZE as MAILBOX_SIZE, DefaultPool, DefaultQuota};
 use monmouth_transport_sim::{SimContext, SimControl, SimLinkConfig, register_node_channels};
 use thiserror::Error;
-use tracing::{debug, info, trace};
+use tracing::{debug, info, trace, warn};
 
 use crate::{TestConfig, TestNode, TestSetup};
 
@@ -68,6 +69,18 @@ pub enum HarnessError {
     /// Missing expected state.
     #[error("missing state: {0}")]
     MissingState(String),
+    /// Replaying the finalized chain from genesis reached a different state root.
+    #[error(
+        "replay from genesis diverged at height {height}: live {live:?}, replayed {replayed:?}"
+    )]
+    ReplayDivergence {
+        /// Height at which the roots differ.
+        height: u64,
+        /// State root recorded by the live chain.
+        live: StateRoot,
+        /// State root computed by the replay.
+        replayed: StateRoot,
+    },
     /// Balance mismatch.
     #[error("balance mismatch for {address}: expected {expected}, got {actual}")]
     BalanceMismatch {
@@ -93,6 +106,11 @@ pub struct TestOutcome {
     pub blocks_finalized: u64,
     /// Per-node finalization counts.
     pub node_finalization_counts: Vec<u64>,
+    /// State root reached by replaying from genesis, if
+    /// [`TestConfig::replay_from_genesis`] was set.
+    pub replayed_state_root: Option<StateRoot>,
+    /// Finalized notifications node 0 received, subscribed before it started.
+    pub finalized_notifications: Vec<FinalizedNotification>,
 }
 
 /// Test harness for running e2e consensus tests.
@@ -180,12 +198,38 @@ impl TestHarness {
         // Verify expected balances
         verify_expected_balances(&nodes[0], head, &setup.expected_balances).await?;
 
+        // Optionally re-execute the whole chain in a fresh ledger
+        let replayed_state_root = if config.replay_from_genesis {
+            let replayed = replay_from_genesis(
+                &context,
+                &nodes[0],
+                head,
+                config.max_blocks,
+                &bootstrap,
+                &format!("{partition_prefix}-replay"),
+            )
+            .await?;
+            if replayed != state_root {
+                return Err(HarnessError::ReplayDivergence {
+                    height: config.max_blocks,
+                    live: state_root,
+                    replayed,
+                });
+            }
+            info!(?replayed, "Replay from genesis matched the live state root");
+            Some(replayed)
+        } else {
+            None
+        };
+
         Ok(TestOutcome {
             finalized_head: head,
             state_root,
             seed,
             blocks_finalized: config.max_blocks,
             node_finalization_counts: node_counts,
+            replayed_state_root,
+            finalized_notifications: nodes[0].finalized_notifications(),
         })
     }
 }
@@ -315,13 +359,13 @@ async fn start_single_node(
         buffer_pool.clone(),
         format!("{partition_prefix}-qmdb-{index}"),
         bootstrap.genesis_alloc.clone(),
+        bootstrap.fork,
     )
     .await
     .context("init qmdb")?;
 
     let ledger = LedgerService::new(state.clone());
     spawn_ledger_observers(ledger.clone(), context.clone(), index, finalized_tx);
-    let test_node = TestNode::new(index, ledger.clone());
 
     // Create application
     let app = TestApplication::<ThresholdScheme>::new(block_cfg.max_txs, state.clone());
@@ -349,6 +393,10 @@ async fn start_single_node(
     )
     .await?;
 
+    let test_node = TestNode::new(index, ledger.clone())
+        .with_marshal(marshal_mailbox.clone())
+        .with_finalized_subscription();
+
     // Create marshaled application
     let epocher = FixedEpocher::new(NZU64!(EPOCH_LENGTH));
     let marshaled = Marshaled::new(
@@ -367,6 +415,16 @@ async fn start_single_node(
         let _ = ledger.submit_tx(tx.clone()).await;
     }
 
+    // Gossip pooled transactions to the other nodes
+    let (tx_sender, tx_receiver) = channels.txs;
+    TxGossip::new(
+        context.with_label(&format!("tx_gossip_{index}")),
+        ledger.clone(),
+        tx_sender,
+        tx_receiver,
+    )
+    .start();
+
     // Start consensus engine
     let engine = simplex::Engine::new(
         context.with_label(&format!("engine_{index}")),
@@ -406,6 +464,7 @@ fn spawn_ledger_observers<S: Spawner>(
     finalized_tx: mpsc::UnboundedSender<FinalizationEvent>,
 ) {
     let mut receiver = service.subscribe();
+    let delivery = NotificationDelivery::<FinalizationEvent>::default();
     spawner.shared(true).spawn(move |_| async move {
         while let Some(event) = receiver.next().await {
             match event {
@@ -417,7 +476,21 @@ fn spawn_ledger_observers<S: Spawner>(
                 }
                 LedgerEvent::SnapshotPersisted(digest) => {
                     trace!(node = node_index, ?digest, "snapshot persisted");
-                    let _ = finalized_tx.unbounded_send((node_index as u32, digest));
+                    let notification = (node_index as u32, digest);
+                    let sent = delivery
+                        .deliver(notification, |event| {
+                            std::future::ready(finalized_tx.unbounded_send(event))
+                        })
+                        .await;
+                    if let Err(letter) = sent {
+                        warn!(
+                            node = node_index,
+                            ?digest,
+                            attempts = letter.attempts,
+                            error = %letter.error,
+                            "finalization notification dead-lettered"
+                        );
+                    }
                 }
             }
         }
@@ -620,6 +693,65 @@ async fn verify_state_convergence(
     Ok((state_root, seed))
 }
 
+/// Re-execute `node`'s finalized chain up to `head` in a fresh ledger.
+///
+/// Blocks are fetched by height from the node's marshal archive and run through
+/// the same verify-then-persist path a node uses for finalized blocks, each
+/// committed on its own. Returns the replayed state root at `head`.
+async fn replay_from_genesis(
+    context: &tokio::Context,
+    node: &TestNode,
+    head: ConsensusDigest,
+    max_height: u64,
+    bootstrap: &monmouth_domain::BootstrapConfig,
+    partition: &str,
+) -> Result<StateRoot, HarnessError> {
+    let ledger = LedgerView::init(
+        context.with_label("replay"),
+        DefaultPool::init(),
+        partition.to_string(),
+        bootstrap.genesis_alloc.clone(),
+        bootstrap.fork,
+    )
+    .await
+    .context("init replay qmdb")?;
+    let app = TestApplication::<ThresholdScheme>::new(block_codec_cfg().max_txs, ledger.clone());
+
+    let genesis = ledger.genesis_block();
+    let mut parent = genesis.commitment();
+    for height in genesis.height + 1..=genesis.height + max_height {
+        let block = node.finalized_block(height).await.ok_or_else(|| {
+            HarnessError::MissingState(format!("finalized block {height} not in archive"))
+        })?;
+        let digest = block.commitment();
+        if block.parent() != parent {
+            return Err(HarnessError::StateDivergence {
+                digest,
+                message: format!("block {height} does not extend the replayed chain"),
+            });
+        }
+        if !app.verify_block(&block).await {
+            return Err(HarnessError::StateDivergence {
+                digest,
+                message: format!("replayed block {height} failed verification"),
+            });
+        }
+        ledger.persist_snapshot(digest).await.context("persist replayed block")?;
+        ledger.record_block_hash(block.height, block.id().0).await;
+        parent = digest;
+        if digest == head {
+            return ledger.query_state_root(digest).await.ok_or_else(|| {
+                HarnessError::MissingState("replayed head has no state root".to_string())
+            });
+        }
+    }
+
+    Err(HarnessError::StateDivergence {
+        digest: head,
+        message: format!("head not reached within {max_height} replayed blocks"),
+    })
+}
+
 async fn verify_expected_balances(
     node: &TestNode,
     head: ConsensusDigest,
@@ -731,11 +863,14 @@ impl<S> TestApplication<S> {
         self.ledger
             .insert_snapshot(
                 block_digest,
-                parent_digest,
-                next_state,
-                state_root,
-                outcome.changes,
-                &block.txs,
+                BlockSnapshot {
+                    parent: parent_digest,
+                    state: next_state,
+                    root: state_root,
+                    changes: outcome.changes,
+                    txs: &block.txs,
+                    intent_receipts: outcome.intent_receipts,
+                },
             )
             .await;
 
@@ -782,11 +917,14 @@ impl<S> TestApplication<S> {
         self.ledger
             .insert_snapshot(
                 digest,
-                parent_digest,
-                next_state,
-                state_root,
-                execution.outcome.changes,
-                &block.txs,
+                BlockSnapshot {
+                    parent: parent_digest,
+                    state: next_state,
+                    root: state_root,
+                    changes: execution.outcome.changes,
+                    txs: &block.txs,
+                    intent_receipts: execution.outcome.intent_receipts,
+                },
             )
             .await;
 
@@ -1,8 +1,13 @@
 //! Test node wrapper for e2e testing.
 
+use std::sync::{Arc, Mutex, PoisonError};
+
 use alloy_primitives::{Address, B256, U256};
-use monmouth_domain::{ConsensusDigest, StateRoot, Tx};
+use commonware_consensus::{marshal::Mailbox, types::Height};
+use monmouth_crypto::ThresholdScheme;
+use monmouth_domain::{Block, ConsensusDigest, FinalizedNotification, StateRoot, Tx};
 use monmouth_ledger::LedgerService;
+use tokio::sync::broadcast::{self, error::TryRecvError};
 
 /// Handle for interacting with a test node.
 #[derive(Clone)]
@@ -11,12 +16,56 @@ pub struct TestNode {
     pub index: usize,
     /// Ledger service for state queries.
     ledger: LedgerService,
+    /// Marshal mailbox for finalized block lookups.
+    marshal: Option<Mailbox<ThresholdScheme, Block>>,
+    /// Finalized notifications subscribed to before the node started.
+    finalized: Option<Arc<Mutex<broadcast::Receiver<FinalizedNotification>>>>,
 }
 
 impl TestNode {
     /// Create a new test node handle.
     pub const fn new(index: usize, ledger: LedgerService) -> Self {
-        Self { index, ledger }
+        Self { index, ledger, marshal: None, finalized: None }
+    }
+
+    /// Subscribe to the ledger's finalized notifications.
+    #[must_use]
+    pub(crate) fn with_finalized_subscription(mut self) -> Self {
+        self.finalized = Some(Arc::new(Mutex::new(self.ledger.subscribe_finalized())));
+        self
+    }
+
+    /// Drain the finalized notifications received so far.
+    ///
+    /// Empty unless the node subscribed before it started.
+    pub fn finalized_notifications(&self) -> Vec<FinalizedNotification> {
+        let Some(receiver) = &self.finalized else {
+            return Vec::new();
+        };
+        let mut receiver = receiver.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut notifications = Vec::new();
+        loop {
+            match receiver.try_recv() {
+                Ok(notification) => notifications.push(notification),
+                Err(TryRecvError::Lagged(_)) => {}
+                Err(TryRecvError::Empty | TryRecvError::Closed) => return notifications,
+            }
+        }
+    }
+
+    /// Attach the node's marshal mailbox so finalized blocks can be fetched.
+    #[must_use]
+    pub(crate) fn with_marshal(mut self, marshal: Mailbox<ThresholdScheme, Block>) -> Self {
+        self.marshal = Some(marshal);
+        self
+    }
+
+    /// Fetch the finalized block at `height` from this node's marshal archive.
+    ///
+    /// Returns `None` if the block is not finalized or the node has no marshal.
+    pub async fn finalized_block(&self, height: u64) -> Option<Block> {
+        let mut marshal = self.marshal.clone()?;
+        marshal.get_block(Height::new(height)).await
     }
 
     /// Submit a transaction to this node's mempool.
@@ -26,6 +26,9 @@ pub struct TestConfig {
     pub max_blocks: u64,
     /// Test timeout.
     pub timeout: Duration,
+    /// Re-execute the finalized chain from genesis in a fresh ledger and check
+    /// that it reaches the live state root at the head.
+    pub replay_from_genesis: bool,
 }
 
 impl Default for TestConfig {
@@ -43,6 +46,7 @@ impl Default for TestConfig {
             gas_limit: 30_000_000,
             max_blocks: 5,
             timeout: Duration::from_secs(30),
+            replay_from_genesis: false,
         }
     }
 }
@@ -85,6 +89,13 @@ impl TestConfig {
         self.timeout = timeout;
         self
     }
+
+    /// Replay the finalized chain from genesis after reaching the head.
+    #[must_use]
+    pub const fn with_replay_from_genesis(mut self) -> Self {
+        self.replay_from_genesis = true;
+        self
+    }
 }
 
 /// Test scenario setup with genesis state and transactions.
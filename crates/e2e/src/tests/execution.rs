@@ -17,6 +17,19 @@ fn test_simple_transfer() {
     assert_eq!(outcome.blocks_finalized, 3);
 }
 
+/// Test that replaying the transfer chain from genesis reaches the live state root.
+#[test]
+#[ignore = "flaky when run in parallel - run with --test-threads=1"]
+fn test_simple_transfer_replay_from_genesis() {
+    let config =
+        TestConfig::default().with_validators(4).with_max_blocks(3).with_replay_from_genesis();
+    let setup = TestSetup::simple_transfer(config.chain_id);
+
+    let outcome = TestHarness::run(config, setup).expect("replay should match");
+
+    assert_eq!(outcome.replayed_state_root, Some(outcome.state_root));
+}
+
 /// Test multiple independent transfers in a single block.
 #[test]
 #[ignore = "flaky when run in parallel - run with --test-threads=1"]
@@ -0,0 +1,210 @@
+//! Transaction gossip tests.
+//!
+//! These tests run the gossip actor over the simulated network without
+//! consensus, to check what each peer's mempool would propose.
+
+use std::{collections::BTreeSet, time::Duration};
+
+use alloy_consensus::Header;
+use alloy_primitives::{Address, B256, Bytes, U256};
+use commonware_cryptography::{Committable as _, Signer as _, ed25519};
+use commonware_p2p::{Manager as _, Recipients, Sender as _, simulated};
+use commonware_runtime::{Clock as _, Handle, Metrics as _, Runner as _, tokio};
+use commonware_utils::{TryCollect as _, ordered::Set};
+use k256::ecdsa::SigningKey;
+use monmouth_consensus::{Mempool as _, components::ReannounceConfig};
+use monmouth_domain::{PublicKey, Tx, evm::Evm};
+use monmouth_executor::{BlockContext, BlockExecutor as _, RevmExecutor};
+use monmouth_ledger::{LedgerService, LedgerView, TxGossip};
+use monmouth_simplex::{DefaultPool, DefaultQuota};
+use monmouth_transport_sim::{SimContext, SimControl, SimLinkConfig, register_node_channels};
+
+use crate::TestSetup;
+
+const ROUND: Duration = Duration::from_millis(100);
+
+/// A gossiping peer: its ledger and the actor serving it.
+struct Peer {
+    ledger: LedgerView,
+    gossip: Handle<()>,
+}
+
+impl Peer {
+    /// Start a peer with a fresh ledger under `prefix`, registering its channels.
+    async fn start(
+        context: &tokio::Context,
+        control: &SimControl<PublicKey>,
+        key: PublicKey,
+        label: &str,
+        prefix: &str,
+        setup: &TestSetup,
+    ) -> Self {
+        let ledger = LedgerView::init(
+            context.with_label(&format!("{label}_state")),
+            DefaultPool::init(),
+            format!("{prefix}-{label}"),
+            setup.genesis_alloc.clone(),
+            None,
+        )
+        .await
+        .expect("init ledger");
+        let mut peer_control = control.peer_control(key);
+        let channels = register_node_channels(&mut peer_control, DefaultQuota::init())
+            .await
+            .expect("register channels");
+        let (sender, receiver) = channels.txs;
+        let gossip = TxGossip::new(
+            context.with_label(&format!("{label}_gossip")),
+            LedgerService::new(ledger.clone()),
+            sender,
+            receiver,
+        )
+        .with_interval(ROUND)
+        .with_reannounce_config(ReannounceConfig::default().with_interval_secs(1))
+        .start();
+        Self { ledger, gossip }
+    }
+
+    /// Transactions this peer would put in its next proposal.
+    async fn proposal(&self) -> Vec<Tx> {
+        let (_, mempool, _) = self.ledger.proposal_components().await;
+        mempool.build(usize::MAX, &BTreeSet::new())
+    }
+
+    /// Wait up to `timeout` for `tx` to be proposable by this peer.
+    async fn wait_for(&self, context: &tokio::Context, tx: &Tx, timeout: Duration) -> bool {
+        let deadline = context.current() + timeout;
+        while context.current() < deadline {
+            if self.proposal().await.contains(tx) {
+                return true;
+            }
+            context.sleep(ROUND).await;
+        }
+        false
+    }
+}
+
+/// A transaction submitted to one peer is still proposed by a peer that lost it
+/// in a restart, because the origin reannounces it.
+#[test]
+fn test_transaction_survives_peer_restart() {
+    let executor = tokio::Runner::default();
+    executor.start(|context| async move {
+        let run_id = context.current().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos();
+        let prefix = format!("e2e-gossip-{run_id}");
+
+        let (network, oracle) = simulated::Network::new(
+            SimContext::new(context.with_label("network")),
+            simulated::Config {
+                max_size: 1024 * 1024,
+                disconnect_on_block: true,
+                tracked_peer_sets: None,
+            },
+        );
+        network.start();
+        let mut control = SimControl::new(oracle);
+        let keys: Vec<PublicKey> =
+            (0..2).map(|seed| ed25519::PrivateKey::from_seed(seed).public_key()).collect();
+        let participants: Set<PublicKey> = keys.iter().cloned().try_collect().unwrap();
+        control.manager().update(0, participants).await;
+        control.connect_all(&keys, SimLinkConfig::default()).await.unwrap();
+
+        let setup = TestSetup::simple_transfer(1337);
+        let tx = setup.bootstrap_txs[0].clone();
+        let origin =
+            Peer::start(&context, &control, keys[0].clone(), "origin", &prefix, &setup).await;
+        let peer = Peer::start(&context, &control, keys[1].clone(), "peer", &prefix, &setup).await;
+
+        assert!(LedgerService::new(origin.ledger.clone()).submit_tx(tx.clone()).await);
+        assert!(peer.wait_for(&context, &tx, ROUND * 10).await, "first broadcast not received");
+
+        // Restart the peer: its mempool is gone and it missed the first broadcast.
+        peer.gossip.abort();
+        let restarted =
+            Peer::start(&context, &control, keys[1].clone(), "restarted", &prefix, &setup).await;
+        assert!(restarted.proposal().await.is_empty());
+
+        assert!(
+            restarted.wait_for(&context, &tx, Duration::from_secs(5)).await,
+            "reannouncement did not reach the restarted peer"
+        );
+    });
+}
+
+/// Garbage and unfunded transactions gossiped by a peer are refused, so the
+/// honest peer still builds a block from the funded transaction.
+#[test]
+fn test_invalid_gossip_does_not_block_proposals() {
+    let executor = tokio::Runner::default();
+    executor.start(|context| async move {
+        let run_id = context.current().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos();
+        let prefix = format!("e2e-gossip-invalid-{run_id}");
+
+        let (network, oracle) = simulated::Network::new(
+            SimContext::new(context.with_label("network")),
+            simulated::Config {
+                max_size: 1024 * 1024,
+                disconnect_on_block: true,
+                tracked_peer_sets: None,
+            },
+        );
+        network.start();
+        let mut control = SimControl::new(oracle);
+        let keys: Vec<PublicKey> =
+            (0..3).map(|seed| ed25519::PrivateKey::from_seed(seed).public_key()).collect();
+        let participants: Set<PublicKey> = keys.iter().cloned().try_collect().unwrap();
+        control.manager().update(0, participants).await;
+        control.connect_all(&keys, SimLinkConfig::default()).await.unwrap();
+
+        let chain_id = 1337;
+        let setup = TestSetup::simple_transfer(chain_id);
+        let funded = setup.bootstrap_txs[0].clone();
+        let origin =
+            Peer::start(&context, &control, keys[0].clone(), "origin", &prefix, &setup).await;
+        let peer = Peer::start(&context, &control, keys[1].clone(), "peer", &prefix, &setup).await;
+
+        // The attacker sends raw messages without running the gossip actor.
+        let mut attacker_control = control.peer_control(keys[2].clone());
+        let (mut attacker, _inbox) =
+            register_node_channels(&mut attacker_control, DefaultQuota::init())
+                .await
+                .expect("register channels")
+                .txs;
+        let unfunded_key = SigningKey::from_bytes(&[9u8; 32].into()).expect("valid key");
+        let unfunded = Evm::sign_eip1559_transfer(
+            &unfunded_key,
+            chain_id,
+            Address::repeat_byte(0x22),
+            U256::from(100u64),
+            0,
+            21_000,
+        );
+        let garbage = Tx::new(Bytes::from(vec![0xde, 0xad, 0xbe, 0xef]));
+        for tx in [&garbage, &unfunded] {
+            attacker.send(Recipients::All, tx.bytes.0.clone(), false).await.unwrap();
+        }
+
+        assert!(LedgerService::new(origin.ledger.clone()).submit_tx(funded.clone()).await);
+        assert!(peer.wait_for(&context, &funded, ROUND * 10).await, "funded tx not received");
+        context.sleep(ROUND * 5).await;
+        let proposal = peer.proposal().await;
+        assert_eq!(proposal, vec![funded]);
+
+        let genesis = peer.ledger.genesis_block();
+        let snapshot =
+            peer.ledger.parent_snapshot(genesis.commitment()).await.expect("genesis snapshot");
+        let header = Header {
+            number: 1,
+            timestamp: 1,
+            gas_limit: 30_000_000,
+            beneficiary: Address::ZERO,
+            base_fee_per_gas: Some(0),
+            ..Default::default()
+        };
+        let block_context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+        let txs: Vec<Bytes> = proposal.iter().map(|tx| tx.bytes.clone()).collect();
+        RevmExecutor::new(chain_id)
+            .execute(&snapshot.state, &block_context, &txs)
+            .expect("proposal executes");
+    });
+}
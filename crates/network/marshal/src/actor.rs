@@ -19,9 +19,15 @@ use commonware_consensus::{
 use commonware_cryptography::certificate::Provider;
 use commonware_parallel::Sequential;
 use commonware_runtime::{Clock, Metrics, Spawner, Storage, buffer::PoolRef};
-use commonware_utils::{Acknowledgement, NZU64, NZUsize};
+use commonware_storage::metadata::{self, Metadata};
+use commonware_utils::{Acknowledgement, NZU64, NZUsize, sequence::U64};
 use rand_core::CryptoRngCore;
 
+use crate::MarshalConfig;
+
+/// Key the marshal actor stores its last processed height under.
+const PROCESSED_HEIGHT_KEY: U64 = U64::new(0xFF);
+
 /// Provides sensible defaults for marshal actor configuration and initialization.
 ///
 /// # Example
@@ -73,8 +79,8 @@ impl ActorInitializer {
 impl ActorInitializer {
     /// Initializes the marshal actor with sensible defaults.
     ///
-    /// This method constructs a [`Config`] using the default constants and delegates
-    /// to [`Actor::init`] for initialization. Uses [`FixedEpocher`] with
+    /// This method uses [`MarshalConfig::default`] and delegates to
+    /// [`init_with_config`](Self::init_with_config) for initialization. Uses [`FixedEpocher`] with
     /// [`DEFAULT_BLOCKS_PER_EPOCH`](Self::DEFAULT_BLOCKS_PER_EPOCH) and
     /// [`Sequential`] strategy.
     ///
@@ -110,23 +116,16 @@ impl ActorInitializer {
         FB: Blocks<Block = B>,
         A: Acknowledgement,
     {
-        let config = Config {
+        Self::init_with_config(
+            context,
+            finalizations_by_height,
+            finalized_blocks,
             provider,
-            epocher: FixedEpocher::new(Self::DEFAULT_BLOCKS_PER_EPOCH),
-            partition_prefix: Self::DEFAULT_PARTITION_PREFIX.to_string(),
-            mailbox_size: Self::DEFAULT_MAILBOX_SIZE,
-            view_retention_timeout: Self::DEFAULT_VIEW_RETENTION_TIMEOUT,
-            prunable_items_per_section: Self::DEFAULT_PRUNABLE_ITEMS_PER_SECTION,
             buffer_pool,
-            replay_buffer: Self::DEFAULT_REPLAY_BUFFER,
-            key_write_buffer: Self::DEFAULT_KEY_WRITE_BUFFER,
-            value_write_buffer: Self::DEFAULT_VALUE_WRITE_BUFFER,
             block_codec_config,
-            max_repair: Self::DEFAULT_MAX_REPAIR,
-            strategy: Sequential,
-        };
-
-        Actor::init(context, finalizations_by_height, finalized_blocks, config).await
+            MarshalConfig::default(),
+        )
+        .await
     }
 
     /// Initializes the marshal actor with a custom partition prefix.
@@ -151,11 +150,56 @@ impl ActorInitializer {
         FB: Blocks<Block = B>,
         A: Acknowledgement,
     {
+        Self::init_with_config(
+            context,
+            finalizations_by_height,
+            finalized_blocks,
+            provider,
+            buffer_pool,
+            block_codec_config,
+            MarshalConfig::default().with_partition_prefix(partition_prefix),
+        )
+        .await
+    }
+
+    /// Initializes the marshal actor from an explicit [`MarshalConfig`].
+    ///
+    /// Use this to tune backfill concurrency via [`MarshalConfig::max_repair`]. The
+    /// remaining storage settings use the default constants. A non-zero
+    /// [`MarshalConfig::start_height`] is stored as the processed height if the
+    /// actor has not recorded any progress yet.
+    #[allow(clippy::type_complexity)]
+    pub async fn init_with_config<E, B, P, FC, FB, A>(
+        context: E,
+        finalizations_by_height: FC,
+        finalized_blocks: FB,
+        provider: P,
+        buffer_pool: PoolRef,
+        block_codec_config: B::Cfg,
+        marshal_config: MarshalConfig,
+    ) -> (Actor<E, B, P, FC, FB, FixedEpocher, Sequential, A>, Mailbox<P::Scheme, B>, Height)
+    where
+        E: CryptoRngCore + Spawner + Metrics + Clock + Storage,
+        B: Block,
+        P: Provider<Scope = Epoch, Scheme: Scheme<B::Commitment>>,
+        FC: Certificates<Commitment = B::Commitment, Scheme = P::Scheme>,
+        FB: Blocks<Block = B>,
+        A: Acknowledgement,
+    {
+        if marshal_config.start_height > 0 {
+            seed_processed_height(
+                context.with_label("start_height"),
+                &marshal_config.partition_prefix,
+                Height::new(marshal_config.start_height),
+            )
+            .await;
+        }
+
         let config = Config {
             provider,
-            epocher: FixedEpocher::new(Self::DEFAULT_BLOCKS_PER_EPOCH),
-            partition_prefix: partition_prefix.into(),
-            mailbox_size: Self::DEFAULT_MAILBOX_SIZE,
+            epocher: FixedEpocher::new(marshal_config.blocks_per_epoch),
+            partition_prefix: marshal_config.partition_prefix,
+            mailbox_size: marshal_config.mailbox_size,
             view_retention_timeout: Self::DEFAULT_VIEW_RETENTION_TIMEOUT,
             prunable_items_per_section: Self::DEFAULT_PRUNABLE_ITEMS_PER_SECTION,
             buffer_pool,
@@ -163,7 +207,7 @@ impl ActorInitializer {
             key_write_buffer: Self::DEFAULT_KEY_WRITE_BUFFER,
             value_write_buffer: Self::DEFAULT_VALUE_WRITE_BUFFER,
             block_codec_config,
-            max_repair: Self::DEFAULT_MAX_REPAIR,
+            max_repair: marshal_config.max_repair,
             strategy: Sequential,
         };
 
@@ -171,10 +215,63 @@ impl ActorInitializer {
     }
 }
 
+/// Store `height` as the actor's processed height unless progress is already recorded.
+async fn seed_processed_height<E>(context: E, partition_prefix: &str, height: Height)
+where
+    E: Clock + Metrics + Storage,
+{
+    let mut metadata = Metadata::<_, U64, Height>::init(
+        context,
+        metadata::Config {
+            partition: format!("{partition_prefix}-application-metadata"),
+            codec_config: (),
+        },
+    )
+    .await
+    .expect("failed to initialize application metadata");
+    if metadata.get(&PROCESSED_HEIGHT_KEY).is_none() {
+        metadata.put(PROCESSED_HEIGHT_KEY, height);
+        metadata.sync().await.expect("failed to seed processed height");
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use commonware_runtime::{Runner as _, deterministic};
+
     use super::*;
 
+    async fn processed_height(context: deterministic::Context, partition_prefix: &str) -> Height {
+        let metadata = Metadata::<_, U64, Height>::init(
+            context,
+            metadata::Config {
+                partition: format!("{partition_prefix}-application-metadata"),
+                codec_config: (),
+            },
+        )
+        .await
+        .unwrap();
+        *metadata.get(&PROCESSED_HEIGHT_KEY).unwrap()
+    }
+
+    #[test]
+    fn test_seed_processed_height_keeps_recorded_progress() {
+        deterministic::Runner::default().start(|context| async move {
+            seed_processed_height(context.with_label("seed"), "fork", Height::new(1000)).await;
+            assert_eq!(
+                processed_height(context.with_label("first"), "fork").await,
+                Height::new(1000)
+            );
+
+            // A restart after the actor advanced must not rewind it to the fork height.
+            seed_processed_height(context.with_label("reseed"), "fork", Height::new(5)).await;
+            assert_eq!(
+                processed_height(context.with_label("second"), "fork").await,
+                Height::new(1000)
+            );
+        });
+    }
+
     #[test]
     fn test_defaults() {
         assert_eq!(ActorInitializer::DEFAULT_MAILBOX_SIZE, 1024);
@@ -7,6 +7,33 @@ use commonware_runtime::{Clock, Metrics, Spawner, Storage, buffer::PoolRef};
 use commonware_storage::archive::immutable::{Archive, Config};
 use commonware_utils::{NZU16, NZU64, NZUsize, sequence::Array};
 
+/// Per-archive overrides of the [`ArchiveInitializer`] defaults.
+///
+/// Each `None` field falls back to the matching `DEFAULT_*` constant.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArchiveOverrides {
+    /// Initial freezer table size.
+    pub freezer_table_initial_size: Option<u32>,
+    /// Freezer table resize frequency.
+    pub freezer_table_resize_frequency: Option<u8>,
+    /// Freezer table resize chunk size.
+    pub freezer_table_resize_chunk_size: Option<u32>,
+    /// Freezer value target size.
+    pub freezer_value_target_size: Option<u64>,
+    /// zstd compression level; `Some(None)` disables compression.
+    pub compression_level: Option<Option<u8>>,
+    /// Items per section.
+    pub items_per_section: Option<NonZeroU64>,
+    /// Write buffer size for the freezer key, freezer value and ordinal journals.
+    pub write_buffer: Option<NonZeroUsize>,
+    /// Replay buffer size.
+    pub replay_buffer: Option<NonZeroUsize>,
+    /// Freezer key buffer pool page size.
+    pub page_size: Option<NonZeroU16>,
+    /// Freezer key buffer pool page cache size.
+    pub page_cache_size: Option<NonZeroUsize>,
+}
+
 /// Initializes immutable archive storage with sensible defaults.
 #[derive(Debug, Clone, Copy)]
 pub struct ArchiveInitializer;
@@ -50,6 +77,51 @@ impl ArchiveInitializer {
 }
 
 impl ArchiveInitializer {
+    /// Builds the archive configuration for `partition_prefix`, applying `overrides`
+    /// on top of the defaults.
+    pub fn config<C>(
+        partition_prefix: impl Into<String>,
+        codec_config: C,
+        overrides: ArchiveOverrides,
+    ) -> Config<C> {
+        let prefix = partition_prefix.into();
+        let write_buffer = overrides.write_buffer.unwrap_or(Self::DEFAULT_WRITE_BUFFER);
+        Config {
+            metadata_partition: format!("{prefix}-metadata"),
+            freezer_table_partition: format!("{prefix}-freezer-table"),
+            freezer_table_initial_size: overrides
+                .freezer_table_initial_size
+                .unwrap_or(Self::DEFAULT_FREEZER_TABLE_INITIAL_SIZE),
+            freezer_table_resize_frequency: overrides
+                .freezer_table_resize_frequency
+                .unwrap_or(Self::DEFAULT_FREEZER_TABLE_RESIZE_FREQUENCY),
+            freezer_table_resize_chunk_size: overrides
+                .freezer_table_resize_chunk_size
+                .unwrap_or(Self::DEFAULT_FREEZER_TABLE_RESIZE_CHUNK_SIZE),
+            freezer_key_partition: format!("{prefix}-freezer-key"),
+            freezer_key_buffer_pool: PoolRef::new(
+                overrides.page_size.unwrap_or(Self::DEFAULT_PAGE_SIZE),
+                overrides.page_cache_size.unwrap_or(Self::DEFAULT_PAGE_CACHE_SIZE),
+            ),
+            freezer_value_partition: format!("{prefix}-freezer-value"),
+            freezer_value_target_size: overrides
+                .freezer_value_target_size
+                .unwrap_or(Self::DEFAULT_FREEZER_VALUE_TARGET_SIZE),
+            freezer_value_compression: overrides
+                .compression_level
+                .unwrap_or(Self::DEFAULT_COMPRESSION_LEVEL),
+            ordinal_partition: format!("{prefix}-ordinal"),
+            items_per_section: overrides
+                .items_per_section
+                .unwrap_or(Self::DEFAULT_ITEMS_PER_SECTION),
+            freezer_key_write_buffer: write_buffer,
+            freezer_value_write_buffer: write_buffer,
+            ordinal_write_buffer: write_buffer,
+            replay_buffer: overrides.replay_buffer.unwrap_or(Self::DEFAULT_REPLAY_BUFFER),
+            codec_config,
+        }
+    }
+
     /// Initializes an immutable archive with a custom partition prefix.
     ///
     /// The `partition_prefix` is used to namespace all storage partitions.
@@ -69,30 +141,23 @@ impl ArchiveInitializer {
         K: Array,
         V: Codec + Send + Sync,
     {
-        let prefix = partition_prefix.into();
-        let config = Config {
-            metadata_partition: format!("{prefix}-metadata"),
-            freezer_table_partition: format!("{prefix}-freezer-table"),
-            freezer_table_initial_size: Self::DEFAULT_FREEZER_TABLE_INITIAL_SIZE,
-            freezer_table_resize_frequency: Self::DEFAULT_FREEZER_TABLE_RESIZE_FREQUENCY,
-            freezer_table_resize_chunk_size: Self::DEFAULT_FREEZER_TABLE_RESIZE_CHUNK_SIZE,
-            freezer_key_partition: format!("{prefix}-freezer-key"),
-            freezer_key_buffer_pool: PoolRef::new(
-                Self::DEFAULT_PAGE_SIZE,
-                Self::DEFAULT_PAGE_CACHE_SIZE,
-            ),
-            freezer_value_partition: format!("{prefix}-freezer-value"),
-            freezer_value_target_size: Self::DEFAULT_FREEZER_VALUE_TARGET_SIZE,
-            freezer_value_compression: Self::DEFAULT_COMPRESSION_LEVEL,
-            ordinal_partition: format!("{prefix}-ordinal"),
-            items_per_section: Self::DEFAULT_ITEMS_PER_SECTION,
-            freezer_key_write_buffer: Self::DEFAULT_WRITE_BUFFER,
-            freezer_value_write_buffer: Self::DEFAULT_WRITE_BUFFER,
-            ordinal_write_buffer: Self::DEFAULT_WRITE_BUFFER,
-            replay_buffer: Self::DEFAULT_REPLAY_BUFFER,
-            codec_config,
-        };
-        Archive::init(ctx, config).await
+        Self::init_with(ctx, partition_prefix, codec_config, ArchiveOverrides::default()).await
+    }
+
+    /// Initializes an immutable archive like [`init`](Self::init), replacing the
+    /// defaults with any parameters set in `overrides`.
+    pub async fn init_with<E, K, V>(
+        ctx: E,
+        partition_prefix: impl Into<String>,
+        codec_config: V::Cfg,
+        overrides: ArchiveOverrides,
+    ) -> Result<Archive<E, K, V>, commonware_storage::archive::Error>
+    where
+        E: Spawner + Storage + Metrics + Clock + Clone,
+        K: Array,
+        V: Codec + Send + Sync,
+    {
+        Archive::init(ctx, Self::config(partition_prefix, codec_config, overrides)).await
     }
 
     /// Initializes a finalizations archive with the default prefix.
@@ -145,4 +210,33 @@ mod tests {
         assert_eq!(ArchiveInitializer::DEFAULT_FINALIZATIONS_PREFIX, "finalizations");
         assert_eq!(ArchiveInitializer::DEFAULT_BLOCKS_PREFIX, "blocks");
     }
+
+    #[test]
+    fn test_config_applies_overrides() {
+        let overrides = ArchiveOverrides {
+            compression_level: Some(None),
+            items_per_section: Some(NZU64!(4096)),
+            ..ArchiveOverrides::default()
+        };
+
+        let config = ArchiveInitializer::config("blocks", (), overrides);
+
+        assert_eq!(config.freezer_value_compression, None);
+        assert_eq!(config.items_per_section.get(), 4096);
+        assert_eq!(
+            config.freezer_table_initial_size,
+            ArchiveInitializer::DEFAULT_FREEZER_TABLE_INITIAL_SIZE
+        );
+        assert_eq!(config.replay_buffer, ArchiveInitializer::DEFAULT_REPLAY_BUFFER);
+        assert_eq!(config.ordinal_partition, "blocks-ordinal");
+    }
+
+    #[test]
+    fn test_config_without_overrides_uses_defaults() {
+        let config = ArchiveInitializer::config("finalizations", (), ArchiveOverrides::default());
+
+        assert_eq!(config.freezer_value_compression, ArchiveInitializer::DEFAULT_COMPRESSION_LEVEL);
+        assert_eq!(config.items_per_section, ArchiveInitializer::DEFAULT_ITEMS_PER_SECTION);
+        assert_eq!(config.freezer_key_write_buffer, ArchiveInitializer::DEFAULT_WRITE_BUFFER);
+    }
 }
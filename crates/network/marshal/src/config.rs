@@ -0,0 +1,110 @@
+//! Contains the [`MarshalConfig`] used to tune the marshal actor.
+
+use std::num::{NonZeroU64, NonZeroUsize};
+
+use crate::ActorInitializer;
+
+/// Tunable settings for the marshal actor.
+///
+/// Fields default to the [`ActorInitializer`] constants. `max_repair` bounds how many
+/// missing blocks are fetched and processed concurrently during backfill; blocks are
+/// still delivered to the application and written to the finalized archive in height
+/// order regardless of this bound.
+///
+/// `start_height` is the height the application starts from, e.g. a genesis block
+/// forked from exported history. It seeds the actor's processed height on first
+/// start so it does not wait for blocks below the genesis block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarshalConfig {
+    /// Prefix used to namespace marshal storage partitions.
+    pub partition_prefix: String,
+    /// Maximum number of blocks repaired concurrently during backfill.
+    pub max_repair: NonZeroUsize,
+    /// Number of blocks per epoch.
+    pub blocks_per_epoch: NonZeroU64,
+    /// Size of the actor mailbox.
+    pub mailbox_size: usize,
+    /// Height treated as already processed when no progress is stored yet.
+    pub start_height: u64,
+}
+
+impl Default for MarshalConfig {
+    fn default() -> Self {
+        Self {
+            partition_prefix: ActorInitializer::DEFAULT_PARTITION_PREFIX.to_string(),
+            max_repair: ActorInitializer::DEFAULT_MAX_REPAIR,
+            blocks_per_epoch: ActorInitializer::DEFAULT_BLOCKS_PER_EPOCH,
+            mailbox_size: ActorInitializer::DEFAULT_MAILBOX_SIZE,
+            start_height: 0,
+        }
+    }
+}
+
+impl MarshalConfig {
+    /// Set the storage partition prefix.
+    #[must_use]
+    pub fn with_partition_prefix(mut self, partition_prefix: impl Into<String>) -> Self {
+        self.partition_prefix = partition_prefix.into();
+        self
+    }
+
+    /// Set the backfill concurrency bound.
+    #[must_use]
+    pub const fn with_max_repair(mut self, max_repair: NonZeroUsize) -> Self {
+        self.max_repair = max_repair;
+        self
+    }
+
+    /// Set the number of blocks per epoch.
+    #[must_use]
+    pub const fn with_blocks_per_epoch(mut self, blocks_per_epoch: NonZeroU64) -> Self {
+        self.blocks_per_epoch = blocks_per_epoch;
+        self
+    }
+
+    /// Set the actor mailbox size.
+    #[must_use]
+    pub const fn with_mailbox_size(mut self, mailbox_size: usize) -> Self {
+        self.mailbox_size = mailbox_size;
+        self
+    }
+
+    /// Set the height treated as already processed on first start.
+    #[must_use]
+    pub const fn with_start_height(mut self, start_height: u64) -> Self {
+        self.start_height = start_height;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use commonware_utils::{NZU64, NZUsize};
+
+    use super::*;
+
+    #[test]
+    fn test_defaults_match_initializer() {
+        let config = MarshalConfig::default();
+        assert_eq!(config.partition_prefix, ActorInitializer::DEFAULT_PARTITION_PREFIX);
+        assert_eq!(config.max_repair, ActorInitializer::DEFAULT_MAX_REPAIR);
+        assert_eq!(config.blocks_per_epoch, ActorInitializer::DEFAULT_BLOCKS_PER_EPOCH);
+        assert_eq!(config.mailbox_size, ActorInitializer::DEFAULT_MAILBOX_SIZE);
+        assert_eq!(config.start_height, 0);
+    }
+
+    #[test]
+    fn test_builders() {
+        let config = MarshalConfig::default()
+            .with_partition_prefix("node-1")
+            .with_max_repair(NZUsize!(32))
+            .with_blocks_per_epoch(NZU64!(100))
+            .with_mailbox_size(16)
+            .with_start_height(1000);
+        assert_eq!(config.partition_prefix, "node-1");
+        assert_eq!(config.max_repair.get(), 32);
+        assert_eq!(config.blocks_per_epoch.get(), 100);
+        assert_eq!(config.mailbox_size, 16);
+        assert_eq!(config.start_height, 1000);
+    }
+}
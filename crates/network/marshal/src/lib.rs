@@ -9,10 +9,19 @@ mod actor;
 pub use actor::ActorInitializer;
 
 mod archive;
-pub use archive::ArchiveInitializer;
+pub use archive::{ArchiveInitializer, ArchiveOverrides};
+
+mod config;
+pub use config::MarshalConfig;
 
 mod broadcast;
 pub use broadcast::BroadcastInitializer;
 
 mod peers;
 pub use peers::PeerInitializer;
+
+mod repair;
+pub use repair::{ArchiveRepair, ArchiveReport, RepairError};
+
+mod reader;
+pub use reader::{ArchiveError, ArchiveReader, PrunableArchive};
@@ -0,0 +1,158 @@
+//! Contains the [`ArchiveReader`] which serves archive lookups above a pruning bound.
+
+use std::future::Future;
+
+use commonware_codec::CodecShared;
+use commonware_runtime::{Metrics, Storage};
+use commonware_storage::{
+    archive::{self, Archive, Identifier, prunable},
+    translator::Translator,
+};
+use commonware_utils::sequence::Array;
+use thiserror::Error;
+
+/// Errors returned by [`ArchiveReader`] lookups.
+#[derive(Debug, Error)]
+pub enum ArchiveError {
+    /// The requested index is below the pruning bound.
+    #[error("index {requested} is pruned (lowest available: {lowest})")]
+    Pruned {
+        /// Index that was requested.
+        requested: u64,
+        /// Lowest index still available.
+        lowest: u64,
+    },
+    /// The underlying archive failed.
+    #[error("archive error: {0}")]
+    Archive(#[from] archive::Error),
+}
+
+/// An [`Archive`] that can remove stored entries below an index.
+pub trait PrunableArchive: Archive {
+    /// Remove entries below `min` from storage.
+    ///
+    /// Implementations may keep some entries below `min`, e.g. when pruning whole
+    /// sections.
+    fn prune(&mut self, min: u64) -> impl Future<Output = Result<(), archive::Error>> + Send;
+}
+
+impl<T, E, K, V> PrunableArchive for prunable::Archive<T, E, K, V>
+where
+    T: Translator,
+    E: Storage + Metrics,
+    K: Array,
+    V: CodecShared,
+{
+    async fn prune(&mut self, min: u64) -> Result<(), archive::Error> {
+        Self::prune(self, min).await
+    }
+}
+
+/// Read access to an archive that reports pruned ranges.
+///
+/// Lookups below the pruning bound fail with [`ArchiveError::Pruned`] rather
+/// than returning `None`, so callers can tell a pruned index from one that was
+/// never stored.
+#[derive(Debug)]
+pub struct ArchiveReader<A> {
+    archive: A,
+    pruned_below: u64,
+}
+
+impl<A: Archive> ArchiveReader<A> {
+    /// Wrap `archive` with no pruning bound.
+    pub const fn new(archive: A) -> Self {
+        Self { archive, pruned_below: 0 }
+    }
+
+    /// Returns the lowest and highest stored indices at or above the pruning bound.
+    ///
+    /// Returns `None` if no such index is stored.
+    pub fn available_range(&self) -> Option<(u64, u64)> {
+        let highest = self.archive.last_index()?;
+        let lowest = self.archive.first_index()?.max(self.pruned_below);
+        (lowest <= highest).then_some((lowest, highest))
+    }
+
+    /// Look up the value stored at `index`.
+    pub async fn get(&self, index: u64) -> Result<Option<A::Value>, ArchiveError> {
+        if index < self.pruned_below {
+            let lowest = self.available_range().map_or(self.pruned_below, |(lowest, _)| lowest);
+            return Err(ArchiveError::Pruned { requested: index, lowest });
+        }
+        Ok(self.archive.get(Identifier::Index(index)).await?)
+    }
+
+    /// Returns a reference to the wrapped archive.
+    pub const fn archive(&self) -> &A {
+        &self.archive
+    }
+}
+
+impl<A: PrunableArchive> ArchiveReader<A> {
+    /// Remove every index below `min` from the archive and report it as pruned.
+    ///
+    /// The bound only moves forward; a lower `min` than the current bound is ignored.
+    /// Entries the archive keeps below `min` are still reported as pruned.
+    pub async fn prune(&mut self, min: u64) -> Result<(), ArchiveError> {
+        if min <= self.pruned_below {
+            return Ok(());
+        }
+        self.archive.prune(min).await?;
+        self.pruned_below = min;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use commonware_cryptography::{Hasher as _, sha256::Sha256};
+    use commonware_runtime::{Runner, buffer::PoolRef, deterministic};
+    use commonware_storage::translator::FourCap;
+    use commonware_utils::{NZU16, NZU64, NZUsize};
+
+    use super::*;
+
+    #[test]
+    fn pruned_lookups_report_lower_bound() {
+        deterministic::Runner::default().start(|context| async move {
+            let config = prunable::Config {
+                translator: FourCap,
+                key_partition: "reader-keys".into(),
+                key_buffer_pool: PoolRef::new(NZU16!(1024), NZUsize!(10)),
+                value_partition: "reader-values".into(),
+                compression: None,
+                codec_config: (),
+                items_per_section: NZU64!(2),
+                key_write_buffer: NZUsize!(1024),
+                value_write_buffer: NZUsize!(1024),
+                replay_buffer: NZUsize!(1024),
+            };
+            let mut archive = prunable::Archive::<_, _, _, u64>::init(context, config)
+                .await
+                .expect("init archive");
+            for height in 1..=10u64 {
+                let key = Sha256::hash(&height.to_be_bytes());
+                archive.put(height, key, height * 100).await.expect("put");
+            }
+
+            let mut reader = ArchiveReader::new(archive);
+            assert_eq!(reader.available_range(), Some((1, 10)));
+
+            reader.prune(5).await.expect("prune");
+            assert_eq!(reader.available_range(), Some((5, 10)));
+            assert!(matches!(
+                reader.get(3).await,
+                Err(ArchiveError::Pruned { requested: 3, lowest: 5 })
+            ));
+            assert_eq!(reader.get(7).await.expect("in range"), Some(700));
+
+            // Entries below the pruned section are gone from the archive itself.
+            assert_eq!(reader.archive().get(Identifier::Index(3)).await.expect("get"), None);
+            assert_eq!(reader.archive().first_index(), Some(4));
+
+            reader.prune(2).await.expect("prune");
+            assert_eq!(reader.available_range(), Some((5, 10)));
+        });
+    }
+}
@@ -0,0 +1,184 @@
+//! Contains the [`ArchiveRepair`] which checks and rebuilds a finalized block archive.
+
+use std::collections::BTreeMap;
+
+use commonware_consensus::{Block, Heightable as _};
+use commonware_cryptography::Committable as _;
+use commonware_runtime::{Clock, Metrics, Spawner, Storage};
+use commonware_storage::archive::{self, Archive as _, Identifier, immutable::Archive};
+use thiserror::Error;
+
+use crate::ArchiveInitializer;
+
+/// Errors returned by [`ArchiveRepair`].
+#[derive(Debug, Error)]
+pub enum RepairError {
+    /// The archive failed.
+    #[error("archive error: {0}")]
+    Archive(#[from] archive::Error),
+    /// The ordinal partition could not be removed.
+    #[error("storage error: {0}")]
+    Storage(#[from] commonware_runtime::Error),
+}
+
+/// Problems found by [`ArchiveRepair::verify`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArchiveReport {
+    /// Indices whose block was read and matched its key and height.
+    pub verified: Vec<u64>,
+    /// Inclusive index ranges missing between stored indices.
+    pub gaps: Vec<(u64, u64)>,
+    /// Indices whose block could not be read, is stored at the wrong height, or
+    /// is not reachable under its own commitment.
+    pub corrupt: Vec<u64>,
+    /// Indices whose block does not build on the block stored one index below.
+    pub broken_links: Vec<u64>,
+}
+
+impl ArchiveReport {
+    /// Whether no problems were found.
+    pub const fn is_consistent(&self) -> bool {
+        self.gaps.is_empty() && self.corrupt.is_empty() && self.broken_links.is_empty()
+    }
+}
+
+/// Verifies and repairs a finalized block archive.
+///
+/// Blocks are stored in a key-value freezer and found by height through an
+/// ordinal index. If the index loses entries, the blocks are still reachable by
+/// commitment, so [`repair`](Self::repair) walks parent links down from every
+/// verified block and rebuilds the index from what it finds.
+#[derive(Debug, Clone, Copy)]
+pub struct ArchiveRepair;
+
+impl ArchiveRepair {
+    /// Check every indexed block in `archive`.
+    ///
+    /// Each stored block must sit at its own height, be retrievable by its
+    /// commitment, and build on the block at the index below when that is
+    /// stored. Missing ranges between stored indices are reported as gaps.
+    pub async fn verify<E, B>(
+        archive: &Archive<E, B::Commitment, B>,
+    ) -> Result<ArchiveReport, RepairError>
+    where
+        E: Spawner + Storage + Metrics + Clock + Clone,
+        B: Block,
+    {
+        let mut report = ArchiveReport::default();
+        // Last index visited and, if it verified, its block's commitment.
+        let mut previous: Option<(u64, Option<B::Commitment>)> = None;
+        let ranges: Vec<(u64, u64)> = archive.ranges().collect();
+        for (start, end) in ranges {
+            if let Some((last, _)) = previous
+                && start > last + 1
+            {
+                report.gaps.push((last + 1, start - 1));
+            }
+            for index in start..=end {
+                let block = match archive.get(Identifier::Index(index)).await {
+                    Ok(Some(block)) if block.height().get() == index => block,
+                    _ => {
+                        report.corrupt.push(index);
+                        previous = Some((index, None));
+                        continue;
+                    }
+                };
+                let commitment = block.commitment();
+                let keyed = archive.get(Identifier::Key(&commitment)).await;
+                if !matches!(keyed, Ok(Some(ref stored)) if stored.commitment() == commitment) {
+                    report.corrupt.push(index);
+                    previous = Some((index, None));
+                    continue;
+                }
+                if let Some((last, Some(parent))) = previous
+                    && last + 1 == index
+                    && block.parent() != parent
+                {
+                    report.broken_links.push(index);
+                }
+                report.verified.push(index);
+                previous = Some((index, Some(commitment)));
+            }
+        }
+        Ok(report)
+    }
+
+    /// Verify the archive under `partition_prefix` and rebuild its ordinal index.
+    ///
+    /// The ordinal partition is dropped and every block reachable by walking
+    /// parent commitments down from a verified block is indexed again. Blocks
+    /// that fail verification and cannot be reached this way stay out of the
+    /// index. Re-indexed blocks are appended to the freezer again, so repair
+    /// grows the archive by one copy of each recovered block.
+    ///
+    /// Returns the report before and after the repair.
+    pub async fn repair<E, B>(
+        context: E,
+        partition_prefix: &str,
+        codec_config: B::Cfg,
+    ) -> Result<(ArchiveReport, ArchiveReport), RepairError>
+    where
+        E: Spawner + Storage + Metrics + Clock + Clone,
+        B: Block,
+    {
+        let mut archive = ArchiveInitializer::init::<_, B::Commitment, B>(
+            context.with_label("scan"),
+            partition_prefix,
+            codec_config.clone(),
+        )
+        .await?;
+        let before = Self::verify(&archive).await?;
+
+        let mut chain: BTreeMap<u64, B> = BTreeMap::new();
+        for &index in before.verified.iter().rev() {
+            if chain.contains_key(&index) {
+                continue;
+            }
+            let Some(mut block) = archive.get(Identifier::Index(index)).await? else {
+                continue;
+            };
+            loop {
+                let height = block.height().get();
+                let parent = block.parent();
+                chain.insert(height, block);
+                if height == 0 || chain.contains_key(&(height - 1)) {
+                    break;
+                }
+                match archive.get(Identifier::Key(&parent)).await {
+                    Ok(Some(next)) if next.height().get() + 1 == height => block = next,
+                    _ => break,
+                }
+            }
+        }
+        archive.sync().await?;
+        drop(archive);
+
+        context.remove(&format!("{partition_prefix}-ordinal"), None).await?;
+
+        let mut archive = ArchiveInitializer::init::<_, B::Commitment, B>(
+            context.with_label("rebuild"),
+            partition_prefix,
+            codec_config,
+        )
+        .await?;
+        for (height, block) in chain {
+            archive.put(height, block.commitment(), block).await?;
+        }
+        archive.sync().await?;
+        let after = Self::verify(&archive).await?;
+        Ok((before, after))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_consistency() {
+        assert!(ArchiveReport::default().is_consistent());
+        assert!(!ArchiveReport { gaps: vec![(3, 4)], ..Default::default() }.is_consistent());
+        assert!(!ArchiveReport { corrupt: vec![2], ..Default::default() }.is_consistent());
+        assert!(!ArchiveReport { broken_links: vec![9], ..Default::default() }.is_consistent());
+    }
+}
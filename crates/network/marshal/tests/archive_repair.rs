@@ -0,0 +1,80 @@
+//! Integration tests for verifying and repairing a block archive.
+
+#![allow(missing_docs)]
+
+mod common;
+
+use commonware_consensus::{Heightable as _, types::Height};
+use commonware_cryptography::{Committable as _, Hasher as _, sha256::Sha256};
+use commonware_runtime::{Blob as _, Metrics as _, Runner, Storage as _, deterministic};
+use commonware_storage::archive::{Archive as _, Identifier};
+use monmouth_marshal::{ArchiveInitializer, ArchiveRepair};
+
+use crate::common::Block;
+
+const PREFIX: &str = "repair-blocks";
+const BLOCKS: u64 = 10;
+const CORRUPTED: u64 = 5;
+
+#[test]
+fn repair_rebuilds_corrupted_ordinal_index() {
+    deterministic::Runner::default().start(|context| async move {
+        // Store a chain of blocks at heights 1..=BLOCKS.
+        let mut archive =
+            ArchiveInitializer::init::<_, _, Block>(context.with_label("write"), PREFIX, ())
+                .await
+                .expect("init archive");
+        let mut parent = Sha256::hash(b"genesis");
+        for height in 1..=BLOCKS {
+            let block = Block::new(parent, Height::new(height), height);
+            parent = block.commitment();
+            archive.put(height, block.commitment(), block).await.expect("put");
+        }
+        archive.sync().await.expect("sync");
+        drop(archive);
+
+        // Flip a byte in the ordinal record for one height. Records are fixed size
+        // and laid out by index, so the blob holds indices 0..=BLOCKS.
+        let partition = format!("{PREFIX}-ordinal");
+        let blobs = context.scan(&partition).await.expect("scan ordinal");
+        assert_eq!(blobs.len(), 1);
+        let (blob, len) = context.open(&partition, &blobs[0]).await.expect("open ordinal");
+        let offset = len / (BLOCKS + 1) * CORRUPTED;
+        let byte = blob.read_at(vec![0u8; 1], offset).await.expect("read").as_ref()[0];
+        blob.write_at(vec![!byte], offset).await.expect("write");
+        blob.sync().await.expect("sync blob");
+        drop(blob);
+
+        // Verification reports the missing entry.
+        let archive =
+            ArchiveInitializer::init::<_, _, Block>(context.with_label("verify"), PREFIX, ())
+                .await
+                .expect("reopen archive");
+        let report = ArchiveRepair::verify(&archive).await.expect("verify");
+        assert!(!report.is_consistent());
+        assert_eq!(report.gaps, vec![(CORRUPTED, CORRUPTED)]);
+        assert!(archive.get(Identifier::Index(CORRUPTED)).await.expect("get").is_none());
+        drop(archive);
+
+        // Repair re-indexes the block from the freezer.
+        let (before, after) =
+            ArchiveRepair::repair::<_, Block>(context.with_label("repair"), PREFIX, ())
+                .await
+                .expect("repair");
+        assert_eq!(before, report);
+        assert!(after.is_consistent(), "{after:?}");
+        assert_eq!(after.verified, (1..=BLOCKS).collect::<Vec<_>>());
+
+        let archive =
+            ArchiveInitializer::init::<_, _, Block>(context.with_label("check"), PREFIX, ())
+                .await
+                .expect("reopen repaired archive");
+        let block = archive
+            .get(Identifier::Index(CORRUPTED))
+            .await
+            .expect("get")
+            .expect("re-indexed block");
+        assert_eq!(block.height(), Height::new(CORRUPTED));
+        assert!(ArchiveRepair::verify(&archive).await.expect("verify").is_consistent());
+    });
+}
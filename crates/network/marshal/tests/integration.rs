@@ -42,7 +42,7 @@ use commonware_parallel::Sequential;
 use commonware_runtime::{Clock, Metrics, Quota, Runner, buffer::PoolRef, deterministic};
 use commonware_utils::{Acknowledgement, NZU16, NZUsize};
 use monmouth_marshal::{
-    ActorInitializer, ArchiveInitializer, BroadcastInitializer, PeerInitializer,
+    ActorInitializer, ArchiveInitializer, BroadcastInitializer, MarshalConfig, PeerInitializer,
 };
 
 use crate::common::Block;
@@ -175,6 +175,64 @@ async fn setup_validator(
     (application, mailbox, processed_height)
 }
 
+/// Sets up a validator with an explicit marshal config and namespaced archives.
+async fn setup_validator_with_config(
+    context: deterministic::Context,
+    oracle: &mut Oracle<K, deterministic::Context>,
+    validator: K,
+    provider: ConstantProvider<S, Epoch>,
+    config: MarshalConfig,
+) -> (MockApplication, commonware_consensus::marshal::Mailbox<S, B>) {
+    let buffer_pool = PoolRef::new(NZU16!(1024), NZUsize!(10));
+    let prefix = config.partition_prefix.clone();
+
+    let control = oracle.control(validator.clone());
+    let backfill = control.register(1, TEST_QUOTA).await.unwrap();
+    let resolver = PeerInitializer::init::<_, _, _, B, _, _, _>(
+        &context,
+        validator.clone(),
+        oracle.manager(),
+        control.clone(),
+        backfill,
+    );
+
+    let (broadcast_engine, buffer) =
+        BroadcastInitializer::init::<_, _, B>(context.clone(), validator.clone(), ());
+    let network = control.register(2, TEST_QUOTA).await.unwrap();
+    broadcast_engine.start(network);
+
+    let finalizations_by_height = ArchiveInitializer::init(
+        context.with_label("finalizations_by_height"),
+        format!("{prefix}-finalizations"),
+        S::certificate_codec_config_unbounded(),
+    )
+    .await
+    .expect("failed to init finalizations archive");
+    let finalized_blocks = ArchiveInitializer::init(
+        context.with_label("finalized_blocks"),
+        format!("{prefix}-blocks"),
+        (),
+    )
+    .await
+    .expect("failed to init blocks archive");
+
+    let (actor, mailbox, _) = ActorInitializer::init_with_config(
+        context.clone(),
+        finalizations_by_height,
+        finalized_blocks,
+        provider,
+        buffer_pool,
+        (),
+        config,
+    )
+    .await;
+
+    let application = MockApplication::default();
+    actor.start(application.clone(), buffer, resolver);
+
+    (application, mailbox)
+}
+
 /// Sets up network links between all peers.
 async fn setup_network_links(
     oracle: &mut Oracle<K, deterministic::Context>,
@@ -348,3 +406,112 @@ fn test_start_marshal_multiple_validators() {
         assert_eq!(applications[1].blocks().len(), 1);
     });
 }
+
+/// Number of blocks the lagging validator must backfill.
+const BACKFILL_BLOCKS: u64 = 12;
+
+/// Finalizes a chain on one validator, then hands a lagging validator only the tip
+/// finalization and checks it backfills a complete, ordered archive.
+fn run_lagging_node_backfill(max_repair: usize) {
+    let runner = deterministic::Runner::timed(Duration::from_secs(120));
+    runner.start(|mut context| async move {
+        let (network, mut oracle) = Network::new(
+            context.with_label("network"),
+            simulated::Config {
+                max_size: 1024 * 1024,
+                disconnect_on_block: true,
+                tracked_peer_sets: Some(3),
+            },
+        );
+        network.start();
+
+        let Fixture { participants, schemes, .. } =
+            bls12381_threshold::fixture::<V, _>(&mut context, NAMESPACE, NUM_VALIDATORS);
+        let mut manager = oracle.manager();
+        manager.update(0, participants.clone().try_into().unwrap()).await;
+
+        let (leader_app, mut leader) = setup_validator_with_config(
+            context.with_label("leader"),
+            &mut oracle,
+            participants[0].clone(),
+            ConstantProvider::new(schemes[0].clone()),
+            MarshalConfig::default().with_partition_prefix("leader"),
+        )
+        .await;
+        let (lagging_app, mut lagging) = setup_validator_with_config(
+            context.with_label("lagging"),
+            &mut oracle,
+            participants[1].clone(),
+            ConstantProvider::new(schemes[1].clone()),
+            MarshalConfig::default()
+                .with_partition_prefix("lagging")
+                .with_max_repair(NZUsize!(max_repair)),
+        )
+        .await;
+        setup_network_links(&mut oracle, &participants[..2], LINK).await;
+
+        // Finalize a chain of blocks on the leader only.
+        let mut parent = Sha256::hash(b"genesis");
+        let mut chain = Vec::new();
+        let mut tip_finalization = None;
+        for height in 1..=BACKFILL_BLOCKS {
+            let block = Block::new(parent, Height::new(height), height);
+            let round = Round::new(Epoch::new(0), View::new(height));
+            leader.verified(round, block.clone()).await;
+
+            let proposal =
+                Proposal { round, parent: View::new(height - 1), payload: block.digest() };
+            let notarization = make_notarization(proposal.clone(), &schemes, QUORUM);
+            let finalization = make_finalization(proposal, &schemes, QUORUM);
+            leader.report(Activity::Notarization(notarization)).await;
+            leader.report(Activity::Finalization(finalization.clone())).await;
+
+            parent = block.digest();
+            chain.push(block);
+            tip_finalization = Some(finalization);
+        }
+
+        let mut attempts = 0;
+        while leader_app.blocks().len() < BACKFILL_BLOCKS as usize && attempts < 1_000 {
+            context.sleep(Duration::from_millis(10)).await;
+            attempts += 1;
+        }
+        assert_eq!(leader_app.blocks().len(), BACKFILL_BLOCKS as usize);
+
+        // The lagging validator only learns about the tip and must backfill the rest.
+        lagging.report(Activity::Finalization(tip_finalization.unwrap())).await;
+
+        let mut attempts = 0;
+        while lagging_app.blocks().len() < BACKFILL_BLOCKS as usize && attempts < 5_000 {
+            context.sleep(Duration::from_millis(10)).await;
+            attempts += 1;
+        }
+
+        let delivered = lagging_app.blocks();
+        let heights: Vec<u64> = delivered.keys().map(|height| height.get()).collect();
+        assert_eq!(heights, (1..=BACKFILL_BLOCKS).collect::<Vec<_>>());
+        for (expected, block) in chain.iter().zip(delivered.values()) {
+            assert_eq!(block, expected);
+        }
+
+        for expected in &chain {
+            let stored = lagging
+                .get_block(expected.height())
+                .await
+                .expect("backfilled block should be archived");
+            assert_eq!(&stored, expected);
+        }
+    });
+}
+
+/// A lagging node backfills sequentially.
+#[test_traced("WARN")]
+fn test_lagging_node_backfill_sequential() {
+    run_lagging_node_backfill(1);
+}
+
+/// A lagging node backfills with concurrent repair and still archives in order.
+#[test_traced("WARN")]
+fn test_lagging_node_backfill_concurrent() {
+    run_lagging_node_backfill(8);
+}
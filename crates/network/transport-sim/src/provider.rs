@@ -12,12 +12,12 @@ use commonware_runtime::{Quota, tokio};
 use monmouth_config::NodeConfig;
 use monmouth_service::TransportProvider;
 use monmouth_transport::{
-    CHANNEL_BACKFILL, CHANNEL_BLOCKS, CHANNEL_CERTS, CHANNEL_RESOLVER, CHANNEL_VOTES,
+    CHANNEL_BACKFILL, CHANNEL_BLOCKS, CHANNEL_CERTS, CHANNEL_RESOLVER, CHANNEL_TXS, CHANNEL_VOTES,
 };
 
 use crate::{
     SimContext, SimTransportError,
-    channels::{SimMarshalChannels, SimSimplexChannels},
+    channels::{Receiver, Sender, SimMarshalChannels, SimSimplexChannels},
 };
 
 /// Configuration for simulated network links.
@@ -114,6 +114,8 @@ pub struct SimChannels<P: PublicKey> {
     pub simplex: SimSimplexChannels<P>,
     /// Marshal block dissemination channels.
     pub marshal: SimMarshalChannels<P>,
+    /// Transaction gossip channel.
+    pub txs: (Sender<P>, Receiver<P>),
 }
 
 impl<P: PublicKey> fmt::Debug for SimChannels<P> {
@@ -147,10 +149,15 @@ pub async fn register_node_channels<P: PublicKey>(
         .register(CHANNEL_BACKFILL, quota)
         .await
         .map_err(|e| SimTransportError::ChannelRegistration(format!("backfill: {e}")))?;
+    let txs = control
+        .register(CHANNEL_TXS, quota)
+        .await
+        .map_err(|e| SimTransportError::ChannelRegistration(format!("txs: {e}")))?;
 
     Ok(SimChannels {
         simplex: SimSimplexChannels { votes, certs, resolver },
         marshal: SimMarshalChannels { blocks, backfill },
+        txs,
     })
 }
 
@@ -9,10 +9,11 @@ use rand_core::CryptoRngCore;
 
 use crate::{
     channels::{
-        CHANNEL_BACKFILL, CHANNEL_BLOCKS, CHANNEL_CERTS, CHANNEL_RESOLVER, CHANNEL_VOTES,
-        MarshalChannels, SimplexChannels,
+        CHANNEL_BACKFILL, CHANNEL_BLOCKS, CHANNEL_CERTS, CHANNEL_KEEPALIVE, CHANNEL_RESOLVER,
+        CHANNEL_TXS, CHANNEL_VOTES, MarshalChannels, SimplexChannels,
     },
     config::TransportConfig,
+    keepalive::Keepalive,
     transport::NetworkTransport,
 };
 
@@ -70,6 +71,7 @@ impl<C: Signer> TransportConfig<C> {
         E: Spawner + Clock + CryptoRngCore + RNetwork + Resolver + Metrics,
     {
         let backlog = self.backlog;
+        let keepalive = self.keepalive;
 
         // Create network and oracle
         let (mut network, oracle) =
@@ -84,16 +86,38 @@ impl<C: Signer> TransportConfig<C> {
         let blocks = network.register(CHANNEL_BLOCKS, quota, backlog);
         let backfill = network.register(CHANNEL_BACKFILL, quota, backlog);
 
+        // Register transaction gossip channel
+        let txs = network.register(CHANNEL_TXS, quota, backlog);
+
+        // Register keepalive channel
+        let keepalive = keepalive.map(|settings| {
+            let (sender, receiver) = network.register(CHANNEL_KEEPALIVE, quota, backlog);
+            (settings, sender, receiver)
+        });
+
         // Start the network
         let handle = network.start();
 
-        tracing::info!("network transport started with 5 channels");
+        let keepalive = keepalive.map(|(settings, sender, receiver)| {
+            Keepalive::new(
+                context.with_label("keepalive"),
+                settings,
+                sender,
+                receiver,
+                oracle.clone(),
+            )
+            .start()
+        });
+
+        tracing::info!(keepalive = keepalive.is_some(), "network transport started");
 
         NetworkTransport {
             oracle,
             handle,
+            keepalive,
             simplex: SimplexChannels { votes, certs, resolver },
             marshal: MarshalChannels { blocks, backfill },
+            txs,
         }
     }
 }
@@ -5,7 +5,7 @@ use std::fmt;
 use commonware_cryptography::PublicKey;
 use commonware_runtime::{Clock, Handle};
 
-use crate::channels::{MarshalChannels, SimplexChannels};
+use crate::channels::{MarshalChannels, Receiver, Sender, SimplexChannels};
 
 /// Bundle of registered transport channels ready for node use.
 ///
@@ -18,6 +18,9 @@ pub struct TransportBundle<P: PublicKey, E: Clock> {
     /// Channels for block dissemination and backfill (marshal).
     pub marshal: MarshalChannels<P, E>,
 
+    /// Transaction gossip channel.
+    pub txs: (Sender<P, E>, Receiver<P>),
+
     /// Network handle to keep the transport alive.
     pub handle: Handle<()>,
 }
@@ -36,8 +39,9 @@ impl<P: PublicKey, E: Clock> TransportBundle<P, E> {
     pub const fn new(
         simplex: SimplexChannels<P, E>,
         marshal: MarshalChannels<P, E>,
+        txs: (Sender<P, E>, Receiver<P>),
         handle: Handle<()>,
     ) -> Self {
-        Self { simplex, marshal, handle }
+        Self { simplex, marshal, txs, handle }
     }
 }
@@ -21,6 +21,12 @@ pub const CHANNEL_BLOCKS: u64 = 3;
 /// Channel ID for backfill messages.
 pub const CHANNEL_BACKFILL: u64 = 4;
 
+/// Channel ID for keepalive ping/pong messages.
+pub const CHANNEL_KEEPALIVE: u64 = 5;
+
+/// Channel ID for transaction gossip.
+pub const CHANNEL_TXS: u64 = 6;
+
 /// Type alias for channel sender.
 pub type Sender<P, E> = discovery::Sender<P, E>;
 
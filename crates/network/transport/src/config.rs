@@ -5,6 +5,7 @@ use std::{fmt, net::SocketAddr};
 use commonware_codec::{FixedSize, ReadExt};
 use commonware_cryptography::ed25519;
 use commonware_p2p::{Ingress, authenticated::discovery};
+use monmouth_config::KeepaliveSettings;
 
 use crate::error::TransportError;
 
@@ -28,11 +29,17 @@ pub struct TransportConfig<C: commonware_cryptography::Signer> {
 
     /// Channel backlog size.
     pub(crate) backlog: usize,
+
+    /// Keepalive schedule, if peers should be pinged.
+    pub(crate) keepalive: Option<KeepaliveSettings>,
 }
 
 impl<C: commonware_cryptography::Signer> fmt::Debug for TransportConfig<C> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("TransportConfig").field("backlog", &self.backlog).finish_non_exhaustive()
+        f.debug_struct("TransportConfig")
+            .field("backlog", &self.backlog)
+            .field("keepalive", &self.keepalive)
+            .finish_non_exhaustive()
     }
 }
 
@@ -62,6 +69,7 @@ impl<C: commonware_cryptography::Signer> TransportConfig<C> {
                 max_message_size,
             ),
             backlog: DEFAULT_BACKLOG,
+            keepalive: None,
         }
     }
 
@@ -86,6 +94,7 @@ impl<C: commonware_cryptography::Signer> TransportConfig<C> {
                 max_message_size,
             ),
             backlog: DEFAULT_BACKLOG,
+            keepalive: None,
         }
     }
 
@@ -96,6 +105,13 @@ impl<C: commonware_cryptography::Signer> TransportConfig<C> {
         self
     }
 
+    /// Ping peers on the given keepalive schedule, disconnecting unresponsive ones.
+    #[must_use]
+    pub const fn with_keepalive(mut self, keepalive: KeepaliveSettings) -> Self {
+        self.keepalive = Some(keepalive);
+        self
+    }
+
     /// Allow private IP addresses for connections.
     #[must_use]
     pub const fn with_allow_private_ips(mut self, allow: bool) -> Self {
@@ -34,6 +34,10 @@ pub enum TransportError {
     /// Failed to parse port.
     #[error("invalid port: {0}")]
     InvalidPort(String),
+
+    /// Keepalive schedule is invalid.
+    #[error(transparent)]
+    InvalidKeepalive(#[from] monmouth_config::ConfigError),
 }
 
 #[cfg(test)]
@@ -57,7 +57,8 @@ impl NetworkConfigExt for NetworkConfig {
             bootstrappers,
             DEFAULT_MAX_MESSAGE_SIZE,
         )
-        .with_allow_private_ips(true);
+        .with_allow_private_ips(true)
+        .with_keepalive(self.validated_keepalive()?);
 
         Ok(transport_config.build(context))
     }
@@ -79,7 +80,8 @@ impl NetworkConfigExt for NetworkConfig {
             dialable,
             bootstrappers,
             DEFAULT_MAX_MESSAGE_SIZE,
-        );
+        )
+        .with_keepalive(self.validated_keepalive()?);
 
         Ok(transport_config.build(context))
     }
@@ -89,24 +91,72 @@ impl NetworkConfigExt for NetworkConfig {
 type Bootstrappers = Vec<(ed25519::PublicKey, Ingress)>;
 
 /// Parse network config into transport construction parameters.
+///
+/// The listener binds `listen_addr`, while the advertised address (see
+/// [`NetworkConfig::advertised_addr`]) goes into the signed peer record that
+/// discovery shares, so peers dial the reachable address.
 fn parse_network_config(
     config: &NetworkConfig,
 ) -> Result<(SocketAddr, Ingress, Bootstrappers), TransportError> {
-    let listen_addr: SocketAddr = config
-        .listen_addr
-        .parse()
-        .map_err(|_| TransportError::InvalidListenAddr(config.listen_addr.clone()))?;
-
-    let dialable = if let Some(ref dialable_addr) = config.dialable_addr {
-        let addr: SocketAddr = dialable_addr
-            .parse()
-            .map_err(|_| TransportError::InvalidListenAddr(dialable_addr.clone()))?;
-        Ingress::Socket(addr)
-    } else {
-        Ingress::Socket(listen_addr)
+    let listen_addr = TransportParsing::parse_listen_addr(&config.listen_addr)?;
+
+    let dialable = match config.dialable_addr {
+        Some(ref dialable_addr) => TransportParsing::parse_ingress(dialable_addr)?,
+        None => Ingress::Socket(listen_addr),
     };
 
     let bootstrappers = TransportParsing::parse_bootstrappers(&config.bootstrap_peers)?;
 
     Ok((listen_addr, dialable, bootstrappers))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advertised_address_is_dialable_while_listen_address_binds() {
+        let config = NetworkConfig {
+            listen_addr: "0.0.0.0:30303".to_string(),
+            dialable_addr: Some("203.0.113.7:40404".to_string()),
+            bootstrap_peers: Vec::new(),
+            ..Default::default()
+        };
+
+        let (listen, dialable, _) = parse_network_config(&config).unwrap();
+        assert_eq!(listen, "0.0.0.0:30303".parse::<SocketAddr>().unwrap());
+        let advertised: SocketAddr = "203.0.113.7:40404".parse().unwrap();
+        assert!(matches!(dialable, Ingress::Socket(addr) if addr == advertised));
+    }
+
+    #[test]
+    fn advertised_hostname_is_dialable() {
+        let config = NetworkConfig {
+            dialable_addr: Some("lb.example.com:30303".to_string()),
+            ..Default::default()
+        };
+
+        let (listen, dialable, _) = parse_network_config(&config).unwrap();
+        assert_eq!(listen.to_string(), config.listen_addr);
+        assert!(matches!(dialable, Ingress::Dns { port: 30303, .. }));
+    }
+
+    #[test]
+    fn listen_address_is_dialable_by_default() {
+        let config = NetworkConfig::default();
+        let (listen, dialable, _) = parse_network_config(&config).unwrap();
+        assert!(matches!(dialable, Ingress::Socket(addr) if addr == listen));
+    }
+
+    #[test]
+    fn invalid_advertised_address_is_rejected() {
+        let config = NetworkConfig {
+            dialable_addr: Some("not an address".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            parse_network_config(&config),
+            Err(TransportError::InvalidDialableAddr(_))
+        ));
+    }
+}
@@ -0,0 +1,358 @@
+//! Application-level keepalive for connected peers.
+
+use std::{collections::HashMap, fmt, hash::Hash};
+
+use bytes::Bytes;
+use commonware_cryptography::PublicKey;
+use commonware_macros::select;
+use commonware_p2p::{Blocker, Receiver, Recipients, Sender};
+use commonware_runtime::{Clock, Handle, Spawner};
+use monmouth_config::KeepaliveSettings;
+
+/// Message tag for a ping.
+const TAG_PING: u8 = 0;
+
+/// Message tag for a pong.
+const TAG_PONG: u8 = 1;
+
+/// Encoded keepalive message length: tag byte followed by a big-endian nonce.
+const MESSAGE_LEN: usize = 9;
+
+/// A keepalive message exchanged on [`CHANNEL_KEEPALIVE`](crate::CHANNEL_KEEPALIVE).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Ping(u64),
+    Pong(u64),
+}
+
+impl Message {
+    fn encode(self) -> Bytes {
+        let (tag, nonce) = match self {
+            Self::Ping(nonce) => (TAG_PING, nonce),
+            Self::Pong(nonce) => (TAG_PONG, nonce),
+        };
+        let mut buf = [0u8; MESSAGE_LEN];
+        buf[0] = tag;
+        buf[1..].copy_from_slice(&nonce.to_be_bytes());
+        Bytes::copy_from_slice(&buf)
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        let (&tag, nonce) = bytes.split_first()?;
+        let nonce = u64::from_be_bytes(nonce.try_into().ok()?);
+        match tag {
+            TAG_PING => Some(Self::Ping(nonce)),
+            TAG_PONG => Some(Self::Pong(nonce)),
+            _ => None,
+        }
+    }
+}
+
+/// Per-peer liveness state.
+#[derive(Debug, Default)]
+struct Liveness {
+    /// Nonce of the last ping that has not been answered yet.
+    outstanding: Option<u64>,
+    /// Consecutive pings that went unanswered.
+    missed: u32,
+}
+
+/// Tracks unanswered pings and decides which peers to disconnect.
+#[derive(Debug)]
+struct Tracker<P> {
+    max_missed: u32,
+    peers: HashMap<P, Liveness>,
+}
+
+impl<P: Clone + Eq + Hash> Tracker<P> {
+    fn new(max_missed: u32) -> Self {
+        Self { max_missed: max_missed.max(1), peers: HashMap::new() }
+    }
+
+    /// Record a ping with `nonce` sent to `pinged`.
+    ///
+    /// Peers that did not answer their previous ping accrue a miss. Returns the
+    /// peers that reached the miss limit; they are forgotten so a reconnect
+    /// starts from a clean slate, as are peers that were not pinged this round.
+    fn ping(&mut self, nonce: u64, pinged: &[P]) -> Vec<P> {
+        let mut next = HashMap::with_capacity(pinged.len());
+        let mut dead = Vec::new();
+        for peer in pinged {
+            let mut liveness = self.peers.remove(peer).unwrap_or_default();
+            if liveness.outstanding.is_some() {
+                liveness.missed += 1;
+            }
+            if liveness.missed >= self.max_missed {
+                dead.push(peer.clone());
+                continue;
+            }
+            liveness.outstanding = Some(nonce);
+            next.insert(peer.clone(), liveness);
+        }
+        self.peers = next;
+        dead
+    }
+
+    /// Record a pong for `nonce` from `peer`.
+    fn pong(&mut self, peer: &P, nonce: u64) {
+        if let Some(liveness) = self.peers.get_mut(peer)
+            && liveness.outstanding == Some(nonce)
+        {
+            *liveness = Liveness::default();
+        }
+    }
+}
+
+/// Keepalive actor for the transport.
+///
+/// Pings every connected peer on [`KeepaliveSettings::interval`] and answers
+/// their pings. A peer that leaves [`KeepaliveSettings::max_missed`]
+/// consecutive pings unanswered is handed to the [`Blocker`], which drops the
+/// connection.
+pub struct Keepalive<E, S, R, B> {
+    context: E,
+    settings: KeepaliveSettings,
+    sender: S,
+    receiver: R,
+    blocker: B,
+}
+
+impl<E, S, R, B> fmt::Debug for Keepalive<E, S, R, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Keepalive").field("settings", &self.settings).finish_non_exhaustive()
+    }
+}
+
+impl<E, P, S, R, B> Keepalive<E, S, R, B>
+where
+    E: Spawner + Clock,
+    P: PublicKey,
+    S: Sender<PublicKey = P>,
+    R: Receiver<PublicKey = P>,
+    B: Blocker<PublicKey = P>,
+{
+    /// Create a keepalive actor over the given channel.
+    pub const fn new(
+        context: E,
+        settings: KeepaliveSettings,
+        sender: S,
+        receiver: R,
+        blocker: B,
+    ) -> Self {
+        Self { context, settings, sender, receiver, blocker }
+    }
+
+    /// Spawn the actor.
+    ///
+    /// The actor runs until the channel closes or the handle is aborted.
+    pub fn start(self) -> Handle<()> {
+        let Self { context, settings, mut sender, mut receiver, mut blocker } = self;
+        context.spawn(move |context| async move {
+            let mut tracker = Tracker::new(settings.max_missed);
+            let mut nonce = 0u64;
+            let mut next_tick = context.current();
+            loop {
+                select! {
+                    _ = context.sleep_until(next_tick) => {
+                        next_tick += settings.interval;
+                        nonce = nonce.wrapping_add(1);
+                        let pinged = match sender
+                            .send(Recipients::All, Message::Ping(nonce).encode(), false)
+                            .await
+                        {
+                            Ok(pinged) => pinged,
+                            Err(err) => {
+                                tracing::warn!(%err, "failed to send keepalive ping");
+                                Vec::new()
+                            }
+                        };
+                        for peer in tracker.ping(nonce, &pinged) {
+                            tracing::info!(?peer, "disconnecting unresponsive peer");
+                            blocker.block(peer).await;
+                        }
+                    },
+                    message = receiver.recv() => {
+                        let Ok((peer, bytes)) = message else {
+                            tracing::debug!("keepalive channel closed");
+                            return;
+                        };
+                        match Message::decode(&bytes) {
+                            Some(Message::Ping(nonce)) => {
+                                let pong = Message::Pong(nonce).encode();
+                                if let Err(err) =
+                                    sender.send(Recipients::One(peer), pong, false).await
+                                {
+                                    tracing::debug!(%err, "failed to send keepalive pong");
+                                }
+                            }
+                            Some(Message::Pong(nonce)) => tracker.pong(&peer, nonce),
+                            None => tracing::debug!(?peer, "malformed keepalive message"),
+                        }
+                    },
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        num::NonZeroU32,
+        sync::{Arc, Mutex, PoisonError},
+        time::Duration,
+    };
+
+    use commonware_cryptography::{Signer as _, ed25519};
+    use commonware_p2p::{Manager as _, simulated};
+    use commonware_runtime::{Metrics as _, Quota, Runner as _, deterministic};
+    use commonware_utils::{TryCollect as _, ordered::Set};
+
+    use super::*;
+    use crate::CHANNEL_KEEPALIVE;
+
+    const SETTINGS: KeepaliveSettings =
+        KeepaliveSettings { interval: Duration::from_millis(500), max_missed: 4 };
+
+    #[derive(Clone, Default)]
+    struct RecordingBlocker {
+        blocked: Arc<Mutex<Vec<ed25519::PublicKey>>>,
+    }
+
+    impl RecordingBlocker {
+        fn blocked(&self) -> Vec<ed25519::PublicKey> {
+            self.blocked.lock().unwrap_or_else(PoisonError::into_inner).clone()
+        }
+    }
+
+    impl Blocker for RecordingBlocker {
+        type PublicKey = ed25519::PublicKey;
+
+        async fn block(&mut self, peer: Self::PublicKey) {
+            self.blocked.lock().unwrap_or_else(PoisonError::into_inner).push(peer);
+        }
+    }
+
+    fn key(seed: u8) -> ed25519::PrivateKey {
+        ed25519::PrivateKey::from(ed25519_consensus::SigningKey::from([seed; 32]))
+    }
+
+    /// Runs a keepalive on peer A and returns its blocker after `elapsed`.
+    ///
+    /// Peer B answers pings only if `responsive` is set; otherwise it keeps its
+    /// channel registered but never reads from it.
+    fn run_pair(
+        responsive: bool,
+        elapsed: Duration,
+    ) -> (ed25519::PublicKey, Vec<ed25519::PublicKey>) {
+        let runner = deterministic::Runner::timed(Duration::from_secs(60));
+        runner.start(|context| async move {
+            let (network, mut oracle) = simulated::Network::new(
+                context.with_label("network"),
+                simulated::Config {
+                    max_size: 1024,
+                    disconnect_on_block: true,
+                    tracked_peer_sets: None,
+                },
+            );
+            network.start();
+
+            let (a, b) = (key(1).public_key(), key(2).public_key());
+            let peers: Set<_> = [a.clone(), b.clone()].into_iter().try_collect().unwrap();
+            oracle.manager().update(0, peers).await;
+            let link = simulated::Link {
+                latency: Duration::from_millis(10),
+                jitter: Duration::ZERO,
+                success_rate: 1.0,
+            };
+            oracle.add_link(a.clone(), b.clone(), link.clone()).await.unwrap();
+            oracle.add_link(b.clone(), a.clone(), link).await.unwrap();
+
+            let quota = Quota::per_second(NonZeroU32::new(100).unwrap());
+            let (a_sender, a_receiver) =
+                oracle.control(a.clone()).register(CHANNEL_KEEPALIVE, quota).await.unwrap();
+            let (b_sender, b_receiver) =
+                oracle.control(b.clone()).register(CHANNEL_KEEPALIVE, quota).await.unwrap();
+
+            let blocker = RecordingBlocker::default();
+            let _a = Keepalive::new(
+                context.with_label("keepalive_a"),
+                SETTINGS,
+                a_sender,
+                a_receiver,
+                blocker.clone(),
+            )
+            .start();
+            let _b = responsive.then(|| {
+                Keepalive::new(
+                    context.with_label("keepalive_b"),
+                    SETTINGS,
+                    b_sender,
+                    b_receiver,
+                    RecordingBlocker::default(),
+                )
+                .start()
+            });
+
+            context.sleep(elapsed).await;
+            (b, blocker.blocked())
+        })
+    }
+
+    #[test]
+    fn unresponsive_peer_is_disconnected_within_timeout() {
+        let timeout = SETTINGS.interval * SETTINGS.max_missed;
+
+        let (_, blocked) = run_pair(false, timeout - SETTINGS.interval / 2);
+        assert!(blocked.is_empty(), "peer blocked before the timeout elapsed");
+
+        let (b, blocked) = run_pair(false, timeout + SETTINGS.interval / 2);
+        assert_eq!(blocked, vec![b]);
+    }
+
+    #[test]
+    fn responsive_peer_stays_connected() {
+        let (_, blocked) = run_pair(true, SETTINGS.interval * SETTINGS.max_missed * 4);
+        assert!(blocked.is_empty());
+    }
+
+    #[test]
+    fn tracker_blocks_after_max_missed() {
+        let mut tracker = Tracker::new(3);
+        assert!(tracker.ping(1, &["a"]).is_empty());
+        assert!(tracker.ping(2, &["a"]).is_empty());
+        assert!(tracker.ping(3, &["a"]).is_empty());
+        assert_eq!(tracker.ping(4, &["a"]), vec!["a"]);
+        assert!(tracker.peers.is_empty());
+    }
+
+    #[test]
+    fn tracker_pong_resets_misses() {
+        let mut tracker = Tracker::new(2);
+        tracker.ping(1, &["a"]);
+        tracker.ping(2, &["a"]);
+        tracker.pong(&"a", 1);
+        assert_eq!(tracker.peers["a"].missed, 1, "stale pong must not count");
+        tracker.pong(&"a", 2);
+        assert_eq!(tracker.peers["a"].missed, 0);
+        assert!(tracker.ping(3, &["a"]).is_empty());
+    }
+
+    #[test]
+    fn tracker_forgets_disconnected_peers() {
+        let mut tracker = Tracker::new(2);
+        tracker.ping(1, &["a", "b"]);
+        tracker.ping(2, &["b"]);
+        assert!(!tracker.peers.contains_key("a"));
+        assert!(tracker.ping(3, &["a"]).is_empty());
+    }
+
+    #[test]
+    fn message_roundtrip() {
+        for message in [Message::Ping(7), Message::Pong(u64::MAX)] {
+            assert_eq!(Message::decode(&message.encode()), Some(message));
+        }
+        assert_eq!(Message::decode(&[TAG_PING]), None);
+        assert_eq!(Message::decode(&[9; MESSAGE_LEN]), None);
+    }
+}
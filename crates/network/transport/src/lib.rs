@@ -12,8 +12,8 @@ pub use bundle::TransportBundle;
 
 mod channels;
 pub use channels::{
-    CHANNEL_BACKFILL, CHANNEL_BLOCKS, CHANNEL_CERTS, CHANNEL_RESOLVER, CHANNEL_VOTES,
-    MarshalChannels, Receiver, Sender, SimplexChannels,
+    CHANNEL_BACKFILL, CHANNEL_BLOCKS, CHANNEL_CERTS, CHANNEL_KEEPALIVE, CHANNEL_RESOLVER,
+    CHANNEL_TXS, CHANNEL_VOTES, MarshalChannels, Receiver, Sender, SimplexChannels,
 };
 
 mod config;
@@ -30,6 +30,9 @@ pub use ext::NetworkConfigExt;
 mod provider;
 pub use provider::TransportProvider;
 
+mod keepalive;
+pub use keepalive::Keepalive;
+
 mod network_provider;
 pub use network_provider::{NetworkControl, NetworkTransportProvider};
 
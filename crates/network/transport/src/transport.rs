@@ -6,13 +6,13 @@ use commonware_cryptography::PublicKey;
 use commonware_p2p::authenticated::discovery;
 use commonware_runtime::{Clock, Handle};
 
-use crate::channels::{MarshalChannels, SimplexChannels};
+use crate::channels::{MarshalChannels, Receiver, Sender, SimplexChannels};
 
 /// Complete network transport bundle.
 ///
 /// Contains everything needed to wire up consensus and application layers:
 /// - The oracle for peer management and blocking
-/// - All 5 channel pairs grouped by consumer
+/// - All channel pairs grouped by consumer
 /// - The network handle to keep it alive
 ///
 /// # Channel Groups
@@ -20,6 +20,7 @@ use crate::channels::{MarshalChannels, SimplexChannels};
 /// Channels are grouped by their consumer:
 /// - [`SimplexChannels`]: For consensus engine (votes, certs, resolver)
 /// - [`MarshalChannels`]: For block dissemination (blocks, backfill)
+/// - `txs`: For transaction gossip
 pub struct NetworkTransport<P: PublicKey, E: Clock> {
     /// Oracle for peer management and Byzantine blocking.
     ///
@@ -32,11 +33,17 @@ pub struct NetworkTransport<P: PublicKey, E: Clock> {
     /// Drop this and the network shuts down.
     pub handle: Handle<()>,
 
+    /// Keepalive task handle, if a keepalive schedule was configured.
+    pub keepalive: Option<Handle<()>>,
+
     /// Channels for consensus engine (simplex).
     pub simplex: SimplexChannels<P, E>,
 
     /// Channels for block dissemination and backfill (marshal).
     pub marshal: MarshalChannels<P, E>,
+
+    /// Transaction gossip channel.
+    pub txs: (Sender<P, E>, Receiver<P>),
 }
 
 impl<P: PublicKey, E: Clock> fmt::Debug for NetworkTransport<P, E> {
@@ -1,17 +1,113 @@
 //! Consensus configuration.
 
-use std::path::PathBuf;
+use std::{fmt, num::NonZeroU64, path::PathBuf, time::Duration};
 
-use alloy_primitives::hex;
+use alloy_primitives::{B256, hex};
 use commonware_codec::{FixedSize, ReadExt};
-use commonware_cryptography::ed25519;
+use commonware_cryptography::{bls12381, ed25519};
 use serde::{Deserialize, Serialize};
 
-use crate::ConfigError;
+use crate::{ConfigError, Passphrase};
 
 /// Default validator threshold.
 pub const DEFAULT_THRESHOLD: u32 = 2;
 
+/// Default number of blocks per consensus epoch.
+///
+/// `u64::MAX` keeps the whole chain in a single epoch.
+pub const DEFAULT_EPOCH_LENGTH: u64 = u64::MAX;
+
+/// Default minimum spacing between proposed blocks, in milliseconds.
+///
+/// Zero lets a leader propose as soon as its view starts.
+pub const DEFAULT_MIN_BLOCK_TIME_MS: u64 = 0;
+
+/// Default maximum time a leader waits for transactions before proposing, in milliseconds.
+///
+/// Zero proposes immediately, producing an empty block if the mempool is empty.
+pub const DEFAULT_MAX_BLOCK_TIME_MS: u64 = 0;
+
+/// Default number of blocks a node may trail the network tip and still propose.
+pub const DEFAULT_CATCH_UP_THRESHOLD: u64 = 16;
+
+/// Validated block-production interval.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlockInterval {
+    /// Minimum time between consecutive blocks, enforced even when transactions are pending.
+    pub min: Duration,
+    /// Maximum time to wait for transactions before proposing an empty block.
+    pub max: Duration,
+}
+
+/// Signature scheme of the configured participant public keys.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyScheme {
+    /// 32-byte ed25519 keys.
+    #[default]
+    Ed25519,
+    /// 48-byte BLS12-381 keys.
+    Bls12381,
+}
+
+impl KeyScheme {
+    /// Stable label used in configuration files and errors.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Ed25519 => "ed25519",
+            Self::Bls12381 => "bls12381",
+        }
+    }
+}
+
+impl fmt::Display for KeyScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Participant public keys parsed according to their [`KeyScheme`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParticipantKeys {
+    /// ed25519 participants.
+    Ed25519(Vec<ed25519::PublicKey>),
+    /// BLS12-381 participants.
+    Bls12381(Vec<bls12381::PublicKey>),
+}
+
+impl ParticipantKeys {
+    /// Scheme the keys belong to.
+    pub const fn scheme(&self) -> KeyScheme {
+        match self {
+            Self::Ed25519(_) => KeyScheme::Ed25519,
+            Self::Bls12381(_) => KeyScheme::Bls12381,
+        }
+    }
+
+    /// Number of participants.
+    pub const fn len(&self) -> usize {
+        match self {
+            Self::Ed25519(keys) => keys.len(),
+            Self::Bls12381(keys) => keys.len(),
+        }
+    }
+
+    /// Whether no participants are configured.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// State root a light validator trusts at a given height.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LightCheckpoint {
+    /// Block height.
+    pub height: u64,
+    /// Trusted state root (hex-encoded).
+    #[serde(serialize_with = "serialize_root", deserialize_with = "deserialize_root")]
+    pub state_root: B256,
+}
+
 /// Consensus layer configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ConsensusConfig {
@@ -19,10 +115,22 @@ pub struct ConsensusConfig {
     #[serde(default)]
     pub validator_key: Option<PathBuf>,
 
+    /// Passphrase encrypting the validator key file at rest.
+    ///
+    /// Newly generated keys are written encrypted when set; existing plaintext
+    /// keys keep loading either way. Never written back out when the config is
+    /// serialized.
+    #[serde(default, skip_serializing)]
+    pub validator_key_passphrase: Option<Passphrase>,
+
     /// Threshold for consensus (e.g., 2f+1 of 3f+1).
     #[serde(default = "default_threshold")]
     pub threshold: u32,
 
+    /// Signature scheme of the participant public keys.
+    #[serde(default)]
+    pub key_scheme: KeyScheme,
+
     /// List of participant public keys (hex-encoded).
     #[serde(
         default,
@@ -30,11 +138,61 @@ pub struct ConsensusConfig {
         deserialize_with = "deserialize_participants"
     )]
     pub participants: Vec<Vec<u8>>,
+
+    /// Number of blocks per consensus epoch. Must be non-zero.
+    ///
+    /// Omitted from serialized output when left at the default, since TOML
+    /// integers cannot represent [`DEFAULT_EPOCH_LENGTH`].
+    #[serde(default = "default_epoch_length", skip_serializing_if = "is_default_epoch_length")]
+    pub epoch_length: u64,
+
+    /// Minimum spacing between proposed blocks, in milliseconds.
+    #[serde(default = "default_min_block_time_ms")]
+    pub min_block_time_ms: u64,
+
+    /// Maximum time a leader waits for transactions before proposing an empty block,
+    /// in milliseconds. Must be at least `min_block_time_ms` and should stay below
+    /// the simplex leader timeout, or the view is skipped instead.
+    #[serde(default = "default_max_block_time_ms")]
+    pub max_block_time_ms: u64,
+
+    /// Number of blocks the local head may trail the network tip before the node
+    /// stops proposing and only applies blocks until it catches up.
+    #[serde(default = "default_catch_up_threshold")]
+    pub catch_up_threshold: u64,
+
+    /// Verify proposals structurally and against [`light_checkpoints`](Self::light_checkpoints)
+    /// instead of re-executing them. Trusts proposers' state roots between
+    /// checkpoints; a light validator does not propose.
+    #[serde(default)]
+    pub light_verification: bool,
+
+    /// Trusted state roots used in light verification.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub light_checkpoints: Vec<LightCheckpoint>,
+
+    /// Finalized blocks between checkpoints this validator signs with its key
+    /// share. Zero disables checkpoints.
+    #[serde(default)]
+    pub checkpoint_interval: u64,
 }
 
 impl Default for ConsensusConfig {
     fn default() -> Self {
-        Self { validator_key: None, threshold: DEFAULT_THRESHOLD, participants: Vec::new() }
+        Self {
+            validator_key: None,
+            validator_key_passphrase: None,
+            threshold: DEFAULT_THRESHOLD,
+            key_scheme: KeyScheme::Ed25519,
+            participants: Vec::new(),
+            epoch_length: DEFAULT_EPOCH_LENGTH,
+            min_block_time_ms: DEFAULT_MIN_BLOCK_TIME_MS,
+            max_block_time_ms: DEFAULT_MAX_BLOCK_TIME_MS,
+            catch_up_threshold: DEFAULT_CATCH_UP_THRESHOLD,
+            light_verification: false,
+            light_checkpoints: Vec::new(),
+            checkpoint_interval: 0,
+        }
     }
 }
 
@@ -42,25 +200,107 @@ impl ConsensusConfig {
     /// Build the validator set from configured participants.
     ///
     /// Parses the hex-encoded participant public keys into [`ed25519::PublicKey`] values.
-    /// Returns an empty set if no participants are configured.
+    /// Returns an empty set if no participants are configured, and
+    /// [`ConfigError::UnsupportedKeyScheme`] if the participants use another scheme.
     pub fn build_validator_set(&self) -> Result<Vec<ed25519::PublicKey>, ConfigError> {
-        self.participants
-            .iter()
-            .map(|bytes| {
-                if bytes.len() != ed25519::PublicKey::SIZE {
-                    return Err(ConfigError::InvalidParticipantKeyLength(bytes.len()));
-                }
-                let mut buf = bytes.as_slice();
-                ed25519::PublicKey::read(&mut buf).map_err(|_| ConfigError::InvalidParticipantKey)
-            })
-            .collect()
+        match self.build_participant_keys()? {
+            ParticipantKeys::Ed25519(keys) => Ok(keys),
+            keys => Err(ConfigError::UnsupportedKeyScheme {
+                configured: keys.scheme(),
+                required: KeyScheme::Ed25519,
+            }),
+        }
+    }
+
+    /// Parse the hex-encoded participant public keys as [`key_scheme`](Self::key_scheme) keys.
+    pub fn build_participant_keys(&self) -> Result<ParticipantKeys, ConfigError> {
+        Ok(match self.key_scheme {
+            KeyScheme::Ed25519 => ParticipantKeys::Ed25519(parse_keys(&self.participants)?),
+            KeyScheme::Bls12381 => ParticipantKeys::Bls12381(parse_keys(&self.participants)?),
+        })
+    }
+
+    /// Validate the configured epoch length.
+    ///
+    /// Returns [`ConfigError::ZeroEpochLength`] if the epoch length is zero.
+    pub const fn validated_epoch_length(&self) -> Result<NonZeroU64, ConfigError> {
+        match NonZeroU64::new(self.epoch_length) {
+            Some(length) => Ok(length),
+            None => Err(ConfigError::ZeroEpochLength),
+        }
+    }
+
+    /// Validate the configured block-production interval.
+    ///
+    /// Returns [`ConfigError::InvalidBlockInterval`] if the minimum exceeds the maximum.
+    pub const fn validated_block_interval(&self) -> Result<BlockInterval, ConfigError> {
+        if self.min_block_time_ms > self.max_block_time_ms {
+            return Err(ConfigError::InvalidBlockInterval {
+                min_ms: self.min_block_time_ms,
+                max_ms: self.max_block_time_ms,
+            });
+        }
+        Ok(BlockInterval {
+            min: Duration::from_millis(self.min_block_time_ms),
+            max: Duration::from_millis(self.max_block_time_ms),
+        })
     }
 }
 
+fn parse_keys<K: FixedSize + ReadExt>(participants: &[Vec<u8>]) -> Result<Vec<K>, ConfigError> {
+    participants
+        .iter()
+        .map(|bytes| {
+            if bytes.len() != K::SIZE {
+                return Err(ConfigError::InvalidParticipantKeyLength(bytes.len()));
+            }
+            let mut buf = bytes.as_slice();
+            K::read(&mut buf).map_err(|_| ConfigError::InvalidParticipantKey)
+        })
+        .collect()
+}
+
 const fn default_threshold() -> u32 {
     DEFAULT_THRESHOLD
 }
 
+const fn default_epoch_length() -> u64 {
+    DEFAULT_EPOCH_LENGTH
+}
+
+const fn default_min_block_time_ms() -> u64 {
+    DEFAULT_MIN_BLOCK_TIME_MS
+}
+
+const fn default_max_block_time_ms() -> u64 {
+    DEFAULT_MAX_BLOCK_TIME_MS
+}
+
+const fn default_catch_up_threshold() -> u64 {
+    DEFAULT_CATCH_UP_THRESHOLD
+}
+
+const fn is_default_epoch_length(epoch_length: &u64) -> bool {
+    *epoch_length == DEFAULT_EPOCH_LENGTH
+}
+
+fn serialize_root<S>(root: &B256, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&hex::encode(root))
+}
+
+fn deserialize_root<'de, D>(deserializer: D) -> Result<B256, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let string = String::deserialize(deserializer)?;
+    let bytes = hex::decode(string.strip_prefix("0x").unwrap_or(&string))
+        .map_err(serde::de::Error::custom)?;
+    B256::try_from(bytes.as_slice()).map_err(serde::de::Error::custom)
+}
+
 fn serialize_participants<S>(participants: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error>
 where
     S: serde::Serializer,
@@ -106,6 +346,7 @@ mod tests {
         assert!(config.validator_key.is_none());
         assert_eq!(config.threshold, DEFAULT_THRESHOLD);
         assert!(config.participants.is_empty());
+        assert_eq!(config.epoch_length, DEFAULT_EPOCH_LENGTH);
     }
 
     #[test]
@@ -119,14 +360,40 @@ mod tests {
         let pk_bytes = create_valid_public_key_bytes();
         let config = ConsensusConfig {
             validator_key: Some(PathBuf::from("/path/to/key")),
+            validator_key_passphrase: None,
             threshold: 3,
+            key_scheme: KeyScheme::Ed25519,
             participants: vec![pk_bytes],
+            epoch_length: 100,
+            min_block_time_ms: 250,
+            max_block_time_ms: 1000,
+            catch_up_threshold: 4,
+            light_verification: true,
+            light_checkpoints: vec![LightCheckpoint {
+                height: 8,
+                state_root: B256::repeat_byte(0x08),
+            }],
+            checkpoint_interval: 1_000,
         };
         let serialized = serde_json::to_string(&config).expect("serialize");
         let deserialized: ConsensusConfig = serde_json::from_str(&serialized).expect("deserialize");
         assert_eq!(config, deserialized);
     }
 
+    #[test]
+    fn passphrase_is_redacted_and_never_serialized() {
+        let config: ConsensusConfig =
+            toml::from_str("validator_key_passphrase = \"hunter2\"\n").expect("deserialize toml");
+        assert_eq!(
+            config.validator_key_passphrase.as_ref().map(Passphrase::expose),
+            Some("hunter2")
+        );
+
+        assert!(!format!("{config:?}").contains("hunter2"));
+        assert!(!toml::to_string(&config).expect("serialize toml").contains("hunter2"));
+        assert!(!serde_json::to_string(&config).expect("serialize").contains("hunter2"));
+    }
+
     #[test]
     fn serde_toml_roundtrip() {
         let config =
@@ -136,12 +403,82 @@ mod tests {
         assert_eq!(config, deserialized);
     }
 
+    #[test]
+    fn serde_toml_light_checkpoints() {
+        let root = B256::repeat_byte(0xab);
+        let toml = format!(
+            "light_verification = true\n\n[[light_checkpoints]]\nheight = 12\nstate_root = \"0x{}\"\n",
+            hex::encode(root)
+        );
+        let config: ConsensusConfig = toml::from_str(&toml).expect("deserialize toml");
+        assert!(config.light_verification);
+        assert_eq!(
+            config.light_checkpoints,
+            vec![LightCheckpoint { height: 12, state_root: root }]
+        );
+
+        let serialized = toml::to_string(&config).expect("serialize toml");
+        assert_eq!(toml::from_str::<ConsensusConfig>(&serialized).expect("roundtrip"), config);
+    }
+
     #[test]
     fn serde_defaults_applied() {
         let config: ConsensusConfig = serde_json::from_str("{}").expect("deserialize");
         assert!(config.validator_key.is_none());
         assert_eq!(config.threshold, DEFAULT_THRESHOLD);
         assert!(config.participants.is_empty());
+        assert_eq!(config.catch_up_threshold, DEFAULT_CATCH_UP_THRESHOLD);
+    }
+
+    #[test]
+    fn serde_toml_default_epoch_length_roundtrip() {
+        let config = ConsensusConfig::default();
+        let serialized = toml::to_string(&config).expect("serialize toml");
+        assert!(!serialized.contains("epoch_length"));
+        let deserialized: ConsensusConfig = toml::from_str(&serialized).expect("deserialize toml");
+        assert_eq!(deserialized.epoch_length, DEFAULT_EPOCH_LENGTH);
+    }
+
+    #[test]
+    fn serde_toml_custom_epoch_length() {
+        let config: ConsensusConfig = toml::from_str("epoch_length = 50").expect("deserialize");
+        assert_eq!(config.epoch_length, 50);
+        assert_eq!(config.validated_epoch_length().unwrap().get(), 50);
+    }
+
+    #[test]
+    fn validated_epoch_length_rejects_zero() {
+        let config = ConsensusConfig { epoch_length: 0, ..Default::default() };
+        assert!(matches!(config.validated_epoch_length(), Err(ConfigError::ZeroEpochLength)));
+    }
+
+    #[test]
+    fn validated_block_interval_defaults_to_zero() {
+        let interval = ConsensusConfig::default().validated_block_interval().unwrap();
+        assert_eq!(interval, BlockInterval::default());
+    }
+
+    #[test]
+    fn serde_toml_custom_block_interval() {
+        let config: ConsensusConfig =
+            toml::from_str("min_block_time_ms = 200\nmax_block_time_ms = 800")
+                .expect("deserialize");
+        let interval = config.validated_block_interval().unwrap();
+        assert_eq!(interval.min, Duration::from_millis(200));
+        assert_eq!(interval.max, Duration::from_millis(800));
+    }
+
+    #[test]
+    fn validated_block_interval_rejects_min_above_max() {
+        let config = ConsensusConfig {
+            min_block_time_ms: 500,
+            max_block_time_ms: 100,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validated_block_interval(),
+            Err(ConfigError::InvalidBlockInterval { min_ms: 500, max_ms: 100 })
+        ));
     }
 
     #[test]
@@ -223,6 +560,47 @@ mod tests {
         assert!(matches!(result.unwrap_err(), ConfigError::InvalidParticipantKeyLength(16)));
     }
 
+    fn bls_public_key_bytes(seed: u64) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bls12381::PrivateKey::from_seed(seed).public_key().write(&mut bytes);
+        bytes
+    }
+
+    #[test]
+    fn build_participant_keys_bls12381() {
+        let participants: Vec<Vec<u8>> = (1..=3).map(bls_public_key_bytes).collect();
+        let config: ConsensusConfig = serde_json::from_value(serde_json::json!({
+            "key_scheme": "bls12381",
+            "participants": participants.iter().map(hex::encode).collect::<Vec<_>>(),
+        }))
+        .expect("deserialize");
+
+        let keys = config.build_participant_keys().expect("parse bls keys");
+        let expected: Vec<bls12381::PublicKey> =
+            (1..=3).map(|seed| bls12381::PrivateKey::from_seed(seed).public_key()).collect();
+        assert_eq!(keys, ParticipantKeys::Bls12381(expected));
+        assert!(matches!(
+            config.build_validator_set(),
+            Err(ConfigError::UnsupportedKeyScheme {
+                configured: KeyScheme::Bls12381,
+                required: KeyScheme::Ed25519,
+            })
+        ));
+    }
+
+    #[test]
+    fn build_participant_keys_rejects_mismatched_length() {
+        let config = ConsensusConfig {
+            key_scheme: KeyScheme::Bls12381,
+            participants: vec![bls_public_key_bytes(1), create_valid_public_key_bytes()],
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.build_participant_keys(),
+            Err(ConfigError::InvalidParticipantKeyLength(32))
+        ));
+    }
+
     #[test]
     fn participants_hex_serialization() {
         let pk_bytes = create_valid_public_key_bytes();
@@ -240,6 +618,8 @@ mod tests {
             validator_key: Some(PathBuf::from("/custom/path")),
             threshold: 10,
             participants: vec![pk_bytes],
+            epoch_length: DEFAULT_EPOCH_LENGTH,
+            ..Default::default()
         };
         assert_eq!(config, config.clone());
         assert_ne!(config, ConsensusConfig::default());
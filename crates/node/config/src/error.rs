@@ -2,6 +2,8 @@
 
 use std::path::PathBuf;
 
+use crate::KeyScheme;
+
 /// Errors that can occur when loading or parsing configuration.
 #[derive(Debug, thiserror::Error)]
 pub enum ConfigError {
@@ -26,6 +28,16 @@ pub enum ConfigError {
     #[error("failed to serialize config to TOML: {0}")]
     TomlSerialize(#[from] toml::ser::Error),
 
+    /// Failed to parse YAML configuration.
+    #[cfg(feature = "yaml")]
+    #[error("failed to parse YAML config: {0}")]
+    YamlParse(#[source] serde_yaml::Error),
+
+    /// Failed to serialize configuration to YAML.
+    #[cfg(feature = "yaml")]
+    #[error("failed to serialize config to YAML: {0}")]
+    YamlSerialize(#[source] serde_yaml::Error),
+
     /// Invalid validator key format.
     #[error("invalid validator key format: expected 32 bytes, got {0}")]
     InvalidKeyLength(usize),
@@ -55,6 +67,91 @@ pub enum ConfigError {
     /// Failed to parse participant public key.
     #[error("invalid participant public key bytes")]
     InvalidParticipantKey,
+
+    /// Participants use a key scheme the caller cannot handle.
+    #[error(
+        "participant key scheme {configured} is unsupported here; {required} keys are required"
+    )]
+    UnsupportedKeyScheme {
+        /// Configured participant key scheme.
+        configured: KeyScheme,
+        /// Scheme the caller requires.
+        required: KeyScheme,
+    },
+
+    /// Consensus epoch length was configured as zero.
+    #[error("consensus epoch length must be non-zero")]
+    ZeroEpochLength,
+
+    /// Minimum block time exceeds the maximum block time.
+    #[error("invalid block interval: min block time {min_ms}ms exceeds max block time {max_ms}ms")]
+    InvalidBlockInterval {
+        /// Configured minimum block time in milliseconds.
+        min_ms: u64,
+        /// Configured maximum block time in milliseconds.
+        max_ms: u64,
+    },
+
+    /// Keepalive interval is zero or exceeds the keepalive timeout.
+    #[error(
+        "invalid keepalive: interval {interval_ms}ms must be non-zero and at most timeout {timeout_ms}ms"
+    )]
+    InvalidKeepalive {
+        /// Configured ping interval in milliseconds.
+        interval_ms: u64,
+        /// Configured liveness timeout in milliseconds.
+        timeout_ms: u64,
+    },
+
+    /// Chain ID was configured as zero.
+    #[error("chain id must be non-zero")]
+    ZeroChainId,
+
+    /// Consensus threshold exceeds the number of configured participants.
+    #[error("consensus threshold {threshold} exceeds {participants} configured participants")]
+    ThresholdExceedsParticipants {
+        /// Configured threshold.
+        threshold: u32,
+        /// Number of configured participants.
+        participants: usize,
+    },
+
+    /// A participant public key is listed more than once.
+    #[error("duplicate consensus participant {0}")]
+    DuplicateParticipant(String),
+
+    /// The data directory cannot be created or written.
+    #[error("data directory {path} is not writable: {reason}")]
+    DataDirNotWritable {
+        /// Configured data directory.
+        path: PathBuf,
+        /// Why the directory is not writable.
+        reason: String,
+    },
+
+    /// The validator key file could not be decrypted.
+    #[error("failed to decrypt validator key {path}: {reason}")]
+    Decrypt {
+        /// Key file path.
+        path: PathBuf,
+        /// Why decryption failed.
+        reason: &'static str,
+    },
+
+    /// An environment variable override could not be parsed.
+    #[error("invalid {var}={value:?}: {reason}")]
+    EnvParse {
+        /// Environment variable name.
+        var: &'static str,
+        /// Value that failed to parse.
+        value: String,
+        /// Parse failure.
+        reason: String,
+    },
+
+    /// Archive compression level is above the highest zstd level.
+    #[error("archive compression level {0} exceeds maximum 22")]
+    InvalidArchiveCompression(u8),
 }
 
 #[cfg(test)]
@@ -117,6 +214,79 @@ mod tests {
         assert_eq!(err.to_string(), "invalid participant public key bytes");
     }
 
+    #[test]
+    fn test_unsupported_key_scheme_display() {
+        let err = ConfigError::UnsupportedKeyScheme {
+            configured: KeyScheme::Bls12381,
+            required: KeyScheme::Ed25519,
+        };
+        assert_eq!(
+            err.to_string(),
+            "participant key scheme bls12381 is unsupported here; ed25519 keys are required"
+        );
+    }
+
+    #[test]
+    fn test_zero_epoch_length_display() {
+        let err = ConfigError::ZeroEpochLength;
+        assert_eq!(err.to_string(), "consensus epoch length must be non-zero");
+    }
+
+    #[test]
+    fn test_invalid_block_interval_display() {
+        let err = ConfigError::InvalidBlockInterval { min_ms: 500, max_ms: 100 };
+        assert_eq!(
+            err.to_string(),
+            "invalid block interval: min block time 500ms exceeds max block time 100ms"
+        );
+    }
+
+    #[test]
+    fn test_invalid_keepalive_display() {
+        let err = ConfigError::InvalidKeepalive { interval_ms: 0, timeout_ms: 100 };
+        assert_eq!(
+            err.to_string(),
+            "invalid keepalive: interval 0ms must be non-zero and at most timeout 100ms"
+        );
+    }
+
+    #[test]
+    fn test_invalid_archive_compression_display() {
+        let err = ConfigError::InvalidArchiveCompression(23);
+        assert_eq!(err.to_string(), "archive compression level 23 exceeds maximum 22");
+    }
+
+    #[test]
+    fn test_threshold_exceeds_participants_display() {
+        let err = ConfigError::ThresholdExceedsParticipants { threshold: 3, participants: 2 };
+        assert_eq!(err.to_string(), "consensus threshold 3 exceeds 2 configured participants");
+    }
+
+    #[test]
+    fn test_decrypt_display() {
+        let err = ConfigError::Decrypt {
+            path: PathBuf::from("/var/lib/monmouth/validator.key"),
+            reason: "wrong passphrase or corrupted key file",
+        };
+        assert_eq!(
+            err.to_string(),
+            "failed to decrypt validator key /var/lib/monmouth/validator.key: wrong passphrase or corrupted key file"
+        );
+    }
+
+    #[test]
+    fn test_env_parse_display() {
+        let err = ConfigError::EnvParse {
+            var: "MONMOUTH_CHAIN_ID",
+            value: "abc".to_string(),
+            reason: "invalid digit found in string".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid MONMOUTH_CHAIN_ID=\"abc\": invalid digit found in string"
+        );
+    }
+
     #[test]
     fn test_toml_parse_error_from() {
         let result: Result<toml::Value, _> = toml::from_str("invalid = [unclosed");
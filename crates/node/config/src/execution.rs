@@ -11,6 +11,9 @@ pub const DEFAULT_BLOCK_TIME: u64 = 2;
 /// Default confidence threshold for agent transaction classification.
 pub const DEFAULT_CONFIDENCE_THRESHOLD: f64 = 0.7;
 
+/// Default number of block execution worker threads; one executes sequentially.
+pub const DEFAULT_PARALLEL_WORKERS: usize = 1;
+
 /// Execution layer configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ExecutionConfig {
@@ -29,6 +32,13 @@ pub struct ExecutionConfig {
     /// Minimum confidence threshold for agent classification (0.0-1.0).
     #[serde(default = "default_confidence_threshold")]
     pub confidence_threshold: f64,
+
+    /// Worker threads executing a block's non-conflicting transactions in parallel.
+    ///
+    /// Values below 2 execute blocks sequentially, the default. Parallel execution
+    /// produces the same outcome as sequential execution.
+    #[serde(default = "default_parallel_workers")]
+    pub parallel_workers: usize,
 }
 
 impl Default for ExecutionConfig {
@@ -38,6 +48,7 @@ impl Default for ExecutionConfig {
             block_time: DEFAULT_BLOCK_TIME,
             enable_agent_pool: false,
             confidence_threshold: DEFAULT_CONFIDENCE_THRESHOLD,
+            parallel_workers: DEFAULT_PARALLEL_WORKERS,
         }
     }
 }
@@ -54,6 +65,10 @@ const fn default_confidence_threshold() -> f64 {
     DEFAULT_CONFIDENCE_THRESHOLD
 }
 
+const fn default_parallel_workers() -> usize {
+    DEFAULT_PARALLEL_WORKERS
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,6 +89,7 @@ mod tests {
             block_time: 5,
             enable_agent_pool: true,
             confidence_threshold: 0.85,
+            parallel_workers: 8,
         };
         let serialized = serde_json::to_string(&config).expect("serialize");
         let deserialized: ExecutionConfig = serde_json::from_str(&serialized).expect("deserialize");
@@ -87,6 +103,7 @@ mod tests {
             block_time: 1,
             enable_agent_pool: true,
             confidence_threshold: 0.9,
+            parallel_workers: 4,
         };
         let serialized = toml::to_string(&config).expect("serialize toml");
         let deserialized: ExecutionConfig = toml::from_str(&serialized).expect("deserialize toml");
@@ -100,6 +117,7 @@ mod tests {
         assert_eq!(config.block_time, DEFAULT_BLOCK_TIME);
         assert!(!config.enable_agent_pool);
         assert!((config.confidence_threshold - DEFAULT_CONFIDENCE_THRESHOLD).abs() < f64::EPSILON);
+        assert_eq!(config.parallel_workers, DEFAULT_PARALLEL_WORKERS);
     }
 
     #[test]
@@ -131,6 +149,7 @@ mod tests {
             block_time: 42,
             enable_agent_pool: true,
             confidence_threshold: 0.5,
+            parallel_workers: DEFAULT_PARALLEL_WORKERS,
         };
         assert_eq!(config, config.clone());
         assert_ne!(config, ExecutionConfig::default());
@@ -0,0 +1,107 @@
+//! Passphrase encryption of the validator key file.
+
+use std::fmt;
+
+use argon2::Argon2;
+use chacha20poly1305::{
+    ChaCha20Poly1305, Key, KeyInit as _, Nonce,
+    aead::{Aead as _, Error as AeadError},
+};
+use serde::Deserialize;
+use zeroize::{Zeroize as _, Zeroizing};
+
+/// Passphrase sealing the validator key file.
+///
+/// Never serialized, redacted in `Debug` output, and wiped from memory on drop.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Passphrase(String);
+
+impl Passphrase {
+    /// The passphrase text.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for Passphrase {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Passphrase {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl fmt::Debug for Passphrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Passphrase(<redacted>)")
+    }
+}
+
+impl Drop for Passphrase {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Header identifying an encrypted key file.
+const MAGIC: &[u8; 8] = b"MONKEY\x00\x01";
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Magic, Argon2 salt, ChaCha20-Poly1305 nonce, then the sealed 32-byte seed.
+const ENCRYPTED_LEN: usize = MAGIC.len() + SALT_LEN + NONCE_LEN + 32 + 16;
+
+/// Whether `bytes` hold an encrypted key rather than a raw seed.
+pub(crate) fn is_encrypted(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+fn cipher(passphrase: &str, salt: &[u8]) -> ChaCha20Poly1305 {
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, key.as_mut_slice())
+        .expect("16-byte salt and 32-byte output are valid Argon2 parameters");
+    ChaCha20Poly1305::new(Key::from_slice(key.as_slice()))
+}
+
+/// Seal `seed` under a key derived from `passphrase`.
+pub(crate) fn encrypt_seed(seed: &[u8; 32], passphrase: &str) -> Vec<u8> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut salt);
+    rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut nonce);
+
+    let sealed = cipher(passphrase, &salt)
+        .encrypt(Nonce::from_slice(&nonce), seed.as_slice())
+        .expect("sealing a 32-byte seed cannot exceed the AEAD length limit");
+
+    let mut out = Vec::with_capacity(ENCRYPTED_LEN);
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&sealed);
+    out
+}
+
+/// Open a key written by [`encrypt_seed`].
+///
+/// Fails if the file is truncated, was tampered with, or `passphrase` is wrong.
+pub(crate) fn decrypt_seed(
+    bytes: &[u8],
+    passphrase: &str,
+) -> Result<Zeroizing<[u8; 32]>, AeadError> {
+    if bytes.len() != ENCRYPTED_LEN || !is_encrypted(bytes) {
+        return Err(AeadError);
+    }
+    let (salt, rest) = bytes[MAGIC.len()..].split_at(SALT_LEN);
+    let (nonce, sealed) = rest.split_at(NONCE_LEN);
+    let seed = Zeroizing::new(cipher(passphrase, salt).decrypt(Nonce::from_slice(nonce), sealed)?);
+    let seed: [u8; 32] = seed.as_slice().try_into().map_err(|_| AeadError)?;
+    Ok(Zeroizing::new(seed))
+}
@@ -5,21 +5,44 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
 mod consensus;
-pub use consensus::{ConsensusConfig, DEFAULT_THRESHOLD};
+pub use consensus::{
+    BlockInterval, ConsensusConfig, DEFAULT_CATCH_UP_THRESHOLD, DEFAULT_EPOCH_LENGTH,
+    DEFAULT_MAX_BLOCK_TIME_MS, DEFAULT_MIN_BLOCK_TIME_MS, DEFAULT_THRESHOLD, KeyScheme,
+    LightCheckpoint, ParticipantKeys,
+};
 
 mod error;
 pub use error::ConfigError;
 
 mod execution;
 pub use execution::{
-    DEFAULT_BLOCK_TIME, DEFAULT_CONFIDENCE_THRESHOLD, DEFAULT_GAS_LIMIT, ExecutionConfig,
+    DEFAULT_BLOCK_TIME, DEFAULT_CONFIDENCE_THRESHOLD, DEFAULT_GAS_LIMIT, DEFAULT_PARALLEL_WORKERS,
+    ExecutionConfig,
 };
 
+mod keystore;
+pub use keystore::Passphrase;
+
+mod mempool;
+pub use mempool::{DEFAULT_MAX_TX_CALLDATA_BYTES, DEFAULT_MAX_TXS_PER_SENDER, MempoolConfig};
+
 mod network;
-pub use network::{DEFAULT_LISTEN_ADDR, NetworkConfig};
+pub use network::{
+    DEFAULT_KEEPALIVE_INTERVAL_MS, DEFAULT_KEEPALIVE_TIMEOUT_MS, DEFAULT_LISTEN_ADDR,
+    KeepaliveSettings, NetworkConfig,
+};
 
 mod node;
-pub use node::{DEFAULT_CHAIN_ID, DEFAULT_DATA_DIR, NodeConfig};
+pub use node::{
+    DEFAULT_CHAIN_ID, DEFAULT_DATA_DIR, ENV_CHAIN_ID, ENV_DATA_DIR, ENV_RPC_PORT,
+    ENV_VALIDATOR_KEY_PASSPHRASE, NodeConfig,
+};
 
 mod rpc;
-pub use rpc::{DEFAULT_HTTP_ADDR, DEFAULT_WS_ADDR, RpcConfig};
+pub use rpc::{
+    DEFAULT_CALL_CACHE_CAPACITY, DEFAULT_EXECUTION_QUEUE_TIMEOUT_MS, DEFAULT_HTTP_ADDR,
+    DEFAULT_MAX_CONCURRENT_EXECUTIONS, DEFAULT_WS_ADDR, RpcConfig,
+};
+
+mod storage;
+pub use storage::{MAX_ARCHIVE_COMPRESSION_LEVEL, StorageConfig};
@@ -0,0 +1,61 @@
+//! Mempool configuration.
+
+use serde::{Deserialize, Serialize};
+
+/// Default maximum number of pooled transactions per sender.
+pub const DEFAULT_MAX_TXS_PER_SENDER: usize = 16;
+
+/// Default maximum calldata (or initcode) size of an admitted transaction, in bytes.
+pub const DEFAULT_MAX_TX_CALLDATA_BYTES: usize = 128 * 1024;
+
+/// Mempool admission configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MempoolConfig {
+    /// Most transactions one sender may pool; replacements of a pooled nonce are exempt.
+    #[serde(default = "default_max_txs_per_sender")]
+    pub max_txs_per_sender: usize,
+
+    /// Largest calldata (or initcode) an admitted transaction may carry, in bytes.
+    ///
+    /// Bounded separately from the envelope size and from gas.
+    #[serde(default = "default_max_tx_calldata_bytes")]
+    pub max_tx_calldata_bytes: usize,
+}
+
+impl Default for MempoolConfig {
+    fn default() -> Self {
+        Self {
+            max_txs_per_sender: DEFAULT_MAX_TXS_PER_SENDER,
+            max_tx_calldata_bytes: DEFAULT_MAX_TX_CALLDATA_BYTES,
+        }
+    }
+}
+
+const fn default_max_txs_per_sender() -> usize {
+    DEFAULT_MAX_TXS_PER_SENDER
+}
+
+const fn default_max_tx_calldata_bytes() -> usize {
+    DEFAULT_MAX_TX_CALLDATA_BYTES
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mempool_config_serde_defaults() {
+        let config: MempoolConfig = serde_json::from_str("{}").expect("deserialize");
+        assert_eq!(config, MempoolConfig::default());
+        assert_eq!(config.max_txs_per_sender, DEFAULT_MAX_TXS_PER_SENDER);
+        assert_eq!(config.max_tx_calldata_bytes, DEFAULT_MAX_TX_CALLDATA_BYTES);
+    }
+
+    #[test]
+    fn test_mempool_config_toml_roundtrip() {
+        let config = MempoolConfig { max_txs_per_sender: 4, max_tx_calldata_bytes: 1024 };
+        let serialized = toml::to_string(&config).expect("serialize toml");
+        let deserialized: MempoolConfig = toml::from_str(&serialized).expect("deserialize toml");
+        assert_eq!(config, deserialized);
+    }
+}
@@ -1,10 +1,29 @@
 //! Network configuration.
 
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
+use crate::ConfigError;
+
 /// Default listen address.
 pub const DEFAULT_LISTEN_ADDR: &str = "0.0.0.0:30303";
 
+/// Default interval between keepalive pings, in milliseconds.
+pub const DEFAULT_KEEPALIVE_INTERVAL_MS: u64 = 15_000;
+
+/// Default time without a pong before a peer is disconnected, in milliseconds.
+pub const DEFAULT_KEEPALIVE_TIMEOUT_MS: u64 = 60_000;
+
+/// Validated keepalive schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeepaliveSettings {
+    /// Time between pings to each connected peer.
+    pub interval: Duration,
+    /// Consecutive unanswered pings after which a peer is disconnected.
+    pub max_missed: u32,
+}
+
 /// Network layer configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct NetworkConfig {
@@ -14,12 +33,23 @@ pub struct NetworkConfig {
 
     /// External address for NAT traversal (if different from listen_addr).
     /// Use this when behind NAT/firewall to specify the publicly reachable address.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Advertised to peers during discovery; may be `IP:PORT` or `HOST:PORT`.
+    /// Also accepted as `advertised_address`.
+    #[serde(default, alias = "advertised_address", skip_serializing_if = "Option::is_none")]
     pub dialable_addr: Option<String>,
 
     /// Bootstrap peers to connect to on startup.
     #[serde(default)]
     pub bootstrap_peers: Vec<String>,
+
+    /// Interval between keepalive pings to each connected peer, in milliseconds.
+    #[serde(default = "default_keepalive_interval_ms")]
+    pub keepalive_interval_ms: u64,
+
+    /// Time a peer may leave pings unanswered before it is disconnected, in
+    /// milliseconds. Must be at least `keepalive_interval_ms`.
+    #[serde(default = "default_keepalive_timeout_ms")]
+    pub keepalive_timeout_ms: u64,
 }
 
 impl Default for NetworkConfig {
@@ -28,14 +58,48 @@ impl Default for NetworkConfig {
             listen_addr: DEFAULT_LISTEN_ADDR.to_string(),
             dialable_addr: None,
             bootstrap_peers: Vec::new(),
+            keepalive_interval_ms: DEFAULT_KEEPALIVE_INTERVAL_MS,
+            keepalive_timeout_ms: DEFAULT_KEEPALIVE_TIMEOUT_MS,
         }
     }
 }
 
+impl NetworkConfig {
+    /// Address peers should dial: `dialable_addr` if set, otherwise `listen_addr`.
+    pub fn advertised_addr(&self) -> &str {
+        self.dialable_addr.as_deref().unwrap_or(&self.listen_addr)
+    }
+
+    /// Validate the configured keepalive schedule.
+    ///
+    /// A peer is disconnected after missing `timeout / interval` consecutive pings.
+    /// Returns [`ConfigError::InvalidKeepalive`] if the interval is zero or exceeds
+    /// the timeout.
+    pub const fn validated_keepalive(&self) -> Result<KeepaliveSettings, ConfigError> {
+        let (interval_ms, timeout_ms) = (self.keepalive_interval_ms, self.keepalive_timeout_ms);
+        if interval_ms == 0 || interval_ms > timeout_ms {
+            return Err(ConfigError::InvalidKeepalive { interval_ms, timeout_ms });
+        }
+        let missed = timeout_ms / interval_ms;
+        Ok(KeepaliveSettings {
+            interval: Duration::from_millis(interval_ms),
+            max_missed: if missed > u32::MAX as u64 { u32::MAX } else { missed as u32 },
+        })
+    }
+}
+
 fn default_listen_addr() -> String {
     DEFAULT_LISTEN_ADDR.to_string()
 }
 
+const fn default_keepalive_interval_ms() -> u64 {
+    DEFAULT_KEEPALIVE_INTERVAL_MS
+}
+
+const fn default_keepalive_timeout_ms() -> u64 {
+    DEFAULT_KEEPALIVE_TIMEOUT_MS
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,6 +118,7 @@ mod tests {
             listen_addr: "127.0.0.1:9000".to_string(),
             dialable_addr: Some("1.2.3.4:9000".to_string()),
             bootstrap_peers: vec!["peer1:30303".to_string()],
+            ..Default::default()
         };
         let serialized = serde_json::to_string(&config).expect("serialize");
         let deserialized: NetworkConfig = serde_json::from_str(&serialized).expect("deserialize");
@@ -66,6 +131,7 @@ mod tests {
             listen_addr: "0.0.0.0:8080".to_string(),
             dialable_addr: None,
             bootstrap_peers: vec!["node1.example.com:30303".to_string()],
+            ..Default::default()
         };
         let serialized = toml::to_string(&config).expect("serialize toml");
         let deserialized: NetworkConfig = toml::from_str(&serialized).expect("deserialize toml");
@@ -103,8 +169,50 @@ mod tests {
             listen_addr: "10.0.0.1:5555".to_string(),
             dialable_addr: Some("external.host:5555".to_string()),
             bootstrap_peers: vec!["a".to_string()],
+            ..Default::default()
         };
         assert_eq!(config, config.clone());
         assert_ne!(config, NetworkConfig::default());
     }
+
+    #[test]
+    fn test_network_config_advertised_address_alias() {
+        let config: NetworkConfig =
+            serde_json::from_str(r#"{"advertised_address":"lb.example.com:30303"}"#)
+                .expect("deserialize");
+        assert_eq!(config.dialable_addr.as_deref(), Some("lb.example.com:30303"));
+        assert_eq!(config.advertised_addr(), "lb.example.com:30303");
+        assert_eq!(NetworkConfig::default().advertised_addr(), DEFAULT_LISTEN_ADDR);
+    }
+
+    #[test]
+    fn test_validated_keepalive_defaults() {
+        let keepalive = NetworkConfig::default().validated_keepalive().expect("valid");
+        assert_eq!(keepalive.interval, Duration::from_millis(DEFAULT_KEEPALIVE_INTERVAL_MS));
+        assert_eq!(keepalive.max_missed, 4);
+    }
+
+    #[test]
+    fn test_validated_keepalive_rejects_bad_schedules() {
+        for (interval, timeout) in [(0, 1_000), (2_000, 1_000)] {
+            let config = NetworkConfig {
+                keepalive_interval_ms: interval,
+                keepalive_timeout_ms: timeout,
+                ..Default::default()
+            };
+            assert!(matches!(
+                config.validated_keepalive(),
+                Err(ConfigError::InvalidKeepalive { interval_ms, timeout_ms })
+                    if interval_ms == interval && timeout_ms == timeout
+            ));
+        }
+    }
+
+    #[test]
+    fn test_keepalive_serde_defaults() {
+        let config: NetworkConfig =
+            toml::from_str("keepalive_interval_ms = 500").expect("deserialize toml");
+        assert_eq!(config.keepalive_interval_ms, 500);
+        assert_eq!(config.keepalive_timeout_ms, DEFAULT_KEEPALIVE_TIMEOUT_MS);
+    }
 }
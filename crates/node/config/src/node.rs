@@ -1,10 +1,18 @@
 //! Top-level node configuration.
 
-use std::path::{Path, PathBuf};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
 
+use alloy_primitives::hex;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
 
-use crate::{ConfigError, ConsensusConfig, ExecutionConfig, NetworkConfig, RpcConfig};
+use crate::{
+    ConfigError, ConsensusConfig, ExecutionConfig, MempoolConfig, NetworkConfig, Passphrase,
+    RpcConfig, StorageConfig, keystore,
+};
 
 /// Default chain ID for Monmouth network.
 pub const DEFAULT_CHAIN_ID: u64 = 7750;
@@ -12,6 +20,18 @@ pub const DEFAULT_CHAIN_ID: u64 = 7750;
 /// Default data directory.
 pub const DEFAULT_DATA_DIR: &str = "/var/lib/monmouth";
 
+/// Environment variable overriding [`NodeConfig::chain_id`].
+pub const ENV_CHAIN_ID: &str = "MONMOUTH_CHAIN_ID";
+
+/// Environment variable overriding [`NodeConfig::data_dir`].
+pub const ENV_DATA_DIR: &str = "MONMOUTH_DATA_DIR";
+
+/// Environment variable overriding the port of [`RpcConfig::http_addr`].
+pub const ENV_RPC_PORT: &str = "MONMOUTH_RPC_PORT";
+
+/// Environment variable overriding [`ConsensusConfig::validator_key_passphrase`].
+pub const ENV_VALIDATOR_KEY_PASSPHRASE: &str = "MONMOUTH_VALIDATOR_KEY_PASSPHRASE";
+
 /// Complete node configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NodeConfig {
@@ -35,9 +55,17 @@ pub struct NodeConfig {
     #[serde(default)]
     pub execution: ExecutionConfig,
 
+    /// Mempool configuration.
+    #[serde(default)]
+    pub mempool: MempoolConfig,
+
     /// RPC configuration.
     #[serde(default)]
     pub rpc: RpcConfig,
+
+    /// Storage configuration.
+    #[serde(default)]
+    pub storage: StorageConfig,
 }
 
 impl Default for NodeConfig {
@@ -48,7 +76,9 @@ impl Default for NodeConfig {
             consensus: ConsensusConfig::default(),
             network: NetworkConfig::default(),
             execution: ExecutionConfig::default(),
+            mempool: MempoolConfig::default(),
             rpc: RpcConfig::default(),
+            storage: StorageConfig::default(),
         }
     }
 }
@@ -57,7 +87,8 @@ impl NodeConfig {
     /// Load configuration from a file path, auto-detecting format by extension.
     ///
     /// If the path is `None`, returns the default configuration.
-    /// Supported extensions: `.json` for JSON, all others default to TOML.
+    /// Supported extensions: `.json` for JSON, `.yaml`/`.yml` for YAML with the
+    /// `yaml` feature, all others default to TOML.
     pub fn load(path: Option<&Path>) -> Result<Self, ConfigError> {
         path.map_or_else(
             || Ok(Self::default()),
@@ -65,12 +96,93 @@ impl NodeConfig {
                 let ext = p.extension().and_then(|e| e.to_str()).unwrap_or("toml");
                 match ext {
                     "json" => Self::from_json_file(p),
+                    #[cfg(feature = "yaml")]
+                    "yaml" | "yml" => Self::from_yaml_file(p),
                     _ => Self::from_toml_file(p),
                 }
             },
         )
     }
 
+    /// Load configuration like [`load`](Self::load), then [`validate`](Self::validate) it.
+    pub fn load_and_validate(path: Option<&Path>) -> Result<Self, ConfigError> {
+        let config = Self::load(path)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check that the configuration is internally consistent.
+    ///
+    /// Rejects a zero chain ID, a consensus threshold above the number of
+    /// participants, duplicate participants, invalid epoch, block-interval or
+    /// keepalive settings, and a data directory that cannot be written. Parsing
+    /// never checks these, so a bad config would otherwise fail deep inside the
+    /// engine after startup.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.chain_id == 0 {
+            return Err(ConfigError::ZeroChainId);
+        }
+
+        let participants = &self.consensus.participants;
+        if self.consensus.threshold as usize > participants.len() {
+            return Err(ConfigError::ThresholdExceedsParticipants {
+                threshold: self.consensus.threshold,
+                participants: participants.len(),
+            });
+        }
+        let mut seen = HashSet::with_capacity(participants.len());
+        if let Some(duplicate) = participants.iter().find(|pk| !seen.insert(pk.as_slice())) {
+            return Err(ConfigError::DuplicateParticipant(hex::encode(duplicate)));
+        }
+
+        self.consensus.validated_epoch_length()?;
+        self.consensus.validated_block_interval()?;
+        self.network.validated_keepalive()?;
+        self.storage.validated_archive_compression()?;
+        check_data_dir_writable(&self.data_dir)
+    }
+
+    /// Override fields from the process environment.
+    ///
+    /// Reads [`ENV_CHAIN_ID`], [`ENV_DATA_DIR`], [`ENV_RPC_PORT`] and
+    /// [`ENV_VALIDATOR_KEY_PASSPHRASE`]; unset
+    /// variables leave their field untouched. Apply after loading the file and
+    /// before command-line flags so that file < environment < CLI.
+    pub fn apply_env_overrides(&mut self) -> Result<(), ConfigError> {
+        self.apply_overrides_from(|var| std::env::var(var).ok())
+    }
+
+    fn apply_overrides_from(
+        &mut self,
+        lookup: impl Fn(&'static str) -> Option<String>,
+    ) -> Result<(), ConfigError> {
+        if let Some(value) = lookup(ENV_CHAIN_ID) {
+            self.chain_id =
+                value.parse().map_err(|e: std::num::ParseIntError| ConfigError::EnvParse {
+                    var: ENV_CHAIN_ID,
+                    value: value.clone(),
+                    reason: e.to_string(),
+                })?;
+        }
+        if let Some(value) = lookup(ENV_DATA_DIR) {
+            self.data_dir = PathBuf::from(value);
+        }
+        if let Some(value) = lookup(ENV_RPC_PORT) {
+            let port: u16 =
+                value.parse().map_err(|e: std::num::ParseIntError| ConfigError::EnvParse {
+                    var: ENV_RPC_PORT,
+                    value: value.clone(),
+                    reason: e.to_string(),
+                })?;
+            let host = self.rpc.http_addr.rsplit_once(':').map_or("", |(host, _)| host);
+            self.rpc.http_addr = format!("{host}:{port}");
+        }
+        if let Some(value) = lookup(ENV_VALIDATOR_KEY_PASSPHRASE) {
+            self.consensus.validator_key_passphrase = Some(value.into());
+        }
+        Ok(())
+    }
+
     /// Load configuration from a TOML file.
     pub fn from_toml_file(path: &Path) -> Result<Self, ConfigError> {
         let contents = std::fs::read_to_string(path)
@@ -95,6 +207,20 @@ impl NodeConfig {
         Ok(serde_json::from_str(s)?)
     }
 
+    /// Load configuration from a YAML file.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml_file(path: &Path) -> Result<Self, ConfigError> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| ConfigError::Read { path: path.into(), source: e })?;
+        Self::from_yaml(&contents)
+    }
+
+    /// Parse configuration from a YAML string.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(s: &str) -> Result<Self, ConfigError> {
+        serde_yaml::from_str(s).map_err(ConfigError::YamlParse)
+    }
+
     /// Serialize configuration to a TOML string.
     pub fn to_toml(&self) -> Result<String, ConfigError> {
         Ok(toml::to_string_pretty(self)?)
@@ -105,7 +231,17 @@ impl NodeConfig {
         Ok(serde_json::to_string_pretty(self)?)
     }
 
+    /// Serialize configuration to a YAML string.
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, ConfigError> {
+        serde_yaml::to_string(self).map_err(ConfigError::YamlSerialize)
+    }
+
     /// Get or create the validator private key from `{data_dir}/validator.key`.
+    ///
+    /// With [`validator_key_passphrase`](ConsensusConfig::validator_key_passphrase)
+    /// set, a new key is written encrypted and an encrypted file is decrypted on
+    /// read. Plaintext key files load with or without a passphrase.
     pub fn validator_key(
         &self,
     ) -> Result<commonware_cryptography::ed25519::PrivateKey, ConfigError> {
@@ -114,23 +250,42 @@ impl NodeConfig {
             .validator_key
             .clone()
             .unwrap_or_else(|| self.data_dir.join("validator.key"));
+        let passphrase = self.consensus.validator_key_passphrase.as_ref().map(Passphrase::expose);
 
         // Try to load existing key
         match std::fs::read(&key_path) {
+            Ok(key_bytes) if keystore::is_encrypted(&key_bytes) => {
+                let Some(passphrase) = passphrase else {
+                    return Err(ConfigError::Decrypt {
+                        path: key_path,
+                        reason: "key is encrypted but no passphrase is configured",
+                    });
+                };
+                let seed = keystore::decrypt_seed(&key_bytes, passphrase).map_err(|_| {
+                    ConfigError::Decrypt {
+                        path: key_path.clone(),
+                        reason: "wrong passphrase or corrupted key file",
+                    }
+                })?;
+                Ok(commonware_cryptography::ed25519::PrivateKey::from(
+                    ed25519_consensus::SigningKey::from(*seed),
+                ))
+            }
             Ok(key_bytes) => {
+                let key_bytes = Zeroizing::new(key_bytes);
                 if key_bytes.len() != 32 {
                     return Err(ConfigError::InvalidKeyLength(key_bytes.len()));
                 }
-                let mut seed = [0u8; 32];
+                let mut seed = Zeroizing::new([0u8; 32]);
                 seed.copy_from_slice(&key_bytes);
                 Ok(commonware_cryptography::ed25519::PrivateKey::from(
-                    ed25519_consensus::SigningKey::from(seed),
+                    ed25519_consensus::SigningKey::from(*seed),
                 ))
             }
             Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
                 // Generate new key
-                let mut seed = [0u8; 32];
-                rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut seed);
+                let mut seed = Zeroizing::new([0u8; 32]);
+                rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, seed.as_mut_slice());
 
                 // Ensure parent directory exists
                 if let Some(parent) = key_path.parent() {
@@ -140,12 +295,16 @@ impl NodeConfig {
                     })?;
                 }
 
-                // Write key to disk
-                std::fs::write(&key_path, seed)
+                // Write key to disk, sealed if a passphrase is configured
+                let contents = Zeroizing::new(passphrase.map_or_else(
+                    || seed.to_vec(),
+                    |passphrase| keystore::encrypt_seed(&seed, passphrase),
+                ));
+                std::fs::write(&key_path, contents.as_slice())
                     .map_err(|e| ConfigError::Write { path: key_path.clone(), source: e })?;
 
                 Ok(commonware_cryptography::ed25519::PrivateKey::from(
-                    ed25519_consensus::SigningKey::from(seed),
+                    ed25519_consensus::SigningKey::from(*seed),
                 ))
             }
             Err(e) => Err(ConfigError::Read { path: key_path, source: e }),
@@ -161,6 +320,25 @@ impl NodeConfig {
     }
 }
 
+/// Check that `data_dir`, or the closest ancestor that exists, is a writable directory.
+fn check_data_dir_writable(data_dir: &Path) -> Result<(), ConfigError> {
+    let not_writable =
+        |reason: String| ConfigError::DataDirNotWritable { path: data_dir.to_path_buf(), reason };
+    let Some(existing) = data_dir.ancestors().find(|dir| dir.exists()) else {
+        // A relative path with no existing ancestor is created under the working directory.
+        return Ok(());
+    };
+    let metadata = std::fs::metadata(existing)
+        .map_err(|e| not_writable(format!("{}: {e}", existing.display())))?;
+    if !metadata.is_dir() {
+        return Err(not_writable(format!("{} is not a directory", existing.display())));
+    }
+    if metadata.permissions().readonly() {
+        return Err(not_writable(format!("{} is read-only", existing.display())));
+    }
+    Ok(())
+}
+
 const fn default_chain_id() -> u64 {
     DEFAULT_CHAIN_ID
 }
@@ -171,6 +349,8 @@ fn default_data_dir() -> PathBuf {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use super::*;
 
     #[test]
@@ -196,6 +376,15 @@ mod tests {
         assert_eq!(config, parsed);
     }
 
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_yaml_roundtrip() {
+        let config = NodeConfig::default();
+        let yaml_str = config.to_yaml().unwrap();
+        let parsed = NodeConfig::from_yaml(&yaml_str).unwrap();
+        assert_eq!(config, parsed);
+    }
+
     #[test]
     fn test_load_none_returns_default() {
         let config = NodeConfig::load(None).unwrap();
@@ -224,6 +413,20 @@ mod tests {
         assert_eq!(loaded.chain_id, 99);
     }
 
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_load_yaml_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let expected = NodeConfig { chain_id: 88, ..Default::default() };
+        for name in ["config.yaml", "config.yml"] {
+            let path = dir.path().join(name);
+            std::fs::write(&path, expected.to_yaml().unwrap()).unwrap();
+
+            let loaded = NodeConfig::load(Some(&path)).unwrap();
+            assert_eq!(loaded, expected);
+        }
+    }
+
     #[test]
     fn test_load_unknown_extension_defaults_to_toml() {
         let dir = tempfile::tempdir().unwrap();
@@ -241,4 +444,187 @@ mod tests {
         let path = dir.path().join("nonexistent.toml");
         assert!(NodeConfig::load(Some(&path)).is_err());
     }
+
+    /// A config that passes validation, rooted in `dir`.
+    fn valid_config(dir: &Path) -> NodeConfig {
+        let mut config = NodeConfig { data_dir: dir.join("data"), ..Default::default() };
+        config.consensus.participants = vec![vec![1; 32], vec![2; 32], vec![3; 32]];
+        config
+    }
+
+    #[test]
+    fn test_validate_accepts_consistent_config() {
+        let dir = tempfile::tempdir().unwrap();
+        valid_config(dir.path()).validate().unwrap();
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_chain_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = NodeConfig { chain_id: 0, ..valid_config(dir.path()) };
+        assert!(matches!(config.validate(), Err(ConfigError::ZeroChainId)));
+    }
+
+    #[test]
+    fn test_validate_rejects_archive_compression_above_max() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = valid_config(dir.path());
+        config.storage.archive_compression = Some(23);
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidArchiveCompression(23))));
+    }
+
+    #[test]
+    fn test_storage_section_toml_roundtrip() {
+        let mut config = NodeConfig::default();
+        config.storage.archive_compression = Some(0);
+        let parsed = NodeConfig::from_toml(&config.to_toml().unwrap()).unwrap();
+        assert_eq!(parsed.storage.archive_compression, Some(0));
+    }
+
+    #[test]
+    fn test_validate_rejects_threshold_above_participants() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = valid_config(dir.path());
+        config.consensus.threshold = 4;
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::ThresholdExceedsParticipants { threshold: 4, participants: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_threshold_without_participants() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = valid_config(dir.path());
+        config.consensus.participants.clear();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::ThresholdExceedsParticipants { threshold: 2, participants: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicate_participants() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = valid_config(dir.path());
+        config.consensus.participants.push(vec![2; 32]);
+        let Err(ConfigError::DuplicateParticipant(pk)) = config.validate() else {
+            panic!("duplicate participant must be rejected");
+        };
+        assert_eq!(pk, hex::encode([2; 32]));
+    }
+
+    #[test]
+    fn test_validate_rejects_read_only_data_dir_parent() {
+        let dir = tempfile::tempdir().unwrap();
+        let parent = dir.path().join("locked");
+        std::fs::create_dir(&parent).unwrap();
+        let mut permissions = std::fs::metadata(&parent).unwrap().permissions();
+        permissions.set_readonly(true);
+        std::fs::set_permissions(&parent, permissions).unwrap();
+
+        let config = valid_config(&parent);
+        assert!(matches!(config.validate(), Err(ConfigError::DataDirNotWritable { .. })));
+    }
+
+    #[test]
+    fn test_validate_rejects_data_dir_under_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("file");
+        std::fs::write(&file, b"").unwrap();
+        let config = valid_config(&file);
+        assert!(matches!(config.validate(), Err(ConfigError::DataDirNotWritable { .. })));
+    }
+
+    #[test]
+    fn test_load_and_validate_rejects_invalid_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let config = NodeConfig { chain_id: 0, ..valid_config(dir.path()) };
+        std::fs::write(&path, config.to_toml().unwrap()).unwrap();
+
+        assert!(NodeConfig::load(Some(&path)).is_ok());
+        assert!(matches!(
+            NodeConfig::load_and_validate(Some(&path)),
+            Err(ConfigError::ZeroChainId)
+        ));
+    }
+
+    #[test]
+    fn test_env_overrides_apply_to_default_config() {
+        let env = HashMap::from([
+            (ENV_CHAIN_ID, "42"),
+            (ENV_DATA_DIR, "/tmp/monmouth-env"),
+            (ENV_RPC_PORT, "9545"),
+        ]);
+        let mut config = NodeConfig::load(None).unwrap();
+        config.apply_overrides_from(|var| env.get(var).map(ToString::to_string)).unwrap();
+
+        assert_eq!(config.chain_id, 42);
+        assert_eq!(config.data_dir, PathBuf::from("/tmp/monmouth-env"));
+        assert_eq!(config.rpc.http_addr, "0.0.0.0:9545");
+        assert_eq!(config.rpc.ws_addr, crate::DEFAULT_WS_ADDR);
+    }
+
+    #[test]
+    fn test_env_overrides_leave_unset_fields() {
+        let mut config = NodeConfig::default();
+        config.apply_overrides_from(|_| None).unwrap();
+        assert_eq!(config, NodeConfig::default());
+    }
+
+    #[test]
+    fn test_env_override_parse_error() {
+        let mut config = NodeConfig::default();
+        let err = config
+            .apply_overrides_from(|var| (var == ENV_RPC_PORT).then(|| "70000".to_string()))
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::EnvParse { var: ENV_RPC_PORT, .. }));
+        assert_eq!(config, NodeConfig::default());
+    }
+
+    fn key_config(dir: &Path, passphrase: Option<&str>) -> NodeConfig {
+        let mut config = NodeConfig { data_dir: dir.to_path_buf(), ..Default::default() };
+        config.consensus.validator_key_passphrase = passphrase.map(Passphrase::from);
+        config
+    }
+
+    #[test]
+    fn test_encrypted_validator_key_roundtrip() {
+        use commonware_cryptography::Signer as _;
+
+        let dir = tempfile::tempdir().unwrap();
+        let config = key_config(dir.path(), Some("correct horse"));
+        let created = config.validator_key().unwrap();
+
+        let on_disk = std::fs::read(dir.path().join("validator.key")).unwrap();
+        assert!(keystore::is_encrypted(&on_disk));
+        assert_ne!(on_disk.len(), 32);
+
+        let loaded = config.validator_key().unwrap();
+        assert_eq!(loaded.public_key(), created.public_key());
+    }
+
+    #[test]
+    fn test_encrypted_validator_key_rejects_wrong_passphrase() {
+        let dir = tempfile::tempdir().unwrap();
+        key_config(dir.path(), Some("correct horse")).validator_key().unwrap();
+
+        let wrong = key_config(dir.path(), Some("battery staple"));
+        assert!(matches!(wrong.validator_key(), Err(ConfigError::Decrypt { .. })));
+        let missing = key_config(dir.path(), None);
+        assert!(matches!(missing.validator_key(), Err(ConfigError::Decrypt { .. })));
+    }
+
+    #[test]
+    fn test_plaintext_validator_key_loads_with_passphrase() {
+        use commonware_cryptography::Signer as _;
+
+        let dir = tempfile::tempdir().unwrap();
+        let created = key_config(dir.path(), None).validator_key().unwrap();
+        assert_eq!(std::fs::read(dir.path().join("validator.key")).unwrap().len(), 32);
+
+        let loaded = key_config(dir.path(), Some("correct horse")).validator_key().unwrap();
+        assert_eq!(loaded.public_key(), created.public_key());
+    }
 }
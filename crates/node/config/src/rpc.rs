@@ -8,6 +8,15 @@ pub const DEFAULT_HTTP_ADDR: &str = "0.0.0.0:8545";
 /// Default WebSocket RPC address.
 pub const DEFAULT_WS_ADDR: &str = "0.0.0.0:8546";
 
+/// Default maximum concurrent read-only EVM executions.
+pub const DEFAULT_MAX_CONCURRENT_EXECUTIONS: usize = 16;
+
+/// Default milliseconds a read-only execution waits for a free slot.
+pub const DEFAULT_EXECUTION_QUEUE_TIMEOUT_MS: u64 = 1_000;
+
+/// Default maximum number of cached `eth_call` results.
+pub const DEFAULT_CALL_CACHE_CAPACITY: usize = 1024;
+
 /// RPC server configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct RpcConfig {
@@ -18,11 +27,51 @@ pub struct RpcConfig {
     /// WebSocket server address.
     #[serde(default = "default_ws_addr")]
     pub ws_addr: String,
+
+    /// JSON-RPC methods to expose, as exact names or `namespace_*` wildcards.
+    ///
+    /// `None` exposes the standard `eth_*`, `net_*`, `web3_*`, and `monmouth_*`
+    /// namespaces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_methods: Option<Vec<String>>,
+
+    /// JSON-RPC methods to hide even if they match `allowed_methods`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub denied_methods: Vec<String>,
+
+    /// Reject requests with more parameters than the method accepts (`-32602`).
+    ///
+    /// Lenient by default: extra parameters are ignored.
+    #[serde(default)]
+    pub strict_params: bool,
+
+    /// Maximum concurrent `eth_call`, `eth_estimateGas`, and `debug_trace*` executions.
+    #[serde(default = "default_max_concurrent_executions")]
+    pub max_concurrent_executions: usize,
+
+    /// Milliseconds an execution waits for a free slot before failing as "server busy".
+    #[serde(default = "default_execution_queue_timeout_ms")]
+    pub execution_queue_timeout_ms: u64,
+
+    /// Most `eth_call` results cached against the latest finalized block.
+    ///
+    /// `0` disables the cache and executes every call.
+    #[serde(default = "default_call_cache_capacity")]
+    pub call_cache_capacity: usize,
 }
 
 impl Default for RpcConfig {
     fn default() -> Self {
-        Self { http_addr: DEFAULT_HTTP_ADDR.to_string(), ws_addr: DEFAULT_WS_ADDR.to_string() }
+        Self {
+            http_addr: DEFAULT_HTTP_ADDR.to_string(),
+            ws_addr: DEFAULT_WS_ADDR.to_string(),
+            allowed_methods: None,
+            denied_methods: Vec::new(),
+            strict_params: false,
+            max_concurrent_executions: DEFAULT_MAX_CONCURRENT_EXECUTIONS,
+            execution_queue_timeout_ms: DEFAULT_EXECUTION_QUEUE_TIMEOUT_MS,
+            call_cache_capacity: DEFAULT_CALL_CACHE_CAPACITY,
+        }
     }
 }
 
@@ -34,6 +83,18 @@ fn default_ws_addr() -> String {
     DEFAULT_WS_ADDR.to_string()
 }
 
+const fn default_max_concurrent_executions() -> usize {
+    DEFAULT_MAX_CONCURRENT_EXECUTIONS
+}
+
+const fn default_execution_queue_timeout_ms() -> u64 {
+    DEFAULT_EXECUTION_QUEUE_TIMEOUT_MS
+}
+
+const fn default_call_cache_capacity() -> usize {
+    DEFAULT_CALL_CACHE_CAPACITY
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,6 +104,10 @@ mod tests {
         let config = RpcConfig::default();
         assert_eq!(config.http_addr, DEFAULT_HTTP_ADDR);
         assert_eq!(config.ws_addr, DEFAULT_WS_ADDR);
+        assert!(config.allowed_methods.is_none());
+        assert!(config.denied_methods.is_empty());
+        assert!(!config.strict_params);
+        assert_eq!(config.call_cache_capacity, DEFAULT_CALL_CACHE_CAPACITY);
     }
 
     #[test]
@@ -50,6 +115,12 @@ mod tests {
         let config = RpcConfig {
             http_addr: "127.0.0.1:8080".to_string(),
             ws_addr: "127.0.0.1:8081".to_string(),
+            allowed_methods: Some(vec!["eth_*".to_string()]),
+            denied_methods: vec!["eth_sendRawTransaction".to_string()],
+            strict_params: true,
+            max_concurrent_executions: 4,
+            execution_queue_timeout_ms: 250,
+            call_cache_capacity: 0,
         };
         let serialized = serde_json::to_string(&config).expect("serialize");
         let deserialized: RpcConfig = serde_json::from_str(&serialized).expect("deserialize");
@@ -61,6 +132,10 @@ mod tests {
         let config = RpcConfig {
             http_addr: "0.0.0.0:9545".to_string(),
             ws_addr: "0.0.0.0:9546".to_string(),
+            allowed_methods: Some(vec!["eth_*".to_string(), "net_version".to_string()]),
+            denied_methods: vec!["eth_sendRawTransaction".to_string()],
+            strict_params: false,
+            ..Default::default()
         };
         let serialized = toml::to_string(&config).expect("serialize toml");
         let deserialized: RpcConfig = toml::from_str(&serialized).expect("deserialize toml");
@@ -72,6 +147,9 @@ mod tests {
         let config: RpcConfig = serde_json::from_str("{}").expect("deserialize");
         assert_eq!(config.http_addr, DEFAULT_HTTP_ADDR);
         assert_eq!(config.ws_addr, DEFAULT_WS_ADDR);
+        assert_eq!(config.max_concurrent_executions, DEFAULT_MAX_CONCURRENT_EXECUTIONS);
+        assert_eq!(config.execution_queue_timeout_ms, DEFAULT_EXECUTION_QUEUE_TIMEOUT_MS);
+        assert_eq!(config.call_cache_capacity, DEFAULT_CALL_CACHE_CAPACITY);
     }
 
     #[test]
@@ -89,8 +167,11 @@ mod tests {
 
     #[test]
     fn test_rpc_config_clone_and_eq() {
-        let config =
-            RpcConfig { http_addr: "custom:1111".to_string(), ws_addr: "custom:2222".to_string() };
+        let config = RpcConfig {
+            http_addr: "custom:1111".to_string(),
+            ws_addr: "custom:2222".to_string(),
+            ..Default::default()
+        };
         assert_eq!(config, config.clone());
         assert_ne!(config, RpcConfig::default());
     }
@@ -0,0 +1,94 @@
+//! Storage configuration.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ConfigError;
+
+/// Highest zstd level accepted for archive compression.
+pub const MAX_ARCHIVE_COMPRESSION_LEVEL: u8 = 22;
+
+/// Storage configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StorageConfig {
+    /// zstd level for the finalized block and certificate archives.
+    ///
+    /// `0` disables compression; unset keeps the archive default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive_compression: Option<u8>,
+
+    /// Persisted ancestors kept in memory below each finalized snapshot.
+    ///
+    /// Older snapshots are pruned on finalization; unset keeps the ledger default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot_retention: Option<usize>,
+}
+
+impl StorageConfig {
+    /// Validate the configured archive compression level.
+    ///
+    /// Returns `None` when unset, `Some(None)` when compression is disabled, and
+    /// `Some(Some(level))` otherwise. Returns
+    /// [`ConfigError::InvalidArchiveCompression`] above
+    /// [`MAX_ARCHIVE_COMPRESSION_LEVEL`].
+    pub const fn validated_archive_compression(&self) -> Result<Option<Option<u8>>, ConfigError> {
+        match self.archive_compression {
+            None => Ok(None),
+            Some(0) => Ok(Some(None)),
+            Some(level) if level <= MAX_ARCHIVE_COMPRESSION_LEVEL => Ok(Some(Some(level))),
+            Some(level) => Err(ConfigError::InvalidArchiveCompression(level)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_storage_config() {
+        let config = StorageConfig::default();
+        assert_eq!(config.archive_compression, None);
+        assert_eq!(config.snapshot_retention, None);
+        assert_eq!(config.validated_archive_compression().unwrap(), None);
+    }
+
+    #[test]
+    fn test_storage_config_serde_roundtrip() {
+        let config = StorageConfig { archive_compression: Some(9), snapshot_retention: Some(8) };
+        let serialized = serde_json::to_string(&config).expect("serialize");
+        let deserialized: StorageConfig = serde_json::from_str(&serialized).expect("deserialize");
+        assert_eq!(config, deserialized);
+    }
+
+    #[test]
+    fn test_storage_config_toml_roundtrip() {
+        let config = StorageConfig { archive_compression: Some(0), ..Default::default() };
+        let serialized = toml::to_string(&config).expect("serialize");
+        let deserialized: StorageConfig = toml::from_str(&serialized).expect("deserialize");
+        assert_eq!(config, deserialized);
+    }
+
+    #[test]
+    fn test_zero_archive_compression_disables_compression() {
+        let config = StorageConfig { archive_compression: Some(0), ..Default::default() };
+        assert_eq!(config.validated_archive_compression().unwrap(), Some(None));
+    }
+
+    #[test]
+    fn test_archive_compression_level_bounds() {
+        let max = StorageConfig {
+            archive_compression: Some(MAX_ARCHIVE_COMPRESSION_LEVEL),
+            ..Default::default()
+        };
+        assert_eq!(
+            max.validated_archive_compression().unwrap(),
+            Some(Some(MAX_ARCHIVE_COMPRESSION_LEVEL))
+        );
+
+        let too_high = StorageConfig { archive_compression: Some(23), ..Default::default() };
+        assert!(matches!(
+            too_high.validated_archive_compression(),
+            Err(ConfigError::InvalidArchiveCompression(23))
+        ));
+    }
+}
@@ -1,23 +1,747 @@
 //! In-memory mempool implementation.
 
-use std::{collections::BTreeMap, sync::Arc};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, VecDeque},
+    sync::Arc,
+};
 
-use monmouth_domain::Tx;
+use alloy_consensus::{Transaction as _, TxEnvelope, transaction::SignerRecoverable as _};
+use alloy_eips::eip2718::Decodable2718;
+use alloy_primitives::{Address, U256};
+use monmouth_domain::{SenderCache, Tx};
+use monmouth_traits::StateDbRead;
+use monmouth_txpool::{ADMISSION_PIPELINE, PoolConfig, TxPoolError};
 use parking_lot::RwLock;
 
-use crate::traits::{Mempool, TxId};
+use crate::{
+    error::MempoolError,
+    traits::{Mempool, TxId},
+};
+
+/// Default minimum fee increase, in percent, for a same-nonce replacement.
+pub const DEFAULT_MIN_REPLACEMENT_BUMP_PERCENT: u64 = 10;
+
+/// Default maximum number of pooled transactions.
+pub const DEFAULT_MAX_TRANSACTIONS: usize = 10_000;
+
+/// Default seconds a ready transaction waits between reannouncements.
+pub const DEFAULT_REANNOUNCE_INTERVAL_SECS: u64 = 30;
+
+/// Default reannouncements made before a stranded transaction is dropped.
+pub const DEFAULT_MAX_REANNOUNCE_ATTEMPTS: u32 = 5;
+
+/// Default maximum number of transactions announced per round.
+pub const DEFAULT_MAX_REANNOUNCE_BATCH: usize = 64;
+
+/// Pooled transactions of each sender keyed by nonce.
+pub type SenderTransactions = BTreeMap<Address, BTreeMap<u64, Tx>>;
+
+/// Snapshot of a mempool's transactions, as returned by [`InMemoryMempool::content`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MempoolContent {
+    /// Transactions that can execute now.
+    pub ready: SenderTransactions,
+    /// Transactions parked behind a sender nonce gap.
+    pub parked: SenderTransactions,
+}
+
+/// How [`InMemoryMempool::reannounce_due`] re-broadcasts un-included transactions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReannounceConfig {
+    /// Seconds a ready transaction waits between announcements.
+    pub interval_secs: u64,
+    /// Reannouncements made before a stranded transaction is dropped.
+    pub max_attempts: u32,
+    /// Maximum number of transactions announced per round.
+    pub max_batch: usize,
+}
+
+impl Default for ReannounceConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: DEFAULT_REANNOUNCE_INTERVAL_SECS,
+            max_attempts: DEFAULT_MAX_REANNOUNCE_ATTEMPTS,
+            max_batch: DEFAULT_MAX_REANNOUNCE_BATCH,
+        }
+    }
+}
+
+impl ReannounceConfig {
+    /// Set the seconds a ready transaction waits between announcements.
+    #[must_use]
+    pub const fn with_interval_secs(mut self, secs: u64) -> Self {
+        self.interval_secs = secs;
+        self
+    }
+
+    /// Set the reannouncements made before a stranded transaction is dropped.
+    #[must_use]
+    pub const fn with_max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// Set the maximum number of transactions announced per round.
+    #[must_use]
+    pub const fn with_max_batch(mut self, max: usize) -> Self {
+        self.max_batch = max;
+        self
+    }
+}
+
+/// Order in which [`InMemoryMempool`] hands out transactions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MempoolOrdering {
+    /// Ascending transaction ID.
+    #[default]
+    Id,
+    /// Descending effective tip, then arrival order.
+    Priority,
+}
+
+/// How [`InMemoryMempool`] shares a block between fee ranking and transaction age.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InclusionPolicy {
+    /// Every slot follows the mempool ordering.
+    #[default]
+    FeeOnly,
+    /// Reserve `reserved_percent` of each block's transaction slots, rounded up,
+    /// for the oldest ready transactions regardless of fee.
+    Fair {
+        /// Share of the slots reserved for the oldest transactions, capped at 100.
+        reserved_percent: u8,
+    },
+}
+
+impl InclusionPolicy {
+    /// Number of the `max_txs` slots reserved for the oldest transactions.
+    fn reserved_slots(self, max_txs: usize) -> usize {
+        match self {
+            Self::FeeOnly => 0,
+            Self::Fair { reserved_percent } => {
+                let percent = u128::from(reserved_percent.min(100));
+                let reserved = (max_txs as u128 * percent).div_ceil(100);
+                usize::try_from(reserved).unwrap_or(max_txs)
+            }
+        }
+    }
+}
+
+/// Fee caps decoded from a transaction envelope.
+#[derive(Clone, Copy, Debug)]
+struct FeeCaps {
+    /// Maximum fee per gas, or the gas price of a legacy transaction.
+    max_fee_per_gas: u128,
+    /// Maximum priority fee per gas; `None` for legacy transactions.
+    max_priority_fee_per_gas: Option<u128>,
+}
+
+impl FeeCaps {
+    /// Read the fee caps of `envelope`.
+    fn of(envelope: &TxEnvelope) -> Self {
+        Self {
+            max_fee_per_gas: envelope.max_fee_per_gas(),
+            max_priority_fee_per_gas: envelope.max_priority_fee_per_gas(),
+        }
+    }
+
+    /// Whether these caps raise both the fee cap and the tip of `pooled` by at
+    /// least `percent`. Legacy transactions tip their whole gas price.
+    fn bumps(self, pooled: Self, percent: u64) -> bool {
+        let bumped = |new: u128, old: u128| {
+            let required = old.saturating_add((old * u128::from(percent)).div_ceil(100));
+            new >= required
+        };
+        bumped(self.max_fee_per_gas, pooled.max_fee_per_gas)
+            && bumped(self.tip_cap(), pooled.tip_cap())
+    }
+
+    fn tip_cap(self) -> u128 {
+        self.max_priority_fee_per_gas.unwrap_or(self.max_fee_per_gas)
+    }
+
+    /// Tip paid per gas at `base_fee`, or `None` if the fee cap is below it.
+    fn effective_tip(self, base_fee: u64) -> Option<u128> {
+        let headroom = self.max_fee_per_gas.checked_sub(u128::from(base_fee))?;
+        Some(self.max_priority_fee_per_gas.map_or(headroom, |tip| tip.min(headroom)))
+    }
+}
+
+/// Fields decoded from a transaction envelope at insertion.
+#[derive(Clone, Copy, Debug)]
+struct Decoded {
+    sender: Address,
+    nonce: u64,
+    fees: FeeCaps,
+    gas_limit: u64,
+    /// Most the transaction can charge its sender: gas limit times fee cap, plus value.
+    cost: U256,
+}
+
+impl Decoded {
+    /// Decode `tx`, or `None` if it is not a valid signed envelope.
+    ///
+    /// The sender is looked up in, or recovered into, `sender_cache`.
+    fn decode(tx: &Tx, sender_cache: &SenderCache) -> Option<Self> {
+        let envelope = TxEnvelope::decode_2718(&mut tx.bytes.as_ref()).ok()?;
+        Self::from_envelope(&envelope, sender_cache)
+    }
+
+    /// Read `envelope`, or `None` if its signature does not recover.
+    fn from_envelope(envelope: &TxEnvelope, sender_cache: &SenderCache) -> Option<Self> {
+        let sender =
+            sender_cache.get_or_recover(*envelope.tx_hash(), || envelope.recover_signer()).ok()?;
+        let cost = U256::from(envelope.gas_limit())
+            .saturating_mul(U256::from(envelope.max_fee_per_gas()))
+            .saturating_add(envelope.value());
+        Some(Self {
+            sender,
+            nonce: envelope.nonce(),
+            fees: FeeCaps::of(envelope),
+            gas_limit: envelope.gas_limit(),
+            cost,
+        })
+    }
+}
+
+/// Broadcast bookkeeping for a pooled transaction.
+#[derive(Clone, Copy, Debug)]
+struct Announcement {
+    /// Unix time, in seconds, of the last announcement.
+    last: u64,
+    /// Reannouncements made after the first announcement.
+    attempts: u32,
+}
+
+/// A pooled transaction.
+#[derive(Debug)]
+struct Entry {
+    tx: Tx,
+    /// Insertion sequence number.
+    arrival: u64,
+    /// Sender, nonce and fees, if the transaction decodes.
+    decoded: Option<Decoded>,
+    /// Last broadcast, once the transaction has been announced.
+    announcement: Option<Announcement>,
+}
+
+/// Eviction rank of a transaction: the lowest rank is evicted first.
+type Rank = (Option<u128>, Reverse<u64>);
+
+/// Eviction rank of a transaction at `base_fee`.
+///
+/// Transactions rank by effective tip at `base_fee`, with undecodable and
+/// underpriced ones lowest; among equal tips the later arrival ranks lower.
+fn eviction_rank(decoded: Option<Decoded>, arrival: u64, base_fee: u64) -> Rank {
+    (decoded.and_then(|decoded| decoded.fees.effective_tip(base_fee)), Reverse(arrival))
+}
+
+/// Buffered transactions of one sender.
+#[derive(Debug, Default)]
+struct SenderQueue {
+    /// Next nonce the sender can execute, once seeded from state.
+    next_nonce: Option<u64>,
+    /// Buffered transactions by nonce.
+    nonces: BTreeMap<u64, TxId>,
+}
+
+impl SenderQueue {
+    /// Nonces that can execute now: the contiguous run from `next_nonce`, or
+    /// every buffered nonce if the sender was never seeded.
+    fn ready(&self) -> impl Iterator<Item = u64> + '_ {
+        let start = self.next_nonce.unwrap_or(0);
+        let mut expected = self.next_nonce;
+        self.nonces.keys().copied().filter(move |nonce| *nonce >= start).take_while(move |nonce| {
+            match expected {
+                Some(next) if *nonce == next => {
+                    expected = Some(next + 1);
+                    true
+                }
+                Some(_) => false,
+                None => true,
+            }
+        })
+    }
+
+    /// First missing nonce that keeps buffered transactions parked.
+    fn gap(&self) -> Option<u64> {
+        let next = self.next_nonce?;
+        let ready = self.ready().count() as u64;
+        let first_missing = next + ready;
+        self.nonces.range(first_missing..).next().map(|_| first_missing)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Pool {
+    entries: BTreeMap<TxId, Entry>,
+    senders: HashMap<Address, SenderQueue>,
+    /// Pooled transactions ordered by eviction rank at `base_fee`.
+    by_rank: BTreeSet<(Rank, TxId)>,
+    /// Base fee that priority ordering and eviction measure tips against.
+    base_fee: u64,
+    next_arrival: u64,
+}
+
+impl Pool {
+    /// Nonces each sender can execute now.
+    fn ready_nonces(&self) -> HashMap<Address, BTreeSet<u64>> {
+        self.senders.iter().map(|(sender, queue)| (*sender, queue.ready().collect())).collect()
+    }
+
+    fn rank(&self, entry: &Entry) -> Rank {
+        eviction_rank(entry.decoded, entry.arrival, self.base_fee)
+    }
+
+    /// Re-rank every pooled transaction if `base_fee` changed.
+    fn set_base_fee(&mut self, base_fee: u64) {
+        if self.base_fee == base_fee {
+            return;
+        }
+        self.base_fee = base_fee;
+        self.by_rank = self.entries.iter().map(|(id, entry)| (self.rank(entry), *id)).collect();
+    }
+
+    /// The pooled transaction to evict first, with its rank.
+    fn eviction_candidate(&self) -> Option<(Rank, TxId)> {
+        self.by_rank.first().copied()
+    }
+
+    /// `id` followed by its sender's later pooled nonces, which cannot execute without it.
+    fn dependent_chain(&self, id: &TxId) -> Vec<TxId> {
+        let decoded = self.entries.get(id).and_then(|entry| entry.decoded);
+        match decoded.and_then(|decoded| Some((decoded, self.senders.get(&decoded.sender)?))) {
+            Some((decoded, queue)) => {
+                queue.nonces.range(decoded.nonce..).map(|(_, id)| *id).collect()
+            }
+            None => vec![*id],
+        }
+    }
+
+    fn insert(&mut self, id: TxId, entry: Entry) {
+        self.by_rank.insert((self.rank(&entry), id));
+        self.entries.insert(id, entry);
+    }
+
+    fn remove(&mut self, id: &TxId) -> Option<Entry> {
+        let entry = self.entries.remove(id)?;
+        self.by_rank.remove(&(self.rank(&entry), *id));
+        if let Some(decoded) = entry.decoded
+            && let Some(queue) = self.senders.get_mut(&decoded.sender)
+        {
+            if queue.nonces.get(&decoded.nonce) == Some(id) {
+                queue.nonces.remove(&decoded.nonce);
+            }
+            if queue.next_nonce.is_none() && queue.nonces.is_empty() {
+                self.senders.remove(&decoded.sender);
+            }
+        }
+        Some(entry)
+    }
+
+    /// Raise the sender's next nonce to `nonce` and drop transactions below it.
+    fn advance(&mut self, sender: Address, nonce: u64) {
+        let queue = self.senders.entry(sender).or_default();
+        if queue.next_nonce.is_some_and(|next| next >= nonce) {
+            return;
+        }
+        queue.next_nonce = Some(nonce);
+        let stale: Vec<TxId> = queue.nonces.range(..nonce).map(|(_, id)| *id).collect();
+        for id in stale {
+            self.remove(&id);
+        }
+    }
+}
+
+/// Whether `entry` can execute given each sender's ready nonces.
+fn is_ready(entry: &Entry, ready: &HashMap<Address, BTreeSet<u64>>) -> bool {
+    entry.decoded.is_none_or(|decoded| {
+        ready.get(&decoded.sender).is_some_and(|nonces| nonces.contains(&decoded.nonce))
+    })
+}
 
 /// Simple in-memory mempool backed by a BTreeMap.
+///
+/// Transactions are built in ID order by default. A [`priority`](Self::priority)
+/// mempool instead returns the highest effective tip first, where the tip is
+/// `min(max_priority_fee_per_gas, max_fee_per_gas - base_fee)` against the base
+/// fee last passed to [`set_base_fee`](Self::set_base_fee). Ties go to the
+/// earlier arrival; undecodable or underpriced transactions come last.
+///
+/// Once a sender's next nonce is known, from [`insert_from_state`](Self::insert_from_state)
+/// or [`set_next_nonce`](Self::set_next_nonce), only its transactions contiguous
+/// with that nonce are built; later ones stay parked until the gap fills. Either
+/// way a sender's transactions are built in nonce order. A transaction reusing a
+/// buffered nonce replaces the earlier one only if it raises both fee caps by at
+/// least [`min_replacement_bump_percent`](Self::min_replacement_bump_percent).
+///
+/// The pool holds at most [`max_transactions`](Self::max_transactions). When it is
+/// full, a new transaction evicts the one with the lowest effective tip, together
+/// with its sender's later nonces that could no longer execute, or is rejected if
+/// it would itself rank lowest. Each sender may pool at most the
+/// admission config's [`max_txs_per_sender`](PoolConfig::max_txs_per_sender);
+/// replacements of a pooled nonce are exempt.
+///
+/// Under [`InclusionPolicy::Fair`] a share of each block's slots goes to the
+/// oldest ready transactions first and the rest follow the ordering, so a
+/// low-fee transaction cannot be starved by a steady stream of better payers.
+///
+/// [`reannounce_due`](Self::reannounce_due) hands out ready transactions for
+/// periodic re-broadcast and drops those that are never included.
 #[derive(Debug, Clone)]
 pub struct InMemoryMempool {
-    inner: Arc<RwLock<BTreeMap<TxId, Tx>>>,
+    inner: Arc<RwLock<Pool>>,
+    ordering: MempoolOrdering,
+    inclusion_policy: InclusionPolicy,
+    min_replacement_bump_percent: u64,
+    max_transactions: usize,
+    sender_cache: SenderCache,
+    chain_id: Option<u64>,
+    admission: PoolConfig,
 }
 
 impl InMemoryMempool {
     /// Create a new empty mempool.
     #[must_use]
     pub fn new() -> Self {
-        Self { inner: Arc::new(RwLock::new(BTreeMap::new())) }
+        Self::with_ordering(MempoolOrdering::Id)
+    }
+
+    /// Create a new empty mempool that builds the highest-tipping transactions first.
+    #[must_use]
+    pub fn priority() -> Self {
+        Self::with_ordering(MempoolOrdering::Priority)
+    }
+
+    /// Create a new empty mempool with the given ordering.
+    #[must_use]
+    pub fn with_ordering(ordering: MempoolOrdering) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Pool::default())),
+            ordering,
+            inclusion_policy: InclusionPolicy::FeeOnly,
+            min_replacement_bump_percent: DEFAULT_MIN_REPLACEMENT_BUMP_PERCENT,
+            max_transactions: DEFAULT_MAX_TRANSACTIONS,
+            sender_cache: SenderCache::default(),
+            chain_id: None,
+            admission: PoolConfig::default(),
+        }
+    }
+
+    /// Admit only transactions for `chain_id` that pass the stateless checks of
+    /// [`ADMISSION_PIPELINE`] under `config`.
+    ///
+    /// Without it, transactions of any chain are admitted under the default
+    /// [`PoolConfig`].
+    #[must_use]
+    pub fn with_admission(mut self, chain_id: u64, config: PoolConfig) -> Self {
+        self.chain_id = Some(chain_id);
+        self.admission = config;
+        self
+    }
+
+    /// Share `cache` for senders recovered at admission.
+    #[must_use]
+    pub fn with_sender_cache(mut self, cache: SenderCache) -> Self {
+        self.sender_cache = cache;
+        self
+    }
+
+    /// The cache senders recovered at admission are kept in.
+    ///
+    /// Hand a clone to the executor so included transactions skip recovery.
+    pub const fn sender_cache(&self) -> &SenderCache {
+        &self.sender_cache
+    }
+
+    /// Set the maximum number of pooled transactions.
+    #[must_use]
+    pub const fn with_max_transactions(mut self, max_transactions: usize) -> Self {
+        self.max_transactions = max_transactions;
+        self
+    }
+
+    /// The maximum number of pooled transactions.
+    pub const fn max_transactions(&self) -> usize {
+        self.max_transactions
+    }
+
+    /// Whether the pool holds [`max_transactions`](Self::max_transactions).
+    pub fn is_full(&self) -> bool {
+        self.inner.read().entries.len() >= self.max_transactions
+    }
+
+    /// Set the minimum fee increase, in percent, a same-nonce replacement must offer.
+    #[must_use]
+    pub const fn with_min_replacement_bump_percent(mut self, percent: u64) -> Self {
+        self.min_replacement_bump_percent = percent;
+        self
+    }
+
+    /// The minimum fee increase, in percent, a same-nonce replacement must offer.
+    pub const fn min_replacement_bump_percent(&self) -> u64 {
+        self.min_replacement_bump_percent
+    }
+
+    /// The ordering used when building.
+    pub const fn ordering(&self) -> MempoolOrdering {
+        self.ordering
+    }
+
+    /// Set how block slots are shared between the ordering and transaction age.
+    #[must_use]
+    pub const fn with_inclusion_policy(mut self, policy: InclusionPolicy) -> Self {
+        self.inclusion_policy = policy;
+        self
+    }
+
+    /// The inclusion policy used when building.
+    pub const fn inclusion_policy(&self) -> InclusionPolicy {
+        self.inclusion_policy
+    }
+
+    /// Set the base fee that priority ordering and eviction measure tips against.
+    pub fn set_base_fee(&self, base_fee: u64) {
+        self.inner.write().set_base_fee(base_fee);
+    }
+
+    /// Record that `sender` can next execute `nonce`.
+    ///
+    /// Buffered transactions below `nonce` are dropped. A lower nonce than the one
+    /// already recorded is ignored.
+    pub fn set_next_nonce(&self, sender: Address, nonce: u64) {
+        self.inner.write().advance(sender, nonce);
+    }
+
+    /// Insert `tx`, reporting why it was refused.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MempoolError::AlreadyKnown`] for a pooled transaction,
+    /// [`MempoolError::NonceTooLow`] for a nonce below the sender's next nonce,
+    /// [`MempoolError::ReplacementUnderpriced`] for a same-nonce replacement that
+    /// does not bump the fees enough, [`MempoolError::SenderFull`] if the sender
+    /// already pools its limit of transactions and `tx` replaces none of them, and
+    /// [`MempoolError::PoolFull`] if the pool is full and `tx` pays no more than
+    /// the cheapest pooled transaction.
+    pub fn try_insert(&self, tx: Tx) -> Result<(), MempoolError> {
+        let decoded = Decoded::decode(&tx, &self.sender_cache);
+        self.insert_decoded(tx, decoded)
+    }
+
+    /// Insert `tx` after checking it against its sender's account in `state`.
+    ///
+    /// The transaction must pass the stateless checks of [`ADMISSION_PIPELINE`]:
+    /// calldata size, chain ID, fee floor and intrinsic gas. The sender's next
+    /// nonce is then refreshed from `state`, and the sender must hold enough
+    /// balance to pay the transaction's gas limit at its fee cap plus its value.
+    /// This is the admission path for transactions from RPC and gossip.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MempoolError::Undecodable`] if `tx` is not a signed envelope,
+    /// [`MempoolError::Invalid`] if a stateless check fails,
+    /// [`MempoolError::StateDb`] if the sender's account cannot be read,
+    /// [`MempoolError::InsufficientFunds`] if the sender cannot pay for `tx`, or
+    /// any error of [`try_insert`](Self::try_insert).
+    pub async fn insert_from_state<S: StateDbRead>(
+        &self,
+        state: &S,
+        tx: Tx,
+    ) -> Result<(), MempoolError> {
+        self.admit(state, tx, None).await
+    }
+
+    /// Like [`insert_from_state`](Self::insert_from_state) for a transaction whose
+    /// signer the caller already recovered, sparing a second recovery.
+    ///
+    /// `sender` is trusted: it is cached as the signer of `tx` and handed to the
+    /// executor, so it must come from recovering `tx`'s signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error of [`insert_from_state`](Self::insert_from_state).
+    pub async fn insert_recovered<S: StateDbRead>(
+        &self,
+        state: &S,
+        tx: Tx,
+        sender: Address,
+    ) -> Result<(), MempoolError> {
+        self.admit(state, tx, Some(sender)).await
+    }
+
+    async fn admit<S: StateDbRead>(
+        &self,
+        state: &S,
+        tx: Tx,
+        sender: Option<Address>,
+    ) -> Result<(), MempoolError> {
+        if tx.bytes.len() > self.admission.max_tx_size {
+            return Err(TxPoolError::TxTooLarge {
+                size: tx.bytes.len(),
+                max: self.admission.max_tx_size,
+            }
+            .into());
+        }
+        let envelope = TxEnvelope::decode_2718(&mut tx.bytes.as_ref())
+            .map_err(|_| MempoolError::Undecodable)?;
+        let chain_id = self.chain_id.or(envelope.chain_id()).unwrap_or_default();
+        for stage in ADMISSION_PIPELINE {
+            stage.check(&envelope, chain_id, &self.admission)?;
+        }
+        if let Some(sender) = sender {
+            self.sender_cache.insert(*envelope.tx_hash(), sender);
+        }
+        let decoded = Decoded::from_envelope(&envelope, &self.sender_cache)
+            .ok_or(MempoolError::Undecodable)?;
+        let nonce = state.nonce(&decoded.sender).await?;
+        self.set_next_nonce(decoded.sender, nonce);
+        let balance = state.balance(&decoded.sender).await?;
+        if balance < decoded.cost {
+            return Err(MempoolError::InsufficientFunds { cost: decoded.cost, balance });
+        }
+        self.insert_decoded(tx, Some(decoded))
+    }
+
+    /// Pooled transactions split into ready and parked ones, by sender and nonce.
+    ///
+    /// Transactions that do not decode have no sender and are left out.
+    pub fn content(&self) -> MempoolContent {
+        let inner = self.inner.read();
+        let ready = inner.ready_nonces();
+        let mut content = MempoolContent::default();
+        for entry in inner.entries.values() {
+            let Some(decoded) = entry.decoded else { continue };
+            let section =
+                if is_ready(entry, &ready) { &mut content.ready } else { &mut content.parked };
+            section.entry(decoded.sender).or_default().insert(decoded.nonce, entry.tx.clone());
+        }
+        content
+    }
+
+    /// First missing nonce holding back `sender`'s buffered transactions.
+    ///
+    /// Returns `None` if the sender's next nonce is unknown or none of its
+    /// transactions are parked.
+    pub fn pending_gap(&self, sender: &Address) -> Option<u64> {
+        self.inner.read().senders.get(sender).and_then(SenderQueue::gap)
+    }
+
+    /// Ready transactions due for broadcast under `config` at unix time `now`,
+    /// in seconds, oldest first.
+    ///
+    /// A transaction is due on the first call after it was pooled, then again
+    /// each time [`interval_secs`](ReannounceConfig::interval_secs) have passed
+    /// since it was last announced, so peers that restarted and lost it see it
+    /// again. At most [`max_batch`](ReannounceConfig::max_batch) transactions
+    /// are returned per call. A due transaction already reannounced
+    /// [`max_attempts`](ReannounceConfig::max_attempts) times is dropped
+    /// instead. Transactions parked behind a nonce gap are not announced.
+    pub fn reannounce_due(&self, now: u64, config: ReannounceConfig) -> Vec<Tx> {
+        let mut inner = self.inner.write();
+        let ready = inner.ready_nonces();
+        let mut candidates: Vec<(u64, TxId)> = inner
+            .entries
+            .iter()
+            .filter(|(_, entry)| {
+                is_ready(entry, &ready)
+                    && entry.announcement.is_none_or(|announcement| {
+                        now.saturating_sub(announcement.last) >= config.interval_secs
+                    })
+            })
+            .map(|(id, entry)| (entry.arrival, *id))
+            .collect();
+        candidates.sort_unstable();
+
+        let mut due = Vec::new();
+        let mut exhausted = Vec::new();
+        for (_, id) in candidates {
+            if due.len() >= config.max_batch {
+                break;
+            }
+            let entry = inner.entries.get_mut(&id).expect("candidate is pooled");
+            match &mut entry.announcement {
+                Some(announcement) if announcement.attempts >= config.max_attempts => {
+                    exhausted.push(id);
+                    continue;
+                }
+                Some(announcement) => {
+                    announcement.last = now;
+                    announcement.attempts += 1;
+                }
+                None => entry.announcement = Some(Announcement { last: now, attempts: 0 }),
+            }
+            due.push(entry.tx.clone());
+        }
+        for id in exhausted {
+            inner.remove(&id);
+        }
+        due
+    }
+
+    fn insert_decoded(&self, tx: Tx, decoded: Option<Decoded>) -> Result<(), MempoolError> {
+        let id = tx.id();
+        let mut inner = self.inner.write();
+        if inner.entries.contains_key(&id) {
+            return Err(MempoolError::AlreadyKnown);
+        }
+        let mut replaced = None;
+        if let Some(decoded) = decoded {
+            let queue = inner.senders.get(&decoded.sender);
+            if let Some(next) = queue.and_then(|queue| queue.next_nonce)
+                && decoded.nonce < next
+            {
+                return Err(MempoolError::NonceTooLow { nonce: decoded.nonce, next });
+            }
+            let pooled = queue.and_then(|queue| queue.nonces.get(&decoded.nonce)).copied();
+            if let Some(pooled) = pooled {
+                let pooled_fees = inner.entries[&pooled].decoded.map(|pooled| pooled.fees);
+                if pooled_fees.is_some_and(|fees| {
+                    !decoded.fees.bumps(fees, self.min_replacement_bump_percent)
+                }) {
+                    return Err(MempoolError::ReplacementUnderpriced {
+                        nonce: decoded.nonce,
+                        min_bump_percent: self.min_replacement_bump_percent,
+                    });
+                }
+                replaced = Some(pooled);
+            }
+            let max = self.admission.max_txs_per_sender;
+            if replaced.is_none() && queue.is_some_and(|queue| queue.nonces.len() >= max) {
+                return Err(MempoolError::SenderFull { sender: decoded.sender, max });
+            }
+        }
+        // A replacement frees its own slot; anything else must outrank the cheapest entry.
+        if replaced.is_none() && inner.entries.len() >= self.max_transactions {
+            let rank = eviction_rank(decoded, inner.next_arrival, inner.base_fee);
+            let Some((_, victim)) =
+                inner.eviction_candidate().filter(|(victim_rank, _)| *victim_rank < rank)
+            else {
+                return Err(MempoolError::PoolFull);
+            };
+            // Evicting an earlier nonce of the same sender would park `tx` behind the gap.
+            let victim_decoded = inner.entries[&victim].decoded;
+            if let (Some(decoded), Some(victim_decoded)) = (decoded, victim_decoded)
+                && decoded.sender == victim_decoded.sender
+                && decoded.nonce > victim_decoded.nonce
+            {
+                return Err(MempoolError::PoolFull);
+            }
+            for id in inner.dependent_chain(&victim) {
+                inner.remove(&id);
+            }
+        }
+        if let Some(pooled) = replaced {
+            inner.remove(&pooled);
+        }
+        if let Some(decoded) = decoded {
+            inner.senders.entry(decoded.sender).or_default().nonces.insert(decoded.nonce, id);
+        }
+        let arrival = inner.next_arrival;
+        inner.next_arrival += 1;
+        inner.insert(id, Entry { tx, arrival, decoded, announcement: None });
+        Ok(())
     }
 }
 
@@ -29,22 +753,111 @@ impl Default for InMemoryMempool {
 
 impl Mempool for InMemoryMempool {
     fn insert(&self, tx: Tx) -> bool {
-        let id = tx.id();
-        let mut inner = self.inner.write();
-        inner.insert(id, tx).is_none()
+        self.try_insert(tx).is_ok()
     }
 
-    fn build(&self, max_txs: usize, excluded: &std::collections::BTreeSet<TxId>) -> Vec<Tx> {
+    fn build(&self, max_txs: usize, excluded: &BTreeSet<TxId>) -> Vec<Tx> {
         let inner = self.inner.read();
-        inner
+        let ready = inner.ready_nonces();
+        let mut candidates: Vec<&Entry> = inner
+            .entries
             .iter()
-            .filter(|(id, _)| !excluded.contains(id))
+            .filter(|(id, entry)| !excluded.contains(id) && is_ready(entry, &ready))
+            .map(|(_, entry)| entry)
+            .collect();
+
+        if self.ordering == MempoolOrdering::Priority {
+            let base_fee = inner.base_fee;
+            candidates.sort_by_key(|entry| {
+                let tip = entry.decoded.and_then(|decoded| decoded.fees.effective_tip(base_fee));
+                (Reverse(tip), entry.arrival)
+            });
+        }
+
+        // Move the oldest transactions ahead of the ranking to claim the reserved slots.
+        let reserved = self.inclusion_policy.reserved_slots(max_txs).min(candidates.len());
+        if reserved > 0 {
+            let mut by_age = candidates.clone();
+            by_age.sort_by_key(|entry| entry.arrival);
+            by_age.truncate(reserved);
+            let oldest: BTreeSet<u64> = by_age.iter().map(|entry| entry.arrival).collect();
+            by_age.extend(candidates.into_iter().filter(|entry| !oldest.contains(&entry.arrival)));
+            candidates = by_age;
+        }
+
+        // Keep each sender's positions in the ranking but fill them in nonce order.
+        let mut by_sender: HashMap<Address, Vec<&Entry>> = HashMap::new();
+        for entry in &candidates {
+            if let Some(decoded) = entry.decoded {
+                by_sender.entry(decoded.sender).or_default().push(*entry);
+            }
+        }
+        for entries in by_sender.values_mut() {
+            entries.sort_by_key(|entry| Reverse(entry.decoded.map(|decoded| decoded.nonce)));
+        }
+        candidates
+            .into_iter()
+            .map(|entry| match entry.decoded {
+                Some(decoded) => by_sender
+                    .get_mut(&decoded.sender)
+                    .and_then(Vec::pop)
+                    .expect("one slot per sender entry"),
+                None => entry,
+            })
             .take(max_txs)
-            .map(|(_, tx)| tx.clone())
+            .map(|entry| entry.tx.clone())
             .collect()
     }
 
+    fn best_transactions(&self, gas_limit: u64, base_fee: u64) -> impl Iterator<Item = Tx> {
+        let inner = self.inner.read();
+        let mut queues: Vec<VecDeque<(Decoded, &Entry)>> = inner
+            .senders
+            .values()
+            .map(|queue| {
+                queue
+                    .ready()
+                    .filter_map(|nonce| inner.entries.get(&queue.nonces[&nonce]))
+                    .filter_map(|entry| Some((entry.decoded?, entry)))
+                    .collect()
+            })
+            .collect();
+        let rank = |(decoded, entry): &(Decoded, &Entry)| {
+            (decoded.fees.effective_tip(base_fee), Reverse(entry.arrival))
+        };
+
+        // Only each sender's lowest remaining nonce competes on fee at a time.
+        let mut heads: BinaryHeap<_> = queues
+            .iter()
+            .enumerate()
+            .filter_map(|(index, queue)| Some((rank(queue.front()?), index)))
+            .collect();
+        let mut best = Vec::new();
+        while let Some(((tip, _), index)) = heads.pop() {
+            let Some((decoded, entry)) = queues[index].pop_front() else { continue };
+            if tip.is_none() || decoded.gas_limit > gas_limit {
+                continue;
+            }
+            best.push(entry.tx.clone());
+            if let Some(next) = queues[index].front() {
+                heads.push((rank(next), index));
+            }
+        }
+        best.into_iter()
+    }
+
     fn prune(&self, tx_ids: &[TxId]) {
+        let mut inner = self.inner.write();
+        for id in tx_ids {
+            if let Some(Entry { decoded: Some(decoded), .. }) = inner.remove(id)
+                && inner.senders.get(&decoded.sender).is_some_and(|q| q.next_nonce.is_some())
+            {
+                inner.advance(decoded.sender, decoded.nonce + 1);
+            }
+        }
+    }
+
+    fn evict(&self, tx_ids: &[TxId]) {
         let mut inner = self.inner.write();
         for id in tx_ids {
             inner.remove(id);
@@ -52,12 +865,14 @@ impl Mempool for InMemoryMempool {
     }
 
     fn len(&self) -> usize {
-        self.inner.read().len()
+        self.inner.read().entries.len()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use monmouth_traits::StateDbError;
+
     use super::*;
 
     #[test]
@@ -109,4 +924,634 @@ mod tests {
         assert_eq!(txs.len(), 1);
         assert_eq!(txs[0], tx2);
     }
+
+    /// Sign an EIP-1559 transaction from the key `key` repeated, with the given fee caps.
+    fn eip1559_tx(
+        key: u8,
+        nonce: u64,
+        max_fee_per_gas: u128,
+        max_priority_fee_per_gas: u128,
+    ) -> Tx {
+        signed_tx(key, nonce, max_fee_per_gas, max_priority_fee_per_gas, 0)
+    }
+
+    /// Call from the key `0x11` repeated carrying `calldata_len` zero bytes.
+    fn calldata_tx(nonce: u64, calldata_len: usize) -> Tx {
+        signed_tx(0x11, nonce, 0, 0, calldata_len)
+    }
+
+    fn signed_tx(
+        key: u8,
+        nonce: u64,
+        max_fee_per_gas: u128,
+        max_priority_fee_per_gas: u128,
+        calldata_len: usize,
+    ) -> Tx {
+        use alloy_consensus::{SignableTransaction as _, TxEip1559};
+        use alloy_eips::eip2718::Encodable2718 as _;
+        use alloy_primitives::{Signature, TxKind};
+
+        let key = k256::ecdsa::SigningKey::from_slice(&[key; 32]).unwrap();
+        let tx = TxEip1559 {
+            chain_id: 1,
+            nonce,
+            // Zero calldata bytes cost 4 gas each.
+            gas_limit: 21_000 + 4 * calldata_len as u64,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            to: TxKind::Call(Address::repeat_byte(0x22)),
+            value: U256::ZERO,
+            access_list: Default::default(),
+            input: vec![0; calldata_len].into(),
+        };
+        let (sig, recovery_id) =
+            key.sign_prehash_recoverable(tx.signature_hash().as_slice()).unwrap();
+        let signature = Signature::from((sig, recovery_id));
+        let mut raw = Vec::new();
+        TxEnvelope::from(tx.into_signed(signature)).encode_2718(&mut raw);
+        Tx::new(raw.into())
+    }
+
+    /// Transfer from the key `0x11` repeated with the given nonce.
+    fn nonce_tx(nonce: u64) -> Tx {
+        eip1559_tx(0x11, nonce, 0, 0)
+    }
+
+    fn sender() -> Address {
+        Decoded::decode(&nonce_tx(0), &SenderCache::default()).unwrap().sender
+    }
+
+    /// State in which every account has the same nonce and balance.
+    #[derive(Clone, Debug)]
+    struct FixedAccount {
+        nonce: u64,
+        balance: U256,
+    }
+
+    impl FixedAccount {
+        /// Every account at `nonce` with an unlimited balance.
+        const fn nonce(nonce: u64) -> Self {
+            Self { nonce, balance: U256::MAX }
+        }
+    }
+
+    impl StateDbRead for FixedAccount {
+        async fn nonce(&self, _address: &Address) -> Result<u64, StateDbError> {
+            Ok(self.nonce)
+        }
+
+        async fn balance(&self, _address: &Address) -> Result<U256, StateDbError> {
+            Ok(self.balance)
+        }
+
+        async fn code_hash(
+            &self,
+            _address: &Address,
+        ) -> Result<alloy_primitives::B256, StateDbError> {
+            Ok(alloy_primitives::B256::ZERO)
+        }
+
+        async fn code(
+            &self,
+            _code_hash: &alloy_primitives::B256,
+        ) -> Result<alloy_primitives::Bytes, StateDbError> {
+            Ok(alloy_primitives::Bytes::new())
+        }
+
+        async fn storage(&self, _address: &Address, _slot: &U256) -> Result<U256, StateDbError> {
+            Ok(U256::ZERO)
+        }
+    }
+
+    fn build_all(mempool: &InMemoryMempool) -> Vec<Tx> {
+        mempool.build(usize::MAX, &BTreeSet::new())
+    }
+
+    #[test]
+    fn admission_caches_sender_under_tx_hash() {
+        let cache = SenderCache::default();
+        let mempool = InMemoryMempool::new().with_sender_cache(cache.clone());
+        let tx = nonce_tx(0);
+        assert!(mempool.insert(tx.clone()));
+
+        let envelope = TxEnvelope::decode_2718(&mut tx.bytes.as_ref()).unwrap();
+        assert_eq!(cache.recoveries(), 1);
+        assert_eq!(cache.get(envelope.tx_hash()), Some(sender()));
+    }
+
+    #[test]
+    fn priority_mempool_builds_highest_tip_first() {
+        let mempool = InMemoryMempool::priority();
+        mempool.set_base_fee(10);
+
+        // Effective tips at base fee 10: 2, 7 and 5 (capped by max_fee - base_fee).
+        let low = eip1559_tx(1, 0, 100, 2);
+        let high = eip1559_tx(2, 0, 100, 7);
+        let capped = eip1559_tx(3, 0, 15, 9);
+        for tx in [&low, &high, &capped] {
+            assert!(mempool.insert(tx.clone()));
+        }
+
+        assert_eq!(build_all(&mempool), vec![high, capped, low]);
+    }
+
+    #[test]
+    fn priority_mempool_breaks_ties_by_arrival() {
+        let mempool = InMemoryMempool::priority();
+
+        let first = eip1559_tx(1, 0, 50, 3);
+        let second = eip1559_tx(2, 0, 50, 3);
+        let undecodable = Tx::new(vec![1, 2, 3].into());
+        let third = eip1559_tx(3, 0, 50, 3);
+        for tx in [&first, &undecodable, &second, &third] {
+            mempool.insert(tx.clone());
+        }
+
+        let txs = mempool.build(3, &BTreeSet::new());
+        assert_eq!(txs, vec![first, second, third]);
+    }
+
+    #[test]
+    fn priority_mempool_ranks_underpriced_last() {
+        let mempool = InMemoryMempool::priority();
+        mempool.set_base_fee(40);
+
+        let underpriced = eip1559_tx(1, 0, 30, 30);
+        let priced = eip1559_tx(2, 0, 41, 1);
+        mempool.insert(underpriced.clone());
+        mempool.insert(priced.clone());
+
+        assert_eq!(build_all(&mempool), vec![priced, underpriced]);
+    }
+
+    #[test]
+    fn fair_policy_includes_old_low_fee_transaction() {
+        let fee_only = InMemoryMempool::priority();
+        let fair = InMemoryMempool::priority()
+            .with_inclusion_policy(InclusionPolicy::Fair { reserved_percent: 25 });
+
+        let old = eip1559_tx(1, 0, 100, 1);
+        let competitors: Vec<Tx> = (2..=6).map(|key| eip1559_tx(key, 0, 100, 10)).collect();
+        for mempool in [&fee_only, &fair] {
+            mempool.insert(old.clone());
+            for tx in &competitors {
+                mempool.insert(tx.clone());
+            }
+        }
+
+        assert!(!fee_only.build(4, &BTreeSet::new()).contains(&old));
+        let txs = fair.build(4, &BTreeSet::new());
+        assert_eq!(txs, [&[old][..], &competitors[..3]].concat());
+    }
+
+    #[test]
+    fn fair_policy_keeps_sender_nonce_order() {
+        let mempool = InMemoryMempool::priority()
+            .with_inclusion_policy(InclusionPolicy::Fair { reserved_percent: 50 });
+
+        // The oldest transaction is nonce 1; its reserved slot goes to nonce 0.
+        let second = eip1559_tx(1, 1, 100, 1);
+        let rich = eip1559_tx(2, 0, 100, 10);
+        let first = eip1559_tx(1, 0, 100, 1);
+        for tx in [&second, &rich, &first] {
+            assert!(mempool.insert(tx.clone()));
+        }
+
+        assert_eq!(mempool.build(2, &BTreeSet::new()), vec![first, rich]);
+    }
+
+    #[test]
+    fn priority_mempool_keeps_sender_nonce_order() {
+        let mempool = InMemoryMempool::priority();
+
+        let cheap_first = eip1559_tx(1, 0, 50, 1);
+        let rich_second = eip1559_tx(1, 1, 50, 9);
+        mempool.insert(rich_second.clone());
+        mempool.insert(cheap_first.clone());
+
+        assert_eq!(build_all(&mempool), vec![cheap_first, rich_second]);
+    }
+
+    #[test]
+    fn best_transactions_follow_fee_and_nonce_order() {
+        let mempool = InMemoryMempool::new();
+
+        // Tips at base fee 10: key 1 pays 2 then 9, key 2 pays 5. Key 3's nonce 0
+        // is below the base fee, which also holds back its nonce 1.
+        let first = eip1559_tx(1, 0, 100, 2);
+        let second = eip1559_tx(1, 1, 100, 9);
+        let rich = eip1559_tx(2, 0, 100, 5);
+        let underpriced = eip1559_tx(3, 0, 8, 8);
+        let blocked = eip1559_tx(3, 1, 100, 20);
+        for tx in [&second, &underpriced, &first, &blocked, &rich] {
+            assert!(mempool.insert(tx.clone()));
+        }
+
+        let best: Vec<Tx> = mempool.best_transactions(30_000_000, 10).collect();
+        assert_eq!(best, vec![rich, first, second]);
+    }
+
+    #[test]
+    fn best_transactions_skip_txs_over_gas_limit() {
+        let mempool = InMemoryMempool::new();
+        assert!(mempool.insert(nonce_tx(0)));
+
+        assert_eq!(mempool.best_transactions(20_000, 0).count(), 0);
+        assert_eq!(mempool.best_transactions(21_000, 0).count(), 1);
+    }
+
+    #[test]
+    fn reannounce_returns_due_transactions_oldest_first() {
+        let mempool = InMemoryMempool::new();
+        let config = ReannounceConfig::default().with_interval_secs(10);
+        let older = eip1559_tx(1, 0, 0, 0);
+        let newer = eip1559_tx(2, 0, 0, 0);
+        mempool.insert(older.clone());
+        assert_eq!(mempool.reannounce_due(1_000, config), vec![older.clone()]);
+        mempool.insert(newer.clone());
+        assert_eq!(mempool.reannounce_due(1_005, config), vec![newer.clone()]);
+
+        // Each transaction waits a full interval before its next announcement.
+        assert!(mempool.reannounce_due(1_009, config).is_empty());
+        assert_eq!(mempool.reannounce_due(1_010, config), vec![older.clone()]);
+        assert_eq!(mempool.reannounce_due(1_020, config), vec![older, newer]);
+    }
+
+    #[test]
+    fn reannounce_is_rate_limited() {
+        let mempool = InMemoryMempool::new();
+        let config = ReannounceConfig::default().with_max_batch(2);
+        for key in 1..=3 {
+            mempool.insert(eip1559_tx(key, 0, 0, 0));
+        }
+
+        assert_eq!(mempool.reannounce_due(0, config).len(), 2);
+        assert_eq!(mempool.reannounce_due(0, config).len(), 1);
+    }
+
+    #[test]
+    fn reannounce_drops_transaction_after_max_attempts() {
+        let mempool = InMemoryMempool::new();
+        let config = ReannounceConfig::default().with_interval_secs(10).with_max_attempts(2);
+        mempool.insert(nonce_tx(0));
+
+        assert_eq!(mempool.reannounce_due(0, config).len(), 1);
+        assert_eq!(mempool.reannounce_due(10, config).len(), 1);
+        assert_eq!(mempool.reannounce_due(20, config).len(), 1);
+        assert!(mempool.reannounce_due(30, config).is_empty());
+        assert_eq!(mempool.len(), 0);
+    }
+
+    #[test]
+    fn reannounce_skips_parked_transactions() {
+        let mempool = InMemoryMempool::new();
+        mempool.set_next_nonce(sender(), 0);
+        mempool.insert(nonce_tx(0));
+        mempool.insert(nonce_tx(2));
+
+        assert_eq!(mempool.reannounce_due(0, ReannounceConfig::default()), vec![nonce_tx(0)]);
+    }
+
+    #[tokio::test]
+    async fn in_order_submission_is_built() {
+        let mempool = InMemoryMempool::new();
+        let state = FixedAccount::nonce(3);
+
+        for nonce in 3..6 {
+            mempool.insert_from_state(&state, nonce_tx(nonce)).await.unwrap();
+        }
+
+        assert_eq!(build_all(&mempool), vec![nonce_tx(3), nonce_tx(4), nonce_tx(5)]);
+        assert_eq!(mempool.pending_gap(&sender()), None);
+    }
+
+    #[tokio::test]
+    async fn future_nonce_is_parked_until_gap_fills() {
+        let mempool = InMemoryMempool::new();
+        let state = FixedAccount::nonce(0);
+
+        mempool.insert_from_state(&state, nonce_tx(0)).await.unwrap();
+        mempool.insert_from_state(&state, nonce_tx(2)).await.unwrap();
+        assert_eq!(build_all(&mempool), vec![nonce_tx(0)]);
+        assert_eq!(mempool.pending_gap(&sender()), Some(1));
+
+        mempool.insert_from_state(&state, nonce_tx(1)).await.unwrap();
+        assert_eq!(build_all(&mempool), vec![nonce_tx(0), nonce_tx(1), nonce_tx(2)]);
+        assert_eq!(mempool.pending_gap(&sender()), None);
+    }
+
+    #[tokio::test]
+    async fn content_splits_ready_and_parked() {
+        let mempool = InMemoryMempool::new();
+        let state = FixedAccount::nonce(0);
+
+        for nonce in [0, 1, 3] {
+            mempool.insert_from_state(&state, nonce_tx(nonce)).await.unwrap();
+        }
+        mempool.insert(Tx::new(vec![1, 2, 3].into()));
+
+        let content = mempool.content();
+        assert_eq!(
+            content.ready,
+            BTreeMap::from([(sender(), BTreeMap::from([(0, nonce_tx(0)), (1, nonce_tx(1))]))])
+        );
+        assert_eq!(
+            content.parked,
+            BTreeMap::from([(sender(), BTreeMap::from([(3, nonce_tx(3))]))])
+        );
+    }
+
+    #[tokio::test]
+    async fn stale_nonce_is_rejected_and_prune_advances() {
+        let mempool = InMemoryMempool::new();
+
+        assert!(matches!(
+            mempool.insert_from_state(&FixedAccount::nonce(2), nonce_tx(1)).await,
+            Err(MempoolError::NonceTooLow { nonce: 1, next: 2 })
+        ));
+        mempool.insert_from_state(&FixedAccount::nonce(2), nonce_tx(2)).await.unwrap();
+        mempool.insert_from_state(&FixedAccount::nonce(2), nonce_tx(4)).await.unwrap();
+
+        mempool.prune(&[nonce_tx(2).id()]);
+        assert_eq!(mempool.pending_gap(&sender()), Some(3));
+        assert!(build_all(&mempool).is_empty());
+    }
+
+    #[tokio::test]
+    async fn same_nonce_replaces_buffered_transaction() {
+        let mempool = InMemoryMempool::new();
+        let state = FixedAccount::nonce(0);
+
+        let original = eip1559_tx(0x11, 0, 10, 1);
+        let replacement = eip1559_tx(0x11, 0, 20, 2);
+        mempool.insert_from_state(&state, original).await.unwrap();
+        mempool.insert_from_state(&state, replacement.clone()).await.unwrap();
+
+        assert_eq!(mempool.len(), 1);
+        assert_eq!(build_all(&mempool), vec![replacement]);
+    }
+
+    #[tokio::test]
+    async fn admission_rejects_undecodable_and_unfunded_transactions() {
+        let mempool = InMemoryMempool::new();
+
+        assert!(matches!(
+            mempool.insert_from_state(&FixedAccount::nonce(0), Tx::new(vec![1, 2, 3].into())).await,
+            Err(MempoolError::Undecodable)
+        ));
+        // 21000 gas at a fee cap of 10 costs 210000.
+        let state = FixedAccount { nonce: 0, balance: U256::from(209_999) };
+        assert!(matches!(
+            mempool.insert_from_state(&state, eip1559_tx(0x11, 0, 10, 1)).await,
+            Err(MempoolError::InsufficientFunds { .. })
+        ));
+        assert!(mempool.is_empty());
+
+        let state = FixedAccount { nonce: 0, balance: U256::from(210_000) };
+        mempool.insert_from_state(&state, eip1559_tx(0x11, 0, 10, 1)).await.unwrap();
+        assert_eq!(mempool.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn admission_rejects_other_chains_before_recovery() {
+        let cache = SenderCache::default();
+        let mempool = InMemoryMempool::new()
+            .with_admission(2, PoolConfig::default())
+            .with_sender_cache(cache.clone());
+
+        assert!(matches!(
+            mempool.insert_from_state(&FixedAccount::nonce(0), nonce_tx(0)).await,
+            Err(MempoolError::Invalid(TxPoolError::InvalidChainId { got: 1, expected: 2 }))
+        ));
+        assert!(mempool.is_empty());
+        assert_eq!(cache.recoveries(), 0);
+    }
+
+    #[tokio::test]
+    async fn admission_caps_calldata_size() {
+        let config = PoolConfig::default().with_max_tx_calldata_bytes(64);
+        let mempool = InMemoryMempool::new().with_admission(1, config);
+        let state = FixedAccount::nonce(0);
+
+        assert!(matches!(
+            mempool.insert_from_state(&state, calldata_tx(0, 65)).await,
+            Err(MempoolError::Invalid(TxPoolError::CalldataTooLarge { size: 65, max: 64 }))
+        ));
+        assert!(mempool.is_empty());
+
+        mempool.insert_from_state(&state, calldata_tx(0, 64)).await.unwrap();
+        assert_eq!(mempool.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn sender_cap_rejects_new_nonces_but_not_replacements() {
+        let config = PoolConfig::default().with_max_txs_per_sender(2);
+        let mempool = InMemoryMempool::new().with_admission(1, config);
+        let state = FixedAccount::nonce(0);
+        mempool.insert_from_state(&state, eip1559_tx(0x11, 0, 10, 1)).await.unwrap();
+        mempool.insert_from_state(&state, eip1559_tx(0x11, 1, 10, 1)).await.unwrap();
+
+        assert!(matches!(
+            mempool.insert_from_state(&state, eip1559_tx(0x11, 2, 10, 1)).await,
+            Err(MempoolError::SenderFull { sender: full, max: 2 }) if full == sender()
+        ));
+
+        let replacement = eip1559_tx(0x11, 1, 20, 2);
+        mempool.insert_from_state(&state, replacement.clone()).await.unwrap();
+        mempool.insert_from_state(&state, eip1559_tx(0x12, 0, 10, 1)).await.unwrap();
+        assert_eq!(mempool.len(), 3);
+        assert!(build_all(&mempool).contains(&replacement));
+    }
+
+    #[tokio::test]
+    async fn recovered_sender_skips_recovery() {
+        let cache = SenderCache::default();
+        let mempool = InMemoryMempool::new().with_sender_cache(cache.clone());
+
+        mempool.insert_recovered(&FixedAccount::nonce(0), nonce_tx(0), sender()).await.unwrap();
+
+        assert_eq!(cache.recoveries(), 0);
+        assert_eq!(build_all(&mempool), vec![nonce_tx(0)]);
+    }
+
+    #[tokio::test]
+    async fn evict_keeps_the_next_nonce() {
+        let mempool = InMemoryMempool::new();
+        let state = FixedAccount::nonce(0);
+        mempool.insert_from_state(&state, nonce_tx(0)).await.unwrap();
+        mempool.insert_from_state(&state, nonce_tx(1)).await.unwrap();
+
+        mempool.evict(&[nonce_tx(0).id()]);
+
+        assert_eq!(mempool.len(), 1);
+        assert_eq!(mempool.pending_gap(&sender()), Some(0));
+        assert!(build_all(&mempool).is_empty());
+    }
+
+    #[test]
+    fn replacement_with_sufficient_bump_is_accepted() {
+        let mempool = InMemoryMempool::new();
+
+        let original = eip1559_tx(0x11, 0, 100, 10);
+        let replacement = eip1559_tx(0x11, 0, 110, 11);
+        mempool.try_insert(original).unwrap();
+        mempool.try_insert(replacement.clone()).unwrap();
+
+        assert_eq!(build_all(&mempool), vec![replacement]);
+    }
+
+    #[test]
+    fn replacement_with_insufficient_bump_is_rejected() {
+        let mempool = InMemoryMempool::new().with_min_replacement_bump_percent(25);
+
+        let original = eip1559_tx(0x11, 0, 100, 10);
+        mempool.try_insert(original.clone()).unwrap();
+
+        // Each attempt misses the 25% bump on the fee cap, the tip, or both.
+        for (max_fee, tip) in [(124, 20), (200, 12), (101, 11)] {
+            assert!(matches!(
+                mempool.try_insert(eip1559_tx(0x11, 0, max_fee, tip)),
+                Err(MempoolError::ReplacementUnderpriced { nonce: 0, min_bump_percent: 25 })
+            ));
+        }
+        assert_eq!(build_all(&mempool), vec![original]);
+    }
+
+    #[test]
+    fn replacement_is_scoped_to_the_sender() {
+        let mempool = InMemoryMempool::new();
+
+        let first = eip1559_tx(0x11, 0, 100, 10);
+        let other = eip1559_tx(0x12, 0, 100, 10);
+        mempool.try_insert(first.clone()).unwrap();
+        mempool.try_insert(other.clone()).unwrap();
+        assert_eq!(mempool.len(), 2);
+
+        let bumped = eip1559_tx(0x12, 0, 150, 15);
+        mempool.try_insert(bumped.clone()).unwrap();
+
+        let built = build_all(&mempool);
+        assert_eq!(built.len(), 2);
+        assert!(built.contains(&first));
+        assert!(built.contains(&bumped));
+        assert!(!built.contains(&other));
+    }
+
+    #[test]
+    fn full_pool_evicts_lowest_fee_for_higher_fee() {
+        let mempool = InMemoryMempool::new().with_max_transactions(3);
+        mempool.set_base_fee(10);
+
+        let cheapest = eip1559_tx(1, 0, 100, 1);
+        let middle = eip1559_tx(2, 0, 100, 5);
+        let rich = eip1559_tx(3, 0, 100, 9);
+        for tx in [&middle, &cheapest, &rich] {
+            mempool.try_insert(tx.clone()).unwrap();
+        }
+        assert!(mempool.is_full());
+
+        let incoming = eip1559_tx(4, 0, 100, 3);
+        mempool.try_insert(incoming.clone()).unwrap();
+
+        assert_eq!(mempool.len(), 3);
+        let built = build_all(&mempool);
+        assert!(!built.contains(&cheapest));
+        assert!(built.contains(&incoming));
+        let sender = Decoded::decode(&cheapest, &SenderCache::default()).unwrap().sender;
+        assert!(!mempool.inner.read().senders.contains_key(&sender));
+    }
+
+    #[test]
+    fn full_pool_rejects_lowest_fee_incoming() {
+        let mempool = InMemoryMempool::new().with_max_transactions(2);
+
+        let first = eip1559_tx(1, 0, 100, 4);
+        let second = eip1559_tx(2, 0, 100, 6);
+        mempool.try_insert(first.clone()).unwrap();
+        mempool.try_insert(second.clone()).unwrap();
+
+        // An equal tip loses to the earlier arrival, a lower one always loses.
+        for tip in [4, 2] {
+            assert!(matches!(
+                mempool.try_insert(eip1559_tx(3, 0, 100, tip)),
+                Err(MempoolError::PoolFull)
+            ));
+        }
+        assert_eq!(mempool.len(), 2);
+        assert!(!mempool.insert(Tx::new(vec![1, 2, 3].into())));
+    }
+
+    #[test]
+    fn full_pool_accepts_replacement_without_eviction() {
+        let mempool = InMemoryMempool::new().with_max_transactions(2);
+
+        let cheap = eip1559_tx(1, 0, 100, 1);
+        let original = eip1559_tx(2, 0, 100, 10);
+        mempool.try_insert(cheap.clone()).unwrap();
+        mempool.try_insert(original).unwrap();
+
+        let replacement = eip1559_tx(2, 0, 200, 20);
+        mempool.try_insert(replacement.clone()).unwrap();
+
+        let built = build_all(&mempool);
+        assert_eq!(built.len(), 2);
+        assert!(built.contains(&cheap));
+        assert!(built.contains(&replacement));
+    }
+
+    #[test]
+    fn full_pool_evicts_the_victims_later_nonces() {
+        let mempool = InMemoryMempool::new().with_max_transactions(3);
+
+        let cheap_head = eip1559_tx(1, 0, 100, 1);
+        let rich_tail = eip1559_tx(1, 1, 100, 9);
+        let other = eip1559_tx(2, 0, 100, 5);
+        for tx in [&cheap_head, &rich_tail, &other] {
+            mempool.try_insert(tx.clone()).unwrap();
+        }
+
+        let incoming = eip1559_tx(3, 0, 100, 3);
+        mempool.try_insert(incoming.clone()).unwrap();
+
+        // The tail could never execute without its head, so both go.
+        assert_eq!(mempool.len(), 2);
+        let built = build_all(&mempool);
+        assert!(built.contains(&other));
+        assert!(built.contains(&incoming));
+    }
+
+    #[test]
+    fn full_pool_keeps_senders_earlier_nonce() {
+        let mempool = InMemoryMempool::new().with_max_transactions(2);
+
+        let head = eip1559_tx(1, 0, 100, 1);
+        mempool.try_insert(head.clone()).unwrap();
+        mempool.try_insert(eip1559_tx(2, 0, 100, 5)).unwrap();
+
+        assert!(matches!(
+            mempool.try_insert(eip1559_tx(1, 1, 100, 9)),
+            Err(MempoolError::PoolFull)
+        ));
+        assert!(build_all(&mempool).contains(&head));
+    }
+
+    #[test]
+    fn base_fee_change_reranks_eviction() {
+        let mempool = InMemoryMempool::new().with_max_transactions(2);
+
+        let capped = eip1559_tx(1, 0, 100, 50);
+        let steady = eip1559_tx(2, 0, 1_000, 10);
+        mempool.try_insert(capped.clone()).unwrap();
+        mempool.try_insert(steady.clone()).unwrap();
+
+        // At base fee 95 the capped transaction only tips 5.
+        mempool.set_base_fee(95);
+        let incoming = eip1559_tx(3, 0, 1_000, 7);
+        mempool.try_insert(incoming.clone()).unwrap();
+
+        let built = build_all(&mempool);
+        assert!(!built.contains(&capped));
+        assert!(built.contains(&steady));
+        assert!(built.contains(&incoming));
+    }
 }
@@ -1,7 +1,11 @@
 //! Default component implementations.
 
 mod mempool;
-pub use mempool::InMemoryMempool;
+pub use mempool::{
+    DEFAULT_MAX_REANNOUNCE_ATTEMPTS, DEFAULT_MAX_REANNOUNCE_BATCH, DEFAULT_MAX_TRANSACTIONS,
+    DEFAULT_MIN_REPLACEMENT_BUMP_PERCENT, DEFAULT_REANNOUNCE_INTERVAL_SECS, InMemoryMempool,
+    InclusionPolicy, MempoolContent, MempoolOrdering, ReannounceConfig, SenderTransactions,
+};
 
 mod seed;
 pub use seed::InMemorySeedTracker;
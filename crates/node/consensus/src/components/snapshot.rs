@@ -10,7 +10,7 @@ use monmouth_traits::StateDb;
 use parking_lot::RwLock;
 
 use crate::{
-    ConsensusError,
+    ConsensusError, SnapshotChainError,
     traits::{Digest, Snapshot, SnapshotStore},
 };
 
@@ -20,6 +20,7 @@ pub struct InMemorySnapshotStore<S> {
     snapshots: Arc<RwLock<BTreeMap<Digest, Snapshot<S>>>>,
     persisted: Arc<RwLock<BTreeSet<Digest>>>,
     persisting: Arc<RwLock<BTreeSet<Digest>>>,
+    persisted_tip: Arc<RwLock<Option<Digest>>>,
 }
 
 impl<S> Clone for InMemorySnapshotStore<S> {
@@ -28,6 +29,7 @@ impl<S> Clone for InMemorySnapshotStore<S> {
             snapshots: Arc::clone(&self.snapshots),
             persisted: Arc::clone(&self.persisted),
             persisting: Arc::clone(&self.persisting),
+            persisted_tip: Arc::clone(&self.persisted_tip),
         }
     }
 }
@@ -40,8 +42,14 @@ impl<S> InMemorySnapshotStore<S> {
             snapshots: Arc::new(RwLock::new(BTreeMap::new())),
             persisted: Arc::new(RwLock::new(BTreeSet::new())),
             persisting: Arc::new(RwLock::new(BTreeSet::new())),
+            persisted_tip: Arc::new(RwLock::new(None)),
         }
     }
+
+    /// Most recently persisted digest, if any.
+    pub fn persisted_tip(&self) -> Option<Digest> {
+        *self.persisted_tip.read()
+    }
 }
 
 impl<S> InMemorySnapshotStore<S> {
@@ -67,6 +75,60 @@ impl<S> InMemorySnapshotStore<S> {
             persisting.remove(digest);
         }
     }
+
+    /// Drop persisted snapshots buried beneath the finalized `digest`.
+    ///
+    /// Walks the parent links down from `digest` and removes every persisted
+    /// ancestor that no unpersisted snapshot still builds on. `digest` itself is
+    /// kept so new blocks can extend it. Persisted markers are retained, so walks
+    /// for merged changes still stop at the persisted boundary without needing
+    /// the pruned snapshots. Returns the number of snapshots removed.
+    pub fn prune_below(&self, digest: Digest) -> usize {
+        let mut snapshots = self.snapshots.write();
+        let persisted = self.persisted.read();
+
+        let pinned: BTreeSet<Digest> = snapshots
+            .iter()
+            .filter(|(child, _)| !persisted.contains(*child))
+            .filter_map(|(_, snapshot)| snapshot.parent)
+            .collect();
+
+        let mut buried = Vec::new();
+        let mut current = snapshots.get(&digest).and_then(|snapshot| snapshot.parent);
+        while let Some(ancestor) = current {
+            let Some(snapshot) = snapshots.get(&ancestor) else {
+                break;
+            };
+            current = snapshot.parent;
+            if persisted.contains(&ancestor) && !pinned.contains(&ancestor) {
+                buried.push(ancestor);
+            }
+        }
+
+        for ancestor in &buried {
+            snapshots.remove(ancestor);
+        }
+        buried.len()
+    }
+
+    /// Like [`prune_below`](Self::prune_below), but also keeps the `retain`
+    /// nearest ancestors of `digest`.
+    ///
+    /// Returns `0` without pruning when fewer than `retain` ancestors remain.
+    pub fn prune_retaining(&self, digest: Digest, retain: usize) -> usize {
+        let floor = {
+            let snapshots = self.snapshots.read();
+            let mut floor = digest;
+            for _ in 0..retain {
+                match snapshots.get(&floor).and_then(|snapshot| snapshot.parent) {
+                    Some(parent) => floor = parent,
+                    None => return 0,
+                }
+            }
+            floor
+        };
+        self.prune_below(floor)
+    }
 }
 
 impl<S> Default for InMemorySnapshotStore<S> {
@@ -93,6 +155,9 @@ impl<S: StateDb> SnapshotStore<S> for InMemorySnapshotStore<S> {
         for digest in digests {
             persisted.insert(*digest);
         }
+        if let Some(last) = digests.last() {
+            *self.persisted_tip.write() = Some(*last);
+        }
     }
 
     fn merged_changes(
@@ -129,6 +194,11 @@ impl<S: StateDb> SnapshotStore<S> for InMemorySnapshotStore<S> {
         Ok(merged)
     }
 
+    /// Collect the unpersisted chain ending at `digest`.
+    ///
+    /// The walk must end at the persisted tip. A missing snapshot on the way is
+    /// reported as [`SnapshotChainError::Gap`]; ending at an older persisted
+    /// digest, or at a root without passing one, as [`SnapshotChainError::Fork`].
     fn changes_for_persist(
         &self,
         digest: Digest,
@@ -145,13 +215,21 @@ impl<S: StateDb> SnapshotStore<S> for InMemorySnapshotStore<S> {
                 break;
             }
 
-            let snapshot = snapshots.get(&d).ok_or(ConsensusError::SnapshotNotFound(d))?;
+            let snapshot =
+                snapshots.get(&d).ok_or(SnapshotChainError::Gap { digest, missing: d })?;
 
             chain.push(d);
             changes_chain.push(snapshot.changes.clone());
             current = snapshot.parent;
         }
 
+        if let Some(tip) = self.persisted_tip()
+            && !chain.is_empty()
+            && current != Some(tip)
+        {
+            return Err(SnapshotChainError::Fork { digest, base: current, tip }.into());
+        }
+
         // Reverse to get oldest-first order
         chain.reverse();
         changes_chain.reverse();
@@ -286,4 +364,147 @@ mod tests {
         store.mark_persisted(&[digest]);
         assert!(!store.can_persist_chain(&[digest]));
     }
+
+    fn empty_snapshot(parent: Option<Digest>) -> Snapshot<MockStateDb> {
+        Snapshot::new(parent, MockStateDb, StateRoot(B256::ZERO), ChangeSet::new(), BTreeSet::new())
+    }
+
+    /// Store with persisted `root <- tip` and unpersisted `tip <- child`.
+    fn persisted_chain_store() -> (InMemorySnapshotStore<MockStateDb>, [Digest; 3]) {
+        let store = InMemorySnapshotStore::<MockStateDb>::new();
+        let [root, tip, child] = [0x10u8, 0x11, 0x12].map(|i| Digest::from([i; 32]));
+        store.insert(root, empty_snapshot(None));
+        store.insert(tip, empty_snapshot(Some(root)));
+        store.insert(child, empty_snapshot(Some(tip)));
+        store.mark_persisted(&[root, tip]);
+        (store, [root, tip, child])
+    }
+
+    #[test]
+    fn changes_for_persist_clean_chain() {
+        let (store, [_, tip, child]) = persisted_chain_store();
+        assert_eq!(store.persisted_tip(), Some(tip));
+
+        let (chain, _) = store.changes_for_persist(child).unwrap();
+        assert_eq!(chain, vec![child]);
+        assert!(store.changes_for_persist(tip).unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn changes_for_persist_reports_gap() {
+        let (store, _) = persisted_chain_store();
+        let missing = Digest::from([0x20u8; 32]);
+        let digest = Digest::from([0x21u8; 32]);
+        store.insert(digest, empty_snapshot(Some(missing)));
+
+        let err = store.changes_for_persist(digest).unwrap_err();
+        assert!(matches!(
+            err,
+            ConsensusError::SnapshotChain(SnapshotChainError::Gap { digest: d, missing: m })
+                if d == digest && m == missing
+        ));
+    }
+
+    #[test]
+    fn changes_for_persist_reports_fork() {
+        let (store, [root, tip, _]) = persisted_chain_store();
+        let sibling = Digest::from([0x30u8; 32]);
+        store.insert(sibling, empty_snapshot(Some(root)));
+
+        let err = store.changes_for_persist(sibling).unwrap_err();
+        assert!(matches!(
+            err,
+            ConsensusError::SnapshotChain(SnapshotChainError::Fork { digest, base, tip: t })
+                if digest == sibling && base == Some(root) && t == tip
+        ));
+
+        let orphan = Digest::from([0x31u8; 32]);
+        store.insert(orphan, empty_snapshot(None));
+        let err = store.changes_for_persist(orphan).unwrap_err();
+        assert!(matches!(
+            err,
+            ConsensusError::SnapshotChain(SnapshotChainError::Fork { base: None, .. })
+        ));
+    }
+
+    #[test]
+    fn snapshot_store_prune_below_removes_buried_persisted() {
+        let store = InMemorySnapshotStore::<MockStateDb>::new();
+
+        // Chain 1 <- 2 <- 3 <- 4 <- 5 with the first three persisted.
+        let digests: Vec<Digest> = (1..=5u8).map(|i| Digest::from([i; 32])).collect();
+        let mut parent = None;
+        for digest in &digests {
+            let snapshot = Snapshot::new(
+                parent,
+                MockStateDb,
+                StateRoot(B256::ZERO),
+                ChangeSet::new(),
+                BTreeSet::new(),
+            );
+            store.insert(*digest, snapshot);
+            parent = Some(*digest);
+        }
+        store.mark_persisted(&digests[..3]);
+
+        assert_eq!(store.prune_below(digests[2]), 2);
+
+        assert!(store.get(&digests[0]).is_none());
+        assert!(store.get(&digests[1]).is_none());
+        for digest in &digests[2..] {
+            assert!(store.get(digest).is_some());
+        }
+        assert!(store.merged_changes(digests[4], ChangeSet::new()).is_ok());
+        assert_eq!(store.changes_for_persist(digests[4]).unwrap().0, digests[3..].to_vec());
+
+        // Nothing more is buried beneath the finalized head.
+        assert_eq!(store.prune_below(digests[2]), 0);
+    }
+
+    #[test]
+    fn snapshot_store_prune_retaining_keeps_recent_ancestors() {
+        let store = InMemorySnapshotStore::<MockStateDb>::new();
+
+        // Persisted chain 1 <- 2 <- 3 <- 4 <- 5.
+        let digests: Vec<Digest> = (1..=5u8).map(|i| Digest::from([i; 32])).collect();
+        let mut parent = None;
+        for digest in &digests {
+            store.insert(*digest, empty_snapshot(parent));
+            parent = Some(*digest);
+        }
+        store.mark_persisted(&digests);
+
+        // Too few ancestors to prune anything.
+        assert_eq!(store.prune_retaining(digests[1], 2), 0);
+
+        assert_eq!(store.prune_retaining(digests[4], 2), 2);
+        assert!(store.get(&digests[0]).is_none());
+        assert!(store.get(&digests[1]).is_none());
+        for digest in &digests[2..] {
+            assert!(store.get(digest).is_some());
+        }
+    }
+
+    #[test]
+    fn snapshot_store_prune_below_keeps_parents_of_unpersisted_children() {
+        let store = InMemorySnapshotStore::<MockStateDb>::new();
+
+        // 1 <- 2 <- 3, plus an unpersisted fork 1 <- 4.
+        let [a, b, c, fork] = [1u8, 2, 3, 4].map(|i| Digest::from([i; 32]));
+        for (digest, parent) in [(a, None), (b, Some(a)), (c, Some(b)), (fork, Some(a))] {
+            let snapshot = Snapshot::new(
+                parent,
+                MockStateDb,
+                StateRoot(B256::ZERO),
+                ChangeSet::new(),
+                BTreeSet::new(),
+            );
+            store.insert(digest, snapshot);
+        }
+        store.mark_persisted(&[a, b, c]);
+
+        assert_eq!(store.prune_below(c), 1);
+        assert!(store.get(&a).is_some(), "parent of an unpersisted fork must survive");
+        assert!(store.get(&b).is_none());
+    }
 }
@@ -1,5 +1,6 @@
 //! Error types for consensus operations.
 
+use alloy_primitives::{Address, U256};
 use monmouth_domain::{ConsensusDigest, StateRoot};
 use thiserror::Error;
 
@@ -34,6 +35,103 @@ pub enum ConsensusError {
         /// Actual state root.
         actual: StateRoot,
     },
+
+    /// The unpersisted chain below a digest could not be walked.
+    #[error(transparent)]
+    SnapshotChain(#[from] SnapshotChainError),
+}
+
+/// Why the unpersisted chain below a digest cannot be collected for persistence.
+///
+/// A [`Gap`](Self::Gap) can be backfilled by re-executing the missing block; a
+/// [`Fork`](Self::Fork) must be rejected because the persisted state has moved
+/// on along another branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum SnapshotChainError {
+    /// A snapshot on the walk down from `digest` is not cached.
+    #[error("snapshot gap below {digest:?}: missing {missing:?}")]
+    Gap {
+        /// Digest the walk started from.
+        digest: ConsensusDigest,
+        /// First digest on the walk without a cached snapshot.
+        missing: ConsensusDigest,
+    },
+
+    /// The walk down from `digest` ended somewhere other than the persisted tip.
+    #[error("snapshot fork at {digest:?}: branches off {base:?}, persisted tip is {tip:?}")]
+    Fork {
+        /// Digest the walk started from.
+        digest: ConsensusDigest,
+        /// Persisted digest the walk reached, or `None` if it reached a root
+        /// snapshot without passing a persisted one.
+        base: Option<ConsensusDigest>,
+        /// Most recently persisted digest.
+        tip: ConsensusDigest,
+    },
+}
+
+/// Reasons a transaction is refused by the mempool.
+#[derive(Debug, Error)]
+pub enum MempoolError {
+    /// The transaction is already pooled.
+    #[error("transaction already known")]
+    AlreadyKnown,
+
+    /// The transaction is not a signed EIP-2718 envelope.
+    #[error("transaction does not decode")]
+    Undecodable,
+
+    /// A stateless admission check failed.
+    #[error("invalid transaction: {0}")]
+    Invalid(#[from] monmouth_txpool::TxPoolError),
+
+    /// The sender's balance does not cover the transaction's maximum cost.
+    #[error("insufficient funds: cost {cost} exceeds balance {balance}")]
+    InsufficientFunds {
+        /// Gas limit times fee cap, plus value.
+        cost: U256,
+        /// Sender's balance.
+        balance: U256,
+    },
+
+    /// The transaction's nonce is below the sender's next executable nonce.
+    #[error("nonce too low: got {nonce}, next is {next}")]
+    NonceTooLow {
+        /// Nonce of the rejected transaction.
+        nonce: u64,
+        /// Sender's next executable nonce.
+        next: u64,
+    },
+
+    /// A transaction with the same sender and nonce is pooled and the new one
+    /// does not raise its fees enough to replace it.
+    #[error(
+        "replacement transaction underpriced: nonce {nonce} needs a {min_bump_percent}% fee bump"
+    )]
+    ReplacementUnderpriced {
+        /// Nonce shared by both transactions.
+        nonce: u64,
+        /// Minimum fee increase required, in percent.
+        min_bump_percent: u64,
+    },
+
+    /// The pool is full and the transaction pays no more than the cheapest
+    /// pooled transaction.
+    #[error("mempool full: transaction fee too low to evict a pooled transaction")]
+    PoolFull,
+
+    /// The sender already has the most transactions a sender may pool.
+    #[error("sender {sender} has {max} pooled transactions")]
+    SenderFull {
+        /// Sender of the rejected transaction.
+        sender: Address,
+        /// Maximum pooled transactions per sender.
+        max: usize,
+    },
+
+    /// The sender's account could not be read.
+    #[error("state db error: {0}")]
+    StateDb(#[from] monmouth_traits::StateDbError),
 }
 
 #[cfg(test)]
@@ -92,6 +190,30 @@ mod tests {
         assert!(msg.contains("got"));
     }
 
+    #[test]
+    fn test_snapshot_chain_error_from() {
+        let digest = test_digest();
+        let err: ConsensusError =
+            SnapshotChainError::Gap { digest, missing: ConsensusDigest::from([1u8; 32]) }.into();
+        assert!(err.to_string().starts_with("snapshot gap below"));
+        assert!(matches!(err, ConsensusError::SnapshotChain(SnapshotChainError::Gap { .. })));
+    }
+
+    #[test]
+    fn test_replacement_underpriced_display() {
+        let err = MempoolError::ReplacementUnderpriced { nonce: 3, min_bump_percent: 10 };
+        assert_eq!(
+            err.to_string(),
+            "replacement transaction underpriced: nonce 3 needs a 10% fee bump"
+        );
+    }
+
+    #[test]
+    fn test_insufficient_funds_display() {
+        let err = MempoolError::InsufficientFunds { cost: U256::from(21_000), balance: U256::ZERO };
+        assert_eq!(err.to_string(), "insufficient funds: cost 21000 exceeds balance 0");
+    }
+
     #[test]
     fn test_error_is_send_sync() {
         fn assert_send_sync<T: Send + Sync>() {}
@@ -0,0 +1,80 @@
+//! Transaction filtering policy for block building.
+
+use monmouth_domain::Tx;
+use monmouth_executor::classifier::ClassificationResult;
+
+/// Policy hook consulted by the block builder after classification.
+///
+/// Implementations decide whether a candidate transaction may enter a block,
+/// e.g. to enforce address denylists or agent-only transaction policies.
+/// [`select_transactions`](crate::select_transactions) passes over disallowed
+/// transactions, keeps filling the block, and evicts them from the mempool.
+pub trait TransactionFilter: Clone + Send + Sync + 'static {
+    /// Returns `true` if the transaction may be included in the block.
+    fn allow(&self, tx: &Tx, classification: &ClassificationResult) -> bool;
+}
+
+/// Filter that admits every transaction.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AllowAll;
+
+impl TransactionFilter for AllowAll {
+    fn allow(&self, _tx: &Tx, _classification: &ClassificationResult) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use alloy_consensus::{Transaction as _, TxEnvelope};
+    use alloy_eips::eip2718::Decodable2718 as _;
+    use alloy_primitives::{Address, U256};
+    use k256::ecdsa::SigningKey;
+    use monmouth_domain::evm::Evm;
+    use monmouth_executor::classifier::{
+        TransactionClassification, TransactionClassifier, precompiles,
+    };
+
+    use super::*;
+
+    /// Filter rejecting transactions routed to the SVM.
+    #[derive(Clone)]
+    pub(crate) struct DenySvmRouted;
+
+    impl TransactionFilter for DenySvmRouted {
+        fn allow(&self, _tx: &Tx, classification: &ClassificationResult) -> bool {
+            classification.classification != TransactionClassification::SvmRouted
+        }
+    }
+
+    fn classify(tx: &Tx) -> ClassificationResult {
+        let envelope = TxEnvelope::decode_2718(&mut tx.bytes.as_ref()).expect("signed tx");
+        TransactionClassifier::enabled().classify(envelope.to(), envelope.input())
+    }
+
+    fn signing_key() -> SigningKey {
+        let mut secret = [0u8; 32];
+        secret[31] = 1;
+        SigningKey::from_bytes((&secret).into()).expect("valid key")
+    }
+
+    #[test]
+    fn allow_all_admits_svm_routed() {
+        let key = signing_key();
+        let svm =
+            Evm::sign_eip1559_transfer(&key, 1, precompiles::SVM_ROUTER, U256::ZERO, 0, 21_000);
+        assert!(AllowAll.allow(&svm, &classify(&svm)));
+    }
+
+    #[test]
+    fn deny_filter_rejects_matching_classification() {
+        let key = signing_key();
+        let svm =
+            Evm::sign_eip1559_transfer(&key, 1, precompiles::SVM_ROUTER, U256::ZERO, 0, 21_000);
+        let plain =
+            Evm::sign_eip1559_transfer(&key, 1, Address::repeat_byte(0xAB), U256::ZERO, 1, 21_000);
+
+        assert!(!DenySvmRouted.allow(&svm, &classify(&svm)));
+        assert!(DenySvmRouted.allow(&plain, &classify(&plain)));
+    }
+}
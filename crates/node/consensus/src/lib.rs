@@ -11,7 +11,7 @@ mod application;
 pub use application::{ConsensusApplication, ConsensusApplicationExt};
 
 mod error;
-pub use error::ConsensusError;
+pub use error::{ConsensusError, MempoolError, SnapshotChainError};
 
 mod traits;
 pub use traits::{Digest, Mempool, SeedTracker, Snapshot, SnapshotStore, TxId};
@@ -19,8 +19,11 @@ pub use traits::{Digest, Mempool, SeedTracker, Snapshot, SnapshotStore, TxId};
 mod ledger;
 pub use ledger::LedgerView;
 
+mod filter;
+pub use filter::{AllowAll, TransactionFilter};
+
 mod proposal;
-pub use proposal::ProposalBuilder;
+pub use proposal::{ProposalBuilder, select_transactions};
 
 mod execution;
 pub use execution::BlockExecution;
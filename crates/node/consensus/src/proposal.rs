@@ -2,14 +2,17 @@
 
 use std::collections::BTreeSet;
 
-use alloy_consensus::Header;
+use alloy_consensus::{Header, Transaction as _, TxEnvelope};
+use alloy_eips::eip2718::Decodable2718 as _;
 use alloy_primitives::{Address, B256, Bytes};
 use commonware_cryptography::Committable as _;
 use monmouth_domain::{Block, StateRoot, Tx};
-use monmouth_executor::{BlockContext, BlockExecutor};
+use monmouth_executor::{BlockContext, BlockExecutor, classifier::TransactionClassifier};
 use monmouth_traits::StateDb;
 
-use crate::{ConsensusError, Digest, Mempool, Snapshot, SnapshotStore, TxId};
+use crate::{
+    AllowAll, ConsensusError, Digest, Mempool, Snapshot, SnapshotStore, TransactionFilter, TxId,
+};
 
 fn block_context(height: u64, prevrandao: B256) -> BlockContext {
     let header = Header {
@@ -23,12 +26,61 @@ fn block_context(height: u64, prevrandao: B256) -> BlockContext {
     BlockContext::new(header, B256::ZERO, prevrandao)
 }
 
+/// Select transactions for a block in [`Mempool::best_transactions`] order.
+///
+/// Transactions in `excluded`, already included by pending ancestor blocks, are
+/// passed over. Each candidate is classified and offered to `filter`; rejected
+/// ones are skipped and evicted from the mempool once selection ends, and
+/// selection keeps filling the block. Selection stops once `max_txs` are taken
+/// or the next transaction's gas limit no longer fits in what is left of
+/// `gas_limit`. Transactions that do not decode are admitted and count no gas;
+/// they fail at execution.
+pub fn select_transactions<M: Mempool, F: TransactionFilter>(
+    mempool: &M,
+    excluded: &BTreeSet<TxId>,
+    max_txs: usize,
+    gas_limit: u64,
+    base_fee: u64,
+    classifier: &TransactionClassifier,
+    filter: &F,
+) -> Vec<Tx> {
+    let mut remaining = gas_limit;
+    let mut selected = Vec::new();
+    let mut denied = Vec::new();
+    for tx in mempool.best_transactions(gas_limit, base_fee) {
+        if selected.len() >= max_txs {
+            break;
+        }
+        if excluded.contains(&tx.id()) {
+            continue;
+        }
+        let envelope = TxEnvelope::decode_2718(&mut tx.bytes.as_ref()).ok();
+        if let Some(envelope) = &envelope {
+            let classification = classifier.classify(envelope.to(), envelope.input());
+            if !filter.allow(&tx, &classification) {
+                denied.push(tx.id());
+                continue;
+            }
+        }
+        let gas = envelope.map_or(0, |envelope| envelope.gas_limit());
+        let Some(left) = remaining.checked_sub(gas) else { break };
+        remaining = left;
+        selected.push(tx);
+    }
+    if !denied.is_empty() {
+        mempool.evict(&denied);
+    }
+    selected
+}
+
 /// Builder for constructing block proposals.
 ///
 /// ProposalBuilder coordinates gathering transactions from the mempool,
 /// executing them against parent state, and constructing a complete block.
+/// Candidate transactions are classified and passed through a
+/// [`TransactionFilter`] while they are selected.
 #[derive(Debug)]
-pub struct ProposalBuilder<S, M, SS, E> {
+pub struct ProposalBuilder<S, M, SS, E, F = AllowAll> {
     /// State database for execution.
     state: S,
     /// Transaction mempool.
@@ -39,6 +91,10 @@ pub struct ProposalBuilder<S, M, SS, E> {
     executor: E,
     /// Maximum transactions per block.
     max_txs: usize,
+    /// Classifier applied to candidate transactions.
+    classifier: TransactionClassifier,
+    /// Inclusion policy consulted after classification.
+    filter: F,
 }
 
 impl<S, M, SS, E> ProposalBuilder<S, M, SS, E>
@@ -48,20 +104,40 @@ where
     SS: SnapshotStore<S>,
     E: BlockExecutor<S, Tx = Bytes>,
 {
-    /// Default maximum transactions per block.
-    pub const DEFAULT_MAX_TXS: usize = 1000;
-
     /// Create a new proposal builder.
     ///
+    /// The builder admits every transaction until a filter is installed with
+    /// [`Self::with_filter`].
+    ///
     /// # Arguments
     ///
     /// * `state` - State database for execution lookups.
     /// * `mempool` - Transaction mempool to pull transactions from.
     /// * `snapshots` - Snapshot store for parent state lookup.
     /// * `executor` - Block executor for transaction execution.
-    pub const fn new(state: S, mempool: M, snapshots: SS, executor: E) -> Self {
-        Self { state, mempool, snapshots, executor, max_txs: Self::DEFAULT_MAX_TXS }
+    pub fn new(state: S, mempool: M, snapshots: SS, executor: E) -> Self {
+        Self {
+            state,
+            mempool,
+            snapshots,
+            executor,
+            max_txs: Self::DEFAULT_MAX_TXS,
+            classifier: TransactionClassifier::enabled(),
+            filter: AllowAll,
+        }
     }
+}
+
+impl<S, M, SS, E, F> ProposalBuilder<S, M, SS, E, F>
+where
+    S: StateDb,
+    M: Mempool,
+    SS: SnapshotStore<S>,
+    E: BlockExecutor<S, Tx = Bytes>,
+    F: TransactionFilter,
+{
+    /// Default maximum transactions per block.
+    pub const DEFAULT_MAX_TXS: usize = 1000;
 
     /// Set the maximum number of transactions per block.
     ///
@@ -72,11 +148,34 @@ where
         self
     }
 
+    /// Set the classifier used to label candidate transactions.
+    #[must_use]
+    pub fn with_classifier(mut self, classifier: TransactionClassifier) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
+    /// Replace the transaction filter consulted after classification.
+    #[must_use]
+    pub fn with_filter<G: TransactionFilter>(self, filter: G) -> ProposalBuilder<S, M, SS, E, G> {
+        ProposalBuilder {
+            state: self.state,
+            mempool: self.mempool,
+            snapshots: self.snapshots,
+            executor: self.executor,
+            max_txs: self.max_txs,
+            classifier: self.classifier,
+            filter,
+        }
+    }
+
     /// Build a block proposal from the given parent block.
     ///
     /// This method:
     /// 1. Retrieves the parent snapshot from the snapshot store.
-    /// 2. Builds a transaction batch from the mempool, excluding the parent's txs.
+    /// 2. Selects transactions with [`select_transactions`] up to the block gas
+    ///    limit, excluding the parent's txs and evicting any rejected by the
+    ///    [`TransactionFilter`].
     /// 3. Executes the batch against the parent state.
     /// 4. Computes the new state root from the execution outcome.
     /// 5. Constructs and returns the new block and its snapshot.
@@ -92,10 +191,10 @@ where
             .ok_or(ConsensusError::SnapshotNotFound(parent_digest))?;
 
         let excluded = self.collect_pending_tx_ids(parent_digest)?;
-        let txs = self.mempool.build(self.max_txs, &excluded);
-
         let height = parent.height + 1;
         let context = block_context(height, prevrandao);
+        let txs = self.select_txs(&excluded, &context);
+
         let txs_bytes: Vec<Bytes> = txs.iter().map(|tx| tx.bytes.clone()).collect();
         let outcome = self
             .executor
@@ -134,10 +233,10 @@ where
             .ok_or(ConsensusError::SnapshotNotFound(parent_digest))?;
 
         let excluded = self.collect_pending_tx_ids(parent_digest)?;
-        let txs = self.mempool.build(self.max_txs, &excluded);
-
         let height = parent.height + 1;
         let context = block_context(height, prevrandao);
+        let txs = self.select_txs(&excluded, &context);
+
         let txs_bytes: Vec<Bytes> = txs.iter().map(|tx| tx.bytes.clone()).collect();
         let outcome = self
             .executor
@@ -163,6 +262,19 @@ where
         Ok((block, snapshot))
     }
 
+    fn select_txs(&self, excluded: &BTreeSet<TxId>, context: &BlockContext) -> Vec<Tx> {
+        let base_fee = context.header.base_fee_per_gas.unwrap_or_default();
+        select_transactions(
+            &self.mempool,
+            excluded,
+            self.max_txs,
+            context.header.gas_limit,
+            base_fee,
+            &self.classifier,
+            &self.filter,
+        )
+    }
+
     fn tx_ids_from_block(&self, block: &Block) -> BTreeSet<TxId> {
         block.txs.iter().map(Tx::id).collect()
     }
@@ -298,6 +410,10 @@ mod tests {
                 .collect()
         }
 
+        fn best_transactions(&self, _gas_limit: u64, _base_fee: u64) -> impl Iterator<Item = Tx> {
+            self.txs.read().unwrap().values().cloned().collect::<Vec<_>>().into_iter()
+        }
+
         fn prune(&self, tx_ids: &[TxId]) {
             let mut txs = self.txs.write().unwrap();
             for id in tx_ids {
@@ -305,6 +421,10 @@ mod tests {
             }
         }
 
+        fn evict(&self, tx_ids: &[TxId]) {
+            self.prune(tx_ids);
+        }
+
         fn len(&self) -> usize {
             self.txs.read().unwrap().len()
         }
@@ -384,6 +504,7 @@ mod tests {
                 changes: ChangeSet::new(),
                 receipts: Vec::new(),
                 gas_used: txs.len() as u64 * 21000,
+                intent_receipts: Vec::new(),
             })
         }
 
@@ -543,6 +664,81 @@ mod tests {
         assert_eq!(block.txs.len(), 10);
     }
 
+    #[test]
+    fn proposal_builder_filter_excludes_rejected_txs() {
+        use k256::ecdsa::SigningKey;
+        use monmouth_domain::evm::Evm;
+        use monmouth_executor::classifier::precompiles;
+
+        use crate::filter::tests::DenySvmRouted;
+
+        let state = MockStateDb::new();
+        let mempool = MockMempool::new();
+        let snapshots = MockSnapshotStore::new();
+        let executor = MockExecutor;
+
+        let mut secret = [0u8; 32];
+        secret[31] = 7;
+        let key = SigningKey::from_bytes((&secret).into()).expect("valid key");
+        let svm_tx =
+            Evm::sign_eip1559_transfer(&key, 1, precompiles::SVM_ROUTER, U256::ZERO, 0, 21_000);
+        let evm_txs: Vec<_> = (1..=2)
+            .map(|nonce| {
+                let to = Address::repeat_byte(0x11);
+                Evm::sign_eip1559_transfer(&key, 1, to, U256::ZERO, nonce, 21_000)
+            })
+            .collect();
+        mempool.add(svm_tx.clone());
+        evm_txs.iter().cloned().for_each(|tx| mempool.add(tx));
+
+        let parent = parent_block();
+        let parent_digest = parent.commitment();
+        let parent_snapshot = Snapshot::new(
+            None,
+            MockStateDb::new(),
+            StateRoot(B256::ZERO),
+            ChangeSet::new(),
+            BTreeSet::new(),
+        );
+        snapshots.insert(parent_digest, parent_snapshot);
+
+        // The rejected tx does not take a slot: the block still fills to its limit.
+        let builder = ProposalBuilder::new(state, mempool.clone(), snapshots, executor)
+            .with_max_txs(2)
+            .with_filter(DenySvmRouted);
+        let (block, snapshot) = builder.build_proposal(&parent, B256::ZERO).unwrap();
+
+        assert_eq!(block.txs.len(), 2);
+        for tx in &evm_txs {
+            assert!(block.txs.contains(tx));
+            assert!(snapshot.tx_ids.contains(&tx.id()));
+        }
+        assert!(!snapshot.tx_ids.contains(&svm_tx.id()));
+        assert!(!mempool.txs.read().unwrap().contains_key(&svm_tx.id()));
+        assert_eq!(mempool.len(), 2);
+    }
+
+    #[test]
+    fn select_transactions_admits_undecodable_txs() {
+        use crate::filter::tests::DenySvmRouted;
+
+        let mempool = MockMempool::new();
+        let tx = Tx::new(vec![1, 2, 3].into());
+        mempool.add(tx.clone());
+
+        let selected = select_transactions(
+            &mempool,
+            &BTreeSet::new(),
+            10,
+            monmouth_config::DEFAULT_GAS_LIMIT,
+            0,
+            &TransactionClassifier::enabled(),
+            &DenySvmRouted,
+        );
+        assert_eq!(selected, vec![tx]);
+        assert_eq!(mempool.len(), 1);
+    }
+
     #[test]
     fn tx_id_computation() {
         let tx = Tx::new(vec![1, 2, 3, 4, 5].into());
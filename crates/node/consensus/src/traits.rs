@@ -4,6 +4,7 @@ use std::collections::BTreeSet;
 
 use alloy_primitives::B256;
 use monmouth_domain::{ConsensusDigest, StateRoot, Tx, TxId as DomainTxId};
+use monmouth_executor::IntentReceipt;
 use monmouth_qmdb::ChangeSet;
 use monmouth_traits::StateDb;
 
@@ -28,6 +29,8 @@ pub struct Snapshot<S> {
     pub changes: ChangeSet,
     /// Transaction IDs included in this snapshot's block.
     pub tx_ids: BTreeSet<TxId>,
+    /// Intents declared by this snapshot's block, persisted with it.
+    pub intent_receipts: Vec<IntentReceipt>,
 }
 
 impl<S> Snapshot<S> {
@@ -39,7 +42,14 @@ impl<S> Snapshot<S> {
         changes: ChangeSet,
         tx_ids: BTreeSet<TxId>,
     ) -> Self {
-        Self { parent, state, state_root, changes, tx_ids }
+        Self { parent, state, state_root, changes, tx_ids, intent_receipts: Vec::new() }
+    }
+
+    /// Attach the intents declared by the snapshot's block.
+    #[must_use]
+    pub fn with_intent_receipts(mut self, intent_receipts: Vec<IntentReceipt>) -> Self {
+        self.intent_receipts = intent_receipts;
+        self
     }
 }
 
@@ -58,9 +68,23 @@ pub trait Mempool: Clone + Send + Sync + 'static {
     /// `max_txs` limits the number of transactions returned.
     fn build(&self, max_txs: usize, excluded: &BTreeSet<TxId>) -> Vec<Tx>;
 
+    /// Executable transactions in block-building order.
+    ///
+    /// Transactions are yielded by descending effective tip at `base_fee`, ties
+    /// going to the earlier arrival, and a sender's transactions always follow
+    /// their nonce order. A transaction whose fee cap is below `base_fee` or whose
+    /// gas limit exceeds `gas_limit` is skipped together with the sender's later
+    /// nonces. The block builder consumes the iterator and stops once the block
+    /// is full.
+    fn best_transactions(&self, gas_limit: u64, base_fee: u64) -> impl Iterator<Item = Tx>;
+
     /// Remove finalized transactions from the mempool.
     fn prune(&self, tx_ids: &[TxId]);
 
+    /// Drop transactions that can never be included, such as ones that fail to
+    /// execute. Unlike [`prune`](Self::prune), their nonces are not consumed.
+    fn evict(&self, tx_ids: &[TxId]);
+
     /// Get the current number of pending transactions.
     fn len(&self) -> usize;
 
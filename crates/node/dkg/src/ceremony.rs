@@ -4,6 +4,7 @@
 
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+use commonware_cryptography::ed25519;
 use tracing::{debug, info, warn};
 
 use crate::{
@@ -12,10 +13,6 @@ use crate::{
     protocol::{DkgParticipant, ProtocolMessage, ProtocolMessageKind},
 };
 
-/// Phase 2 max timeout (collecting dealer messages and sending acks).
-const PHASE2_MAX_TIMEOUT_SECS: u64 = 120;
-/// Phase 4 max timeout (collecting dealer logs for finalization).
-const PHASE4_MAX_TIMEOUT_SECS: u64 = 120;
 /// Initial backoff delay for retries.
 const INITIAL_BACKOFF_MS: u64 = 100;
 /// Maximum backoff delay for retries.
@@ -184,11 +181,12 @@ impl DkgCeremony {
             "Phase 2: Collecting dealer messages and sending acks"
         );
 
-        let deadline = Instant::now() + Duration::from_secs(PHASE2_MAX_TIMEOUT_SECS);
+        let deadline = RoundDeadline::new(Instant::now(), self.config.round_timeout);
         let mut last_progress_log = Instant::now();
         let mut backoff = ExponentialBackoff::new();
 
-        while Instant::now() < deadline {
+        loop {
+            deadline.check(Instant::now(), "dealer messages", || participant.missing_dealers())?;
             self.receive_and_process(network, participant)?;
             self.send_outgoing(network, participant)?;
 
@@ -226,8 +224,6 @@ impl DkgCeremony {
 
             tokio::time::sleep(backoff.next_delay()).await;
         }
-
-        Err(DkgError::Timeout)
     }
 
     /// Phase 2.5: Wait for all nodes to signal ready.
@@ -248,11 +244,12 @@ impl DkgCeremony {
         participant.broadcast_ready();
         self.send_outgoing(network, participant)?;
 
-        let deadline = Instant::now() + Duration::from_secs(PHASE2_MAX_TIMEOUT_SECS);
+        let deadline = RoundDeadline::new(Instant::now(), self.config.round_timeout);
         let mut last_progress_log = Instant::now();
         let mut backoff = ExponentialBackoff::new();
 
-        while Instant::now() < deadline {
+        loop {
+            deadline.check(Instant::now(), "ready signals", || participant.missing_ready())?;
             self.receive_and_process(network, participant)?;
             self.send_outgoing(network, participant)?;
 
@@ -273,12 +270,6 @@ impl DkgCeremony {
 
             tokio::time::sleep(backoff.next_delay()).await;
         }
-
-        Err(DkgError::CeremonyFailed(format!(
-            "Phase 2.5 timeout: only {}/{} participants ready",
-            participant.ready_count(),
-            participant.total_participants()
-        )))
     }
 
     /// Phase 3: Finalize our dealer.
@@ -305,12 +296,13 @@ impl DkgCeremony {
             "Phase 4: Collecting dealer logs for finalization"
         );
 
-        let deadline = Instant::now() + Duration::from_secs(PHASE4_MAX_TIMEOUT_SECS);
+        let deadline = RoundDeadline::new(Instant::now(), self.config.round_timeout);
         let mut last_progress_log = Instant::now();
         let mut last_request_time = Instant::now() - Duration::from_secs(10); // Allow immediate first request
         let mut backoff = ExponentialBackoff::new();
 
-        while Instant::now() < deadline {
+        loop {
+            deadline.check(Instant::now(), "dealer logs", || participant.missing_dealer_logs())?;
             self.receive_and_process(network, participant)?;
             self.send_outgoing(network, participant)?;
 
@@ -349,12 +341,6 @@ impl DkgCeremony {
 
             tokio::time::sleep(backoff.next_delay()).await;
         }
-
-        Err(DkgError::CeremonyFailed(format!(
-            "Phase 4 timeout: only collected {}/{} dealer logs",
-            participant.dealer_log_count(),
-            participant.required_quorum()
-        )))
     }
 
     /// Check if this node is the leader (coordinator).
@@ -407,20 +393,57 @@ impl DkgCeremony {
     }
 
     /// Receive and process incoming messages.
+    ///
+    /// Returns [`DkgError::PeerAborted`] once a peer aborts the ceremony, after persisting the
+    /// aborted phase so a restart begins a fresh ceremony.
     fn receive_and_process(
         &self,
         network: &DkgNetwork,
         participant: &mut DkgParticipant,
     ) -> Result<(), DkgError> {
         for envelope in network.poll_incoming() {
-            if let Err(e) = participant.handle_message_bytes(&envelope.from, &envelope.payload) {
-                warn!(?e, "Failed to handle message");
+            match participant.handle_message_bytes(&envelope.from, &envelope.payload) {
+                Ok(()) => {}
+                Err(e @ DkgError::PeerAborted { .. }) => {
+                    participant.save_state(&self.config.data_dir)?;
+                    return Err(e);
+                }
+                Err(e) => warn!(?e, "Failed to handle message"),
             }
         }
         Ok(())
     }
 }
 
+/// Deadline for a single ceremony round.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RoundDeadline {
+    expires_at: Instant,
+}
+
+impl RoundDeadline {
+    /// Create a deadline that expires `timeout` after `start`.
+    pub(crate) fn new(start: Instant, timeout: Duration) -> Self {
+        Self { expires_at: start + timeout }
+    }
+
+    /// Fail with [`DkgError::RoundTimeout`] naming the `missing` participants if the
+    /// deadline has passed at `now`.
+    pub(crate) fn check(
+        &self,
+        now: Instant,
+        round: &'static str,
+        missing: impl FnOnce() -> Vec<ed25519::PublicKey>,
+    ) -> Result<(), DkgError> {
+        if now < self.expires_at {
+            return Ok(());
+        }
+        let missing = missing();
+        warn!(round, missing = ?missing, "DKG round timed out");
+        Err(DkgError::RoundTimeout { round, missing })
+    }
+}
+
 /// Exponential backoff helper for retry delays.
 struct ExponentialBackoff {
     current_ms: u64,
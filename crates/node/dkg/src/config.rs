@@ -21,11 +21,14 @@ pub struct DkgConfig {
     pub listen_addr: std::net::SocketAddr,
     /// Initial peers to connect to, as (public_key, address) pairs.
     pub bootstrap_peers: Vec<(ed25519::PublicKey, String)>,
-    /// Timeout duration for DKG protocol rounds.
-    pub timeout: Duration,
+    /// Deadline for each DKG protocol round before missing participants are reported.
+    pub round_timeout: Duration,
 }
 
 impl DkgConfig {
+    /// Default per-round deadline.
+    pub const DEFAULT_ROUND_TIMEOUT: Duration = Duration::from_secs(120);
+
     /// Returns the total number of participants (n).
     pub const fn n(&self) -> usize {
         self.participants.len()
@@ -70,7 +73,7 @@ mod tests {
             data_dir: PathBuf::from("/tmp/dkg-test"),
             listen_addr: "127.0.0.1:8000".parse::<SocketAddr>().unwrap(),
             bootstrap_peers: vec![],
-            timeout: Duration::from_secs(60),
+            round_timeout: Duration::from_secs(60),
         }
     }
 
@@ -92,7 +95,7 @@ mod tests {
             data_dir: PathBuf::from("/tmp/dkg-test"),
             listen_addr: "127.0.0.1:8000".parse::<SocketAddr>().unwrap(),
             bootstrap_peers: vec![],
-            timeout: Duration::from_secs(60),
+            round_timeout: Duration::from_secs(60),
         };
         assert_eq!(config.n(), 1);
     }
@@ -112,7 +115,7 @@ mod tests {
             data_dir: PathBuf::from("/tmp/dkg-test"),
             listen_addr: "127.0.0.1:8000".parse::<SocketAddr>().unwrap(),
             bootstrap_peers: vec![],
-            timeout: Duration::from_secs(60),
+            round_timeout: Duration::from_secs(60),
         };
         assert_eq!(config.n(), 100);
     }
@@ -135,7 +138,7 @@ mod tests {
             data_dir: PathBuf::from("/tmp/dkg-test"),
             listen_addr: "127.0.0.1:8000".parse::<SocketAddr>().unwrap(),
             bootstrap_peers: vec![],
-            timeout: Duration::from_secs(60),
+            round_timeout: Duration::from_secs(60),
         };
         assert_eq!(config.t(), 1);
     }
@@ -155,7 +158,7 @@ mod tests {
             data_dir: PathBuf::from("/tmp/dkg-test"),
             listen_addr: "127.0.0.1:8000".parse::<SocketAddr>().unwrap(),
             bootstrap_peers: vec![],
-            timeout: Duration::from_secs(60),
+            round_timeout: Duration::from_secs(60),
         };
         assert_eq!(config.t(), 67);
     }
@@ -193,7 +196,7 @@ mod tests {
             data_dir: PathBuf::from("/tmp/dkg-test-1"),
             listen_addr: "127.0.0.1:8001".parse::<SocketAddr>().unwrap(),
             bootstrap_peers: vec![],
-            timeout: Duration::from_secs(60),
+            round_timeout: Duration::from_secs(60),
         };
 
         let config2 = DkgConfig {
@@ -208,7 +211,7 @@ mod tests {
             data_dir: PathBuf::from("/tmp/dkg-test-2"),
             listen_addr: "127.0.0.1:8002".parse::<SocketAddr>().unwrap(),
             bootstrap_peers: vec![],
-            timeout: Duration::from_secs(60),
+            round_timeout: Duration::from_secs(60),
         };
 
         assert_ne!(config1.my_public_key(), config2.my_public_key());
@@ -256,7 +259,7 @@ mod tests {
             data_dir: PathBuf::from("/tmp/dkg-test"),
             listen_addr: "127.0.0.1:8000".parse::<SocketAddr>().unwrap(),
             bootstrap_peers: vec![],
-            timeout: Duration::from_secs(60),
+            round_timeout: Duration::from_secs(60),
         };
 
         assert_eq!(config.t(), config.n() as u32);
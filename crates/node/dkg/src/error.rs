@@ -1,3 +1,4 @@
+use commonware_cryptography::ed25519;
 use thiserror::Error;
 
 /// Errors that can occur during Distributed Key Generation (DKG) ceremonies.
@@ -104,6 +105,31 @@ pub enum DkgError {
     /// Invalid message format or content.
     #[error("Invalid message: {0}")]
     InvalidMessage(String),
+
+    /// A ceremony round did not complete before its deadline.
+    #[error("Timed out waiting for {round} from {} participant(s)", missing.len())]
+    RoundTimeout {
+        /// The round that timed out.
+        round: &'static str,
+        /// Participants whose messages for the round never arrived.
+        missing: Vec<ed25519::PublicKey>,
+    },
+
+    /// The local participant aborted the ceremony.
+    #[error("DKG ceremony aborted: {reason}")]
+    Aborted {
+        /// Reason given when aborting.
+        reason: String,
+    },
+
+    /// A peer aborted the ceremony.
+    #[error("Participant {participant} aborted the DKG ceremony: {reason}")]
+    PeerAborted {
+        /// The aborting participant.
+        participant: String,
+        /// Reason given by the peer.
+        reason: String,
+    },
 }
 
 #[cfg(test)]
@@ -217,6 +243,24 @@ mod tests {
         assert_eq!(err.to_string(), "Invalid message: malformed data");
     }
 
+    #[test]
+    fn test_round_timeout_display() {
+        use commonware_cryptography::Signer;
+
+        let missing = vec![ed25519::PrivateKey::from_seed(7).public_key()];
+        let err = DkgError::RoundTimeout { round: "dealer logs", missing };
+        assert_eq!(err.to_string(), "Timed out waiting for dealer logs from 1 participant(s)");
+    }
+
+    #[test]
+    fn test_peer_aborted_display() {
+        let err = DkgError::PeerAborted {
+            participant: "peer1".to_string(),
+            reason: "shutting down".to_string(),
+        };
+        assert_eq!(err.to_string(), "Participant peer1 aborted the DKG ceremony: shutting down");
+    }
+
     #[test]
     fn test_error_is_send_sync() {
         fn assert_send_sync<T: Send + Sync>() {}
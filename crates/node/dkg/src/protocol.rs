@@ -79,6 +79,18 @@ impl CeremonySession {
     }
 }
 
+/// Truncate an abort reason to at most [`MAX_ABORT_REASON_LEN`] bytes on a char boundary.
+fn truncate_reason(reason: &str) -> &str {
+    if reason.len() <= MAX_ABORT_REASON_LEN {
+        return reason;
+    }
+    let mut end = MAX_ABORT_REASON_LEN;
+    while !reason.is_char_boundary(end) {
+        end -= 1;
+    }
+    &reason[..end]
+}
+
 /// Compute SHA256 hash of message bytes for deduplication.
 fn compute_message_hash(bytes: &[u8]) -> [u8; 32] {
     let mut hasher = Sha256::default();
@@ -91,6 +103,9 @@ fn compute_message_hash(bytes: &[u8]) -> [u8; 32] {
 
 use crate::{DkgConfig, DkgError, DkgOutput, DkgPhase, PersistedDkgState};
 
+/// Maximum length in bytes of the reason carried by an abort message.
+const MAX_ABORT_REASON_LEN: usize = 256;
+
 /// Inner message types for the DKG protocol (without session binding).
 #[derive(Debug, Clone)]
 pub enum ProtocolMessageKind {
@@ -134,6 +149,13 @@ pub enum ProtocolMessageKind {
         /// The player's public key.
         player: ed25519::PublicKey,
     },
+    /// Abort signal indicating a participant has left the ceremony.
+    Abort {
+        /// The aborting participant's public key.
+        participant: ed25519::PublicKey,
+        /// Human-readable reason for the abort.
+        reason: String,
+    },
 }
 
 /// Message envelope that wraps protocol messages with session binding.
@@ -209,6 +231,13 @@ impl ProtocolMessage {
                 buf.push(6u8);
                 player.write(&mut buf);
             }
+            ProtocolMessageKind::Abort { participant, reason } => {
+                buf.push(7u8);
+                participant.write(&mut buf);
+                let reason = truncate_reason(reason);
+                (reason.len() as u32).write(&mut buf);
+                buf.extend_from_slice(reason.as_bytes());
+            }
         }
         buf
     }
@@ -283,6 +312,19 @@ impl ProtocolMessage {
                 let player = ed25519::PublicKey::read(&mut reader)?;
                 ProtocolMessageKind::Ready { player }
             }
+            7 => {
+                let participant = ed25519::PublicKey::read(&mut reader)?;
+                let len = u32::read(&mut reader)? as usize;
+                if len > MAX_ABORT_REASON_LEN {
+                    return Err(commonware_codec::Error::InvalidLength(len));
+                }
+                if reader.len() < len {
+                    return Err(commonware_codec::Error::EndOfBuffer);
+                }
+                let reason = String::from_utf8_lossy(&reader[..len]).into_owned();
+                reader = &reader[len..];
+                ProtocolMessageKind::Abort { participant, reason }
+            }
             _ => return Err(commonware_codec::Error::InvalidEnum(tag)),
         };
 
@@ -533,6 +575,11 @@ impl DkgParticipant {
             return Err(DkgError::UnknownSender { sender: format!("{:?}", from) });
         }
 
+        if self.is_aborted() {
+            debug!(?from, "Ignoring message for aborted ceremony");
+            return Ok(());
+        }
+
         match msg.kind {
             ProtocolMessageKind::DealerPublic { dealer, msg } => {
                 if from != &dealer {
@@ -657,6 +704,20 @@ impl DkgParticipant {
                 debug!(?player, "Received ready signal");
                 self.ready_players.insert(player);
             }
+            ProtocolMessageKind::Abort { participant, reason } => {
+                if from != &participant {
+                    return Err(DkgError::SenderMismatch {
+                        expected: format!("{:?}", participant),
+                        actual: format!("{:?}", from),
+                    });
+                }
+                warn!(?participant, %reason, "Peer aborted the DKG ceremony");
+                self.discard_partial_state();
+                return Err(DkgError::PeerAborted {
+                    participant: format!("{:?}", participant),
+                    reason,
+                });
+            }
         }
         Ok(())
     }
@@ -744,6 +805,42 @@ impl DkgParticipant {
         self.sent_ready = true;
     }
 
+    /// Abort the ceremony.
+    ///
+    /// Discards any partial dealer and player state, moves to [`DkgPhase::Aborted`] and queues
+    /// an abort message for all peers. No output can be produced afterwards. Aborting a
+    /// ceremony that has already finished or aborted is a no-op.
+    pub fn abort(&mut self, reason: DkgError) {
+        if self.finalized || self.is_aborted() {
+            return;
+        }
+        let reason = reason.to_string();
+        warn!(validator_index = self.config.validator_index, %reason, "Aborting DKG ceremony");
+        self.discard_partial_state();
+        let ceremony_id = self.ceremony_id();
+        self.outgoing.push((
+            None, // broadcast
+            ProtocolMessage::new(
+                ceremony_id,
+                ProtocolMessageKind::Abort { participant: self.config.my_public_key(), reason },
+            ),
+        ));
+    }
+
+    /// Returns true if the ceremony was aborted locally or by a peer.
+    pub const fn is_aborted(&self) -> bool {
+        matches!(self.current_phase, DkgPhase::Aborted)
+    }
+
+    /// Drop all partial key material and enter the aborted phase.
+    fn discard_partial_state(&mut self) {
+        self.player = None;
+        self.dealer = None;
+        self.dealer_priv_msgs.clear();
+        self.outgoing.clear();
+        self.current_phase = DkgPhase::Aborted;
+    }
+
     /// Check if all participants have signaled ready.
     pub fn all_ready(&self) -> bool {
         self.ready_players.len() >= self.config.n()
@@ -766,6 +863,10 @@ impl DkgParticipant {
             return Err(DkgError::CeremonyFailed("Already finalized".into()));
         }
 
+        if self.is_aborted() {
+            return Err(DkgError::Aborted { reason: "ceremony was aborted".into() });
+        }
+
         if !self.can_finalize() {
             return Err(DkgError::CeremonyFailed(format!(
                 "Not enough dealer logs: {} < {}",
@@ -907,6 +1008,28 @@ impl DkgParticipant {
         self.acks_sent.len()
     }
 
+    /// Participants we have not yet received both dealer messages from.
+    pub fn missing_dealers(&self) -> Vec<ed25519::PublicKey> {
+        self.missing(|pk| {
+            self.dealer_pub_msgs.contains_key(pk) && self.dealer_priv_msgs.contains_key(pk)
+        })
+    }
+
+    /// Participants that have not yet signaled ready.
+    pub fn missing_ready(&self) -> Vec<ed25519::PublicKey> {
+        self.missing(|pk| self.ready_players.contains(pk))
+    }
+
+    /// Participants whose dealer log we have not yet collected.
+    pub fn missing_dealer_logs(&self) -> Vec<ed25519::PublicKey> {
+        self.missing(|pk| self.dealer_logs.contains_key(pk))
+    }
+
+    /// Participants, in configuration order, for which `received` is false.
+    fn missing(&self, received: impl Fn(&ed25519::PublicKey) -> bool) -> Vec<ed25519::PublicKey> {
+        self.config.participants.iter().filter(|pk| !received(pk)).cloned().collect()
+    }
+
     /// True if we've received pub+priv messages from all n participants.
     pub fn received_all_dealer_messages(&self) -> bool {
         self.received_dealer_count() >= self.config.n()
@@ -980,6 +1103,12 @@ impl DkgParticipant {
         let state = PersistedDkgState::load(&config.data_dir)?;
         let persisted_session = state.session()?;
 
+        if state.phase == DkgPhase::Aborted {
+            info!("Previous ceremony was aborted, clearing old state");
+            PersistedDkgState::clear(&config.data_dir)?;
+            return Ok(None);
+        }
+
         let expected_session =
             CeremonySession::new(config.chain_id, &config.participants, timestamp_nanos);
 
@@ -23,6 +23,8 @@ pub enum DkgPhase {
     Completed,
     /// DKG failed.
     Failed,
+    /// DKG was aborted by this participant or a peer.
+    Aborted,
 }
 
 impl std::fmt::Display for DkgPhase {
@@ -35,6 +37,7 @@ impl std::fmt::Display for DkgPhase {
             Self::CollectingLogs => write!(f, "CollectingLogs"),
             Self::Completed => write!(f, "Completed"),
             Self::Failed => write!(f, "Failed"),
+            Self::Aborted => write!(f, "Aborted"),
         }
     }
 }
@@ -1,10 +1,16 @@
 //! Integration tests for the interactive DKG protocol.
 
-use std::{path::PathBuf, time::Duration};
+use std::{
+    path::PathBuf,
+    time::{Duration, Instant},
+};
 
 use commonware_cryptography::{Signer as _, ed25519};
 
-use crate::{DkgConfig, DkgParticipant, ProtocolMessage, ProtocolMessageKind};
+use crate::{
+    DkgConfig, DkgError, DkgParticipant, DkgPhase, ProtocolMessage, ProtocolMessageKind,
+    ceremony::RoundDeadline,
+};
 
 const TEST_TIMESTAMP: u64 = 1_234_567_890_000_000_000;
 
@@ -34,7 +40,7 @@ fn make_test_config(keys: &[ed25519::PrivateKey], index: usize, base_port: u16)
         data_dir: PathBuf::from(format!("/tmp/dkg-test-{}", index)),
         listen_addr: format!("127.0.0.1:{}", base_port + index as u16).parse().unwrap(),
         bootstrap_peers,
-        timeout: Duration::from_secs(60),
+        round_timeout: Duration::from_secs(60),
     }
 }
 
@@ -360,3 +366,163 @@ fn test_duplicate_message_rejection() {
     let result2 = participant1.handle_message_bytes(&from_pk, &bytes);
     assert!(result2.is_ok(), "duplicate should be silently ignored");
 }
+
+#[test]
+fn test_abort_message_serialization() {
+    let keys = generate_test_keys(3, 42);
+    let msg = ProtocolMessage::new(
+        [7u8; 32],
+        ProtocolMessageKind::Abort { participant: keys[1].public_key(), reason: "x".repeat(1024) },
+    );
+
+    let decoded = ProtocolMessage::from_bytes(&msg.to_bytes(), 2).expect("should decode abort");
+
+    assert_eq!(decoded.session_id, Some([7u8; 32]));
+    match decoded.kind {
+        ProtocolMessageKind::Abort { participant, reason } => {
+            assert_eq!(participant, keys[1].public_key());
+            assert_eq!(reason.len(), 256, "reason should be truncated");
+        }
+        other => panic!("unexpected message kind: {:?}", other),
+    }
+}
+
+#[test]
+fn test_three_party_abort() {
+    let keys = generate_test_keys(3, 42);
+
+    let mut participants: Vec<_> = (0..keys.len())
+        .map(|i| {
+            let config = make_test_config(&keys, i, 40600 + (i as u16) * 100);
+            DkgParticipant::new(config, TEST_TIMESTAMP).expect("should create participant")
+        })
+        .collect();
+
+    // Exchange dealer messages so every participant holds partial shares.
+    let mut messages: Vec<(usize, Option<ed25519::PublicKey>, ProtocolMessage)> = Vec::new();
+    for (i, p) in participants.iter_mut().enumerate() {
+        p.start_dealer().expect("should start dealer");
+        for (target, msg) in p.take_outgoing() {
+            messages.push((i, target, msg));
+        }
+    }
+    for (from_idx, target, msg) in messages {
+        let from_pk = keys[from_idx].public_key();
+        for (i, p) in participants.iter_mut().enumerate() {
+            if i != from_idx && target.as_ref().is_none_or(|pk| pk == &keys[i].public_key()) {
+                let _ = p.handle_message(&from_pk, msg.clone());
+            }
+        }
+    }
+    for p in participants.iter_mut() {
+        p.take_outgoing();
+    }
+
+    // Participant 2 aborts mid-ceremony.
+    participants[2].abort(DkgError::Timeout);
+    assert!(participants[2].is_aborted());
+    assert_eq!(participants[2].current_phase(), DkgPhase::Aborted);
+
+    let outgoing = participants[2].take_outgoing();
+    assert_eq!(outgoing.len(), 1, "abort should queue a single broadcast");
+    let (target, abort_msg) = &outgoing[0];
+    assert!(target.is_none());
+    assert!(matches!(abort_msg.kind, ProtocolMessageKind::Abort { .. }));
+
+    // Aborting again is a no-op.
+    participants[2].abort(DkgError::Timeout);
+    assert!(participants[2].take_outgoing().is_empty());
+
+    // The remaining participants observe the abort as a typed error.
+    let bytes = abort_msg.to_bytes();
+    let aborter = keys[2].public_key();
+    for p in participants.iter_mut().take(2) {
+        let err = p.handle_message_bytes(&aborter, &bytes).expect_err("should surface abort");
+        match err {
+            DkgError::PeerAborted { participant, reason } => {
+                assert_eq!(participant, format!("{:?}", aborter));
+                assert_eq!(reason, DkgError::Timeout.to_string());
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert!(p.is_aborted());
+    }
+
+    // No participant can produce output after the abort.
+    for p in participants.iter_mut() {
+        assert!(matches!(p.finalize(), Err(DkgError::Aborted { .. })));
+    }
+}
+
+#[test]
+fn test_abort_from_spoofed_sender_rejected() {
+    let keys = generate_test_keys(3, 42);
+    let mut participant =
+        DkgParticipant::new(make_test_config(&keys, 0, 40900), TEST_TIMESTAMP).expect("create p0");
+
+    let msg = ProtocolMessage::new(
+        participant.ceremony_id(),
+        ProtocolMessageKind::Abort { participant: keys[2].public_key(), reason: "spoof".into() },
+    );
+
+    let result = participant.handle_message(&keys[1].public_key(), msg);
+
+    assert!(matches!(result, Err(DkgError::SenderMismatch { .. })));
+    assert!(!participant.is_aborted());
+}
+
+#[test]
+fn test_round_timeout_names_withheld_participant() {
+    let keys = generate_test_keys(3, 42);
+    let withheld = 1;
+
+    let mut participants: Vec<_> = (0..keys.len())
+        .map(|i| {
+            let config = make_test_config(&keys, i, 41000 + (i as u16) * 100);
+            DkgParticipant::new(config, TEST_TIMESTAMP).expect("should create participant")
+        })
+        .collect();
+
+    // Every dealer starts, but participant 1's round-1 messages never leave the node.
+    let mut messages: Vec<(usize, Option<ed25519::PublicKey>, ProtocolMessage)> = Vec::new();
+    for (i, p) in participants.iter_mut().enumerate() {
+        p.start_dealer().expect("should start dealer");
+        for (target, msg) in p.take_outgoing() {
+            if i != withheld {
+                messages.push((i, target, msg));
+            }
+        }
+    }
+    for (from_idx, target, msg) in messages {
+        let from_pk = keys[from_idx].public_key();
+        for (i, p) in participants.iter_mut().enumerate() {
+            if i != from_idx && target.as_ref().is_none_or(|pk| pk == &keys[i].public_key()) {
+                let _ = p.handle_message(&from_pk, msg.clone());
+            }
+        }
+    }
+
+    // Drive a simulated clock instead of waiting for the real deadline.
+    let timeout = Duration::from_secs(120);
+    let start = Instant::now();
+    let deadline = RoundDeadline::new(start, timeout);
+    let observer = &participants[0];
+
+    deadline
+        .check(start + timeout / 2, "dealer messages", || observer.missing_dealers())
+        .expect("deadline should not have elapsed");
+
+    let err = deadline
+        .check(start + timeout, "dealer messages", || observer.missing_dealers())
+        .expect_err("deadline should have elapsed");
+    match err {
+        DkgError::RoundTimeout { round, missing } => {
+            assert_eq!(round, "dealer messages");
+            assert_eq!(missing, vec![keys[withheld].public_key()]);
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+
+    // The withholding participant still received every other dealer's messages.
+    assert!(participants[withheld].missing_dealers().is_empty());
+}
@@ -1,11 +1,12 @@
 //! Block types
 
 use alloy_evm::revm::primitives::{B256, keccak256};
+use alloy_trie::root::ordered_trie_root_with_encoder;
 use bytes::{Buf, BufMut};
 use commonware_codec::{Encode, EncodeSize, Error as CodecError, RangeCfg, Read, ReadExt, Write};
 use commonware_cryptography::{Committable, Digestible, Hasher as _, Sha256};
 
-use crate::{BlockId, Idents, StateRoot, Tx, TxCfg};
+use crate::{BlockId, ForkPoint, Idents, StateRoot, Tx, TxCfg};
 
 #[derive(Clone, Copy, Debug)]
 /// Configuration used when decoding blocks and their transactions.
@@ -36,6 +37,31 @@ impl Block {
     pub fn id(&self) -> BlockId {
         BlockId(keccak256(self.encode()))
     }
+
+    /// Compute the Ethereum transactions root over this block's transactions.
+    ///
+    /// This is the root of the trie keyed by each transaction's RLP-encoded index
+    /// with its EIP-2718 encoding as the value.
+    pub fn compute_transactions_root(&self) -> B256 {
+        ordered_trie_root_with_encoder(&self.txs, |tx, buf| buf.extend_from_slice(&tx.bytes))
+    }
+
+    /// Whether `expected` is the transactions root of this block's transactions.
+    pub fn verify_transactions_root(&self, expected: B256) -> bool {
+        self.compute_transactions_root() == expected
+    }
+
+    /// Genesis block committing to `state_root`.
+    ///
+    /// Without a fork point the genesis block sits at height zero under a zero
+    /// parent; with one it takes the fork's height and references its parent.
+    pub const fn genesis(state_root: StateRoot, fork: Option<ForkPoint>) -> Self {
+        let (parent, height) = match fork {
+            Some(fork) => (fork.parent, fork.height),
+            None => (BlockId(B256::ZERO), 0),
+        };
+        Self { parent, height, prevrandao: B256::ZERO, state_root, txs: Vec::new() }
+    }
 }
 
 fn digest_for_block_id(id: &BlockId) -> crate::ConsensusDigest {
@@ -107,7 +133,7 @@ impl Read for Block {
 
 #[cfg(test)]
 mod tests {
-    use alloy_primitives::Bytes;
+    use alloy_primitives::{Bytes, b256};
     use commonware_codec::Decode;
     use commonware_cryptography::Committable as _;
 
@@ -159,6 +185,31 @@ mod tests {
         assert_ne!(block1.id(), block2.id());
     }
 
+    #[test]
+    fn genesis_defaults_to_height_zero() {
+        let genesis = Block::genesis(StateRoot(B256::repeat_byte(0xcd)), None);
+        assert_eq!(genesis.height, 0);
+        assert_eq!(genesis.parent, BlockId(B256::ZERO));
+    }
+
+    #[test]
+    fn forked_genesis_continues_numbering() {
+        let fork = ForkPoint::new(BlockId(B256::repeat_byte(0x42)), 1000);
+        let genesis = Block::genesis(StateRoot(B256::repeat_byte(0xcd)), Some(fork));
+        assert_eq!(genesis.height, 1000);
+        assert_eq!(genesis.parent, fork.parent);
+
+        let first = Block {
+            parent: genesis.id(),
+            height: genesis.height + 1,
+            prevrandao: B256::ZERO,
+            state_root: genesis.state_root,
+            txs: Vec::new(),
+        };
+        assert_eq!(first.height, 1001);
+        assert_eq!(first.parent, genesis.id());
+    }
+
     #[test]
     fn block_commitment_matches_digest() {
         let block = sample_block();
@@ -173,6 +224,30 @@ mod tests {
         assert_eq!(block, decoded);
     }
 
+    #[test]
+    fn two_transaction_block_roundtrip_preserves_state_root() {
+        let block = Block {
+            txs: vec![
+                Tx::new(Bytes::from_static(&[0x02, 0x01])),
+                Tx::new(Bytes::from_static(&[0x02, 0x02, 0x03])),
+            ],
+            ..sample_block()
+        };
+
+        let decoded = Block::decode_cfg(block.encode(), &default_block_cfg()).expect("decode");
+
+        assert_eq!(decoded.state_root, block.state_root);
+        assert_eq!(decoded.txs.len(), 2);
+        assert_eq!(decoded, block);
+    }
+
+    #[test]
+    fn truncated_block_fails_to_decode() {
+        let mut encoded = sample_block().encode().to_vec();
+        encoded.pop();
+        assert!(Block::decode_cfg(encoded.as_slice(), &default_block_cfg()).is_err());
+    }
+
     #[test]
     fn block_encode_size_matches_encoded() {
         let block = sample_block();
@@ -193,6 +268,51 @@ mod tests {
         assert_eq!(block, decoded);
     }
 
+    #[test]
+    fn empty_block_has_empty_transactions_root() {
+        let block = Block { txs: Vec::new(), ..sample_block() };
+        assert_eq!(block.compute_transactions_root(), alloy_trie::EMPTY_ROOT_HASH);
+        assert!(block.verify_transactions_root(alloy_trie::EMPTY_ROOT_HASH));
+    }
+
+    #[test]
+    fn transactions_root_is_stable_and_verifies() {
+        let first = Tx::new(Bytes::from_static(&[0x02, 0x01]));
+        let second = Tx::new(Bytes::from_static(&[0x02, 0x02, 0x03]));
+        let block = Block { txs: vec![first.clone(), second.clone()], ..sample_block() };
+
+        let root = block.compute_transactions_root();
+
+        assert_ne!(root, alloy_trie::EMPTY_ROOT_HASH);
+        assert_eq!(root, block.clone().compute_transactions_root());
+        assert!(block.verify_transactions_root(root));
+        let reordered = Block { txs: vec![second, first], ..block };
+        assert!(!reordered.verify_transactions_root(root));
+    }
+
+    #[test]
+    fn transactions_root_matches_known_vectors() {
+        // One leaf at key rlp(0) = 0x80: keccak256(rlp([hp([8, 0], leaf), 0xdeadbeef])).
+        let single = sample_block();
+        assert_eq!(
+            single.compute_transactions_root(),
+            b256!("0x0edc0f786de32a755fbbe5750f60508319fc30bd33c435ec405fc57be7a20229")
+        );
+
+        // Keys 0x80 and 0x01 split at a branch holding both leaves inline.
+        let pair = Block {
+            txs: vec![
+                Tx::new(Bytes::from_static(&[0x02, 0x01])),
+                Tx::new(Bytes::from_static(&[0x02, 0x02, 0x03])),
+            ],
+            ..sample_block()
+        };
+        assert_eq!(
+            pair.compute_transactions_root(),
+            b256!("0x63738808387fc65583a7131939d3958758c98348854b2b576c8afa4bd027d7d6")
+        );
+    }
+
     #[test]
     fn block_heightable() {
         use commonware_consensus::Heightable as _;
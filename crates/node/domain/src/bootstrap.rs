@@ -2,10 +2,10 @@
 
 use std::{path::Path, str::FromStr};
 
-use alloy_evm::revm::primitives::{Address, U256};
+use alloy_evm::revm::primitives::{Address, B256, U256};
 use serde::{Deserialize, Serialize};
 
-use crate::Tx;
+use crate::{BlockId, Tx};
 
 /// Bootstrap configuration for genesis state and initial transactions.
 #[derive(Clone, Debug)]
@@ -14,6 +14,33 @@ pub struct BootstrapConfig {
     pub genesis_alloc: Vec<(Address, U256)>,
     /// Transactions to execute during bootstrap.
     pub bootstrap_txs: Vec<Tx>,
+    /// Point in existing history the genesis block continues from, if forking.
+    pub fork: Option<ForkPoint>,
+    /// Recipients of collected priority fees with their basis-point weights;
+    /// empty pays them all to the proposer.
+    ///
+    /// Part of the chain spec: every validator must apply the same split.
+    pub priority_fee_split: Vec<(Address, u16)>,
+}
+
+/// Point in exported history a private fork continues from.
+///
+/// The genesis block takes `height` and references `parent`, so the first
+/// produced block is numbered `height + 1` instead of restarting at one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ForkPoint {
+    /// Hash of the exported block the fork chains onto.
+    pub parent: BlockId,
+    /// Height assigned to the genesis block.
+    pub height: u64,
+}
+
+impl ForkPoint {
+    /// Create a fork point.
+    #[must_use]
+    pub const fn new(parent: BlockId, height: u64) -> Self {
+        Self { parent, height }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -21,6 +48,22 @@ struct GenesisJson {
     chain_id: u64,
     timestamp: u64,
     allocations: Vec<AllocationJson>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fork: Option<ForkJson>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    priority_fee_split: Vec<FeeShareJson>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FeeShareJson {
+    recipient: String,
+    weight_bps: u16,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ForkJson {
+    parent: String,
+    height: u64,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -33,7 +76,21 @@ impl BootstrapConfig {
     /// Create a new bootstrap configuration.
     #[must_use]
     pub const fn new(genesis_alloc: Vec<(Address, U256)>, bootstrap_txs: Vec<Tx>) -> Self {
-        Self { genesis_alloc, bootstrap_txs }
+        Self { genesis_alloc, bootstrap_txs, fork: None, priority_fee_split: Vec::new() }
+    }
+
+    /// Continue numbering from `fork` instead of starting at height zero.
+    #[must_use]
+    pub const fn with_fork(mut self, fork: ForkPoint) -> Self {
+        self.fork = Some(fork);
+        self
+    }
+
+    /// Split collected priority fees between `split` recipients by basis-point weight.
+    #[must_use]
+    pub fn with_priority_fee_split(mut self, split: Vec<(Address, u16)>) -> Self {
+        self.priority_fee_split = split;
+        self
     }
 
     /// Load bootstrap configuration from a genesis JSON file.
@@ -50,7 +107,23 @@ impl BootstrapConfig {
             genesis_alloc.push((address, balance));
         }
 
-        Ok(Self { genesis_alloc, bootstrap_txs: Vec::new() })
+        let fork = genesis
+            .fork
+            .map(|fork| {
+                let parent = B256::from_str(&fork.parent)
+                    .map_err(|e| BootstrapError::Parse(format!("invalid fork parent: {}", e)))?;
+                Ok::<_, BootstrapError>(ForkPoint::new(BlockId(parent), fork.height))
+            })
+            .transpose()?;
+
+        let mut priority_fee_split = Vec::with_capacity(genesis.priority_fee_split.len());
+        for share in genesis.priority_fee_split {
+            let recipient = Address::from_str(&share.recipient)
+                .map_err(|e| BootstrapError::Parse(format!("invalid fee recipient: {}", e)))?;
+            priority_fee_split.push((recipient, share.weight_bps));
+        }
+
+        Ok(Self { genesis_alloc, bootstrap_txs: Vec::new(), fork, priority_fee_split })
     }
 }
 
@@ -88,3 +161,65 @@ impl From<serde_json::Error> for BootstrapError {
         Self::Json(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load_json(name: &str, json: &str) -> Result<BootstrapConfig, BootstrapError> {
+        let path = std::env::temp_dir().join(format!("monmouth-bootstrap-{name}.json"));
+        std::fs::write(&path, json).unwrap();
+        let result = BootstrapConfig::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        result
+    }
+
+    #[test]
+    fn load_without_fork_starts_at_zero() {
+        let config = load_json(
+            "no-fork",
+            r#"{"chain_id":1,"timestamp":0,"allocations":[{"address":"0x0000000000000000000000000000000000000001","balance":"100"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(config.genesis_alloc.len(), 1);
+        assert_eq!(config.fork, None);
+        assert!(config.priority_fee_split.is_empty());
+    }
+
+    #[test]
+    fn load_reads_priority_fee_split() {
+        let proposer = Address::repeat_byte(0x01);
+        let treasury = Address::repeat_byte(0x02);
+        let config = load_json(
+            "fee-split",
+            &format!(
+                r#"{{"chain_id":1,"timestamp":0,"allocations":[],"priority_fee_split":[{{"recipient":"{proposer}","weight_bps":7000}},{{"recipient":"{treasury}","weight_bps":3000}}]}}"#
+            ),
+        )
+        .unwrap();
+        assert_eq!(config.priority_fee_split, vec![(proposer, 7_000), (treasury, 3_000)]);
+    }
+
+    #[test]
+    fn load_reads_fork_point() {
+        let parent = B256::repeat_byte(0x42);
+        let config = load_json(
+            "fork",
+            &format!(
+                r#"{{"chain_id":1,"timestamp":0,"allocations":[],"fork":{{"parent":"{parent}","height":1000}}}}"#
+            ),
+        )
+        .unwrap();
+        assert_eq!(config.fork, Some(ForkPoint::new(BlockId(parent), 1000)));
+    }
+
+    #[test]
+    fn load_rejects_invalid_fork_parent() {
+        let err = load_json(
+            "bad-fork",
+            r#"{"chain_id":1,"timestamp":0,"allocations":[],"fork":{"parent":"0x12","height":1}}"#,
+        )
+        .unwrap_err();
+        assert!(matches!(err, BootstrapError::Parse(_)));
+    }
+}
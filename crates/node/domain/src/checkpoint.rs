@@ -0,0 +1,366 @@
+//! Threshold-signed state checkpoints for bootstrapping new nodes.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use alloy_primitives::B256;
+use bytes::{Buf, BufMut};
+use commonware_codec::{EncodeSize, Error as CodecError, Read, ReadExt as _, Write};
+use commonware_cryptography::bls12381::primitives::{
+    group::Share,
+    ops,
+    sharing::Sharing,
+    variant::{PartialSignature, Variant},
+};
+use parking_lot::RwLock;
+
+use crate::{BlockId, StateRoot};
+
+/// Namespace checkpoint signatures are domain-separated under.
+pub const CHECKPOINT_NAMESPACE: &[u8] = b"_MONMOUTH_CHECKPOINT";
+
+/// Default number of checkpoint heights a [`CheckpointStore`] retains.
+pub const DEFAULT_CHECKPOINT_RETENTION: usize = 16;
+
+/// Finalized `(height, block hash, state root)` triple a new node can start from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// Height of the checkpointed block.
+    pub height: u64,
+    /// Hash of the checkpointed block.
+    pub block_hash: BlockId,
+    /// State root after executing the checkpointed block.
+    pub state_root: StateRoot,
+}
+
+impl Checkpoint {
+    /// Create a checkpoint.
+    #[must_use]
+    pub const fn new(height: u64, block_hash: BlockId, state_root: StateRoot) -> Self {
+        Self { height, block_hash, state_root }
+    }
+
+    /// Returns `true` if a checkpoint should be taken at `height` given `interval`.
+    ///
+    /// An interval of zero disables checkpoints.
+    #[must_use]
+    pub const fn is_due(height: u64, interval: u64) -> bool {
+        interval != 0 && height != 0 && height.is_multiple_of(interval)
+    }
+
+    /// Bytes signed by validators: `height (BE) || block_hash || state_root`.
+    #[must_use]
+    pub fn message(&self) -> [u8; 72] {
+        let mut message = [0u8; 72];
+        message[..8].copy_from_slice(&self.height.to_be_bytes());
+        message[8..40].copy_from_slice(self.block_hash.0.as_slice());
+        message[40..].copy_from_slice(self.state_root.0.as_slice());
+        message
+    }
+
+    /// Produce this validator's partial signature over the checkpoint.
+    pub fn sign_partial<V: Variant>(&self, share: &Share) -> PartialSignature<V> {
+        ops::partial_sign_message::<V>(share, Some(CHECKPOINT_NAMESPACE), &self.message())
+    }
+
+    /// Combine a threshold of partial signatures into a [`SignedCheckpoint`].
+    ///
+    /// Returns `None` if too few partials are supplied or recovery fails.
+    pub fn aggregate<'a, V: Variant>(
+        self,
+        sharing: &Sharing<V>,
+        partials: impl IntoIterator<Item = &'a PartialSignature<V>>,
+    ) -> Option<SignedCheckpoint<V>> {
+        let signature = ops::threshold_signature_recover::<V, _>(sharing, partials).ok()?;
+        Some(SignedCheckpoint { checkpoint: self, signature })
+    }
+}
+
+impl Write for Checkpoint {
+    fn write(&self, buf: &mut impl BufMut) {
+        self.height.write(buf);
+        self.block_hash.write(buf);
+        self.state_root.write(buf);
+    }
+}
+
+impl EncodeSize for Checkpoint {
+    fn encode_size(&self) -> usize {
+        self.height.encode_size() + self.block_hash.encode_size() + self.state_root.encode_size()
+    }
+}
+
+impl Read for Checkpoint {
+    type Cfg = ();
+
+    fn read_cfg(buf: &mut impl Buf, _cfg: &Self::Cfg) -> Result<Self, CodecError> {
+        let height = u64::read(buf)?;
+        let block_hash = BlockId::read(buf)?;
+        let state_root = StateRoot::read(buf)?;
+        Ok(Self { height, block_hash, state_root })
+    }
+}
+
+/// [`Checkpoint`] together with the validator set's threshold signature over it.
+///
+/// Encodes with the commonware codec so it can be exported to a file and
+/// verified by a new node that only knows the group public key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedCheckpoint<V: Variant> {
+    /// The signed checkpoint.
+    pub checkpoint: Checkpoint,
+    /// Threshold signature over [`Checkpoint::message`].
+    pub signature: V::Signature,
+}
+
+impl<V: Variant> SignedCheckpoint<V> {
+    /// Returns `true` if the signature verifies under the group public key `identity`.
+    pub fn verify(&self, identity: &V::Public) -> bool {
+        ops::verify_message::<V>(
+            identity,
+            Some(CHECKPOINT_NAMESPACE),
+            &self.checkpoint.message(),
+            &self.signature,
+        )
+        .is_ok()
+    }
+}
+
+impl<V: Variant> Write for SignedCheckpoint<V> {
+    fn write(&self, buf: &mut impl BufMut) {
+        self.checkpoint.write(buf);
+        self.signature.write(buf);
+    }
+}
+
+impl<V: Variant> EncodeSize for SignedCheckpoint<V> {
+    fn encode_size(&self) -> usize {
+        self.checkpoint.encode_size() + self.signature.encode_size()
+    }
+}
+
+impl<V: Variant> Read for SignedCheckpoint<V> {
+    type Cfg = ();
+
+    fn read_cfg(buf: &mut impl Buf, _cfg: &Self::Cfg) -> Result<Self, CodecError> {
+        let checkpoint = Checkpoint::read(buf)?;
+        let signature = V::Signature::read(buf)?;
+        Ok(Self { checkpoint, signature })
+    }
+}
+
+#[derive(Debug)]
+struct CheckpointEntry<V: Variant> {
+    checkpoint: Checkpoint,
+    partials: Vec<PartialSignature<V>>,
+    signed: Option<SignedCheckpoint<V>>,
+}
+
+/// Checkpoints taken at finalized heights and the partial signatures collected
+/// for them.
+///
+/// A checkpoint is aggregated into a [`SignedCheckpoint`] once a threshold of
+/// partials is collected and the result verifies under the group public key.
+/// Only the most recent `retention` heights are kept; clones share state.
+#[derive(Debug)]
+pub struct CheckpointStore<V: Variant> {
+    retention: usize,
+    inner: Arc<RwLock<BTreeMap<u64, CheckpointEntry<V>>>>,
+}
+
+impl<V: Variant> Clone for CheckpointStore<V> {
+    fn clone(&self) -> Self {
+        Self { retention: self.retention, inner: self.inner.clone() }
+    }
+}
+
+impl<V: Variant> Default for CheckpointStore<V> {
+    fn default() -> Self {
+        Self { retention: DEFAULT_CHECKPOINT_RETENTION, inner: Arc::default() }
+    }
+}
+
+impl<V: Variant> CheckpointStore<V> {
+    /// Retain at most `retention` checkpoint heights, dropping the lowest first.
+    #[must_use]
+    pub const fn with_retention(mut self, retention: usize) -> Self {
+        self.retention = retention;
+        self
+    }
+
+    /// Record `checkpoint` and add `partial` over it.
+    ///
+    /// A checkpoint already recorded at the same height with different contents
+    /// is kept and `partial` is dropped. Returns the signed checkpoint once the
+    /// height has one.
+    pub fn insert(
+        &self,
+        checkpoint: Checkpoint,
+        partial: PartialSignature<V>,
+        sharing: &Sharing<V>,
+    ) -> Option<SignedCheckpoint<V>> {
+        {
+            let mut inner = self.inner.write();
+            let entry = inner.entry(checkpoint.height).or_insert_with(|| CheckpointEntry {
+                checkpoint,
+                partials: Vec::new(),
+                signed: None,
+            });
+            if entry.checkpoint != checkpoint {
+                return entry.signed.clone();
+            }
+            while inner.len() > self.retention {
+                inner.pop_first();
+            }
+        }
+        self.add_partial(checkpoint.height, partial, sharing)
+    }
+
+    /// Add another validator's `partial` over the checkpoint at `height`.
+    ///
+    /// Returns the signed checkpoint once the height has one, or `None` if no
+    /// checkpoint is recorded at `height` or too few partials are collected.
+    pub fn add_partial(
+        &self,
+        height: u64,
+        partial: PartialSignature<V>,
+        sharing: &Sharing<V>,
+    ) -> Option<SignedCheckpoint<V>> {
+        let mut inner = self.inner.write();
+        let entry = inner.get_mut(&height)?;
+        if entry.signed.is_some() || entry.partials.contains(&partial) {
+            return entry.signed.clone();
+        }
+        entry.partials.push(partial);
+        let signed = entry.checkpoint.aggregate(sharing, &entry.partials)?;
+        if !signed.verify(sharing.public()) {
+            return None;
+        }
+        entry.signed = Some(signed.clone());
+        Some(signed)
+    }
+
+    /// The checkpoint recorded at `height`, signed or not.
+    pub fn checkpoint(&self, height: u64) -> Option<Checkpoint> {
+        self.inner.read().get(&height).map(|entry| entry.checkpoint)
+    }
+
+    /// Partial signatures collected for the checkpoint at `height`.
+    pub fn partials(&self, height: u64) -> Vec<PartialSignature<V>> {
+        self.inner.read().get(&height).map(|entry| entry.partials.clone()).unwrap_or_default()
+    }
+
+    /// The signed checkpoint at `height`, once aggregated.
+    pub fn signed(&self, height: u64) -> Option<SignedCheckpoint<V>> {
+        self.inner.read().get(&height).and_then(|entry| entry.signed.clone())
+    }
+
+    /// The highest signed checkpoint, for exporting to a new node.
+    pub fn latest_signed(&self) -> Option<SignedCheckpoint<V>> {
+        self.inner.read().values().rev().find_map(|entry| entry.signed.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use commonware_codec::{Decode as _, Encode as _};
+    use commonware_cryptography::{
+        Signer as _,
+        bls12381::{
+            dkg,
+            primitives::{sharing::Mode, variant::MinSig},
+        },
+        ed25519,
+    };
+    use commonware_utils::{N3f1, TryCollect as _, ordered::Set};
+    use rand::{SeedableRng as _, rngs::StdRng};
+
+    use super::*;
+
+    /// A dealt 3-of-4 sharing and each participant's share.
+    fn dealt() -> (Sharing<MinSig>, Vec<Share>) {
+        let participants: Set<ed25519::PublicKey> = (0..4u64)
+            .map(|i| ed25519::PrivateKey::from_seed(i).public_key())
+            .try_collect()
+            .expect("participant public keys are unique");
+        let mut rng = StdRng::seed_from_u64(7);
+        let (output, shares) =
+            dkg::deal::<MinSig, _, N3f1>(&mut rng, Mode::default(), participants.clone())
+                .expect("dkg deal");
+        let shares =
+            participants.iter().map(|pk| shares.get_value(pk).expect("share").clone()).collect();
+        (output.public().clone(), shares)
+    }
+
+    fn checkpoint() -> Checkpoint {
+        Checkpoint::new(1_000, BlockId(B256::repeat_byte(0x11)), StateRoot(B256::repeat_byte(0x22)))
+    }
+
+    fn signed_checkpoint() -> (SignedCheckpoint<MinSig>, <MinSig as Variant>::Public) {
+        let (sharing, shares) = dealt();
+        let checkpoint = checkpoint();
+        let partials: Vec<_> =
+            shares.iter().take(3).map(|share| checkpoint.sign_partial::<MinSig>(share)).collect();
+        let signed = checkpoint.aggregate(&sharing, &partials).expect("threshold reached");
+        (signed, *sharing.public())
+    }
+
+    #[test]
+    fn signature_verifies_under_group_key() {
+        let (signed, identity) = signed_checkpoint();
+        assert!(signed.verify(&identity));
+
+        let decoded = SignedCheckpoint::<MinSig>::decode(signed.encode()).unwrap();
+        assert_eq!(decoded, signed);
+        assert!(decoded.verify(&identity));
+    }
+
+    #[test]
+    fn tampered_root_fails_verification() {
+        let (mut signed, identity) = signed_checkpoint();
+        signed.checkpoint.state_root = StateRoot(B256::repeat_byte(0x33));
+        assert!(!signed.verify(&identity));
+    }
+
+    #[test]
+    fn store_aggregates_once_threshold_partials_arrive() {
+        let (sharing, shares) = dealt();
+        let checkpoint = checkpoint();
+        let store = CheckpointStore::<MinSig>::default();
+
+        assert!(store.insert(checkpoint, checkpoint.sign_partial(&shares[0]), &sharing).is_none());
+        // A repeated partial does not count twice.
+        assert!(store.add_partial(1_000, checkpoint.sign_partial(&shares[0]), &sharing).is_none());
+        assert!(store.add_partial(1_000, checkpoint.sign_partial(&shares[1]), &sharing).is_none());
+        assert_eq!(store.partials(1_000).len(), 2);
+        assert!(store.latest_signed().is_none());
+
+        let signed = store
+            .add_partial(1_000, checkpoint.sign_partial(&shares[2]), &sharing)
+            .expect("threshold reached");
+        assert_eq!(signed.checkpoint, checkpoint);
+        assert!(signed.verify(sharing.public()));
+        assert_eq!(store.latest_signed(), Some(signed));
+        assert!(store.add_partial(2_000, checkpoint.sign_partial(&shares[3]), &sharing).is_none());
+    }
+
+    #[test]
+    fn store_keeps_recent_heights() {
+        let (sharing, shares) = dealt();
+        let store = CheckpointStore::<MinSig>::default().with_retention(2);
+        for height in [100, 200, 300] {
+            let checkpoint = Checkpoint { height, ..checkpoint() };
+            store.insert(checkpoint, checkpoint.sign_partial(&shares[0]), &sharing);
+        }
+
+        assert_eq!(store.checkpoint(100), None);
+        assert_eq!(store.checkpoint(300).map(|checkpoint| checkpoint.height), Some(300));
+    }
+
+    #[test]
+    fn checkpoints_are_due_on_interval() {
+        assert!(Checkpoint::is_due(100, 50));
+        assert!(!Checkpoint::is_due(101, 50));
+        assert!(!Checkpoint::is_due(0, 50));
+        assert!(!Checkpoint::is_due(100, 0));
+    }
+}
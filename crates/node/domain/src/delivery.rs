@@ -0,0 +1,172 @@
+//! Bounded-retry delivery of notifications to downstream consumers.
+
+use std::{collections::VecDeque, fmt::Display, future::Future, sync::Arc};
+
+use parking_lot::Mutex;
+
+/// Default number of times a notification is offered to its consumer.
+pub const DEFAULT_MAX_DELIVERY_ATTEMPTS: u32 = 3;
+
+/// Default number of dead letters retained for inspection.
+pub const DEFAULT_DEAD_LETTER_CAPACITY: usize = 1_024;
+
+/// Notification that could not be delivered within the retry budget.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeadLetter<T> {
+    /// The undelivered notification.
+    pub event: T,
+    /// Delivery attempts made before giving up.
+    pub attempts: u32,
+    /// Error returned by the final attempt.
+    pub error: String,
+}
+
+#[derive(Debug)]
+struct DeliveryInner<T> {
+    dead_letters: VecDeque<DeadLetter<T>>,
+    failed_attempts: u64,
+    dead_lettered: u64,
+}
+
+/// Delivers notifications to a fallible consumer, retrying a bounded number of
+/// times and recording permanently undeliverable ones in a dead-letter log.
+///
+/// Retries run back to back, so they cover consumers that fail transiently
+/// rather than ones that need time to recover. The dead-letter log keeps the
+/// most recent `capacity` entries; clones share the same log and counters.
+#[derive(Debug)]
+pub struct NotificationDelivery<T> {
+    max_attempts: u32,
+    capacity: usize,
+    inner: Arc<Mutex<DeliveryInner<T>>>,
+}
+
+impl<T> Clone for NotificationDelivery<T> {
+    fn clone(&self) -> Self {
+        Self { max_attempts: self.max_attempts, capacity: self.capacity, inner: self.inner.clone() }
+    }
+}
+
+impl<T: Clone> NotificationDelivery<T> {
+    /// Offer each notification at most `max_attempts` times (at least once).
+    #[must_use]
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            capacity: DEFAULT_DEAD_LETTER_CAPACITY,
+            inner: Arc::new(Mutex::new(DeliveryInner {
+                dead_letters: VecDeque::new(),
+                failed_attempts: 0,
+                dead_lettered: 0,
+            })),
+        }
+    }
+
+    /// Retain at most `capacity` dead letters, evicting the oldest first.
+    #[must_use]
+    pub const fn with_dead_letter_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Deliver `event` to `consumer`, retrying failed attempts.
+    ///
+    /// Returns the dead letter if every attempt failed; it is also recorded in
+    /// the dead-letter log.
+    pub async fn deliver<F, Fut, E>(&self, event: T, mut consumer: F) -> Result<(), DeadLetter<T>>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = Result<(), E>>,
+        E: Display,
+    {
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let Err(err) = consumer(event.clone()).await else {
+                return Ok(());
+            };
+            let mut inner = self.inner.lock();
+            inner.failed_attempts += 1;
+            if attempts < self.max_attempts {
+                continue;
+            }
+            let letter = DeadLetter { event, attempts, error: err.to_string() };
+            inner.dead_lettered += 1;
+            if self.capacity > 0 {
+                if inner.dead_letters.len() == self.capacity {
+                    inner.dead_letters.pop_front();
+                }
+                inner.dead_letters.push_back(letter.clone());
+            }
+            return Err(letter);
+        }
+    }
+
+    /// Returns the retained dead letters, oldest first.
+    pub fn dead_letters(&self) -> Vec<DeadLetter<T>> {
+        self.inner.lock().dead_letters.iter().cloned().collect()
+    }
+
+    /// Returns how many notifications were dead-lettered, including evicted ones.
+    pub fn dead_lettered(&self) -> u64 {
+        self.inner.lock().dead_lettered
+    }
+
+    /// Returns how many delivery attempts failed, retried ones included.
+    pub fn failed_attempts(&self) -> u64 {
+        self.inner.lock().failed_attempts
+    }
+}
+
+impl<T: Clone> Default for NotificationDelivery<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_DELIVERY_ATTEMPTS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use futures::executor::block_on;
+
+    use super::*;
+
+    #[test]
+    fn delivered_after_transient_failures() {
+        let delivery = NotificationDelivery::new(3);
+        let calls = Cell::new(0);
+        let received = Cell::new(None);
+
+        let result = block_on(delivery.deliver(7u64, |event| {
+            calls.set(calls.get() + 1);
+            let outcome = if calls.get() <= 2 {
+                Err("indexer unavailable")
+            } else {
+                received.set(Some(event));
+                Ok(())
+            };
+            std::future::ready(outcome)
+        }));
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(received.get(), Some(7));
+        assert_eq!(calls.get(), 3);
+        assert_eq!(delivery.failed_attempts(), 2);
+        assert!(delivery.dead_letters().is_empty());
+    }
+
+    #[test]
+    fn undeliverable_event_is_dead_lettered() {
+        let delivery = NotificationDelivery::new(2).with_dead_letter_capacity(1);
+        let fail = |_| std::future::ready(Err::<(), _>("closed"));
+
+        let letter = block_on(delivery.deliver(1u64, fail)).unwrap_err();
+        assert_eq!(letter, DeadLetter { event: 1, attempts: 2, error: "closed".to_string() });
+
+        assert!(block_on(delivery.deliver(2u64, fail)).is_err());
+        assert_eq!(delivery.dead_letters().iter().map(|l| l.event).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(delivery.dead_lettered(), 2);
+        assert_eq!(delivery.failed_attempts(), 4);
+    }
+}
@@ -7,7 +7,7 @@ use futures::channel::mpsc::{UnboundedReceiver, UnboundedSender, unbounded};
 use parking_lot::Mutex;
 
 use super::TxId;
-use crate::ConsensusDigest;
+use crate::{ConsensusDigest, StateRoot};
 
 /// Ledger-related domain events emitted by the example chain.
 #[derive(Clone, Debug)]
@@ -20,6 +20,17 @@ pub enum LedgerEvent {
     SeedUpdated(ConsensusDigest, B256),
 }
 
+/// A finalized block whose state has been persisted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FinalizedNotification {
+    /// Block height.
+    pub height: u64,
+    /// Block digest.
+    pub digest: ConsensusDigest,
+    /// State root after the block.
+    pub state_root: StateRoot,
+}
+
 /// Pub-sub registry for ledger events.
 #[derive(Clone, Debug)]
 pub struct LedgerEvents {
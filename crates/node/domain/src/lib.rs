@@ -11,10 +11,21 @@ mod commitment;
 pub use commitment::{AccountChange, StateChanges, StateChangesCfg};
 
 mod events;
-pub use events::{LedgerEvent, LedgerEvents};
+pub use events::{FinalizedNotification, LedgerEvent, LedgerEvents};
+
+mod delivery;
+pub use delivery::{
+    DEFAULT_DEAD_LETTER_CAPACITY, DEFAULT_MAX_DELIVERY_ATTEMPTS, DeadLetter, NotificationDelivery,
+};
+
+mod checkpoint;
+pub use checkpoint::{
+    CHECKPOINT_NAMESPACE, Checkpoint, CheckpointStore, DEFAULT_CHECKPOINT_RETENTION,
+    SignedCheckpoint,
+};
 
 mod bootstrap;
-pub use bootstrap::{BootstrapConfig, BootstrapError};
+pub use bootstrap::{BootstrapConfig, BootstrapError, ForkPoint};
 
 mod block;
 pub use block::{Block, BlockCfg};
@@ -22,6 +33,12 @@ pub use block::{Block, BlockCfg};
 mod idents;
 pub use idents::{BlockId, Idents, StateRoot, TxId};
 
+mod prevrandao;
+pub use prevrandao::prevrandao_from_seed;
+
+mod sender_cache;
+pub use sender_cache::{DEFAULT_SENDER_CACHE_CAPACITY, SenderCache};
+
 mod tx;
 pub use tx::{Tx, TxCfg};
 
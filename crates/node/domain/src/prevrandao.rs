@@ -0,0 +1,59 @@
+//! Derivation of the EVM `prevrandao` value from threshold-simplex seeds.
+
+use alloy_primitives::{B256, keccak256};
+use commonware_codec::Encode as _;
+use commonware_consensus::simplex::scheme::bls12381_threshold::Seed;
+use commonware_cryptography::bls12381::primitives::variant::Variant;
+
+/// Derive the `prevrandao` value for blocks built on top of the block certified by `seed`.
+///
+/// The value is `keccak256(seed.encode())`, where the encoding is the canonical
+/// commonware codec encoding of the seed: its round (epoch then view) followed by
+/// the threshold signature. This derivation is consensus-critical; every node must
+/// use it so that proposers and verifiers agree on `prevrandao`.
+pub fn prevrandao_from_seed<V: Variant>(seed: &Seed<V>) -> B256 {
+    keccak256(seed.encode())
+}
+
+#[cfg(test)]
+mod tests {
+    use commonware_consensus::types::{Epoch, Round, View};
+    use commonware_cryptography::bls12381::primitives::{
+        group::{Element as _, G1},
+        variant::MinSig,
+    };
+
+    use super::*;
+
+    fn seed(view: u64, signature: G1) -> Seed<MinSig> {
+        Seed::new(Round::new(Epoch::zero(), View::new(view)), signature)
+    }
+
+    #[test]
+    fn derivation_is_keccak_of_encoded_seed() {
+        let seed = seed(1, G1::one());
+        assert_eq!(prevrandao_from_seed(&seed), keccak256(seed.encode()));
+    }
+
+    #[test]
+    fn derivation_is_stable() {
+        let first = prevrandao_from_seed(&seed(7, G1::one()));
+        let second = prevrandao_from_seed(&seed(7, G1::one()));
+        assert_eq!(first, second);
+        assert_ne!(first, B256::ZERO);
+    }
+
+    #[test]
+    fn distinct_seeds_yield_distinct_values() {
+        let mut doubled = G1::one();
+        doubled.add(&G1::one());
+
+        let base = prevrandao_from_seed(&seed(1, G1::one()));
+        let other_view = prevrandao_from_seed(&seed(2, G1::one()));
+        let other_signature = prevrandao_from_seed(&seed(1, doubled));
+
+        assert_ne!(base, other_view);
+        assert_ne!(base, other_signature);
+        assert_ne!(other_view, other_signature);
+    }
+}
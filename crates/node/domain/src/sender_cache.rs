@@ -0,0 +1,150 @@
+//! Shared cache of recovered transaction senders.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+};
+
+use alloy_primitives::{Address, B256};
+use parking_lot::Mutex;
+
+/// Default maximum number of cached senders.
+pub const DEFAULT_SENDER_CACHE_CAPACITY: usize = 16_384;
+
+#[derive(Debug, Default)]
+struct SenderCacheInner {
+    entries: HashMap<B256, Address>,
+    order: VecDeque<B256>,
+    recoveries: u64,
+}
+
+/// Bounded map from transaction hash to recovered sender.
+///
+/// Keys are the canonical transaction hash (`TxEnvelope::tx_hash`, the keccak256
+/// of the EIP-2718 encoding), so a sender is found whichever wire encoding the
+/// transaction arrived in. The mempool populates
+/// the cache at admission and hands a clone to the executor, sparing one ECDSA
+/// recovery per transaction. Clones share the same entries; once `capacity`
+/// entries are held, the oldest entry is evicted first.
+#[derive(Debug, Clone)]
+pub struct SenderCache {
+    capacity: usize,
+    inner: Arc<Mutex<SenderCacheInner>>,
+}
+
+impl SenderCache {
+    /// Create an empty cache holding at most `capacity` senders.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, inner: Arc::new(Mutex::new(SenderCacheInner::default())) }
+    }
+
+    /// Returns the cached sender for `tx_hash`, if any.
+    pub fn get(&self, tx_hash: &B256) -> Option<Address> {
+        self.inner.lock().entries.get(tx_hash).copied()
+    }
+
+    /// Record the sender recovered for `tx_hash`.
+    pub fn insert(&self, tx_hash: B256, sender: Address) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut inner = self.inner.lock();
+        if inner.entries.insert(tx_hash, sender).is_some() {
+            return;
+        }
+        inner.order.push_back(tx_hash);
+        while inner.entries.len() > self.capacity {
+            let Some(oldest) = inner.order.pop_front() else { break };
+            inner.entries.remove(&oldest);
+        }
+    }
+
+    /// Returns the cached sender for `tx_hash`, running `recover` and caching its
+    /// result on a miss. Failed recoveries are not cached.
+    pub fn get_or_recover<E>(
+        &self,
+        tx_hash: B256,
+        recover: impl FnOnce() -> Result<Address, E>,
+    ) -> Result<Address, E> {
+        if let Some(sender) = self.get(&tx_hash) {
+            return Ok(sender);
+        }
+        self.inner.lock().recoveries += 1;
+        let sender = recover()?;
+        self.insert(tx_hash, sender);
+        Ok(sender)
+    }
+
+    /// Returns how many recoveries were run through [`Self::get_or_recover`].
+    pub fn recoveries(&self) -> u64 {
+        self.inner.lock().recoveries
+    }
+
+    /// Returns the number of cached senders.
+    pub fn len(&self) -> usize {
+        self.inner.lock().entries.len()
+    }
+
+    /// Returns `true` if no senders are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for SenderCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_SENDER_CACHE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_or_recover_runs_recovery_once() {
+        let cache = SenderCache::default();
+        let hash = B256::repeat_byte(1);
+        let sender = Address::repeat_byte(0xaa);
+
+        let first = cache.get_or_recover(hash, || Ok::<_, ()>(sender));
+        let second = cache.get_or_recover(hash, || Err(()));
+
+        assert_eq!(first, Ok(sender));
+        assert_eq!(second, Ok(sender));
+        assert_eq!(cache.recoveries(), 1);
+    }
+
+    #[test]
+    fn failed_recovery_is_not_cached() {
+        let cache = SenderCache::default();
+        let hash = B256::repeat_byte(1);
+
+        assert_eq!(
+            cache.get_or_recover(hash, || Err::<Address, _>("bad signature")),
+            Err("bad signature")
+        );
+        assert!(cache.get(&hash).is_none());
+        assert_eq!(cache.recoveries(), 1);
+    }
+
+    #[test]
+    fn clones_share_entries() {
+        let cache = SenderCache::default();
+        let clone = cache.clone();
+        cache.insert(B256::repeat_byte(1), Address::repeat_byte(0xaa));
+        assert_eq!(clone.get(&B256::repeat_byte(1)), Some(Address::repeat_byte(0xaa)));
+    }
+
+    #[test]
+    fn cache_is_bounded() {
+        let cache = SenderCache::new(2);
+        for byte in 0..3u8 {
+            cache.insert(B256::repeat_byte(byte), Address::repeat_byte(byte));
+        }
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&B256::repeat_byte(0)).is_none());
+        assert!(cache.get(&B256::repeat_byte(2)).is_some());
+    }
+}
@@ -4,6 +4,7 @@
 //! to bridge the async StateDb traits into the sync REVM interface.
 
 use alloy_primitives::{Address, B256, KECCAK256_EMPTY, U256};
+use monmouth_qmdb::canonical_code_hash;
 use monmouth_traits::{StateDbError, StateDbRead};
 use revm::{bytecode::Bytecode, database_interface::DatabaseRef, state::AccountInfo};
 
@@ -34,6 +35,16 @@ impl<S> StateDbAdapter<S> {
     }
 }
 
+impl<S: StateDbRead> StateDbAdapter<S> {
+    /// Length of the code with `code_hash`, served from the state's size index.
+    pub(crate) fn code_size(&self, code_hash: B256) -> Result<usize, ExecutionError> {
+        if code_hash == KECCAK256_EMPTY || code_hash == B256::ZERO {
+            return Ok(0);
+        }
+        Ok(block_on(self.state.code_size(&code_hash))?)
+    }
+}
+
 impl<S: StateDbRead> DatabaseRef for StateDbAdapter<S> {
     type Error = ExecutionError;
 
@@ -41,7 +52,8 @@ impl<S: StateDbRead> DatabaseRef for StateDbAdapter<S> {
         match block_on(self.state.nonce(&address)) {
             Ok(nonce) => {
                 let balance = block_on(self.state.balance(&address))?;
-                let code_hash = block_on(self.state.code_hash(&address))?;
+                // A zero hash is a legacy marker for empty code.
+                let code_hash = canonical_code_hash(block_on(self.state.code_hash(&address))?);
                 Ok(Some(AccountInfo { nonce, balance, code_hash, code: None, account_id: None }))
             }
             Err(StateDbError::AccountNotFound(_)) => Ok(None),
@@ -0,0 +1,76 @@
+//! Block assembly.
+
+use alloy_consensus::{Header, ReceiptEnvelope, TxType, proofs::calculate_receipt_root};
+use alloy_primitives::B256;
+use monmouth_domain::{Block, StateRoot, Tx};
+
+use crate::{BlockContext, ExecutionOutcome};
+
+/// A consensus block together with the Ethereum header describing its execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssembledBlock {
+    /// The block agreed on by consensus.
+    pub block: Block,
+    /// Header committing to the block's transactions and execution results.
+    pub header: Header,
+}
+
+impl AssembledBlock {
+    /// Assemble the child of `parent` holding `txs`.
+    ///
+    /// `outcome` must be the result of executing `txs` under `context`, and
+    /// `state_root` the root it produces. Execution results (roots, logs bloom,
+    /// gas used) come from `outcome` and the environment (gas limit, base fee,
+    /// timestamp, beneficiary, prevrandao) from `context`, so the header cannot
+    /// disagree with either.
+    pub fn assemble(
+        parent: &Block,
+        txs: Vec<Tx>,
+        state_root: StateRoot,
+        outcome: &ExecutionOutcome,
+        context: &BlockContext,
+    ) -> Self {
+        let block = Block {
+            parent: parent.id(),
+            height: parent.height + 1,
+            prevrandao: context.prevrandao,
+            state_root,
+            txs,
+        };
+        let receipts: Vec<ReceiptEnvelope> = block
+            .txs
+            .iter()
+            .zip(&outcome.receipts)
+            .map(|(tx, receipt)| ReceiptEnvelope::from_typed(tx_type(tx), receipt.receipt.clone()))
+            .collect();
+        let header = Header {
+            parent_hash: block.parent.0,
+            beneficiary: context.header.beneficiary,
+            state_root: state_root.0,
+            transactions_root: block.compute_transactions_root(),
+            receipts_root: calculate_receipt_root(&receipts),
+            logs_bloom: outcome.logs_bloom(),
+            number: block.height,
+            gas_limit: context.header.gas_limit,
+            gas_used: outcome.gas_used,
+            timestamp: context.header.timestamp,
+            mix_hash: context.prevrandao,
+            base_fee_per_gas: context.header.base_fee_per_gas,
+            ..Default::default()
+        };
+        Self { block, header }
+    }
+
+    /// Hash of the assembled header, which commits to every header field.
+    pub fn hash(&self) -> B256 {
+        self.header.hash_slow()
+    }
+}
+
+/// EIP-2718 type of `tx`; anything without a type byte is legacy.
+fn tx_type(tx: &Tx) -> TxType {
+    match tx.bytes.first() {
+        Some(&ty) if ty < 0x80 => TxType::try_from(ty).unwrap_or_default(),
+        _ => TxType::Legacy,
+    }
+}
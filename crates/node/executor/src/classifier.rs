@@ -4,6 +4,8 @@
 //! target addresses, and calldata patterns. This enables the Monmouth node to
 //! route transactions to appropriate execution environments.
 
+use std::collections::HashMap;
+
 use alloy_primitives::{Address, Bytes, address};
 
 /// Well-known ERC-8004 registry addresses on Monmouth.
@@ -80,6 +82,73 @@ impl std::fmt::Display for TransactionClassification {
     }
 }
 
+/// A classification rule whose confidence can be tuned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ClassifierPattern {
+    /// Call targets an ERC-8004 registry.
+    RegistryTarget,
+    /// Call targets the SVM Router precompile.
+    SvmRouterTarget,
+    /// Call targets the AI inference or vector similarity precompile.
+    RagPrecompileTarget,
+    /// Call targets the Cross-Chain Message Passer.
+    CrossChainTarget,
+    /// Calldata starts with the SVM route selector.
+    SvmSelector,
+    /// Calldata starts with an AI inference or vector search selector.
+    RagSelector,
+    /// Calldata starts with the intent parser selector.
+    IntentSelector,
+    /// Calldata starts with an ERC-8004 registry selector.
+    RegistrySelector,
+}
+
+impl ClassifierPattern {
+    /// Confidence used when [`ClassifierConfig::pattern_confidences`] has no override.
+    pub const fn default_confidence(self) -> f64 {
+        match self {
+            Self::RegistryTarget | Self::SvmRouterTarget | Self::CrossChainTarget => 0.95,
+            Self::RagPrecompileTarget => 0.90,
+            Self::SvmSelector => 0.85,
+            Self::RagSelector | Self::IntentSelector | Self::RegistrySelector => 0.80,
+        }
+    }
+}
+
+/// A well-known contract the classifier matches call targets against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ContractRole {
+    /// ERC-8004 IdentityRegistry.
+    IdentityRegistry,
+    /// ERC-8004 ReputationRegistry.
+    ReputationRegistry,
+    /// ERC-8004 ValidationRegistry.
+    ValidationRegistry,
+    /// AI Inference precompile.
+    AiInference,
+    /// Vector Similarity precompile.
+    VectorSimilarity,
+    /// SVM Router precompile.
+    SvmRouter,
+    /// Cross-Chain Message Passer precompile.
+    CrossChainMessagePasser,
+}
+
+impl ContractRole {
+    /// Address used when [`ClassifierConfig::addresses`] has no override.
+    pub const fn default_address(self) -> Address {
+        match self {
+            Self::IdentityRegistry => registries::IDENTITY_REGISTRY,
+            Self::ReputationRegistry => registries::REPUTATION_REGISTRY,
+            Self::ValidationRegistry => registries::VALIDATION_REGISTRY,
+            Self::AiInference => precompiles::AI_INFERENCE,
+            Self::VectorSimilarity => precompiles::VECTOR_SIMILARITY,
+            Self::SvmRouter => precompiles::SVM_ROUTER,
+            Self::CrossChainMessagePasser => precompiles::CROSS_CHAIN_MESSAGE_PASSER,
+        }
+    }
+}
+
 /// Result of transaction classification with confidence score.
 #[derive(Clone, Debug)]
 pub struct ClassificationResult {
@@ -99,11 +168,53 @@ pub struct ClassifierConfig {
     pub confidence_threshold: f64,
     /// Whether classification is enabled.
     pub enabled: bool,
+    /// Per-pattern confidence overrides.
+    ///
+    /// Patterns without an entry use [`ClassifierPattern::default_confidence`].
+    pub pattern_confidences: HashMap<ClassifierPattern, f64>,
+    /// Per-role contract address overrides, for devnets and forks.
+    ///
+    /// Roles without an entry use [`ContractRole::default_address`].
+    pub addresses: HashMap<ContractRole, Address>,
+}
+
+impl ClassifierConfig {
+    /// Override the confidence reported when `pattern` matches.
+    #[must_use]
+    pub fn with_pattern_confidence(mut self, pattern: ClassifierPattern, confidence: f64) -> Self {
+        self.pattern_confidences.insert(pattern, confidence);
+        self
+    }
+
+    /// Confidence reported when `pattern` matches.
+    pub fn confidence(&self, pattern: ClassifierPattern) -> f64 {
+        self.pattern_confidences
+            .get(&pattern)
+            .copied()
+            .unwrap_or_else(|| pattern.default_confidence())
+    }
+
+    /// Override the address matched for `role`.
+    #[must_use]
+    pub fn with_address(mut self, role: ContractRole, address: Address) -> Self {
+        self.addresses.insert(role, address);
+        self
+    }
+
+    /// Address matched for `role`.
+    pub fn address(&self, role: ContractRole) -> Address {
+        self.addresses.get(&role).copied().unwrap_or_else(|| role.default_address())
+    }
 }
 
 impl Default for ClassifierConfig {
     fn default() -> Self {
-        Self { confidence_threshold: 0.7, enabled: true }
+        Self {
+            confidence_threshold: 0.7,
+            enabled: true,
+            pattern_confidences: HashMap::new(),
+            addresses: HashMap::new(),
+        }
     }
 }
 
@@ -194,39 +305,41 @@ impl TransactionClassifier {
             }
         };
 
+        let is = |role| target == self.config.address(role);
+
         // Check if targeting ERC-8004 registries
-        if target == registries::IDENTITY_REGISTRY
-            || target == registries::REPUTATION_REGISTRY
-            || target == registries::VALIDATION_REGISTRY
+        if is(ContractRole::IdentityRegistry)
+            || is(ContractRole::ReputationRegistry)
+            || is(ContractRole::ValidationRegistry)
         {
             return ClassificationResult {
                 classification: TransactionClassification::AgentToAgent,
-                confidence: 0.95,
+                confidence: self.config.confidence(ClassifierPattern::RegistryTarget),
                 reason: format!("targets ERC-8004 registry at {target}"),
             };
         }
 
         // Check if targeting agent precompiles
-        if target == precompiles::SVM_ROUTER {
+        if is(ContractRole::SvmRouter) {
             return ClassificationResult {
                 classification: TransactionClassification::SvmRouted,
-                confidence: 0.95,
+                confidence: self.config.confidence(ClassifierPattern::SvmRouterTarget),
                 reason: "targets SVM Router precompile".into(),
             };
         }
 
-        if target == precompiles::VECTOR_SIMILARITY || target == precompiles::AI_INFERENCE {
+        if is(ContractRole::VectorSimilarity) || is(ContractRole::AiInference) {
             return ClassificationResult {
                 classification: TransactionClassification::RagEnhanced,
-                confidence: 0.90,
+                confidence: self.config.confidence(ClassifierPattern::RagPrecompileTarget),
                 reason: format!("targets AI/RAG precompile at {target}"),
             };
         }
 
-        if target == precompiles::CROSS_CHAIN_MESSAGE_PASSER {
+        if is(ContractRole::CrossChainMessagePasser) {
             return ClassificationResult {
                 classification: TransactionClassification::HybridCrossChain,
-                confidence: 0.95,
+                confidence: self.config.confidence(ClassifierPattern::CrossChainTarget),
                 reason: "targets Cross-Chain Message Passer".into(),
             };
         }
@@ -238,7 +351,7 @@ impl TransactionClassifier {
             if selector == selectors::SVM_ROUTE {
                 return ClassificationResult {
                     classification: TransactionClassification::SvmRouted,
-                    confidence: 0.85,
+                    confidence: self.config.confidence(ClassifierPattern::SvmSelector),
                     reason: "SVM route function selector".into(),
                 };
             }
@@ -246,7 +359,7 @@ impl TransactionClassifier {
             if selector == selectors::VECTOR_SEARCH || selector == selectors::AI_INFER {
                 return ClassificationResult {
                     classification: TransactionClassification::RagEnhanced,
-                    confidence: 0.80,
+                    confidence: self.config.confidence(ClassifierPattern::RagSelector),
                     reason: "AI/RAG function selector".into(),
                 };
             }
@@ -254,7 +367,7 @@ impl TransactionClassifier {
             if selector == selectors::PARSE_INTENT {
                 return ClassificationResult {
                     classification: TransactionClassification::RagEnhanced,
-                    confidence: 0.80,
+                    confidence: self.config.confidence(ClassifierPattern::IntentSelector),
                     reason: "intent parser function selector".into(),
                 };
             }
@@ -265,7 +378,7 @@ impl TransactionClassifier {
             {
                 return ClassificationResult {
                     classification: TransactionClassification::AgentToAgent,
-                    confidence: 0.80,
+                    confidence: self.config.confidence(ClassifierPattern::RegistrySelector),
                     reason: "ERC-8004 function selector".into(),
                 };
             }
@@ -392,7 +505,7 @@ mod tests {
 
     #[test]
     fn confidence_threshold_fallback() {
-        let config = ClassifierConfig { confidence_threshold: 0.99, enabled: true };
+        let config = ClassifierConfig { confidence_threshold: 0.99, ..ClassifierConfig::default() };
         let classifier = TransactionClassifier::new(config);
         // SVM selector has 0.85 confidence, which is below 0.99 threshold
         let input = Bytes::from(selectors::SVM_ROUTE.to_vec());
@@ -416,4 +529,60 @@ mod tests {
         let result = classifier.classify(Some(Address::ZERO), &Bytes::from(vec![0x01, 0x02]));
         assert_eq!(result.classification, TransactionClassification::PureEvm);
     }
+
+    #[test]
+    fn custom_confidence_below_threshold_falls_back() {
+        let config = ClassifierConfig::default()
+            .with_pattern_confidence(ClassifierPattern::RegistryTarget, 0.5);
+        let classifier = TransactionClassifier::new(config);
+
+        let result = classifier.classify(Some(registries::IDENTITY_REGISTRY), &Bytes::new());
+        assert_eq!(result.classification, TransactionClassification::PureEvm);
+        assert_eq!(result.confidence, 0.5);
+
+        // Other patterns keep their defaults.
+        let result = classifier.classify(Some(precompiles::SVM_ROUTER), &Bytes::new());
+        assert_eq!(result.classification, TransactionClassification::SvmRouted);
+        assert_eq!(result.confidence, 0.95);
+    }
+
+    #[test]
+    fn unset_confidences_preserve_defaults() {
+        let classifier = TransactionClassifier::enabled();
+        let cases = [
+            (Some(registries::VALIDATION_REGISTRY), Bytes::new(), 0.95),
+            (Some(precompiles::SVM_ROUTER), Bytes::new(), 0.95),
+            (Some(precompiles::AI_INFERENCE), Bytes::new(), 0.90),
+            (Some(precompiles::CROSS_CHAIN_MESSAGE_PASSER), Bytes::new(), 0.95),
+            (Some(Address::ZERO), Bytes::from(selectors::SVM_ROUTE.to_vec()), 0.85),
+            (Some(Address::ZERO), Bytes::from(selectors::VECTOR_SEARCH.to_vec()), 0.80),
+            (Some(Address::ZERO), Bytes::from(selectors::PARSE_INTENT.to_vec()), 0.80),
+            (Some(Address::ZERO), Bytes::from(selectors::GIVE_FEEDBACK.to_vec()), 0.80),
+        ];
+        for (to, input, confidence) in cases {
+            assert_eq!(classifier.classify(to, &input).confidence, confidence);
+        }
+    }
+
+    #[test]
+    fn remapped_addresses_are_classified() {
+        let identity = Address::repeat_byte(0xaa);
+        let router = Address::repeat_byte(0xbb);
+        let config = ClassifierConfig::default()
+            .with_address(ContractRole::IdentityRegistry, identity)
+            .with_address(ContractRole::SvmRouter, router);
+        let classifier = TransactionClassifier::new(config);
+
+        let result = classifier.classify(Some(identity), &Bytes::new());
+        assert_eq!(result.classification, TransactionClassification::AgentToAgent);
+        let result = classifier.classify(Some(router), &Bytes::new());
+        assert_eq!(result.classification, TransactionClassification::SvmRouted);
+
+        // The well-known addresses no longer match remapped roles.
+        let result = classifier.classify(Some(registries::IDENTITY_REGISTRY), &Bytes::new());
+        assert_eq!(result.classification, TransactionClassification::PureEvm);
+        // Roles without an override keep their defaults.
+        let result = classifier.classify(Some(registries::REPUTATION_REGISTRY), &Bytes::new());
+        assert_eq!(result.classification, TransactionClassification::AgentToAgent);
+    }
 }
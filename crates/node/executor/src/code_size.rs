@@ -0,0 +1,77 @@
+//! EXTCODESIZE backed by the state database's code size index.
+
+use alloy_primitives::{B256, U256};
+use monmouth_traits::StateDb;
+use revm::{
+    bytecode::{Bytecode, opcode::EXTCODESIZE},
+    context_interface::{ContextTr as _, host::LoadError},
+    handler::instructions::EthInstructions,
+    interpreter::{
+        Host as _, Instruction, InstructionContext,
+        instructions::utility::IntoAddress as _,
+        interpreter::EthInterpreter,
+        interpreter_types::{RuntimeFlag as _, StackTr as _},
+    },
+    primitives::hardfork::SpecId,
+};
+
+use crate::{
+    ExecutionError,
+    revm::{EvmContext, EvmDb},
+};
+
+/// Serve EXTCODESIZE through [`extcodesize`], keeping the spec's static gas.
+pub(crate) fn install<S: StateDb>(
+    instructions: &mut EthInstructions<EthInterpreter, EvmContext<S>>,
+) {
+    let static_gas = instructions.instruction_table[EXTCODESIZE as usize].static_gas();
+    instructions.insert_instruction(EXTCODESIZE, Instruction::new(extcodesize::<S>, static_gas));
+}
+
+/// Length of the code with `code_hash` without loading the code itself.
+///
+/// Code deployed earlier in the block is still cached by the EVM database; older
+/// code is measured through [`StateDbRead::code_size`](monmouth_traits::StateDbRead::code_size).
+fn code_size<S: StateDb>(db: &EvmDb<S>, code_hash: B256) -> Result<usize, ExecutionError> {
+    match db.cache.contracts.get(&code_hash) {
+        Some(code) => Ok(code.len()),
+        None => db.database.0.code_size(code_hash),
+    }
+}
+
+/// EXTCODESIZE that reads the account header and the code length only.
+///
+/// Gas and warm/cold accounting match the stock instruction; the code itself is
+/// only read when the journal already holds it.
+fn extcodesize<S: StateDb>(context: InstructionContext<'_, EvmContext<S>, EthInterpreter>) {
+    let Some(top) = context.interpreter.stack.top() else {
+        return context.interpreter.halt_underflow();
+    };
+    let address = top.into_address();
+
+    let berlin = context.interpreter.runtime_flag.spec_id().is_enabled_in(SpecId::BERLIN);
+    let cold_load_gas = context.host.gas_params().cold_account_additional_cost();
+    let skip_cold_load = berlin && context.interpreter.gas.remaining() < cold_load_gas;
+    let (is_cold, code_hash, loaded) =
+        match context.host.load_account_info_skip_cold_load(address, false, skip_cold_load) {
+            Ok(account) => {
+                (account.is_cold, account.code_hash, account.code.as_ref().map(Bytecode::len))
+            }
+            Err(LoadError::ColdLoadSkipped) => return context.interpreter.halt_oog(),
+            Err(LoadError::DBError) => return context.interpreter.halt_fatal(),
+        };
+    if berlin && is_cold && !context.interpreter.gas.record_cost(cold_load_gas) {
+        return context.interpreter.halt_oog();
+    }
+
+    let size = match loaded {
+        Some(size) => size,
+        None => match code_size(context.host.db_ref(), code_hash) {
+            Ok(size) => size,
+            Err(_) => return context.interpreter.halt_fatal(),
+        },
+    };
+    if let Some(top) = context.interpreter.stack.top() {
+        *top = U256::from(size);
+    }
+}
@@ -1,7 +1,12 @@
 //! Execution configuration.
 
+use std::time::Duration;
+
+use alloy_primitives::Address;
 use revm::primitives::hardfork::SpecId;
 
+use crate::{ExecutionError, fees::validate_priority_fee_split};
+
 /// Gas limit bounds for block validation.
 #[derive(Clone, Debug)]
 pub struct GasLimitBounds {
@@ -45,6 +50,43 @@ impl Default for BaseFeeParams {
     }
 }
 
+/// Custom precompile configuration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrecompileConfig {
+    /// Maximum concurrent calls to external-backend precompiles, unlimited when `None`.
+    ///
+    /// Whether a call is rejected depends on load, so validators can disagree on
+    /// the outcome of a transaction. Only enable this where protecting the backend
+    /// matters more than deterministic execution.
+    pub max_concurrent_external_calls: Option<usize>,
+    /// Calls allowed to wait for a slot before further calls are rejected.
+    pub max_queued_external_calls: usize,
+}
+
+impl PrecompileConfig {
+    /// Default precompile configuration: external calls are not limited.
+    pub const DEFAULT: Self =
+        Self { max_concurrent_external_calls: None, max_queued_external_calls: 0 };
+
+    /// Limit external-backend calls to `max_concurrent` at once with `max_queued` waiters.
+    #[must_use]
+    pub const fn with_external_call_limit(
+        mut self,
+        max_concurrent: usize,
+        max_queued: usize,
+    ) -> Self {
+        self.max_concurrent_external_calls = Some(max_concurrent);
+        self.max_queued_external_calls = max_queued;
+        self
+    }
+}
+
+impl Default for PrecompileConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// Execution configuration.
 #[derive(Clone, Debug)]
 pub struct ExecutionConfig {
@@ -56,6 +98,23 @@ pub struct ExecutionConfig {
     pub gas_limit_bounds: GasLimitBounds,
     /// EIP-1559 base fee parameters.
     pub base_fee_params: BaseFeeParams,
+    /// Wall-clock budget for executing a single block, disabled when `None`.
+    ///
+    /// Timeouts depend on hardware and load, so validators can disagree on whether
+    /// a block timed out. Only enable this where liveness matters more than
+    /// deterministic block acceptance.
+    pub execution_timeout: Option<Duration>,
+    /// Custom precompile configuration.
+    pub precompiles: PrecompileConfig,
+    /// Recipients of collected priority fees with weights in basis points.
+    ///
+    /// Only set through [`Self::with_priority_fee_split`], which validates it.
+    priority_fee_split: Vec<(Address, u16)>,
+    /// Maximum logs a single transaction may emit, unlimited when `None`.
+    ///
+    /// A transaction emitting more reverts: its state changes and logs are
+    /// discarded and the gas it used is still charged.
+    pub max_logs_per_tx: Option<usize>,
 }
 
 impl ExecutionConfig {
@@ -66,6 +125,10 @@ impl ExecutionConfig {
             spec_id: SpecId::PRAGUE,
             gas_limit_bounds: GasLimitBounds::DEFAULT,
             base_fee_params: BaseFeeParams::DEFAULT,
+            execution_timeout: None,
+            precompiles: PrecompileConfig::DEFAULT,
+            priority_fee_split: Vec::new(),
+            max_logs_per_tx: None,
         }
     }
 
@@ -89,6 +152,51 @@ impl ExecutionConfig {
         self.base_fee_params = params;
         self
     }
+
+    /// Set the per-block execution timeout.
+    #[must_use]
+    pub const fn with_execution_timeout(mut self, timeout: Duration) -> Self {
+        self.execution_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the custom precompile configuration.
+    #[must_use]
+    pub const fn with_precompile_config(mut self, precompiles: PrecompileConfig) -> Self {
+        self.precompiles = precompiles;
+        self
+    }
+
+    /// Revert transactions that emit more than `max` logs.
+    #[must_use]
+    pub const fn with_max_logs_per_tx(mut self, max: usize) -> Self {
+        self.max_logs_per_tx = Some(max);
+        self
+    }
+
+    /// Recipients of collected priority fees with weights in basis points.
+    ///
+    /// Empty pays every priority fee to the block beneficiary (the proposer).
+    /// Otherwise the weights sum to [`FEE_SPLIT_TOTAL_BPS`](crate::FEE_SPLIT_TOTAL_BPS)
+    /// and fees are redistributed once the block's transactions have executed.
+    pub fn priority_fee_split(&self) -> &[(Address, u16)] {
+        &self.priority_fee_split
+    }
+
+    /// Split collected priority fees between `split` recipients by basis-point weight.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExecutionError::InvalidPriorityFeeSplit`] if the weights do not sum
+    /// to [`FEE_SPLIT_TOTAL_BPS`](crate::FEE_SPLIT_TOTAL_BPS).
+    pub fn with_priority_fee_split(
+        mut self,
+        split: Vec<(Address, u16)>,
+    ) -> Result<Self, ExecutionError> {
+        validate_priority_fee_split(&split)?;
+        self.priority_fee_split = split;
+        Ok(self)
+    }
 }
 
 impl Default for ExecutionConfig {
@@ -106,6 +214,39 @@ mod tests {
         let config = ExecutionConfig::default();
         assert_eq!(config.chain_id, 7750);
         assert_eq!(config.spec_id, SpecId::PRAGUE);
+        assert!(config.execution_timeout.is_none());
+        assert_eq!(config.precompiles, PrecompileConfig::DEFAULT);
+        assert!(config.priority_fee_split().is_empty());
+        assert!(config.max_logs_per_tx.is_none());
+    }
+
+    #[test]
+    fn config_with_priority_fee_split() {
+        let proposer = Address::repeat_byte(0x01);
+        let treasury = Address::repeat_byte(0x02);
+        let config = ExecutionConfig::new(1)
+            .with_priority_fee_split(vec![(proposer, 7_000), (treasury, 3_000)])
+            .unwrap();
+        assert_eq!(config.priority_fee_split(), [(proposer, 7_000), (treasury, 3_000)]);
+
+        let err = ExecutionConfig::new(1)
+            .with_priority_fee_split(vec![(proposer, 7_000), (treasury, 2_000)])
+            .unwrap_err();
+        assert!(matches!(err, ExecutionError::InvalidPriorityFeeSplit(9_000)));
+    }
+
+    #[test]
+    fn config_with_external_call_limit() {
+        let config = ExecutionConfig::new(1)
+            .with_precompile_config(PrecompileConfig::default().with_external_call_limit(4, 8));
+        assert_eq!(config.precompiles.max_concurrent_external_calls, Some(4));
+        assert_eq!(config.precompiles.max_queued_external_calls, 8);
+    }
+
+    #[test]
+    fn config_with_execution_timeout() {
+        let config = ExecutionConfig::new(1).with_execution_timeout(Duration::from_millis(250));
+        assert_eq!(config.execution_timeout, Some(Duration::from_millis(250)));
     }
 
     #[test]
@@ -0,0 +1,173 @@
+//! Differential execution for executor conformance testing.
+//!
+//! Runs the same block through two [`BlockExecutor`]s and reports the first
+//! place their results diverge, so alternative executors can be checked
+//! against the sequential [`RevmExecutor`](crate::RevmExecutor).
+
+use alloy_primitives::B256;
+use monmouth_traits::{StateDb, StateDbError};
+use thiserror::Error;
+
+use crate::{BlockContext, BlockExecutor, ExecutionError, ExecutionOutcome};
+
+/// Divergence between two executions of the same block.
+#[derive(Debug, Error)]
+pub enum ConformanceError {
+    /// The reference executor failed.
+    #[error("reference executor failed: {0}")]
+    Reference(ExecutionError),
+
+    /// The candidate executor failed.
+    #[error("candidate executor failed: {0}")]
+    Candidate(ExecutionError),
+
+    /// Computing a state root failed.
+    #[error("state root computation failed: {0}")]
+    State(#[from] StateDbError),
+
+    /// The executions produced different state roots.
+    #[error("state root mismatch: reference {reference}, candidate {candidate}")]
+    StateRoot {
+        /// Root from the reference execution.
+        reference: B256,
+        /// Root from the candidate execution.
+        candidate: B256,
+    },
+
+    /// The executions produced different change sets.
+    #[error("change set mismatch")]
+    ChangeSet,
+
+    /// The executions used different amounts of gas.
+    #[error("gas used mismatch: reference {reference}, candidate {candidate}")]
+    GasUsed {
+        /// Gas used by the reference execution.
+        reference: u64,
+        /// Gas used by the candidate execution.
+        candidate: u64,
+    },
+
+    /// The executions produced different numbers of receipts.
+    #[error("receipt count mismatch: reference {reference}, candidate {candidate}")]
+    ReceiptCount {
+        /// Receipts from the reference execution.
+        reference: usize,
+        /// Receipts from the candidate execution.
+        candidate: usize,
+    },
+
+    /// The receipts at `index` differ.
+    #[error("receipt {index} mismatch")]
+    Receipt {
+        /// Position of the first differing receipt.
+        index: usize,
+    },
+
+    /// The executions collected different intent receipts.
+    #[error("intent receipt mismatch")]
+    IntentReceipts,
+}
+
+/// Execute `txs` with both executors against `state` and check the results agree.
+///
+/// Compares state roots, change sets, gas used, receipts, and intent receipts,
+/// returning the reference outcome when they match. `state` is only read, so
+/// both executors see the same pre-state.
+pub async fn compare_executors<S, R, C>(
+    state: &S,
+    context: &BlockContext,
+    txs: &[R::Tx],
+    reference: &R,
+    candidate: &C,
+) -> Result<ExecutionOutcome, ConformanceError>
+where
+    S: StateDb,
+    R: BlockExecutor<S>,
+    C: BlockExecutor<S, Tx = R::Tx>,
+{
+    let expected = reference.execute(state, context, txs).map_err(ConformanceError::Reference)?;
+    let actual = candidate.execute(state, context, txs).map_err(ConformanceError::Candidate)?;
+
+    let reference_root = state.compute_root(&expected.changes).await?;
+    let candidate_root = state.compute_root(&actual.changes).await?;
+    if reference_root != candidate_root {
+        return Err(ConformanceError::StateRoot {
+            reference: reference_root,
+            candidate: candidate_root,
+        });
+    }
+    diff_outcomes(&expected, &actual)?;
+    Ok(expected)
+}
+
+/// Return the first difference between two outcomes, ignoring state roots.
+fn diff_outcomes(
+    reference: &ExecutionOutcome,
+    candidate: &ExecutionOutcome,
+) -> Result<(), ConformanceError> {
+    if reference.changes != candidate.changes {
+        return Err(ConformanceError::ChangeSet);
+    }
+    if reference.gas_used != candidate.gas_used {
+        return Err(ConformanceError::GasUsed {
+            reference: reference.gas_used,
+            candidate: candidate.gas_used,
+        });
+    }
+    if reference.receipts.len() != candidate.receipts.len() {
+        return Err(ConformanceError::ReceiptCount {
+            reference: reference.receipts.len(),
+            candidate: candidate.receipts.len(),
+        });
+    }
+    if let Some(index) =
+        reference.receipts.iter().zip(&candidate.receipts).position(|(a, b)| a != b)
+    {
+        return Err(ConformanceError::Receipt { index });
+    }
+    if reference.intent_receipts != candidate.intent_receipts {
+        return Err(ConformanceError::IntentReceipts);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ExecutionReceipt;
+
+    fn outcome(gas_used: u64) -> ExecutionOutcome {
+        ExecutionOutcome {
+            receipts: vec![ExecutionReceipt::new(
+                B256::ZERO,
+                true,
+                gas_used,
+                gas_used,
+                vec![],
+                None,
+            )],
+            gas_used,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn identical_outcomes_agree() {
+        assert!(diff_outcomes(&outcome(21_000), &outcome(21_000)).is_ok());
+    }
+
+    #[test]
+    fn first_divergence_is_reported() {
+        assert!(matches!(
+            diff_outcomes(&outcome(21_000), &outcome(22_000)),
+            Err(ConformanceError::GasUsed { reference: 21_000, candidate: 22_000 })
+        ));
+
+        let mut failed = outcome(21_000);
+        failed.receipts[0] = ExecutionReceipt::new(B256::ZERO, false, 21_000, 21_000, vec![], None);
+        assert!(matches!(
+            diff_outcomes(&outcome(21_000), &failed),
+            Err(ConformanceError::Receipt { index: 0 })
+        ));
+    }
+}
@@ -1,5 +1,7 @@
 //! Execution error types.
 
+use std::time::Duration;
+
 use alloy_primitives::B256;
 use revm::database_interface::DBErrorMarker;
 use thiserror::Error;
@@ -30,6 +32,14 @@ pub enum ExecutionError {
     /// Code not found for hash.
     #[error("code not found: {0}")]
     CodeNotFound(B256),
+
+    /// Block execution exceeded the configured wall-clock timeout.
+    #[error("block execution exceeded timeout of {0:?}")]
+    ExecutionTimeout(Duration),
+
+    /// Priority fee split weights do not sum to 10000 basis points.
+    #[error("priority fee split weights sum to {0} bps, expected 10000")]
+    InvalidPriorityFeeSplit(u32),
 }
 
 impl DBErrorMarker for ExecutionError {}
@@ -87,6 +97,12 @@ mod tests {
         assert!(err.to_string().contains(&hash.to_string()));
     }
 
+    #[test]
+    fn test_execution_timeout_display() {
+        let err = ExecutionError::ExecutionTimeout(Duration::from_millis(500));
+        assert_eq!(err.to_string(), "block execution exceeded timeout of 500ms");
+    }
+
     #[test]
     fn test_error_debug() {
         let err = ExecutionError::TxDecode("test".to_string());
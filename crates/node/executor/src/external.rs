@@ -0,0 +1,155 @@
+//! Concurrency limits for precompiles backed by external services.
+
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+
+use thiserror::Error;
+
+/// Returned when an external call finds both the call slots and the queue full.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+#[error("external backend over capacity ({max_concurrent} in flight, {max_queued} queued)")]
+pub struct ExternalCallRejected {
+    /// Configured concurrent call limit.
+    pub max_concurrent: usize,
+    /// Configured queue bound.
+    pub max_queued: usize,
+}
+
+#[derive(Debug, Default)]
+struct Slots {
+    in_flight: usize,
+    queued: usize,
+}
+
+/// Counting semaphore bounding concurrent external-backend precompile calls.
+///
+/// Up to `max_concurrent` calls run at once. Further calls wait for a slot while
+/// fewer than `max_queued` are already waiting; beyond that they are rejected.
+/// Clones share the same slots.
+#[derive(Clone, Debug)]
+pub struct ExternalCallLimiter {
+    max_concurrent: usize,
+    max_queued: usize,
+    slots: Arc<(Mutex<Slots>, Condvar)>,
+}
+
+impl ExternalCallLimiter {
+    /// Create a limiter allowing `max_concurrent` calls with `max_queued` waiters.
+    pub fn new(max_concurrent: usize, max_queued: usize) -> Self {
+        Self {
+            max_concurrent,
+            max_queued,
+            slots: Arc::new((Mutex::new(Slots::default()), Condvar::new())),
+        }
+    }
+
+    /// Number of calls currently holding a slot.
+    pub fn in_flight(&self) -> usize {
+        self.slots.0.lock().unwrap_or_else(PoisonError::into_inner).in_flight
+    }
+
+    /// Take a call slot, waiting if the queue has room.
+    pub fn acquire(&self) -> Result<ExternalCallPermit, ExternalCallRejected> {
+        let (lock, available) = &*self.slots;
+        let mut slots = lock.lock().unwrap_or_else(PoisonError::into_inner);
+        if slots.in_flight >= self.max_concurrent {
+            if slots.queued >= self.max_queued {
+                return Err(ExternalCallRejected {
+                    max_concurrent: self.max_concurrent,
+                    max_queued: self.max_queued,
+                });
+            }
+            slots.queued += 1;
+            while slots.in_flight >= self.max_concurrent {
+                slots = available.wait(slots).unwrap_or_else(PoisonError::into_inner);
+            }
+            slots.queued -= 1;
+        }
+        slots.in_flight += 1;
+        Ok(ExternalCallPermit { slots: Arc::clone(&self.slots) })
+    }
+}
+
+/// Slot held for the duration of one external call; released on drop.
+#[derive(Debug)]
+pub struct ExternalCallPermit {
+    slots: Arc<(Mutex<Slots>, Condvar)>,
+}
+
+impl Drop for ExternalCallPermit {
+    fn drop(&mut self) {
+        let (lock, available) = &*self.slots;
+        lock.lock().unwrap_or_else(PoisonError::into_inner).in_flight -= 1;
+        available.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            Barrier,
+            atomic::{AtomicUsize, Ordering},
+        },
+        thread,
+        time::Duration,
+    };
+
+    use super::*;
+
+    /// Backend that records the highest number of simultaneous calls.
+    #[derive(Default)]
+    struct MockBackend {
+        active: AtomicUsize,
+        peak: AtomicUsize,
+    }
+
+    impl MockBackend {
+        fn call(&self) {
+            let active = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(active, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(5));
+            self.active.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn concurrency_never_exceeds_limit() {
+        const CALLERS: usize = 16;
+        let limiter = ExternalCallLimiter::new(3, CALLERS);
+        let backend = Arc::new(MockBackend::default());
+        let start = Arc::new(Barrier::new(CALLERS));
+
+        let handles: Vec<_> = (0..CALLERS)
+            .map(|_| {
+                let limiter = limiter.clone();
+                let backend = Arc::clone(&backend);
+                let start = Arc::clone(&start);
+                thread::spawn(move || {
+                    start.wait();
+                    let _permit = limiter.acquire().expect("queue has room");
+                    backend.call();
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let peak = backend.peak.load(Ordering::SeqCst);
+        assert!(peak <= 3, "peak concurrency {peak} exceeded limit");
+        assert!(peak >= 1);
+        assert_eq!(limiter.in_flight(), 0);
+    }
+
+    #[test]
+    fn full_queue_rejects() {
+        let limiter = ExternalCallLimiter::new(1, 0);
+        let permit = limiter.acquire().unwrap();
+        assert_eq!(
+            limiter.acquire().unwrap_err(),
+            ExternalCallRejected { max_concurrent: 1, max_queued: 0 }
+        );
+        drop(permit);
+        assert!(limiter.acquire().is_ok());
+    }
+}
@@ -0,0 +1,120 @@
+//! Priority fee settlement.
+
+use std::collections::BTreeMap;
+
+use alloy_primitives::{Address, KECCAK256_EMPTY, U256};
+use monmouth_qmdb::{AccountUpdate, ChangeSet};
+use monmouth_traits::StateDbRead;
+use revm::database_interface::DatabaseRef;
+
+use crate::{ExecutionError, StateDbAdapter};
+
+/// Basis points that the weights of a priority fee split must sum to.
+pub const FEE_SPLIT_TOTAL_BPS: u16 = 10_000;
+
+/// Check that `split` is empty or its weights sum to [`FEE_SPLIT_TOTAL_BPS`].
+pub(crate) fn validate_priority_fee_split(split: &[(Address, u16)]) -> Result<(), ExecutionError> {
+    if split.is_empty() {
+        return Ok(());
+    }
+    let total: u32 = split.iter().map(|(_, weight)| u32::from(*weight)).sum();
+    if total != u32::from(FEE_SPLIT_TOTAL_BPS) {
+        return Err(ExecutionError::InvalidPriorityFeeSplit(total));
+    }
+    Ok(())
+}
+
+/// Each recipient's share of `collected`, rounded down.
+fn shares(collected: U256, split: &[(Address, u16)]) -> Vec<(Address, U256)> {
+    split
+        .iter()
+        .map(|(recipient, weight)| {
+            (*recipient, collected * U256::from(*weight) / U256::from(FEE_SPLIT_TOTAL_BPS))
+        })
+        .collect()
+}
+
+/// Move the priority fees REVM credited to `beneficiary` to the `split` recipients.
+///
+/// Rounding dust stays with the beneficiary. Recipients not already in `changes`
+/// are loaded from `db`.
+pub(crate) fn settle_priority_fees<S: StateDbRead>(
+    db: &StateDbAdapter<S>,
+    changes: &mut ChangeSet,
+    beneficiary: Address,
+    collected: U256,
+    split: &[(Address, u16)],
+) -> Result<(), ExecutionError> {
+    if split.is_empty() || collected.is_zero() {
+        return Ok(());
+    }
+
+    let shares = shares(collected, split);
+    let distributed = shares.iter().fold(U256::ZERO, |total, (_, share)| total + share);
+    update_balance(db, changes, beneficiary, |balance| balance.saturating_sub(distributed))?;
+    for (recipient, share) in shares {
+        update_balance(db, changes, recipient, |balance| balance.saturating_add(share))?;
+    }
+    Ok(())
+}
+
+fn update_balance<S: StateDbRead>(
+    db: &StateDbAdapter<S>,
+    changes: &mut ChangeSet,
+    address: Address,
+    f: impl FnOnce(U256) -> U256,
+) -> Result<(), ExecutionError> {
+    if let Some(update) = changes.accounts.get_mut(&address) {
+        update.balance = f(update.balance);
+        return Ok(());
+    }
+
+    let update = match db.basic_ref(address)? {
+        Some(info) => AccountUpdate {
+            created: false,
+            selfdestructed: false,
+            nonce: info.nonce,
+            balance: f(info.balance),
+            code_hash: info.code_hash,
+            code: None,
+            storage: BTreeMap::new(),
+        },
+        None => AccountUpdate {
+            created: true,
+            selfdestructed: false,
+            nonce: 0,
+            balance: f(U256::ZERO),
+            code_hash: KECCAK256_EMPTY,
+            code: None,
+            storage: BTreeMap::new(),
+        },
+    };
+    changes.insert(address, update);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_rejects_weights_not_summing_to_total() {
+        let a = Address::repeat_byte(0x01);
+        let b = Address::repeat_byte(0x02);
+        assert!(validate_priority_fee_split(&[]).is_ok());
+        assert!(validate_priority_fee_split(&[(a, 10_000)]).is_ok());
+        assert!(validate_priority_fee_split(&[(a, 5_000), (b, 5_000)]).is_ok());
+        assert!(matches!(
+            validate_priority_fee_split(&[(a, 9_000), (b, 9_000)]),
+            Err(ExecutionError::InvalidPriorityFeeSplit(18_000))
+        ));
+    }
+
+    #[test]
+    fn shares_round_down() {
+        let a = Address::repeat_byte(0x01);
+        let b = Address::repeat_byte(0x02);
+        let shares = shares(U256::from(1_001), &[(a, 7_000), (b, 3_000)]);
+        assert_eq!(shares, vec![(a, U256::from(700)), (b, U256::from(300))]);
+    }
+}
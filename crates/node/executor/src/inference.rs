@@ -0,0 +1,17 @@
+//! Pluggable backend for the AI inference precompile.
+
+use thiserror::Error;
+
+/// Error returned by an [`InferenceBackend`]; the precompile call fails with it.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error("inference backend failed: {0}")]
+pub struct InferenceError(pub String);
+
+/// Service answering AI inference precompile calls.
+///
+/// `infer` runs synchronously inside EVM execution, so implementations must be
+/// deterministic across validators and should return promptly.
+pub trait InferenceBackend: std::fmt::Debug + Send + Sync {
+    /// Run inference on the precompile call data, returning the call output.
+    fn infer(&self, input: &[u8]) -> Result<Vec<u8>, InferenceError>;
+}
@@ -0,0 +1,248 @@
+//! Intent receipts produced by the intent-parser precompile.
+
+use alloy_primitives::{Address, B256, Bytes, U256};
+use revm::{
+    Inspector,
+    context_interface::ContextTr,
+    interpreter::{CallInputs, CallOutcome, CreateInputs, CreateOutcome},
+};
+
+use crate::classifier::precompiles::INTENT_PARSER;
+
+/// Structured intent returned by the intent-parser precompile.
+///
+/// Encoded as four ABI words:
+/// `(uint8 intentType, address target, uint256 value, bool parsed)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParsedIntent {
+    /// Declared intent type; see [`TRANSFER`](Self::TRANSFER), [`SWAP`](Self::SWAP),
+    /// and [`STAKE`](Self::STAKE). Zero when nothing was recognized.
+    pub intent_type: u8,
+    /// Target address the intent acts on.
+    pub target: Address,
+    /// Value the intent declares.
+    pub value: U256,
+    /// Whether the input was recognized as an intent.
+    pub parsed: bool,
+}
+
+impl ParsedIntent {
+    /// ABI-encoded size in bytes.
+    pub const ENCODED_LEN: usize = 128;
+
+    /// Intent type of a value transfer.
+    pub const TRANSFER: u8 = 1;
+
+    /// Intent type of a token swap.
+    pub const SWAP: u8 = 2;
+
+    /// Intent type of a stake.
+    pub const STAKE: u8 = 3;
+
+    /// Parse a natural-language intent such as `"transfer 100 to 0x…"`.
+    ///
+    /// The first `transfer`/`send`, `swap`, or `stake` keyword sets the intent
+    /// type, and the first address and decimal amount anywhere in the text set
+    /// the target and value. Input that is not UTF-8 or contains no keyword
+    /// yields an unparsed, all-zero intent.
+    pub fn parse(input: &[u8]) -> Self {
+        let Ok(text) = std::str::from_utf8(input) else {
+            return Self::default();
+        };
+        let mut intent = Self::default();
+        for word in text.split_whitespace().map(|word| word.trim_matches([',', '.', ';'])) {
+            if intent.intent_type == 0
+                && let Some(intent_type) = keyword_intent_type(word)
+            {
+                intent.intent_type = intent_type;
+            } else if intent.target.is_zero()
+                && word.starts_with("0x")
+                && let Ok(target) = word.parse::<Address>()
+            {
+                intent.target = target;
+            } else if intent.value.is_zero()
+                && word.bytes().all(|byte| byte.is_ascii_digit())
+                && let Ok(value) = U256::from_str_radix(word, 10)
+            {
+                intent.value = value;
+            }
+        }
+        if intent.intent_type == 0 {
+            return Self::default();
+        }
+        intent.parsed = true;
+        intent
+    }
+
+    /// ABI-encode the intent as returned by the precompile.
+    pub fn encode(&self) -> Bytes {
+        let mut output = Vec::with_capacity(Self::ENCODED_LEN);
+        output.extend_from_slice(&U256::from(self.intent_type).to_be_bytes::<32>());
+        output.extend_from_slice(self.target.into_word().as_slice());
+        output.extend_from_slice(&self.value.to_be_bytes::<32>());
+        output.extend_from_slice(&U256::from(self.parsed).to_be_bytes::<32>());
+        Bytes::from(output)
+    }
+
+    /// Decode an intent from precompile output.
+    ///
+    /// Returns `None` if the output is not exactly four well-formed ABI words.
+    pub fn decode(output: &[u8]) -> Option<Self> {
+        if output.len() != Self::ENCODED_LEN {
+            return None;
+        }
+        let intent_type = U256::from_be_slice(&output[..32]).try_into().ok()?;
+        if output[32..44].iter().any(|byte| *byte != 0) {
+            return None;
+        }
+        let target = Address::from_slice(&output[44..64]);
+        let value = U256::from_be_slice(&output[64..96]);
+        let parsed = match U256::from_be_slice(&output[96..128]) {
+            flag if flag.is_zero() => false,
+            flag if flag == U256::from(1) => true,
+            _ => return None,
+        };
+        Some(Self { intent_type, target, value, parsed })
+    }
+}
+
+fn keyword_intent_type(word: &str) -> Option<u8> {
+    match word.to_ascii_lowercase().as_str() {
+        "transfer" | "send" => Some(ParsedIntent::TRANSFER),
+        "swap" => Some(ParsedIntent::SWAP),
+        "stake" => Some(ParsedIntent::STAKE),
+        _ => None,
+    }
+}
+
+/// Declared intent recorded for a transaction that successfully called the
+/// intent parser.
+///
+/// Receipts are kept so the declared intent can later be compared against the
+/// transaction's actual effects.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntentReceipt {
+    /// Hash of the transaction that declared the intent.
+    pub tx_hash: B256,
+    /// Number of the block containing the transaction.
+    pub block_number: u64,
+    /// The parsed intent.
+    pub intent: ParsedIntent,
+}
+
+/// Collects the intents parsed during a transaction, discarding those made
+/// inside frames that later revert.
+///
+/// Each frame remembers how many intents had been collected when it started;
+/// when it fails, everything collected since is dropped, so only intents from
+/// calls whose effects survive the transaction are kept.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct IntentCollector {
+    intents: Vec<ParsedIntent>,
+    frames: Vec<usize>,
+}
+
+impl IntentCollector {
+    /// Take the intents collected since the last call, in call order.
+    pub(crate) fn take(&mut self) -> Vec<ParsedIntent> {
+        self.frames.clear();
+        std::mem::take(&mut self.intents)
+    }
+
+    fn end_frame(&mut self, success: bool) {
+        let start = self.frames.pop().unwrap_or_default();
+        if !success {
+            self.intents.truncate(start);
+        }
+    }
+}
+
+impl<CTX: ContextTr> Inspector<CTX> for IntentCollector {
+    fn call(&mut self, _context: &mut CTX, _inputs: &mut CallInputs) -> Option<CallOutcome> {
+        self.frames.push(self.intents.len());
+        None
+    }
+
+    fn call_end(&mut self, _context: &mut CTX, inputs: &CallInputs, outcome: &mut CallOutcome) {
+        let success = outcome.result.is_ok();
+        self.end_frame(success);
+        if success
+            && inputs.bytecode_address == INTENT_PARSER
+            && let Some(intent) = ParsedIntent::decode(&outcome.result.output)
+            && intent.parsed
+        {
+            self.intents.push(intent);
+        }
+    }
+
+    fn create(&mut self, _context: &mut CTX, _inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        self.frames.push(self.intents.len());
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &CreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        self.end_frame(outcome.result.is_ok());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_decode_roundtrip() {
+        let intent = ParsedIntent {
+            intent_type: 3,
+            target: Address::repeat_byte(0xaa),
+            value: U256::from(1_000_000u64),
+            parsed: true,
+        };
+        let encoded = intent.encode();
+        assert_eq!(encoded.len(), ParsedIntent::ENCODED_LEN);
+        assert_eq!(ParsedIntent::decode(&encoded), Some(intent));
+    }
+
+    #[test]
+    fn decode_rejects_malformed_output() {
+        assert_eq!(ParsedIntent::decode(&[0u8; 64]), None);
+
+        let mut dirty_address = ParsedIntent::default().encode().to_vec();
+        dirty_address[32] = 1;
+        assert_eq!(ParsedIntent::decode(&dirty_address), None);
+
+        let mut wide_type = ParsedIntent::default().encode().to_vec();
+        wide_type[30] = 1;
+        assert_eq!(ParsedIntent::decode(&wide_type), None);
+
+        let mut bad_flag = ParsedIntent::default().encode().to_vec();
+        bad_flag[127] = 2;
+        assert_eq!(ParsedIntent::decode(&bad_flag), None);
+    }
+
+    #[test]
+    fn parses_transfer_intent() {
+        let target = Address::repeat_byte(0xab);
+        let intent = ParsedIntent::parse(format!("Transfer 100 to {target}.").as_bytes());
+        assert_eq!(
+            intent,
+            ParsedIntent {
+                intent_type: ParsedIntent::TRANSFER,
+                target,
+                value: U256::from(100),
+                parsed: true,
+            }
+        );
+    }
+
+    #[test]
+    fn unrecognized_input_is_not_parsed() {
+        assert_eq!(ParsedIntent::parse(b"what is the weather 42"), ParsedIntent::default());
+        assert_eq!(ParsedIntent::parse(&[0xff, 0xfe, 0x74]), ParsedIntent::default());
+        assert!(!ParsedIntent::parse(b"").parsed);
+    }
+}
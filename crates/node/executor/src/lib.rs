@@ -8,16 +8,28 @@
 mod adapter;
 pub use adapter::StateDbAdapter;
 
+mod assemble;
+pub use assemble::AssembledBlock;
+
 pub mod classifier;
 pub use classifier::{
-    ClassificationResult, ClassifierConfig, TransactionClassification, TransactionClassifier,
+    ClassificationResult, ClassifierConfig, ClassifierPattern, ContractRole,
+    TransactionClassification, TransactionClassifier,
 };
 
+mod code_size;
+
+pub mod conformance;
+pub use conformance::{ConformanceError, compare_executors};
+
 mod config;
-pub use config::{BaseFeeParams, ExecutionConfig, GasLimitBounds};
+pub use config::{BaseFeeParams, ExecutionConfig, GasLimitBounds, PrecompileConfig};
 
 pub mod precompiles;
-pub use precompiles::MonmouthPrecompiles;
+pub use precompiles::{
+    CROSS_CHAIN_NONCE_SLOT, MESSAGE_PASSED_SIGNATURE, MonmouthPrecompiles,
+    cross_chain_nonce_issued, highest_cross_chain_nonce,
+};
 
 mod context;
 pub use context::{BlockContext, ParentBlock};
@@ -25,12 +37,47 @@ pub use context::{BlockContext, ParentBlock};
 mod error;
 pub use error::ExecutionError;
 
+mod external;
+pub use external::{ExternalCallLimiter, ExternalCallPermit, ExternalCallRejected};
+
+mod fees;
+pub use fees::FEE_SPLIT_TOTAL_BPS;
+
+mod inference;
+pub use inference::{InferenceBackend, InferenceError};
+
+mod intent;
+pub use intent::{IntentReceipt, ParsedIntent};
+
+mod log_limit;
+
 mod outcome;
 pub use outcome::{ExecutionOutcome, ExecutionReceipt};
 
+mod parallel;
+pub use parallel::{ParallelExecutor, default_parallel_workers};
+
+mod pool;
+
+mod registry_events;
+pub use registry_events::{
+    AGENT_REGISTERED_SIGNATURE, AgentRegistered, AgentRegistryEvent, FEEDBACK_GIVEN_SIGNATURE,
+    FeedbackGiven, VALIDATION_REQUESTED_SIGNATURE, ValidationRequested, decode_registry_event,
+    decode_registry_events,
+};
+
 mod revm;
 pub use revm::{RevmExecutor, calculate_base_fee};
 
+mod routing;
+pub use routing::{
+    DEFAULT_ROUTING_LOG_LIMIT, DEFAULT_ROUTING_LOG_WINDOW, ExecutionPipeline, RoutingMetrics,
+    RoutingObserver, RoutingOutcome, RoutingRecord,
+};
+
+mod trace;
+pub use trace::{CallFrame, CallKind, CallTracer, TransactionTrace};
+
 mod traits;
 pub use traits::BlockExecutor;
 
@@ -39,3 +86,5 @@ pub use validation::{
     ACCESS_LIST_ADDRESS_GAS, ACCESS_LIST_STORAGE_KEY_GAS, MAX_BLOBS_PER_TX, TX_BASE_GAS,
     TX_CREATE_GAS, TX_DATA_NON_ZERO_GAS, TX_DATA_ZERO_GAS, TxValidator, ValidatedTx,
 };
+
+mod vector;
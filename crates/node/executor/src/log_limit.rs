@@ -0,0 +1,47 @@
+//! Per-transaction log limit.
+
+use revm::{
+    Inspector,
+    context_interface::{ContextTr, JournalTr as _},
+    interpreter::{InstructionResult, Interpreter},
+};
+
+/// Reverts a transaction once it has emitted more than `max` logs.
+///
+/// Checked after every instruction against the logs the journal currently holds,
+/// so logs discarded by a reverted sub-call do not count. Once the limit is
+/// exceeded every active frame reverts on its next instruction, unwinding the
+/// whole transaction even if a caller would otherwise catch the failure.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LogLimit {
+    max: usize,
+    exceeded: bool,
+}
+
+impl LogLimit {
+    /// Limit transactions to `max` logs.
+    pub(crate) const fn new(max: usize) -> Self {
+        Self { max, exceeded: false }
+    }
+
+    /// Reset the limit before executing the next transaction.
+    pub(crate) const fn begin_transaction(&mut self) {
+        self.exceeded = false;
+    }
+
+    /// Whether the last transaction was reverted for exceeding the limit.
+    pub(crate) const fn exceeded(&self) -> bool {
+        self.exceeded
+    }
+}
+
+impl<CTX: ContextTr> Inspector<CTX> for LogLimit {
+    fn step_end(&mut self, interp: &mut Interpreter, context: &mut CTX) {
+        if !self.exceeded && context.journal_mut().logs().len() > self.max {
+            self.exceeded = true;
+        }
+        if self.exceeded {
+            interp.halt(InstructionResult::Revert);
+        }
+    }
+}
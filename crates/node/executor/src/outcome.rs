@@ -1,9 +1,11 @@
 //! Execution outcome types.
 
 use alloy_consensus::{Eip658Value, Receipt};
-use alloy_primitives::{Address, B256, Log};
+use alloy_primitives::{Address, B256, Bloom, Log};
 use monmouth_qmdb::ChangeSet;
 
+use crate::IntentReceipt;
+
 /// Result of executing a block's transactions.
 #[derive(Clone, Debug, Default)]
 pub struct ExecutionOutcome {
@@ -13,13 +15,35 @@ pub struct ExecutionOutcome {
     pub receipts: Vec<ExecutionReceipt>,
     /// Total gas used by all transactions.
     pub gas_used: u64,
+    /// Intents declared by successful transactions via the intent parser, in block order.
+    pub intent_receipts: Vec<IntentReceipt>,
 }
 
 impl ExecutionOutcome {
     /// Create a new empty execution outcome.
     #[must_use]
     pub fn new() -> Self {
-        Self { changes: ChangeSet::new(), receipts: Vec::new(), gas_used: 0 }
+        Self {
+            changes: ChangeSet::new(),
+            receipts: Vec::new(),
+            gas_used: 0,
+            intent_receipts: Vec::new(),
+        }
+    }
+
+    /// Returns the receipt of the transaction at `index` in block order.
+    pub fn receipt(&self, index: usize) -> Option<&ExecutionReceipt> {
+        self.receipts.get(index)
+    }
+
+    /// Returns the 2048-bit logs bloom over the address and topics of every
+    /// log emitted in the block.
+    pub fn logs_bloom(&self) -> Bloom {
+        let mut bloom = Bloom::ZERO;
+        for log in self.receipts.iter().flat_map(ExecutionReceipt::logs) {
+            bloom.accrue_log(log);
+        }
+        bloom
     }
 }
 
@@ -27,7 +51,7 @@ impl ExecutionOutcome {
 ///
 /// Wraps [`alloy_consensus::Receipt`] with additional execution metadata
 /// that is not part of the consensus receipt (tx hash, per-tx gas, contract address).
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ExecutionReceipt {
     /// Transaction hash.
     pub tx_hash: B256,
@@ -83,5 +107,20 @@ mod tests {
         assert!(outcome.changes.is_empty());
         assert!(outcome.receipts.is_empty());
         assert_eq!(outcome.gas_used, 0);
+        assert!(outcome.intent_receipts.is_empty());
+    }
+
+    #[test]
+    fn receipt_returns_receipt_by_index() {
+        let receipt = ExecutionReceipt::new(B256::ZERO, true, 21_000, 21_000, Vec::new(), None);
+        let outcome =
+            ExecutionOutcome { receipts: vec![receipt.clone()], ..ExecutionOutcome::new() };
+        assert_eq!(outcome.receipt(0), Some(&receipt));
+        assert_eq!(outcome.receipt(1), None);
+    }
+
+    #[test]
+    fn empty_outcome_has_empty_logs_bloom() {
+        assert_eq!(ExecutionOutcome::new().logs_bloom(), Bloom::ZERO);
     }
 }
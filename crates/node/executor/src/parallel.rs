@@ -0,0 +1,399 @@
+//! Parallel block execution.
+//!
+//! [`ParallelExecutor`] runs groups of transactions on separate threads and
+//! merges their results. Groups start as per-sender transaction chains. After a
+//! round, the read and write sets each group actually touched are compared. Groups
+//! that conflict are merged and run again. The loop ends when no two groups
+//! conflict, and the merged result then equals sequential execution. If any
+//! group fails to execute, the block is run sequentially so that errors match
+//! [`RevmExecutor`] exactly.
+//!
+//! The block beneficiary is an ordinary state key. Transactions that pay it a
+//! non-zero priority fee all write its balance and so conflict with one another.
+//! Only zero-tip transactions can spread across groups.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    num::NonZeroUsize,
+    sync::{Arc, OnceLock, mpsc},
+};
+
+use alloy_consensus::Header;
+use alloy_primitives::{Address, Bytes, U256, keccak256};
+use monmouth_traits::StateDb;
+
+use crate::{
+    BlockContext, BlockExecutor, ExecutionError, ExecutionOutcome, RevmExecutor, StateDbAdapter,
+    fees::settle_priority_fees, pool::WorkerPool, revm::ExecutionDeadline,
+};
+
+/// Default number of worker threads used by [`ParallelExecutor`]: the host's
+/// available parallelism, or 1 if it cannot be determined.
+pub fn default_parallel_workers() -> usize {
+    std::thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// A piece of state a transaction can read or write.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum StateKey {
+    /// An account's nonce, balance and code.
+    Account(Address),
+    /// One storage slot of an account.
+    Slot(Address, U256),
+}
+
+/// State accessed by one or more transactions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct AccessSet {
+    /// Every key read or written.
+    accessed: BTreeSet<StateKey>,
+    /// Keys written.
+    writes: BTreeSet<StateKey>,
+}
+
+impl AccessSet {
+    /// Record an access to `key`.
+    pub(crate) fn record(&mut self, key: StateKey, written: bool) {
+        self.accessed.insert(key);
+        if written {
+            self.writes.insert(key);
+        }
+    }
+
+    /// Add every access in `other` to this set.
+    fn extend(&mut self, other: &Self) {
+        self.accessed.extend(other.accessed.iter().copied());
+        self.writes.extend(other.writes.iter().copied());
+    }
+
+    /// Whether either set writes a key the other accesses.
+    fn conflicts(&self, other: &Self) -> bool {
+        !self.writes.is_disjoint(&other.accessed) || !other.writes.is_disjoint(&self.accessed)
+    }
+}
+
+/// Transactions executed in order on one EVM, before priority fees are settled.
+#[derive(Debug, Default)]
+pub(crate) struct BlockRun {
+    /// Outcome with receipts numbered from the start of the run.
+    pub(crate) outcome: ExecutionOutcome,
+    /// Priority fees owed to the beneficiary.
+    pub(crate) priority_fees: U256,
+    /// State each transaction accessed, in order, when tracked.
+    pub(crate) access: Vec<AccessSet>,
+}
+
+/// Transactions executed together and the result of their last run.
+#[derive(Debug)]
+struct Group {
+    /// Block positions of the transactions, ascending.
+    txs: Vec<usize>,
+    /// Result of running `txs` against the parent state.
+    run: Option<BlockRun>,
+}
+
+impl Group {
+    /// Union of the state accessed by every transaction in the last run.
+    fn access(&self) -> AccessSet {
+        let mut access = AccessSet::default();
+        for tx in self.run.iter().flat_map(|run| &run.access) {
+            access.extend(tx);
+        }
+        access
+    }
+}
+
+/// Block executor that runs non-conflicting transactions concurrently.
+///
+/// Produces the same [`ExecutionOutcome`] as the wrapped [`RevmExecutor`]. Use
+/// [`compare_executors`](crate::compare_executors) to check this for a block.
+///
+/// Groups run on a pool of `workers` threads, spawned when the first block is
+/// executed and reused for every later block. Clones share the pool.
+#[derive(Clone, Debug)]
+pub struct ParallelExecutor {
+    /// Executor used for every group and for sequential fallback.
+    inner: RevmExecutor,
+    /// Maximum number of groups executed at once.
+    workers: usize,
+    /// Threads running the groups, shared by clones.
+    pool: Arc<OnceLock<WorkerPool>>,
+}
+
+impl ParallelExecutor {
+    /// Run `inner` across [`default_parallel_workers`] threads.
+    #[must_use]
+    pub fn new(inner: RevmExecutor) -> Self {
+        Self { inner, workers: default_parallel_workers(), pool: Arc::default() }
+    }
+
+    /// Set the maximum number of worker threads. Values below 2 execute sequentially.
+    #[must_use]
+    pub fn with_workers(mut self, workers: usize) -> Self {
+        if workers != self.workers {
+            self.workers = workers;
+            self.pool = Arc::default();
+        }
+        self
+    }
+
+    /// Get the wrapped sequential executor.
+    pub const fn inner(&self) -> &RevmExecutor {
+        &self.inner
+    }
+
+    /// Get the maximum number of worker threads.
+    pub const fn workers(&self) -> usize {
+        self.workers
+    }
+
+    /// Split `txs` into per-sender chains, ordered by each chain's first transaction.
+    fn sender_groups(&self, txs: &[Bytes]) -> Result<Vec<Group>, ExecutionError> {
+        let mut groups: Vec<Group> = Vec::new();
+        let mut by_sender = HashMap::new();
+        for (index, tx) in txs.iter().enumerate() {
+            let sender = self.inner.sender(tx)?;
+            let group = *by_sender.entry(sender).or_insert_with(|| {
+                groups.push(Group { txs: Vec::new(), run: None });
+                groups.len() - 1
+            });
+            groups[group].txs.push(index);
+        }
+        Ok(groups)
+    }
+
+    /// Run every group without a result on the worker pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a group's execution panicked.
+    fn run_pending<S: StateDb>(
+        &self,
+        state: &S,
+        context: &BlockContext,
+        txs: &[Bytes],
+        deadline: ExecutionDeadline,
+        groups: &mut [Group],
+    ) -> Result<(), ExecutionError> {
+        let pending: Vec<usize> =
+            groups.iter().enumerate().filter(|(_, g)| g.run.is_none()).map(|(i, _)| i).collect();
+        let batches: Vec<Vec<Bytes>> = pending
+            .iter()
+            .map(|&group| groups[group].txs.iter().map(|&tx| txs[tx].clone()).collect())
+            .collect();
+
+        let pool = self.pool.get_or_init(|| WorkerPool::new(self.workers));
+        let (done, finished) = mpsc::channel();
+        for (batch, batch_txs) in batches.into_iter().enumerate() {
+            let (done, inner, state, context) =
+                (done.clone(), self.inner.clone(), state.clone(), context.clone());
+            pool.execute(move || {
+                let run = inner.run(&state, &context, &batch_txs, deadline, true);
+                let _ = done.send((batch, run));
+            });
+        }
+        drop(done);
+        let mut results: Vec<(usize, Result<BlockRun, ExecutionError>)> = finished.iter().collect();
+        assert_eq!(results.len(), pending.len(), "parallel execution worker panicked");
+
+        results.sort_by_key(|(batch, _)| *batch);
+        for (batch, run) in results {
+            groups[pending[batch]].run = Some(run?);
+        }
+        Ok(())
+    }
+
+    /// Assemble the block outcome from groups that do not conflict.
+    fn merge<S: StateDb>(
+        &self,
+        state: &S,
+        context: &BlockContext,
+        txs: &[Bytes],
+        groups: Vec<Group>,
+    ) -> Result<ExecutionOutcome, ExecutionError> {
+        let mut receipts = vec![None; txs.len()];
+        let mut outcome = ExecutionOutcome::new();
+        let mut priority_fees = U256::ZERO;
+
+        for group in groups {
+            let run = group.run.expect("every group has run before merging");
+            for (&tx, receipt) in group.txs.iter().zip(run.outcome.receipts) {
+                receipts[tx] = Some(receipt);
+            }
+            outcome.changes.merge(run.outcome.changes);
+            outcome.intent_receipts.extend(run.outcome.intent_receipts);
+            priority_fees = priority_fees.saturating_add(run.priority_fees);
+        }
+
+        for mut receipt in receipts.into_iter().flatten() {
+            outcome.gas_used = outcome.gas_used.saturating_add(receipt.gas_used);
+            receipt.receipt.cumulative_gas_used = outcome.gas_used;
+            outcome.receipts.push(receipt);
+        }
+
+        let positions: HashMap<_, _> =
+            txs.iter().enumerate().rev().map(|(index, tx)| (keccak256(tx), index)).collect();
+        outcome.intent_receipts.sort_by_key(|receipt| positions.get(&receipt.tx_hash).copied());
+
+        settle_priority_fees(
+            &StateDbAdapter::new(state.clone()),
+            &mut outcome.changes,
+            context.header.beneficiary,
+            priority_fees,
+            self.inner.config().priority_fee_split(),
+        )?;
+        Ok(outcome)
+    }
+}
+
+impl<S: StateDb> BlockExecutor<S> for ParallelExecutor {
+    type Tx = Bytes;
+
+    fn execute(
+        &self,
+        state: &S,
+        context: &BlockContext,
+        txs: &[Self::Tx],
+    ) -> Result<ExecutionOutcome, ExecutionError> {
+        if self.workers < 2 {
+            return self.inner.execute(state, context, txs);
+        }
+        let Ok(mut groups) = self.sender_groups(txs) else {
+            return self.inner.execute(state, context, txs);
+        };
+        if groups.len() < 2 {
+            return self.inner.execute(state, context, txs);
+        }
+
+        let deadline = ExecutionDeadline::new(self.inner.config().execution_timeout);
+        loop {
+            // Apart from a timeout, a group can fail because it is missing a transaction
+            // from another group; the sequential run reports the error the block really has.
+            match self.run_pending(state, context, txs, deadline, &mut groups) {
+                Ok(()) => {}
+                Err(err @ ExecutionError::ExecutionTimeout(_)) => return Err(err),
+                Err(_) => return self.inner.execute(state, context, txs),
+            }
+
+            let access: Vec<AccessSet> = groups.iter().map(Group::access).collect();
+            let components = conflict_groups(&access);
+            if components.len() == groups.len() {
+                break;
+            }
+            if components.len() == 1 {
+                return self.inner.execute(state, context, txs);
+            }
+
+            let mut slots: Vec<Option<Group>> = groups.into_iter().map(Some).collect();
+            groups = components
+                .into_iter()
+                .map(|members| {
+                    if let [member] = members[..] {
+                        return slots[member].take().expect("each group joins one component");
+                    }
+                    let mut txs: Vec<usize> = members
+                        .iter()
+                        .filter_map(|&member| slots[member].take())
+                        .flat_map(|group| group.txs)
+                        .collect();
+                    txs.sort_unstable();
+                    Group { txs, run: None }
+                })
+                .collect();
+        }
+
+        deadline.check()?;
+        self.merge(state, context, txs, groups)
+    }
+
+    fn validate_header(&self, header: &Header) -> Result<(), ExecutionError> {
+        <RevmExecutor as BlockExecutor<S>>::validate_header(&self.inner, header)
+    }
+}
+
+/// Partition `sets` into connected components of the conflict relation.
+///
+/// Components are ordered by their first member and list members in ascending order.
+fn conflict_groups(sets: &[AccessSet]) -> Vec<Vec<usize>> {
+    fn root(parent: &mut [usize], mut node: usize) -> usize {
+        while parent[node] != node {
+            parent[node] = parent[parent[node]];
+            node = parent[node];
+        }
+        node
+    }
+
+    let mut parent: Vec<usize> = (0..sets.len()).collect();
+    for a in 0..sets.len() {
+        for b in a + 1..sets.len() {
+            if sets[a].conflicts(&sets[b]) {
+                let (ra, rb) = (root(&mut parent, a), root(&mut parent, b));
+                parent[ra.max(rb)] = ra.min(rb);
+            }
+        }
+    }
+
+    let mut components: Vec<Vec<usize>> = Vec::new();
+    let mut index = HashMap::new();
+    for node in 0..sets.len() {
+        let root = root(&mut parent, node);
+        let component = *index.entry(root).or_insert_with(|| {
+            components.push(Vec::new());
+            components.len() - 1
+        });
+        components[component].push(node);
+    }
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(byte: u8) -> Address {
+        Address::repeat_byte(byte)
+    }
+
+    fn access(reads: &[StateKey], writes: &[StateKey]) -> AccessSet {
+        let mut set = AccessSet::default();
+        for key in reads {
+            set.record(*key, false);
+        }
+        for key in writes {
+            set.record(*key, true);
+        }
+        set
+    }
+
+    #[test]
+    fn shared_reads_do_not_conflict() {
+        let shared = StateKey::Account(address(9));
+        let a = access(&[shared], &[StateKey::Account(address(1))]);
+        let b = access(&[shared], &[StateKey::Account(address(2))]);
+        assert!(!a.conflicts(&b));
+        assert_eq!(conflict_groups(&[a, b]), vec![vec![0], vec![1]]);
+    }
+
+    #[test]
+    fn write_conflicts_with_read_of_same_key() {
+        let slot = StateKey::Slot(address(3), U256::from(7));
+        let a = access(&[], &[slot]);
+        let b = access(&[slot], &[]);
+        assert!(a.conflicts(&b));
+        assert!(b.conflicts(&a));
+        assert!(!a.conflicts(&access(&[StateKey::Slot(address(3), U256::from(8))], &[])));
+    }
+
+    #[test]
+    fn conflicts_are_transitive_within_a_component() {
+        let x = StateKey::Account(address(1));
+        let y = StateKey::Account(address(2));
+        let sets = [
+            access(&[], &[x]),
+            access(&[], &[StateKey::Account(address(5))]),
+            access(&[x], &[y]),
+            access(&[y], &[]),
+        ];
+        assert_eq!(conflict_groups(&sets), vec![vec![0, 2, 3], vec![1]]);
+    }
+}
@@ -0,0 +1,150 @@
+//! Fixed-size worker pool for parallel execution.
+
+use std::{
+    fmt,
+    panic::{AssertUnwindSafe, catch_unwind},
+    sync::{Arc, Mutex, PoisonError, mpsc},
+    thread::JoinHandle,
+};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// A fixed set of threads that run jobs from a shared queue.
+///
+/// Threads are spawned once, when the pool is created, and take the next queued
+/// job whenever they are idle, so a pool is reused across blocks and never runs
+/// more than [`workers`](Self::workers) jobs at once. A panicking job does not
+/// take its thread down. Dropping the pool lets queued jobs finish, then joins
+/// the threads.
+pub(crate) struct WorkerPool {
+    queue: Option<mpsc::Sender<Job>>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl fmt::Debug for WorkerPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WorkerPool").field("workers", &self.workers()).finish()
+    }
+}
+
+impl WorkerPool {
+    /// Spawn a pool of `workers` threads, at least one.
+    pub(crate) fn new(workers: usize) -> Self {
+        let (queue, jobs) = mpsc::channel::<Job>();
+        let jobs = Arc::new(Mutex::new(jobs));
+        let threads = (0..workers.max(1))
+            .map(|index| {
+                let jobs = Arc::clone(&jobs);
+                std::thread::Builder::new()
+                    .name(format!("parallel-exec-{index}"))
+                    .spawn(move || {
+                        loop {
+                            let job = jobs.lock().unwrap_or_else(PoisonError::into_inner).recv();
+                            let Ok(job) = job else { break };
+                            // The job's result channel reports the panic to its submitter.
+                            let _ = catch_unwind(AssertUnwindSafe(job));
+                        }
+                    })
+                    .expect("failed to spawn parallel execution worker")
+            })
+            .collect();
+        Self { queue: Some(queue), threads }
+    }
+
+    /// Number of threads in the pool.
+    pub(crate) const fn workers(&self) -> usize {
+        self.threads.len()
+    }
+
+    /// Queue `job` to run on the next idle thread.
+    pub(crate) fn execute(&self, job: impl FnOnce() + Send + 'static) {
+        if let Some(queue) = &self.queue {
+            // Threads only exit once the queue is closed in `drop`.
+            let _ = queue.send(Box::new(job));
+        }
+    }
+}
+
+impl Drop for WorkerPool {
+    fn drop(&mut self) {
+        self.queue.take();
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashSet,
+        sync::{
+            Barrier,
+            atomic::{AtomicUsize, Ordering},
+        },
+        thread::ThreadId,
+        time::Duration,
+    };
+
+    use super::*;
+
+    /// Run `jobs` jobs on `pool` and return the threads they ran on and the most
+    /// that ran at once.
+    fn run_jobs(pool: &WorkerPool, jobs: usize) -> (HashSet<ThreadId>, usize) {
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let (done, results) = mpsc::channel();
+        for _ in 0..jobs {
+            let (running, peak, done) = (Arc::clone(&running), Arc::clone(&peak), done.clone());
+            pool.execute(move || {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                std::thread::sleep(Duration::from_millis(5));
+                running.fetch_sub(1, Ordering::SeqCst);
+                done.send(std::thread::current().id()).expect("receiver alive");
+            });
+        }
+        drop(done);
+        let threads = results.iter().collect();
+        (threads, peak.load(Ordering::SeqCst))
+    }
+
+    #[test]
+    fn pool_runs_at_most_its_worker_count_at_once() {
+        let pool = WorkerPool::new(3);
+        assert_eq!(pool.workers(), 3);
+
+        let (threads, peak) = run_jobs(&pool, 12);
+        assert!(threads.len() <= 3);
+        assert!(peak <= 3);
+    }
+
+    #[test]
+    fn pool_threads_are_reused_across_batches() {
+        let pool = WorkerPool::new(2);
+        // Both threads must be busy at once for the barrier to release.
+        let barrier = Arc::new(Barrier::new(2));
+        let (done, results) = mpsc::channel();
+        for _ in 0..2 {
+            let (barrier, done) = (Arc::clone(&barrier), done.clone());
+            pool.execute(move || {
+                barrier.wait();
+                done.send(std::thread::current().id()).expect("receiver alive");
+            });
+        }
+        drop(done);
+        let first: HashSet<_> = results.iter().collect();
+        assert_eq!(first.len(), 2);
+
+        let (second, _) = run_jobs(&pool, 8);
+        assert!(second.is_subset(&first));
+    }
+
+    #[test]
+    fn panicking_job_keeps_its_thread() {
+        let pool = WorkerPool::new(1);
+        pool.execute(|| panic!("job failed"));
+        let (threads, _) = run_jobs(&pool, 2);
+        assert_eq!(threads.len(), 1);
+    }
+}
@@ -3,29 +3,64 @@
 //! Extends the standard Ethereum precompiles with agent-specific operations
 //! at well-known addresses.
 
-use alloy_primitives::{Address, Bytes};
+use std::{collections::HashSet, sync::Arc};
+
+use alloy_primitives::{Address, B256, Bytes, Log, U256, keccak256};
+use monmouth_traits::{StateDbError, StateDbRead};
 use revm::{
-    context::{Cfg, LocalContextTr},
+    context::{Cfg, JournalTr, LocalContextTr},
     context_interface::ContextTr,
     handler::{EthPrecompiles, PrecompileProvider},
     interpreter::{CallInput, CallInputs, Gas, InstructionResult, InterpreterResult},
     primitives::hardfork::SpecId,
 };
 
-use crate::classifier::precompiles as addrs;
+use crate::{
+    ExternalCallLimiter, InferenceBackend,
+    classifier::precompiles as addrs,
+    intent::ParsedIntent,
+    vector::{SimilarityQuery, encode_match},
+};
+
+/// Storage slot of the cross-chain message passer holding the highest assigned nonce.
+///
+/// The counter lives in the passer's own account storage, so it is committed with
+/// the block state, covered by the state root, and survives restarts.
+pub const CROSS_CHAIN_NONCE_SLOT: U256 = U256::ZERO;
+
+/// Event the cross-chain message passer emits for every message.
+///
+/// `MessagePassed(uint256 indexed nonce, address sender, bytes32 payloadHash)`, where
+/// `payloadHash` is the keccak256 of the call data. Relayers match it against the
+/// payload carried by the sending transaction.
+pub const MESSAGE_PASSED_SIGNATURE: &str = "MessagePassed(uint256,address,bytes32)";
 
 /// Gas costs for custom precompile operations.
 mod gas {
     /// Base gas for AI inference stub.
     pub(super) const AI_INFERENCE_BASE: u64 = 10_000;
-    /// Base gas for vector similarity stub.
+    /// Base gas for vector similarity.
     pub(super) const VECTOR_SIMILARITY_BASE: u64 = 5_000;
-    /// Base gas for intent parser stub.
+    /// Additional vector similarity gas per corpus row scored.
+    pub(super) const VECTOR_SIMILARITY_PER_ROW: u64 = 500;
+    /// Base gas for the intent parser.
     pub(super) const INTENT_PARSER_BASE: u64 = 5_000;
     /// Base gas for SVM router stub.
     pub(super) const SVM_ROUTER_BASE: u64 = 10_000;
     /// Base gas for cross-chain message passer.
     pub(super) const CROSS_CHAIN_MESSAGE_PASSER_BASE: u64 = 20_000;
+    /// Surcharge for the first access to the nonce slot in a transaction (EIP-2929).
+    pub(super) const NONCE_SLOT_COLD: u64 = 2_100;
+    /// Nonce write that sets a slot which was zero (EIP-2200 `SSTORE_SET`).
+    pub(super) const NONCE_SET: u64 = 20_000;
+    /// Nonce write that changes a slot which was non-zero (EIP-2929 `SSTORE_RESET`).
+    pub(super) const NONCE_RESET: u64 = 2_900;
+    /// Nonce write to a slot already written in the transaction.
+    pub(super) const NONCE_DIRTY: u64 = 100;
+    /// Surcharge for the first call to a custom precompile in a transaction.
+    pub(super) const COLD_ACCESS_SURCHARGE: u64 = 2_600;
+    /// Surcharge for repeat calls to a custom precompile in a transaction.
+    pub(super) const WARM_ACCESS_SURCHARGE: u64 = 100;
 }
 
 /// Custom precompile provider for Monmouth that extends standard Ethereum precompiles.
@@ -33,11 +68,18 @@ mod gas {
 pub struct MonmouthPrecompiles {
     /// Standard Ethereum precompiles.
     inner: EthPrecompiles,
+    /// Limit on concurrent external-backend calls, if configured.
+    external_calls: Option<ExternalCallLimiter>,
+    /// Backend answering AI inference calls; the mock response is used when unset.
+    inference: Option<Arc<dyn InferenceBackend>>,
+    /// Custom precompiles called since the last
+    /// [`begin_transaction`](Self::begin_transaction).
+    accessed: HashSet<Address>,
 }
 
 impl MonmouthPrecompiles {
     /// All custom precompile addresses.
-    const CUSTOM_ADDRESSES: [Address; 5] = [
+    pub const CUSTOM_ADDRESSES: [Address; 5] = [
         addrs::AI_INFERENCE,
         addrs::VECTOR_SIMILARITY,
         addrs::INTENT_PARSER,
@@ -45,9 +87,67 @@ impl MonmouthPrecompiles {
         addrs::CROSS_CHAIN_MESSAGE_PASSER,
     ];
 
+    /// Custom precompiles that call external backends.
+    pub const EXTERNAL_ADDRESSES: [Address; 2] = [addrs::AI_INFERENCE, addrs::VECTOR_SIMILARITY];
+
     /// Create a new Monmouth precompile provider with the given spec.
     pub fn new(spec: SpecId) -> Self {
-        Self { inner: EthPrecompiles::new(spec) }
+        Self {
+            inner: EthPrecompiles::new(spec),
+            external_calls: None,
+            inference: None,
+            accessed: HashSet::new(),
+        }
+    }
+
+    /// Create a provider whose AI inference precompile calls `backend`.
+    pub fn with_backend(spec: SpecId, backend: Arc<dyn InferenceBackend>) -> Self {
+        Self { inference: Some(backend), ..Self::new(spec) }
+    }
+
+    /// Bound concurrent external-backend calls with `limiter`.
+    ///
+    /// Calls rejected by the limiter fail with `PrecompileError`.
+    #[must_use]
+    pub fn with_external_limiter(mut self, limiter: ExternalCallLimiter) -> Self {
+        self.external_calls = Some(limiter);
+        self
+    }
+
+    /// Reset per-transaction access tracking, making every custom precompile cold.
+    ///
+    /// Call before each transaction so repeat-access discounts do not leak
+    /// across transactions.
+    pub fn begin_transaction(&mut self) {
+        self.accessed.clear();
+    }
+
+    /// Mark `address` accessed and return its access surcharge.
+    ///
+    /// The first call in a transaction pays the cold surcharge; later calls pay
+    /// the warm one. Accesses are not rolled back with reverted frames.
+    fn access_gas(&mut self, address: Address) -> u64 {
+        if self.accessed.insert(address) {
+            gas::COLD_ACCESS_SURCHARGE
+        } else {
+            gas::WARM_ACCESS_SURCHARGE
+        }
+    }
+
+    /// Execute a custom precompile, charging its access surcharge first.
+    fn execute_accessed(
+        &mut self,
+        address: &Address,
+        input: &[u8],
+        gas_limit: u64,
+    ) -> InterpreterResult {
+        let access_gas = self.access_gas(*address);
+        match gas_limit.checked_sub(access_gas) {
+            Some(remaining) => {
+                charge_access(self.execute_custom(address, input, remaining), gas_limit, access_gas)
+            }
+            None => out_of_gas(gas_limit),
+        }
     }
 
     /// Check if an address is a custom Monmouth precompile.
@@ -68,24 +168,44 @@ impl MonmouthPrecompiles {
     }
 
     /// Execute a custom precompile.
-    fn execute_custom(address: &Address, input: &[u8], gas_limit: u64) -> InterpreterResult {
-        let (base_gas, output) = if *address == addrs::AI_INFERENCE {
-            (gas::AI_INFERENCE_BASE, execute_ai_inference(input))
-        } else if *address == addrs::VECTOR_SIMILARITY {
-            (gas::VECTOR_SIMILARITY_BASE, execute_vector_similarity(input))
+    ///
+    /// Gas is charged before the precompile runs. Vector similarity calldata is
+    /// decoded first, since its cost scales with the number of corpus rows.
+    fn execute_custom(&self, address: &Address, input: &[u8], gas_limit: u64) -> InterpreterResult {
+        let precompile_error = |gas| InterpreterResult {
+            result: InstructionResult::PrecompileError,
+            gas,
+            output: Bytes::new(),
+        };
+        let similarity = if *address == addrs::VECTOR_SIMILARITY {
+            match SimilarityQuery::decode(input) {
+                Some(query) => Some(query),
+                None => {
+                    tracing::debug!(
+                        input_len = input.len(),
+                        "malformed vector similarity calldata"
+                    );
+                    return precompile_error(Gas::new(gas_limit));
+                }
+            }
+        } else {
+            None
+        };
+
+        let base_gas = if *address == addrs::AI_INFERENCE {
+            gas::AI_INFERENCE_BASE
+        } else if let Some(query) = &similarity {
+            gas::VECTOR_SIMILARITY_PER_ROW
+                .saturating_mul(query.rows() as u64)
+                .saturating_add(gas::VECTOR_SIMILARITY_BASE)
         } else if *address == addrs::INTENT_PARSER {
-            (gas::INTENT_PARSER_BASE, execute_intent_parser(input))
+            gas::INTENT_PARSER_BASE
         } else if *address == addrs::SVM_ROUTER {
-            (gas::SVM_ROUTER_BASE, execute_svm_router(input))
-        } else if *address == addrs::CROSS_CHAIN_MESSAGE_PASSER {
-            (gas::CROSS_CHAIN_MESSAGE_PASSER_BASE, execute_cross_chain_message_passer(input))
+            gas::SVM_ROUTER_BASE
         } else {
-            // Should not reach here due to is_custom check
-            return InterpreterResult {
-                result: InstructionResult::PrecompileError,
-                gas: Gas::new(gas_limit),
-                output: Bytes::new(),
-            };
+            // Should not reach here due to is_custom check; the cross-chain
+            // message passer needs state access and is dispatched by `run`.
+            return precompile_error(Gas::new(gas_limit));
         };
 
         let mut gas = Gas::new(gas_limit);
@@ -98,6 +218,31 @@ impl MonmouthPrecompiles {
             };
         }
 
+        let output = if *address == addrs::AI_INFERENCE {
+            match &self.inference {
+                Some(backend) => match backend.infer(input) {
+                    Ok(output) => Bytes::from(output),
+                    Err(err) => {
+                        tracing::warn!(%err, "AI inference precompile failed");
+                        return precompile_error(gas);
+                    }
+                },
+                None => execute_ai_inference(input),
+            }
+        } else if let Some(query) = &similarity {
+            match query.best_match() {
+                Some((index, score)) => encode_match(index, score),
+                None => {
+                    tracing::debug!(rows = query.rows(), "no scorable vector similarity match");
+                    return precompile_error(gas);
+                }
+            }
+        } else if *address == addrs::INTENT_PARSER {
+            execute_intent_parser(input)
+        } else {
+            execute_svm_router(input)
+        };
+
         tracing::debug!(
             address = %address,
             input_len = input.len(),
@@ -108,6 +253,129 @@ impl MonmouthPrecompiles {
 
         InterpreterResult { result: InstructionResult::Return, gas, output }
     }
+
+    /// Execute the cross-chain message passer, assigning the next message nonce.
+    ///
+    /// Emits a [`MESSAGE_PASSED_SIGNATURE`] log recording the nonce, `sender`, and
+    /// payload hash; it is reverted with the call frame like the nonce increment.
+    /// The nonce write is charged like an `SSTORE` of the counter slot. Writing
+    /// state is not allowed in a static call, so one fails with `PrecompileError`.
+    fn execute_cross_chain<CTX: ContextTr>(
+        context: &mut CTX,
+        sender: Address,
+        input: &[u8],
+        gas_limit: u64,
+        is_static: bool,
+    ) -> Result<InterpreterResult, String> {
+        let mut gas = Gas::new(gas_limit);
+        if is_static {
+            tracing::debug!(%sender, "cross-chain message passer called in a static context");
+            return Ok(InterpreterResult {
+                result: InstructionResult::PrecompileError,
+                gas,
+                output: Bytes::new(),
+            });
+        }
+        let out_of_gas = |gas| InterpreterResult {
+            result: InstructionResult::PrecompileOOG,
+            gas,
+            output: Bytes::new(),
+        };
+        if !gas.record_cost(gas::CROSS_CHAIN_MESSAGE_PASSER_BASE) {
+            return Ok(out_of_gas(gas));
+        }
+
+        let (nonce, write_gas) = next_cross_chain_nonce(context.journal_mut())?;
+        if !gas.record_cost(write_gas) {
+            return Ok(out_of_gas(gas));
+        }
+        tracing::debug!(input_len = input.len(), nonce, "cross-chain message nonce assigned");
+        context.journal_mut().log(message_passed_log(nonce, sender, keccak256(input)));
+
+        Ok(InterpreterResult {
+            result: InstructionResult::Return,
+            gas,
+            output: execute_cross_chain_message_passer(input, nonce),
+        })
+    }
+}
+
+/// Out-of-gas result consuming all of `gas_limit`.
+fn out_of_gas(gas_limit: u64) -> InterpreterResult {
+    let mut gas = Gas::new(gas_limit);
+    gas.spend_all();
+    InterpreterResult { result: InstructionResult::PrecompileOOG, gas, output: Bytes::new() }
+}
+
+/// Re-base `result`, run with `gas_limit - access_gas`, onto the full `gas_limit`.
+fn charge_access(
+    mut result: InterpreterResult,
+    gas_limit: u64,
+    access_gas: u64,
+) -> InterpreterResult {
+    let mut gas = Gas::new(gas_limit);
+    gas.record_cost(access_gas.saturating_add(result.gas.spent()));
+    result.gas = gas;
+    result
+}
+
+/// Increment the cross-chain message nonce stored in the journal.
+///
+/// Returns the new nonce and the gas an `SSTORE` making the same write would
+/// cost under EIP-2200 and EIP-2929. Nonces start at 1 and are strictly
+/// monotonic across blocks. A reverted call frame rolls the increment back with
+/// the rest of its state changes. Callers reject static calls before this.
+fn next_cross_chain_nonce<J: JournalTr>(journal: &mut J) -> Result<(u64, u64), String> {
+    let passer = addrs::CROSS_CHAIN_MESSAGE_PASSER;
+    journal.load_account(passer).map_err(|e| e.to_string())?;
+    journal.touch_account(passer);
+
+    let current = journal.sload(passer, CROSS_CHAIN_NONCE_SLOT).map_err(|e| e.to_string())?;
+    let next = current
+        .data
+        .checked_add(U256::from(1))
+        .filter(|n| *n <= U256::from(u64::MAX))
+        .ok_or_else(|| "cross-chain nonce overflow".to_string())?;
+    let write =
+        journal.sstore(passer, CROSS_CHAIN_NONCE_SLOT, next).map_err(|e| e.to_string())?.data;
+
+    let write_gas = if write.original_value != write.present_value {
+        gas::NONCE_DIRTY
+    } else if write.original_value.is_zero() {
+        gas::NONCE_SET
+    } else {
+        gas::NONCE_RESET
+    };
+    let access_gas = if current.is_cold { gas::NONCE_SLOT_COLD } else { 0 };
+    Ok((next.to::<u64>(), write_gas + access_gas))
+}
+
+/// Build the [`MESSAGE_PASSED_SIGNATURE`] log for message `nonce`.
+fn message_passed_log(nonce: u64, sender: Address, payload_hash: B256) -> Log {
+    let mut data = Vec::with_capacity(64);
+    data.extend_from_slice(sender.into_word().as_slice());
+    data.extend_from_slice(payload_hash.as_slice());
+    Log::new_unchecked(
+        addrs::CROSS_CHAIN_MESSAGE_PASSER,
+        vec![keccak256(MESSAGE_PASSED_SIGNATURE), B256::from(U256::from(nonce))],
+        Bytes::from(data),
+    )
+}
+
+/// Returns the highest cross-chain message nonce assigned in `state`, or 0 if none.
+pub async fn highest_cross_chain_nonce<S: StateDbRead>(state: &S) -> Result<u64, StateDbError> {
+    let value = state.storage(&addrs::CROSS_CHAIN_MESSAGE_PASSER, &CROSS_CHAIN_NONCE_SLOT).await?;
+    Ok(value.saturating_to::<u64>())
+}
+
+/// Returns `true` if `nonce` has already been issued to a cross-chain message in `state`.
+///
+/// Relayers use this to drop replayed messages after the queue is rebuilt.
+pub async fn cross_chain_nonce_issued<S: StateDbRead>(
+    state: &S,
+    nonce: u64,
+) -> Result<bool, StateDbError> {
+    Ok(nonce != 0 && nonce <= highest_cross_chain_nonce(state).await?)
 }
 
 impl<CTX: ContextTr> PrecompileProvider<CTX> for MonmouthPrecompiles {
@@ -131,9 +399,41 @@ impl<CTX: ContextTr> PrecompileProvider<CTX> for MonmouthPrecompiles {
                 }
                 CallInput::Bytes(bytes) => bytes.0.to_vec(),
             };
-            let result =
-                Self::execute_custom(&inputs.bytecode_address, &input_bytes, inputs.gas_limit);
-            return Ok(Some(result));
+            if inputs.bytecode_address == addrs::CROSS_CHAIN_MESSAGE_PASSER {
+                let access_gas = self.access_gas(inputs.bytecode_address);
+                let Some(remaining) = inputs.gas_limit.checked_sub(access_gas) else {
+                    return Ok(Some(out_of_gas(inputs.gas_limit)));
+                };
+                return Self::execute_cross_chain(
+                    context,
+                    inputs.caller,
+                    &input_bytes,
+                    remaining,
+                    inputs.is_static,
+                )
+                .map(|result| Some(charge_access(result, inputs.gas_limit, access_gas)));
+            }
+            let _permit = match &self.external_calls {
+                Some(limiter) if Self::EXTERNAL_ADDRESSES.contains(&inputs.bytecode_address) => {
+                    match limiter.acquire() {
+                        Ok(permit) => Some(permit),
+                        Err(err) => {
+                            tracing::warn!(address = %inputs.bytecode_address, %err, "external precompile call rejected");
+                            return Ok(Some(InterpreterResult {
+                                result: InstructionResult::PrecompileError,
+                                gas: Gas::new(inputs.gas_limit),
+                                output: Bytes::new(),
+                            }));
+                        }
+                    }
+                }
+                _ => None,
+            };
+            return Ok(Some(self.execute_accessed(
+                &inputs.bytecode_address,
+                &input_bytes,
+                inputs.gas_limit,
+            )));
         }
 
         // Delegate to standard Ethereum precompiles
@@ -153,7 +453,7 @@ impl<CTX: ContextTr> PrecompileProvider<CTX> for MonmouthPrecompiles {
 
 // --- Stub implementations ---
 
-/// AI Inference precompile (0x1000).
+/// AI Inference precompile (0x1000) without a configured backend.
 /// Accepts input data and returns a mock inference result.
 fn execute_ai_inference(input: &[u8]) -> Bytes {
     tracing::info!(input_len = input.len(), "AI Inference precompile called");
@@ -169,31 +469,16 @@ fn execute_ai_inference(input: &[u8]) -> Bytes {
     Bytes::from(output)
 }
 
-/// Vector Similarity precompile (0x1001).
-/// Semantic search stub.
-fn execute_vector_similarity(input: &[u8]) -> Bytes {
-    tracing::info!(input_len = input.len(), "Vector Similarity precompile called");
-    // Return mock similarity score: uint256 score (0.85 scaled to 1e18)
-    let mut output = [0u8; 32];
-    // 0.85 * 1e18 = 850000000000000000 = 0x0BC8D3F7B3340000
-    output[24..32].copy_from_slice(&850_000_000_000_000_000u64.to_be_bytes());
-    Bytes::from(output.to_vec())
-}
-
 /// Intent Parser precompile (0x1002).
-/// Natural language → structured intent stub.
+/// Natural language → structured intent; see [`ParsedIntent::parse`].
 fn execute_intent_parser(input: &[u8]) -> Bytes {
-    tracing::info!(input_len = input.len(), "Intent Parser precompile called");
-    // Return mock parsed intent: (uint8 intentType, address target, uint256 value)
-    let mut output = Vec::with_capacity(96);
-    // intentType = 1 (transfer)
-    output.extend_from_slice(&[0u8; 31]);
-    output.push(1);
-    // target = zero address
-    output.extend_from_slice(&[0u8; 32]);
-    // value = 0
-    output.extend_from_slice(&[0u8; 32]);
-    Bytes::from(output)
+    let intent = ParsedIntent::parse(input);
+    tracing::info!(
+        input_len = input.len(),
+        parsed = intent.parsed,
+        "Intent Parser precompile called"
+    );
+    intent.encode()
 }
 
 /// SVM Router precompile (0x1003).
@@ -212,13 +497,10 @@ fn execute_svm_router(input: &[u8]) -> Bytes {
 
 /// Cross-Chain Message Passer precompile (0x4200).
 /// Cross-chain deposit/withdrawal message passing.
-fn execute_cross_chain_message_passer(input: &[u8]) -> Bytes {
+fn execute_cross_chain_message_passer(input: &[u8], nonce: u64) -> Bytes {
     tracing::info!(input_len = input.len(), "Cross-Chain Message Passer precompile called");
     // Return message nonce: uint256 nonce
-    let mut output = [0u8; 32];
-    // nonce = 1 (first message)
-    output[31] = 1;
-    Bytes::from(output.to_vec())
+    Bytes::from(U256::from(nonce).to_be_bytes::<32>().to_vec())
 }
 
 #[cfg(test)]
@@ -274,16 +556,48 @@ mod tests {
     }
 
     #[test]
-    fn vector_similarity_returns_score() {
-        let output = execute_vector_similarity(&[]);
-        assert_eq!(output.len(), 32);
+    fn vector_similarity_gas_scales_with_rows() {
+        use crate::vector::tests::encode_query;
+
+        let precompiles = MonmouthPrecompiles::new(SpecId::PRAGUE);
+        let input = encode_query(&[1, 2], &[&[0, 1], &[2, 4], &[1, 0]]);
+        let result = precompiles.execute_custom(&addrs::VECTOR_SIMILARITY, &input, 100_000);
+        assert_eq!(result.result, InstructionResult::Return);
+        assert_eq!(
+            result.gas.spent(),
+            gas::VECTOR_SIMILARITY_BASE + 3 * gas::VECTOR_SIMILARITY_PER_ROW
+        );
+        assert_eq!(U256::from_be_slice(&result.output[..32]), U256::from(1));
+
+        let result = precompiles.execute_custom(
+            &addrs::VECTOR_SIMILARITY,
+            &input,
+            gas::VECTOR_SIMILARITY_BASE,
+        );
+        assert_eq!(result.result, InstructionResult::PrecompileOOG);
+    }
+
+    #[test]
+    fn vector_similarity_rejects_unscorable_input() {
+        use crate::vector::tests::encode_query;
+
+        let precompiles = MonmouthPrecompiles::new(SpecId::PRAGUE);
+        for input in [vec![0xde, 0xad], encode_query(&[1, 2], &[])] {
+            let result = precompiles.execute_custom(&addrs::VECTOR_SIMILARITY, &input, 100_000);
+            assert_eq!(result.result, InstructionResult::PrecompileError);
+        }
     }
 
     #[test]
     fn intent_parser_returns_intent() {
+        let output = execute_intent_parser(b"stake 32");
+        assert_eq!(output.len(), 128);
+        assert_eq!(output[31], ParsedIntent::STAKE);
+        assert_eq!(output[127], 1); // parsed = true
+
         let output = execute_intent_parser(&[0xde, 0xad]);
-        assert_eq!(output.len(), 96);
-        assert_eq!(output[31], 1); // intentType = 1
+        assert_eq!(output.len(), 128);
+        assert!(output.iter().all(|byte| *byte == 0));
     }
 
     #[test]
@@ -295,22 +609,191 @@ mod tests {
 
     #[test]
     fn cross_chain_message_passer_returns_nonce() {
-        let output = execute_cross_chain_message_passer(&[0x01]);
+        let output = execute_cross_chain_message_passer(&[0x01], 1);
         assert_eq!(output.len(), 32);
         assert_eq!(output[31], 1); // nonce = 1
+
+        let output = execute_cross_chain_message_passer(&[0x01], 0x0102);
+        assert_eq!(&output[30..], &[0x01, 0x02]);
+    }
+
+    #[test]
+    fn cross_chain_nonce_continues_from_committed_state() {
+        use revm::{
+            Journal,
+            database::{CacheDB, EmptyDB},
+        };
+
+        // Simulate a restart: the counter was committed by earlier blocks.
+        let mut db = CacheDB::new(EmptyDB::default());
+        db.insert_account_storage(
+            addrs::CROSS_CHAIN_MESSAGE_PASSER,
+            CROSS_CHAIN_NONCE_SLOT,
+            U256::from(5),
+        )
+        .unwrap();
+        let mut journal = <Journal<_> as JournalTr>::new(db);
+
+        assert_eq!(
+            next_cross_chain_nonce(&mut journal),
+            Ok((6, gas::NONCE_RESET + gas::NONCE_SLOT_COLD))
+        );
+        assert_eq!(next_cross_chain_nonce(&mut journal), Ok((7, gas::NONCE_DIRTY)));
+    }
+
+    #[test]
+    fn cross_chain_messages_get_sequential_nonces() {
+        use revm::{Context, MainContext, database::EmptyDB};
+
+        let mut context = Context::mainnet().with_db(EmptyDB::default());
+        let sender = Address::repeat_byte(0x11);
+        for expected in 1..=3u64 {
+            let payload = [expected as u8; 4];
+            let result = MonmouthPrecompiles::execute_cross_chain(
+                &mut context,
+                sender,
+                &payload,
+                100_000,
+                false,
+            )
+            .unwrap();
+            assert_eq!(result.result, InstructionResult::Return);
+            assert_eq!(U256::from_be_slice(&result.output), U256::from(expected));
+        }
+
+        let logs = context.journal_mut().take_logs();
+        assert_eq!(logs.len(), 3);
+        for (log, nonce) in logs.iter().zip(1..=3u64) {
+            assert_eq!(log.address, addrs::CROSS_CHAIN_MESSAGE_PASSER);
+            assert_eq!(log.topics()[0], keccak256(MESSAGE_PASSED_SIGNATURE));
+            assert_eq!(log.topics()[1], B256::from(U256::from(nonce)));
+            assert_eq!(&log.data.data[..32], sender.into_word().as_slice());
+            assert_eq!(&log.data.data[32..], keccak256([nonce as u8; 4]).as_slice());
+        }
+    }
+
+    #[test]
+    fn cross_chain_nonce_starts_at_one() {
+        use revm::{Journal, database::EmptyDB};
+
+        let mut journal = <Journal<_> as JournalTr>::new(EmptyDB::default());
+        assert_eq!(
+            next_cross_chain_nonce(&mut journal),
+            Ok((1, gas::NONCE_SET + gas::NONCE_SLOT_COLD))
+        );
+    }
+
+    #[test]
+    fn cross_chain_message_charges_nonce_write() {
+        use revm::{Context, MainContext, database::EmptyDB};
+
+        let mut context = Context::mainnet().with_db(EmptyDB::default());
+        let sender = Address::repeat_byte(0x11);
+        let base = gas::CROSS_CHAIN_MESSAGE_PASSER_BASE;
+        let first_write = gas::NONCE_SET + gas::NONCE_SLOT_COLD;
+
+        let first =
+            MonmouthPrecompiles::execute_cross_chain(&mut context, sender, &[], 100_000, false)
+                .unwrap();
+        assert_eq!(first.gas.spent(), base + first_write);
+        let second =
+            MonmouthPrecompiles::execute_cross_chain(&mut context, sender, &[], 100_000, false)
+                .unwrap();
+        assert_eq!(second.gas.spent(), base + gas::NONCE_DIRTY);
+
+        let starved =
+            MonmouthPrecompiles::execute_cross_chain(&mut context, sender, &[], base, false)
+                .unwrap();
+        assert_eq!(starved.result, InstructionResult::PrecompileOOG);
+    }
+
+    #[test]
+    fn cross_chain_message_rejects_static_calls() {
+        use revm::{Context, MainContext, database::EmptyDB};
+
+        let mut context = Context::mainnet().with_db(EmptyDB::default());
+        let result = MonmouthPrecompiles::execute_cross_chain(
+            &mut context,
+            Address::repeat_byte(0x11),
+            &[0x01],
+            100_000,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(result.result, InstructionResult::PrecompileError);
+        assert!(context.journal_mut().take_logs().is_empty());
+        let (nonce, _) = next_cross_chain_nonce(context.journal_mut()).unwrap();
+        assert_eq!(nonce, 1);
     }
 
     #[test]
     fn execute_custom_out_of_gas() {
         // AI inference needs 10000 gas, give it only 100
-        let result = MonmouthPrecompiles::execute_custom(&addrs::AI_INFERENCE, &[], 100);
+        let precompiles = MonmouthPrecompiles::new(SpecId::PRAGUE);
+        let result = precompiles.execute_custom(&addrs::AI_INFERENCE, &[], 100);
         assert_eq!(result.result, InstructionResult::PrecompileOOG);
     }
 
     #[test]
     fn execute_custom_sufficient_gas() {
-        let result = MonmouthPrecompiles::execute_custom(&addrs::AI_INFERENCE, &[], 100_000);
+        let precompiles = MonmouthPrecompiles::new(SpecId::PRAGUE);
+        let result = precompiles.execute_custom(&addrs::AI_INFERENCE, &[], 100_000);
         assert_eq!(result.result, InstructionResult::Return);
         assert!(!result.output.is_empty());
     }
+
+    #[test]
+    fn repeat_access_is_warm() {
+        let mut precompiles = MonmouthPrecompiles::new(SpecId::PRAGUE);
+        let first = precompiles.execute_accessed(&addrs::SVM_ROUTER, &[], 100_000);
+        let second = precompiles.execute_accessed(&addrs::SVM_ROUTER, &[], 100_000);
+        assert_eq!(first.result, InstructionResult::Return);
+        assert_eq!(second.result, InstructionResult::Return);
+        assert_eq!(first.gas.spent(), gas::SVM_ROUTER_BASE + gas::COLD_ACCESS_SURCHARGE);
+        assert_eq!(second.gas.spent(), gas::SVM_ROUTER_BASE + gas::WARM_ACCESS_SURCHARGE);
+        assert!(second.gas.spent() < first.gas.spent());
+
+        // Other custom precompiles stay cold until called.
+        let other = precompiles.execute_accessed(&addrs::INTENT_PARSER, &[], 100_000);
+        assert_eq!(other.gas.spent(), gas::INTENT_PARSER_BASE + gas::COLD_ACCESS_SURCHARGE);
+
+        // A new transaction starts cold again.
+        precompiles.begin_transaction();
+        let third = precompiles.execute_accessed(&addrs::SVM_ROUTER, &[], 100_000);
+        assert_eq!(third.gas.spent(), first.gas.spent());
+    }
+
+    #[test]
+    fn access_surcharge_counts_toward_gas_limit() {
+        let mut precompiles = MonmouthPrecompiles::new(SpecId::PRAGUE);
+        let result = precompiles.execute_accessed(&addrs::SVM_ROUTER, &[], gas::SVM_ROUTER_BASE);
+        assert_eq!(result.result, InstructionResult::PrecompileOOG);
+
+        let result = precompiles.execute_accessed(&addrs::SVM_ROUTER, &[], 50);
+        assert_eq!(result.result, InstructionResult::PrecompileOOG);
+        assert_eq!(result.gas.spent(), 50);
+    }
+
+    #[derive(Debug)]
+    struct InputLength;
+
+    impl InferenceBackend for InputLength {
+        fn infer(&self, input: &[u8]) -> Result<Vec<u8>, crate::InferenceError> {
+            Ok(U256::from(input.len()).to_be_bytes::<32>().to_vec())
+        }
+    }
+
+    #[test]
+    fn ai_inference_routes_to_backend() {
+        let precompiles = MonmouthPrecompiles::with_backend(SpecId::PRAGUE, Arc::new(InputLength));
+        let result = precompiles.execute_custom(&addrs::AI_INFERENCE, &[0xab; 5], 100_000);
+        assert_eq!(result.result, InstructionResult::Return);
+        assert_eq!(result.output.as_ref(), U256::from(5).to_be_bytes::<32>());
+        assert_eq!(result.gas.spent(), gas::AI_INFERENCE_BASE);
+
+        // Gas is charged before the backend is consulted.
+        let result = precompiles.execute_custom(&addrs::AI_INFERENCE, &[0xab; 5], 100);
+        assert_eq!(result.result, InstructionResult::PrecompileOOG);
+    }
 }
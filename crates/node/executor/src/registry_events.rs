@@ -0,0 +1,301 @@
+//! Decoding of ERC-8004 agent-registry events from execution receipts.
+//!
+//! Bridges classification with agent-activity indexing: for successful
+//! [`TransactionClassification::AgentToAgent`] transactions, the logs emitted by the
+//! well-known registries are decoded into typed events. Logs with an unknown
+//! shape, topic, or emitter are skipped.
+
+use alloy_primitives::{Address, B256, Log, U256, keccak256};
+
+use crate::{
+    ExecutionReceipt, TransactionClassification,
+    classifier::registries::{IDENTITY_REGISTRY, REPUTATION_REGISTRY, VALIDATION_REGISTRY},
+};
+
+/// Event signature emitted by the IdentityRegistry when an agent registers.
+pub const AGENT_REGISTERED_SIGNATURE: &str = "AgentRegistered(uint256,string,address)";
+
+/// Event signature emitted by the ReputationRegistry when feedback is given.
+pub const FEEDBACK_GIVEN_SIGNATURE: &str = "FeedbackGiven(uint256,address,uint8,bytes32)";
+
+/// Event signature emitted by the ValidationRegistry when validation is requested.
+pub const VALIDATION_REQUESTED_SIGNATURE: &str = "ValidationRequested(uint256,address,bytes32)";
+
+/// `AgentRegistered(uint256 indexed agentId, string agentDomain, address agentAddress)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AgentRegistered {
+    /// Identifier assigned to the agent.
+    pub agent_id: U256,
+    /// Domain the agent card is served from.
+    pub agent_domain: String,
+    /// Address controlling the agent.
+    pub agent_address: Address,
+}
+
+/// `FeedbackGiven(uint256 indexed agentId, address indexed client, uint8 score, bytes32 hash)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeedbackGiven {
+    /// Agent receiving the feedback.
+    pub agent_id: U256,
+    /// Client giving the feedback.
+    pub client: Address,
+    /// Feedback score.
+    pub score: u8,
+    /// Hash of the off-chain feedback payload.
+    pub feedback_hash: B256,
+}
+
+/// `ValidationRequested(uint256 indexed agentId, address indexed validator, bytes32 dataHash)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationRequested {
+    /// Agent whose work is to be validated.
+    pub agent_id: U256,
+    /// Validator asked to perform the validation.
+    pub validator: Address,
+    /// Hash of the data to validate.
+    pub data_hash: B256,
+}
+
+/// A decoded ERC-8004 registry event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AgentRegistryEvent {
+    /// An agent was registered in the IdentityRegistry.
+    AgentRegistered(AgentRegistered),
+    /// Feedback was recorded in the ReputationRegistry.
+    FeedbackGiven(FeedbackGiven),
+    /// Validation was requested from the ValidationRegistry.
+    ValidationRequested(ValidationRequested),
+}
+
+/// Decode the registry events of a classified transaction's receipt.
+///
+/// Returns an empty list unless the transaction was classified as
+/// [`TransactionClassification::AgentToAgent`] and succeeded. Logs that are not
+/// recognised registry events are skipped.
+pub fn decode_registry_events(
+    classification: &TransactionClassification,
+    receipt: &ExecutionReceipt,
+) -> Vec<AgentRegistryEvent> {
+    if *classification != TransactionClassification::AgentToAgent || !receipt.success() {
+        return Vec::new();
+    }
+    receipt.logs().iter().filter_map(decode_registry_event).collect()
+}
+
+/// Decode a single log into a registry event.
+///
+/// Returns `None` if the log was not emitted by the matching registry or does not
+/// have the expected topics and data layout.
+pub fn decode_registry_event(log: &Log) -> Option<AgentRegistryEvent> {
+    let topics = log.topics();
+    let signature = *topics.first()?;
+    let data = log.data.data.as_ref();
+
+    if log.address == IDENTITY_REGISTRY && signature == keccak256(AGENT_REGISTERED_SIGNATURE) {
+        let [_, agent_id] = topics else { return None };
+        if data.len() < 64 {
+            return None;
+        }
+        let agent_domain = decode_string(data, word(data, 0)?)?;
+        let agent_address = decode_address(word(data, 1)?)?;
+        return Some(AgentRegistryEvent::AgentRegistered(AgentRegistered {
+            agent_id: U256::from_be_bytes(agent_id.0),
+            agent_domain,
+            agent_address,
+        }));
+    }
+
+    if log.address == REPUTATION_REGISTRY && signature == keccak256(FEEDBACK_GIVEN_SIGNATURE) {
+        let [_, agent_id, client] = topics else { return None };
+        if data.len() != 64 {
+            return None;
+        }
+        let score = u8::try_from(U256::from_be_bytes(word(data, 0)?)).ok()?;
+        return Some(AgentRegistryEvent::FeedbackGiven(FeedbackGiven {
+            agent_id: U256::from_be_bytes(agent_id.0),
+            client: decode_address(client.0)?,
+            score,
+            feedback_hash: B256::from(word(data, 1)?),
+        }));
+    }
+
+    if log.address == VALIDATION_REGISTRY && signature == keccak256(VALIDATION_REQUESTED_SIGNATURE)
+    {
+        let [_, agent_id, validator] = topics else { return None };
+        if data.len() != 32 {
+            return None;
+        }
+        return Some(AgentRegistryEvent::ValidationRequested(ValidationRequested {
+            agent_id: U256::from_be_bytes(agent_id.0),
+            validator: decode_address(validator.0)?,
+            data_hash: B256::from(word(data, 0)?),
+        }));
+    }
+
+    None
+}
+
+/// Returns the 32-byte ABI word at `index`.
+fn word(data: &[u8], index: usize) -> Option<[u8; 32]> {
+    let start = index.checked_mul(32)?;
+    data.get(start..start.checked_add(32)?)?.try_into().ok()
+}
+
+/// Decode a left-padded address word, rejecting dirty high bytes.
+fn decode_address(word: [u8; 32]) -> Option<Address> {
+    word[..12].iter().all(|b| *b == 0).then(|| Address::from_slice(&word[12..]))
+}
+
+/// Decode a dynamic `string` whose head word holds its byte offset.
+fn decode_string(data: &[u8], offset_word: [u8; 32]) -> Option<String> {
+    let offset = usize::try_from(U256::from_be_bytes(offset_word)).ok()?;
+    if offset % 32 != 0 {
+        return None;
+    }
+    let len = usize::try_from(U256::from_be_bytes(word(data, offset / 32)?)).ok()?;
+    let start = offset.checked_add(32)?;
+    let bytes = data.get(start..start.checked_add(len)?)?;
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, LogData};
+
+    use super::*;
+
+    fn agent_registered_log(agent_id: u64, domain: &str, agent: Address) -> Log {
+        let mut data = Vec::new();
+        data.extend_from_slice(&U256::from(64).to_be_bytes::<32>());
+        data.extend_from_slice(B256::left_padding_from(agent.as_slice()).as_slice());
+        data.extend_from_slice(&U256::from(domain.len()).to_be_bytes::<32>());
+        let mut padded = domain.as_bytes().to_vec();
+        padded.resize(domain.len().div_ceil(32) * 32, 0);
+        data.extend_from_slice(&padded);
+
+        Log {
+            address: IDENTITY_REGISTRY,
+            data: LogData::new_unchecked(
+                vec![
+                    keccak256(AGENT_REGISTERED_SIGNATURE),
+                    U256::from(agent_id).to_be_bytes::<32>().into(),
+                ],
+                Bytes::from(data),
+            ),
+        }
+    }
+
+    fn receipt(success: bool, logs: Vec<Log>) -> ExecutionReceipt {
+        ExecutionReceipt::new(B256::ZERO, success, 50_000, 50_000, logs, None)
+    }
+
+    #[test]
+    fn decodes_agent_registered() {
+        let agent = Address::repeat_byte(0xaa);
+        let log = agent_registered_log(7, "agent.example.com", agent);
+
+        let event = decode_registry_event(&log).expect("decodes");
+
+        assert_eq!(
+            event,
+            AgentRegistryEvent::AgentRegistered(AgentRegistered {
+                agent_id: U256::from(7),
+                agent_domain: "agent.example.com".into(),
+                agent_address: agent,
+            })
+        );
+    }
+
+    #[test]
+    fn decodes_feedback_and_validation() {
+        let client = Address::repeat_byte(0x11);
+        let mut data = U256::from(90).to_be_bytes::<32>().to_vec();
+        data.extend_from_slice(&[0x22; 32]);
+        let feedback = Log {
+            address: REPUTATION_REGISTRY,
+            data: LogData::new_unchecked(
+                vec![
+                    keccak256(FEEDBACK_GIVEN_SIGNATURE),
+                    U256::from(3).to_be_bytes::<32>().into(),
+                    B256::left_padding_from(client.as_slice()),
+                ],
+                Bytes::from(data),
+            ),
+        };
+        let validator = Address::repeat_byte(0x33);
+        let validation = Log {
+            address: VALIDATION_REGISTRY,
+            data: LogData::new_unchecked(
+                vec![
+                    keccak256(VALIDATION_REQUESTED_SIGNATURE),
+                    U256::from(3).to_be_bytes::<32>().into(),
+                    B256::left_padding_from(validator.as_slice()),
+                ],
+                Bytes::from(vec![0x44; 32]),
+            ),
+        };
+
+        let events = decode_registry_events(
+            &TransactionClassification::AgentToAgent,
+            &receipt(true, vec![feedback, validation]),
+        );
+
+        assert_eq!(
+            events,
+            vec![
+                AgentRegistryEvent::FeedbackGiven(FeedbackGiven {
+                    agent_id: U256::from(3),
+                    client,
+                    score: 90,
+                    feedback_hash: B256::repeat_byte(0x22),
+                }),
+                AgentRegistryEvent::ValidationRequested(ValidationRequested {
+                    agent_id: U256::from(3),
+                    validator,
+                    data_hash: B256::repeat_byte(0x44),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_unknown_and_malformed_logs() {
+        let agent = Address::repeat_byte(0xaa);
+        let valid = agent_registered_log(1, "a", agent);
+
+        let mut wrong_emitter = valid.clone();
+        wrong_emitter.address = REPUTATION_REGISTRY;
+
+        let mut truncated = valid.clone();
+        truncated.data = LogData::new_unchecked(
+            valid.topics().to_vec(),
+            Bytes::copy_from_slice(&valid.data.data[..40]),
+        );
+
+        let unknown = Log {
+            address: IDENTITY_REGISTRY,
+            data: LogData::new_unchecked(vec![B256::repeat_byte(0x99)], Bytes::new()),
+        };
+
+        let events = decode_registry_events(
+            &TransactionClassification::AgentToAgent,
+            &receipt(true, vec![wrong_emitter, truncated, unknown, valid]),
+        );
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], AgentRegistryEvent::AgentRegistered(_)));
+    }
+
+    #[test]
+    fn ignores_failed_or_non_agent_transactions() {
+        let log = agent_registered_log(1, "a", Address::repeat_byte(0xaa));
+
+        let failed = receipt(false, vec![log.clone()]);
+        assert!(
+            decode_registry_events(&TransactionClassification::AgentToAgent, &failed).is_empty()
+        );
+
+        let pure = receipt(true, vec![log]);
+        assert!(decode_registry_events(&TransactionClassification::PureEvm, &pure).is_empty());
+    }
+}
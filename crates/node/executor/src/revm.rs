@@ -1,32 +1,49 @@
 //! REVM-based block executor.
 
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use alloy_consensus::Header;
-use alloy_primitives::{B256, Bytes, U256, keccak256};
+use alloy_primitives::{Address, B256, Bytes, KECCAK256_EMPTY, U256, keccak256};
+use monmouth_domain::SenderCache;
 use monmouth_qmdb::{AccountUpdate, ChangeSet};
 use monmouth_traits::StateDb;
 use revm::{
-    Context, ExecuteEvm, Journal, MainBuilder,
+    Context, InspectEvm, Journal, MainBuilder,
     bytecode::Bytecode,
     context::{
+        CfgEnv, TxEnv,
         block::BlockEnv,
         result::{ExecutionResult, Output},
     },
     context_interface::{
         ContextSetters,
-        transaction::{AccessList, AccessListItem},
+        transaction::{AccessList, AccessListItem, Transaction as _},
     },
-    database::State,
+    database::{State, WrapDatabaseRef},
+    database_interface::DatabaseRef,
     primitives::{TxKind, hardfork::SpecId},
     state::{EvmState, EvmStorageSlot},
 };
 
 use crate::{
     BlockContext, BlockExecutor, ExecutionConfig, ExecutionError, ExecutionOutcome,
-    ExecutionReceipt, ParentBlock, StateDbAdapter, TransactionClassifier,
+    ExecutionPipeline, ExecutionReceipt, ExternalCallLimiter, InferenceBackend, IntentReceipt,
+    MonmouthPrecompiles, ParentBlock, RoutingObserver, RoutingOutcome, RoutingRecord,
+    StateDbAdapter, TransactionClassifier, code_size,
+    fees::settle_priority_fees,
+    intent::IntentCollector,
+    log_limit::LogLimit,
+    parallel::{AccessSet, BlockRun, StateKey},
+    trace::{CallTracer, TransactionTrace},
 };
 
+pub(crate) type EvmDb<S> = State<WrapDatabaseRef<StateDbAdapter<S>>>;
+pub(crate) type EvmContext<S> = Context<BlockEnv, TxEnv, CfgEnv, EvmDb<S>, Journal<EvmDb<S>>, ()>;
+
 /// REVM-based block executor.
 ///
 /// This executor uses REVM to execute EVM transactions against a state database.
@@ -37,19 +54,59 @@ pub struct RevmExecutor {
     config: ExecutionConfig,
     /// Optional agent-aware transaction classifier.
     classifier: Option<TransactionClassifier>,
+    /// Optional cache of senders recovered at mempool admission.
+    sender_cache: Option<SenderCache>,
+    /// Limiter shared by all executions when external calls are bounded.
+    external_calls: Option<ExternalCallLimiter>,
+    /// Backend for the AI inference precompile, if configured.
+    inference: Option<Arc<dyn InferenceBackend>>,
+    /// Observer of routing decisions for classified transactions, if configured.
+    routing: Option<Arc<dyn RoutingObserver>>,
 }
 
 impl RevmExecutor {
     /// Create a new REVM executor with the given chain ID.
     #[must_use]
     pub const fn new(chain_id: u64) -> Self {
-        Self { config: ExecutionConfig::new(chain_id), classifier: None }
+        Self {
+            config: ExecutionConfig::new(chain_id),
+            classifier: None,
+            sender_cache: None,
+            external_calls: None,
+            inference: None,
+            routing: None,
+        }
     }
 
     /// Create a new REVM executor with full configuration.
     #[must_use]
-    pub const fn with_config(config: ExecutionConfig) -> Self {
-        Self { config, classifier: None }
+    pub fn with_config(config: ExecutionConfig) -> Self {
+        let external_calls = config
+            .precompiles
+            .max_concurrent_external_calls
+            .map(|max| ExternalCallLimiter::new(max, config.precompiles.max_queued_external_calls));
+        Self {
+            config,
+            classifier: None,
+            sender_cache: None,
+            external_calls,
+            inference: None,
+            routing: None,
+        }
+    }
+
+    /// Build the precompile provider for one execution.
+    fn precompiles(&self) -> MonmouthPrecompiles {
+        let precompiles = match &self.inference {
+            Some(backend) => {
+                MonmouthPrecompiles::with_backend(self.config.spec_id, backend.clone())
+            }
+            None => MonmouthPrecompiles::new(self.config.spec_id),
+        };
+        match &self.external_calls {
+            Some(limiter) => precompiles.with_external_limiter(limiter.clone()),
+            None => precompiles,
+        }
     }
 
     /// Enable agent-aware transaction classification.
@@ -59,6 +116,29 @@ impl RevmExecutor {
         self
     }
 
+    /// Answer AI inference precompile calls with `backend` instead of the mock response.
+    #[must_use]
+    pub fn with_inference_backend(mut self, backend: Arc<dyn InferenceBackend>) -> Self {
+        self.inference = Some(backend);
+        self
+    }
+
+    /// Report a [`RoutingRecord`] to `observer` for every classified transaction.
+    ///
+    /// Has no effect unless a classifier is attached with [`Self::with_classifier`].
+    #[must_use]
+    pub fn with_routing_observer(mut self, observer: Arc<dyn RoutingObserver>) -> Self {
+        self.routing = Some(observer);
+        self
+    }
+
+    /// Reuse senders recovered at mempool admission instead of recovering them again.
+    #[must_use]
+    pub fn with_sender_cache(mut self, cache: SenderCache) -> Self {
+        self.sender_cache = Some(cache);
+        self
+    }
+
     /// Get the chain ID.
     pub const fn chain_id(&self) -> u64 {
         self.config.chain_id
@@ -74,6 +154,179 @@ impl RevmExecutor {
         self.config.spec_id
     }
 
+    /// Recover the sender of `tx_bytes`, consulting the sender cache first.
+    pub(crate) fn sender(&self, tx_bytes: &Bytes) -> Result<Address, ExecutionError> {
+        decode_tx_env(tx_bytes, self.sender_cache.as_ref()).map(|tx_env| tx_env.caller)
+    }
+
+    /// Execute `txs` in order on one EVM without settling priority fees.
+    ///
+    /// Receipts are numbered from the start of `txs`. When `track_access` is set,
+    /// the state each transaction read and wrote is recorded in the run.
+    pub(crate) fn run<S: StateDb>(
+        &self,
+        state: &S,
+        context: &BlockContext,
+        txs: &[Bytes],
+        deadline: ExecutionDeadline,
+        track_access: bool,
+    ) -> Result<BlockRun, ExecutionError> {
+        let log_limit = LogLimit::new(self.config.max_logs_per_tx.unwrap_or(usize::MAX));
+        let mut evm = self
+            .evm_context(state, context)
+            .build_mainnet_with_inspector((log_limit, IntentCollector::default()))
+            .with_precompiles(self.precompiles());
+        code_size::install(&mut evm.instruction);
+
+        let mut run = BlockRun::default();
+        let mut cumulative_gas = 0u64;
+        let base_fee = u128::from(context.header.base_fee_per_gas.unwrap_or_default());
+        let tracker = track_access.then(|| StateDbAdapter::new(state.clone()));
+        let mut infos = HashMap::new();
+
+        for tx_bytes in txs {
+            deadline.check()?;
+            let tx_hash = keccak256(tx_bytes);
+
+            let tx_env = decode_tx_env(tx_bytes, self.sender_cache.as_ref())?;
+
+            // Pre-execution: classify the transaction if classifier is enabled
+            let classification = self.classifier.as_ref().map(|classifier| {
+                let to = match tx_env.kind {
+                    TxKind::Call(addr) => Some(addr),
+                    TxKind::Create => None,
+                };
+                let result = classifier.classify(to, &tx_env.data);
+                tracing::info!(
+                    tx_hash = %tx_hash,
+                    classification = %result.classification,
+                    confidence = result.confidence,
+                    reason = %result.reason,
+                    "pre-execution classification"
+                );
+                result.classification
+            });
+
+            let tip = tx_env.effective_gas_price(base_fee).saturating_sub(base_fee);
+            evm.precompiles.begin_transaction();
+            evm.inspector.0.begin_transaction();
+
+            // Always inspect: intents are collected at frame boundaries so that
+            // those parsed inside a reverted frame can be dropped.
+            let result_and_state = evm
+                .inspect_tx(tx_env)
+                .map_err(|e| ExecutionError::TxExecution(format!("{:?}", e)))?;
+            let intents = evm.inspector.1.take();
+            if evm.inspector.0.exceeded() {
+                tracing::debug!(
+                    tx_hash = %tx_hash,
+                    max_logs = self.config.max_logs_per_tx,
+                    "transaction reverted: log limit exceeded"
+                );
+            }
+
+            if let (Some(observer), Some(classification)) = (&self.routing, classification) {
+                let outcome = match &result_and_state.result {
+                    ExecutionResult::Success { .. } => RoutingOutcome::Success,
+                    ExecutionResult::Revert { .. } => RoutingOutcome::Reverted,
+                    ExecutionResult::Halt { .. } => RoutingOutcome::Halted,
+                };
+                observer.observe(&RoutingRecord {
+                    tx_hash,
+                    pipeline: ExecutionPipeline::for_classification(&classification),
+                    classification,
+                    outcome,
+                });
+            }
+
+            let gas_used = result_and_state.result.gas_used();
+            cumulative_gas = cumulative_gas.saturating_add(gas_used);
+            run.priority_fees =
+                run.priority_fees.saturating_add(U256::from(tip) * U256::from(gas_used));
+
+            let receipt =
+                build_receipt(&result_and_state.result, tx_hash, gas_used, cumulative_gas);
+
+            // Intents parsed inside a failed transaction were never acted on.
+            if receipt.success() {
+                run.outcome.intent_receipts.extend(intents.into_iter().map(|intent| {
+                    IntentReceipt { tx_hash, block_number: context.header.number, intent }
+                }));
+            }
+            run.outcome.receipts.push(receipt);
+
+            if let Some(db) = &tracker {
+                run.access.push(access_set(&result_and_state.state, db, &mut infos)?);
+            }
+            let changes = extract_changes(result_and_state.state);
+            run.outcome.changes.merge(changes);
+        }
+
+        run.outcome.gas_used = cumulative_gas;
+        Ok(run)
+    }
+
+    /// Re-execute `txs` with call tracing enabled.
+    ///
+    /// `state` must reflect the parent of the block described by `context`. Each
+    /// transaction is executed exactly as [`BlockExecutor::execute`] would, and its
+    /// call tree is returned in block order. No state changes are produced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a transaction cannot be decoded or executed, or if the
+    /// configured execution timeout elapses.
+    pub fn trace<S: StateDb>(
+        &self,
+        state: &S,
+        context: &BlockContext,
+        txs: &[Bytes],
+    ) -> Result<Vec<TransactionTrace>, ExecutionError> {
+        let mut evm = self
+            .evm_context(state, context)
+            .build_mainnet_with_inspector(CallTracer::default())
+            .with_precompiles(self.precompiles());
+        code_size::install(&mut evm.instruction);
+
+        let deadline = ExecutionDeadline::new(self.config.execution_timeout);
+        let mut traces = Vec::with_capacity(txs.len());
+
+        for tx_bytes in txs {
+            deadline.check()?;
+            let tx_hash = keccak256(tx_bytes);
+            let tx_env = decode_tx_env(tx_bytes, self.sender_cache.as_ref())?;
+
+            evm.precompiles.begin_transaction();
+            evm.inspect_tx(tx_env).map_err(|e| ExecutionError::TxExecution(format!("{:?}", e)))?;
+
+            let frame = evm.inspector.take_root().ok_or_else(|| {
+                ExecutionError::TxExecution(format!("no call frame traced for {tx_hash}"))
+            })?;
+            traces.push(TransactionTrace { tx_hash, frame });
+        }
+
+        Ok(traces)
+    }
+
+    /// Build the EVM context for executing a block on top of `state`.
+    fn evm_context<S: StateDb>(&self, state: &S, context: &BlockContext) -> EvmContext<S> {
+        let adapter = StateDbAdapter::new(state.clone());
+        let db = State::builder().with_database_ref(adapter).build();
+
+        let ctx: EvmContext<S> = Context::new(db, self.config.spec_id);
+        ctx.modify_cfg_chained(|cfg| {
+            cfg.chain_id = self.config.chain_id;
+        })
+        .modify_block_chained(|blk: &mut BlockEnv| {
+            blk.number = U256::from(context.header.number);
+            blk.timestamp = U256::from(context.header.timestamp);
+            blk.beneficiary = context.header.beneficiary;
+            blk.gas_limit = context.header.gas_limit;
+            blk.basefee = context.header.base_fee_per_gas.unwrap_or_default();
+            blk.prevrandao = Some(context.prevrandao);
+        })
+    }
+
     /// Validate a header against its parent.
     pub fn validate_header_against_parent(
         &self,
@@ -212,72 +465,18 @@ impl<S: StateDb> BlockExecutor<S> for RevmExecutor {
         context: &BlockContext,
         txs: &[Self::Tx],
     ) -> Result<ExecutionOutcome, ExecutionError> {
-        let adapter = StateDbAdapter::new(state.clone());
-
-        let db = State::builder().with_database_ref(adapter).build();
-
-        type Db<S> = State<revm::database::WrapDatabaseRef<StateDbAdapter<S>>>;
-        let ctx: Context<BlockEnv, _, _, Db<S>, Journal<Db<S>>, ()> =
-            Context::new(db, self.config.spec_id);
-        let ctx = ctx
-            .modify_cfg_chained(|cfg| {
-                cfg.chain_id = self.config.chain_id;
-            })
-            .modify_block_chained(|blk: &mut BlockEnv| {
-                blk.number = U256::from(context.header.number);
-                blk.timestamp = U256::from(context.header.timestamp);
-                blk.beneficiary = context.header.beneficiary;
-                blk.gas_limit = context.header.gas_limit;
-                blk.basefee = context.header.base_fee_per_gas.unwrap_or_default();
-                blk.prevrandao = Some(context.prevrandao);
-            });
-
-        let mut evm = ctx
-            .build_mainnet()
-            .with_precompiles(crate::MonmouthPrecompiles::new(self.config.spec_id));
-
-        let mut outcome = ExecutionOutcome::new();
-        let mut cumulative_gas = 0u64;
-
-        for tx_bytes in txs {
-            let tx_hash = keccak256(tx_bytes);
-
-            let tx_env = decode_tx_env(tx_bytes, self.config.chain_id)?;
-
-            // Pre-execution: classify the transaction if classifier is enabled
-            if let Some(ref classifier) = self.classifier {
-                let to = match tx_env.kind {
-                    TxKind::Call(addr) => Some(addr),
-                    TxKind::Create => None,
-                };
-                let result = classifier.classify(to, &tx_env.data);
-                tracing::info!(
-                    tx_hash = %tx_hash,
-                    classification = %result.classification,
-                    confidence = result.confidence,
-                    reason = %result.reason,
-                    "pre-execution classification"
-                );
-            }
-
-            evm.set_tx(tx_env);
-
-            let result_and_state =
-                evm.replay().map_err(|e| ExecutionError::TxExecution(format!("{:?}", e)))?;
-
-            let gas_used = result_and_state.result.gas_used();
-            cumulative_gas = cumulative_gas.saturating_add(gas_used);
-
-            let receipt =
-                build_receipt(&result_and_state.result, tx_hash, gas_used, cumulative_gas);
-            outcome.receipts.push(receipt);
-
-            let changes = extract_changes(result_and_state.state);
-            outcome.changes.merge(changes);
-        }
-
-        outcome.gas_used = cumulative_gas;
-        Ok(outcome)
+        let deadline = ExecutionDeadline::new(self.config.execution_timeout);
+        let mut run = self.run(state, context, txs, deadline, false)?;
+
+        deadline.check()?;
+        settle_priority_fees(
+            &StateDbAdapter::new(state.clone()),
+            &mut run.outcome.changes,
+            context.header.beneficiary,
+            run.priority_fees,
+            self.config.priority_fee_split(),
+        )?;
+        Ok(run.outcome)
     }
 
     fn validate_header(&self, header: &Header) -> Result<(), ExecutionError> {
@@ -299,16 +498,90 @@ impl<S: StateDb> BlockExecutor<S> for RevmExecutor {
     }
 }
 
+/// Recover a transaction's caller, consulting `sender_cache` first when present.
+fn recover_caller<E: std::fmt::Display>(
+    tx_hash: B256,
+    sender_cache: Option<&SenderCache>,
+    recover: impl FnOnce() -> Result<Address, E>,
+) -> Result<Address, ExecutionError> {
+    let caller = match sender_cache {
+        Some(cache) => cache.get_or_recover(tx_hash, recover),
+        None => recover(),
+    };
+    caller.map_err(|e| ExecutionError::TxDecode(format!("failed to recover signer: {}", e)))
+}
+
+/// Wall-clock budget for a single block execution.
+///
+/// Checked between transactions: a single transaction is not interrupted, but the
+/// block is rejected as soon as the budget is spent.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ExecutionDeadline {
+    start: Instant,
+    limit: Option<Duration>,
+}
+
+impl ExecutionDeadline {
+    pub(crate) fn new(limit: Option<Duration>) -> Self {
+        Self { start: Instant::now(), limit }
+    }
+
+    pub(crate) fn check(&self) -> Result<(), ExecutionError> {
+        match self.limit {
+            Some(limit) if self.start.elapsed() >= limit => {
+                Err(ExecutionError::ExecutionTimeout(limit))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Record the state a transaction accessed.
+///
+/// An account is written when its nonce, balance or code hash changed, or when it
+/// was created or self-destructed; `infos` carries each account's info as left by
+/// earlier transactions of the run. A slot is written when its value changed.
+fn access_set<S: StateDb>(
+    state: &EvmState,
+    db: &StateDbAdapter<S>,
+    infos: &mut HashMap<Address, (u64, U256, B256)>,
+) -> Result<AccessSet, ExecutionError> {
+    let mut access = AccessSet::default();
+    for (address, account) in state {
+        let info = (account.info.nonce, account.info.balance, account.info.code_hash);
+        let prior = match infos.get(address) {
+            Some(prior) => *prior,
+            None => db.basic_ref(*address)?.map_or((0, U256::ZERO, KECCAK256_EMPTY), |prior| {
+                (prior.nonce, prior.balance, prior.code_hash)
+            }),
+        };
+        let written = account.is_touched()
+            && (info != prior || account.is_created() || account.is_selfdestructed());
+        access.record(StateKey::Account(*address), written);
+        for (slot, value) in &account.storage {
+            access.record(StateKey::Slot(*address, *slot), value.is_changed());
+        }
+        infos.insert(*address, info);
+    }
+    Ok(access)
+}
+
 /// Decode transaction bytes into a REVM TxEnv.
 ///
 /// Currently supports basic transaction decoding for all Ethereum transaction types.
-fn decode_tx_env(tx_bytes: &Bytes, _chain_id: u64) -> Result<revm::context::TxEnv, ExecutionError> {
+/// The caller is taken from `sender_cache` when the envelope's canonical hash is
+/// present there.
+fn decode_tx_env(
+    tx_bytes: &Bytes,
+    sender_cache: Option<&SenderCache>,
+) -> Result<revm::context::TxEnv, ExecutionError> {
     use alloy_consensus::TxEnvelope;
     use alloy_rlp::Decodable;
 
     // Decode the transaction envelope
     let envelope = TxEnvelope::decode(&mut tx_bytes.as_ref())
         .map_err(|e| ExecutionError::TxDecode(format!("{}", e)))?;
+    let tx_hash = *envelope.tx_hash();
 
     // Build TxEnv using the builder pattern
     let mut builder = revm::context::TxEnv::builder();
@@ -316,9 +589,7 @@ fn decode_tx_env(tx_bytes: &Bytes, _chain_id: u64) -> Result<revm::context::TxEn
     match &envelope {
         TxEnvelope::Legacy(signed) => {
             let tx = signed.tx();
-            let caller = signed.recover_signer().map_err(|e| {
-                ExecutionError::TxDecode(format!("failed to recover signer: {}", e))
-            })?;
+            let caller = recover_caller(tx_hash, sender_cache, || signed.recover_signer())?;
 
             builder = builder
                 .caller(caller)
@@ -332,9 +603,7 @@ fn decode_tx_env(tx_bytes: &Bytes, _chain_id: u64) -> Result<revm::context::TxEn
         }
         TxEnvelope::Eip2930(signed) => {
             let tx = signed.tx();
-            let caller = signed.recover_signer().map_err(|e| {
-                ExecutionError::TxDecode(format!("failed to recover signer: {}", e))
-            })?;
+            let caller = recover_caller(tx_hash, sender_cache, || signed.recover_signer())?;
 
             builder = builder
                 .caller(caller)
@@ -349,9 +618,7 @@ fn decode_tx_env(tx_bytes: &Bytes, _chain_id: u64) -> Result<revm::context::TxEn
         }
         TxEnvelope::Eip1559(signed) => {
             let tx = signed.tx();
-            let caller = signed.recover_signer().map_err(|e| {
-                ExecutionError::TxDecode(format!("failed to recover signer: {}", e))
-            })?;
+            let caller = recover_caller(tx_hash, sender_cache, || signed.recover_signer())?;
 
             builder = builder
                 .caller(caller)
@@ -367,9 +634,7 @@ fn decode_tx_env(tx_bytes: &Bytes, _chain_id: u64) -> Result<revm::context::TxEn
         }
         TxEnvelope::Eip4844(signed) => {
             let tx = signed.tx().tx();
-            let caller = signed.recover_signer().map_err(|e| {
-                ExecutionError::TxDecode(format!("failed to recover signer: {}", e))
-            })?;
+            let caller = recover_caller(tx_hash, sender_cache, || signed.recover_signer())?;
 
             builder = builder
                 .caller(caller)
@@ -387,9 +652,7 @@ fn decode_tx_env(tx_bytes: &Bytes, _chain_id: u64) -> Result<revm::context::TxEn
         }
         TxEnvelope::Eip7702(signed) => {
             let tx = signed.tx();
-            let caller = signed.recover_signer().map_err(|e| {
-                ExecutionError::TxDecode(format!("failed to recover signer: {}", e))
-            })?;
+            let caller = recover_caller(tx_hash, sender_cache, || signed.recover_signer())?;
 
             builder = builder
                 .caller(caller)
@@ -511,6 +774,11 @@ fn extract_changes(state: EvmState) -> ChangeSet {
         // Extract code if present
         let code = account.info.code.as_ref().map(|c: &Bytecode| c.bytes().to_vec());
 
+        debug_assert_ne!(
+            account.info.code_hash,
+            B256::ZERO,
+            "account {address} would be written with a zero code hash"
+        );
         let update = AccountUpdate {
             created: account.is_created(),
             selfdestructed: account.is_selfdestructed(),
@@ -596,6 +864,40 @@ mod tests {
         assert_eq!(executor.spec_id(), SpecId::PRAGUE);
     }
 
+    #[test]
+    fn execution_deadline_expires_after_slow_work() {
+        let deadline = ExecutionDeadline::new(Some(Duration::from_millis(10)));
+        assert!(deadline.check().is_ok());
+
+        // Stand-in for a pathological transaction that burns wall-clock time.
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert!(matches!(deadline.check(), Err(ExecutionError::ExecutionTimeout(_))));
+    }
+
+    #[test]
+    fn execution_deadline_disabled_never_expires() {
+        let deadline = ExecutionDeadline::new(None);
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(deadline.check().is_ok());
+    }
+
+    #[test]
+    fn execute_rejects_block_over_timeout() {
+        let executor = RevmExecutor::with_config(
+            ExecutionConfig::new(1).with_execution_timeout(Duration::ZERO),
+        );
+        let context = BlockContext::new(Header::default(), B256::ZERO, B256::ZERO);
+        let txs = vec![Bytes::from_static(&[0xde, 0xad])];
+
+        let result = executor.execute(&MockStateDb, &context, &txs);
+        assert!(matches!(result, Err(ExecutionError::ExecutionTimeout(limit)) if limit.is_zero()));
+
+        let untimed = RevmExecutor::new(1);
+        let result = untimed.execute(&MockStateDb, &context, &txs);
+        assert!(matches!(result, Err(ExecutionError::TxDecode(_))));
+    }
+
     #[test]
     fn validate_header_gas_limit_bounds() {
         let executor = RevmExecutor::with_config(ExecutionConfig::new(1).with_gas_limit_bounds(
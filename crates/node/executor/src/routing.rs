@@ -0,0 +1,274 @@
+//! Routing decision records for classified transactions.
+//!
+//! When a [`TransactionClassifier`](crate::TransactionClassifier) is attached, the
+//! executor reports which pipeline each transaction was routed to and how its
+//! execution ended, so classification can be audited against actual execution.
+
+use std::{
+    fmt,
+    sync::{
+        Mutex, PoisonError,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use alloy_primitives::B256;
+
+use crate::TransactionClassification;
+
+/// Default number of routing records logged per [`DEFAULT_ROUTING_LOG_WINDOW`].
+pub const DEFAULT_ROUTING_LOG_LIMIT: u32 = 100;
+
+/// Default window over which [`DEFAULT_ROUTING_LOG_LIMIT`] applies.
+pub const DEFAULT_ROUTING_LOG_WINDOW: Duration = Duration::from_secs(1);
+
+/// Execution pipeline a transaction is routed to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExecutionPipeline {
+    /// Plain EVM execution.
+    Evm,
+    /// Solana VM routing.
+    Svm,
+    /// Cross-chain coordination.
+    CrossChain,
+    /// RAG context injection.
+    Rag,
+    /// Agent-to-agent commerce.
+    Agent,
+}
+
+impl ExecutionPipeline {
+    /// All pipelines, in label order used by [`RoutingMetrics`].
+    pub const ALL: [Self; 5] = [Self::Evm, Self::Svm, Self::CrossChain, Self::Rag, Self::Agent];
+
+    /// Pipeline chosen for `classification`.
+    pub const fn for_classification(classification: &TransactionClassification) -> Self {
+        match classification {
+            TransactionClassification::PureEvm => Self::Evm,
+            TransactionClassification::SvmRouted => Self::Svm,
+            TransactionClassification::HybridCrossChain => Self::CrossChain,
+            TransactionClassification::RagEnhanced => Self::Rag,
+            TransactionClassification::AgentToAgent => Self::Agent,
+        }
+    }
+
+    /// Stable label used in trace events and metrics.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Evm => "evm",
+            Self::Svm => "svm",
+            Self::CrossChain => "cross_chain",
+            Self::Rag => "rag",
+            Self::Agent => "agent",
+        }
+    }
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for ExecutionPipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// How a routed transaction's execution ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoutingOutcome {
+    /// Execution succeeded.
+    Success,
+    /// Execution reverted.
+    Reverted,
+    /// Execution halted, e.g. out of gas.
+    Halted,
+}
+
+impl RoutingOutcome {
+    /// All outcomes, in label order used by [`RoutingMetrics`].
+    pub const ALL: [Self; 3] = [Self::Success, Self::Reverted, Self::Halted];
+
+    /// Stable label used in trace events and metrics.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Reverted => "reverted",
+            Self::Halted => "halted",
+        }
+    }
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for RoutingOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// The routing decision for one executed transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoutingRecord {
+    /// Transaction hash.
+    pub tx_hash: B256,
+    /// Classification assigned before execution.
+    pub classification: TransactionClassification,
+    /// Pipeline the transaction was routed to.
+    pub pipeline: ExecutionPipeline,
+    /// How execution ended.
+    pub outcome: RoutingOutcome,
+}
+
+/// Receives a [`RoutingRecord`] for every classified transaction the executor runs.
+///
+/// `observe` runs synchronously inside block execution and must return promptly.
+/// Parallel execution may run a transaction more than once, so a transaction can
+/// be observed more than once per block.
+pub trait RoutingObserver: fmt::Debug + Send + Sync {
+    /// Record one routing decision.
+    fn observe(&self, record: &RoutingRecord);
+}
+
+/// Counts routing decisions per pipeline and outcome and logs them as trace events.
+///
+/// At most `log_limit` records are logged per `log_window`; the rest are only
+/// counted, and the number skipped is logged when the next window opens.
+#[derive(Debug)]
+pub struct RoutingMetrics {
+    counts: [[AtomicU64; RoutingOutcome::ALL.len()]; ExecutionPipeline::ALL.len()],
+    log_limit: u32,
+    log_window: Duration,
+    log_state: Mutex<LogWindow>,
+}
+
+/// Log budget for the current window.
+#[derive(Debug)]
+struct LogWindow {
+    started: Instant,
+    logged: u32,
+    suppressed: u64,
+}
+
+impl Default for RoutingMetrics {
+    fn default() -> Self {
+        Self::new(DEFAULT_ROUTING_LOG_LIMIT, DEFAULT_ROUTING_LOG_WINDOW)
+    }
+}
+
+impl RoutingMetrics {
+    /// Create metrics that log at most `log_limit` records per `log_window`.
+    pub fn new(log_limit: u32, log_window: Duration) -> Self {
+        Self {
+            counts: Default::default(),
+            log_limit,
+            log_window,
+            log_state: Mutex::new(LogWindow { started: Instant::now(), logged: 0, suppressed: 0 }),
+        }
+    }
+
+    /// Number of transactions routed to `pipeline` that ended with `outcome`.
+    pub fn count(&self, pipeline: ExecutionPipeline, outcome: RoutingOutcome) -> u64 {
+        self.counts[pipeline.index()][outcome.index()].load(Ordering::Relaxed)
+    }
+
+    /// Number of transactions routed to `pipeline`, across all outcomes.
+    pub fn pipeline_total(&self, pipeline: ExecutionPipeline) -> u64 {
+        RoutingOutcome::ALL.iter().map(|&outcome| self.count(pipeline, outcome)).sum()
+    }
+
+    /// Whether a record may be logged now, taking one unit of the window's budget.
+    fn take_log_slot(&self) -> bool {
+        let mut window = self.log_state.lock().unwrap_or_else(PoisonError::into_inner);
+        if window.started.elapsed() >= self.log_window {
+            if window.suppressed > 0 {
+                tracing::info!(
+                    target: "monmouth::routing",
+                    suppressed = window.suppressed,
+                    "routing decisions not logged in the last window"
+                );
+            }
+            *window = LogWindow { started: Instant::now(), logged: 0, suppressed: 0 };
+        }
+        if window.logged < self.log_limit {
+            window.logged += 1;
+            true
+        } else {
+            window.suppressed += 1;
+            false
+        }
+    }
+}
+
+impl RoutingObserver for RoutingMetrics {
+    fn observe(&self, record: &RoutingRecord) {
+        self.counts[record.pipeline.index()][record.outcome.index()]
+            .fetch_add(1, Ordering::Relaxed);
+        if self.take_log_slot() {
+            tracing::info!(
+                target: "monmouth::routing",
+                tx_hash = %record.tx_hash,
+                classification = %record.classification,
+                pipeline = record.pipeline.label(),
+                outcome = record.outcome.label(),
+                "routing decision"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(pipeline: ExecutionPipeline, outcome: RoutingOutcome) -> RoutingRecord {
+        RoutingRecord {
+            tx_hash: B256::repeat_byte(0x01),
+            classification: TransactionClassification::PureEvm,
+            pipeline,
+            outcome,
+        }
+    }
+
+    #[test]
+    fn every_classification_has_a_pipeline() {
+        let cases = [
+            (TransactionClassification::PureEvm, "evm"),
+            (TransactionClassification::SvmRouted, "svm"),
+            (TransactionClassification::HybridCrossChain, "cross_chain"),
+            (TransactionClassification::RagEnhanced, "rag"),
+            (TransactionClassification::AgentToAgent, "agent"),
+        ];
+        for (classification, label) in cases {
+            assert_eq!(ExecutionPipeline::for_classification(&classification).label(), label);
+        }
+    }
+
+    #[test]
+    fn metrics_count_per_pipeline_and_outcome() {
+        let metrics = RoutingMetrics::default();
+        metrics.observe(&record(ExecutionPipeline::Svm, RoutingOutcome::Success));
+        metrics.observe(&record(ExecutionPipeline::Svm, RoutingOutcome::Reverted));
+        metrics.observe(&record(ExecutionPipeline::Rag, RoutingOutcome::Success));
+
+        assert_eq!(metrics.count(ExecutionPipeline::Svm, RoutingOutcome::Success), 1);
+        assert_eq!(metrics.pipeline_total(ExecutionPipeline::Svm), 2);
+        assert_eq!(metrics.pipeline_total(ExecutionPipeline::Rag), 1);
+        assert_eq!(metrics.pipeline_total(ExecutionPipeline::Evm), 0);
+    }
+
+    #[test]
+    fn logging_is_rate_limited_but_counting_is_not() {
+        let metrics = RoutingMetrics::new(2, Duration::from_secs(3600));
+        let logged: Vec<bool> = (0..5).map(|_| metrics.take_log_slot()).collect();
+        assert_eq!(logged, vec![true, true, false, false, false]);
+
+        for _ in 0..5 {
+            metrics.observe(&record(ExecutionPipeline::Evm, RoutingOutcome::Halted));
+        }
+        assert_eq!(metrics.count(ExecutionPipeline::Evm, RoutingOutcome::Halted), 5);
+    }
+}
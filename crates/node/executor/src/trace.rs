@@ -0,0 +1,244 @@
+//! Call tracing for re-executed transactions.
+
+use alloy_primitives::{Address, B256, Bytes, U256};
+use revm::{
+    Inspector,
+    context_interface::ContextTr,
+    interpreter::{
+        CallInputs, CallOutcome, CallScheme, CreateInputs, CreateOutcome, CreateScheme,
+        InstructionResult, InterpreterResult,
+    },
+};
+
+/// Kind of call frame recorded by the tracer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallKind {
+    /// `CALL`, or a transaction to an existing account.
+    Call,
+    /// `STATICCALL`.
+    StaticCall,
+    /// `DELEGATECALL`.
+    DelegateCall,
+    /// `CALLCODE`.
+    CallCode,
+    /// `CREATE`, or a contract-creation transaction.
+    Create,
+    /// `CREATE2`.
+    Create2,
+}
+
+impl CallKind {
+    /// Returns the opcode name used by Geth-style call tracers.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Call => "CALL",
+            Self::StaticCall => "STATICCALL",
+            Self::DelegateCall => "DELEGATECALL",
+            Self::CallCode => "CALLCODE",
+            Self::Create => "CREATE",
+            Self::Create2 => "CREATE2",
+        }
+    }
+}
+
+/// A single call frame and the frames it spawned.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallFrame {
+    /// Kind of call.
+    pub kind: CallKind,
+    /// Caller of the frame.
+    pub from: Address,
+    /// Callee, or the created contract. `None` if a creation failed.
+    pub to: Option<Address>,
+    /// Value transferred into the frame.
+    pub value: U256,
+    /// Gas made available to the frame.
+    pub gas: u64,
+    /// Gas spent by the frame, including its subcalls.
+    pub gas_used: u64,
+    /// Call data, or init code for creations.
+    pub input: Bytes,
+    /// Return data, or deployed code for creations.
+    pub output: Bytes,
+    /// Error message if the frame reverted or halted.
+    pub error: Option<String>,
+    /// Frames spawned by this frame, in execution order.
+    pub calls: Vec<Self>,
+}
+
+/// Call trace of one transaction within a block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionTrace {
+    /// Transaction hash.
+    pub tx_hash: B256,
+    /// Top-level call frame.
+    pub frame: CallFrame,
+}
+
+/// [`Inspector`] that records the call tree of a transaction.
+///
+/// Frames are pushed on entry and attached to their parent on exit; the completed
+/// top-level frame is taken with [`CallTracer::take_root`] after each transaction.
+#[derive(Clone, Debug, Default)]
+pub struct CallTracer {
+    stack: Vec<CallFrame>,
+    root: Option<CallFrame>,
+}
+
+impl CallTracer {
+    /// Take the top-level frame of the last traced transaction.
+    pub fn take_root(&mut self) -> Option<CallFrame> {
+        self.stack.clear();
+        self.root.take()
+    }
+
+    fn enter(&mut self, frame: CallFrame) {
+        self.stack.push(frame);
+    }
+
+    fn exit(&mut self, result: &InterpreterResult, created: Option<Address>) {
+        let Some(mut frame) = self.stack.pop() else {
+            return;
+        };
+        frame.gas_used = result.gas.spent();
+        frame.output = result.output.clone();
+        frame.error = frame_error(result.result);
+        if matches!(frame.kind, CallKind::Create | CallKind::Create2) {
+            frame.to = created;
+        }
+        match self.stack.last_mut() {
+            Some(parent) => parent.calls.push(frame),
+            None => self.root = Some(frame),
+        }
+    }
+}
+
+impl<CTX: ContextTr> Inspector<CTX> for CallTracer {
+    fn call(&mut self, context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+        let kind = match inputs.scheme {
+            CallScheme::Call => CallKind::Call,
+            CallScheme::StaticCall => CallKind::StaticCall,
+            CallScheme::DelegateCall => CallKind::DelegateCall,
+            CallScheme::CallCode => CallKind::CallCode,
+        };
+        self.enter(CallFrame {
+            kind,
+            from: inputs.caller,
+            to: Some(inputs.target_address),
+            value: inputs.call_value(),
+            gas: inputs.gas_limit,
+            gas_used: 0,
+            input: inputs.input.bytes(context),
+            output: Bytes::new(),
+            error: None,
+            calls: Vec::new(),
+        });
+        None
+    }
+
+    fn call_end(&mut self, _context: &mut CTX, _inputs: &CallInputs, outcome: &mut CallOutcome) {
+        self.exit(&outcome.result, None);
+    }
+
+    fn create(&mut self, _context: &mut CTX, inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        let kind = match inputs.scheme() {
+            CreateScheme::Create2 { .. } => CallKind::Create2,
+            _ => CallKind::Create,
+        };
+        self.enter(CallFrame {
+            kind,
+            from: inputs.caller(),
+            to: None,
+            value: inputs.value(),
+            gas: inputs.gas_limit(),
+            gas_used: 0,
+            input: inputs.init_code().clone(),
+            output: Bytes::new(),
+            error: None,
+            calls: Vec::new(),
+        });
+        None
+    }
+
+    fn create_end(
+        &mut self,
+        _context: &mut CTX,
+        _inputs: &CreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        self.exit(&outcome.result, outcome.address);
+    }
+}
+
+/// Geth-compatible error message for a non-successful frame.
+fn frame_error(result: InstructionResult) -> Option<String> {
+    if result.is_ok() {
+        None
+    } else if result.is_revert() {
+        Some("execution reverted".to_string())
+    } else {
+        Some(format!("{result:?}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use revm::interpreter::Gas;
+
+    use super::*;
+
+    fn frame(kind: CallKind, to: Address) -> CallFrame {
+        CallFrame {
+            kind,
+            from: Address::repeat_byte(0x01),
+            to: Some(to),
+            value: U256::ZERO,
+            gas: 100_000,
+            gas_used: 0,
+            input: Bytes::new(),
+            output: Bytes::new(),
+            error: None,
+            calls: Vec::new(),
+        }
+    }
+
+    fn result(result: InstructionResult, spent: u64) -> InterpreterResult {
+        let mut gas = Gas::new(100_000);
+        assert!(gas.record_cost(spent));
+        InterpreterResult { result, output: Bytes::new(), gas }
+    }
+
+    #[test]
+    fn nested_frames_attach_to_parent() {
+        let mut tracer = CallTracer::default();
+        tracer.enter(frame(CallKind::Call, Address::repeat_byte(0xaa)));
+        tracer.enter(frame(CallKind::StaticCall, Address::repeat_byte(0xbb)));
+        tracer.exit(&result(InstructionResult::Stop, 700), None);
+        tracer.exit(&result(InstructionResult::Stop, 21_700), None);
+
+        let root = tracer.take_root().expect("root frame");
+        assert_eq!(root.to, Some(Address::repeat_byte(0xaa)));
+        assert_eq!(root.gas_used, 21_700);
+        assert_eq!(root.calls.len(), 1);
+        assert_eq!(root.calls[0].kind, CallKind::StaticCall);
+        assert_eq!(root.calls[0].to, Some(Address::repeat_byte(0xbb)));
+        assert!(tracer.take_root().is_none());
+    }
+
+    #[test]
+    fn reverted_frame_reports_error() {
+        let mut tracer = CallTracer::default();
+        tracer.enter(frame(CallKind::Call, Address::repeat_byte(0xaa)));
+        tracer.exit(&result(InstructionResult::Revert, 100), None);
+
+        let root = tracer.take_root().expect("root frame");
+        assert_eq!(root.error.as_deref(), Some("execution reverted"));
+    }
+
+    #[test]
+    fn call_kind_names() {
+        assert_eq!(CallKind::Call.as_str(), "CALL");
+        assert_eq!(CallKind::DelegateCall.as_str(), "DELEGATECALL");
+        assert_eq!(CallKind::Create2.as_str(), "CREATE2");
+    }
+}
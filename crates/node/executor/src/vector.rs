@@ -0,0 +1,178 @@
+//! Calldata decoding and scoring for the vector-similarity precompile.
+
+use alloy_primitives::{Bytes, U256, U512};
+
+/// Fixed-point scale of returned similarity scores (1.0 = `1e18`).
+const SCORE_SCALE: u64 = 1_000_000_000_000_000_000;
+
+/// Query and corpus decoded from `(uint256[] query, uint256[][] corpus)` calldata.
+///
+/// Vector components must fit in 64 bits so similarity can be computed exactly
+/// in 512-bit integer arithmetic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct SimilarityQuery {
+    query: Vec<u64>,
+    corpus: Vec<Vec<u64>>,
+}
+
+impl SimilarityQuery {
+    /// Decode ABI-encoded calldata, returning `None` if it is malformed.
+    pub(crate) fn decode(input: &[u8]) -> Option<Self> {
+        let query = read_vector(input, read_usize(input, 0)?)?;
+        let corpus_offset = read_usize(input, 32)?;
+        let rows = read_len(input, corpus_offset)?;
+        let heads = corpus_offset.checked_add(32)?;
+        let corpus = (0..rows)
+            .map(|row| {
+                let offset = read_usize(input, heads.checked_add(row * 32)?)?;
+                read_vector(input, heads.checked_add(offset)?)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self { query, corpus })
+    }
+
+    /// Number of corpus rows scored.
+    pub(crate) const fn rows(&self) -> usize {
+        self.corpus.len()
+    }
+
+    /// Index and `1e18`-scaled cosine similarity of the best-matching corpus row.
+    ///
+    /// Ties go to the lowest index. Returns `None` for an empty corpus or if a
+    /// row's dimension differs from the query's.
+    pub(crate) fn best_match(&self) -> Option<(usize, U256)> {
+        let mut best: Option<(usize, U256)> = None;
+        for (index, row) in self.corpus.iter().enumerate() {
+            let score = cosine_similarity(&self.query, row)?;
+            if best.is_none_or(|(_, top)| score > top) {
+                best = Some((index, score));
+            }
+        }
+        best
+    }
+}
+
+/// ABI-encode a match as `(uint256 index, uint256 score)`.
+pub(crate) fn encode_match(index: usize, score: U256) -> Bytes {
+    let mut output = Vec::with_capacity(64);
+    output.extend_from_slice(&U256::from(index).to_be_bytes::<32>());
+    output.extend_from_slice(&score.to_be_bytes::<32>());
+    Bytes::from(output)
+}
+
+/// `1e18`-scaled cosine similarity of two equal-length vectors; zero vectors score 0.
+fn cosine_similarity(a: &[u64], b: &[u64]) -> Option<U256> {
+    if a.len() != b.len() {
+        return None;
+    }
+    let (mut dot, mut aa, mut bb) = (U512::ZERO, U512::ZERO, U512::ZERO);
+    for (x, y) in a.iter().zip(b) {
+        let (x, y) = (U512::from(*x), U512::from(*y));
+        dot += x * y;
+        aa += x * x;
+        bb += y * y;
+    }
+    let norms = aa * bb;
+    if norms.is_zero() {
+        return Some(U256::ZERO);
+    }
+    // score = sqrt(dot^2 * scale^2 / (|a|^2 * |b|^2)); dot^2 <= |a|^2 * |b|^2 keeps it
+    // within SCORE_SCALE.
+    let scale = U512::from(SCORE_SCALE);
+    Some(U256::from(isqrt(dot * dot * scale * scale / norms)))
+}
+
+fn isqrt(n: U512) -> U512 {
+    if n.is_zero() {
+        return n;
+    }
+    let mut x = n;
+    let mut y = (x + U512::from(1)) >> 1;
+    while y < x {
+        x = y;
+        y = (x + n / x) >> 1;
+    }
+    x
+}
+
+fn read_word(input: &[u8], offset: usize) -> Option<U256> {
+    input.get(offset..offset.checked_add(32)?).map(U256::from_be_slice)
+}
+
+fn read_usize(input: &[u8], offset: usize) -> Option<usize> {
+    read_word(input, offset)?.try_into().ok()
+}
+
+/// Read an array length at `offset`, rejecting lengths the input cannot hold.
+fn read_len(input: &[u8], offset: usize) -> Option<usize> {
+    let len = read_usize(input, offset)?;
+    let available = input.len().checked_sub(offset.checked_add(32)?)? / 32;
+    (len <= available).then_some(len)
+}
+
+fn read_vector(input: &[u8], offset: usize) -> Option<Vec<u64>> {
+    let len = read_len(input, offset)?;
+    (0..len).map(|i| read_word(input, offset + 32 + i * 32)?.try_into().ok()).collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// ABI-encode `(uint256[] query, uint256[][] corpus)`.
+    pub(crate) fn encode_query(query: &[u64], corpus: &[&[u64]]) -> Vec<u8> {
+        let word = |value: usize| U256::from(value).to_be_bytes::<32>();
+        let vector = |values: &[u64]| {
+            let mut out = word(values.len()).to_vec();
+            values.iter().for_each(|v| out.extend_from_slice(&U256::from(*v).to_be_bytes::<32>()));
+            out
+        };
+        let query = vector(query);
+        let mut rows = Vec::new();
+        let mut heads = word(corpus.len()).to_vec();
+        for row in corpus {
+            heads.extend_from_slice(&word(corpus.len() * 32 + rows.len()));
+            rows.extend(vector(row));
+        }
+        let mut out = word(64).to_vec();
+        out.extend_from_slice(&word(64 + query.len()));
+        out.extend(query);
+        out.extend(heads);
+        out.extend(rows);
+        out
+    }
+
+    #[test]
+    fn best_match_is_most_similar_row() {
+        let input = encode_query(&[1, 0, 1], &[&[0, 1, 0], &[2, 0, 2], &[1, 1, 1]]);
+        let query = SimilarityQuery::decode(&input).unwrap();
+        assert_eq!(query.rows(), 3);
+        assert_eq!(query.best_match(), Some((1, U256::from(SCORE_SCALE))));
+
+        // cos([1, 0, 1], [1, 1, 1]) = sqrt(2/3)
+        assert_eq!(
+            cosine_similarity(&[1, 0, 1], &[1, 1, 1]),
+            Some(U256::from(816_496_580_927_726_032u64))
+        );
+        assert_eq!(cosine_similarity(&[0, 0], &[1, 1]), Some(U256::ZERO));
+    }
+
+    #[test]
+    fn empty_corpus_has_no_match() {
+        let query = SimilarityQuery::decode(&encode_query(&[1, 2], &[])).unwrap();
+        assert_eq!(query.rows(), 0);
+        assert_eq!(query.best_match(), None);
+    }
+
+    #[test]
+    fn malformed_calldata_is_rejected() {
+        let input = encode_query(&[1, 2], &[&[3, 4]]);
+        assert!(SimilarityQuery::decode(&input[..input.len() - 1]).is_none());
+        assert!(SimilarityQuery::decode(&[0xff; 64]).is_none());
+        assert!(SimilarityQuery::decode(&[]).is_none());
+
+        // Dimension mismatch decodes but cannot be scored.
+        let query = SimilarityQuery::decode(&encode_query(&[1, 2], &[&[3]])).unwrap();
+        assert_eq!(query.best_match(), None);
+    }
+}
@@ -1,14 +1,19 @@
 //! Integration tests for monmouth-executor.
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     sync::{Arc, RwLock},
 };
 
 use alloy_consensus::Header;
 use alloy_primitives::{Address, B256, Bytes, U256};
 use monmouth_executor::{
-    BlockContext, BlockExecutor, ClassifierConfig, RevmExecutor, TransactionClassifier,
+    AssembledBlock, BlockContext, BlockExecutor, CROSS_CHAIN_NONCE_SLOT, CallKind,
+    ClassifierConfig, ExecutionConfig, ExecutionOutcome, ExecutionPipeline, IntentReceipt,
+    ParallelExecutor, ParsedIntent, PrecompileConfig, RevmExecutor, RoutingMetrics,
+    RoutingObserver, RoutingOutcome, RoutingRecord, TransactionClassification,
+    TransactionClassifier, classifier, compare_executors, cross_chain_nonce_issued,
+    highest_cross_chain_nonce,
 };
 use monmouth_qmdb::{AccountUpdate, ChangeSet};
 use monmouth_traits::{StateDb, StateDbError, StateDbRead, StateDbWrite};
@@ -32,6 +37,8 @@ struct MockStateDb {
     accounts: Arc<RwLock<HashMap<Address, MockAccount>>>,
     /// Contract code indexed by code hash.
     code: Arc<RwLock<HashMap<B256, Bytes>>>,
+    /// Code hashes whose bytes are withheld from `code`; only `code_size` sees them.
+    sized_only: Arc<RwLock<HashSet<B256>>>,
     /// Current state root.
     state_root: Arc<RwLock<B256>>,
 }
@@ -82,6 +89,9 @@ impl StateDbRead for MockStateDb {
     }
 
     async fn code(&self, code_hash: &B256) -> Result<Bytes, StateDbError> {
+        if self.sized_only.read().unwrap().contains(code_hash) {
+            return Err(StateDbError::Storage("code withheld".to_string()));
+        }
         self.code
             .read()
             .unwrap()
@@ -90,6 +100,15 @@ impl StateDbRead for MockStateDb {
             .ok_or(StateDbError::CodeNotFound(*code_hash))
     }
 
+    async fn code_size(&self, code_hash: &B256) -> Result<usize, StateDbError> {
+        self.code
+            .read()
+            .unwrap()
+            .get(code_hash)
+            .map(Bytes::len)
+            .ok_or(StateDbError::CodeNotFound(*code_hash))
+    }
+
     async fn storage(&self, address: &Address, slot: &U256) -> Result<U256, StateDbError> {
         let accounts = self.accounts.read().unwrap();
         Ok(accounts
@@ -609,7 +628,7 @@ fn test_executor_with_classifier_default_config() {
 
 #[test]
 fn test_executor_with_classifier_custom_threshold() {
-    let config = ClassifierConfig { confidence_threshold: 0.9, enabled: true };
+    let config = ClassifierConfig { confidence_threshold: 0.9, ..ClassifierConfig::default() };
     let classifier = TransactionClassifier::new(config);
     let executor = RevmExecutor::new(7750).with_classifier(classifier);
 
@@ -635,3 +654,892 @@ fn test_executor_without_classifier_works() {
     assert!(outcome.changes.is_empty());
     assert_eq!(outcome.gas_used, 0);
 }
+
+// ----------------------------------------------------------------------------
+// Tests for cross-chain message nonce tracking
+// ----------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_cross_chain_nonce_issued_tracks_committed_counter() {
+    let state = MockStateDb::new();
+    let passer = classifier::precompiles::CROSS_CHAIN_MESSAGE_PASSER;
+
+    assert_eq!(highest_cross_chain_nonce(&state).await.unwrap(), 0);
+    assert!(!cross_chain_nonce_issued(&state, 1).await.unwrap());
+
+    let mut storage = std::collections::BTreeMap::new();
+    storage.insert(CROSS_CHAIN_NONCE_SLOT, U256::from(2));
+    let mut changes = ChangeSet::new();
+    changes.insert(
+        passer,
+        AccountUpdate {
+            created: false,
+            selfdestructed: false,
+            nonce: 0,
+            balance: U256::ZERO,
+            code_hash: B256::ZERO,
+            code: None,
+            storage,
+        },
+    );
+    state.commit(changes).await.unwrap();
+
+    assert_eq!(highest_cross_chain_nonce(&state).await.unwrap(), 2);
+    assert!(!cross_chain_nonce_issued(&state, 0).await.unwrap());
+    assert!(cross_chain_nonce_issued(&state, 1).await.unwrap());
+    assert!(cross_chain_nonce_issued(&state, 2).await.unwrap());
+    assert!(!cross_chain_nonce_issued(&state, 3).await.unwrap());
+}
+
+#[test]
+fn test_static_call_to_cross_chain_passer_fails_without_assigning_nonce() {
+    use alloy_primitives::keccak256;
+
+    let state = MockStateDb::new();
+    let passer = classifier::precompiles::CROSS_CHAIN_MESSAGE_PASSER;
+
+    // STATICCALL(gas, passer, 0, 0, 0, 0); PUSH1 0; SSTORE
+    // CALL(gas, passer, 0, 0, 0, 0, 0); PUSH1 1; SSTORE; STOP
+    let mut probe_code = vec![0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x73];
+    probe_code.extend_from_slice(passer.as_slice());
+    probe_code.extend_from_slice(&[0x5a, 0xfa, 0x60, 0x00, 0x55]);
+    probe_code.extend_from_slice(&[0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00]);
+    probe_code.push(0x73);
+    probe_code.extend_from_slice(passer.as_slice());
+    probe_code.extend_from_slice(&[0x5a, 0xf1, 0x60, 0x01, 0x55, 0x00]);
+    let probe_code = Bytes::from(probe_code);
+    let probe_hash = keccak256(&probe_code);
+    let probe = Address::repeat_byte(0xaa);
+    state.insert_code(probe_hash, probe_code);
+    state.insert_account(probe, MockAccount { code_hash: probe_hash, ..Default::default() });
+
+    let executor = RevmExecutor::new(1);
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+    let tx = signed_legacy_call(0, probe, U256::ZERO, Bytes::new(), 300_000, 0);
+    let outcome = executor.execute(&state, &context, &[tx]).expect("execution should succeed");
+
+    assert!(outcome.receipts[0].success());
+    let probe_storage = &outcome.changes.accounts[&probe].storage;
+    assert_eq!(probe_storage.get(&U256::ZERO).copied().unwrap_or_default(), U256::ZERO);
+    assert_eq!(probe_storage[&U256::from(1)], U256::from(1));
+    // Only the plain call assigned a nonce.
+    let nonce = outcome.changes.accounts[&passer].storage[&CROSS_CHAIN_NONCE_SLOT];
+    assert_eq!(nonce, U256::from(1));
+}
+
+// ----------------------------------------------------------------------------
+// Tests for sender caching between mempool and executor
+// ----------------------------------------------------------------------------
+
+/// Sign a zero-gas-price legacy transaction to `to` and return its encoding.
+fn signed_legacy_tx(to: Address, gas_limit: u64) -> Bytes {
+    signed_legacy_tx_with_gas_price(to, gas_limit, 0)
+}
+
+/// Address of the key that signs the test transactions.
+fn test_signer() -> Address {
+    signer_address(TEST_SIGNER_KEY)
+}
+
+/// Secret key byte of [`test_signer`].
+const TEST_SIGNER_KEY: u8 = 0x42;
+
+/// Address of the signer whose secret key is `key` repeated.
+fn signer_address(key: u8) -> Address {
+    use k256::elliptic_curve::sec1::ToEncodedPoint as _;
+
+    let signing_key = k256::ecdsa::SigningKey::from_slice(&[key; 32]).unwrap();
+    let point = signing_key.verifying_key().to_encoded_point(false);
+    Address::from_raw_public_key(&point.as_bytes()[1..])
+}
+
+/// Sign a legacy transaction to `to` paying `gas_price` and return its encoding.
+fn signed_legacy_tx_with_gas_price(to: Address, gas_limit: u64, gas_price: u128) -> Bytes {
+    signed_legacy_call(0, to, U256::ZERO, Bytes::new(), gas_limit, gas_price)
+}
+
+/// Sign a legacy call from [`test_signer`] and return its encoding.
+fn signed_legacy_call(
+    nonce: u64,
+    to: Address,
+    value: U256,
+    input: Bytes,
+    gas_limit: u64,
+    gas_price: u128,
+) -> Bytes {
+    signed_legacy_call_from(TEST_SIGNER_KEY, nonce, to, value, input, gas_limit, gas_price)
+}
+
+/// Sign a legacy call from [`signer_address`]`(key)` and return its encoding.
+fn signed_legacy_call_from(
+    key: u8,
+    nonce: u64,
+    to: Address,
+    value: U256,
+    input: Bytes,
+    gas_limit: u64,
+    gas_price: u128,
+) -> Bytes {
+    use alloy_consensus::{SignableTransaction as _, TxEnvelope, TxLegacy};
+    use alloy_eips::eip2718::Encodable2718 as _;
+    use alloy_primitives::{Signature, TxKind};
+
+    let signing_key = k256::ecdsa::SigningKey::from_slice(&[key; 32]).unwrap();
+    let tx = TxLegacy {
+        chain_id: Some(1),
+        nonce,
+        gas_price,
+        gas_limit,
+        to: TxKind::Call(to),
+        value,
+        input,
+    };
+    let (sig, recovery_id) =
+        signing_key.sign_prehash_recoverable(tx.signature_hash().as_slice()).unwrap();
+    let signature = Signature::new(
+        U256::from_be_slice(&sig.r().to_bytes()),
+        U256::from_be_slice(&sig.s().to_bytes()),
+        recovery_id.is_y_odd(),
+    );
+    let envelope = TxEnvelope::from(tx.into_signed(signature));
+    let mut raw = Vec::new();
+    envelope.encode_2718(&mut raw);
+    Bytes::from(raw)
+}
+
+#[test]
+fn test_extcodesize_reads_code_size_without_loading_code() {
+    use alloy_primitives::keccak256;
+
+    let state = MockStateDb::new();
+    let target = Address::repeat_byte(0xbb);
+    let target_code = Bytes::from(vec![0x5b; 1_000]);
+    let target_hash = keccak256(&target_code);
+    state.insert_code(target_hash, target_code);
+    state.sized_only.write().unwrap().insert(target_hash);
+    state.insert_account(target, MockAccount { code_hash: target_hash, ..Default::default() });
+
+    // PUSH20 target; EXTCODESIZE; PUSH1 0; SSTORE; STOP
+    let mut probe_code = vec![0x73];
+    probe_code.extend_from_slice(target.as_slice());
+    probe_code.extend_from_slice(&[0x3b, 0x60, 0x00, 0x55, 0x00]);
+    let probe_code = Bytes::from(probe_code);
+    let probe_hash = keccak256(&probe_code);
+    let probe = Address::repeat_byte(0xaa);
+    state.insert_code(probe_hash, probe_code);
+    state.insert_account(probe, MockAccount { code_hash: probe_hash, ..Default::default() });
+
+    let executor = RevmExecutor::new(1);
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+    let tx = signed_legacy_call(0, probe, U256::ZERO, Bytes::new(), 100_000, 0);
+    let outcome = executor.execute(&state, &context, &[tx]).expect("execution should succeed");
+
+    assert!(outcome.receipts[0].success());
+    let stored = outcome.changes.accounts[&probe].storage[&U256::ZERO];
+    assert_eq!(stored, U256::from(1_000));
+}
+
+#[test]
+fn test_extcodesize_matches_stock_revm() {
+    use alloy_primitives::{KECCAK256_EMPTY, keccak256};
+    use revm::{
+        Context, ExecuteEvm as _, MainBuilder as _,
+        bytecode::Bytecode,
+        context::TxEnv,
+        database::{CacheDB, EmptyDB},
+        primitives::TxKind,
+        state::AccountInfo,
+    };
+
+    let contract = Address::repeat_byte(0xbb);
+    let contract_code = Bytes::from(vec![0x5b; 1_000]);
+    let empty = Address::repeat_byte(0xcc);
+    let missing = Address::repeat_byte(0xdd);
+    let precompile = Address::with_last_byte(0x01);
+    // Cold contract, empty account, missing account, precompile, then the contract warm.
+    let targets = [contract, empty, missing, precompile, contract];
+
+    // For target `i`, store its EXTCODESIZE at slot 2i and the gas the lookup took at 2i + 1.
+    let mut probe_code = Vec::new();
+    for (i, target) in targets.iter().enumerate() {
+        let slot = u8::try_from(2 * i).unwrap();
+        // GAS; PUSH20 target; EXTCODESIZE; GAS
+        probe_code.push(0x5a);
+        probe_code.push(0x73);
+        probe_code.extend_from_slice(target.as_slice());
+        probe_code.extend_from_slice(&[0x3b, 0x5a]);
+        // SWAP1; PUSH1 slot; SSTORE; SWAP1; SUB; PUSH1 slot + 1; SSTORE
+        probe_code.extend_from_slice(&[0x90, 0x60, slot, 0x55, 0x90, 0x03, 0x60, slot + 1, 0x55]);
+    }
+    probe_code.push(0x00);
+    let probe_code = Bytes::from(probe_code);
+    let probe = Address::repeat_byte(0xaa);
+
+    let state = MockStateDb::new();
+    let contract_hash = keccak256(&contract_code);
+    state.insert_code(contract_hash, contract_code.clone());
+    state.insert_account(contract, MockAccount { code_hash: contract_hash, ..Default::default() });
+    state.insert_account(
+        empty,
+        MockAccount { balance: U256::from(1), code_hash: KECCAK256_EMPTY, ..Default::default() },
+    );
+    let probe_hash = keccak256(&probe_code);
+    state.insert_code(probe_hash, probe_code.clone());
+    state.insert_account(probe, MockAccount { code_hash: probe_hash, ..Default::default() });
+
+    let executor = RevmExecutor::new(1);
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+    let tx = signed_legacy_call(0, probe, U256::ZERO, Bytes::new(), 200_000, 0);
+    let outcome = executor.execute(&state, &context, &[tx]).expect("execution should succeed");
+    assert!(outcome.receipts[0].success());
+
+    // Stock revm with its own EXTCODESIZE over the same accounts.
+    let mut db = CacheDB::new(EmptyDB::default());
+    db.insert_account_info(
+        contract,
+        AccountInfo::new(U256::ZERO, 0, contract_hash, Bytecode::new_raw(contract_code)),
+    );
+    db.insert_account_info(empty, AccountInfo { balance: U256::from(1), ..Default::default() });
+    db.insert_account_info(
+        probe,
+        AccountInfo::new(U256::ZERO, 0, probe_hash, Bytecode::new_raw(probe_code)),
+    );
+    let mut evm = Context::new(db, executor.spec_id())
+        .modify_cfg_chained(|cfg| cfg.chain_id = 1)
+        .modify_block_chained(|blk| blk.gas_limit = 30_000_000)
+        .build_mainnet();
+    let tx_env = TxEnv::builder()
+        .caller(signer_address(TEST_SIGNER_KEY))
+        .kind(TxKind::Call(probe))
+        .gas_limit(200_000)
+        .gas_price(0)
+        .chain_id(Some(1))
+        .build()
+        .expect("valid tx env");
+    let reference = evm.transact(tx_env).expect("stock revm execution should succeed");
+    assert!(reference.result.is_success());
+
+    assert_eq!(outcome.gas_used, reference.result.gas_used());
+    // Zero results leave their slot untouched, so a missing slot reads as zero.
+    let stored = &outcome.changes.accounts[&probe].storage;
+    let expected = &reference.state[&probe].storage;
+    let stored_at = |slot: usize| stored.get(&U256::from(slot)).copied().unwrap_or_default();
+    for slot in 0..2 * targets.len() {
+        let expected = expected.get(&U256::from(slot)).map(|s| s.present_value).unwrap_or_default();
+        assert_eq!(stored_at(slot), expected, "slot {slot}");
+    }
+
+    let size = |i: usize| stored_at(2 * i);
+    let gas = |i: usize| stored_at(2 * i + 1);
+    assert_eq!(size(0), U256::from(1_000));
+    assert_eq!(size(1), U256::ZERO);
+    assert_eq!(size(2), U256::ZERO);
+    assert_eq!(size(3), U256::ZERO);
+    assert_eq!(size(4), U256::from(1_000));
+    // Cold lookups pay the Berlin surcharge; precompiles and revisited accounts are warm.
+    assert!(gas(0) > gas(4));
+    assert_eq!(gas(3), gas(4));
+}
+
+#[test]
+fn test_admitted_transaction_recovers_sender_once() {
+    use monmouth_domain::Tx;
+    use monmouth_txpool::{Mempool as _, PoolConfig, TransactionPool};
+
+    let raw = signed_legacy_tx(Address::repeat_byte(0x11), 21_000);
+
+    // Admission recovers the sender and populates the shared cache.
+    let pool = TransactionPool::new(PoolConfig::default());
+    assert!(pool.insert(Tx::new(raw.clone())));
+    let cache = pool.sender_cache().clone();
+    assert_eq!(cache.recoveries(), 1);
+
+    // Execution of the same bytes reuses the cached sender.
+    let executor = RevmExecutor::new(1).with_sender_cache(cache.clone());
+    let state = MockStateDb::new();
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+    let outcome = executor.execute(&state, &context, &[raw]).expect("execution should succeed");
+
+    assert_eq!(cache.recoveries(), 1);
+    assert!(outcome.receipts[0].success());
+    let sender = pool.senders()[0];
+    assert_eq!(outcome.changes.accounts.get(&sender).map(|update| update.nonce), Some(1));
+}
+
+#[test]
+fn test_receipts_track_status_and_cumulative_gas() {
+    // PUSH1 0 PUSH1 0 REVERT
+    let code = Bytes::from_static(&[0x60, 0x00, 0x60, 0x00, 0xfd]);
+    let code_hash = alloy_primitives::keccak256(&code);
+    let reverter = Address::repeat_byte(0xfd);
+    let state = MockStateDb::new();
+    state.insert_code(code_hash, code);
+    state.insert_account(reverter, MockAccount { code_hash, ..Default::default() });
+    let txs = [
+        signed_legacy_call(0, Address::repeat_byte(0x11), U256::ZERO, Bytes::new(), 21_000, 0),
+        signed_legacy_call(1, reverter, U256::ZERO, Bytes::new(), 100_000, 0),
+    ];
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+
+    let outcome =
+        RevmExecutor::new(1).execute(&state, &context, &txs).expect("execution should succeed");
+
+    let transfer = outcome.receipt(0).expect("transfer receipt");
+    let reverted = outcome.receipt(1).expect("revert receipt");
+    assert!(outcome.receipt(2).is_none());
+    assert!(transfer.success());
+    assert!(!reverted.success());
+    assert_eq!(transfer.gas_used, 21_000);
+    assert!(reverted.gas_used > 21_000);
+    assert_eq!(transfer.cumulative_gas_used(), transfer.gas_used);
+    assert_eq!(reverted.cumulative_gas_used(), transfer.gas_used + reverted.gas_used);
+    assert_eq!(outcome.gas_used, reverted.cumulative_gas_used());
+}
+
+// ----------------------------------------------------------------------------
+// Tests for the per-transaction log limit
+// ----------------------------------------------------------------------------
+
+/// Execute a call to a contract that emits `logs` empty `LOG0`s under a limit of three.
+fn execute_log_emitter(logs: usize) -> ExecutionOutcome {
+    // (PUSH1 0 PUSH1 0 LOG0) * logs; STOP
+    let mut code = [0x60, 0x00, 0x60, 0x00, 0xa0].repeat(logs);
+    code.push(0x00);
+    let code = Bytes::from(code);
+    let code_hash = alloy_primitives::keccak256(&code);
+    let emitter = Address::repeat_byte(0xee);
+
+    let state = MockStateDb::new();
+    state.insert_code(code_hash, code);
+    state.insert_account(emitter, MockAccount { code_hash, ..Default::default() });
+
+    let executor = RevmExecutor::with_config(ExecutionConfig::new(1).with_max_logs_per_tx(3));
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+    executor
+        .execute(&state, &context, &[signed_legacy_tx(emitter, 100_000)])
+        .expect("execution should succeed")
+}
+
+#[test]
+fn test_logs_bloom_covers_emitting_addresses_and_topics() {
+    use alloy_primitives::{BloomInput, b256};
+
+    let first_topic = b256!("0x00000000000000000000000000000000000000000000000000000000000000aa");
+    let second_topic = b256!("0x00000000000000000000000000000000000000000000000000000000000000bb");
+    let first = Address::repeat_byte(0xe1);
+    let second = Address::repeat_byte(0xe2);
+    let state = MockStateDb::new();
+    for (emitter, topic) in [(first, 0xaa), (second, 0xbb)] {
+        // PUSH1 topic PUSH1 0 PUSH1 0 LOG1 STOP
+        let code = Bytes::from(vec![0x60, topic, 0x60, 0x00, 0x60, 0x00, 0xa1, 0x00]);
+        let code_hash = alloy_primitives::keccak256(&code);
+        state.insert_code(code_hash, code);
+        state.insert_account(emitter, MockAccount { code_hash, ..Default::default() });
+    }
+    let txs = [
+        signed_legacy_call(0, first, U256::ZERO, Bytes::new(), 100_000, 0),
+        signed_legacy_call(1, second, U256::ZERO, Bytes::new(), 100_000, 0),
+    ];
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+
+    let outcome =
+        RevmExecutor::new(1).execute(&state, &context, &txs).expect("execution should succeed");
+    let bloom = outcome.logs_bloom();
+
+    assert_eq!(outcome.receipts.iter().map(|receipt| receipt.logs().len()).sum::<usize>(), 2);
+    for input in
+        [first.as_slice(), second.as_slice(), first_topic.as_slice(), second_topic.as_slice()]
+    {
+        assert!(bloom.contains_input(BloomInput::Raw(input)));
+    }
+    assert!(!bloom.contains_input(BloomInput::Raw(Address::repeat_byte(0x99).as_slice())));
+    assert_eq!(bloom, outcome.logs_bloom());
+}
+
+#[test]
+fn test_assembled_header_matches_execution_outcome() {
+    use alloy_consensus::{ReceiptEnvelope, proofs::calculate_receipt_root};
+    use alloy_primitives::Bloom;
+    use monmouth_domain::{Block, StateRoot, Tx};
+
+    let emitter = Address::repeat_byte(0xe1);
+    let state = MockStateDb::new();
+    // PUSH1 0xaa PUSH1 0 PUSH1 0 LOG1 STOP
+    let code = Bytes::from(vec![0x60, 0xaa, 0x60, 0x00, 0x60, 0x00, 0xa1, 0x00]);
+    let code_hash = alloy_primitives::keccak256(&code);
+    state.insert_code(code_hash, code);
+    state.insert_account(emitter, MockAccount { code_hash, ..Default::default() });
+    let txs = [signed_legacy_call(0, emitter, U256::ZERO, Bytes::new(), 100_000, 0)];
+    let header = Header {
+        gas_limit: 30_000_000,
+        timestamp: 1_700_000_000,
+        beneficiary: Address::repeat_byte(0xbe),
+        ..Default::default()
+    };
+    let context = BlockContext::new(header, B256::ZERO, B256::repeat_byte(0x5e));
+    let outcome =
+        RevmExecutor::new(1).execute(&state, &context, &txs).expect("execution should succeed");
+    let parent = Block::genesis(StateRoot(B256::repeat_byte(0x01)), None);
+    let state_root = StateRoot(B256::repeat_byte(0x02));
+
+    let assembled = AssembledBlock::assemble(
+        &parent,
+        txs.iter().cloned().map(Tx::new).collect(),
+        state_root,
+        &outcome,
+        &context,
+    );
+
+    let header = &assembled.header;
+    assert_eq!(assembled.block.parent, parent.id());
+    assert_eq!(header.parent_hash, parent.id().0);
+    assert_eq!(header.number, parent.height + 1);
+    assert_eq!(header.state_root, state_root.0);
+    assert_eq!(header.transactions_root, assembled.block.compute_transactions_root());
+    let receipt = ReceiptEnvelope::Legacy(outcome.receipts[0].receipt.clone().into());
+    assert_eq!(header.receipts_root, calculate_receipt_root(&[receipt]));
+    assert_ne!(header.logs_bloom, Bloom::ZERO);
+    assert_eq!(header.logs_bloom, outcome.logs_bloom());
+    assert_eq!(header.gas_used, outcome.gas_used);
+    assert_eq!(header.gas_limit, context.header.gas_limit);
+    assert_eq!(header.timestamp, context.header.timestamp);
+    assert_eq!(header.beneficiary, context.header.beneficiary);
+    assert_eq!(header.base_fee_per_gas, context.header.base_fee_per_gas);
+    assert_eq!(header.mix_hash, assembled.block.prevrandao);
+
+    // The hash commits to every header field.
+    let mut altered = assembled.clone();
+    altered.header.gas_used += 1;
+    assert_ne!(altered.hash(), assembled.hash());
+}
+
+#[test]
+fn test_log_limit_allows_transaction_at_limit() {
+    let outcome = execute_log_emitter(3);
+
+    assert!(outcome.receipts[0].success());
+    assert_eq!(outcome.receipts[0].logs().len(), 3);
+}
+
+#[test]
+fn test_log_limit_reverts_transaction_over_limit() {
+    let outcome = execute_log_emitter(4);
+
+    let receipt = &outcome.receipts[0];
+    assert!(!receipt.success());
+    assert!(receipt.logs().is_empty());
+    assert!(receipt.gas_used > 21_000);
+    // The sender's nonce still advances for the reverted transaction.
+    assert_eq!(outcome.changes.accounts.get(&test_signer()).map(|update| update.nonce), Some(1));
+}
+
+// ----------------------------------------------------------------------------
+// Tests for call tracing
+// ----------------------------------------------------------------------------
+
+#[test]
+fn test_trace_records_nested_call_frame() {
+    let caller_contract = Address::repeat_byte(0xaa);
+    let callee = Address::repeat_byte(0xbb);
+
+    // CALL(gas, callee, 0, 0, 0, 0, 0); STOP
+    let mut code = vec![0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x73];
+    code.extend_from_slice(callee.as_slice());
+    code.extend_from_slice(&[0x5a, 0xf1, 0x00]);
+    let code = Bytes::from(code);
+    let code_hash = alloy_primitives::keccak256(&code);
+
+    let state = MockStateDb::new();
+    state.insert_code(code_hash, code);
+    state.insert_account(caller_contract, MockAccount { code_hash, ..Default::default() });
+
+    let raw = signed_legacy_tx(caller_contract, 100_000);
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+
+    let traces =
+        RevmExecutor::new(1).trace(&state, &context, &[raw.clone()]).expect("trace succeeds");
+
+    assert_eq!(traces.len(), 1);
+    assert_eq!(traces[0].tx_hash, alloy_primitives::keccak256(&raw));
+    let root = &traces[0].frame;
+    assert_eq!(root.kind, CallKind::Call);
+    assert_eq!(root.to, Some(caller_contract));
+    assert!(root.error.is_none());
+    assert_eq!(root.calls.len(), 1);
+    assert_eq!(root.calls[0].kind, CallKind::Call);
+    assert_eq!(root.calls[0].from, caller_contract);
+    assert_eq!(root.calls[0].to, Some(callee));
+}
+
+// ----------------------------------------------------------------------------
+// Tests for intent receipts
+// ----------------------------------------------------------------------------
+
+#[test]
+fn test_intent_parser_call_produces_receipt() {
+    let target = Address::repeat_byte(0x11);
+    let input = Bytes::from(format!("transfer 100 to {target}"));
+    let raw = signed_legacy_call(
+        0,
+        classifier::precompiles::INTENT_PARSER,
+        U256::ZERO,
+        input,
+        100_000,
+        0,
+    );
+    let header = Header { number: 7, gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+
+    let outcome = RevmExecutor::new(1)
+        .execute(&MockStateDb::new(), &context, &[raw.clone()])
+        .expect("execution should succeed");
+
+    assert!(outcome.receipts[0].success());
+    assert_eq!(
+        outcome.intent_receipts,
+        vec![IntentReceipt {
+            tx_hash: alloy_primitives::keccak256(&raw),
+            block_number: 7,
+            intent: ParsedIntent {
+                intent_type: ParsedIntent::TRANSFER,
+                target,
+                value: U256::from(100),
+                parsed: true,
+            },
+        }]
+    );
+}
+
+#[test]
+fn test_unparsed_intent_produces_no_receipt() {
+    let raw = signed_legacy_tx(classifier::precompiles::INTENT_PARSER, 100_000);
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+
+    let outcome = RevmExecutor::new(1)
+        .execute(&MockStateDb::new(), &context, &[raw])
+        .expect("execution should succeed");
+
+    assert!(outcome.receipts[0].success());
+    assert!(outcome.intent_receipts.is_empty());
+}
+
+#[test]
+fn test_failed_intent_parser_call_produces_no_receipt() {
+    // Not enough gas for the precompile's base cost after intrinsic gas.
+    let raw = signed_legacy_tx(classifier::precompiles::INTENT_PARSER, 22_000);
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+
+    let outcome = RevmExecutor::new(1)
+        .execute(&MockStateDb::new(), &context, &[raw])
+        .expect("execution should succeed");
+
+    assert!(!outcome.receipts[0].success());
+    assert!(outcome.intent_receipts.is_empty());
+}
+
+/// Execute a call into a contract that passes `"stake 32"` to the intent parser
+/// from a nested frame, which then ends with `inner_end` (`STOP` or `REVERT`).
+fn execute_nested_intent_call(inner_end: u8) -> ExecutionOutcome {
+    let outer = Address::repeat_byte(0xaa);
+    let inner = Address::repeat_byte(0xbb);
+
+    // MSTORE(0, "stake 32"); CALL(gas, INTENT_PARSER, 0, 24, 8, 0, 0); POP; <end>(0, 0)
+    let mut inner_code = vec![0x67];
+    inner_code.extend_from_slice(b"stake 32");
+    inner_code.extend_from_slice(&[0x60, 0x00, 0x52]);
+    inner_code
+        .extend_from_slice(&[0x60, 0x00, 0x60, 0x00, 0x60, 0x08, 0x60, 0x18, 0x60, 0x00, 0x73]);
+    inner_code.extend_from_slice(classifier::precompiles::INTENT_PARSER.as_slice());
+    inner_code.extend_from_slice(&[0x5a, 0xf1, 0x50, 0x60, 0x00, 0x60, 0x00, inner_end]);
+
+    // CALL(gas, inner, 0, 0, 0, 0, 0); STOP
+    let mut outer_code = vec![0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x73];
+    outer_code.extend_from_slice(inner.as_slice());
+    outer_code.extend_from_slice(&[0x5a, 0xf1, 0x00]);
+
+    let state = MockStateDb::new();
+    for (address, code) in [(outer, outer_code), (inner, inner_code)] {
+        let code = Bytes::from(code);
+        let code_hash = alloy_primitives::keccak256(&code);
+        state.insert_code(code_hash, code);
+        state.insert_account(address, MockAccount { code_hash, ..Default::default() });
+    }
+
+    let raw = signed_legacy_tx(outer, 200_000);
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+    RevmExecutor::new(1).execute(&state, &context, &[raw]).expect("execution should succeed")
+}
+
+#[test]
+fn test_intent_from_nested_call_produces_receipt() {
+    let outcome = execute_nested_intent_call(0x00);
+
+    assert!(outcome.receipts[0].success());
+    assert_eq!(outcome.intent_receipts.len(), 1);
+    assert_eq!(outcome.intent_receipts[0].intent.intent_type, ParsedIntent::STAKE);
+}
+
+#[test]
+fn test_intent_from_reverted_frame_produces_no_receipt() {
+    // The inner frame reverts after parsing, but the outer call swallows the failure.
+    let outcome = execute_nested_intent_call(0xfd);
+
+    assert!(outcome.receipts[0].success());
+    assert!(outcome.intent_receipts.is_empty());
+}
+
+#[test]
+fn test_external_precompile_call_over_capacity_fails() {
+    let raw = signed_legacy_tx(classifier::precompiles::AI_INFERENCE, 100_000);
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+
+    let limited = RevmExecutor::with_config(
+        ExecutionConfig::new(1)
+            .with_precompile_config(PrecompileConfig::default().with_external_call_limit(0, 0)),
+    );
+    let outcome = limited
+        .execute(&MockStateDb::new(), &context, &[raw.clone()])
+        .expect("execution should succeed");
+    assert!(!outcome.receipts[0].success());
+
+    let outcome = RevmExecutor::new(1)
+        .execute(&MockStateDb::new(), &context, &[raw])
+        .expect("execution should succeed");
+    assert!(outcome.receipts[0].success());
+}
+
+// ----------------------------------------------------------------------------
+// Tests for routing decision records
+// ----------------------------------------------------------------------------
+
+/// Routing observer that keeps every record it sees.
+#[derive(Debug, Default)]
+struct RecordingObserver {
+    records: std::sync::Mutex<Vec<RoutingRecord>>,
+}
+
+impl RoutingObserver for RecordingObserver {
+    fn observe(&self, record: &RoutingRecord) {
+        self.records.lock().unwrap().push(record.clone());
+    }
+}
+
+#[test]
+fn test_classified_transaction_produces_routing_record() {
+    let raw = signed_legacy_tx(classifier::precompiles::AI_INFERENCE, 100_000);
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+    let observer = Arc::new(RecordingObserver::default());
+    let metrics = Arc::new(RoutingMetrics::default());
+
+    let classifier = TransactionClassifier::new(ClassifierConfig::default());
+    let observers: [Arc<dyn RoutingObserver>; 2] = [observer.clone(), metrics.clone()];
+    for routing in observers {
+        let outcome = RevmExecutor::new(1)
+            .with_classifier(classifier.clone())
+            .with_routing_observer(routing)
+            .execute(&MockStateDb::new(), &context, &[raw.clone()])
+            .expect("execution should succeed");
+        assert!(outcome.receipts[0].success());
+    }
+
+    let records = observer.records.lock().unwrap();
+    assert_eq!(
+        *records,
+        vec![RoutingRecord {
+            tx_hash: alloy_primitives::keccak256(&raw),
+            classification: TransactionClassification::RagEnhanced,
+            pipeline: ExecutionPipeline::Rag,
+            outcome: RoutingOutcome::Success,
+        }]
+    );
+    assert_eq!(records[0].pipeline.label(), "rag");
+    assert_eq!(metrics.count(ExecutionPipeline::Rag, RoutingOutcome::Success), 1);
+}
+
+#[test]
+fn test_unclassified_execution_produces_no_routing_record() {
+    let raw = signed_legacy_tx(classifier::precompiles::AI_INFERENCE, 100_000);
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+    let observer = Arc::new(RecordingObserver::default());
+
+    RevmExecutor::new(1)
+        .with_routing_observer(observer.clone())
+        .execute(&MockStateDb::new(), &context, &[raw])
+        .expect("execution should succeed");
+
+    assert!(observer.records.lock().unwrap().is_empty());
+}
+
+// ----------------------------------------------------------------------------
+// Tests for priority fee settlement
+// ----------------------------------------------------------------------------
+
+#[test]
+fn test_priority_fees_split_between_recipients() {
+    const GAS_PRICE: u128 = 1_000_000_000;
+
+    let proposer = Address::repeat_byte(0xb0);
+    let treasury = Address::repeat_byte(0x7e);
+    let state = MockStateDb::new();
+    state.insert_account(
+        test_signer(),
+        MockAccount { balance: U256::from(10u128.pow(18)), ..Default::default() },
+    );
+
+    let config = ExecutionConfig::new(1)
+        .with_priority_fee_split(vec![(proposer, 7_000), (treasury, 3_000)])
+        .unwrap();
+    let raw = signed_legacy_tx_with_gas_price(Address::repeat_byte(0x11), 21_000, GAS_PRICE);
+    let header = Header { gas_limit: 30_000_000, beneficiary: proposer, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+
+    let outcome = RevmExecutor::with_config(config)
+        .execute(&state, &context, &[raw])
+        .expect("execution should succeed");
+
+    assert!(outcome.receipts[0].success());
+    let collected = U256::from(GAS_PRICE) * U256::from(outcome.gas_used);
+    assert!(!collected.is_zero());
+    let balance = |address| outcome.changes.accounts.get(&address).map(|update| update.balance);
+    assert_eq!(balance(proposer), Some(collected * U256::from(7) / U256::from(10)));
+    assert_eq!(balance(treasury), Some(collected * U256::from(3) / U256::from(10)));
+}
+
+// ----------------------------------------------------------------------------
+// Conformance tests
+// ----------------------------------------------------------------------------
+
+/// Generate a pseudo-random block of transfers from [`test_signer`] for `seed`.
+fn random_transfer_block(seed: u64) -> Vec<Bytes> {
+    use alloy_primitives::keccak256;
+
+    let count = seed % 16 + 1;
+    (0..count)
+        .map(|nonce| {
+            let entropy = keccak256([seed.to_be_bytes(), nonce.to_be_bytes()].concat());
+            let to = Address::from_slice(&entropy[..20]);
+            let value = U256::from(u32::from_be_bytes(entropy[20..24].try_into().unwrap()));
+            let gas_price = u128::from(entropy[24]) * 1_000_000_000;
+            signed_legacy_call(nonce, to, value, Bytes::new(), 21_000, gas_price)
+        })
+        .collect()
+}
+
+#[rstest]
+#[case(0)]
+#[case(1)]
+#[case(7)]
+#[case(42)]
+#[case(1_337)]
+#[tokio::test]
+async fn test_sequential_executor_conforms_to_itself(#[case] seed: u64) {
+    let state = MockStateDb::new();
+    state.insert_account(
+        test_signer(),
+        MockAccount { balance: U256::from(10u128.pow(18)), ..Default::default() },
+    );
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+    let txs = random_transfer_block(seed);
+
+    let executor = RevmExecutor::new(1);
+    let outcome = compare_executors(&state, &context, &txs, &executor, &executor.clone())
+        .await
+        .expect("sequential executor should agree with itself");
+
+    assert_eq!(outcome.receipts.len(), txs.len());
+    assert!(outcome.receipts.iter().all(|receipt| receipt.success()));
+}
+
+/// Fund each of `keys` and return the state.
+fn funded_signers(keys: &[u8]) -> MockStateDb {
+    let state = MockStateDb::new();
+    for key in keys {
+        state.insert_account(
+            signer_address(*key),
+            MockAccount { balance: U256::from(10u128.pow(18)), ..Default::default() },
+        );
+    }
+    state
+}
+
+#[tokio::test]
+async fn test_parallel_executor_matches_sequential() {
+    let state = funded_signers(&[1, 2, 3, 4, 5, 6]);
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+    let transfer = |key: u8, to: u8, value: u64| {
+        let to = Address::repeat_byte(0xa0 + to);
+        signed_legacy_call_from(key, 0, to, U256::from(value), Bytes::new(), 21_000, 0)
+    };
+
+    // Signers 2 and 5 both credit the same recipient and so conflict; the others are
+    // independent transfers.
+    let txs = vec![
+        transfer(1, 1, 100),
+        transfer(2, 0, 200),
+        transfer(3, 3, 300),
+        transfer(4, 4, 400),
+        transfer(5, 0, 500),
+        transfer(6, 6, 600),
+    ];
+
+    let sequential = RevmExecutor::new(1);
+    let parallel = ParallelExecutor::new(sequential.clone()).with_workers(3);
+    let outcome = compare_executors(&state, &context, &txs, &sequential, &parallel)
+        .await
+        .expect("parallel execution should match sequential execution");
+
+    assert_eq!(outcome.receipts.len(), txs.len());
+    assert!(outcome.receipts.iter().all(|receipt| receipt.success()));
+    assert_eq!(
+        outcome.changes.accounts.get(&Address::repeat_byte(0xa0)).map(|update| update.balance),
+        Some(U256::from(700))
+    );
+}
+
+#[tokio::test]
+async fn test_parallel_executor_output_is_independent_of_worker_count() {
+    let state = funded_signers(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let header = Header { gas_limit: 30_000_000, ..Default::default() };
+    let context = BlockContext::new(header, B256::ZERO, B256::ZERO);
+    // Two nonces per signer; odd signers share a recipient, even ones do not.
+    let txs: Vec<Bytes> = (0..2u64)
+        .flat_map(|nonce| {
+            (1..=8u8).map(move |key| {
+                let to = Address::repeat_byte(if key % 2 == 1 { 0xa0 } else { 0xa0 + key });
+                let value = U256::from(u64::from(key) * 100 + nonce);
+                signed_legacy_call_from(key, nonce, to, value, Bytes::new(), 21_000, 0)
+            })
+        })
+        .collect();
+
+    let sequential = RevmExecutor::new(1);
+    let reference = ParallelExecutor::new(sequential.clone()).with_workers(1);
+    let many = monmouth_executor::default_parallel_workers().max(3);
+    for workers in [2, many] {
+        let parallel = ParallelExecutor::new(sequential.clone()).with_workers(workers);
+        assert_eq!(parallel.workers(), workers);
+        // Run twice so the second block reuses the pool the first one started.
+        for _ in 0..2 {
+            let outcome = compare_executors(&state, &context, &txs, &reference, &parallel)
+                .await
+                .unwrap_or_else(|err| panic!("{workers} workers diverged: {err}"));
+            assert_eq!(outcome.receipts.len(), txs.len());
+        }
+    }
+}
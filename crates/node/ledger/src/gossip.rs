@@ -0,0 +1,117 @@
+//! Transaction gossip between peers.
+
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use commonware_macros::select;
+use commonware_p2p::{Receiver, Recipients, Sender};
+use commonware_runtime::{Clock, Handle, Spawner};
+use monmouth_consensus::components::ReannounceConfig;
+use monmouth_domain::Tx;
+
+use crate::LedgerService;
+
+/// Default interval between gossip rounds.
+pub const DEFAULT_GOSSIP_INTERVAL: Duration = Duration::from_secs(1);
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Actor that gossips pooled transactions over a dedicated channel.
+///
+/// Every round it broadcasts the transactions
+/// [`LedgerService::reannounce_due`] hands out: newly pooled ones on the next
+/// round, then again each reannounce interval until they are included or
+/// dropped, so a peer that restarted and lost its mempool still receives them.
+/// Each message carries one EIP-2718 encoded transaction. Transactions received
+/// from peers are admitted through [`LedgerService::submit_tx`] like local ones.
+pub struct TxGossip<E, S, R> {
+    context: E,
+    ledger: LedgerService,
+    interval: Duration,
+    reannounce: ReannounceConfig,
+    sender: S,
+    receiver: R,
+}
+
+impl<E, S, R> fmt::Debug for TxGossip<E, S, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TxGossip")
+            .field("interval", &self.interval)
+            .field("reannounce", &self.reannounce)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<E, P, S, R> TxGossip<E, S, R>
+where
+    E: Spawner + Clock,
+    P: commonware_cryptography::PublicKey,
+    S: Sender<PublicKey = P>,
+    R: Receiver<PublicKey = P>,
+{
+    /// Create a gossip actor for `ledger` over the given channel.
+    pub fn new(context: E, ledger: LedgerService, sender: S, receiver: R) -> Self {
+        Self {
+            context,
+            ledger,
+            interval: DEFAULT_GOSSIP_INTERVAL,
+            reannounce: ReannounceConfig::default(),
+            sender,
+            receiver,
+        }
+    }
+
+    /// Set the interval between gossip rounds.
+    #[must_use]
+    pub const fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set how un-included transactions are reannounced.
+    #[must_use]
+    pub const fn with_reannounce_config(mut self, config: ReannounceConfig) -> Self {
+        self.reannounce = config;
+        self
+    }
+
+    /// Spawn the actor.
+    ///
+    /// The actor runs until the channel closes or the handle is aborted.
+    pub fn start(self) -> Handle<()> {
+        let Self { context, ledger, interval, reannounce, mut sender, mut receiver } = self;
+        context.spawn(move |context| async move {
+            let mut next_round = context.current();
+            loop {
+                select! {
+                    _ = context.sleep_until(next_round) => {
+                        next_round += interval;
+                        let now = unix_secs(context.current());
+                        for tx in ledger.reannounce_due(now, reannounce).await {
+                            if let Err(err) =
+                                sender.send(Recipients::All, tx.bytes.0, false).await
+                            {
+                                tracing::warn!(%err, "failed to gossip transaction");
+                            }
+                        }
+                    },
+                    message = receiver.recv() => {
+                        let Ok((peer, bytes)) = message else {
+                            tracing::debug!("transaction gossip channel closed");
+                            return;
+                        };
+                        let tx = Tx::new(bytes.into());
+                        let id = tx.id();
+                        if let Err(err) = ledger.try_submit_tx(tx).await {
+                            tracing::trace!(?peer, ?id, %err, "ignored gossiped transaction");
+                        }
+                    },
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,181 @@
+//! Durable log of intent receipts from persisted blocks.
+
+use std::{fmt, sync::Arc};
+
+use alloy_primitives::{Address, B256, U256};
+use commonware_runtime::{Blob as _, Storage as _, tokio};
+use futures::lock::Mutex;
+use monmouth_executor::{IntentReceipt, ParsedIntent};
+
+use crate::{LedgerError, LedgerResult};
+
+const LOG_BLOB: &[u8] = b"receipts";
+
+/// Block number, tx hash, intent type, target, value, parsed flag.
+const RECORD_LEN: u64 = 8 + 32 + 1 + 20 + 32 + 1;
+
+fn log_partition(prefix: &str) -> String {
+    format!("{prefix}-intent-receipts")
+}
+
+fn storage_error(err: impl ToString) -> LedgerError {
+    LedgerError::IntentLog(err.to_string())
+}
+
+fn encode(receipt: &IntentReceipt, out: &mut Vec<u8>) {
+    out.extend_from_slice(&receipt.block_number.to_be_bytes());
+    out.extend_from_slice(receipt.tx_hash.as_slice());
+    out.push(receipt.intent.intent_type);
+    out.extend_from_slice(receipt.intent.target.as_slice());
+    out.extend_from_slice(&receipt.intent.value.to_be_bytes::<32>());
+    out.push(u8::from(receipt.intent.parsed));
+}
+
+fn decode(record: &[u8]) -> IntentReceipt {
+    let mut block_number = [0u8; 8];
+    block_number.copy_from_slice(&record[..8]);
+    IntentReceipt {
+        block_number: u64::from_be_bytes(block_number),
+        tx_hash: B256::from_slice(&record[8..40]),
+        intent: ParsedIntent {
+            intent_type: record[40],
+            target: Address::from_slice(&record[41..61]),
+            value: U256::from_be_slice(&record[61..93]),
+            parsed: record[93] != 0,
+        },
+    }
+}
+
+#[derive(Debug)]
+struct Cursor {
+    /// Length of the log in bytes.
+    len: u64,
+    /// Block number of the last appended receipt.
+    last_block: Option<u64>,
+}
+
+/// Append-only log of the intent receipts of persisted blocks.
+///
+/// Receipts are appended in block order as fixed-size records and synced
+/// before the block's state is committed. A block at or below the last logged
+/// block number is skipped, so re-persisting a block after a crash does not log
+/// its receipts twice. A torn trailing record is dropped on open.
+#[derive(Clone)]
+pub struct IntentReceiptLog {
+    context: tokio::Context,
+    partition: String,
+    cursor: Arc<Mutex<Cursor>>,
+}
+
+impl fmt::Debug for IntentReceiptLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntentReceiptLog")
+            .field("partition", &self.partition)
+            .finish_non_exhaustive()
+    }
+}
+
+impl IntentReceiptLog {
+    /// Open the log stored under `partition_prefix`.
+    pub async fn open(context: tokio::Context, partition_prefix: &str) -> LedgerResult<Self> {
+        let partition = log_partition(partition_prefix);
+        let (blob, len) = context.open(&partition, LOG_BLOB).await.map_err(storage_error)?;
+        let len = len - len % RECORD_LEN;
+        blob.resize(len).await.map_err(storage_error)?;
+        let last_block = if len == 0 {
+            None
+        } else {
+            let record = blob
+                .read_at(vec![0u8; RECORD_LEN as usize], len - RECORD_LEN)
+                .await
+                .map_err(storage_error)?;
+            Some(decode(record.as_ref()).block_number)
+        };
+        Ok(Self { context, partition, cursor: Arc::new(Mutex::new(Cursor { len, last_block })) })
+    }
+
+    /// Append `receipts`, given in block order, and sync the log.
+    pub async fn append(&self, receipts: &[IntentReceipt]) -> LedgerResult<()> {
+        let mut cursor = self.cursor.lock().await;
+        let fresh: Vec<_> = receipts
+            .iter()
+            .filter(|receipt| cursor.last_block.is_none_or(|last| receipt.block_number > last))
+            .collect();
+        let Some(last) = fresh.last() else {
+            return Ok(());
+        };
+        let last_block = last.block_number;
+        let mut buf = Vec::with_capacity(fresh.len() * RECORD_LEN as usize);
+        for receipt in fresh {
+            encode(receipt, &mut buf);
+        }
+        let written = buf.len() as u64;
+        let (blob, _) =
+            self.context.open(&self.partition, LOG_BLOB).await.map_err(storage_error)?;
+        blob.write_at(buf, cursor.len).await.map_err(storage_error)?;
+        blob.sync().await.map_err(storage_error)?;
+        cursor.len += written;
+        cursor.last_block = Some(last_block);
+        Ok(())
+    }
+
+    /// Receipts logged for block `number`.
+    ///
+    /// Scans the whole log; intended for offline comparison of declared intents
+    /// against executed effects rather than the hot path.
+    pub async fn receipts_for_block(&self, number: u64) -> LedgerResult<Vec<IntentReceipt>> {
+        let len = self.cursor.lock().await.len;
+        if len == 0 {
+            return Ok(Vec::new());
+        }
+        let (blob, _) =
+            self.context.open(&self.partition, LOG_BLOB).await.map_err(storage_error)?;
+        let buf = blob.read_at(vec![0u8; len as usize], 0).await.map_err(storage_error)?;
+        Ok(buf
+            .as_ref()
+            .chunks_exact(RECORD_LEN as usize)
+            .map(decode)
+            .filter(|receipt| receipt.block_number == number)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use commonware_runtime::Runner as _;
+
+    use super::*;
+
+    fn receipt(block_number: u64, byte: u8) -> IntentReceipt {
+        IntentReceipt {
+            tx_hash: B256::repeat_byte(byte),
+            block_number,
+            intent: ParsedIntent {
+                intent_type: ParsedIntent::SWAP,
+                target: Address::repeat_byte(byte),
+                value: U256::from(byte),
+                parsed: true,
+            },
+        }
+    }
+
+    #[test]
+    fn receipts_survive_reopen_and_are_logged_once() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let log = IntentReceiptLog::open(context.clone(), "intent-log-reopen").await.unwrap();
+            log.append(&[receipt(1, 0x01), receipt(2, 0x02), receipt(2, 0x03)]).await.unwrap();
+
+            let reopened = IntentReceiptLog::open(context, "intent-log-reopen").await.unwrap();
+            // Block 2 is re-persisted after a restart; its receipts are already logged.
+            reopened.append(&[receipt(2, 0x02), receipt(3, 0x04)]).await.unwrap();
+
+            assert_eq!(reopened.receipts_for_block(1).await.unwrap(), vec![receipt(1, 0x01)]);
+            assert_eq!(
+                reopened.receipts_for_block(2).await.unwrap(),
+                vec![receipt(2, 0x02), receipt(2, 0x03)]
+            );
+            assert_eq!(reopened.receipts_for_block(3).await.unwrap(), vec![receipt(3, 0x04)]);
+        });
+    }
+}
@@ -7,29 +7,72 @@
 
 use std::{collections::BTreeSet, fmt, sync::Arc};
 
+use ::tokio::sync::broadcast;
 use alloy_primitives::{Address, B256, U256};
 use commonware_cryptography::Committable as _;
 use commonware_runtime::{Metrics as _, buffer::PoolRef, tokio};
 use futures::{channel::mpsc::UnboundedReceiver, lock::Mutex};
 use monmouth_consensus::{
-    ConsensusError, Mempool as _, SeedTracker as _, Snapshot, SnapshotStore as _,
-    components::{InMemoryMempool, InMemorySeedTracker, InMemorySnapshotStore},
+    ConsensusError, Mempool as _, MempoolError, SeedTracker as _, Snapshot, SnapshotStore as _,
+    components::{InMemoryMempool, InMemorySeedTracker, InMemorySnapshotStore, ReannounceConfig},
 };
 use monmouth_domain::{
-    Block, BlockId, ConsensusDigest, LedgerEvent, LedgerEvents, StateRoot, Tx, TxId,
+    Block, ConsensusDigest, FinalizedNotification, ForkPoint, LedgerEvent, LedgerEvents,
+    NotificationDelivery, SenderCache, StateRoot, Tx, TxId,
 };
+use monmouth_executor::IntentReceipt;
 use monmouth_overlay::OverlayState;
 use monmouth_qmdb_ledger::{Error as QmdbError, QmdbChangeSet, QmdbConfig, QmdbLedger, QmdbState};
 use monmouth_traits::{StateDbError, StateDbRead, StateDbWrite};
+use monmouth_txpool::PoolConfig;
 use thiserror::Error;
 
+mod gossip;
+pub use gossip::{DEFAULT_GOSSIP_INTERVAL, TxGossip};
+
+mod intents;
+pub use intents::IntentReceiptLog;
+
+/// Default number of [`FinalizedNotification`]s buffered for each subscriber.
+pub const DEFAULT_FINALIZED_CHANNEL_CAPACITY: usize = 256;
+
+/// Default number of persisted ancestors kept below each finalized snapshot.
+pub const DEFAULT_SNAPSHOT_RETENTION: usize = 16;
+
 /// Snapshot type used by the ledger.
 pub type LedgerSnapshot = Snapshot<OverlayState<QmdbState>>;
 
+/// Results of executing a block, cached as its snapshot until it is persisted.
+#[derive(Debug)]
+pub struct BlockSnapshot<'a> {
+    /// Digest of the block's parent.
+    pub parent: ConsensusDigest,
+    /// State after the block, layered over the persisted state.
+    pub state: OverlayState<QmdbState>,
+    /// State root the block commits to.
+    pub root: StateRoot,
+    /// Changes the block itself made.
+    pub changes: QmdbChangeSet,
+    /// Transactions the block includes.
+    pub txs: &'a [Tx],
+    /// Intent receipts, logged when the block is persisted.
+    pub intent_receipts: Vec<IntentReceipt>,
+}
+
 fn tx_ids(txs: &[Tx]) -> BTreeSet<TxId> {
     txs.iter().map(Tx::id).collect()
 }
 
+/// Compare a root computed or committed by QMDB with a snapshot's recorded root.
+fn check_root(expected: Option<StateRoot>, actual: StateRoot) -> LedgerResult<()> {
+    match expected {
+        Some(expected) if expected != actual => {
+            Err(LedgerError::StateRootMismatch { expected, actual })
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Errors surfaced by ledger services.
 #[derive(Debug, Error)]
 pub enum LedgerError {
@@ -42,6 +85,17 @@ pub enum LedgerError {
     /// State database error.
     #[error("state db error: {0}")]
     StateDb(#[from] StateDbError),
+    /// The root QMDB computes for a snapshot's changes differs from its recorded root.
+    #[error("qmdb state root {actual:?} does not match snapshot root {expected:?}")]
+    StateRootMismatch {
+        /// Root recorded in the snapshot.
+        expected: StateRoot,
+        /// Root computed or committed by QMDB.
+        actual: StateRoot,
+    },
+    /// The intent receipt log could not be read or written.
+    #[error("intent receipt log error: {0}")]
+    IntentLog(String),
 }
 
 /// Result alias for ledger operations.
@@ -72,6 +126,23 @@ struct LedgerState {
     seeds: InMemorySeedTracker,
     /// Underlying QMDB ledger service for persistence.
     qmdb: QmdbLedger,
+    /// Intent receipts of persisted blocks.
+    intents: IntentReceiptLog,
+    /// Commit that failed after QMDB may have started writing it.
+    unfinished: Option<UnfinishedCommit>,
+    /// Persisted ancestors kept below each finalized snapshot.
+    snapshot_retention: usize,
+}
+
+/// A chain whose QMDB commit failed and must be finished before any other.
+///
+/// QMDB only resumes a partly written commit for the very same change set, so
+/// the chain stays marked as persisting until it is committed on its own.
+struct UnfinishedCommit {
+    chain: Vec<ConsensusDigest>,
+    changes: QmdbChangeSet,
+    expected: Option<StateRoot>,
+    height: Option<u64>,
 }
 
 impl LedgerView {
@@ -81,27 +152,28 @@ impl LedgerView {
         buffer_pool: PoolRef,
         partition_prefix: String,
         genesis_alloc: Vec<(Address, U256)>,
+        fork: Option<ForkPoint>,
     ) -> LedgerResult<Self> {
         let config = QmdbConfig::new(partition_prefix, buffer_pool);
-        Self::init_with_config(context, config, genesis_alloc).await
+        Self::init_with_config(context, config, genesis_alloc, fork).await
     }
 
     /// Initialize a ledger view with an explicit QMDB configuration.
+    ///
+    /// With a `fork` point the genesis block continues the exported history
+    /// instead of starting at height zero.
     pub async fn init_with_config(
         context: tokio::Context,
         config: QmdbConfig,
         genesis_alloc: Vec<(Address, U256)>,
+        fork: Option<ForkPoint>,
     ) -> LedgerResult<Self> {
+        let intents =
+            IntentReceiptLog::open(context.with_label("intents"), &config.partition_prefix).await?;
         let qmdb = QmdbLedger::init(context.with_label("qmdb"), config, genesis_alloc).await?;
         let genesis_root = qmdb.root().await?;
 
-        let genesis_block = Block {
-            parent: BlockId(B256::ZERO),
-            height: 0,
-            prevrandao: B256::ZERO,
-            state_root: genesis_root,
-            txs: Vec::new(),
-        };
+        let genesis_block = Block::genesis(genesis_root, fork);
         let genesis_digest = genesis_block.commitment();
         let state = OverlayState::new(qmdb.state(), QmdbChangeSet::default());
         let snapshots = InMemorySnapshotStore::new();
@@ -121,6 +193,9 @@ impl LedgerView {
                 snapshots,
                 seeds: InMemorySeedTracker::new(genesis_digest),
                 qmdb,
+                intents,
+                unfinished: None,
+                snapshot_retention: DEFAULT_SNAPSHOT_RETENTION,
             })),
             genesis_block,
         })
@@ -131,10 +206,51 @@ impl LedgerView {
         self.genesis_block.clone()
     }
 
+    /// Admit only transactions for `chain_id` that pass the stateless checks
+    /// configured by `config`.
+    ///
+    /// See [`InMemoryMempool::with_admission`].
+    pub async fn set_admission(&self, chain_id: u64, config: PoolConfig) {
+        let mut inner = self.inner.lock().await;
+        inner.mempool = inner.mempool.clone().with_admission(chain_id, config);
+    }
+
+    /// Set how many persisted ancestors stay cached below each finalized snapshot.
+    ///
+    /// Older snapshots are pruned whenever a finalized block is persisted.
+    pub async fn set_snapshot_retention(&self, retention: usize) {
+        self.inner.lock().await.snapshot_retention = retention;
+    }
+
     /// Submit a transaction into the mempool.
+    ///
+    /// Returns `false` if the mempool refuses it; see
+    /// [`try_submit_tx`](Self::try_submit_tx).
     pub async fn submit_tx(&self, tx: Tx) -> bool {
+        self.try_submit_tx(tx).await.is_ok()
+    }
+
+    /// Submit a transaction into the mempool, reporting why it was refused.
+    ///
+    /// The transaction must pass the mempool's stateless admission checks, and
+    /// its sender's nonce and balance are checked against persisted state, so
+    /// transactions behind a nonce gap stay parked and ones the sender cannot pay
+    /// for are refused. See [`InMemoryMempool::insert_from_state`].
+    pub async fn try_submit_tx(&self, tx: Tx) -> Result<(), MempoolError> {
+        let (mempool, state) = self.admission_components().await;
+        mempool.insert_from_state(&state, tx).await
+    }
+
+    /// Like [`try_submit_tx`](Self::try_submit_tx) for a transaction whose signer
+    /// the caller already recovered. See [`InMemoryMempool::insert_recovered`].
+    pub async fn submit_recovered_tx(&self, tx: Tx, sender: Address) -> Result<(), MempoolError> {
+        let (mempool, state) = self.admission_components().await;
+        mempool.insert_recovered(&state, tx, sender).await
+    }
+
+    async fn admission_components(&self) -> (InMemoryMempool, QmdbState) {
         let inner = self.inner.lock().await;
-        inner.mempool.insert(tx)
+        (inner.mempool.clone(), inner.qmdb.state())
     }
 
     /// Query a balance at the given digest.
@@ -170,25 +286,29 @@ impl LedgerView {
         inner.seeds.insert(digest, seed_hash);
     }
 
+    /// Return a handle to the persisted QMDB state.
+    ///
+    /// Reads through the handle observe the latest finalized block once it has
+    /// been persisted, without any unfinalized overlay changes.
+    pub async fn persisted_state(&self) -> QmdbState {
+        let inner = self.inner.lock().await;
+        inner.qmdb.state()
+    }
+
     /// Fetch the parent snapshot for a given digest.
     pub async fn parent_snapshot(&self, parent: ConsensusDigest) -> Option<LedgerSnapshot> {
         let inner = self.inner.lock().await;
         inner.snapshots.get(&parent)
     }
 
-    /// Insert a snapshot for a block digest.
-    pub async fn insert_snapshot(
-        &self,
-        digest: ConsensusDigest,
-        parent: ConsensusDigest,
-        state: OverlayState<QmdbState>,
-        root: StateRoot,
-        qmdb_changes: QmdbChangeSet,
-        txs: &[Tx],
-    ) {
+    /// Insert the snapshot of the block with `digest`.
+    pub async fn insert_snapshot(&self, digest: ConsensusDigest, block: BlockSnapshot<'_>) {
         let inner = self.inner.lock().await;
-        let ids = tx_ids(txs);
-        inner.snapshots.insert(digest, Snapshot::new(Some(parent), state, root, qmdb_changes, ids));
+        let ids = tx_ids(block.txs);
+        let snapshot =
+            Snapshot::new(Some(block.parent), block.state, block.root, block.changes, ids)
+                .with_intent_receipts(block.intent_receipts);
+        inner.snapshots.insert(digest, snapshot);
     }
 
     /// Cache a snapshot that has already been constructed.
@@ -234,12 +354,68 @@ impl LedgerView {
         Ok(StateRoot(root))
     }
 
+    /// Senders recovered when transactions were admitted to the mempool.
+    pub async fn sender_cache(&self) -> SenderCache {
+        self.inner.lock().await.mempool.sender_cache().clone()
+    }
+
+    /// Handle to the mempool transactions are admitted to.
+    pub async fn mempool(&self) -> InMemoryMempool {
+        self.inner.lock().await.mempool.clone()
+    }
+
+    /// Record the hash of a finalized block so the EVM can serve it to BLOCKHASH.
+    pub async fn record_block_hash(&self, number: u64, hash: B256) {
+        self.inner.lock().await.qmdb.state().push_block_hash(number, hash);
+    }
+
+    /// Intent receipts logged for the persisted block `number`.
+    pub async fn intent_receipts(&self, number: u64) -> LedgerResult<Vec<IntentReceipt>> {
+        let intents = self.inner.lock().await.intents.clone();
+        intents.receipts_for_block(number).await
+    }
+
     /// Persist `digest` and any missing ancestors to QMDB.
     ///
     /// Returns `Ok(true)` if a new commit happened, or `Ok(false)` if the digest is already
     /// persisted or currently being persisted by another task.
+    ///
+    /// The root of the merged changes is computed against QMDB and compared with
+    /// the snapshot's `state_root` before anything is written. On mismatch
+    /// nothing is logged or committed, the chain is left unpersisted and
+    /// [`LedgerError::StateRootMismatch`] is returned. Otherwise the intent
+    /// receipts of every block in the chain are logged and the state is
+    /// committed; the committed root is checked against the snapshot again.
+    ///
+    /// If committing fails, the next call first commits that chain again on its
+    /// own and then only the blocks above it, so QMDB resumes the partly written
+    /// commit instead of refusing the larger merged change set.
     pub async fn persist_snapshot(&self, digest: ConsensusDigest) -> LedgerResult<bool> {
-        let (changes, qmdb, chain) = {
+        self.persist(digest, None).await
+    }
+
+    /// Persist the finalized block `digest` at `height`, as
+    /// [`persist_snapshot`](Self::persist_snapshot) does, and record the
+    /// committed root as the root of `height`.
+    ///
+    /// Recorded roots are served by [`root_at`](Self::root_at).
+    pub async fn persist_finalized(
+        &self,
+        digest: ConsensusDigest,
+        height: u64,
+    ) -> LedgerResult<bool> {
+        self.persist(digest, Some(height)).await
+    }
+
+    /// State root recorded for the finalized block at `height`, if still retained.
+    pub async fn root_at(&self, height: u64) -> LedgerResult<Option<StateRoot>> {
+        let qmdb = self.inner.lock().await.qmdb.clone();
+        Ok(qmdb.root_at(height).await?)
+    }
+
+    async fn persist(&self, digest: ConsensusDigest, height: Option<u64>) -> LedgerResult<bool> {
+        self.finish_unfinished_commit().await?;
+        let (changes, qmdb, intents, receipts, chain, expected) = {
             let inner = self.inner.lock().await;
             let (chain, changes) = inner.snapshots.changes_for_persist(digest)?;
             if chain.is_empty() {
@@ -248,20 +424,80 @@ impl LedgerView {
             if !inner.snapshots.can_persist_chain(&chain) {
                 return Ok(false);
             }
+            let expected = inner.snapshots.get(&digest).map(|snapshot| snapshot.state_root);
+            // The chain runs from `digest` back to the persisted tip.
+            let receipts: Vec<IntentReceipt> = chain
+                .iter()
+                .rev()
+                .filter_map(|digest| inner.snapshots.get(digest))
+                .flat_map(|snapshot| snapshot.intent_receipts)
+                .collect();
             inner.snapshots.mark_persisting_chain(&chain);
-            (changes, inner.qmdb.clone(), chain)
+            (changes, inner.qmdb.clone(), inner.intents.clone(), receipts, chain, expected)
         };
 
-        let result = qmdb.commit_changes(changes).await;
-        let inner = self.inner.lock().await;
+        let prepared = async {
+            check_root(expected, StateRoot(qmdb.state().compute_root(&changes).await?))?;
+            intents.append(&receipts).await
+        }
+        .await;
+        let committed = match prepared {
+            Ok(()) => qmdb.commit_changes(changes.clone()).await,
+            Err(err) => {
+                self.inner.lock().await.snapshots.clear_persisting_chain(&chain);
+                return Err(err);
+            }
+        };
+        let mut inner = self.inner.lock().await;
+        let root = match committed {
+            Ok(root) => root,
+            Err(err) => {
+                inner.unfinished = Some(UnfinishedCommit { chain, changes, expected, height });
+                return Err(err.into());
+            }
+        };
         inner.snapshots.clear_persisting_chain(&chain);
-        match result {
-            Ok(_) => {
-                inner.snapshots.mark_persisted(&chain);
-                Ok(true)
+        check_root(expected, root)?;
+        inner.snapshots.mark_persisted(&chain);
+        if height.is_some() {
+            inner.snapshots.prune_retaining(digest, inner.snapshot_retention);
+        }
+        drop(inner);
+        if let Some(height) = height {
+            qmdb.record_root(height, root).await?;
+        }
+        Ok(true)
+    }
+
+    /// Commit the chain of an earlier failed [`persist_snapshot`](Self::persist_snapshot).
+    ///
+    /// Its root was checked and its intent receipts logged before the failed
+    /// attempt, so only the commit itself is retried.
+    async fn finish_unfinished_commit(&self) -> LedgerResult<()> {
+        let (unfinished, qmdb) = {
+            let mut inner = self.inner.lock().await;
+            let Some(unfinished) = inner.unfinished.take() else {
+                return Ok(());
+            };
+            (unfinished, inner.qmdb.clone())
+        };
+        let committed = qmdb.commit_changes(unfinished.changes.clone()).await;
+        let mut inner = self.inner.lock().await;
+        let root = match committed {
+            Ok(root) => root,
+            Err(err) => {
+                inner.unfinished = Some(unfinished);
+                return Err(err.into());
             }
-            Err(err) => Err(err.into()),
+        };
+        inner.snapshots.clear_persisting_chain(&unfinished.chain);
+        check_root(unfinished.expected, root)?;
+        inner.snapshots.mark_persisted(&unfinished.chain);
+        drop(inner);
+        if let Some(height) = unfinished.height {
+            qmdb.record_root(height, root).await?;
         }
+        Ok(())
     }
 
     /// Remove transactions that are included in a block from the mempool.
@@ -270,6 +506,13 @@ impl LedgerView {
         let tx_ids: Vec<TxId> = txs.iter().map(Tx::id).collect();
         inner.mempool.prune(&tx_ids);
     }
+
+    /// Pooled transactions due for broadcast at unix time `now`, in seconds.
+    ///
+    /// See [`InMemoryMempool::reannounce_due`].
+    pub async fn reannounce_due(&self, now: u64, config: ReannounceConfig) -> Vec<Tx> {
+        self.inner.lock().await.mempool.reannounce_due(now, config)
+    }
 }
 
 /// Domain service that exposes high-level ledger commands.
@@ -277,6 +520,8 @@ impl LedgerView {
 pub struct LedgerService {
     view: LedgerView,
     events: LedgerEvents,
+    finalized: broadcast::Sender<FinalizedNotification>,
+    delivery: NotificationDelivery<FinalizedNotification>,
 }
 
 impl fmt::Debug for LedgerService {
@@ -288,7 +533,40 @@ impl fmt::Debug for LedgerService {
 impl LedgerService {
     /// Create a new ledger service from a ledger view.
     pub fn new(view: LedgerView) -> Self {
-        Self { view, events: LedgerEvents::new() }
+        let (finalized, _) = broadcast::channel(DEFAULT_FINALIZED_CHANNEL_CAPACITY);
+        Self {
+            view,
+            events: LedgerEvents::new(),
+            finalized,
+            delivery: NotificationDelivery::default(),
+        }
+    }
+
+    /// Publish finalized notifications on `sender`, so receivers subscribed
+    /// before the service existed see them.
+    #[must_use]
+    pub fn with_finalized_sender(
+        mut self,
+        sender: broadcast::Sender<FinalizedNotification>,
+    ) -> Self {
+        self.finalized = sender;
+        self
+    }
+
+    /// Deliver finalized notifications through `delivery`, retrying failed sends
+    /// and dead-lettering undeliverable notifications.
+    #[must_use]
+    pub fn with_finalized_delivery(
+        mut self,
+        delivery: NotificationDelivery<FinalizedNotification>,
+    ) -> Self {
+        self.delivery = delivery;
+        self
+    }
+
+    /// Retry and dead-letter log of finalized notifications.
+    pub const fn finalized_delivery(&self) -> &NotificationDelivery<FinalizedNotification> {
+        &self.delivery
     }
 
     fn publish(&self, event: LedgerEvent) {
@@ -300,6 +578,16 @@ impl LedgerService {
         self.events.subscribe()
     }
 
+    /// Subscribe to a [`FinalizedNotification`] for each finalized block persisted
+    /// through [`persist_finalized`](Self::persist_finalized).
+    ///
+    /// The channel is bounded and never blocks the ledger: a receiver that falls
+    /// more than the channel capacity behind loses the oldest notifications and
+    /// its next `recv` returns `RecvError::Lagged` with the number skipped.
+    pub fn subscribe_finalized(&self) -> broadcast::Receiver<FinalizedNotification> {
+        self.finalized.subscribe()
+    }
+
     /// Return the genesis block.
     pub fn genesis_block(&self) -> Block {
         self.view.genesis_block()
@@ -307,12 +595,23 @@ impl LedgerService {
 
     /// Submit a transaction and emit events.
     pub async fn submit_tx(&self, tx: Tx) -> bool {
+        self.try_submit_tx(tx).await.is_ok()
+    }
+
+    /// Submit a transaction and emit events, reporting why it was refused.
+    pub async fn try_submit_tx(&self, tx: Tx) -> Result<(), MempoolError> {
         let tx_id = tx.id();
-        let inserted = self.view.submit_tx(tx).await;
-        if inserted {
-            self.publish(LedgerEvent::TransactionSubmitted(tx_id));
-        }
-        inserted
+        self.view.try_submit_tx(tx).await?;
+        self.publish(LedgerEvent::TransactionSubmitted(tx_id));
+        Ok(())
+    }
+
+    /// Submit a transaction whose signer was already recovered and emit events.
+    pub async fn submit_recovered_tx(&self, tx: Tx, sender: Address) -> Result<(), MempoolError> {
+        let tx_id = tx.id();
+        self.view.submit_recovered_tx(tx, sender).await?;
+        self.publish(LedgerEvent::TransactionSubmitted(tx_id));
+        Ok(())
     }
 
     /// Query a balance at the given digest.
@@ -341,22 +640,19 @@ impl LedgerService {
         self.publish(LedgerEvent::SeedUpdated(digest, seed_hash));
     }
 
+    /// Return a handle to the persisted state at the latest finalized block.
+    pub async fn persisted_state(&self) -> QmdbState {
+        self.view.persisted_state().await
+    }
+
     /// Fetch the snapshot of a parent digest.
     pub async fn parent_snapshot(&self, parent: ConsensusDigest) -> Option<LedgerSnapshot> {
         self.view.parent_snapshot(parent).await
     }
 
-    /// Insert a new snapshot.
-    pub async fn insert_snapshot(
-        &self,
-        digest: ConsensusDigest,
-        parent: ConsensusDigest,
-        state: OverlayState<QmdbState>,
-        root: StateRoot,
-        changes: QmdbChangeSet,
-        txs: &[Tx],
-    ) {
-        self.view.insert_snapshot(digest, parent, state, root, changes, txs).await;
+    /// Insert the snapshot of the block with `digest`.
+    pub async fn insert_snapshot(&self, digest: ConsensusDigest, block: BlockSnapshot<'_>) {
+        self.view.insert_snapshot(digest, block).await;
     }
 
     /// Cache a fully constructed snapshot.
@@ -400,10 +696,79 @@ impl LedgerService {
         Ok(())
     }
 
+    /// Persist the snapshot of the finalized block at `height` and notify
+    /// [`subscribe_finalized`](Self::subscribe_finalized) receivers if a new commit occurs.
+    ///
+    /// The notification goes through [`finalized_delivery`](Self::finalized_delivery);
+    /// one that cannot be sent is logged and kept as a dead letter.
+    pub async fn persist_finalized(
+        &self,
+        digest: ConsensusDigest,
+        height: u64,
+    ) -> LedgerResult<()> {
+        let state_root = self.view.query_state_root(digest).await;
+        let persisted = self.view.persist_finalized(digest, height).await?;
+        if persisted {
+            // Without subscribers there is nobody to deliver to.
+            if let Some(state_root) = state_root
+                && self.finalized.receiver_count() > 0
+            {
+                let notification = FinalizedNotification { height, digest, state_root };
+                let sent = self
+                    .delivery
+                    .deliver(notification, |notification| {
+                        std::future::ready(self.finalized.send(notification).map(|_| ()))
+                    })
+                    .await;
+                if let Err(letter) = sent {
+                    tracing::warn!(
+                        height,
+                        ?digest,
+                        attempts = letter.attempts,
+                        error = %letter.error,
+                        "finalized notification dead-lettered"
+                    );
+                }
+            }
+            self.publish(LedgerEvent::SnapshotPersisted(digest));
+        }
+        Ok(())
+    }
+
+    /// Record the hash of a finalized block for the BLOCKHASH opcode.
+    pub async fn record_block_hash(&self, number: u64, hash: B256) {
+        self.view.record_block_hash(number, hash).await;
+    }
+
+    /// Senders recovered at mempool admission, for the executor to reuse.
+    pub async fn sender_cache(&self) -> SenderCache {
+        self.view.sender_cache().await
+    }
+
+    /// Handle to the mempool, for serving its contents over RPC.
+    pub async fn mempool(&self) -> InMemoryMempool {
+        self.view.mempool().await
+    }
+
+    /// Intent receipts logged for the persisted block `number`.
+    pub async fn intent_receipts(&self, number: u64) -> LedgerResult<Vec<IntentReceipt>> {
+        self.view.intent_receipts(number).await
+    }
+
+    /// State root recorded for the finalized block at `height`, if still retained.
+    pub async fn root_at(&self, height: u64) -> LedgerResult<Option<StateRoot>> {
+        self.view.root_at(height).await
+    }
+
     /// Remove transactions from the mempool.
     pub async fn prune_mempool(&self, txs: &[Tx]) {
         self.view.prune_mempool(txs).await;
     }
+
+    /// Pooled transactions due for broadcast at unix time `now`, in seconds.
+    pub async fn reannounce_due(&self, now: u64, config: ReannounceConfig) -> Vec<Tx> {
+        self.view.reannounce_due(now, config).await
+    }
 }
 
 #[cfg(test)]
@@ -416,12 +781,18 @@ mod tests {
     use commonware_runtime::{Runner, buffer::PoolRef, tokio};
     use commonware_utils::{NZU16, NZUsize};
     use k256::ecdsa::SigningKey;
-    use monmouth_domain::{Block, ConsensusDigest, Tx, evm::Evm};
-    use monmouth_executor::{BlockContext, BlockExecutor, RevmExecutor};
+    use monmouth_consensus::{ConsensusError, SnapshotChainError, SnapshotStore as _};
+    use monmouth_domain::{
+        Block, BlockId, ConsensusDigest, FinalizedNotification, ForkPoint, StateRoot, Tx, evm::Evm,
+    };
+    use monmouth_executor::{
+        BlockContext, BlockExecutor, IntentReceipt, ParsedIntent, RevmExecutor,
+    };
     use monmouth_overlay::OverlayState;
+    use monmouth_qmdb_ledger::QmdbChangeSet;
     use monmouth_traits::StateDbRead;
 
-    use super::{LedgerService, LedgerSnapshot, LedgerView};
+    use super::{BlockSnapshot, LedgerError, LedgerService, LedgerSnapshot, LedgerView};
 
     static PARTITION_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
@@ -502,6 +873,7 @@ mod tests {
             test_buffer_pool(),
             next_partition(partition_prefix),
             allocations,
+            None,
         )
         .await
         .expect("init ledger");
@@ -534,7 +906,17 @@ mod tests {
         let digest = block.commitment();
         let next_state = OverlayState::new(parent_snapshot.state.base(), merged_changes);
         service
-            .insert_snapshot(digest, parent_digest, next_state, root, outcome.changes, &block.txs)
+            .insert_snapshot(
+                digest,
+                BlockSnapshot {
+                    parent: parent_digest,
+                    state: next_state,
+                    root,
+                    changes: outcome.changes,
+                    txs: &block.txs,
+                    intent_receipts: outcome.intent_receipts,
+                },
+            )
             .await;
         BuiltBlock { block, digest }
     }
@@ -594,6 +976,238 @@ mod tests {
         });
     }
 
+    #[test]
+    fn persist_snapshot_finishes_failed_commit_first() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            // Arrange
+            let from_key = key_from_byte(FROM_BYTE_A);
+            let to_key = key_from_byte(TO_BYTE_A);
+            let from = Evm::address_from_key(&from_key);
+            let to = Evm::address_from_key(&to_key);
+            let setup = setup_ledger(
+                context,
+                "revm-ledger-unfinished",
+                vec![(from, U256::from(GENESIS_BALANCE)), (to, U256::ZERO)],
+            )
+            .await;
+            let parent_snapshot = setup
+                .service
+                .parent_snapshot(setup.genesis_digest)
+                .await
+                .expect("genesis snapshot");
+            let block1 = build_block_snapshot(
+                &setup.service,
+                &setup.genesis,
+                parent_snapshot,
+                HEIGHT_ONE,
+                vec![transfer_tx(&from_key, to, TRANSFER_ONE, 0)],
+            )
+            .await;
+            let parent_snapshot =
+                setup.service.parent_snapshot(block1.digest).await.expect("block1 snapshot");
+            let block2 = build_block_snapshot(
+                &setup.service,
+                &block1.block,
+                parent_snapshot,
+                HEIGHT_TWO,
+                vec![transfer_tx(&from_key, to, TRANSFER_TWO, 1)],
+            )
+            .await;
+            // Leave block 1 as a commit that ran out of retries.
+            {
+                let mut inner = setup.ledger.inner.lock().await;
+                let (chain, changes) =
+                    inner.snapshots.changes_for_persist(block1.digest).expect("changes");
+                inner.snapshots.mark_persisting_chain(&chain);
+                let expected = Some(block1.block.state_root);
+                inner.unfinished =
+                    Some(UnfinishedCommit { chain, changes, expected, height: None });
+            }
+
+            // Act
+            let persisted =
+                setup.ledger.persist_snapshot(block2.digest).await.expect("persist snapshot");
+
+            // Assert
+            assert!(persisted);
+            let inner = setup.ledger.inner.lock().await;
+            assert!(inner.unfinished.is_none());
+            let (chain, _) = inner.snapshots.changes_for_persist(block2.digest).expect("changes");
+            assert!(chain.is_empty());
+            let result = inner.qmdb.state().balance(&to).await.expect("balance");
+            assert_eq!(result, U256::from(TRANSFER_ONE + TRANSFER_TWO));
+            assert_eq!(inner.qmdb.root().await.expect("root"), block2.block.state_root);
+        });
+    }
+
+    #[test]
+    fn persist_snapshot_logs_intent_receipts() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            // Arrange
+            let setup = setup_ledger(context, "revm-ledger-intents", Vec::new()).await;
+            let parent_snapshot = setup
+                .service
+                .parent_snapshot(setup.genesis_digest)
+                .await
+                .expect("genesis snapshot");
+            let root = setup
+                .service
+                .compute_root(setup.genesis_digest, QmdbChangeSet::default())
+                .await
+                .expect("compute root");
+            let block = Block {
+                parent: setup.genesis.id(),
+                height: HEIGHT_ONE,
+                prevrandao: PREVRANDAO,
+                state_root: root,
+                txs: Vec::new(),
+            };
+            let digest = block.commitment();
+            let receipt = IntentReceipt {
+                tx_hash: B256::repeat_byte(0x01),
+                block_number: HEIGHT_ONE,
+                intent: ParsedIntent {
+                    intent_type: ParsedIntent::STAKE,
+                    target: Address::repeat_byte(0x02),
+                    value: U256::from(32),
+                    parsed: true,
+                },
+            };
+            setup
+                .service
+                .insert_snapshot(
+                    digest,
+                    BlockSnapshot {
+                        parent: setup.genesis_digest,
+                        state: parent_snapshot.state,
+                        root,
+                        changes: QmdbChangeSet::default(),
+                        txs: &block.txs,
+                        intent_receipts: vec![receipt],
+                    },
+                )
+                .await;
+            assert!(setup.service.intent_receipts(HEIGHT_ONE).await.expect("read").is_empty());
+
+            // Act
+            setup.ledger.persist_snapshot(digest).await.expect("persist snapshot");
+
+            // Assert
+            let logged = setup.service.intent_receipts(HEIGHT_ONE).await.expect("read");
+            assert_eq!(logged, vec![receipt]);
+        });
+    }
+
+    #[test]
+    fn forked_genesis_continues_from_fork_point() {
+        // Tokio runtime required for WrapDatabaseAsync in the QMDB adapter.
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            // Arrange
+            let fork = ForkPoint::new(BlockId(B256::repeat_byte(0x42)), 1_000);
+            let ledger = LedgerView::init(
+                context,
+                test_buffer_pool(),
+                next_partition("revm-ledger-fork"),
+                Vec::new(),
+                Some(fork),
+            )
+            .await
+            .expect("init ledger");
+            let service = LedgerService::new(ledger);
+            let genesis = service.genesis_block();
+            let parent_snapshot =
+                service.parent_snapshot(genesis.commitment()).await.expect("genesis snapshot");
+
+            // Act
+            let first = build_block_snapshot(
+                &service,
+                &genesis,
+                parent_snapshot,
+                genesis.height + 1,
+                Vec::new(),
+            )
+            .await;
+
+            // Assert
+            assert_eq!(genesis.height, fork.height);
+            assert_eq!(genesis.parent, fork.parent);
+            assert_eq!(first.block.height, fork.height + 1);
+            assert_eq!(first.block.parent, genesis.id());
+        });
+    }
+
+    #[test]
+    fn persist_snapshot_rejects_inconsistent_root() {
+        // Tokio runtime required for WrapDatabaseAsync in the QMDB adapter.
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            // Arrange
+            let from_key = key_from_byte(FROM_BYTE_A);
+            let to_key = key_from_byte(TO_BYTE_A);
+            let from = Evm::address_from_key(&from_key);
+            let to = Evm::address_from_key(&to_key);
+            let setup = setup_ledger(
+                context,
+                "revm-ledger-inconsistent",
+                vec![(from, U256::from(GENESIS_BALANCE)), (to, U256::ZERO)],
+            )
+            .await;
+            let parent_snapshot = setup
+                .service
+                .parent_snapshot(setup.genesis_digest)
+                .await
+                .expect("genesis snapshot");
+            let executor = RevmExecutor::new(CHAIN_ID);
+            let txs = vec![transfer_tx(&from_key, to, TRANSFER_ONE, 0)];
+            let txs_bytes: Vec<Bytes> = txs.iter().map(|tx| tx.bytes.clone()).collect();
+            let outcome = executor
+                .execute(&parent_snapshot.state, &block_context(HEIGHT_ONE, PREVRANDAO), &txs_bytes)
+                .expect("execute txs");
+            let bogus_root = StateRoot(B256::repeat_byte(0xee));
+            let block = Block {
+                parent: setup.genesis.id(),
+                height: HEIGHT_ONE,
+                prevrandao: PREVRANDAO,
+                state_root: bogus_root,
+                txs,
+            };
+            let digest = block.commitment();
+            let merged = parent_snapshot.state.merge_changes(outcome.changes.clone());
+            let next_state = OverlayState::new(parent_snapshot.state.base(), merged);
+            setup
+                .service
+                .insert_snapshot(
+                    digest,
+                    BlockSnapshot {
+                        parent: setup.genesis_digest,
+                        state: next_state,
+                        root: bogus_root,
+                        changes: outcome.changes,
+                        txs: &block.txs,
+                        intent_receipts: Vec::new(),
+                    },
+                )
+                .await;
+
+            let qmdb = setup.ledger.inner.lock().await.qmdb.clone();
+            let root_before = qmdb.root().await.expect("root");
+
+            // Act
+            let result = setup.ledger.persist_snapshot(digest).await;
+
+            // Assert
+            assert!(matches!(
+                result,
+                Err(LedgerError::StateRootMismatch { expected, .. }) if expected == bogus_root
+            ));
+            assert!(!setup.ledger.inner.lock().await.snapshots.is_persisted(&digest));
+            assert_eq!(qmdb.root().await.expect("root"), root_before, "nothing committed");
+        });
+    }
+
     #[test]
     fn persist_snapshot_duplicate_is_noop() {
         // Tokio runtime required for WrapDatabaseAsync in the QMDB adapter.
@@ -637,6 +1251,201 @@ mod tests {
         });
     }
 
+    #[test]
+    fn persist_finalized_notifies_subscribers_once() {
+        // Tokio runtime required for WrapDatabaseAsync in the QMDB adapter.
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            // Arrange
+            let from_key = key_from_byte(FROM_BYTE_A);
+            let to = Evm::address_from_key(&key_from_byte(TO_BYTE_A));
+            let from = Evm::address_from_key(&from_key);
+            let setup = setup_ledger(
+                context,
+                "revm-ledger-finalized",
+                vec![(from, U256::from(GENESIS_BALANCE)), (to, U256::ZERO)],
+            )
+            .await;
+            let mut receiver = setup.service.subscribe_finalized();
+            let parent_snapshot = setup
+                .service
+                .parent_snapshot(setup.genesis_digest)
+                .await
+                .expect("genesis snapshot");
+            let block = build_block_snapshot(
+                &setup.service,
+                &setup.genesis,
+                parent_snapshot,
+                HEIGHT_ONE,
+                vec![transfer_tx(&from_key, to, TRANSFER_ONE, 0)],
+            )
+            .await;
+
+            // Act
+            setup.service.persist_finalized(block.digest, HEIGHT_ONE).await.expect("persist");
+            setup.service.persist_finalized(block.digest, HEIGHT_ONE).await.expect("persist");
+
+            // Assert
+            let notification = receiver.try_recv().expect("notification");
+            assert_eq!(
+                notification,
+                FinalizedNotification {
+                    height: HEIGHT_ONE,
+                    digest: block.digest,
+                    state_root: block.block.state_root,
+                }
+            );
+            assert!(receiver.try_recv().is_err());
+            assert_eq!(setup.service.finalized_delivery().failed_attempts(), 0);
+            assert!(setup.service.finalized_delivery().dead_letters().is_empty());
+        });
+    }
+
+    #[test]
+    fn persist_finalized_records_root_by_height() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            // Arrange
+            let from_key = key_from_byte(FROM_BYTE_A);
+            let to = Evm::address_from_key(&key_from_byte(TO_BYTE_A));
+            let from = Evm::address_from_key(&from_key);
+            let setup = setup_ledger(
+                context,
+                "revm-ledger-root-history",
+                vec![(from, U256::from(GENESIS_BALANCE)), (to, U256::ZERO)],
+            )
+            .await;
+            let parent_snapshot = setup
+                .service
+                .parent_snapshot(setup.genesis_digest)
+                .await
+                .expect("genesis snapshot");
+            let block1 = build_block_snapshot(
+                &setup.service,
+                &setup.genesis,
+                parent_snapshot,
+                HEIGHT_ONE,
+                vec![transfer_tx(&from_key, to, TRANSFER_ONE, 0)],
+            )
+            .await;
+            let parent_snapshot =
+                setup.service.parent_snapshot(block1.digest).await.expect("block1 snapshot");
+            let block2 = build_block_snapshot(
+                &setup.service,
+                &block1.block,
+                parent_snapshot,
+                HEIGHT_TWO,
+                vec![transfer_tx(&from_key, to, TRANSFER_TWO, 1)],
+            )
+            .await;
+
+            // Act
+            setup.ledger.persist_finalized(block1.digest, HEIGHT_ONE).await.expect("persist");
+            setup.ledger.persist_finalized(block2.digest, HEIGHT_TWO).await.expect("persist");
+
+            // Assert
+            let root_one = setup.ledger.root_at(HEIGHT_ONE).await.expect("root history");
+            assert_eq!(root_one, Some(block1.block.state_root));
+            let root_two = setup.ledger.root_at(HEIGHT_TWO).await.expect("root history");
+            assert_eq!(root_two, Some(block2.block.state_root));
+            assert_eq!(setup.ledger.root_at(HEIGHT_TWO + 1).await.expect("root history"), None);
+        });
+    }
+
+    #[test]
+    fn persist_finalized_prunes_snapshots_beyond_retention() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            // Arrange
+            let from_key = key_from_byte(FROM_BYTE_A);
+            let to = Evm::address_from_key(&key_from_byte(TO_BYTE_A));
+            let from = Evm::address_from_key(&from_key);
+            let setup = setup_ledger(
+                context,
+                "revm-ledger-snapshot-retention",
+                vec![(from, U256::from(GENESIS_BALANCE)), (to, U256::ZERO)],
+            )
+            .await;
+            setup.ledger.set_snapshot_retention(1).await;
+            let parent_snapshot = setup
+                .service
+                .parent_snapshot(setup.genesis_digest)
+                .await
+                .expect("genesis snapshot");
+            let block1 = build_block_snapshot(
+                &setup.service,
+                &setup.genesis,
+                parent_snapshot,
+                HEIGHT_ONE,
+                vec![transfer_tx(&from_key, to, TRANSFER_ONE, 0)],
+            )
+            .await;
+            let parent_snapshot =
+                setup.service.parent_snapshot(block1.digest).await.expect("block1 snapshot");
+            let block2 = build_block_snapshot(
+                &setup.service,
+                &block1.block,
+                parent_snapshot,
+                HEIGHT_TWO,
+                vec![transfer_tx(&from_key, to, TRANSFER_TWO, 1)],
+            )
+            .await;
+
+            // Act
+            setup.ledger.persist_finalized(block1.digest, HEIGHT_ONE).await.expect("persist");
+            let genesis_retained =
+                setup.ledger.parent_snapshot(setup.genesis_digest).await.is_some();
+            setup.ledger.persist_finalized(block2.digest, HEIGHT_TWO).await.expect("persist");
+
+            // Assert
+            assert!(genesis_retained);
+            assert!(setup.ledger.parent_snapshot(setup.genesis_digest).await.is_none());
+            assert!(setup.ledger.parent_snapshot(block1.digest).await.is_some());
+            assert!(setup.ledger.parent_snapshot(block2.digest).await.is_some());
+        });
+    }
+
+    #[test]
+    fn persisted_state_reflects_only_finalized_blocks() {
+        // Tokio runtime required for WrapDatabaseAsync in the QMDB adapter.
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            // Arrange
+            let from_key = key_from_byte(FROM_BYTE_A);
+            let to = Evm::address_from_key(&key_from_byte(TO_BYTE_A));
+            let from = Evm::address_from_key(&from_key);
+            let setup = setup_ledger(
+                context,
+                "revm-ledger-persisted-state",
+                vec![(from, U256::from(GENESIS_BALANCE)), (to, U256::ZERO)],
+            )
+            .await;
+            let state = setup.service.persisted_state().await;
+            let parent_snapshot = setup
+                .service
+                .parent_snapshot(setup.genesis_digest)
+                .await
+                .expect("genesis snapshot");
+            let block = build_block_snapshot(
+                &setup.service,
+                &setup.genesis,
+                parent_snapshot,
+                HEIGHT_ONE,
+                vec![transfer_tx(&from_key, to, TRANSFER_ONE, 0)],
+            )
+            .await;
+            let before = state.balance(&to).await.expect("balance before");
+
+            // Act
+            setup.service.persist_finalized(block.digest, HEIGHT_ONE).await.expect("persist");
+
+            // Assert
+            assert_eq!(before, U256::ZERO);
+            assert_eq!(state.balance(&to).await.expect("balance"), U256::from(TRANSFER_ONE));
+            assert_eq!(state.nonce(&from).await.expect("nonce"), 1);
+        });
+    }
+
     #[test]
     fn persist_snapshot_merges_overlays() {
         // Tokio runtime required for WrapDatabaseAsync in the QMDB adapter.
@@ -692,7 +1501,7 @@ mod tests {
     }
 
     #[test]
-    fn persist_snapshot_unrelated_merges() {
+    fn persist_snapshot_rejects_fork() {
         // Tokio runtime required for WrapDatabaseAsync in the QMDB adapter.
         let executor = tokio::Runner::default();
         executor.start(|context| async move {
@@ -746,21 +1555,22 @@ mod tests {
             // Act
             let persisted_1 =
                 setup.ledger.persist_snapshot(block1.digest).await.expect("persist snapshot");
-            let persisted_2 =
-                setup.ledger.persist_snapshot(block2.digest).await.expect("persist snapshot");
+            let persisted_2 = setup.ledger.persist_snapshot(block2.digest).await;
 
             // Assert
             assert!(persisted_1);
-            assert!(persisted_2);
+            assert!(matches!(
+                persisted_2,
+                Err(LedgerError::Consensus(ConsensusError::SnapshotChain(
+                    SnapshotChainError::Fork { base: Some(base), tip, .. }
+                ))) if base == setup.genesis_digest && tip == block1.digest
+            ));
             let qmdb = setup.ledger.inner.lock().await.qmdb.clone();
             assert_eq!(
                 qmdb.state().balance(&to_a).await.expect("balance"),
                 U256::from(TRANSFER_ONE)
             );
-            assert_eq!(
-                qmdb.state().balance(&to_b).await.expect("balance"),
-                U256::from(TRANSFER_DUPLICATE)
-            );
+            assert_eq!(qmdb.state().balance(&to_b).await.expect("balance"), U256::ZERO);
         });
     }
 
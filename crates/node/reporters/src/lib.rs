@@ -6,7 +6,7 @@
 
 use std::{fmt, marker::PhantomData};
 
-use alloy_primitives::{B256, Bytes, keccak256};
+use alloy_primitives::Bytes;
 use commonware_consensus::{
     Block as _, Reporter,
     marshal::Update,
@@ -15,17 +15,26 @@ use commonware_consensus::{
         types::Activity,
     },
 };
-use commonware_cryptography::{Committable as _, bls12381::primitives::variant::Variant};
+use commonware_cryptography::{
+    Committable as _,
+    bls12381::primitives::{
+        group::Share,
+        sharing::Sharing,
+        variant::{MinSig, Variant},
+    },
+};
 use commonware_runtime::{Spawner as _, tokio};
 use commonware_utils::acknowledgement::Acknowledgement as _;
 use monmouth_consensus::BlockExecution;
-use monmouth_domain::{Block, ConsensusDigest, PublicKey};
-use monmouth_executor::{BlockContext, BlockExecutor};
-use monmouth_ledger::LedgerService;
+use monmouth_domain::{
+    Block, Checkpoint, CheckpointStore, ConsensusDigest, PublicKey, prevrandao_from_seed,
+};
+use monmouth_executor::{BlockContext, BlockExecutor, RevmExecutor};
+use monmouth_ledger::{BlockSnapshot, LedgerService};
 use monmouth_overlay::OverlayState;
 use monmouth_qmdb_ledger::QmdbState;
-use monmouth_rpc::NodeState;
-use tracing::{error, trace, warn};
+use monmouth_rpc::{BlockTraces, NodeState};
+use tracing::{debug, error, trace, warn};
 
 /// Provides block execution context for finalized block verification.
 pub trait BlockContextProvider: Clone + Send + Sync + 'static {
@@ -41,18 +50,12 @@ async fn seed_report_inner<V: Variant>(
     match activity {
         Activity::Notarization(notarization) => {
             state
-                .set_seed(
-                    notarization.proposal.payload,
-                    SeedReporter::<V>::hash_seed(notarization.seed()),
-                )
+                .set_seed(notarization.proposal.payload, prevrandao_from_seed(&notarization.seed()))
                 .await;
         }
         Activity::Finalization(finalization) => {
             state
-                .set_seed(
-                    finalization.proposal.payload,
-                    SeedReporter::<V>::hash_seed(finalization.seed()),
-                )
+                .set_seed(finalization.proposal.payload, prevrandao_from_seed(&finalization.seed()))
                 .await;
         }
         _ => {}
@@ -79,10 +82,6 @@ impl<V> SeedReporter<V> {
     pub const fn new(state: LedgerService) -> Self {
         Self { state, _variant: PhantomData }
     }
-
-    fn hash_seed(seed: impl commonware_codec::Encode) -> B256 {
-        keccak256(seed.encode())
-    }
 }
 
 impl<V> Reporter for SeedReporter<V>
@@ -99,11 +98,99 @@ where
     }
 }
 
+/// Records call traces of finalized blocks for `debug_traceBlockByNumber`.
+///
+/// Each block is traced against its parent snapshot before the block is
+/// persisted, since the parent state is no longer available afterwards.
+#[derive(Clone, Debug)]
+pub struct TraceRecorder {
+    /// Executor used to re-run blocks with call tracing.
+    executor: RevmExecutor,
+    /// Store the RPC provider serves traces from.
+    traces: BlockTraces,
+}
+
+impl TraceRecorder {
+    /// Create a recorder tracing with `executor` into `traces`.
+    pub const fn new(executor: RevmExecutor, traces: BlockTraces) -> Self {
+        Self { executor, traces }
+    }
+
+    async fn record<P: BlockContextProvider>(
+        &self,
+        state: &LedgerService,
+        provider: &P,
+        block: &Block,
+    ) {
+        let Some(parent_snapshot) = state.parent_snapshot(block.parent()).await else {
+            warn!(height = block.height, "missing parent snapshot; finalized block not traced");
+            return;
+        };
+        let txs: Vec<Bytes> = block.txs.iter().map(|tx| tx.bytes.clone()).collect();
+        match self.executor.trace(&parent_snapshot.state, &provider.context(block), &txs) {
+            Ok(traces) => {
+                self.traces.insert(block.height, traces.into_iter().map(Into::into).collect());
+            }
+            Err(err) => {
+                warn!(height = block.height, error = ?err, "failed to trace finalized block");
+            }
+        }
+    }
+}
+
+/// Signs a [`Checkpoint`] of each finalized block at a height due under its interval.
+///
+/// The partial signature is kept in a [`CheckpointStore`], which aggregates the
+/// checkpoint once a threshold of validators' partials has been added to it.
+/// Partials are not exchanged between validators here: collecting the others'
+/// partials into the store is left to the caller.
+#[derive(Clone)]
+pub struct CheckpointRecorder {
+    /// Blocks between checkpoints; zero disables them.
+    interval: u64,
+    /// This validator's share of the threshold key.
+    share: Share,
+    /// Public polynomial partials are recovered under.
+    sharing: Sharing<MinSig>,
+    /// Store the signed checkpoints are served from.
+    store: CheckpointStore<MinSig>,
+}
+
+impl fmt::Debug for CheckpointRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CheckpointRecorder").field("interval", &self.interval).finish_non_exhaustive()
+    }
+}
+
+impl CheckpointRecorder {
+    /// Create a recorder signing with `share` every `interval` blocks into `store`.
+    pub const fn new(
+        interval: u64,
+        share: Share,
+        sharing: Sharing<MinSig>,
+        store: CheckpointStore<MinSig>,
+    ) -> Self {
+        Self { interval, share, sharing, store }
+    }
+
+    fn record(&self, block: &Block) {
+        if !Checkpoint::is_due(block.height, self.interval) {
+            return;
+        }
+        let checkpoint = Checkpoint::new(block.height, block.id(), block.state_root);
+        let partial = checkpoint.sign_partial::<MinSig>(&self.share);
+        let signed = self.store.insert(checkpoint, partial, &self.sharing).is_some();
+        debug!(height = block.height, signed, "recorded checkpoint partial signature");
+    }
+}
+
 async fn handle_finalized_update<E, P>(
     state: LedgerService,
     context: tokio::Context,
     executor: E,
     provider: P,
+    recorder: Option<TraceRecorder>,
+    checkpoints: Option<CheckpointRecorder>,
     update: Update<Block>,
 ) where
     E: BlockExecutor<OverlayState<QmdbState>, Tx = Bytes>,
@@ -164,20 +251,27 @@ async fn handle_finalized_update<E, P>(
                 state
                     .insert_snapshot(
                         digest,
-                        parent_digest,
-                        next_state,
-                        state_root,
-                        execution.outcome.changes,
-                        &block.txs,
+                        BlockSnapshot {
+                            parent: parent_digest,
+                            state: next_state,
+                            root: state_root,
+                            changes: execution.outcome.changes,
+                            txs: &block.txs,
+                            intent_receipts: execution.outcome.intent_receipts,
+                        },
                     )
                     .await;
             } else {
                 trace!(?digest, "using cached snapshot for finalized block");
             }
+            if let Some(recorder) = &recorder {
+                recorder.record(&state, &provider, &block).await;
+            }
             let persist_state = state.clone();
-            let persist_handle = context
-                .shared(true)
-                .spawn(move |_| async move { persist_state.persist_snapshot(digest).await });
+            let height = block.height;
+            let persist_handle = context.shared(true).spawn(move |_| async move {
+                persist_state.persist_finalized(digest, height).await
+            });
             let persist_result = match persist_handle.await {
                 Ok(result) => result,
                 Err(err) => {
@@ -191,7 +285,11 @@ async fn handle_finalized_update<E, P>(
                 ack.acknowledge();
                 return;
             }
+            state.record_block_hash(block.height, block.id().0).await;
             state.prune_mempool(&block.txs).await;
+            if let Some(checkpoints) = &checkpoints {
+                checkpoints.record(&block);
+            }
             // Marshal waits for the application to acknowledge processing before advancing the
             // delivery floor. Without this, the node can stall on finalized block delivery.
             ack.acknowledge();
@@ -210,6 +308,10 @@ pub struct FinalizedReporter<E, P> {
     executor: E,
     /// Provider that builds block execution context.
     provider: P,
+    /// Optional recorder of finalized block traces.
+    recorder: Option<TraceRecorder>,
+    /// Optional signer of finalized block checkpoints.
+    checkpoints: Option<CheckpointRecorder>,
 }
 
 impl<E, P> fmt::Debug for FinalizedReporter<E, P> {
@@ -230,7 +332,21 @@ where
         executor: E,
         provider: P,
     ) -> Self {
-        Self { state, context, executor, provider }
+        Self { state, context, executor, provider, recorder: None, checkpoints: None }
+    }
+
+    /// Trace each finalized block with `recorder` before persisting it.
+    #[must_use]
+    pub fn with_trace_recorder(mut self, recorder: TraceRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Sign checkpoints of persisted finalized blocks with `recorder`.
+    #[must_use]
+    pub fn with_checkpoint_recorder(mut self, recorder: CheckpointRecorder) -> Self {
+        self.checkpoints = Some(recorder);
+        self
     }
 }
 
@@ -246,8 +362,19 @@ where
         let context = self.context.clone();
         let executor = self.executor.clone();
         let provider = self.provider.clone();
+        let recorder = self.recorder.clone();
+        let checkpoints = self.checkpoints.clone();
         async move {
-            handle_finalized_update(state, context, executor, provider, update).await;
+            handle_finalized_update(
+                state,
+                context,
+                executor,
+                provider,
+                recorder,
+                checkpoints,
+                update,
+            )
+            .await;
         }
     }
 }
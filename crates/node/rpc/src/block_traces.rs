@@ -0,0 +1,101 @@
+//! Bounded store of call traces recorded for finalized blocks.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+};
+
+use parking_lot::Mutex;
+
+use crate::types::RpcTransactionTrace;
+
+/// Default number of finalized blocks whose traces are kept.
+pub const DEFAULT_BLOCK_TRACE_CAPACITY: usize = 128;
+
+#[derive(Debug, Default)]
+struct Inner {
+    traces: HashMap<u64, Vec<RpcTransactionTrace>>,
+    order: VecDeque<u64>,
+}
+
+/// Call traces of recent finalized blocks, keyed by block number.
+///
+/// Finalization traces each block against its parent state while that state is
+/// still held in memory; `debug_traceBlockByNumber` serves the recorded traces.
+/// Once `capacity` blocks are held the oldest is evicted first. Clones share the
+/// same store.
+#[derive(Clone, Debug)]
+pub struct BlockTraces {
+    capacity: usize,
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl BlockTraces {
+    /// Create an empty store holding the traces of at most `capacity` blocks.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, inner: Arc::new(Mutex::new(Inner::default())) }
+    }
+
+    /// Returns the traces recorded for block `number`.
+    pub fn get(&self, number: u64) -> Option<Vec<RpcTransactionTrace>> {
+        self.inner.lock().traces.get(&number).cloned()
+    }
+
+    /// Record the traces of block `number`, one per transaction in block order.
+    pub fn insert(&self, number: u64, traces: Vec<RpcTransactionTrace>) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut inner = self.inner.lock();
+        if inner.traces.insert(number, traces).is_some() {
+            return;
+        }
+        inner.order.push_back(number);
+        while inner.traces.len() > self.capacity {
+            let Some(oldest) = inner.order.pop_front() else { break };
+            inner.traces.remove(&oldest);
+        }
+    }
+
+    /// Number of blocks whose traces are held.
+    pub fn len(&self) -> usize {
+        self.inner.lock().traces.len()
+    }
+
+    /// Returns `true` if no traces are held.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for BlockTraces {
+    fn default() -> Self {
+        Self::new(DEFAULT_BLOCK_TRACE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oldest_block_is_evicted_past_capacity() {
+        let traces = BlockTraces::new(2);
+        for number in 1..=3 {
+            traces.insert(number, Vec::new());
+        }
+
+        assert_eq!(traces.len(), 2);
+        assert!(traces.get(1).is_none());
+        assert!(traces.get(2).is_some());
+        assert!(traces.get(3).is_some());
+    }
+
+    #[test]
+    fn zero_capacity_holds_nothing() {
+        let traces = BlockTraces::new(0);
+        traces.insert(1, Vec::new());
+        assert!(traces.is_empty());
+    }
+}
@@ -0,0 +1,444 @@
+//! Result cache for repeated `eth_call` requests against the same head.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+};
+
+use alloy_primitives::{Address, B256, Bytes, U64, U256};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+
+use crate::{
+    error::RpcError,
+    state_provider::StateProvider,
+    types::{
+        BlockNumberOrTag, CallRequest, RpcBlock, RpcLog, RpcLogFilter, RpcTransaction,
+        RpcTransactionReceipt, RpcTransactionTrace,
+    },
+};
+
+/// Default maximum number of cached call results.
+pub const DEFAULT_CALL_CACHE_CAPACITY: usize = 1024;
+
+/// A call request together with the head it executes against.
+///
+/// Every request field is part of the key: fees, nonce, and chain id can all
+/// change the outcome (e.g. a fee cap the sender cannot afford reverts).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct CallKey {
+    head: B256,
+    from: Option<Address>,
+    to: Option<Address>,
+    input: Bytes,
+    value: Option<U256>,
+    gas: Option<U64>,
+    gas_price: Option<U256>,
+    max_fee_per_gas: Option<U256>,
+    max_priority_fee_per_gas: Option<U256>,
+    nonce: Option<U64>,
+    chain_id: Option<U64>,
+}
+
+impl CallKey {
+    fn new(head: B256, request: &CallRequest) -> Self {
+        Self {
+            head,
+            from: request.from,
+            to: request.to,
+            input: request.input_data(),
+            value: request.value,
+            gas: request.gas,
+            gas_price: request.gas_price,
+            max_fee_per_gas: request.max_fee_per_gas,
+            max_priority_fee_per_gas: request.max_priority_fee_per_gas,
+            nonce: request.nonce,
+            chain_id: request.chain_id,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct CallCacheInner {
+    head: Option<B256>,
+    entries: HashMap<CallKey, Bytes>,
+    order: VecDeque<CallKey>,
+}
+
+/// Bounded memo of `eth_call` outputs for the current head.
+///
+/// Entries are only valid for the head they were computed against. Advancing the
+/// head drops every entry, so a result is never served across head changes. Once
+/// `capacity` entries are held, the oldest entry is evicted first.
+#[derive(Debug)]
+pub struct CallCache {
+    capacity: usize,
+    inner: Mutex<CallCacheInner>,
+}
+
+impl CallCache {
+    /// Create an empty cache holding at most `capacity` results.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, inner: Mutex::new(CallCacheInner::default()) }
+    }
+
+    /// Record a new head digest, invalidating results computed against the previous head.
+    ///
+    /// Call this on each new finalization.
+    pub fn advance_head(&self, head: B256) {
+        let mut inner = self.inner.lock();
+        if inner.head != Some(head) {
+            inner.head = Some(head);
+            inner.entries.clear();
+            inner.order.clear();
+        }
+    }
+
+    /// Returns the head digest the cached results belong to.
+    pub fn head(&self) -> Option<B256> {
+        self.inner.lock().head
+    }
+
+    /// Returns the number of cached results.
+    pub fn len(&self) -> usize {
+        self.inner.lock().entries.len()
+    }
+
+    /// Returns `true` if no results are cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn get(&self, key: &CallKey) -> Option<Bytes> {
+        let inner = self.inner.lock();
+        if inner.head != Some(key.head) {
+            return None;
+        }
+        inner.entries.get(key).cloned()
+    }
+
+    fn insert(&self, key: CallKey, output: Bytes) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut inner = self.inner.lock();
+        // The head advanced while the call executed; the result is already stale.
+        if inner.head != Some(key.head) || inner.entries.contains_key(&key) {
+            return;
+        }
+        while inner.entries.len() >= self.capacity {
+            let Some(oldest) = inner.order.pop_front() else { break };
+            inner.entries.remove(&oldest);
+        }
+        inner.order.push_back(key.clone());
+        inner.entries.insert(key, output);
+    }
+}
+
+impl Default for CallCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CALL_CACHE_CAPACITY)
+    }
+}
+
+/// [`StateProvider`] wrapper that memoizes `eth_call` results per head.
+///
+/// Only calls against the latest block are cached; calls pinned to another block
+/// and calls made before any head is recorded go straight to the inner provider.
+/// Errors are never cached. All other methods delegate unchanged.
+#[derive(Debug)]
+pub struct CachingStateProvider<S> {
+    inner: S,
+    cache: Arc<CallCache>,
+}
+
+impl<S> CachingStateProvider<S> {
+    /// Wrap `inner` with a call cache of the given capacity.
+    #[must_use]
+    pub fn new(inner: S, capacity: usize) -> Self {
+        Self { inner, cache: Arc::new(CallCache::new(capacity)) }
+    }
+
+    /// Returns a handle to the cache, used to advance the head on finalization.
+    pub fn cache(&self) -> Arc<CallCache> {
+        Arc::clone(&self.cache)
+    }
+}
+
+impl<S: Clone> Clone for CachingStateProvider<S> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone(), cache: Arc::clone(&self.cache) }
+    }
+}
+
+#[async_trait]
+impl<S: StateProvider> StateProvider for CachingStateProvider<S> {
+    async fn balance(
+        &self,
+        address: Address,
+        block: Option<BlockNumberOrTag>,
+    ) -> Result<U256, RpcError> {
+        self.inner.balance(address, block).await
+    }
+
+    async fn nonce(
+        &self,
+        address: Address,
+        block: Option<BlockNumberOrTag>,
+    ) -> Result<u64, RpcError> {
+        self.inner.nonce(address, block).await
+    }
+
+    async fn code(
+        &self,
+        address: Address,
+        block: Option<BlockNumberOrTag>,
+    ) -> Result<Bytes, RpcError> {
+        self.inner.code(address, block).await
+    }
+
+    async fn storage(
+        &self,
+        address: Address,
+        slot: U256,
+        block: Option<BlockNumberOrTag>,
+    ) -> Result<U256, RpcError> {
+        self.inner.storage(address, slot, block).await
+    }
+
+    async fn block_by_number(&self, block: BlockNumberOrTag) -> Result<Option<RpcBlock>, RpcError> {
+        self.inner.block_by_number(block).await
+    }
+
+    async fn block_by_hash(&self, hash: B256) -> Result<Option<RpcBlock>, RpcError> {
+        self.inner.block_by_hash(hash).await
+    }
+
+    async fn transaction_by_hash(&self, hash: B256) -> Result<Option<RpcTransaction>, RpcError> {
+        self.inner.transaction_by_hash(hash).await
+    }
+
+    async fn receipt_by_hash(&self, hash: B256) -> Result<Option<RpcTransactionReceipt>, RpcError> {
+        self.inner.receipt_by_hash(hash).await
+    }
+
+    async fn block_number(&self) -> Result<u64, RpcError> {
+        self.inner.block_number().await
+    }
+
+    async fn call(
+        &self,
+        request: CallRequest,
+        block: Option<BlockNumberOrTag>,
+    ) -> Result<Bytes, RpcError> {
+        let latest = block.as_ref().is_none_or(BlockNumberOrTag::is_latest);
+        let Some(head) = self.cache.head().filter(|_| latest) else {
+            return self.inner.call(request, block).await;
+        };
+
+        let key = CallKey::new(head, &request);
+        if let Some(output) = self.cache.get(&key) {
+            return Ok(output);
+        }
+
+        let output = self.inner.call(request, block).await?;
+        self.cache.insert(key, output.clone());
+        Ok(output)
+    }
+
+    async fn estimate_gas(
+        &self,
+        request: CallRequest,
+        block: Option<BlockNumberOrTag>,
+    ) -> Result<u64, RpcError> {
+        self.inner.estimate_gas(request, block).await
+    }
+
+    async fn get_logs(&self, filter: RpcLogFilter) -> Result<Vec<RpcLog>, RpcError> {
+        self.inner.get_logs(filter).await
+    }
+
+    async fn trace_block(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> Result<Vec<RpcTransactionTrace>, RpcError> {
+        self.inner.trace_block(block).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::NoopStateProvider;
+
+    #[derive(Debug, Default)]
+    struct CountingProvider {
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl StateProvider for CountingProvider {
+        async fn balance(
+            &self,
+            address: Address,
+            block: Option<BlockNumberOrTag>,
+        ) -> Result<U256, RpcError> {
+            NoopStateProvider.balance(address, block).await
+        }
+
+        async fn nonce(
+            &self,
+            address: Address,
+            block: Option<BlockNumberOrTag>,
+        ) -> Result<u64, RpcError> {
+            NoopStateProvider.nonce(address, block).await
+        }
+
+        async fn code(
+            &self,
+            address: Address,
+            block: Option<BlockNumberOrTag>,
+        ) -> Result<Bytes, RpcError> {
+            NoopStateProvider.code(address, block).await
+        }
+
+        async fn storage(
+            &self,
+            address: Address,
+            slot: U256,
+            block: Option<BlockNumberOrTag>,
+        ) -> Result<U256, RpcError> {
+            NoopStateProvider.storage(address, slot, block).await
+        }
+
+        async fn block_by_number(
+            &self,
+            block: BlockNumberOrTag,
+        ) -> Result<Option<RpcBlock>, RpcError> {
+            NoopStateProvider.block_by_number(block).await
+        }
+
+        async fn block_by_hash(&self, hash: B256) -> Result<Option<RpcBlock>, RpcError> {
+            NoopStateProvider.block_by_hash(hash).await
+        }
+
+        async fn transaction_by_hash(
+            &self,
+            hash: B256,
+        ) -> Result<Option<RpcTransaction>, RpcError> {
+            NoopStateProvider.transaction_by_hash(hash).await
+        }
+
+        async fn receipt_by_hash(
+            &self,
+            hash: B256,
+        ) -> Result<Option<RpcTransactionReceipt>, RpcError> {
+            NoopStateProvider.receipt_by_hash(hash).await
+        }
+
+        async fn block_number(&self) -> Result<u64, RpcError> {
+            Ok(0)
+        }
+
+        async fn call(
+            &self,
+            _request: CallRequest,
+            _block: Option<BlockNumberOrTag>,
+        ) -> Result<Bytes, RpcError> {
+            let n = self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(Bytes::from(vec![n as u8]))
+        }
+    }
+
+    fn view_call() -> CallRequest {
+        CallRequest {
+            to: Some(Address::repeat_byte(0x42)),
+            input: Some(Bytes::from_static(&[0x70, 0xa0, 0x82, 0x31])),
+            ..Default::default()
+        }
+    }
+
+    fn caching_provider(
+        capacity: usize,
+    ) -> (CachingStateProvider<CountingProvider>, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = CountingProvider { calls: Arc::clone(&calls) };
+        (CachingStateProvider::new(provider, capacity), calls)
+    }
+
+    #[tokio::test]
+    async fn identical_calls_at_same_head_execute_once() {
+        let (provider, calls) = caching_provider(DEFAULT_CALL_CACHE_CAPACITY);
+        provider.cache().advance_head(B256::repeat_byte(1));
+
+        let first = provider.call(view_call(), None).await.unwrap();
+        let second = provider.call(view_call(), Some(BlockNumberOrTag::Latest)).await.unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn new_head_re_executes() {
+        let (provider, calls) = caching_provider(DEFAULT_CALL_CACHE_CAPACITY);
+        let cache = provider.cache();
+        cache.advance_head(B256::repeat_byte(1));
+
+        let before = provider.call(view_call(), None).await.unwrap();
+        cache.advance_head(B256::repeat_byte(2));
+        assert!(cache.is_empty());
+        let after = provider.call(view_call(), None).await.unwrap();
+
+        assert_ne!(before, after);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn calls_bypass_cache_without_head_or_at_historical_block() {
+        let (provider, calls) = caching_provider(DEFAULT_CALL_CACHE_CAPACITY);
+
+        provider.call(view_call(), None).await.unwrap();
+        provider.call(view_call(), None).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        provider.cache().advance_head(B256::repeat_byte(1));
+        let historical = Some(BlockNumberOrTag::Number(U64::from(1)));
+        provider.call(view_call(), historical.clone()).await.unwrap();
+        provider.call(view_call(), historical).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+        assert!(provider.cache().is_empty());
+    }
+
+    #[tokio::test]
+    async fn calls_differing_in_fee_or_nonce_are_cached_separately() {
+        let (provider, calls) = caching_provider(DEFAULT_CALL_CACHE_CAPACITY);
+        provider.cache().advance_head(B256::repeat_byte(1));
+
+        provider.call(view_call(), None).await.unwrap();
+        let priced = CallRequest { gas_price: Some(U256::from(7)), ..view_call() };
+        provider.call(priced, None).await.unwrap();
+        let with_nonce = CallRequest { nonce: Some(U64::from(3)), ..view_call() };
+        provider.call(with_nonce, None).await.unwrap();
+        let other_chain = CallRequest { chain_id: Some(U64::from(9)), ..view_call() };
+        provider.call(other_chain, None).await.unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+        assert_eq!(provider.cache().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn cache_is_bounded() {
+        let (provider, _calls) = caching_provider(2);
+        provider.cache().advance_head(B256::repeat_byte(1));
+
+        for byte in 0..3u8 {
+            let request = CallRequest { input: Some(Bytes::from(vec![byte])), ..view_call() };
+            provider.call(request, None).await.unwrap();
+        }
+
+        assert_eq!(provider.cache().len(), 2);
+    }
+}
@@ -1,6 +1,11 @@
 //! RPC server configuration.
 
-use std::net::SocketAddr;
+use std::{net::SocketAddr, time::Duration};
+
+use crate::{
+    DEFAULT_EXECUTION_QUEUE_TIMEOUT, DEFAULT_MAX_CONCURRENT_EXECUTIONS,
+    DEFAULT_MAX_TRACES_PER_REQUEST, MethodFilter,
+};
 
 /// Configuration for the RPC server.
 #[derive(Clone, Debug)]
@@ -17,6 +22,18 @@ pub struct RpcServerConfig {
     pub rate_limit: RateLimitConfig,
     /// Maximum number of concurrent connections.
     pub max_connections: u32,
+    /// JSON-RPC methods exposed by the server.
+    pub method_filter: MethodFilter,
+    /// Maximum number of transaction traces returned by one debug request.
+    pub max_traces_per_request: usize,
+    /// Reject requests with more parameters than the method accepts.
+    ///
+    /// Lenient (the default) ignores extra parameters.
+    pub strict_params: bool,
+    /// Maximum concurrent `eth_call`, `eth_estimateGas`, and `debug_trace*` executions.
+    pub max_concurrent_executions: usize,
+    /// How long an execution waits for a free slot before failing as "server busy".
+    pub execution_queue_timeout: Duration,
 }
 
 impl RpcServerConfig {
@@ -29,6 +46,11 @@ impl RpcServerConfig {
             cors: CorsConfig::default(),
             rate_limit: RateLimitConfig::default(),
             max_connections: 100,
+            method_filter: MethodFilter::default(),
+            max_traces_per_request: DEFAULT_MAX_TRACES_PER_REQUEST,
+            strict_params: false,
+            max_concurrent_executions: DEFAULT_MAX_CONCURRENT_EXECUTIONS,
+            execution_queue_timeout: DEFAULT_EXECUTION_QUEUE_TIMEOUT,
         }
     }
 
@@ -57,6 +79,39 @@ impl RpcServerConfig {
         self.max_connections = max_connections;
         self
     }
+
+    /// Set the JSON-RPC method filter.
+    #[must_use]
+    pub fn with_method_filter(mut self, method_filter: MethodFilter) -> Self {
+        self.method_filter = method_filter;
+        self
+    }
+
+    /// Set the maximum number of transaction traces returned by one debug request.
+    #[must_use]
+    pub const fn with_max_traces_per_request(mut self, max_traces: usize) -> Self {
+        self.max_traces_per_request = max_traces;
+        self
+    }
+
+    /// Reject unknown parameters with `-32602` instead of ignoring them.
+    #[must_use]
+    pub const fn with_strict_params(mut self, strict: bool) -> Self {
+        self.strict_params = strict;
+        self
+    }
+
+    /// Bound concurrent read-only EVM executions, queuing extras for `queue_timeout`.
+    #[must_use]
+    pub const fn with_execution_limit(
+        mut self,
+        max_concurrent: usize,
+        queue_timeout: Duration,
+    ) -> Self {
+        self.max_concurrent_executions = max_concurrent;
+        self.execution_queue_timeout = queue_timeout;
+        self
+    }
 }
 
 impl Default for RpcServerConfig {
@@ -68,6 +123,11 @@ impl Default for RpcServerConfig {
             cors: CorsConfig::default(),
             rate_limit: RateLimitConfig::default(),
             max_connections: 100,
+            method_filter: MethodFilter::default(),
+            max_traces_per_request: DEFAULT_MAX_TRACES_PER_REQUEST,
+            strict_params: false,
+            max_concurrent_executions: DEFAULT_MAX_CONCURRENT_EXECUTIONS,
+            execution_queue_timeout: DEFAULT_EXECUTION_QUEUE_TIMEOUT,
         }
     }
 }
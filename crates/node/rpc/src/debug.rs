@@ -0,0 +1,264 @@
+//! Debug JSON-RPC API implementation.
+
+use std::sync::Arc;
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+
+use crate::{
+    error::RpcError,
+    execution_limit::ExecutionLimiter,
+    state_provider::StateProvider,
+    types::{BlockNumberOrTag, BlockTransactions, RpcTransactionTrace},
+};
+
+/// Default maximum number of transaction traces returned by a single request.
+pub const DEFAULT_MAX_TRACES_PER_REQUEST: usize = 1_000;
+
+/// Debug JSON-RPC API trait.
+///
+/// Not part of the default method allowlist; enable `debug_*` explicitly.
+#[rpc(server, namespace = "debug")]
+pub trait DebugApi {
+    /// Re-executes every transaction of a finalized block and returns its call traces.
+    #[method(name = "traceBlockByNumber")]
+    async fn trace_block_by_number(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> RpcResult<Vec<RpcTransactionTrace>>;
+}
+
+/// Debug API implementation with state provider.
+pub struct DebugApiImpl<S: StateProvider> {
+    state_provider: Arc<S>,
+    max_traces: usize,
+    executions: Option<ExecutionLimiter>,
+}
+
+impl<S: StateProvider> std::fmt::Debug for DebugApiImpl<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DebugApiImpl").field("max_traces", &self.max_traces).finish()
+    }
+}
+
+impl<S: StateProvider + 'static> DebugApiImpl<S> {
+    /// Create a new Debug API implementation.
+    ///
+    /// Blocks with more than `max_traces` transactions are rejected before any
+    /// re-execution happens.
+    pub fn new(state_provider: S, max_traces: usize) -> Self {
+        Self { state_provider: Arc::new(state_provider), max_traces, executions: None }
+    }
+
+    /// Bound concurrent trace re-executions with `limiter`.
+    #[must_use]
+    pub fn with_execution_limiter(mut self, limiter: ExecutionLimiter) -> Self {
+        self.executions = Some(limiter);
+        self
+    }
+}
+
+#[jsonrpsee::core::async_trait]
+impl<S: StateProvider + 'static> DebugApiServer for DebugApiImpl<S> {
+    async fn trace_block_by_number(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> RpcResult<Vec<RpcTransactionTrace>> {
+        // Reject oversized blocks before re-executing them when the header is
+        // indexed; providers serving recorded traces are checked afterwards.
+        let block = match self.state_provider.block_by_number(block).await? {
+            Some(rpc_block) => {
+                let tx_count = match &rpc_block.transactions {
+                    BlockTransactions::Hashes(hashes) => hashes.len(),
+                    BlockTransactions::Full(txs) => txs.len(),
+                };
+                self.check_trace_count(tx_count)?;
+                BlockNumberOrTag::Number(rpc_block.number)
+            }
+            None => block,
+        };
+
+        let _permit = match &self.executions {
+            Some(limiter) => Some(limiter.acquire().await?),
+            None => None,
+        };
+        let traces = self.state_provider.trace_block(block).await?;
+        self.check_trace_count(traces.len())?;
+        Ok(traces)
+    }
+}
+
+impl<S: StateProvider> DebugApiImpl<S> {
+    fn check_trace_count(&self, tx_count: usize) -> Result<(), RpcError> {
+        if tx_count > self.max_traces {
+            return Err(RpcError::LimitExceeded(format!(
+                "block has {tx_count} transactions, at most {} may be traced",
+                self.max_traces
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, B256, Bytes, U64, U256};
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::{
+        error::codes,
+        types::{RpcBlock, RpcCallFrame, RpcTransaction, RpcTransactionReceipt},
+    };
+
+    /// Provider serving one block whose single transaction calls a contract.
+    struct TracingProvider {
+        tx_count: usize,
+    }
+
+    fn call_frame(from: Address, to: Address, calls: Vec<RpcCallFrame>) -> RpcCallFrame {
+        RpcCallFrame {
+            call_type: "CALL".to_string(),
+            from,
+            to: Some(to),
+            value: U256::ZERO,
+            gas: U64::from(100_000),
+            gas_used: U64::from(30_000),
+            input: Bytes::new(),
+            output: Bytes::new(),
+            error: None,
+            calls,
+        }
+    }
+
+    #[async_trait]
+    impl StateProvider for TracingProvider {
+        async fn balance(
+            &self,
+            _address: Address,
+            _block: Option<BlockNumberOrTag>,
+        ) -> Result<U256, RpcError> {
+            Ok(U256::ZERO)
+        }
+
+        async fn nonce(
+            &self,
+            _address: Address,
+            _block: Option<BlockNumberOrTag>,
+        ) -> Result<u64, RpcError> {
+            Ok(0)
+        }
+
+        async fn code(
+            &self,
+            _address: Address,
+            _block: Option<BlockNumberOrTag>,
+        ) -> Result<Bytes, RpcError> {
+            Ok(Bytes::new())
+        }
+
+        async fn storage(
+            &self,
+            _address: Address,
+            _slot: U256,
+            _block: Option<BlockNumberOrTag>,
+        ) -> Result<U256, RpcError> {
+            Ok(U256::ZERO)
+        }
+
+        async fn block_by_number(
+            &self,
+            block: BlockNumberOrTag,
+        ) -> Result<Option<RpcBlock>, RpcError> {
+            let BlockNumberOrTag::Number(number) = block else {
+                return Ok(None);
+            };
+            Ok(Some(RpcBlock {
+                number,
+                transactions: BlockTransactions::Hashes(vec![B256::ZERO; self.tx_count]),
+                ..Default::default()
+            }))
+        }
+
+        async fn block_by_hash(&self, _hash: B256) -> Result<Option<RpcBlock>, RpcError> {
+            Ok(None)
+        }
+
+        async fn transaction_by_hash(
+            &self,
+            _hash: B256,
+        ) -> Result<Option<RpcTransaction>, RpcError> {
+            Ok(None)
+        }
+
+        async fn receipt_by_hash(
+            &self,
+            _hash: B256,
+        ) -> Result<Option<RpcTransactionReceipt>, RpcError> {
+            Ok(None)
+        }
+
+        async fn block_number(&self) -> Result<u64, RpcError> {
+            Ok(1)
+        }
+
+        async fn trace_block(
+            &self,
+            block: BlockNumberOrTag,
+        ) -> Result<Vec<RpcTransactionTrace>, RpcError> {
+            if !matches!(block, BlockNumberOrTag::Number(_)) {
+                return Err(RpcError::BlockNotFound);
+            }
+            let sender = Address::repeat_byte(0x01);
+            let contract = Address::repeat_byte(0xaa);
+            let callee = Address::repeat_byte(0xbb);
+            Ok(vec![RpcTransactionTrace {
+                tx_hash: B256::repeat_byte(0x11),
+                result: call_frame(sender, contract, vec![call_frame(contract, callee, vec![])]),
+            }])
+        }
+    }
+
+    #[tokio::test]
+    async fn trace_includes_nested_call_frame() {
+        let api = DebugApiImpl::new(TracingProvider { tx_count: 1 }, 10);
+
+        let traces =
+            DebugApiServer::trace_block_by_number(&api, BlockNumberOrTag::Number(U64::from(1)))
+                .await
+                .unwrap();
+
+        assert_eq!(traces.len(), 1);
+        let root = &traces[0].result;
+        assert_eq!(root.to, Some(Address::repeat_byte(0xaa)));
+        assert_eq!(root.calls.len(), 1);
+        assert_eq!(root.calls[0].call_type, "CALL");
+        assert_eq!(root.calls[0].to, Some(Address::repeat_byte(0xbb)));
+
+        let json = serde_json::to_value(&traces[0]).unwrap();
+        assert_eq!(json["result"]["calls"][0]["type"], "CALL");
+        assert!(json["txHash"].is_string());
+    }
+
+    #[tokio::test]
+    async fn trace_rejects_blocks_over_limit() {
+        let api = DebugApiImpl::new(TracingProvider { tx_count: 3 }, 2);
+
+        let err =
+            DebugApiServer::trace_block_by_number(&api, BlockNumberOrTag::Number(U64::from(1)))
+                .await
+                .unwrap_err();
+
+        assert_eq!(err.code(), codes::LIMIT_EXCEEDED);
+    }
+
+    #[tokio::test]
+    async fn trace_unknown_block_is_not_found() {
+        let api = DebugApiImpl::new(TracingProvider { tx_count: 1 }, 10);
+
+        let err = DebugApiServer::trace_block_by_number(&api, BlockNumberOrTag::Latest)
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code(), codes::RESOURCE_NOT_FOUND);
+    }
+}
@@ -67,9 +67,17 @@ pub enum RpcError {
     #[error("internal error: {0}")]
     Internal(String),
 
+    /// Request exceeds a configured limit.
+    #[error("limit exceeded: {0}")]
+    LimitExceeded(String),
+
     /// Method not implemented.
     #[error("method not implemented")]
     NotImplemented,
+
+    /// No execution slot became free in time.
+    #[error("server busy")]
+    ServerBusy,
 }
 
 impl From<RpcError> for ErrorObjectOwned {
@@ -83,7 +91,9 @@ impl From<RpcError> for ErrorObjectOwned {
             RpcError::ExecutionFailed(_) => (codes::EXECUTION_ERROR, err.to_string()),
             RpcError::StateError(_) => (codes::INTERNAL_ERROR, err.to_string()),
             RpcError::Internal(_) => (codes::INTERNAL_ERROR, err.to_string()),
+            RpcError::LimitExceeded(_) => (codes::LIMIT_EXCEEDED, err.to_string()),
             RpcError::NotImplemented => (codes::METHOD_NOT_SUPPORTED, err.to_string()),
+            RpcError::ServerBusy => (codes::RESOURCE_UNAVAILABLE, err.to_string()),
         };
         ErrorObjectOwned::owned(code, message, None::<()>)
     }
@@ -167,6 +177,13 @@ mod tests {
         assert_eq!(err.to_string(), "method not implemented");
     }
 
+    #[test]
+    fn rpc_error_to_error_object_server_busy() {
+        let obj: ErrorObjectOwned = RpcError::ServerBusy.into();
+        assert_eq!(obj.code(), codes::RESOURCE_UNAVAILABLE);
+        assert_eq!(obj.message(), "server busy");
+    }
+
     #[test]
     fn rpc_error_to_error_object_block_not_found() {
         let err = RpcError::BlockNotFound;
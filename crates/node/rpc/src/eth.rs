@@ -2,16 +2,25 @@
 
 use std::sync::Arc;
 
+use alloy_consensus::{Transaction as _, TxEnvelope};
+use alloy_eips::eip2718::Decodable2718 as _;
 use alloy_primitives::{Address, B256, Bytes, U64, U256};
+use futures::future::BoxFuture;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
-use tokio::sync::RwLock;
+use monmouth_domain::Tx;
+use monmouth_executor::TransactionClassifier;
+use monmouth_txpool::{AdmissionStage, PoolConfig, recover_sender_from_envelope};
+use tokio::sync::{OwnedSemaphorePermit, RwLock};
+use tracing::debug;
 
 use crate::{
     error::RpcError,
+    execution_limit::ExecutionLimiter,
+    state::NodeState,
     state_provider::StateProvider,
     types::{
         BlockNumberOrTag, CallRequest, RpcBlock, RpcLog, RpcLogFilter, RpcTransaction,
-        RpcTransactionReceipt,
+        RpcTransactionReceipt, SyncStatus,
     },
 };
 
@@ -125,9 +134,9 @@ pub trait EthApi {
     #[method(name = "protocolVersion")]
     async fn protocol_version(&self) -> RpcResult<String>;
 
-    /// Returns syncing status.
+    /// Returns `false` when caught up, or the backfill progress while syncing.
     #[method(name = "syncing")]
-    async fn syncing(&self) -> RpcResult<bool>;
+    async fn syncing(&self) -> RpcResult<SyncStatus>;
 
     /// Returns logs matching the given filter.
     #[method(name = "getLogs")]
@@ -179,16 +188,40 @@ pub struct FeeHistory {
 
 /// Transaction submission callback type.
 ///
-/// Called when a raw transaction is submitted via `eth_sendRawTransaction`.
-/// Returns true if the transaction was accepted, false otherwise.
-pub type TxSubmitCallback = Arc<dyn Fn(Bytes) -> bool + Send + Sync>;
+/// Called with the raw envelope and recovered sender of each transaction that
+/// passes `eth_sendRawTransaction`'s decoding checks. Resolves to `Err` with the
+/// reason if the mempool refuses the transaction, which is returned to the
+/// caller as an invalid transaction error.
+pub type TxSubmitCallback =
+    Arc<dyn Fn(Bytes, Address) -> BoxFuture<'static, Result<(), String>> + Send + Sync>;
+
+/// Decode a raw EIP-2718 envelope and run the stateless checks that reject it
+/// outright: trailing bytes, a chain ID other than `chain_id`, or a signature
+/// that does not recover. Returns the envelope and its recovered sender.
+fn decode_raw_transaction(data: &Bytes, chain_id: u64) -> Result<(TxEnvelope, Address), RpcError> {
+    let mut buf = data.as_ref();
+    let envelope = TxEnvelope::decode_2718(&mut buf)
+        .map_err(|e| RpcError::InvalidTransaction(format!("invalid envelope: {e}")))?;
+    if !buf.is_empty() {
+        return Err(RpcError::InvalidTransaction("trailing bytes after envelope".into()));
+    }
+    AdmissionStage::ChainId
+        .check(&envelope, chain_id, &PoolConfig::default())
+        .map_err(|e| RpcError::InvalidTransaction(e.to_string()))?;
+    let sender = recover_sender_from_envelope(&envelope)
+        .map_err(|e| RpcError::InvalidTransaction(e.to_string()))?;
+    Ok((envelope, sender))
+}
 
 /// Ethereum API implementation with state provider.
 pub struct EthApiImpl<S: StateProvider> {
     chain_id: u64,
     block_height: Arc<std::sync::atomic::AtomicU64>,
     tx_submit: Option<TxSubmitCallback>,
+    classifier: TransactionClassifier,
     state_provider: Arc<RwLock<S>>,
+    node_state: Option<Arc<NodeState>>,
+    executions: Option<ExecutionLimiter>,
 }
 
 impl<S: StateProvider> std::fmt::Debug for EthApiImpl<S> {
@@ -197,6 +230,7 @@ impl<S: StateProvider> std::fmt::Debug for EthApiImpl<S> {
             .field("chain_id", &self.chain_id)
             .field("block_height", &self.block_height)
             .field("tx_submit", &self.tx_submit.is_some())
+            .field("node_state", &self.node_state.is_some())
             .finish()
     }
 }
@@ -208,7 +242,10 @@ impl<S: StateProvider + 'static> EthApiImpl<S> {
             chain_id,
             block_height: Arc::new(std::sync::atomic::AtomicU64::new(0)),
             tx_submit: None,
+            classifier: TransactionClassifier::enabled(),
             state_provider: Arc::new(RwLock::new(state_provider)),
+            node_state: None,
+            executions: None,
         }
     }
 
@@ -218,10 +255,34 @@ impl<S: StateProvider + 'static> EthApiImpl<S> {
             chain_id,
             block_height: Arc::new(std::sync::atomic::AtomicU64::new(0)),
             tx_submit: Some(tx_submit),
+            classifier: TransactionClassifier::enabled(),
             state_provider: Arc::new(RwLock::new(state_provider)),
+            node_state: None,
+            executions: None,
         }
     }
 
+    /// Bound concurrent `eth_call` and `eth_estimateGas` executions with `limiter`.
+    #[must_use]
+    pub fn with_execution_limiter(mut self, limiter: ExecutionLimiter) -> Self {
+        self.executions = Some(limiter);
+        self
+    }
+
+    /// Classify transactions submitted through `eth_sendRawTransaction` with `classifier`.
+    #[must_use]
+    pub fn with_classifier(mut self, classifier: TransactionClassifier) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
+    /// Report sync progress from the given node state in `eth_syncing`.
+    #[must_use]
+    pub fn with_node_state(mut self, node_state: Arc<NodeState>) -> Self {
+        self.node_state = Some(node_state);
+        self
+    }
+
     /// Get a handle to update the block height.
     pub fn block_height_handle(&self) -> Arc<std::sync::atomic::AtomicU64> {
         self.block_height.clone()
@@ -231,6 +292,13 @@ impl<S: StateProvider + 'static> EthApiImpl<S> {
     pub fn set_block_height(&self, height: u64) {
         self.block_height.store(height, std::sync::atomic::Ordering::Relaxed);
     }
+
+    async fn acquire_execution(&self) -> Result<Option<OwnedSemaphorePermit>, RpcError> {
+        match &self.executions {
+            Some(limiter) => limiter.acquire().await.map(Some),
+            None => Ok(None),
+        }
+    }
 }
 
 #[jsonrpsee::core::async_trait]
@@ -289,12 +357,20 @@ impl<S: StateProvider + 'static> EthApiServer for EthApiImpl<S> {
     }
 
     async fn send_raw_transaction(&self, data: Bytes) -> RpcResult<B256> {
-        let tx_hash = alloy_primitives::keccak256(&data);
-
-        if let Some(ref submit) = self.tx_submit
-            && !submit(data)
-        {
-            return Err(RpcError::InvalidTransaction("transaction rejected".into()).into());
+        let (envelope, sender) = decode_raw_transaction(&data, self.chain_id)?;
+        let tx = Tx::new(data);
+        let tx_hash = tx.id().0;
+
+        let classification = self.classifier.classify(envelope.to(), envelope.input());
+        debug!(
+            %tx_hash,
+            classification = ?classification.classification,
+            confidence = classification.confidence,
+            "Classified raw transaction"
+        );
+
+        if let Some(ref submit) = self.tx_submit {
+            submit(tx.bytes, sender).await.map_err(RpcError::InvalidTransaction)?;
         }
 
         Ok(tx_hash)
@@ -305,6 +381,7 @@ impl<S: StateProvider + 'static> EthApiServer for EthApiImpl<S> {
         request: CallRequest,
         block: Option<BlockNumberOrTag>,
     ) -> RpcResult<Bytes> {
+        let _permit = self.acquire_execution().await?;
         let provider = self.state_provider.read().await;
         provider.call(request, block).await.map_err(Into::into)
     }
@@ -314,6 +391,7 @@ impl<S: StateProvider + 'static> EthApiServer for EthApiImpl<S> {
         request: CallRequest,
         block: Option<BlockNumberOrTag>,
     ) -> RpcResult<U64> {
+        let _permit = self.acquire_execution().await?;
         let provider = self.state_provider.read().await;
         let gas = provider.estimate_gas(request, block).await?;
         Ok(U64::from(gas))
@@ -385,8 +463,11 @@ impl<S: StateProvider + 'static> EthApiServer for EthApiImpl<S> {
         Ok("0x44".to_string())
     }
 
-    async fn syncing(&self) -> RpcResult<bool> {
-        Ok(false)
+    async fn syncing(&self) -> RpcResult<SyncStatus> {
+        Ok(self
+            .node_state
+            .as_ref()
+            .map_or(SyncStatus::NotSyncing(false), |state| state.sync_status()))
     }
 
     async fn get_logs(&self, filter: RpcLogFilter) -> RpcResult<Vec<RpcLog>> {
@@ -465,6 +546,9 @@ impl Web3ApiServer for Web3ApiImpl {
 
 #[cfg(test)]
 mod tests {
+    use futures::FutureExt as _;
+    use monmouth_domain::evm::Evm;
+
     use super::*;
     use crate::state_provider::NoopStateProvider;
 
@@ -489,6 +573,24 @@ mod tests {
         assert_eq!(version, "1337");
     }
 
+    #[tokio::test]
+    async fn eth_syncing_reports_backfill_gap() {
+        let state = Arc::new(NodeState::new(1, 0));
+        let api = EthApiImpl::new(1, NoopStateProvider).with_node_state(Arc::clone(&state));
+
+        state.set_syncing(10, 50);
+        let SyncStatus::Syncing(info) = EthApiServer::syncing(&api).await.unwrap() else {
+            panic!("lagging node should report progress");
+        };
+        assert_eq!(info.starting_block, U64::from(10));
+        assert_eq!(info.current_block, U64::from(10));
+        assert_eq!(info.highest_block, U64::from(50));
+
+        state.set_synced();
+        let json = serde_json::to_string(&EthApiServer::syncing(&api).await.unwrap()).unwrap();
+        assert_eq!(json, "false");
+    }
+
     #[tokio::test]
     async fn eth_block_number() {
         let api = EthApiImpl::new(1, NoopStateProvider);
@@ -504,21 +606,201 @@ mod tests {
         assert_eq!(hash, alloy_primitives::keccak256(b"hello"));
     }
 
-    #[tokio::test]
-    async fn eth_send_raw_transaction() {
-        let submitted = Arc::new(std::sync::atomic::AtomicBool::new(false));
-        let submitted_clone = submitted.clone();
-        let callback: TxSubmitCallback = Arc::new(move |_| {
-            submitted_clone.store(true, std::sync::atomic::Ordering::Relaxed);
-            true
+    fn signing_key() -> k256::ecdsa::SigningKey {
+        let mut secret = [0u8; 32];
+        secret[31] = 1;
+        k256::ecdsa::SigningKey::from_bytes((&secret).into()).expect("valid key")
+    }
+
+    fn recording_callback() -> (TxSubmitCallback, Arc<parking_lot::Mutex<Vec<Bytes>>>) {
+        let submitted = Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&submitted);
+        let callback: TxSubmitCallback = Arc::new(move |data, _| {
+            recorded.lock().push(data);
+            async { Ok(()) }.boxed()
         });
+        (callback, submitted)
+    }
+
+    #[tokio::test]
+    async fn eth_send_raw_transaction_submits_transfer() {
+        let (callback, submitted) = recording_callback();
+        let api = EthApiImpl::with_tx_submit(1, NoopStateProvider, callback);
+        let tx = Evm::sign_eip1559_transfer(
+            &signing_key(),
+            1,
+            Address::repeat_byte(0x22),
+            U256::from(10),
+            0,
+            21_000,
+        );
+
+        let hash = EthApiServer::send_raw_transaction(&api, tx.bytes.clone()).await.unwrap();
 
+        assert_eq!(hash, tx.id().0);
+        assert_eq!(*submitted.lock(), vec![tx.bytes]);
+    }
+
+    #[tokio::test]
+    async fn eth_send_raw_transaction_rejects_wrong_chain_id() {
+        let (callback, submitted) = recording_callback();
         let api = EthApiImpl::with_tx_submit(1, NoopStateProvider, callback);
-        let tx_data = Bytes::from_static(b"fake_tx_data");
-        let result = EthApiServer::send_raw_transaction(&api, tx_data.clone()).await;
+        let tx = Evm::sign_eip1559_transfer(
+            &signing_key(),
+            2,
+            Address::repeat_byte(0x22),
+            U256::from(10),
+            0,
+            21_000,
+        );
+
+        let err = EthApiServer::send_raw_transaction(&api, tx.bytes).await.unwrap_err();
+
+        assert_eq!(err.code(), crate::error::codes::INVALID_PARAMS);
+        assert!(err.message().contains("invalid chain id"));
+        assert!(submitted.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn eth_send_raw_transaction_rejects_garbage() {
+        let (callback, submitted) = recording_callback();
+        let api = EthApiImpl::with_tx_submit(1, NoopStateProvider, callback);
+
+        let err = EthApiServer::send_raw_transaction(&api, Bytes::from_static(b"fake_tx_data"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.code(), crate::error::codes::INVALID_PARAMS);
+        assert!(err.message().contains("invalid envelope"));
+        assert!(submitted.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn eth_send_raw_transaction_reports_mempool_rejection() {
+        let callback: TxSubmitCallback = Arc::new(|_, _| {
+            async { Err("insufficient funds: cost 21010 exceeds balance 0".to_string()) }.boxed()
+        });
+        let api = EthApiImpl::with_tx_submit(1, NoopStateProvider, callback);
+        let tx = Evm::sign_eip1559_transfer(
+            &signing_key(),
+            1,
+            Address::repeat_byte(0x22),
+            U256::from(10),
+            0,
+            21_000,
+        );
+
+        let err = EthApiServer::send_raw_transaction(&api, tx.bytes).await.unwrap_err();
+
+        assert_eq!(err.code(), crate::error::codes::INVALID_PARAMS);
+        assert!(err.message().contains("insufficient funds"));
+    }
+
+    /// Provider whose `eth_call` sleeps while tracking how many calls overlap.
+    #[derive(Debug, Default)]
+    struct SlowCallProvider {
+        in_flight: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl StateProvider for SlowCallProvider {
+        async fn balance(
+            &self,
+            address: Address,
+            block: Option<BlockNumberOrTag>,
+        ) -> Result<U256, RpcError> {
+            NoopStateProvider.balance(address, block).await
+        }
+
+        async fn nonce(
+            &self,
+            address: Address,
+            block: Option<BlockNumberOrTag>,
+        ) -> Result<u64, RpcError> {
+            NoopStateProvider.nonce(address, block).await
+        }
+
+        async fn code(
+            &self,
+            address: Address,
+            block: Option<BlockNumberOrTag>,
+        ) -> Result<Bytes, RpcError> {
+            NoopStateProvider.code(address, block).await
+        }
+
+        async fn storage(
+            &self,
+            address: Address,
+            slot: U256,
+            block: Option<BlockNumberOrTag>,
+        ) -> Result<U256, RpcError> {
+            NoopStateProvider.storage(address, slot, block).await
+        }
+
+        async fn block_by_number(
+            &self,
+            block: BlockNumberOrTag,
+        ) -> Result<Option<RpcBlock>, RpcError> {
+            NoopStateProvider.block_by_number(block).await
+        }
+
+        async fn block_by_hash(&self, hash: B256) -> Result<Option<RpcBlock>, RpcError> {
+            NoopStateProvider.block_by_hash(hash).await
+        }
+
+        async fn transaction_by_hash(
+            &self,
+            hash: B256,
+        ) -> Result<Option<RpcTransaction>, RpcError> {
+            NoopStateProvider.transaction_by_hash(hash).await
+        }
+
+        async fn receipt_by_hash(
+            &self,
+            hash: B256,
+        ) -> Result<Option<RpcTransactionReceipt>, RpcError> {
+            NoopStateProvider.receipt_by_hash(hash).await
+        }
+
+        async fn block_number(&self) -> Result<u64, RpcError> {
+            Ok(0)
+        }
+
+        async fn call(
+            &self,
+            _request: CallRequest,
+            _block: Option<BlockNumberOrTag>,
+        ) -> Result<Bytes, RpcError> {
+            use std::sync::atomic::Ordering;
+
+            let running = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(Bytes::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrent_eth_calls_respect_execution_limit() {
+        let limiter = ExecutionLimiter::new(2, std::time::Duration::from_secs(5));
+        let api = Arc::new(
+            EthApiImpl::new(1, SlowCallProvider::default()).with_execution_limiter(limiter),
+        );
+
+        let mut calls = tokio::task::JoinSet::new();
+        for _ in 0..8 {
+            let api = Arc::clone(&api);
+            calls.spawn(
+                async move { EthApiServer::call(&*api, CallRequest::default(), None).await },
+            );
+        }
+        while let Some(result) = calls.join_next().await {
+            assert!(result.unwrap().is_ok());
+        }
 
-        assert!(result.is_ok());
-        assert!(submitted.load(std::sync::atomic::Ordering::Relaxed));
-        assert_eq!(result.unwrap(), alloy_primitives::keccak256(&tx_data));
+        let peak = api.state_provider.read().await.peak.load(std::sync::atomic::Ordering::SeqCst);
+        assert_eq!(peak, 2);
     }
 }
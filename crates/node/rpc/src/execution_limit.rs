@@ -0,0 +1,69 @@
+//! Concurrency bound for RPC methods that run the EVM.
+
+use std::{sync::Arc, time::Duration};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::RpcError;
+
+/// Default number of read-only EVM executions allowed at once.
+pub const DEFAULT_MAX_CONCURRENT_EXECUTIONS: usize = 16;
+
+/// Default time a read-only execution may wait for a free slot.
+pub const DEFAULT_EXECUTION_QUEUE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Semaphore bounding concurrent `eth_call`, `eth_estimateGas`, and `debug_trace*`
+/// executions.
+///
+/// Requests over the limit wait up to the queue timeout for a slot and then
+/// fail with a "server busy" error. Clones share the same slots.
+#[derive(Clone, Debug)]
+pub struct ExecutionLimiter {
+    slots: Arc<Semaphore>,
+    queue_timeout: Duration,
+}
+
+impl ExecutionLimiter {
+    /// Allow `max_concurrent` executions, queuing others for up to `queue_timeout`.
+    pub fn new(max_concurrent: usize, queue_timeout: Duration) -> Self {
+        Self { slots: Arc::new(Semaphore::new(max_concurrent)), queue_timeout }
+    }
+
+    /// Number of free execution slots.
+    pub fn available(&self) -> usize {
+        self.slots.available_permits()
+    }
+
+    /// Take an execution slot, held until the returned permit is dropped.
+    pub async fn acquire(&self) -> Result<OwnedSemaphorePermit, RpcError> {
+        match tokio::time::timeout(self.queue_timeout, Arc::clone(&self.slots).acquire_owned())
+            .await
+        {
+            Ok(Ok(permit)) => Ok(permit),
+            Ok(Err(_)) => Err(RpcError::Internal("execution limiter closed".to_string())),
+            Err(_) => Err(RpcError::ServerBusy),
+        }
+    }
+}
+
+impl Default for ExecutionLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CONCURRENT_EXECUTIONS, DEFAULT_EXECUTION_QUEUE_TIMEOUT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn full_limiter_reports_busy_after_timeout() {
+        let limiter = ExecutionLimiter::new(1, Duration::from_millis(10));
+        let permit = limiter.acquire().await.unwrap();
+        assert_eq!(limiter.available(), 0);
+        assert!(matches!(limiter.acquire().await, Err(RpcError::ServerBusy)));
+
+        drop(permit);
+        assert!(limiter.acquire().await.is_ok());
+    }
+}
@@ -11,11 +11,12 @@ use monmouth_indexer::{BlockIndex, IndexedBlock, IndexedReceipt, IndexedTransact
 use monmouth_traits::{StateDbError, StateDbRead};
 
 use crate::{
+    block_traces::BlockTraces,
     error::RpcError,
     state_provider::StateProvider,
     types::{
         BlockNumberOrTag, BlockTag, BlockTransactions, RpcBlock, RpcLog, RpcLogFilter,
-        RpcTransaction, RpcTransactionReceipt,
+        RpcTransaction, RpcTransactionReceipt, RpcTransactionTrace,
     },
 };
 
@@ -23,24 +24,37 @@ use crate::{
 ///
 /// Uses [`BlockIndex`] for block, transaction, and receipt lookups, and
 /// delegates account state queries (balance, nonce, code, storage) to
-/// a generic state database implementation.
+/// a generic state database implementation. Block traces are served from a
+/// [`BlockTraces`] store filled at finalization, if one is attached.
 #[derive(Debug)]
 pub struct IndexedStateProvider<S> {
     index: Arc<BlockIndex>,
     state: S,
+    traces: Option<BlockTraces>,
 }
 
 impl<S> IndexedStateProvider<S> {
     /// Creates a new indexed state provider.
     #[must_use]
     pub const fn new(index: Arc<BlockIndex>, state: S) -> Self {
-        Self { index, state }
+        Self { index, state, traces: None }
+    }
+
+    /// Serve `debug_traceBlockByNumber` from `traces`.
+    #[must_use]
+    pub fn with_block_traces(mut self, traces: BlockTraces) -> Self {
+        self.traces = Some(traces);
+        self
     }
 }
 
 impl<S: Clone> Clone for IndexedStateProvider<S> {
     fn clone(&self) -> Self {
-        Self { index: Arc::clone(&self.index), state: self.state.clone() }
+        Self {
+            index: Arc::clone(&self.index),
+            state: self.state.clone(),
+            traces: self.traces.clone(),
+        }
     }
 }
 
@@ -147,6 +161,17 @@ impl<S: StateDbRead + Send + Sync + 'static> StateProvider for IndexedStateProvi
             .collect();
         Ok(logs)
     }
+
+    async fn trace_block(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> Result<Vec<RpcTransactionTrace>, RpcError> {
+        let Some(traces) = &self.traces else {
+            return Err(RpcError::NotImplemented);
+        };
+        let number = self.resolve_block_number(&block)?;
+        traces.get(number).ok_or(RpcError::BlockNotFound)
+    }
 }
 
 impl<S> IndexedStateProvider<S> {
@@ -172,7 +197,7 @@ fn state_error_to_rpc(err: StateDbError) -> RpcError {
     match err {
         StateDbError::AccountNotFound(addr) => RpcError::AccountNotFound(addr.to_string()),
         StateDbError::CodeNotFound(hash) => RpcError::StateError(format!("code not found: {hash}")),
-        StateDbError::Storage(msg) => RpcError::StateError(msg),
+        StateDbError::Storage(msg) | StateDbError::Io(msg) => RpcError::StateError(msg),
         StateDbError::LockPoisoned => RpcError::Internal("lock poisoned".to_string()),
         StateDbError::RootComputation(msg) => RpcError::StateError(msg),
     }
@@ -452,4 +477,27 @@ mod tests {
             provider.block_by_number(BlockNumberOrTag::Tag(BlockTag::Earliest)).await.unwrap();
         assert!(block.is_none());
     }
+
+    #[tokio::test]
+    async fn test_trace_block_serves_recorded_traces() {
+        let traces = BlockTraces::default();
+        let trace = RpcTransactionTrace { tx_hash: B256::repeat_byte(2), ..Default::default() };
+        traces.insert(3, vec![trace.clone()]);
+        let provider = IndexedStateProvider::new(Arc::new(BlockIndex::new()), MockState)
+            .with_block_traces(traces);
+
+        let served = provider.trace_block(BlockNumberOrTag::Number(U64::from(3))).await.unwrap();
+        assert_eq!(served, vec![trace]);
+
+        let missing = provider.trace_block(BlockNumberOrTag::Number(U64::from(4))).await;
+        assert!(matches!(missing, Err(RpcError::BlockNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_trace_block_without_store_is_not_implemented() {
+        let provider = IndexedStateProvider::new(Arc::new(BlockIndex::new()), MockState);
+
+        let result = provider.trace_block(BlockNumberOrTag::Latest).await;
+        assert!(matches!(result, Err(RpcError::NotImplemented)));
+    }
 }
@@ -5,18 +5,37 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
+mod block_traces;
+pub use block_traces::{BlockTraces, DEFAULT_BLOCK_TRACE_CAPACITY};
+
+mod call_cache;
+pub use call_cache::{CachingStateProvider, CallCache, DEFAULT_CALL_CACHE_CAPACITY};
+
 mod config;
 pub use config::{CorsConfig, RateLimitConfig, RpcServerConfig};
 
+mod debug;
+pub use debug::{DEFAULT_MAX_TRACES_PER_REQUEST, DebugApiImpl, DebugApiServer};
+
 mod error;
 pub use error::{RpcError, codes as error_codes};
 
+mod execution_limit;
+pub use execution_limit::{
+    DEFAULT_EXECUTION_QUEUE_TIMEOUT, DEFAULT_MAX_CONCURRENT_EXECUTIONS, ExecutionLimiter,
+};
+
 mod eth;
 pub use eth::{
     EthApiImpl, EthApiServer, FeeHistory, NetApiImpl, NetApiServer, TxSubmitCallback, Web3ApiImpl,
     Web3ApiServer,
 };
 
+mod method_filter;
+pub use method_filter::{DEFAULT_ALLOWED_METHODS, MethodFilter};
+
+mod params;
+
 mod monmouth;
 pub use monmouth::{MonmouthApiImpl, MonmouthApiServer};
 
@@ -32,8 +51,15 @@ pub use state_provider::{NoopStateProvider, StateProvider};
 mod indexed_provider;
 pub use indexed_provider::IndexedStateProvider;
 
+mod txpool;
+pub use txpool::{
+    PoolContent, PooledTransaction, TxPoolApiImpl, TxPoolApiServer, TxPoolContent, TxPoolSection,
+    TxPoolSource, TxPoolStatus,
+};
+
 mod types;
 pub use types::{
-    AddressFilter, BlockNumberOrTag, BlockTag, BlockTransactions, CallRequest, RpcBlock, RpcLog,
-    RpcLogFilter, RpcTransaction, RpcTransactionReceipt, SyncInfo, SyncStatus, TopicFilter,
+    AddressFilter, BlockNumberOrTag, BlockTag, BlockTransactions, CallRequest, RpcBlock,
+    RpcCallFrame, RpcLog, RpcLogFilter, RpcTransaction, RpcTransactionReceipt, RpcTransactionTrace,
+    SyncInfo, SyncStatus, TopicFilter,
 };
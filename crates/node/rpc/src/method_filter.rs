@@ -0,0 +1,111 @@
+//! JSON-RPC method allowlist and denylist.
+
+use jsonrpsee::RpcModule;
+use tracing::debug;
+
+/// Methods exposed when no allowlist is configured: the standard `eth_*`,
+/// `net_*`, and `web3_*` namespaces plus the node's own `monmouth_*` namespace.
+pub const DEFAULT_ALLOWED_METHODS: &[&str] = &["eth_*", "net_*", "web3_*", "monmouth_*"];
+
+/// Restricts which JSON-RPC methods a server exposes.
+///
+/// Entries are exact method names (`eth_call`) or namespace wildcards
+/// (`debug_*`). A method is exposed when it matches the allowlist and does not
+/// match the denylist. Filtered methods are removed from the module before the
+/// server starts, so callers receive the standard "method not found" error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MethodFilter {
+    allowed: Vec<String>,
+    denied: Vec<String>,
+}
+
+impl MethodFilter {
+    /// Create a filter from an optional allowlist and a denylist.
+    ///
+    /// `None` for `allowed` uses [`DEFAULT_ALLOWED_METHODS`].
+    pub fn new(allowed: Option<Vec<String>>, denied: Vec<String>) -> Self {
+        let allowed = allowed
+            .unwrap_or_else(|| DEFAULT_ALLOWED_METHODS.iter().map(|m| (*m).to_string()).collect());
+        Self { allowed, denied }
+    }
+
+    /// Returns `true` if `method` may be called.
+    pub fn is_allowed(&self, method: &str) -> bool {
+        let matches = |pattern: &String| match pattern.strip_suffix('*') {
+            Some(prefix) => method.starts_with(prefix),
+            None => method == pattern,
+        };
+        self.allowed.iter().any(matches) && !self.denied.iter().any(matches)
+    }
+
+    /// Remove every method `module` registers that this filter rejects.
+    pub fn apply<Context>(&self, module: &mut RpcModule<Context>) {
+        let rejected: Vec<&'static str> =
+            module.method_names().filter(|name| !self.is_allowed(name)).collect();
+        for name in rejected {
+            debug!(method = name, "RPC method disabled by filter");
+            module.remove_method(name);
+        }
+    }
+}
+
+impl Default for MethodFilter {
+    fn default() -> Self {
+        Self::new(None, Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonrpsee::{
+        core::{EmptyServerParams, server::MethodsError},
+        types::error::METHOD_NOT_FOUND_CODE,
+    };
+
+    use super::*;
+    use crate::{NetApiImpl, NetApiServer, Web3ApiImpl, Web3ApiServer};
+
+    #[test]
+    fn default_allows_standard_namespaces() {
+        let filter = MethodFilter::default();
+        assert!(filter.is_allowed("eth_call"));
+        assert!(filter.is_allowed("net_version"));
+        assert!(filter.is_allowed("web3_clientVersion"));
+        assert!(filter.is_allowed("monmouth_nodeStatus"));
+        assert!(!filter.is_allowed("debug_traceBlockByNumber"));
+        assert!(!filter.is_allowed("txpool_content"));
+        assert!(!filter.is_allowed("admin_peers"));
+    }
+
+    #[test]
+    fn denylist_overrides_allowlist() {
+        let filter = MethodFilter::new(
+            Some(vec!["eth_*".to_string()]),
+            vec!["eth_sendRawTransaction".to_string()],
+        );
+        assert!(filter.is_allowed("eth_call"));
+        assert!(!filter.is_allowed("eth_sendRawTransaction"));
+        assert!(!filter.is_allowed("net_version"));
+    }
+
+    #[tokio::test]
+    async fn denied_method_is_not_found() {
+        let mut module = RpcModule::new(());
+        module.merge(NetApiImpl::new(7).into_rpc()).unwrap();
+        module.merge(Web3ApiImpl::new().into_rpc()).unwrap();
+
+        MethodFilter::new(None, vec!["web3_*".to_string()]).apply(&mut module);
+
+        let version: String = module.call("net_version", EmptyServerParams::new()).await.unwrap();
+        assert_eq!(version, "7");
+
+        let err = module
+            .call::<_, String>("web3_clientVersion", EmptyServerParams::new())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, MethodsError::JsonRpc(ref e) if e.code() == METHOD_NOT_FOUND_CODE),
+            "unexpected error: {err:?}"
+        );
+    }
+}
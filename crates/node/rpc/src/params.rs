@@ -0,0 +1,169 @@
+//! Strict JSON-RPC parameter checking.
+
+use std::{future::Future, pin::Pin};
+
+use jsonrpsee::{
+    MethodResponse,
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObjectOwned, Request},
+};
+use serde_json::Value;
+
+use crate::error::codes;
+
+/// Maximum number of parameters each served method accepts, optional ones included.
+const METHOD_ARITY: &[(&str, usize)] = &[
+    ("eth_chainId", 0),
+    ("eth_blockNumber", 0),
+    ("eth_getBalance", 2),
+    ("eth_getTransactionCount", 2),
+    ("eth_getCode", 2),
+    ("eth_getStorageAt", 3),
+    ("eth_sendRawTransaction", 1),
+    ("eth_call", 2),
+    ("eth_estimateGas", 2),
+    ("eth_getBlockByNumber", 2),
+    ("eth_getBlockByHash", 2),
+    ("eth_getTransactionByHash", 1),
+    ("eth_getTransactionReceipt", 1),
+    ("eth_gasPrice", 0),
+    ("eth_maxPriorityFeePerGas", 0),
+    ("eth_feeHistory", 3),
+    ("eth_accounts", 0),
+    ("eth_protocolVersion", 0),
+    ("eth_syncing", 0),
+    ("eth_getLogs", 1),
+    ("net_version", 0),
+    ("net_listening", 0),
+    ("net_peerCount", 0),
+    ("web3_clientVersion", 0),
+    ("web3_sha3", 1),
+    ("monmouth_nodeStatus", 0),
+    ("debug_traceBlockByNumber", 1),
+    ("txpool_status", 0),
+    ("txpool_content", 0),
+];
+
+/// Check that `params` does not supply more parameters than `method` accepts.
+///
+/// Methods missing from the arity table are not checked. Type errors in
+/// declared parameters are already rejected by the generated handlers.
+fn check_params(method: &str, params: Option<&str>) -> Result<(), ErrorObjectOwned> {
+    let Some(&(_, arity)) = METHOD_ARITY.iter().find(|(name, _)| *name == method) else {
+        return Ok(());
+    };
+    let supplied = match params.map(serde_json::from_str::<Value>) {
+        None | Some(Ok(Value::Null)) => 0,
+        Some(Ok(Value::Array(values))) => values.len(),
+        Some(Ok(Value::Object(fields))) => fields.len(),
+        Some(Ok(_)) | Some(Err(_)) => {
+            return Err(invalid_params(format!("{method}: params must be an array or object")));
+        }
+    };
+    if supplied > arity {
+        return Err(invalid_params(format!(
+            "{method}: expected at most {arity} params, got {supplied}"
+        )));
+    }
+    Ok(())
+}
+
+fn invalid_params(message: String) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(codes::INVALID_PARAMS, message, None::<()>)
+}
+
+/// RPC middleware rejecting unknown parameters with `-32602` when strict.
+///
+/// When lenient, requests pass through untouched and extra parameters are
+/// ignored by the method handlers.
+#[derive(Clone, Debug)]
+pub(crate) struct StrictParams<S> {
+    service: S,
+    strict: bool,
+}
+
+impl<S> StrictParams<S> {
+    /// Wrap `service`, enforcing parameter counts if `strict`.
+    pub(crate) const fn new(service: S, strict: bool) -> Self {
+        Self { service, strict }
+    }
+}
+
+impl<'a, S> RpcServiceT<'a> for StrictParams<S>
+where
+    S: RpcServiceT<'a> + Send + Sync,
+    S::Future: 'a,
+{
+    type Future = Pin<Box<dyn Future<Output = MethodResponse> + Send + 'a>>;
+
+    fn call(&self, request: Request<'a>) -> Self::Future {
+        if self.strict
+            && let Err(err) = check_params(request.method_name(), request.params().as_str())
+        {
+            return Box::pin(std::future::ready(MethodResponse::error(request.id, err)));
+        }
+        Box::pin(self.service.call(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use jsonrpsee::types::{Id, ResponsePayload};
+    use serde_json::value::RawValue;
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct Accept;
+
+    impl<'a> RpcServiceT<'a> for Accept {
+        type Future = std::future::Ready<MethodResponse>;
+
+        fn call(&self, request: Request<'a>) -> Self::Future {
+            std::future::ready(MethodResponse::response(
+                request.id,
+                ResponsePayload::success(true),
+                usize::MAX,
+            ))
+        }
+    }
+
+    async fn get_balance(strict: bool, params: &str) -> MethodResponse {
+        let params = RawValue::from_string(params.to_string()).unwrap();
+        let request = Request::new(Cow::Borrowed("eth_getBalance"), Some(&*params), Id::Number(1));
+        StrictParams::new(Accept, strict).call(request).await
+    }
+
+    fn error_code(response: &MethodResponse) -> Option<i64> {
+        let json: Value = serde_json::from_str(response.as_result()).unwrap();
+        json["error"]["code"].as_i64()
+    }
+
+    #[tokio::test]
+    async fn extra_param_accepted_when_lenient() {
+        let params = r#"["0x0000000000000000000000000000000000000001", "latest", "extra"]"#;
+        let response = get_balance(false, params).await;
+        assert!(response.is_success());
+    }
+
+    #[tokio::test]
+    async fn extra_param_rejected_when_strict() {
+        let params = r#"["0x0000000000000000000000000000000000000001", "latest", "extra"]"#;
+        let response = get_balance(true, params).await;
+        assert!(response.is_error());
+        assert_eq!(error_code(&response), Some(i64::from(codes::INVALID_PARAMS)));
+
+        let params = r#"["0x0000000000000000000000000000000000000001", "latest"]"#;
+        assert!(get_balance(true, params).await.is_success());
+    }
+
+    #[test]
+    fn unlisted_methods_are_not_checked() {
+        assert!(check_params("eth_unknown", Some("[1, 2, 3]")).is_ok());
+        assert!(check_params("eth_chainId", None).is_ok());
+        assert!(check_params("eth_chainId", Some("[]")).is_ok());
+        assert!(check_params("eth_chainId", Some("[1]")).is_err());
+    }
+}
@@ -3,20 +3,26 @@
 use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use axum::{Router, extract::State, http::StatusCode, response::IntoResponse, routing::get};
-use jsonrpsee::server::{Server, ServerHandle};
+use jsonrpsee::server::{RpcServiceBuilder, Server, ServerHandle};
+use monmouth_executor::TransactionClassifier;
 use tower::limit::ConcurrencyLimitLayer;
 use tower_http::cors::{AllowOrigin, Any, CorsLayer};
 use tracing::{error, info};
 
 use crate::{
     config::{CorsConfig, RpcServerConfig},
+    debug::{DEFAULT_MAX_TRACES_PER_REQUEST, DebugApiImpl, DebugApiServer},
     eth::{
         EthApiImpl, EthApiServer, NetApiImpl, NetApiServer, TxSubmitCallback, Web3ApiImpl,
         Web3ApiServer,
     },
+    execution_limit::ExecutionLimiter,
+    method_filter::MethodFilter,
     monmouth::{MonmouthApiImpl, MonmouthApiServer},
+    params::StrictParams,
     state::NodeState,
     state_provider::{NoopStateProvider, StateProvider},
+    txpool::{TxPoolApiImpl, TxPoolApiServer, TxPoolSource},
 };
 
 /// Error type for RPC server operations.
@@ -74,9 +80,15 @@ pub struct RpcServer<S: StateProvider = NoopStateProvider> {
     addr: SocketAddr,
     chain_id: u64,
     tx_submit: Option<TxSubmitCallback>,
+    classifier: TransactionClassifier,
     state_provider: S,
     cors_config: CorsConfig,
     max_connections: u32,
+    method_filter: MethodFilter,
+    max_traces: usize,
+    tx_pool: Option<Arc<dyn TxPoolSource>>,
+    strict_params: bool,
+    executions: ExecutionLimiter,
 }
 
 impl<S: StateProvider> std::fmt::Debug for RpcServer<S> {
@@ -98,9 +110,15 @@ impl RpcServer<NoopStateProvider> {
             addr,
             chain_id: 1,
             tx_submit: None,
+            classifier: TransactionClassifier::enabled(),
             state_provider: NoopStateProvider,
             cors_config: CorsConfig::default(),
             max_connections: 100,
+            method_filter: MethodFilter::default(),
+            max_traces: DEFAULT_MAX_TRACES_PER_REQUEST,
+            tx_pool: None,
+            strict_params: false,
+            executions: ExecutionLimiter::default(),
         }
     }
 
@@ -111,9 +129,15 @@ impl RpcServer<NoopStateProvider> {
             addr,
             chain_id,
             tx_submit: None,
+            classifier: TransactionClassifier::enabled(),
             state_provider: NoopStateProvider,
             cors_config: CorsConfig::default(),
             max_connections: 100,
+            method_filter: MethodFilter::default(),
+            max_traces: DEFAULT_MAX_TRACES_PER_REQUEST,
+            tx_pool: None,
+            strict_params: false,
+            executions: ExecutionLimiter::default(),
         }
     }
 }
@@ -131,9 +155,15 @@ impl<S: StateProvider + Clone + 'static> RpcServer<S> {
             addr,
             chain_id,
             tx_submit: None,
+            classifier: TransactionClassifier::enabled(),
             state_provider,
             cors_config: CorsConfig::default(),
             max_connections: 100,
+            method_filter: MethodFilter::default(),
+            max_traces: DEFAULT_MAX_TRACES_PER_REQUEST,
+            tx_pool: None,
+            strict_params: false,
+            executions: ExecutionLimiter::default(),
         }
     }
 
@@ -144,6 +174,13 @@ impl<S: StateProvider + Clone + 'static> RpcServer<S> {
         self
     }
 
+    /// Classify raw transactions with `classifier` before submitting them.
+    #[must_use]
+    pub fn with_classifier(mut self, classifier: TransactionClassifier) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
     /// Set CORS configuration.
     #[must_use]
     pub fn with_cors(mut self, cors_config: CorsConfig) -> Self {
@@ -158,6 +195,49 @@ impl<S: StateProvider + Clone + 'static> RpcServer<S> {
         self
     }
 
+    /// Restrict the exposed JSON-RPC methods.
+    ///
+    /// Filtered methods respond with "method not found".
+    #[must_use]
+    pub fn with_method_filter(mut self, method_filter: MethodFilter) -> Self {
+        self.method_filter = method_filter;
+        self
+    }
+
+    /// Set the maximum number of transaction traces returned by one debug request.
+    #[must_use]
+    pub const fn with_max_traces(mut self, max_traces: usize) -> Self {
+        self.max_traces = max_traces;
+        self
+    }
+
+    /// Serve `txpool_*` from `tx_pool`.
+    ///
+    /// The namespace is not in the default method allowlist.
+    #[must_use]
+    pub fn with_tx_pool(mut self, tx_pool: Arc<dyn TxPoolSource>) -> Self {
+        self.tx_pool = Some(tx_pool);
+        self
+    }
+
+    /// Reject requests with more parameters than the method accepts.
+    ///
+    /// Rejected requests receive `-32602`. Lenient by default.
+    #[must_use]
+    pub const fn with_strict_params(mut self, strict: bool) -> Self {
+        self.strict_params = strict;
+        self
+    }
+
+    /// Bound concurrent `eth_call`, `eth_estimateGas`, and `debug_trace*` executions.
+    ///
+    /// Requests waiting longer than `queue_timeout` for a slot fail as "server busy".
+    #[must_use]
+    pub fn with_execution_limit(mut self, max_concurrent: usize, queue_timeout: Duration) -> Self {
+        self.executions = ExecutionLimiter::new(max_concurrent, queue_timeout);
+        self
+    }
+
     /// Create from configuration.
     pub fn from_config(state: NodeState, config: RpcServerConfig, state_provider: S) -> Self {
         Self {
@@ -165,9 +245,18 @@ impl<S: StateProvider + Clone + 'static> RpcServer<S> {
             addr: config.http_addr,
             chain_id: config.chain_id,
             tx_submit: None,
+            classifier: TransactionClassifier::enabled(),
             state_provider,
             cors_config: config.cors,
             max_connections: config.max_connections,
+            method_filter: config.method_filter,
+            max_traces: config.max_traces_per_request,
+            tx_pool: None,
+            strict_params: config.strict_params,
+            executions: ExecutionLimiter::new(
+                config.max_concurrent_executions,
+                config.execution_queue_timeout,
+            ),
         }
     }
 
@@ -181,9 +270,15 @@ impl<S: StateProvider + Clone + 'static> RpcServer<S> {
         let node_state_for_jsonrpc = Arc::clone(&node_state);
         let chain_id = self.chain_id;
         let tx_submit = self.tx_submit;
+        let classifier = self.classifier;
         let cors_layer = build_cors_layer(&self.cors_config);
         let max_connections = self.max_connections;
         let state_provider = self.state_provider;
+        let method_filter = self.method_filter;
+        let max_traces = self.max_traces;
+        let tx_pool = self.tx_pool;
+        let strict_params = self.strict_params;
+        let executions = self.executions;
 
         let http_handle = tokio::spawn(async move {
             let app = Router::new()
@@ -211,6 +306,10 @@ impl<S: StateProvider + Clone + 'static> RpcServer<S> {
         let jsonrpc_handle = tokio::spawn(async move {
             let server = match Server::builder()
                 .max_connections(max_connections)
+                .set_rpc_middleware(
+                    RpcServiceBuilder::new()
+                        .layer_fn(move |service| StrictParams::new(service, strict_params)),
+                )
                 .build(jsonrpc_addr)
                 .await
             {
@@ -221,13 +320,19 @@ impl<S: StateProvider + Clone + 'static> RpcServer<S> {
                 }
             };
 
-            let eth_api = tx_submit.map_or_else(
-                || EthApiImpl::new(chain_id, state_provider.clone()),
-                |submit| EthApiImpl::with_tx_submit(chain_id, state_provider.clone(), submit),
-            );
+            let eth_api = tx_submit
+                .map_or_else(
+                    || EthApiImpl::new(chain_id, state_provider.clone()),
+                    |submit| EthApiImpl::with_tx_submit(chain_id, state_provider.clone(), submit),
+                )
+                .with_classifier(classifier)
+                .with_node_state(Arc::clone(&node_state_for_jsonrpc))
+                .with_execution_limiter(executions.clone());
             let net_api = NetApiImpl::new(chain_id);
             let web3_api = Web3ApiImpl::new();
             let monmouth_api = MonmouthApiImpl::new(node_state_for_jsonrpc);
+            let debug_api =
+                DebugApiImpl::new(state_provider, max_traces).with_execution_limiter(executions);
 
             let mut module = jsonrpsee::RpcModule::new(());
             if let Err(e) = module.merge(eth_api.into_rpc()) {
@@ -246,6 +351,17 @@ impl<S: StateProvider + Clone + 'static> RpcServer<S> {
                 error!(error = %e, "Failed to merge monmouth API");
                 return None;
             }
+            if let Err(e) = module.merge(debug_api.into_rpc()) {
+                error!(error = %e, "Failed to merge debug API");
+                return None;
+            }
+            if let Some(pool) = tx_pool
+                && let Err(e) = module.merge(TxPoolApiImpl::new(pool).into_rpc())
+            {
+                error!(error = %e, "Failed to merge txpool API");
+                return None;
+            }
+            method_filter.apply(&mut module);
 
             info!(addr = %jsonrpc_addr, "Starting JSON-RPC server");
 
@@ -297,8 +413,14 @@ pub struct JsonRpcServer<S: StateProvider = NoopStateProvider> {
     addr: SocketAddr,
     chain_id: u64,
     tx_submit: Option<TxSubmitCallback>,
+    classifier: TransactionClassifier,
     state_provider: S,
     max_connections: u32,
+    method_filter: MethodFilter,
+    max_traces: usize,
+    tx_pool: Option<Arc<dyn TxPoolSource>>,
+    strict_params: bool,
+    executions: ExecutionLimiter,
 }
 
 impl<S: StateProvider> std::fmt::Debug for JsonRpcServer<S> {
@@ -318,8 +440,14 @@ impl JsonRpcServer<NoopStateProvider> {
             addr,
             chain_id,
             tx_submit: None,
+            classifier: TransactionClassifier::enabled(),
             state_provider: NoopStateProvider,
             max_connections: 100,
+            method_filter: MethodFilter::default(),
+            max_traces: DEFAULT_MAX_TRACES_PER_REQUEST,
+            tx_pool: None,
+            strict_params: false,
+            executions: ExecutionLimiter::default(),
         }
     }
 }
@@ -327,7 +455,19 @@ impl JsonRpcServer<NoopStateProvider> {
 impl<S: StateProvider + Clone + 'static> JsonRpcServer<S> {
     /// Create a new JSON-RPC server with a custom state provider.
     pub fn with_state_provider(addr: SocketAddr, chain_id: u64, state_provider: S) -> Self {
-        Self { addr, chain_id, tx_submit: None, state_provider, max_connections: 100 }
+        Self {
+            addr,
+            chain_id,
+            tx_submit: None,
+            classifier: TransactionClassifier::enabled(),
+            state_provider,
+            max_connections: 100,
+            method_filter: MethodFilter::default(),
+            max_traces: DEFAULT_MAX_TRACES_PER_REQUEST,
+            tx_pool: None,
+            strict_params: false,
+            executions: ExecutionLimiter::default(),
+        }
     }
 
     /// Set the transaction submission callback.
@@ -337,6 +477,13 @@ impl<S: StateProvider + Clone + 'static> JsonRpcServer<S> {
         self
     }
 
+    /// Classify raw transactions with `classifier` before submitting them.
+    #[must_use]
+    pub fn with_classifier(mut self, classifier: TransactionClassifier) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
     /// Set maximum concurrent connections.
     #[must_use]
     pub const fn with_max_connections(mut self, max_connections: u32) -> Self {
@@ -344,25 +491,86 @@ impl<S: StateProvider + Clone + 'static> JsonRpcServer<S> {
         self
     }
 
+    /// Restrict the exposed JSON-RPC methods.
+    ///
+    /// Filtered methods respond with "method not found".
+    #[must_use]
+    pub fn with_method_filter(mut self, method_filter: MethodFilter) -> Self {
+        self.method_filter = method_filter;
+        self
+    }
+
+    /// Set the maximum number of transaction traces returned by one debug request.
+    #[must_use]
+    pub const fn with_max_traces(mut self, max_traces: usize) -> Self {
+        self.max_traces = max_traces;
+        self
+    }
+
+    /// Serve `txpool_*` from `tx_pool`.
+    ///
+    /// The namespace is not in the default method allowlist.
+    #[must_use]
+    pub fn with_tx_pool(mut self, tx_pool: Arc<dyn TxPoolSource>) -> Self {
+        self.tx_pool = Some(tx_pool);
+        self
+    }
+
+    /// Reject requests with more parameters than the method accepts.
+    ///
+    /// Rejected requests receive `-32602`. Lenient by default.
+    #[must_use]
+    pub const fn with_strict_params(mut self, strict: bool) -> Self {
+        self.strict_params = strict;
+        self
+    }
+
+    /// Bound concurrent `eth_call`, `eth_estimateGas`, and `debug_trace*` executions.
+    ///
+    /// Requests waiting longer than `queue_timeout` for a slot fail as "server busy".
+    #[must_use]
+    pub fn with_execution_limit(mut self, max_concurrent: usize, queue_timeout: Duration) -> Self {
+        self.executions = ExecutionLimiter::new(max_concurrent, queue_timeout);
+        self
+    }
+
     /// Start the JSON-RPC server.
     pub async fn start(self) -> Result<ServerHandle, ServerError> {
+        let strict_params = self.strict_params;
         let server = Server::builder()
             .max_connections(self.max_connections)
+            .set_rpc_middleware(
+                RpcServiceBuilder::new()
+                    .layer_fn(move |service| StrictParams::new(service, strict_params)),
+            )
             .build(self.addr)
             .await
             .map_err(|e| ServerError::Build(e.to_string()))?;
 
-        let eth_api = self.tx_submit.map_or_else(
-            || EthApiImpl::new(self.chain_id, self.state_provider.clone()),
-            |submit| EthApiImpl::with_tx_submit(self.chain_id, self.state_provider.clone(), submit),
-        );
+        let eth_api = self
+            .tx_submit
+            .map_or_else(
+                || EthApiImpl::new(self.chain_id, self.state_provider.clone()),
+                |submit| {
+                    EthApiImpl::with_tx_submit(self.chain_id, self.state_provider.clone(), submit)
+                },
+            )
+            .with_classifier(self.classifier)
+            .with_execution_limiter(self.executions.clone());
         let net_api = NetApiImpl::new(self.chain_id);
         let web3_api = Web3ApiImpl::new();
+        let debug_api = DebugApiImpl::new(self.state_provider, self.max_traces)
+            .with_execution_limiter(self.executions);
 
         let mut module = jsonrpsee::RpcModule::new(());
         module.merge(eth_api.into_rpc())?;
         module.merge(net_api.into_rpc())?;
         module.merge(web3_api.into_rpc())?;
+        module.merge(debug_api.into_rpc())?;
+        if let Some(pool) = self.tx_pool {
+            module.merge(TxPoolApiImpl::new(pool).into_rpc())?;
+        }
+        self.method_filter.apply(&mut module);
 
         info!(addr = %self.addr, "Starting JSON-RPC server");
 
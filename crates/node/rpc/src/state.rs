@@ -8,9 +8,12 @@ use std::{
     time::Instant,
 };
 
+use alloy_primitives::U64;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 
+use crate::types::{SyncInfo, SyncStatus};
+
 /// Shared node state that can be updated by the consensus engine.
 #[derive(Debug, Clone)]
 pub struct NodeState {
@@ -28,6 +31,7 @@ struct NodeStateInner {
     nullified_count: AtomicU64,
     peer_count: AtomicU64,
     is_leader: RwLock<bool>,
+    sync: RwLock<Option<SyncInfo>>,
 }
 
 impl NodeState {
@@ -45,6 +49,7 @@ impl NodeState {
                 nullified_count: AtomicU64::new(0),
                 peer_count: AtomicU64::new(0),
                 is_leader: RwLock::new(false),
+                sync: RwLock::new(None),
             }),
         }
     }
@@ -77,6 +82,31 @@ impl NodeState {
         self.inner.peer_count.store(count, Ordering::Relaxed);
     }
 
+    /// Record that the node is backfilling, with its local head at `current` and
+    /// the highest known network block at `highest`.
+    ///
+    /// The starting block is the head when the current catch-up began and is
+    /// kept until [`set_synced`](Self::set_synced) is called.
+    pub fn set_syncing(&self, current: u64, highest: u64) {
+        let mut sync = self.inner.sync.write();
+        let starting_block = sync.as_ref().map_or(U64::from(current), |info| info.starting_block);
+        *sync = Some(SyncInfo {
+            starting_block,
+            current_block: U64::from(current),
+            highest_block: U64::from(highest),
+        });
+    }
+
+    /// Record that the node has caught up with the network.
+    pub fn set_synced(&self) {
+        *self.inner.sync.write() = None;
+    }
+
+    /// Get the sync status reported by `eth_syncing`.
+    pub fn sync_status(&self) -> SyncStatus {
+        self.inner.sync.read().clone().map_or(SyncStatus::NotSyncing(false), SyncStatus::Syncing)
+    }
+
     /// Get current node status.
     pub fn status(&self) -> NodeStatus {
         NodeStatus {
@@ -213,4 +243,22 @@ mod tests {
         state.set_peer_count(5);
         assert_eq!(state.status().peer_count, 5);
     }
+
+    #[test]
+    fn node_state_sync_status_keeps_starting_block() {
+        let state = NodeState::new(1, 0);
+        assert!(matches!(state.sync_status(), SyncStatus::NotSyncing(false)));
+
+        state.set_syncing(10, 50);
+        state.set_syncing(20, 55);
+        let SyncStatus::Syncing(info) = state.sync_status() else {
+            panic!("expected syncing status");
+        };
+        assert_eq!(info.starting_block, U64::from(10));
+        assert_eq!(info.current_block, U64::from(20));
+        assert_eq!(info.highest_block, U64::from(55));
+
+        state.set_synced();
+        assert!(matches!(state.sync_status(), SyncStatus::NotSyncing(false)));
+    }
 }
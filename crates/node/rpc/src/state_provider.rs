@@ -7,7 +7,7 @@ use crate::{
     error::RpcError,
     types::{
         BlockNumberOrTag, CallRequest, RpcBlock, RpcLog, RpcLogFilter, RpcTransaction,
-        RpcTransactionReceipt,
+        RpcTransactionReceipt, RpcTransactionTrace,
     },
 };
 
@@ -83,6 +83,17 @@ pub trait StateProvider: Send + Sync {
     async fn get_logs(&self, _filter: RpcLogFilter) -> Result<Vec<RpcLog>, RpcError> {
         Err(RpcError::NotImplemented)
     }
+
+    /// Re-execute a finalized block with call tracing enabled.
+    ///
+    /// Implementations must execute against the state as of the block's parent and
+    /// return one trace per transaction, in block order.
+    async fn trace_block(
+        &self,
+        _block: BlockNumberOrTag,
+    ) -> Result<Vec<RpcTransactionTrace>, RpcError> {
+        Err(RpcError::NotImplemented)
+    }
 }
 
 /// A no-op state provider that returns empty/zero values.
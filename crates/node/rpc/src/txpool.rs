@@ -0,0 +1,210 @@
+//! Transaction pool JSON-RPC API implementation.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use alloy_consensus::{Transaction as _, TxEnvelope};
+use alloy_primitives::{Address, U64, U256};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use monmouth_txpool::{OrderedTransaction, TransactionPool};
+use serde::{Deserialize, Serialize};
+
+use crate::types::RpcTransaction;
+
+/// Transactions of one pool section, keyed by sender and then nonce.
+pub type TxPoolSection = BTreeMap<Address, BTreeMap<u64, RpcTransaction>>;
+
+/// Response of `txpool_status`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TxPoolStatus {
+    /// Number of executable transactions.
+    pub pending: U64,
+    /// Number of transactions waiting on a nonce gap.
+    pub queued: U64,
+}
+
+/// Response of `txpool_content`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TxPoolContent {
+    /// Executable transactions.
+    pub pending: TxPoolSection,
+    /// Transactions waiting on a nonce gap.
+    pub queued: TxPoolSection,
+}
+
+/// Pooled transaction with its recovered sender.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PooledTransaction {
+    /// Recovered sender.
+    pub sender: Address,
+    /// Signed transaction.
+    pub envelope: TxEnvelope,
+}
+
+/// Snapshot of a pool's transactions, as returned by [`TxPoolSource::content`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PoolContent {
+    /// Executable transactions.
+    pub pending: Vec<PooledTransaction>,
+    /// Transactions waiting on a nonce gap.
+    pub queued: Vec<PooledTransaction>,
+}
+
+/// Pool served by the `txpool` namespace.
+///
+/// This keeps the RPC layer independent of the node's mempool implementation.
+pub trait TxPoolSource: Send + Sync + 'static {
+    /// Current pending and queued transactions.
+    fn content(&self) -> PoolContent;
+}
+
+impl TxPoolSource for TransactionPool {
+    fn content(&self) -> PoolContent {
+        let mut content = PoolContent::default();
+        for queue in self.sender_queues() {
+            let pooled = |tx: OrderedTransaction| PooledTransaction {
+                sender: queue.sender,
+                envelope: tx.envelope,
+            };
+            content.pending.extend(queue.pending.into_iter().map(pooled));
+            content.queued.extend(queue.queued.into_iter().map(pooled));
+        }
+        content
+    }
+}
+
+/// Transaction pool JSON-RPC API trait, mirroring Geth's `txpool` namespace.
+///
+/// Not part of the default method allowlist; enable `txpool_*` explicitly.
+#[rpc(server, namespace = "txpool")]
+pub trait TxPoolApi {
+    /// Returns the number of pending and queued transactions.
+    #[method(name = "status")]
+    fn status(&self) -> RpcResult<TxPoolStatus>;
+
+    /// Returns pending and queued transactions grouped by sender and nonce.
+    #[method(name = "content")]
+    fn content(&self) -> RpcResult<TxPoolContent>;
+}
+
+/// Transaction pool API implementation.
+#[derive(Clone)]
+pub struct TxPoolApiImpl {
+    pool: Arc<dyn TxPoolSource>,
+}
+
+impl std::fmt::Debug for TxPoolApiImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TxPoolApiImpl").finish_non_exhaustive()
+    }
+}
+
+impl TxPoolApiImpl {
+    /// Create a new transaction pool API over `pool`.
+    pub fn new(pool: Arc<dyn TxPoolSource>) -> Self {
+        Self { pool }
+    }
+}
+
+impl TxPoolApiServer for TxPoolApiImpl {
+    fn status(&self) -> RpcResult<TxPoolStatus> {
+        let content = self.pool.content();
+        Ok(TxPoolStatus {
+            pending: U64::from(content.pending.len()),
+            queued: U64::from(content.queued.len()),
+        })
+    }
+
+    fn content(&self) -> RpcResult<TxPoolContent> {
+        let content = self.pool.content();
+        Ok(TxPoolContent { pending: section(content.pending), queued: section(content.queued) })
+    }
+}
+
+fn section(txs: Vec<PooledTransaction>) -> TxPoolSection {
+    let mut section = TxPoolSection::new();
+    for tx in txs {
+        section.entry(tx.sender).or_default().insert(tx.envelope.nonce(), pool_tx_to_rpc(&tx));
+    }
+    section
+}
+
+fn pool_tx_to_rpc(tx: &PooledTransaction) -> RpcTransaction {
+    let envelope = &tx.envelope;
+    let signature = envelope.signature();
+    let is_dynamic_fee = envelope.is_dynamic_fee();
+    RpcTransaction {
+        hash: *envelope.tx_hash(),
+        nonce: U64::from(envelope.nonce()),
+        block_hash: None,
+        block_number: None,
+        transaction_index: None,
+        from: tx.sender,
+        to: envelope.to(),
+        value: envelope.value(),
+        gas: U64::from(envelope.gas_limit()),
+        gas_price: U256::from(envelope.effective_gas_price(None)),
+        input: envelope.input().clone(),
+        tx_type: U64::from(envelope.tx_type() as u8),
+        chain_id: envelope.chain_id().map(U64::from),
+        max_fee_per_gas: is_dynamic_fee.then(|| U256::from(envelope.max_fee_per_gas())),
+        max_priority_fee_per_gas: envelope.max_priority_fee_per_gas().map(U256::from),
+        v: U64::from(u64::from(signature.v())),
+        r: signature.r(),
+        s: signature.s(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::{SignableTransaction as _, TxEip1559};
+    use alloy_primitives::{B256, Signature, TxKind, keccak256};
+    use monmouth_txpool::PoolConfig;
+
+    use super::*;
+
+    fn pool_tx(sender: Address, nonce: u64) -> OrderedTransaction {
+        let inner = TxEip1559 {
+            chain_id: 1,
+            nonce,
+            gas_limit: 21_000,
+            max_fee_per_gas: 100,
+            max_priority_fee_per_gas: 100,
+            to: TxKind::Call(Address::ZERO),
+            value: U256::ZERO,
+            access_list: Default::default(),
+            input: Default::default(),
+        };
+        let signature = Signature::from_scalars_and_parity(B256::ZERO, B256::ZERO, false);
+        let envelope = TxEnvelope::from(inner.into_signed(signature));
+        let hash = keccak256([sender.as_slice(), &nonce.to_be_bytes()].concat());
+        OrderedTransaction::new(hash, sender, nonce, 100, 0, envelope)
+    }
+
+    #[test]
+    fn nonce_gap_splits_pending_and_queued() {
+        let pool = Arc::new(TransactionPool::new(PoolConfig::default()));
+        let gapped = Address::repeat_byte(0x01);
+        let contiguous = Address::repeat_byte(0x02);
+        for (sender, nonce) in [(gapped, 0), (gapped, 1), (gapped, 3), (contiguous, 0)] {
+            pool.add(pool_tx(sender, nonce)).unwrap();
+        }
+
+        let api = TxPoolApiImpl::new(pool);
+        let status = api.status().unwrap();
+        assert_eq!(status, TxPoolStatus { pending: U64::from(3), queued: U64::from(1) });
+
+        let content = api.content().unwrap();
+        let nonces = |section: &TxPoolSection, sender: &Address| {
+            section.get(sender).map(|txs| txs.keys().copied().collect::<Vec<_>>())
+        };
+        assert_eq!(nonces(&content.pending, &gapped), Some(vec![0, 1]));
+        assert_eq!(nonces(&content.queued, &gapped), Some(vec![3]));
+        assert_eq!(nonces(&content.pending, &contiguous), Some(vec![0]));
+        assert_eq!(nonces(&content.queued, &contiguous), None);
+        assert_eq!(content.queued[&gapped][&3].from, gapped);
+        assert_eq!(content.queued[&gapped][&3].gas_price, U256::from(100));
+
+        let json = serde_json::to_value(&content).unwrap();
+        assert!(json["queued"][gapped.to_string()]["3"].is_object());
+    }
+}
@@ -349,6 +349,69 @@ impl TopicFilter {
     }
 }
 
+/// Call frame in the format of Geth's `callTracer`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcCallFrame {
+    /// Call type (`CALL`, `STATICCALL`, `DELEGATECALL`, `CALLCODE`, `CREATE`, `CREATE2`).
+    #[serde(rename = "type")]
+    pub call_type: String,
+    /// Caller address.
+    pub from: Address,
+    /// Callee or created contract address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<Address>,
+    /// Value transferred.
+    pub value: U256,
+    /// Gas made available to the call.
+    pub gas: U64,
+    /// Gas spent by the call, including subcalls.
+    pub gas_used: U64,
+    /// Call data.
+    pub input: Bytes,
+    /// Return data.
+    pub output: Bytes,
+    /// Error message if the call reverted or halted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Nested calls.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub calls: Vec<Self>,
+}
+
+/// Call trace of one transaction, as returned by `debug_traceBlockByNumber`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcTransactionTrace {
+    /// Transaction hash.
+    pub tx_hash: B256,
+    /// Top-level call frame.
+    pub result: RpcCallFrame,
+}
+
+impl From<monmouth_executor::CallFrame> for RpcCallFrame {
+    fn from(frame: monmouth_executor::CallFrame) -> Self {
+        Self {
+            call_type: frame.kind.as_str().to_string(),
+            from: frame.from,
+            to: frame.to,
+            value: frame.value,
+            gas: U64::from(frame.gas),
+            gas_used: U64::from(frame.gas_used),
+            input: frame.input,
+            output: frame.output,
+            error: frame.error,
+            calls: frame.calls.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<monmouth_executor::TransactionTrace> for RpcTransactionTrace {
+    fn from(trace: monmouth_executor::TransactionTrace) -> Self {
+        Self { tx_hash: trace.tx_hash, result: trace.frame.into() }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,174 @@
+//! Integration tests for account state queries over HTTP JSON-RPC.
+
+use std::{
+    collections::HashMap,
+    net::{SocketAddr, TcpListener},
+    sync::Arc,
+};
+
+use alloy_primitives::{Address, B256, Bytes, U256, address, b256};
+use monmouth_indexer::BlockIndex;
+use monmouth_rpc::{IndexedStateProvider, JsonRpcServer, error_codes};
+use monmouth_traits::{StateDbError, StateDbRead};
+use serde_json::{Value, json};
+
+const CHAIN_ID: u64 = 1337;
+
+const FUNDED: Address = address!("0x1000000000000000000000000000000000000001");
+const CONTRACT: Address = address!("0x2000000000000000000000000000000000000002");
+const MISSING: Address = address!("0x3000000000000000000000000000000000000003");
+
+const CODE_HASH: B256 = b256!("0x4000000000000000000000000000000000000000000000000000000000000004");
+
+#[derive(Clone, Debug)]
+struct Account {
+    nonce: u64,
+    balance: U256,
+    code_hash: B256,
+    storage: HashMap<U256, U256>,
+}
+
+/// Read-only state seeded with a funded account and a contract.
+#[derive(Clone, Debug)]
+struct SeededState {
+    accounts: Arc<HashMap<Address, Account>>,
+    code: Arc<HashMap<B256, Bytes>>,
+}
+
+impl SeededState {
+    fn new() -> Self {
+        let funded = Account {
+            nonce: 7,
+            balance: U256::from(1_000_000_000_000_000_000u128),
+            code_hash: B256::ZERO,
+            storage: HashMap::new(),
+        };
+        let contract = Account {
+            nonce: 1,
+            balance: U256::ZERO,
+            code_hash: CODE_HASH,
+            storage: HashMap::from([(U256::from(1), U256::from(0xbeef))]),
+        };
+        Self {
+            accounts: Arc::new(HashMap::from([(FUNDED, funded), (CONTRACT, contract)])),
+            code: Arc::new(HashMap::from([(CODE_HASH, Bytes::from_static(&[0x60, 0x00]))])),
+        }
+    }
+
+    fn account(&self, address: &Address) -> Result<&Account, StateDbError> {
+        self.accounts.get(address).ok_or(StateDbError::AccountNotFound(*address))
+    }
+}
+
+impl StateDbRead for SeededState {
+    async fn nonce(&self, address: &Address) -> Result<u64, StateDbError> {
+        self.account(address).map(|account| account.nonce)
+    }
+
+    async fn balance(&self, address: &Address) -> Result<U256, StateDbError> {
+        self.account(address).map(|account| account.balance)
+    }
+
+    async fn code_hash(&self, address: &Address) -> Result<B256, StateDbError> {
+        self.account(address).map(|account| account.code_hash)
+    }
+
+    async fn code(&self, code_hash: &B256) -> Result<Bytes, StateDbError> {
+        if *code_hash == B256::ZERO {
+            return Ok(Bytes::new());
+        }
+        self.code.get(code_hash).cloned().ok_or(StateDbError::CodeNotFound(*code_hash))
+    }
+
+    async fn storage(&self, address: &Address, slot: &U256) -> Result<U256, StateDbError> {
+        Ok(self.account(address)?.storage.get(slot).copied().unwrap_or_default())
+    }
+}
+
+/// A JSON-RPC server over [`SeededState`] and the address it listens on.
+struct TestServer {
+    url: String,
+    handle: jsonrpsee::server::ServerHandle,
+}
+
+impl TestServer {
+    async fn start() -> Self {
+        let addr = free_addr();
+        let provider = IndexedStateProvider::new(Arc::new(BlockIndex::new()), SeededState::new());
+        let handle = JsonRpcServer::with_state_provider(addr, CHAIN_ID, provider)
+            .start()
+            .await
+            .expect("server starts");
+        Self { url: format!("http://{addr}"), handle }
+    }
+
+    async fn call(&self, method: &str, params: Value) -> Value {
+        reqwest::Client::new()
+            .post(&self.url)
+            .json(&json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }))
+            .send()
+            .await
+            .expect("request sent")
+            .json()
+            .await
+            .expect("json response")
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        let _ = self.handle.stop();
+    }
+}
+
+fn free_addr() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind ephemeral port");
+    listener.local_addr().expect("local addr")
+}
+
+#[tokio::test]
+async fn get_balance_returns_hex_quantity() {
+    let server = TestServer::start().await;
+
+    let response = server.call("eth_getBalance", json!([FUNDED, "latest"])).await;
+
+    assert_eq!(response["result"], "0xde0b6b3a7640000");
+}
+
+#[tokio::test]
+async fn get_transaction_count_returns_nonce() {
+    let server = TestServer::start().await;
+
+    let response = server.call("eth_getTransactionCount", json!([FUNDED, "latest"])).await;
+
+    assert_eq!(response["result"], "0x7");
+}
+
+#[tokio::test]
+async fn get_storage_at_returns_slot_value() {
+    let server = TestServer::start().await;
+
+    let response = server.call("eth_getStorageAt", json!([CONTRACT, "0x1", "latest"])).await;
+
+    assert_eq!(response["result"], "0xbeef");
+}
+
+#[tokio::test]
+async fn get_code_returns_contract_bytecode() {
+    let server = TestServer::start().await;
+
+    let response = server.call("eth_getCode", json!([CONTRACT, "latest"])).await;
+
+    assert_eq!(response["result"], "0x6000");
+}
+
+#[tokio::test]
+async fn missing_account_returns_error_object() {
+    let server = TestServer::start().await;
+
+    let response = server.call("eth_getBalance", json!([MISSING, "latest"])).await;
+
+    assert!(response.get("result").is_none());
+    assert_eq!(response["error"]["code"], error_codes::RESOURCE_NOT_FOUND);
+    assert!(response["error"]["message"].as_str().expect("message").contains("account not found"));
+}
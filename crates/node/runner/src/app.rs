@@ -11,28 +11,47 @@ use commonware_consensus::{
 use commonware_cryptography::{Committable as _, certificate::Scheme as CertScheme};
 use commonware_runtime::{Clock, Metrics, Spawner};
 use futures::StreamExt;
-use monmouth_consensus::{BlockExecution, SnapshotStore, components::InMemorySnapshotStore};
-use monmouth_domain::{Block, ConsensusDigest};
-use monmouth_executor::{BlockContext, BlockExecutor};
-use monmouth_ledger::LedgerService;
+use monmouth_config::BlockInterval;
+use monmouth_consensus::{
+    AllowAll, BlockExecution, SnapshotStore, TransactionFilter, components::InMemorySnapshotStore,
+    select_transactions,
+};
+use monmouth_domain::{Block, ConsensusDigest, Tx};
+use monmouth_executor::{
+    BlockContext, BlockExecutor, ExecutionError, ExecutionOutcome, TransactionClassifier,
+};
+use monmouth_ledger::{BlockSnapshot, LedgerService};
 use monmouth_overlay::OverlayState;
 use monmouth_qmdb_ledger::QmdbState;
 use monmouth_rpc::NodeState;
 use rand::Rng;
-use tracing::{info, trace, warn};
+use tracing::{debug, info, trace, warn};
+
+use crate::{
+    BlockPacer, LightHeader, LightVerifier, PrecomputedRoot, RootPrecomputer, SyncTracker,
+};
 
 /// REVM-based consensus application.
+///
+/// Proposed blocks are filled through a [`TransactionFilter`], which admits
+/// every transaction until one is installed with [`Self::with_filter`].
 #[derive(Clone)]
-pub struct RevmApplication<S, E> {
+pub struct RevmApplication<S, E, F = AllowAll> {
     ledger: LedgerService,
     executor: E,
     max_txs: usize,
     gas_limit: u64,
+    classifier: TransactionClassifier,
+    filter: F,
     node_state: Option<NodeState>,
+    pacer: Option<BlockPacer>,
+    light: Option<LightVerifier>,
+    sync: Option<SyncTracker>,
+    roots: RootPrecomputer,
     _scheme: std::marker::PhantomData<S>,
 }
 
-impl<S, E> std::fmt::Debug for RevmApplication<S, E> {
+impl<S, E, F> std::fmt::Debug for RevmApplication<S, E, F> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RevmApplication")
             .field("max_txs", &self.max_txs)
@@ -46,13 +65,51 @@ where
     E: BlockExecutor<OverlayState<QmdbState>, Tx = Bytes> + Clone,
 {
     /// Create a new REVM application.
-    pub const fn new(ledger: LedgerService, executor: E, max_txs: usize, gas_limit: u64) -> Self {
+    pub fn new(ledger: LedgerService, executor: E, max_txs: usize, gas_limit: u64) -> Self {
         Self {
             ledger,
             executor,
             max_txs,
             gas_limit,
+            classifier: TransactionClassifier::enabled(),
+            filter: AllowAll,
             node_state: None,
+            pacer: None,
+            light: None,
+            sync: None,
+            roots: RootPrecomputer::new(),
+            _scheme: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<S, E, F> RevmApplication<S, E, F>
+where
+    E: BlockExecutor<OverlayState<QmdbState>, Tx = Bytes> + Clone,
+    F: TransactionFilter,
+{
+    /// Set the classifier used to label candidate transactions for the filter.
+    #[must_use]
+    pub fn with_classifier(mut self, classifier: TransactionClassifier) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
+    /// Replace the filter deciding which transactions may enter proposed blocks.
+    #[must_use]
+    pub fn with_filter<G: TransactionFilter>(self, filter: G) -> RevmApplication<S, E, G> {
+        RevmApplication {
+            ledger: self.ledger,
+            executor: self.executor,
+            max_txs: self.max_txs,
+            gas_limit: self.gas_limit,
+            classifier: self.classifier,
+            filter,
+            node_state: self.node_state,
+            pacer: self.pacer,
+            light: self.light,
+            sync: self.sync,
+            roots: self.roots,
             _scheme: std::marker::PhantomData,
         }
     }
@@ -64,6 +121,99 @@ where
         self
     }
 
+    /// Pace proposals between the minimum and maximum block time of `interval`.
+    #[must_use]
+    pub fn with_block_interval(mut self, interval: BlockInterval) -> Self {
+        self.pacer = Some(BlockPacer::new(interval));
+        self
+    }
+
+    /// Verify blocks with `verifier` instead of re-executing them.
+    ///
+    /// See [`LightVerifier`] for the checks performed and the trust this mode
+    /// places in proposers. The genesis block is trusted as the first accepted
+    /// block.
+    #[must_use]
+    pub fn with_light_verification(mut self, verifier: LightVerifier) -> Self {
+        let genesis = self.ledger.genesis_block();
+        verifier.trust(
+            genesis.commitment(),
+            LightHeader { height: genesis.height, state_root: genesis.state_root },
+        );
+        self.light = Some(verifier);
+        self
+    }
+
+    /// Suppress proposals while `tracker` reports the node as catching up.
+    ///
+    /// Verified and built blocks advance the tracker's head; proposals seen during
+    /// verification advance its tip. The genesis block is the initial head.
+    #[must_use]
+    pub fn with_sync_tracker(mut self, tracker: SyncTracker) -> Self {
+        tracker.observe_head(self.ledger.genesis_block().height);
+        self.sync = Some(tracker);
+        self
+    }
+
+    /// Publish the tracker's heights to the RPC node state for `eth_syncing`.
+    fn report_sync(&self, sync: &SyncTracker) {
+        let Some(state) = &self.node_state else { return };
+        if sync.is_live() {
+            state.set_synced();
+        } else {
+            let (head, tip) = sync.heights();
+            state.set_syncing(head, tip);
+        }
+    }
+
+    /// Start executing `block` and computing its state root ahead of verification.
+    ///
+    /// The block is executed against its parent snapshot on a task spawned on
+    /// `spawner`, and the outcome and root are cached under the block's digest
+    /// for verification to take. Does nothing in light mode, if the block is
+    /// already verified, or if its parent snapshot is not available yet.
+    pub async fn precompute_root<Sp: Spawner>(&self, spawner: Sp, block: &Block)
+    where
+        E: Send + Sync + 'static,
+    {
+        let digest = block.commitment();
+        if self.light.is_some() || self.is_verified(digest).await {
+            return;
+        }
+        let Some(parent) = self.ledger.parent_snapshot(block.parent()).await else {
+            return;
+        };
+        let ledger = self.ledger.clone();
+        let executor = self.executor.clone();
+        let context = self.block_context(block.height, block.prevrandao);
+        let parent_digest = block.parent();
+        let txs = block.txs.clone();
+        self.roots.start(spawner, digest, block.height, async move {
+            let execution =
+                BlockExecution::execute(&parent, &executor, &context, &txs).await.ok()?;
+            let root = ledger
+                .compute_root_from_store(parent_digest, execution.outcome.changes.clone())
+                .await
+                .ok()?;
+            Some(PrecomputedRoot { outcome: execution.outcome, root })
+        });
+        trace!(?digest, height = block.height, "started state root precomputation");
+    }
+
+    /// Cancel the precomputation for a block that will not be verified.
+    pub fn abandon(&self, digest: &ConsensusDigest) {
+        if self.roots.cancel(digest) {
+            trace!(?digest, "cancelled state root precomputation");
+        }
+    }
+
+    async fn is_verified(&self, digest: ConsensusDigest) -> bool {
+        if let Some(light) = &self.light {
+            return light.header(&digest).is_some();
+        }
+        self.ledger.query_state_root(digest).await.is_some()
+    }
+
     fn block_context(&self, height: u64, prevrandao: B256) -> BlockContext {
         let header = Header {
             number: height,
@@ -88,18 +238,33 @@ where
         let parent_snapshot = self.ledger.parent_snapshot(parent_digest).await?;
         let snapshot_elapsed = start.elapsed();
 
-        let (_, mempool, snapshots) = self.ledger.proposal_components().await;
-        let excluded = self.collect_pending_tx_ids(&snapshots, parent_digest);
-        let txs = mempool.build(self.max_txs, &excluded);
-
         let prevrandao = self.get_prevrandao(parent_digest).await;
         let height = parent.height + 1;
         let context = self.block_context(height, prevrandao);
-        let txs_bytes: Vec<Bytes> = txs.iter().map(|tx| tx.bytes.clone()).collect();
+
+        let (_, mempool, snapshots) = self.ledger.proposal_components().await;
+        let excluded = self.collect_pending_tx_ids(&snapshots, parent_digest);
+        let base_fee = context.header.base_fee_per_gas.unwrap_or_default();
+        // Rank the pool, and what it evicts, against the base fee of this block.
+        mempool.set_base_fee(base_fee);
+        let txs = select_transactions(
+            &mempool,
+            &excluded,
+            self.max_txs,
+            self.gas_limit,
+            base_fee,
+            &self.classifier,
+            &self.filter,
+        );
 
         let exec_start = Instant::now();
-        let outcome = self.executor.execute(&parent_snapshot.state, &context, &txs_bytes).ok()?;
+        let (txs, outcome, failed) =
+            self.execute_skipping_failed(&parent_snapshot.state, &context, txs)?;
         let exec_elapsed = exec_start.elapsed();
+        if !failed.is_empty() {
+            debug!(height, failed = failed.len(), "evicting transactions that failed to execute");
+            mempool.evict(&failed.iter().map(Tx::id).collect::<Vec<_>>());
+        }
 
         let root_start = Instant::now();
         let state_root = self
@@ -118,13 +283,20 @@ where
         self.ledger
             .insert_snapshot(
                 block_digest,
-                parent_digest,
-                next_state,
-                state_root,
-                outcome.changes,
-                &block.txs,
+                BlockSnapshot {
+                    parent: parent_digest,
+                    state: next_state,
+                    root: state_root,
+                    changes: outcome.changes,
+                    txs: &block.txs,
+                    intent_receipts: outcome.intent_receipts,
+                },
             )
             .await;
+        if let Some(sync) = &self.sync {
+            sync.observe_head(height);
+            self.report_sync(sync);
+        }
 
         let total_elapsed = start.elapsed();
         info!(
@@ -140,49 +312,122 @@ where
         Some(block)
     }
 
+    /// Execute `txs` on `state`, leaving out transactions that fail.
+    ///
+    /// The batch is executed as a whole first. If a transaction in it fails, the
+    /// block is rebuilt one transaction at a time, keeping each one that executes
+    /// on top of those already kept. Returns the kept transactions, their outcome
+    /// and the failed ones, or `None` if execution fails for a reason no single
+    /// transaction accounts for, such as a state read error or the timeout.
+    fn execute_skipping_failed(
+        &self,
+        state: &OverlayState<QmdbState>,
+        context: &BlockContext,
+        txs: Vec<Tx>,
+    ) -> Option<(Vec<Tx>, ExecutionOutcome, Vec<Tx>)> {
+        fn encoded(txs: &[Tx]) -> Vec<Bytes> {
+            txs.iter().map(|tx| tx.bytes.clone()).collect()
+        }
+        const fn is_tx_failure(err: &ExecutionError) -> bool {
+            matches!(
+                err,
+                ExecutionError::TxDecode(_)
+                    | ExecutionError::TxExecution(_)
+                    | ExecutionError::InvalidTx(_)
+            )
+        }
+
+        match self.executor.execute(state, context, &encoded(&txs)) {
+            Ok(outcome) => return Some((txs, outcome, Vec::new())),
+            Err(err) if is_tx_failure(&err) => {
+                debug!(error = %err, "retrying block without failing transactions");
+            }
+            Err(err) => {
+                warn!(error = %err, "block execution failed");
+                return None;
+            }
+        }
+
+        let mut outcome = self.executor.execute(state, context, &[]).ok()?;
+        let mut kept = Vec::with_capacity(txs.len());
+        let mut failed = Vec::new();
+        for tx in txs {
+            kept.push(tx);
+            match self.executor.execute(state, context, &encoded(&kept)) {
+                Ok(next) => outcome = next,
+                Err(err) if is_tx_failure(&err) => {
+                    let tx = kept.pop().expect("just pushed");
+                    trace!(id = ?tx.id(), error = %err, "transaction failed to execute");
+                    failed.push(tx);
+                }
+                Err(err) => {
+                    warn!(error = %err, "block execution failed");
+                    return None;
+                }
+            }
+        }
+        Some((kept, outcome, failed))
+    }
+
     async fn verify_block(&self, block: &Block) -> bool {
         let start = Instant::now();
         let digest = block.commitment();
         let parent_digest = block.parent();
 
-        if self.ledger.query_state_root(digest).await.is_some() {
+        if self.is_verified(digest).await {
             trace!(?digest, "block already verified");
             return true;
         }
 
+        if let Some(light) = &self.light {
+            return light.verify(block);
+        }
+
         let Some(parent_snapshot) = self.ledger.parent_snapshot(parent_digest).await else {
             warn!(?digest, ?parent_digest, height = block.height, "missing parent snapshot");
+            self.abandon(&digest);
             return false;
         };
         let snapshot_elapsed = start.elapsed();
 
-        let context = self.block_context(block.height, block.prevrandao);
+        // Execution and root computation, or the wait for their precomputed result.
         let exec_start = Instant::now();
-        let execution =
-            match BlockExecution::execute(&parent_snapshot, &self.executor, &context, &block.txs)
+        let (outcome, state_root) = match self.roots.take(&digest).await {
+            Some(precomputed) => {
+                trace!(?digest, "using precomputed state root");
+                let PrecomputedRoot { outcome, root } = (*precomputed).clone();
+                (outcome, root)
+            }
+            None => {
+                let context = self.block_context(block.height, block.prevrandao);
+                let execution = match BlockExecution::execute(
+                    &parent_snapshot,
+                    &self.executor,
+                    &context,
+                    &block.txs,
+                )
                 .await
-            {
-                Ok(result) => result,
-                Err(err) => {
-                    warn!(?digest, error = ?err, "execution failed");
-                    return false;
+                {
+                    Ok(result) => result,
+                    Err(err) => {
+                        warn!(?digest, error = ?err, "execution failed");
+                        return false;
+                    }
+                };
+                match self
+                    .ledger
+                    .compute_root_from_store(parent_digest, execution.outcome.changes.clone())
+                    .await
+                {
+                    Ok(root) => (execution.outcome, root),
+                    Err(err) => {
+                        warn!(?digest, error = ?err, "compute root failed");
+                        return false;
+                    }
                 }
-            };
-        let exec_elapsed = exec_start.elapsed();
-
-        let root_start = Instant::now();
-        let state_root = match self
-            .ledger
-            .compute_root_from_store(parent_digest, execution.outcome.changes.clone())
-            .await
-        {
-            Ok(root) => root,
-            Err(err) => {
-                warn!(?digest, error = ?err, "compute root failed");
-                return false;
             }
         };
-        let root_elapsed = root_start.elapsed();
+        let exec_elapsed = exec_start.elapsed();
 
         if state_root != block.state_root {
             warn!(
@@ -194,19 +439,28 @@ where
             return false;
         }
 
-        let merged_changes = parent_snapshot.state.merge_changes(execution.outcome.changes.clone());
+        let merged_changes = parent_snapshot.state.merge_changes(outcome.changes.clone());
         let next_state = OverlayState::new(parent_snapshot.state.base(), merged_changes);
 
         self.ledger
             .insert_snapshot(
                 digest,
-                parent_digest,
-                next_state,
-                state_root,
-                execution.outcome.changes,
-                &block.txs,
+                BlockSnapshot {
+                    parent: parent_digest,
+                    state: next_state,
+                    root: state_root,
+                    changes: outcome.changes,
+                    txs: &block.txs,
+                    intent_receipts: outcome.intent_receipts,
+                },
             )
             .await;
+        // Competing blocks at this height will not be verified on this branch.
+        self.roots.cancel_through(block.height);
+        if let Some(sync) = &self.sync {
+            sync.observe_head(block.height);
+            self.report_sync(sync);
+        }
 
         let total_elapsed = start.elapsed();
         info!(
@@ -215,7 +469,6 @@ where
             txs = block.txs.len(),
             snapshot_ms = snapshot_elapsed.as_millis(),
             exec_ms = exec_elapsed.as_millis(),
-            root_ms = root_elapsed.as_millis(),
             total_ms = total_elapsed.as_millis(),
             "verified block"
         );
@@ -245,11 +498,12 @@ where
     }
 }
 
-impl<Env, S, E> Application<Env> for RevmApplication<S, E>
+impl<Env, S, E, F> Application<Env> for RevmApplication<S, E, F>
 where
     Env: Rng + Spawner + Metrics + Clock,
     S: CertScheme + Send + Sync + 'static,
     E: BlockExecutor<OverlayState<QmdbState>, Tx = Bytes> + Clone + Send + Sync + 'static,
+    F: TransactionFilter,
 {
     type SigningScheme = S;
     type Context = Context<ConsensusDigest, S::PublicKey>;
@@ -261,20 +515,49 @@ where
 
     fn propose(
         &mut self,
-        _context: (Env, Self::Context),
+        (env, _): (Env, Self::Context),
         mut ancestry: AncestorStream<Self::SigningScheme, Self::Block>,
     ) -> impl std::future::Future<Output = Option<Self::Block>> + Send {
         let node_state = self.node_state.clone();
         async move {
+            use monmouth_consensus::Mempool as _;
+
             let start = Instant::now();
             let parent = ancestry.next().await?;
             let ancestry_elapsed = start.elapsed();
 
+            if let Some(sync) = &self.sync {
+                sync.observe_tip(parent.height);
+                self.report_sync(sync);
+                if !sync.is_live() {
+                    let (head, tip) = sync.heights();
+                    debug!(head, tip, "catching up, skipping proposal");
+                    return None;
+                }
+            }
+
+            // Checked before pacing so a light validator does not hold its slot.
+            if self.light.is_some() {
+                warn!(height = parent.height + 1, "light validators do not propose");
+                return None;
+            }
+
+            if let Some(pacer) = &self.pacer {
+                let (_, mempool, _) = self.ledger.proposal_components().await;
+                pacer.wait(&env, || !mempool.is_empty()).await;
+            }
+
             let build_start = Instant::now();
             let block = self.build_block(&parent).await;
             let build_elapsed = build_start.elapsed();
+            // The leader builds on `parent`; precomputations for blocks competing
+            // with it or its ancestors are abandoned.
+            self.roots.cancel_through(parent.height);
 
             if let Some(ref b) = block {
+                if let Some(pacer) = &self.pacer {
+                    pacer.record_block(env.current());
+                }
                 if let Some(ref state) = node_state {
                     state.inc_proposed();
                 }
@@ -292,15 +575,16 @@ where
     }
 }
 
-impl<Env, S, E> VerifyingApplication<Env> for RevmApplication<S, E>
+impl<Env, S, E, F> VerifyingApplication<Env> for RevmApplication<S, E, F>
 where
     Env: Rng + Spawner + Metrics + Clock,
     S: CertScheme + Send + Sync + 'static,
     E: BlockExecutor<OverlayState<QmdbState>, Tx = Bytes> + Clone + Send + Sync + 'static,
+    F: TransactionFilter,
 {
     fn verify(
         &mut self,
-        _context: (Env, Self::Context),
+        (env, _): (Env, Self::Context),
         mut ancestry: AncestorStream<Self::SigningScheme, Self::Block>,
     ) -> impl std::future::Future<Output = bool> + Send {
         async move {
@@ -309,13 +593,16 @@ where
             // The ancestry stream yields tip-first (newest → oldest).
             // We only need to verify blocks that we haven't seen yet.
             // Collect blocks until we hit one we've already verified.
+            // A block whose parent is already verified starts executing as soon as
+            // it arrives, while the rest of the ancestry is still being fetched.
             let mut blocks_to_verify = Vec::new();
             while let Some(block) = ancestry.next().await {
                 let digest = block.commitment();
                 // Stop if we've already verified this block
-                if self.ledger.query_state_root(digest).await.is_some() {
+                if self.is_verified(digest).await {
                     break;
                 }
+                self.precompute_root(env.clone(), &block).await;
                 blocks_to_verify.push(block);
             }
             let ancestry_elapsed = start.elapsed();
@@ -328,6 +615,10 @@ where
 
             let block_count = blocks_to_verify.len();
             let tip_height = blocks_to_verify.first().map(|b| b.height).unwrap_or(0);
+            if let Some(sync) = &self.sync {
+                sync.observe_tip(tip_height);
+                self.report_sync(sync);
+            }
 
             // Verify from oldest (parent) to newest (tip)
             let verify_start = Instant::now();
@@ -338,6 +629,9 @@ where
             }
             let verify_elapsed = verify_start.elapsed();
             let total_elapsed = start.elapsed();
+            if let Some(pacer) = &self.pacer {
+                pacer.record_block(env.current());
+            }
 
             info!(
                 tip_height,
@@ -352,3 +646,180 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::U256;
+    use commonware_runtime::{Runner as _, tokio};
+    use k256::ecdsa::SigningKey;
+    use monmouth_consensus::Mempool as _;
+    use monmouth_domain::evm::Evm;
+    use monmouth_executor::RevmExecutor;
+    use monmouth_ledger::LedgerView;
+    use monmouth_simplex::DefaultPool;
+
+    use super::*;
+
+    const CHAIN_ID: u64 = 1337;
+
+    fn transfer(key: &SigningKey) -> Tx {
+        Evm::sign_eip1559_transfer(
+            key,
+            CHAIN_ID,
+            Address::repeat_byte(0x33),
+            U256::from(100u64),
+            0,
+            21_000,
+        )
+    }
+
+    #[test]
+    fn build_block_skips_and_evicts_failing_transactions() {
+        let runner = tokio::Runner::default();
+        runner.start(|context| async move {
+            let funded = SigningKey::from_bytes(&[1u8; 32].into()).expect("valid key");
+            let unfunded = SigningKey::from_bytes(&[2u8; 32].into()).expect("valid key");
+            let run_id =
+                context.current().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos();
+            let view = LedgerView::init(
+                context,
+                DefaultPool::init(),
+                format!("app-build-block-{run_id}"),
+                vec![(Evm::address_from_key(&funded), U256::from(1_000_000u64))],
+                None,
+            )
+            .await
+            .expect("init ledger");
+            let ledger = LedgerService::new(view);
+
+            // Bypass admission so the builder meets a transaction that fails to execute.
+            let good = transfer(&funded);
+            let broke = transfer(&unfunded);
+            let (_, mempool, _) = ledger.proposal_components().await;
+            for tx in [&broke, &good] {
+                assert!(mempool.insert(tx.clone()));
+            }
+
+            let genesis = ledger.genesis_block();
+            let app =
+                RevmApplication::<(), _>::new(ledger, RevmExecutor::new(CHAIN_ID), 64, 30_000_000);
+            let block = app.build_block(&genesis).await.expect("block is built");
+
+            assert_eq!(block.txs, vec![good.clone()]);
+            assert_eq!(mempool.build(usize::MAX, &BTreeSet::new()), vec![good]);
+        });
+    }
+
+    #[test]
+    fn precomputed_root_is_cached_at_verification() {
+        let runner = tokio::Runner::default();
+        runner.start(|context| async move {
+            let key = SigningKey::from_bytes(&[1u8; 32].into()).expect("valid key");
+            let alloc = vec![(Evm::address_from_key(&key), U256::from(1_000_000u64))];
+            let run_id =
+                context.current().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos();
+            let mut ledgers = Vec::new();
+            for role in ["proposer", "verifier"] {
+                let view = LedgerView::init(
+                    context.clone(),
+                    DefaultPool::init(),
+                    format!("app-precompute-{role}-{run_id}"),
+                    alloc.clone(),
+                    None,
+                )
+                .await
+                .expect("init ledger");
+                ledgers.push(LedgerService::new(view));
+            }
+            let verifier_ledger = ledgers.pop().expect("verifier ledger");
+            let proposer_ledger = ledgers.pop().expect("proposer ledger");
+
+            let (_, mempool, _) = proposer_ledger.proposal_components().await;
+            assert!(mempool.insert(transfer(&key)));
+            let genesis = proposer_ledger.genesis_block();
+            let proposer = RevmApplication::<(), _>::new(
+                proposer_ledger,
+                RevmExecutor::new(CHAIN_ID),
+                64,
+                30_000_000,
+            );
+            let block = proposer.build_block(&genesis).await.expect("block is built");
+            let digest = block.commitment();
+
+            let verifier = RevmApplication::<(), _>::new(
+                verifier_ledger,
+                RevmExecutor::new(CHAIN_ID),
+                64,
+                30_000_000,
+            );
+            verifier.precompute_root(context.clone(), &block).await;
+            for _ in 0..1_000 {
+                if verifier.roots.ready(&digest).is_some() {
+                    break;
+                }
+                context.sleep(std::time::Duration::from_millis(1)).await;
+            }
+
+            // The root is cached before verification asks for it.
+            assert_eq!(verifier.roots.ready(&digest), Some(block.state_root));
+            assert!(verifier.verify_block(&block).await);
+            assert!(verifier.roots.is_empty());
+            assert_eq!(verifier.ledger.query_state_root(digest).await, Some(block.state_root));
+        });
+    }
+
+    /// Filter rejecting a single transaction.
+    #[derive(Clone)]
+    struct DenyTx(monmouth_consensus::TxId);
+
+    impl TransactionFilter for DenyTx {
+        fn allow(
+            &self,
+            tx: &Tx,
+            _classification: &monmouth_executor::ClassificationResult,
+        ) -> bool {
+            tx.id() != self.0
+        }
+    }
+
+    #[test]
+    fn build_block_fills_past_and_evicts_filtered_transactions() {
+        let runner = tokio::Runner::default();
+        runner.start(|context| async move {
+            let keys: Vec<_> = (1..=3u8)
+                .map(|byte| SigningKey::from_bytes(&[byte; 32].into()).expect("valid key"))
+                .collect();
+            let run_id =
+                context.current().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos();
+            let view = LedgerView::init(
+                context,
+                DefaultPool::init(),
+                format!("app-build-filtered-{run_id}"),
+                keys.iter()
+                    .map(|key| (Evm::address_from_key(key), U256::from(1_000_000u64)))
+                    .collect(),
+                None,
+            )
+            .await
+            .expect("init ledger");
+            let ledger = LedgerService::new(view);
+
+            let txs: Vec<_> = keys.iter().map(transfer).collect();
+            let (_, mempool, _) = ledger.proposal_components().await;
+            for tx in &txs {
+                assert!(mempool.insert(tx.clone()));
+            }
+
+            let genesis = ledger.genesis_block();
+            let app =
+                RevmApplication::<(), _>::new(ledger, RevmExecutor::new(CHAIN_ID), 2, 30_000_000)
+                    .with_filter(DenyTx(txs[0].id()));
+            let block = app.build_block(&genesis).await.expect("block is built");
+
+            assert_eq!(block.txs.len(), 2);
+            assert!(!block.txs.contains(&txs[0]));
+            assert_eq!(mempool.len(), 2);
+            assert!(!mempool.build(usize::MAX, &BTreeSet::new()).contains(&txs[0]));
+        });
+    }
+}
@@ -0,0 +1,95 @@
+//! Offline verification and repair of the finalized block archive.
+
+use std::path::Path;
+
+use commonware_runtime::{Metrics as _, Runner as _, tokio};
+use monmouth_config::{ConfigError, StorageConfig};
+use monmouth_domain::Block;
+use monmouth_marshal::{ArchiveInitializer, ArchiveOverrides, ArchiveRepair, ArchiveReport};
+
+use crate::{RunnerError, partition_name, resolve_partition_prefix, runner::block_codec_cfg};
+
+/// Store name of the finalized block archive under a node's partition prefix.
+pub const FINALIZED_BLOCKS_STORE: &str = "finalized-blocks";
+
+/// Archive overrides for the finalized block and certificate archives from
+/// the node's storage configuration.
+pub(crate) fn archive_overrides(storage: &StorageConfig) -> Result<ArchiveOverrides, ConfigError> {
+    Ok(ArchiveOverrides {
+        compression_level: storage.validated_archive_compression()?,
+        ..ArchiveOverrides::default()
+    })
+}
+
+/// Verify the finalized block archive under `partition_prefix` in `storage_directory`.
+///
+/// With `repair` set, the archive's ordinal index is then rebuilt from the stored
+/// blocks and the report after the rebuild is returned alongside the original.
+/// The node must not be running while the archive is checked.
+pub fn verify_block_archive(
+    storage_directory: &Path,
+    partition_prefix: &str,
+    repair: bool,
+) -> Result<(ArchiveReport, Option<ArchiveReport>), RunnerError> {
+    let derived = partition_prefix.to_string();
+    let config = tokio::Config::new().with_storage_directory(storage_directory);
+    tokio::Runner::new(config).start(|context| async move {
+        let prefix = resolve_partition_prefix(&context, &derived).await;
+        let partition = partition_name(&prefix, FINALIZED_BLOCKS_STORE);
+        if repair {
+            let (before, after) = ArchiveRepair::repair::<_, Block>(
+                context.with_label("archive_repair"),
+                &partition,
+                block_codec_cfg(),
+            )
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to repair block archive: {e}"))?;
+            return Ok((before, Some(after)));
+        }
+
+        let archive = ArchiveInitializer::init::<_, _, Block>(
+            context.with_label("archive_verify"),
+            partition,
+            block_codec_cfg(),
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("failed to open block archive: {e}"))?;
+        let report = ArchiveRepair::verify(&archive)
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to verify block archive: {e}"))?;
+        Ok((report, None))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_archive_compression_disables_compression() {
+        let storage = StorageConfig { archive_compression: Some(0), ..Default::default() };
+
+        let overrides = archive_overrides(&storage).unwrap();
+        let config = ArchiveInitializer::config("blocks", (), overrides);
+
+        assert_eq!(config.freezer_value_compression, None);
+    }
+
+    #[test]
+    fn unset_archive_compression_keeps_default() {
+        let overrides = archive_overrides(&StorageConfig::default()).unwrap();
+        let config = ArchiveInitializer::config("blocks", (), overrides);
+
+        assert_eq!(config.freezer_value_compression, ArchiveInitializer::DEFAULT_COMPRESSION_LEVEL);
+    }
+
+    #[test]
+    fn configured_archive_compression_sets_level() {
+        let storage = StorageConfig { archive_compression: Some(19), ..Default::default() };
+
+        let overrides = archive_overrides(&storage).unwrap();
+        let config = ArchiveInitializer::config("blocks", (), overrides);
+
+        assert_eq!(config.freezer_value_compression, Some(19));
+    }
+}
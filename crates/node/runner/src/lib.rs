@@ -10,11 +10,40 @@
 mod app;
 pub use app::RevmApplication;
 
+mod archive;
+pub use archive::{FINALIZED_BLOCKS_STORE, verify_block_archive};
+
 mod error;
 pub use error::RunnerError;
 
+mod light;
+pub use light::{DEFAULT_LIGHT_RETENTION, LightHeader, LightVerifier};
+
+mod mempool;
+
+mod pacer;
+pub use pacer::BlockPacer;
+
+mod partition;
+pub use partition::{
+    ConsensusPartitions, LEGACY_PARTITION_PREFIX, NodeRole, PartitionOverlap,
+    ensure_disjoint_partitions, partition_name, partition_prefix, resolve_partition_prefix,
+};
+
+mod precompute;
+pub use precompute::{PrecomputedRoot, RootPrecomputer};
+
+mod provider;
+pub use provider::DkgConsensusProvider;
+
 mod runner;
 pub use runner::ProductionRunner;
 
 mod scheme;
 pub use scheme::{ThresholdScheme, load_threshold_scheme};
+
+mod summary;
+pub use summary::StartupSummary;
+
+mod sync;
+pub use sync::{SyncMode, SyncTracker};
@@ -0,0 +1,264 @@
+//! Verify-only block verification for light validators.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use commonware_consensus::Block as _;
+use commonware_cryptography::Committable as _;
+use monmouth_domain::{Block, ConsensusDigest, StateRoot};
+use tracing::warn;
+
+/// Default number of heights below the highest accepted block for which a
+/// [`LightVerifier`] keeps accepted headers.
+pub const DEFAULT_LIGHT_RETENTION: u64 = 256;
+
+/// Height and state root of a block accepted by a [`LightVerifier`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LightHeader {
+    /// Block height.
+    pub height: u64,
+    /// State root claimed by the block.
+    pub state_root: StateRoot,
+}
+
+/// Verifies proposed blocks without re-executing them.
+///
+/// # Trust model
+///
+/// A light verifier holds no execution state, so it cannot recompute the state
+/// root of a block with transactions. It accepts a block when:
+///
+/// - the block extends a block it already accepted, at the next height;
+/// - the block contains no duplicate transactions;
+/// - a block without transactions keeps its parent's state root;
+/// - the state root matches the trusted checkpoint for the block's height, if one
+///   is configured.
+///
+/// Between checkpoints it trusts the proposer's claimed root for blocks with
+/// transactions. Proposer authenticity is not checked here: simplex only hands
+/// proposals carrying a valid leader signature to the application.
+///
+/// A validator in this mode has no state for the blocks it verifies and must not
+/// propose.
+///
+/// Accepted headers more than [`retention`](Self::with_retention) heights below
+/// the highest accepted block are dropped, so a block extending such an old
+/// parent is rejected.
+#[derive(Clone, Debug)]
+pub struct LightVerifier {
+    checkpoints: BTreeMap<u64, StateRoot>,
+    retention: u64,
+    accepted: Arc<Mutex<Accepted>>,
+}
+
+/// Headers accepted by a [`LightVerifier`].
+#[derive(Debug, Default)]
+struct Accepted {
+    headers: HashMap<ConsensusDigest, LightHeader>,
+    /// Highest accepted height.
+    tip: u64,
+}
+
+impl Default for LightVerifier {
+    fn default() -> Self {
+        Self {
+            checkpoints: BTreeMap::new(),
+            retention: DEFAULT_LIGHT_RETENTION,
+            accepted: Arc::default(),
+        }
+    }
+}
+
+impl LightVerifier {
+    /// Create a verifier with no trusted checkpoints.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep accepted headers for `heights` heights below the highest accepted block.
+    #[must_use]
+    pub const fn with_retention(mut self, heights: u64) -> Self {
+        self.retention = heights;
+        self
+    }
+
+    /// Trust `state_root` as the state root at `height`.
+    #[must_use]
+    pub fn with_checkpoint(mut self, height: u64, state_root: StateRoot) -> Self {
+        self.checkpoints.insert(height, state_root);
+        self
+    }
+
+    /// Record `header` for `digest` as accepted, e.g. for the genesis block.
+    pub fn trust(&self, digest: ConsensusDigest, header: LightHeader) {
+        let mut accepted = self.accepted.lock().unwrap_or_else(PoisonError::into_inner);
+        accepted.headers.insert(digest, header);
+        if header.height > accepted.tip {
+            accepted.tip = header.height;
+            let floor = header.height.saturating_sub(self.retention);
+            accepted.headers.retain(|_, header| header.height >= floor);
+        }
+    }
+
+    /// Returns the header of an accepted block.
+    pub fn header(&self, digest: &ConsensusDigest) -> Option<LightHeader> {
+        self.accepted.lock().unwrap_or_else(PoisonError::into_inner).headers.get(digest).copied()
+    }
+
+    /// Verify `block` and record it as accepted on success.
+    pub fn verify(&self, block: &Block) -> bool {
+        let digest = block.commitment();
+        let parent_digest = block.parent();
+
+        let Some(parent) = self.header(&parent_digest) else {
+            warn!(?digest, ?parent_digest, height = block.height, "missing light parent");
+            return false;
+        };
+
+        if block.height != parent.height + 1 {
+            warn!(?digest, parent = parent.height, height = block.height, "non-sequential height");
+            return false;
+        }
+
+        let mut seen = BTreeSet::new();
+        if !block.txs.iter().all(|tx| seen.insert(tx.id())) {
+            warn!(?digest, "duplicate transaction in block");
+            return false;
+        }
+
+        if block.txs.is_empty() && block.state_root != parent.state_root {
+            warn!(
+                ?digest,
+                parent = ?parent.state_root,
+                claimed = ?block.state_root,
+                "empty block changed state root"
+            );
+            return false;
+        }
+
+        if let Some(expected) = self.checkpoints.get(&block.height)
+            && *expected != block.state_root
+        {
+            warn!(
+                ?digest,
+                height = block.height,
+                expected = ?expected,
+                claimed = ?block.state_root,
+                "state root does not match checkpoint"
+            );
+            return false;
+        }
+
+        self.trust(digest, LightHeader { height: block.height, state_root: block.state_root });
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{B256, Bytes};
+    use monmouth_domain::{BlockId, Tx};
+
+    use super::*;
+
+    fn genesis() -> Block {
+        Block {
+            parent: BlockId(B256::ZERO),
+            height: 0,
+            prevrandao: B256::ZERO,
+            state_root: StateRoot(B256::repeat_byte(0x01)),
+            txs: Vec::new(),
+        }
+    }
+
+    fn child(parent: &Block, state_root: StateRoot, txs: Vec<Tx>) -> Block {
+        Block {
+            parent: parent.id(),
+            height: parent.height + 1,
+            prevrandao: B256::ZERO,
+            state_root,
+            txs,
+        }
+    }
+
+    fn verifier(genesis: &Block) -> LightVerifier {
+        let verifier = LightVerifier::new().with_checkpoint(2, StateRoot(B256::repeat_byte(0x02)));
+        verifier.trust(
+            genesis.commitment(),
+            LightHeader { height: genesis.height, state_root: genesis.state_root },
+        );
+        verifier
+    }
+
+    #[test]
+    fn accepts_well_formed_blocks() {
+        let genesis = genesis();
+        let verifier = verifier(&genesis);
+
+        let empty = child(&genesis, genesis.state_root, Vec::new());
+        assert!(verifier.verify(&empty));
+
+        let tx = Tx::new(Bytes::from_static(&[0x01]));
+        let checkpointed = child(&empty, StateRoot(B256::repeat_byte(0x02)), vec![tx]);
+        assert!(verifier.verify(&checkpointed));
+        assert_eq!(
+            verifier.header(&checkpointed.commitment()).map(|header| header.height),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn rejects_root_inconsistent_blocks() {
+        let genesis = genesis();
+        let verifier = verifier(&genesis);
+
+        let empty = child(&genesis, StateRoot(B256::repeat_byte(0xee)), Vec::new());
+        assert!(!verifier.verify(&empty));
+
+        let empty = child(&genesis, genesis.state_root, Vec::new());
+        assert!(verifier.verify(&empty));
+        let tx = Tx::new(Bytes::from_static(&[0x01]));
+        let wrong = child(&empty, StateRoot(B256::repeat_byte(0xee)), vec![tx]);
+        assert!(!verifier.verify(&wrong));
+        assert!(verifier.header(&wrong.commitment()).is_none());
+    }
+
+    #[test]
+    fn drops_headers_below_retention() {
+        let genesis = genesis();
+        let verifier = verifier(&genesis).with_retention(1);
+
+        let first = child(&genesis, genesis.state_root, Vec::new());
+        assert!(verifier.verify(&first));
+        assert!(verifier.header(&genesis.commitment()).is_some());
+
+        let tx = Tx::new(Bytes::from_static(&[0x01]));
+        let second = child(&first, StateRoot(B256::repeat_byte(0x02)), vec![tx]);
+        assert!(verifier.verify(&second));
+        assert!(verifier.header(&genesis.commitment()).is_none());
+        assert!(verifier.header(&first.commitment()).is_some());
+
+        // A fork off the dropped genesis can no longer be verified.
+        let fork = child(&genesis, genesis.state_root, vec![Tx::new(Bytes::from_static(&[0x02]))]);
+        assert!(!verifier.verify(&fork));
+    }
+
+    #[test]
+    fn rejects_structurally_invalid_blocks() {
+        let genesis = genesis();
+        let verifier = verifier(&genesis);
+
+        let tx = Tx::new(Bytes::from_static(&[0x01]));
+        let duplicate = child(&genesis, genesis.state_root, vec![tx.clone(), tx]);
+        assert!(!verifier.verify(&duplicate));
+
+        let mut skipped = child(&genesis, genesis.state_root, Vec::new());
+        skipped.height = 5;
+        assert!(!verifier.verify(&skipped));
+
+        let orphan = child(&skipped, genesis.state_root, Vec::new());
+        assert!(!verifier.verify(&orphan));
+    }
+}
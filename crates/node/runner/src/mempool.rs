@@ -0,0 +1,71 @@
+//! `txpool_*` RPC source backed by the node's mempool.
+
+use alloy_consensus::TxEnvelope;
+use alloy_eips::eip2718::Decodable2718 as _;
+use monmouth_consensus::components::{InMemoryMempool, SenderTransactions};
+use monmouth_rpc::{PoolContent, PooledTransaction, TxPoolSource};
+
+/// Serves the node's [`InMemoryMempool`] to the `txpool` namespace.
+///
+/// Ready transactions are reported as pending and parked ones as queued.
+#[derive(Clone, Debug)]
+pub(crate) struct MempoolTxPool(pub(crate) InMemoryMempool);
+
+impl TxPoolSource for MempoolTxPool {
+    fn content(&self) -> PoolContent {
+        let content = self.0.content();
+        PoolContent { pending: pooled(content.ready), queued: pooled(content.parked) }
+    }
+}
+
+fn pooled(txs: SenderTransactions) -> Vec<PooledTransaction> {
+    txs.into_iter()
+        .flat_map(|(sender, txs)| txs.into_values().map(move |tx| (sender, tx)))
+        .filter_map(|(sender, tx)| {
+            let envelope = TxEnvelope::decode_2718(&mut tx.bytes.as_ref()).ok()?;
+            Some(PooledTransaction { sender, envelope })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_consensus::Transaction as _;
+    use alloy_primitives::{Address, U256};
+    use k256::ecdsa::SigningKey;
+    use monmouth_consensus::Mempool as _;
+    use monmouth_domain::evm::Evm;
+
+    use super::*;
+
+    #[test]
+    fn ready_is_pending_and_parked_is_queued() {
+        let key = SigningKey::from_bytes(&[1u8; 32].into()).expect("valid key");
+        let sender = Evm::address_from_key(&key);
+        let transfer = |nonce| {
+            Evm::sign_eip1559_transfer(
+                &key,
+                1337,
+                Address::repeat_byte(0x33),
+                U256::from(100u64),
+                nonce,
+                21_000,
+            )
+        };
+        let mempool = InMemoryMempool::new();
+        mempool.set_next_nonce(sender, 0);
+        for nonce in [0, 1, 3] {
+            mempool.insert(transfer(nonce));
+        }
+
+        let content = MempoolTxPool(mempool).content();
+        let nonces = |txs: &[PooledTransaction]| {
+            txs.iter()
+                .inspect(|tx| assert_eq!(tx.sender, sender))
+                .map(|tx| tx.envelope.nonce())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(nonces(&content.pending), vec![0, 1]);
+        assert_eq!(nonces(&content.queued), vec![3]);
+    }
+}
@@ -0,0 +1,176 @@
+//! Block-production pacing between a minimum and maximum block time.
+
+use std::{
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, SystemTime},
+};
+
+use commonware_runtime::Clock;
+use monmouth_config::BlockInterval;
+
+/// How often a leader re-checks the mempool while waiting for transactions.
+const TX_POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+/// Paces proposals according to a configured [`BlockInterval`].
+///
+/// A proposal never starts earlier than `min` after the last observed block, even
+/// under load. When no transactions are pending, the leader keeps waiting until
+/// `max` after the last block and then proposes an empty block. Clones share the
+/// last observed block time.
+#[derive(Debug, Clone)]
+pub struct BlockPacer {
+    interval: BlockInterval,
+    last_block: Arc<Mutex<Option<SystemTime>>>,
+}
+
+impl BlockPacer {
+    /// Create a pacer for the given interval.
+    pub fn new(interval: BlockInterval) -> Self {
+        Self { interval, last_block: Arc::new(Mutex::new(None)) }
+    }
+
+    /// Returns the configured interval.
+    pub const fn interval(&self) -> BlockInterval {
+        self.interval
+    }
+
+    /// Returns the time the most recent block was observed, if any.
+    pub fn last_block(&self) -> Option<SystemTime> {
+        *self.last_block.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Record that a block was proposed or verified at `at`.
+    ///
+    /// Earlier timestamps than the one already recorded are ignored.
+    pub fn record_block(&self, at: SystemTime) {
+        let mut last = self.last_block.lock().unwrap_or_else(PoisonError::into_inner);
+        if last.is_none_or(|prev| at > prev) {
+            *last = Some(at);
+        }
+    }
+
+    /// Wait until a new block may be proposed.
+    ///
+    /// Returns immediately if no block has been observed yet. Otherwise sleeps until
+    /// the minimum block time has passed, then waits for `has_txs` to report pending
+    /// transactions or for the maximum block time to pass, whichever comes first.
+    pub async fn wait<C: Clock>(&self, clock: &C, has_txs: impl Fn() -> bool) {
+        let Some(last) = self.last_block() else {
+            return;
+        };
+
+        let earliest = last + self.interval.min;
+        if clock.current() < earliest {
+            clock.sleep_until(earliest).await;
+        }
+
+        let deadline = last + self.interval.max;
+        while !has_txs() {
+            let Ok(remaining) = deadline.duration_since(clock.current()) else {
+                break;
+            };
+            if remaining.is_zero() {
+                break;
+            }
+            clock.sleep(remaining.min(TX_POLL_INTERVAL)).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use commonware_runtime::{Runner as _, deterministic};
+
+    use super::*;
+
+    fn interval(min_ms: u64, max_ms: u64) -> BlockInterval {
+        BlockInterval { min: Duration::from_millis(min_ms), max: Duration::from_millis(max_ms) }
+    }
+
+    #[test]
+    fn produced_blocks_respect_min_spacing_under_load() {
+        deterministic::Runner::default().start(|context| async move {
+            let pacer = BlockPacer::new(interval(200, 1_000));
+            let mut produced = Vec::new();
+
+            for _ in 0..5 {
+                // Transactions are always pending, so only the minimum applies.
+                pacer.wait(&context, || true).await;
+                let now = context.current();
+                pacer.record_block(now);
+                produced.push(now);
+            }
+
+            for pair in produced.windows(2) {
+                let spacing = pair[1].duration_since(pair[0]).unwrap();
+                assert!(spacing >= Duration::from_millis(200), "spacing {spacing:?} below min");
+                assert!(spacing < Duration::from_millis(1_000));
+            }
+        });
+    }
+
+    #[test]
+    fn empty_mempool_waits_for_max_block_time() {
+        deterministic::Runner::default().start(|context| async move {
+            let pacer = BlockPacer::new(interval(100, 500));
+            let start = context.current();
+            pacer.record_block(start);
+
+            pacer.wait(&context, || false).await;
+
+            let elapsed = context.current().duration_since(start).unwrap();
+            assert!(elapsed >= Duration::from_millis(500));
+            assert!(elapsed < Duration::from_millis(500) + TX_POLL_INTERVAL);
+        });
+    }
+
+    #[test]
+    fn arriving_transactions_end_wait_early() {
+        deterministic::Runner::default().start(|context| async move {
+            let pacer = BlockPacer::new(interval(100, 2_000));
+            let start = context.current();
+            pacer.record_block(start);
+
+            let arrived = Arc::new(AtomicBool::new(false));
+            let arrival = start + Duration::from_millis(300);
+            let flag = Arc::clone(&arrived);
+            let clock = context.clone();
+            pacer
+                .wait(&context, move || {
+                    if clock.current() >= arrival {
+                        flag.store(true, Ordering::SeqCst);
+                    }
+                    flag.load(Ordering::SeqCst)
+                })
+                .await;
+
+            let elapsed = context.current().duration_since(start).unwrap();
+            assert!(arrived.load(Ordering::SeqCst));
+            assert!(elapsed >= Duration::from_millis(300));
+            assert!(elapsed < Duration::from_millis(300) + TX_POLL_INTERVAL);
+        });
+    }
+
+    #[test]
+    fn first_block_is_not_delayed() {
+        deterministic::Runner::default().start(|context| async move {
+            let pacer = BlockPacer::new(interval(200, 1_000));
+            let start = context.current();
+
+            pacer.wait(&context, || false).await;
+
+            assert_eq!(context.current(), start);
+        });
+    }
+
+    #[test]
+    fn record_block_keeps_latest_time() {
+        let pacer = BlockPacer::new(BlockInterval::default());
+        let later = SystemTime::UNIX_EPOCH + Duration::from_secs(10);
+        pacer.record_block(later);
+        pacer.record_block(SystemTime::UNIX_EPOCH);
+        assert_eq!(pacer.last_block(), Some(later));
+    }
+}
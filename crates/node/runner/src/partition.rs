@@ -0,0 +1,260 @@
+//! Storage partition naming.
+
+use std::fmt;
+
+use commonware_runtime::Storage;
+use tracing::warn;
+
+use crate::FINALIZED_BLOCKS_STORE;
+
+/// Namespace shared by every partition this node creates.
+const PARTITION_NAMESPACE: &str = "monmouth";
+
+/// Prefix every node used before prefixes were derived per chain, role, and index.
+pub const LEGACY_PARTITION_PREFIX: &str = PARTITION_NAMESPACE;
+
+/// Role a node process plays, used to keep co-located nodes from sharing storage.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NodeRole {
+    /// Consensus participant.
+    #[default]
+    Validator,
+    /// Read-only replica following finalized blocks.
+    Replica,
+}
+
+impl NodeRole {
+    /// Stable name used in partition prefixes.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Validator => "validator",
+            Self::Replica => "replica",
+        }
+    }
+}
+
+impl fmt::Display for NodeRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Derive the storage partition prefix for a node.
+///
+/// The prefix is a pure function of `chain_id`, `role`, and `index`, so a node
+/// reopens the same partitions across restarts, while nodes on other chains or
+/// in other roles never touch them. The format is
+/// `monmouth-{chain_id}-{role}-{index}`; changing it orphans existing data.
+pub fn partition_prefix(chain_id: u64, role: NodeRole, index: u32) -> String {
+    format!("{PARTITION_NAMESPACE}-{chain_id}-{role}-{index}")
+}
+
+/// Name of the partition holding `store` under `prefix`.
+pub fn partition_name(prefix: &str, store: &str) -> String {
+    format!("{prefix}-{store}")
+}
+
+/// Whether a finalized block archive with at least one block exists under `prefix`.
+async fn has_finalized_blocks<S: Storage>(storage: &S, prefix: &str) -> bool {
+    let ordinal = format!("{}-ordinal", partition_name(prefix, FINALIZED_BLOCKS_STORE));
+    storage.scan(&ordinal).await.is_ok_and(|blobs| !blobs.is_empty())
+}
+
+/// Resolve the prefix a node opens its partitions under.
+///
+/// Stores created before prefixes were derived live under
+/// [`LEGACY_PARTITION_PREFIX`]. While the `derived` prefix holds no finalized
+/// blocks but the legacy prefix does, the legacy prefix is returned so the node
+/// reopens its existing chain instead of starting an empty one beside it.
+pub async fn resolve_partition_prefix<S: Storage>(storage: &S, derived: &str) -> String {
+    if derived != LEGACY_PARTITION_PREFIX
+        && !has_finalized_blocks(storage, derived).await
+        && has_finalized_blocks(storage, LEGACY_PARTITION_PREFIX).await
+    {
+        warn!(
+            legacy = LEGACY_PARTITION_PREFIX,
+            derived, "found existing store under the legacy partition prefix; keeping it"
+        );
+        return LEGACY_PARTITION_PREFIX.to_string();
+    }
+    derived.to_string()
+}
+
+/// Partitions written by one consensus component.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConsensusPartitions<'a> {
+    /// A single partition with exactly this name, e.g. the simplex journal.
+    Single(&'a str),
+    /// Every partition named `{prefix}-...`, e.g. an archive or the marshal actor.
+    Prefixed(&'a str),
+}
+
+impl ConsensusPartitions<'_> {
+    /// Whether a partition of this component may also be a QMDB partition.
+    ///
+    /// QMDB names its partitions `{execution}-...`.
+    fn overlaps(&self, execution: &str) -> bool {
+        let under = |name: &str, prefix: &str| {
+            name.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('-'))
+        };
+        match *self {
+            Self::Single(name) => under(name, execution),
+            Self::Prefixed(prefix) => {
+                prefix == execution || under(prefix, execution) || under(execution, prefix)
+            }
+        }
+    }
+
+    const fn name(&self) -> &str {
+        match self {
+            Self::Single(name) | Self::Prefixed(name) => name,
+        }
+    }
+}
+
+/// A consensus partition falls inside the QMDB partition namespace or contains it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartitionOverlap {
+    /// Overlapping consensus partition or partition prefix.
+    pub consensus: String,
+    /// QMDB partition prefix.
+    pub execution: String,
+}
+
+impl fmt::Display for PartitionOverlap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "consensus partition `{}` overlaps QMDB partitions under `{}`; \
+             consensus and state must use disjoint partition prefixes",
+            self.consensus, self.execution
+        )
+    }
+}
+
+impl std::error::Error for PartitionOverlap {}
+
+/// Check that consensus and state writes cannot land in the same partition.
+///
+/// QMDB derives its partitions as `{execution}-{store}-...`. Every partition in
+/// `consensus` must lie outside that namespace, and no prefixed consensus
+/// namespace may contain it.
+pub fn ensure_disjoint_partitions(
+    consensus: &[ConsensusPartitions<'_>],
+    execution: &str,
+) -> Result<(), PartitionOverlap> {
+    match consensus.iter().find(|partitions| partitions.overlaps(execution)) {
+        Some(partitions) => Err(PartitionOverlap {
+            consensus: partitions.name().to_string(),
+            execution: execution.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use commonware_runtime::{Blob as _, Runner as _, deterministic};
+
+    use super::*;
+
+    async fn write_block_index<S: Storage>(storage: &S, prefix: &str) {
+        let ordinal = format!("{}-ordinal", partition_name(prefix, FINALIZED_BLOCKS_STORE));
+        let (blob, _) = storage.open(&ordinal, b"0").await.unwrap();
+        blob.write_at(vec![1u8], 0).await.unwrap();
+        blob.sync().await.unwrap();
+    }
+
+    #[test]
+    fn fresh_node_uses_derived_prefix() {
+        deterministic::Runner::default().start(|context| async move {
+            let derived = partition_prefix(7750, NodeRole::Validator, 0);
+            assert_eq!(resolve_partition_prefix(&context, &derived).await, derived);
+        });
+    }
+
+    #[test]
+    fn existing_legacy_store_keeps_legacy_prefix() {
+        deterministic::Runner::default().start(|context| async move {
+            let derived = partition_prefix(7750, NodeRole::Validator, 0);
+            write_block_index(&context, LEGACY_PARTITION_PREFIX).await;
+            assert_eq!(resolve_partition_prefix(&context, &derived).await, LEGACY_PARTITION_PREFIX);
+
+            // Once the derived prefix holds blocks it takes precedence.
+            write_block_index(&context, &derived).await;
+            assert_eq!(resolve_partition_prefix(&context, &derived).await, derived);
+        });
+    }
+
+    #[test]
+    fn prefix_is_stable() {
+        assert_eq!(partition_prefix(7750, NodeRole::Validator, 2), "monmouth-7750-validator-2");
+        assert_eq!(
+            partition_prefix(7750, NodeRole::Validator, 2),
+            partition_prefix(7750, NodeRole::Validator, 2)
+        );
+    }
+
+    #[test]
+    fn chain_ids_yield_disjoint_partitions() {
+        // A prefix must not be a prefix of another chain's partition names.
+        let a = partition_name(&partition_prefix(1, NodeRole::Validator, 0), "qmdb");
+        let b = partition_name(&partition_prefix(11, NodeRole::Validator, 0), "qmdb");
+        assert_ne!(a, b);
+        assert!(!b.starts_with(&partition_prefix(1, NodeRole::Validator, 0)));
+    }
+
+    #[test]
+    fn roles_and_indices_yield_distinct_prefixes() {
+        let validator = partition_prefix(7750, NodeRole::Validator, 0);
+        assert_ne!(validator, partition_prefix(7750, NodeRole::Replica, 0));
+        assert_ne!(validator, partition_prefix(7750, NodeRole::Validator, 1));
+    }
+
+    #[test]
+    fn runner_layout_is_disjoint() {
+        let prefix = partition_prefix(7750, NodeRole::Validator, 0);
+        let finalizations = partition_name(&prefix, "finalizations-by-height");
+        let blocks = partition_name(&prefix, FINALIZED_BLOCKS_STORE);
+        let consensus = [
+            ConsensusPartitions::Single(&prefix),
+            ConsensusPartitions::Prefixed(&finalizations),
+            ConsensusPartitions::Prefixed(&blocks),
+            ConsensusPartitions::Prefixed("marshal"),
+        ];
+        assert!(ensure_disjoint_partitions(&consensus, &partition_name(&prefix, "qmdb")).is_ok());
+        // Sharing a leading substring without the separator is not an overlap.
+        assert!(
+            ensure_disjoint_partitions(
+                &[ConsensusPartitions::Prefixed("state-qmdbx")],
+                "state-qmdb"
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn overlapping_partitions_are_rejected() {
+        let prefix = partition_prefix(7750, NodeRole::Validator, 0);
+        let qmdb = partition_name(&prefix, "qmdb");
+
+        let err =
+            ensure_disjoint_partitions(&[ConsensusPartitions::Prefixed(&qmdb)], &qmdb).unwrap_err();
+        assert_eq!(err.consensus, qmdb);
+        assert!(err.to_string().contains("disjoint partition prefixes"), "{err}");
+
+        // A journal named like a QMDB partition.
+        let nested = partition_name(&qmdb, "accounts-log");
+        assert!(
+            ensure_disjoint_partitions(&[ConsensusPartitions::Single(&nested)], &qmdb).is_err()
+        );
+
+        // An archive whose partitions include every QMDB partition.
+        let err = ensure_disjoint_partitions(
+            &[ConsensusPartitions::Single(&prefix), ConsensusPartitions::Prefixed(&prefix)],
+            &qmdb,
+        )
+        .unwrap_err();
+        assert_eq!(err.consensus, prefix);
+    }
+}
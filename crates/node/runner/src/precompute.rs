@@ -0,0 +1,203 @@
+//! Speculative state-root precomputation.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use commonware_runtime::Spawner;
+use futures::{
+    FutureExt as _,
+    future::{AbortHandle, BoxFuture, Shared, abortable},
+};
+use monmouth_domain::{ConsensusDigest, StateRoot};
+use monmouth_executor::ExecutionOutcome;
+
+/// Execution outcome and state root of a block, computed ahead of its verification.
+#[derive(Clone, Debug)]
+pub struct PrecomputedRoot {
+    /// Outcome of executing the block against its parent snapshot.
+    pub outcome: ExecutionOutcome,
+    /// State root the outcome produces.
+    pub root: StateRoot,
+}
+
+type RootFuture = Shared<BoxFuture<'static, Option<Arc<PrecomputedRoot>>>>;
+
+struct Pending {
+    height: u64,
+    root: RootFuture,
+    abort: AbortHandle,
+}
+
+/// Cache of state roots computed ahead of verification, keyed by block digest.
+///
+/// Each root is computed on its own task as soon as [`start`](Self::start) is
+/// called, so by the time the block is verified the root is usually ready.
+/// Blocks abandoned before verification are [`cancel`](Self::cancel)led, which
+/// aborts the computation and drops the entry.
+#[derive(Clone, Default)]
+pub struct RootPrecomputer {
+    pending: Arc<Mutex<HashMap<ConsensusDigest, Pending>>>,
+}
+
+impl fmt::Debug for RootPrecomputer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RootPrecomputer").field("pending", &self.len()).finish()
+    }
+}
+
+impl RootPrecomputer {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<ConsensusDigest, Pending>> {
+        self.pending.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Spawn `compute` on `spawner` and cache its result under `digest`.
+    ///
+    /// `height` is the block's height, used by [`cancel_through`](Self::cancel_through).
+    /// A computation already running for `digest` is kept and `compute` dropped.
+    pub fn start<Sp, F>(&self, spawner: Sp, digest: ConsensusDigest, height: u64, compute: F)
+    where
+        Sp: Spawner,
+        F: Future<Output = Option<PrecomputedRoot>> + Send + 'static,
+    {
+        let mut pending = self.lock();
+        if pending.contains_key(&digest) {
+            return;
+        }
+        let (compute, abort) = abortable(compute);
+        let root = compute.map(|result| result.ok().flatten().map(Arc::new)).boxed().shared();
+        let task = root.clone();
+        spawner.shared(true).spawn(move |_| async move {
+            task.await;
+        });
+        pending.insert(digest, Pending { height, root, abort });
+    }
+
+    /// Returns the root for `digest` if its computation has already finished.
+    pub fn ready(&self, digest: &ConsensusDigest) -> Option<StateRoot> {
+        self.lock().get(digest)?.root.peek().cloned().flatten().map(|pre| pre.root)
+    }
+
+    /// Remove the entry for `digest` and wait for its result.
+    ///
+    /// Returns `None` if no computation was started, it was cancelled, or it
+    /// failed; callers then execute the block themselves.
+    pub async fn take(&self, digest: &ConsensusDigest) -> Option<Arc<PrecomputedRoot>> {
+        let pending = self.lock().remove(digest)?;
+        pending.root.await
+    }
+
+    /// Abort the computation for `digest`, e.g. because the block was abandoned.
+    ///
+    /// Returns `true` if an entry was removed.
+    pub fn cancel(&self, digest: &ConsensusDigest) -> bool {
+        self.lock().remove(digest).map(|pending| pending.abort.abort()).is_some()
+    }
+
+    /// Abort every computation for a block at or below `height`.
+    ///
+    /// Once a block at `height` is accepted, competing blocks at that height and
+    /// below will never be verified. Returns the number of entries removed.
+    pub fn cancel_through(&self, height: u64) -> usize {
+        let mut pending = self.lock();
+        let before = pending.len();
+        pending.retain(|_, entry| {
+            let keep = entry.height > height;
+            if !keep {
+                entry.abort.abort();
+            }
+            keep
+        });
+        before - pending.len()
+    }
+
+    /// Number of cached or in-flight computations.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if nothing is cached or in flight.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use alloy_primitives::B256;
+    use commonware_cryptography::{Hasher as _, sha256::Sha256};
+    use commonware_runtime::{Clock as _, Runner as _, deterministic};
+    use futures::channel::oneshot;
+
+    use super::*;
+
+    fn precomputed(byte: u8) -> PrecomputedRoot {
+        PrecomputedRoot {
+            outcome: ExecutionOutcome::default(),
+            root: StateRoot(B256::repeat_byte(byte)),
+        }
+    }
+
+    #[test]
+    fn precomputed_root_is_ready_before_it_is_taken() {
+        deterministic::Runner::default().start(|context| async move {
+            let roots = RootPrecomputer::new();
+            let digest = Sha256::hash(b"block");
+            let expected = precomputed(0x42);
+            let root = expected.root;
+
+            roots.start(context.clone(), digest, 1, async move { Some(expected) });
+            context.sleep(Duration::from_millis(1)).await;
+
+            assert_eq!(roots.ready(&digest), Some(root));
+            assert_eq!(roots.take(&digest).await.map(|pre| pre.root), Some(root));
+            assert!(roots.is_empty());
+        });
+    }
+
+    #[test]
+    fn cancelled_computation_yields_nothing() {
+        deterministic::Runner::default().start(|context| async move {
+            let roots = RootPrecomputer::new();
+            let digest = Sha256::hash(b"abandoned");
+            let (_release, gate) = oneshot::channel::<()>();
+
+            roots.start(context.clone(), digest, 1, async move {
+                gate.await.ok()?;
+                Some(precomputed(0x01))
+            });
+            context.sleep(Duration::from_millis(1)).await;
+            assert_eq!(roots.ready(&digest), None);
+
+            assert!(roots.cancel(&digest));
+            assert!(!roots.cancel(&digest));
+            assert!(roots.take(&digest).await.is_none());
+        });
+    }
+
+    #[test]
+    fn cancel_through_drops_competing_blocks() {
+        deterministic::Runner::default().start(|context| async move {
+            let roots = RootPrecomputer::new();
+            let (low, sibling, high) =
+                (Sha256::hash(b"low"), Sha256::hash(b"sibling"), Sha256::hash(b"high"));
+            roots.start(context.clone(), low, 1, async { Some(precomputed(0x01)) });
+            roots.start(context.clone(), sibling, 2, async { Some(precomputed(0x02)) });
+            roots.start(context.clone(), high, 3, async { Some(precomputed(0x03)) });
+
+            assert_eq!(roots.cancel_through(2), 2);
+            assert_eq!(roots.len(), 1);
+            assert!(roots.take(&high).await.is_some());
+        });
+    }
+}
@@ -0,0 +1,193 @@
+//! Consensus provider backed by a DKG-derived threshold scheme.
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+use commonware_consensus::{
+    CertifiableAutomaton, Relay, Reporter,
+    simplex::{
+        self,
+        elector::Random,
+        types::{Activity, Context},
+    },
+    types::{Epoch, ViewDelta},
+};
+use commonware_cryptography::{certificate::Scheme as _, ed25519};
+use commonware_p2p::Blocker;
+use commonware_parallel::Sequential;
+use commonware_runtime::buffer::PoolRef;
+use commonware_utils::NZUsize;
+use monmouth_builder::ConsensusProvider;
+use monmouth_domain::ConsensusDigest;
+use monmouth_simplex::DEFAULT_MAILBOX_SIZE;
+
+use crate::{RunnerError, ThresholdScheme};
+
+const LEADER_TIMEOUT: Duration = Duration::from_millis(500);
+const NOTARIZATION_TIMEOUT: Duration = Duration::from_secs(1);
+const NULLIFY_RETRY: Duration = Duration::from_secs(2);
+const FETCH_TIMEOUT: Duration = Duration::from_millis(500);
+const ACTIVITY_TIMEOUT: u64 = 20;
+const SKIP_TIMEOUT: u64 = 10;
+const FETCH_CONCURRENT: usize = 8;
+const JOURNAL_BUFFER: usize = 1024 * 1024;
+
+/// [`ConsensusProvider`] that drives simplex with a [`ThresholdScheme`] from a completed DKG.
+///
+/// Leaders are elected with the threshold VRF ([`Random`]). The automaton, relay,
+/// reporter, and blocker are supplied by the caller, typically the marshaled
+/// application, its reporters, and the p2p oracle.
+#[derive(Clone)]
+pub struct DkgConsensusProvider<B, A, R, Rp> {
+    scheme: ThresholdScheme,
+    blocker: B,
+    automaton: A,
+    relay: R,
+    reporter: Rp,
+    partition: String,
+    buffer_pool: PoolRef,
+}
+
+impl<B, A, R, Rp> std::fmt::Debug for DkgConsensusProvider<B, A, R, Rp> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DkgConsensusProvider")
+            .field("participants", &self.scheme.participants().len())
+            .field("partition", &self.partition)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B, A, R, Rp> DkgConsensusProvider<B, A, R, Rp> {
+    /// Create a provider from a DKG-derived scheme and the consensus components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scheme has no participants or holds no share for this
+    /// node, since such a scheme cannot sign votes.
+    pub fn new(
+        scheme: ThresholdScheme,
+        blocker: B,
+        automaton: A,
+        relay: R,
+        reporter: Rp,
+        partition: impl Into<String>,
+        buffer_pool: PoolRef,
+    ) -> Result<Self, RunnerError> {
+        if scheme.participants().is_empty() {
+            return Err(anyhow!("threshold scheme has no participants").into());
+        }
+        if scheme.me().is_none() {
+            return Err(anyhow!("threshold scheme holds no share for this node").into());
+        }
+        Ok(Self {
+            scheme,
+            blocker,
+            automaton,
+            relay,
+            reporter,
+            partition: partition.into(),
+            buffer_pool,
+        })
+    }
+
+    /// Returns the threshold scheme.
+    pub const fn scheme(&self) -> &ThresholdScheme {
+        &self.scheme
+    }
+}
+
+impl<B, A, R, Rp> ConsensusProvider for DkgConsensusProvider<B, A, R, Rp>
+where
+    B: Blocker<PublicKey = ed25519::PublicKey> + Clone,
+    A: CertifiableAutomaton<
+            Context = Context<ConsensusDigest, ed25519::PublicKey>,
+            Digest = ConsensusDigest,
+        > + Clone,
+    R: Relay<Digest = ConsensusDigest> + Clone,
+    Rp: Reporter<Activity = Activity<ThresholdScheme, ConsensusDigest>> + Clone,
+{
+    type Scheme = ThresholdScheme;
+    type Elector = Random;
+    type Blocker = B;
+    type Digest = ConsensusDigest;
+    type Automaton = A;
+    type Relay = R;
+    type Reporter = Rp;
+
+    fn simplex_config(
+        &self,
+    ) -> simplex::Config<
+        Self::Scheme,
+        Self::Elector,
+        Self::Blocker,
+        Self::Digest,
+        Self::Automaton,
+        Self::Relay,
+        Self::Reporter,
+        Self::Strategy,
+    > {
+        simplex::Config {
+            scheme: self.scheme.clone(),
+            elector: Random,
+            blocker: self.blocker.clone(),
+            automaton: self.automaton.clone(),
+            relay: self.relay.clone(),
+            reporter: self.reporter.clone(),
+            strategy: Sequential,
+            partition: self.partition.clone(),
+            mailbox_size: DEFAULT_MAILBOX_SIZE,
+            epoch: Epoch::zero(),
+            replay_buffer: NZUsize!(JOURNAL_BUFFER),
+            write_buffer: NZUsize!(JOURNAL_BUFFER),
+            leader_timeout: LEADER_TIMEOUT,
+            notarization_timeout: NOTARIZATION_TIMEOUT,
+            nullify_retry: NULLIFY_RETRY,
+            fetch_timeout: FETCH_TIMEOUT,
+            activity_timeout: ViewDelta::new(ACTIVITY_TIMEOUT),
+            skip_timeout: ViewDelta::new(SKIP_TIMEOUT),
+            fetch_concurrent: FETCH_CONCURRENT,
+            buffer_pool: self.buffer_pool.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use monmouth_builder::NodeBuilder;
+    use monmouth_service::{StubAutomaton, StubBlocker, StubRelay, StubReporter};
+    use monmouth_simplex::DefaultPool;
+
+    use super::*;
+
+    type TestProvider =
+        DkgConsensusProvider<StubBlocker, StubAutomaton, StubRelay, StubReporter<ThresholdScheme>>;
+
+    fn provider(scheme: ThresholdScheme) -> Result<TestProvider, RunnerError> {
+        DkgConsensusProvider::new(
+            scheme,
+            StubBlocker,
+            StubAutomaton,
+            StubRelay,
+            StubReporter::default(),
+            "dkg-provider-test",
+            DefaultPool::init(),
+        )
+    }
+
+    #[test]
+    fn simplex_config_uses_dkg_scheme() {
+        let (participants, schemes) = monmouth_crypto::threshold_schemes(7, 4).unwrap();
+        let provider = provider(schemes[0].clone()).expect("valid scheme");
+
+        let builder = NodeBuilder::new().with_consensus(provider);
+        let config = builder.consensus_provider().simplex_config();
+
+        assert_eq!(config.scheme.participants().len(), participants.len());
+        assert_eq!(config.scheme.me(), schemes[0].me());
+        assert_eq!(config.partition, "dkg-provider-test");
+        assert_eq!(config.epoch, Epoch::zero());
+        assert_eq!(config.mailbox_size, DEFAULT_MAILBOX_SIZE);
+        assert!(config.leader_timeout < config.notarization_timeout);
+        assert!(config.skip_timeout < config.activity_timeout);
+    }
+}
@@ -1,5 +1,6 @@
-use std::{net::SocketAddr, sync::Arc, time::Duration};
+use std::{net::SocketAddr, num::NonZeroU64, sync::Arc};
 
+use ::tokio::sync::broadcast;
 use alloy_consensus::Header;
 use alloy_primitives::{Address, B256};
 use anyhow::Context as _;
@@ -7,33 +8,47 @@ use axum::{Router, http::StatusCode, response::IntoResponse, routing::get};
 use commonware_consensus::{
     Reporters,
     application::marshaled::Marshaled,
-    simplex::{self, elector::Random, types::Finalization},
-    types::{Epoch, FixedEpocher, ViewDelta},
+    simplex::{self, types::Finalization},
+    types::{Epoch, FixedEpocher},
 };
 use commonware_cryptography::{bls12381::primitives::variant::MinSig, ed25519};
 use commonware_p2p::Manager;
-use commonware_parallel::Sequential;
 use commonware_runtime::{Metrics as _, Spawner, buffer::PoolRef, tokio};
-use commonware_utils::{NZU64, NZUsize, acknowledgement::Exact};
-use futures::StreamExt;
-use monmouth_domain::{Block, BlockCfg, BootstrapConfig, ConsensusDigest, LedgerEvent, TxCfg};
-use monmouth_executor::{BlockContext, ClassifierConfig, RevmExecutor, TransactionClassifier};
-use monmouth_ledger::{LedgerService, LedgerView};
-use monmouth_marshal::{ArchiveInitializer, BroadcastInitializer, PeerInitializer};
+use commonware_utils::acknowledgement::Exact;
+use futures::{FutureExt as _, StreamExt};
+use monmouth_builder::ConsensusProvider as _;
+use monmouth_domain::{
+    Block, BlockCfg, BootstrapConfig, CheckpointStore, ConsensusDigest, FinalizedNotification,
+    LedgerEvent, SenderCache, StateRoot, Tx, TxCfg,
+};
+use monmouth_executor::{
+    BlockContext, ClassifierConfig, ExecutionConfig, MonmouthPrecompiles, ParallelExecutor,
+    RevmExecutor, TransactionClassifier,
+};
+use monmouth_ledger::{DEFAULT_FINALIZED_CHANNEL_CAPACITY, LedgerService, LedgerView, TxGossip};
+use monmouth_marshal::{ArchiveInitializer, BroadcastInitializer, MarshalConfig, PeerInitializer};
 use monmouth_reporters::{
-    BlockContextProvider, FinalizedReporter, NodeStateReporter, SeedReporter,
+    BlockContextProvider, CheckpointRecorder, FinalizedReporter, NodeStateReporter, SeedReporter,
+    TraceRecorder,
 };
 use monmouth_service::{NodeRunContext, NodeRunner};
-use monmouth_simplex::{DEFAULT_MAILBOX_SIZE as MAILBOX_SIZE, DefaultPool};
+use monmouth_simplex::DefaultPool;
 use monmouth_transport::NetworkTransport;
+use monmouth_txpool::PoolConfig;
 use tracing::{debug, info, trace};
 
-use crate::{RevmApplication, RunnerError, scheme::ThresholdScheme};
+use crate::{
+    ConsensusPartitions, DkgConsensusProvider, FINALIZED_BLOCKS_STORE, LightVerifier, NodeRole,
+    RevmApplication, RunnerError, StartupSummary, SyncTracker,
+    archive::archive_overrides,
+    ensure_disjoint_partitions,
+    mempool::MempoolTxPool,
+    partition_name, partition_prefix, resolve_partition_prefix,
+    scheme::{ThresholdScheme, load_checkpoint_keys},
+};
 
 const BLOCK_CODEC_MAX_TXS: usize = 64;
 const BLOCK_CODEC_MAX_TX_BYTES: usize = 1024;
-const EPOCH_LENGTH: u64 = u64::MAX;
-const PARTITION_PREFIX: &str = "monmouth";
 
 type Peer = ed25519::PublicKey;
 type CertArchive = Finalization<ThresholdScheme, ConsensusDigest>;
@@ -44,10 +59,26 @@ fn default_buffer_pool() -> PoolRef {
     DefaultPool::init()
 }
 
-const fn block_codec_cfg() -> BlockCfg {
+pub(crate) const fn block_codec_cfg() -> BlockCfg {
     BlockCfg { max_txs: BLOCK_CODEC_MAX_TXS, tx: TxCfg { max_tx_bytes: BLOCK_CODEC_MAX_TX_BYTES } }
 }
 
+fn epoch_length(config: &monmouth_config::ConsensusConfig) -> Result<NonZeroU64, RunnerError> {
+    Ok(config
+        .validated_epoch_length()
+        .map_err(|e| anyhow::anyhow!("invalid consensus config: {}", e))?)
+}
+
+fn build_epocher(config: &monmouth_config::ConsensusConfig) -> Result<FixedEpocher, RunnerError> {
+    epoch_length(config).map(FixedEpocher::new)
+}
+
+/// Marshal settings sharing the consensus epoch length, so both sides agree
+/// on epoch boundaries.
+fn marshal_config(config: &monmouth_config::ConsensusConfig) -> Result<MarshalConfig, RunnerError> {
+    Ok(MarshalConfig::default().with_blocks_per_epoch(epoch_length(config)?))
+}
+
 #[derive(Clone)]
 struct ConstantSchemeProvider(Arc<ThresholdScheme>);
 
@@ -108,6 +139,54 @@ fn spawn_ledger_observers<S: Spawner>(service: LedgerService, spawner: S) {
     });
 }
 
+/// Advance the `eth_call` cache head on every finalized block.
+fn spawn_call_cache_invalidation(
+    cache: Arc<monmouth_rpc::CallCache>,
+    mut finalized: broadcast::Receiver<FinalizedNotification>,
+) {
+    ::tokio::spawn(async move {
+        loop {
+            match finalized.recv().await {
+                Ok(notification) => {
+                    cache.advance_head(B256::from_slice(notification.digest.as_ref()));
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug!(skipped, "call cache invalidation lagged behind finalization");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+/// Node services attached to the RPC server, whichever state provider backs it.
+struct RpcServices {
+    tx_submit: monmouth_rpc::TxSubmitCallback,
+    tx_pool: Arc<dyn monmouth_rpc::TxPoolSource>,
+    classifier: TransactionClassifier,
+    method_filter: monmouth_rpc::MethodFilter,
+}
+
+impl RpcServices {
+    /// Attach the services to `server` and apply the configured request limits.
+    fn configure<S: monmouth_rpc::StateProvider + Clone + 'static>(
+        self,
+        server: monmouth_rpc::RpcServer<S>,
+        settings: &monmouth_config::RpcConfig,
+    ) -> monmouth_rpc::RpcServer<S> {
+        server
+            .with_tx_submit(self.tx_submit)
+            .with_tx_pool(self.tx_pool)
+            .with_classifier(self.classifier)
+            .with_method_filter(self.method_filter)
+            .with_strict_params(settings.strict_params)
+            .with_execution_limit(
+                settings.max_concurrent_executions,
+                std::time::Duration::from_millis(settings.execution_queue_timeout_ms),
+            )
+    }
+}
+
 /// Production validator node runner.
 #[derive(Clone, Debug)]
 pub struct ProductionRunner {
@@ -119,7 +198,7 @@ pub struct ProductionRunner {
     pub gas_limit: u64,
     /// Bootstrap configuration.
     pub bootstrap: BootstrapConfig,
-    /// Storage partition prefix.
+    /// Storage partition prefix, derived from the chain ID and node role.
     pub partition_prefix: String,
     /// Optional RPC configuration (state, bind address).
     pub rpc_config: Option<(monmouth_rpc::NodeState, std::net::SocketAddr)>,
@@ -129,27 +208,75 @@ pub struct ProductionRunner {
     pub enable_agent_pool: bool,
     /// Confidence threshold for agent classification.
     pub confidence_threshold: f64,
+    /// Execution settings shared by every executor the node builds.
+    execution: ExecutionConfig,
+    /// Worker threads for block execution; values below 2 execute sequentially.
+    parallel_workers: usize,
+    /// Stateless checks and per-sender limit applied at mempool admission.
+    admission: PoolConfig,
+    /// Sender shared with the ledger for finalized block notifications.
+    finalized: broadcast::Sender<FinalizedNotification>,
+    /// Whether finalized blocks are traced for `debug_traceBlockByNumber`.
+    trace_finalized: bool,
+    /// Traces of recent finalized blocks, shared with the RPC provider.
+    block_traces: monmouth_rpc::BlockTraces,
+    /// Finalized blocks between signed checkpoints; zero disables them.
+    checkpoint_interval: u64,
+    /// Checkpoints this validator signed, with the partials collected for them.
+    checkpoints: CheckpointStore<MinSig>,
 }
 
 impl ProductionRunner {
     /// Create a new production runner.
+    ///
+    /// Collected priority fees are split as the genesis `bootstrap` specifies.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the genesis priority fee split is invalid.
     pub fn new(
         scheme: ThresholdScheme,
         chain_id: u64,
         gas_limit: u64,
         bootstrap: BootstrapConfig,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, RunnerError> {
+        let execution = ExecutionConfig::new(chain_id)
+            .with_priority_fee_split(bootstrap.priority_fee_split.clone())
+            .context("invalid genesis priority fee split")?;
+        Ok(Self {
             scheme,
             chain_id,
             gas_limit,
             bootstrap,
-            partition_prefix: PARTITION_PREFIX.to_string(),
+            partition_prefix: partition_prefix(chain_id, NodeRole::Validator, 0),
             rpc_config: None,
             metrics_addr: None,
             enable_agent_pool: false,
             confidence_threshold: monmouth_config::DEFAULT_CONFIDENCE_THRESHOLD,
-        }
+            execution,
+            parallel_workers: monmouth_config::DEFAULT_PARALLEL_WORKERS,
+            admission: PoolConfig::default(),
+            finalized: broadcast::channel(DEFAULT_FINALIZED_CHANNEL_CAPACITY).0,
+            trace_finalized: false,
+            block_traces: monmouth_rpc::BlockTraces::default(),
+            checkpoint_interval: 0,
+            checkpoints: CheckpointStore::default(),
+        })
+    }
+
+    /// Subscribe to a notification for each finalized block the node persists.
+    ///
+    /// May be called before the node starts. See
+    /// [`LedgerService::subscribe_finalized`] for the backpressure behavior.
+    pub fn subscribe_finalized(&self) -> broadcast::Receiver<FinalizedNotification> {
+        self.finalized.subscribe()
+    }
+
+    /// Derive the storage partition prefix for `role` and `index` on this chain.
+    #[must_use]
+    pub fn with_node_role(mut self, role: NodeRole, index: u32) -> Self {
+        self.partition_prefix = partition_prefix(self.chain_id, role, index);
+        self
     }
 
     /// Configure RPC server.
@@ -174,39 +301,96 @@ impl ProductionRunner {
         self
     }
 
-    /// Build a `RevmExecutor`, optionally with the agent classifier.
-    #[allow(clippy::missing_const_for_fn)]
-    fn build_executor(&self) -> RevmExecutor {
-        let executor = RevmExecutor::new(self.chain_id);
-        if self.enable_agent_pool {
-            let classifier = TransactionClassifier::new(ClassifierConfig {
+    /// Execute blocks on up to `workers` threads.
+    ///
+    /// Blocks execute sequentially by default and for values below 2.
+    #[must_use]
+    pub const fn with_parallel_workers(mut self, workers: usize) -> Self {
+        self.parallel_workers = workers;
+        self
+    }
+
+    /// Apply the mempool admission limits of `config`.
+    #[must_use]
+    pub const fn with_mempool_config(mut self, config: &monmouth_config::MempoolConfig) -> Self {
+        self.admission.max_txs_per_sender = config.max_txs_per_sender;
+        self.admission.max_tx_calldata_bytes = config.max_tx_calldata_bytes;
+        self
+    }
+
+    /// Sign a checkpoint of every `interval`-th finalized block with this
+    /// validator's key share, loaded from the DKG output in the data directory.
+    ///
+    /// Zero, the default, disables checkpoints.
+    #[must_use]
+    pub const fn with_checkpoint_interval(mut self, interval: u64) -> Self {
+        self.checkpoint_interval = interval;
+        self
+    }
+
+    /// Store of the checkpoints this validator signs.
+    ///
+    /// Partials from other validators added to it aggregate into signed
+    /// checkpoints; exchanging them between validators is not wired yet.
+    pub fn checkpoints(&self) -> CheckpointStore<MinSig> {
+        self.checkpoints.clone()
+    }
+
+    /// Build the agent classifier if agent features are enabled.
+    fn build_classifier(&self) -> Option<TransactionClassifier> {
+        self.enable_agent_pool.then(|| {
+            TransactionClassifier::new(ClassifierConfig {
                 confidence_threshold: self.confidence_threshold,
-                enabled: true,
-            });
-            executor.with_classifier(classifier)
-        } else {
-            executor
+                ..ClassifierConfig::default()
+            })
+        })
+    }
+
+    /// Build a `RevmExecutor` reusing the mempool's recovered senders, optionally
+    /// with the agent classifier.
+    fn build_executor(&self, sender_cache: SenderCache) -> RevmExecutor {
+        let mut config = self.execution.clone();
+        config.chain_id = self.chain_id;
+        let executor = RevmExecutor::with_config(config).with_sender_cache(sender_cache);
+        match self.build_classifier() {
+            Some(classifier) => executor.with_classifier(classifier),
+            None => executor,
         }
     }
+
+    /// Build the executor that builds, verifies and replays blocks, running them
+    /// in parallel when [`with_parallel_workers`](Self::with_parallel_workers)
+    /// allows more than one worker.
+    fn build_block_executor(&self, sender_cache: SenderCache) -> ParallelExecutor {
+        ParallelExecutor::new(self.build_executor(sender_cache)).with_workers(self.parallel_workers)
+    }
 }
 
 impl ProductionRunner {
     /// Run the validator as a standalone process.
-    pub fn run_standalone(self, config: monmouth_config::NodeConfig) -> Result<(), RunnerError> {
+    pub fn run_standalone(
+        mut self,
+        config: monmouth_config::NodeConfig,
+    ) -> Result<(), RunnerError> {
         use commonware_runtime::Runner;
         use monmouth_transport::NetworkConfigExt;
 
         let rpc_config = self.rpc_config.clone();
+        let method_filter = monmouth_rpc::MethodFilter::new(
+            config.rpc.allowed_methods.clone(),
+            config.rpc.denied_methods.clone(),
+        );
+        // Tracing re-executes every finalized block, so only pay for it when
+        // the debug endpoint is served.
+        self.trace_finalized =
+            rpc_config.is_some() && method_filter.is_allowed("debug_traceBlockByNumber");
+        let rpc_settings = config.rpc.clone();
         let metrics_addr = self.metrics_addr;
+        let chain_id = self.chain_id;
+        let classifier = self.build_classifier().unwrap_or_else(TransactionClassifier::disabled);
 
         let executor = tokio::Runner::default();
         executor.start(|context| async move {
-            // Start RPC server if configured
-            if let Some((state, addr)) = rpc_config {
-                let rpc = monmouth_rpc::RpcServer::new(state, addr);
-                drop(rpc.start());
-            }
-
             // Start metrics server if configured
             if let Some(addr) = metrics_addr {
                 let metrics_ctx = context.clone();
@@ -230,7 +414,46 @@ impl ProductionRunner {
                 transport,
             );
 
-            let _ledger = self.run(ctx).await?;
+            let ledger = self.run(ctx).await?;
+
+            // Start RPC server if configured, serving account state from the
+            // latest persisted finalized block.
+            if let Some((state, addr)) = rpc_config {
+                let provider = monmouth_rpc::IndexedStateProvider::new(
+                    Arc::new(monmouth_indexer::BlockIndex::new()),
+                    ledger.persisted_state().await,
+                )
+                .with_block_traces(self.block_traces.clone());
+                let submit_ledger = ledger.clone();
+                let tx_submit: monmouth_rpc::TxSubmitCallback = Arc::new(move |bytes, sender| {
+                    let ledger = submit_ledger.clone();
+                    async move {
+                        ledger
+                            .submit_recovered_tx(Tx::new(bytes), sender)
+                            .await
+                            .map_err(|err| err.to_string())
+                    }
+                    .boxed()
+                });
+                let tx_pool = Arc::new(MempoolTxPool(ledger.mempool().await));
+                let rpc = RpcServices { tx_submit, tx_pool, classifier, method_filter };
+                if rpc_settings.call_cache_capacity == 0 {
+                    let server = monmouth_rpc::RpcServer::with_state_provider(
+                        state, addr, chain_id, provider,
+                    );
+                    drop(rpc.configure(server, &rpc_settings).start());
+                } else {
+                    let provider = monmouth_rpc::CachingStateProvider::new(
+                        provider,
+                        rpc_settings.call_cache_capacity,
+                    );
+                    spawn_call_cache_invalidation(provider.cache(), ledger.subscribe_finalized());
+                    let server = monmouth_rpc::RpcServer::with_state_provider(
+                        state, addr, chain_id, provider,
+                    );
+                    drop(rpc.configure(server, &rpc_settings).start());
+                }
+            }
 
             futures::future::pending::<()>().await;
             Ok::<(), RunnerError>(())
@@ -246,25 +469,45 @@ impl NodeRunner for ProductionRunner {
     async fn run(&self, ctx: NodeRunContext<Self::Transport>) -> Result<Self::Handle, Self::Error> {
         let (context, config, mut transport) = ctx.into_parts();
 
-        info!(chain_id = self.chain_id, "Starting production validator");
-
         let validators = self.scheme.participants().clone();
         transport.oracle.update(0, validators).await;
-        info!(count = self.scheme.participants().len(), "Registered validators with oracle");
+        debug!(count = self.scheme.participants().len(), "Registered validators with oracle");
 
         let buffer_pool = default_buffer_pool();
         let block_cfg = block_codec_cfg();
 
+        let partition_prefix = resolve_partition_prefix(&context, &self.partition_prefix).await;
+        let qmdb_partition = partition_name(&partition_prefix, "qmdb");
+        let finalizations_partition = partition_name(&partition_prefix, "finalizations-by-height");
+        let blocks_partition = partition_name(&partition_prefix, FINALIZED_BLOCKS_STORE);
+        let marshal = marshal_config(&config.consensus)?
+            .with_start_height(self.bootstrap.fork.map_or(0, |fork| fork.height));
+        ensure_disjoint_partitions(
+            &[
+                ConsensusPartitions::Single(&partition_prefix),
+                ConsensusPartitions::Prefixed(&finalizations_partition),
+                ConsensusPartitions::Prefixed(&blocks_partition),
+                ConsensusPartitions::Prefixed(&marshal.partition_prefix),
+            ],
+            &qmdb_partition,
+        )
+        .map_err(anyhow::Error::from)?;
         let state = LedgerView::init(
             context.with_label("state"),
             buffer_pool.clone(),
-            format!("{}-qmdb", self.partition_prefix),
+            qmdb_partition,
             self.bootstrap.genesis_alloc.clone(),
+            self.bootstrap.fork,
         )
         .await
         .context("init qmdb")?;
+        state.set_admission(self.chain_id, self.admission.clone()).await;
+        if let Some(retention) = config.storage.snapshot_retention {
+            state.set_snapshot_retention(retention).await;
+        }
 
-        let ledger = LedgerService::new(state.clone());
+        let ledger =
+            LedgerService::new(state.clone()).with_finalized_sender(self.finalized.clone());
         spawn_ledger_observers(ledger.clone(), context.clone());
 
         let validator_key = config
@@ -272,10 +515,42 @@ impl NodeRunner for ProductionRunner {
             .map_err(|e| anyhow::anyhow!("failed to load validator key: {}", e))?;
         let my_pk = commonware_cryptography::Signer::public_key(&validator_key);
 
-        let executor = self.build_executor();
+        let participants = self.scheme.participants().len();
+        StartupSummary {
+            chain_id: self.chain_id,
+            genesis_hash: ledger.genesis_block().id().0,
+            validator: my_pk.clone(),
+            participants,
+            threshold: StartupSummary::threshold_for(participants),
+            data_dir: config.data_dir.clone(),
+            rpc_addr: self.rpc_config.as_ref().map(|(_, addr)| *addr),
+            metrics_addr: self.metrics_addr,
+            precompiles: MonmouthPrecompiles::CUSTOM_ADDRESSES.to_vec(),
+        }
+        .log();
+
+        let sender_cache = ledger.sender_cache().await;
+        let executor = self.build_block_executor(sender_cache.clone());
         let context_provider = RevmContextProvider { gas_limit: self.gas_limit };
-        let finalized_reporter =
+        let mut finalized_reporter =
             FinalizedReporter::new(ledger.clone(), context.clone(), executor, context_provider);
+        if self.trace_finalized {
+            let recorder = TraceRecorder::new(
+                self.build_executor(sender_cache.clone()),
+                self.block_traces.clone(),
+            );
+            finalized_reporter = finalized_reporter.with_trace_recorder(recorder);
+        }
+        if self.checkpoint_interval != 0 {
+            let (share, sharing) = load_checkpoint_keys(&config.data_dir)?;
+            let recorder = CheckpointRecorder::new(
+                self.checkpoint_interval,
+                share,
+                sharing,
+                self.checkpoints.clone(),
+            );
+            finalized_reporter = finalized_reporter.with_checkpoint_recorder(recorder);
+        }
 
         let scheme_provider = ConstantSchemeProvider::from(self.scheme.clone());
 
@@ -294,47 +569,69 @@ impl NodeRunner for ProductionRunner {
         );
         broadcast_engine.start(transport.marshal.blocks);
 
-        let partition_prefix = &self.partition_prefix;
         <ThresholdScheme as commonware_cryptography::certificate::Scheme>::certificate_codec_config_unbounded();
-        let finalizations_by_height = ArchiveInitializer::init::<_, ConsensusDigest, CertArchive>(
-            context.with_label("finalizations_by_height"),
-            format!("{partition_prefix}-finalizations-by-height"),
-            (),
-        )
-        .await
-        .context("init finalizations archive")?;
+        let overrides = archive_overrides(&config.storage)
+            .map_err(|e| anyhow::anyhow!("invalid storage config: {}", e))?;
+        let finalizations_by_height =
+            ArchiveInitializer::init_with::<_, ConsensusDigest, CertArchive>(
+                context.with_label("finalizations_by_height"),
+                finalizations_partition,
+                (),
+                overrides,
+            )
+            .await
+            .context("init finalizations archive")?;
 
-        let finalized_blocks = ArchiveInitializer::init::<_, ConsensusDigest, Block>(
+        let finalized_blocks = ArchiveInitializer::init_with::<_, ConsensusDigest, Block>(
             context.with_label("finalized_blocks"),
-            format!("{partition_prefix}-finalized-blocks"),
+            blocks_partition,
             block_cfg,
+            overrides,
         )
         .await
         .context("init blocks archive")?;
 
         let (actor, marshal_mailbox, _last_processed_height) =
-            monmouth_marshal::ActorInitializer::init::<_, Block, _, _, _, Exact>(
+            monmouth_marshal::ActorInitializer::init_with_config::<_, Block, _, _, _, Exact>(
                 context.clone(),
                 finalizations_by_height,
                 finalized_blocks,
                 scheme_provider,
                 buffer_pool.clone(),
                 block_cfg,
+                marshal,
             )
             .await;
         actor.start(finalized_reporter, buffer, resolver);
 
-        let epocher = FixedEpocher::new(NZU64!(EPOCH_LENGTH));
-        let executor = self.build_executor();
+        let epocher = build_epocher(&config.consensus)?;
+        let executor = self.build_block_executor(sender_cache);
         let mut app = RevmApplication::<ThresholdScheme, _>::new(
             ledger.clone(),
             executor,
             block_cfg.max_txs,
             self.gas_limit,
         );
+        let block_interval = config
+            .consensus
+            .validated_block_interval()
+            .map_err(|e| anyhow::anyhow!("invalid consensus config: {}", e))?;
+        app = app.with_block_interval(block_interval);
+        if let Some(classifier) = self.build_classifier() {
+            app = app.with_classifier(classifier);
+        }
+        app = app.with_sync_tracker(SyncTracker::new(config.consensus.catch_up_threshold));
         if let Some((state, _)) = &self.rpc_config {
             app = app.with_node_state(state.clone());
         }
+        if config.consensus.light_verification {
+            let verifier =
+                config.consensus.light_checkpoints.iter().fold(LightVerifier::new(), |v, cp| {
+                    v.with_checkpoint(cp.height, StateRoot(cp.state_root))
+                });
+            info!(checkpoints = config.consensus.light_checkpoints.len(), "light verification");
+            app = app.with_light_verification(verifier);
+        }
         let marshaled =
             Marshaled::new(context.with_label("marshaled"), app, marshal_mailbox.clone(), epocher);
 
@@ -350,32 +647,20 @@ impl NodeRunner for ProductionRunner {
         for tx in &self.bootstrap.bootstrap_txs {
             let _ = ledger.submit_tx(tx.clone()).await;
         }
+        let (tx_sender, tx_receiver) = transport.txs;
+        TxGossip::new(context.with_label("tx_gossip"), ledger.clone(), tx_sender, tx_receiver)
+            .start();
 
-        let engine = simplex::Engine::new(
-            context.with_label("engine"),
-            simplex::Config {
-                scheme: self.scheme.clone(),
-                elector: Random,
-                blocker: transport.oracle.clone(),
-                automaton: marshaled.clone(),
-                relay: marshaled,
-                reporter,
-                strategy: Sequential,
-                partition: self.partition_prefix.clone(),
-                mailbox_size: MAILBOX_SIZE,
-                epoch: Epoch::zero(),
-                replay_buffer: NZUsize!(1024 * 1024),
-                write_buffer: NZUsize!(1024 * 1024),
-                leader_timeout: Duration::from_millis(500),
-                notarization_timeout: Duration::from_secs(1),
-                nullify_retry: Duration::from_secs(2),
-                fetch_timeout: Duration::from_millis(500),
-                activity_timeout: ViewDelta::new(20),
-                skip_timeout: ViewDelta::new(10),
-                fetch_concurrent: 8,
-                buffer_pool,
-            },
-        );
+        let consensus = DkgConsensusProvider::new(
+            self.scheme.clone(),
+            transport.oracle.clone(),
+            marshaled.clone(),
+            marshaled,
+            reporter,
+            partition_prefix,
+            buffer_pool,
+        )?;
+        let engine = simplex::Engine::new(context.with_label("engine"), consensus.simplex_config());
         engine.start(transport.simplex.votes, transport.simplex.certs, transport.simplex.resolver);
 
         info!("Validator started successfully");
@@ -413,3 +698,77 @@ async fn serve_metrics(ctx: tokio::Context, addr: SocketAddr) {
         tracing::error!(error = %e, "Metrics server error");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use commonware_consensus::types::{Epocher as _, Height};
+    use monmouth_config::ConsensusConfig;
+
+    use super::*;
+
+    #[test]
+    fn block_executor_is_sequential_unless_configured() {
+        let (_, schemes) = monmouth_crypto::threshold_schemes(0, 4).unwrap();
+        let bootstrap = BootstrapConfig::new(Vec::new(), Vec::new());
+        let runner =
+            ProductionRunner::new(schemes[0].clone(), 1, 30_000_000, bootstrap).expect("runner");
+
+        let sequential = runner.build_block_executor(SenderCache::default());
+        assert_eq!(sequential.workers(), monmouth_config::DEFAULT_PARALLEL_WORKERS);
+        assert!(sequential.workers() < 2);
+
+        let parallel = runner.with_parallel_workers(8).build_block_executor(SenderCache::default());
+        assert_eq!(parallel.workers(), 8);
+    }
+
+    #[test]
+    fn genesis_priority_fee_split_is_validated() {
+        let (_, schemes) = monmouth_crypto::threshold_schemes(0, 4).unwrap();
+        let genesis =
+            |split| BootstrapConfig::new(Vec::new(), Vec::new()).with_priority_fee_split(split);
+
+        let short = genesis(vec![(Address::repeat_byte(0x01), 9_000)]);
+        assert!(ProductionRunner::new(schemes[0].clone(), 1, 30_000_000, short).is_err());
+
+        let full =
+            genesis(vec![(Address::repeat_byte(0x01), 7_000), (Address::repeat_byte(0x02), 3_000)]);
+        assert!(ProductionRunner::new(schemes[0].clone(), 1, 30_000_000, full).is_ok());
+    }
+
+    #[test]
+    fn build_epocher_uses_configured_length() {
+        let config = ConsensusConfig { epoch_length: 10, ..Default::default() };
+        let epocher = build_epocher(&config).expect("valid epoch length");
+
+        let epoch_of = |height: u64| epocher.containing(Height::new(height)).unwrap().epoch();
+        assert_eq!(epoch_of(0), Epoch::new(0));
+        assert_eq!(epoch_of(9), Epoch::new(0));
+        assert_eq!(epoch_of(10), Epoch::new(1));
+        assert_eq!(epoch_of(19), Epoch::new(1));
+        assert_eq!(epoch_of(20), Epoch::new(2));
+        assert_eq!(epocher.first(Epoch::new(1)), Some(Height::new(10)));
+        assert_eq!(epocher.last(Epoch::new(1)), Some(Height::new(19)));
+    }
+
+    #[test]
+    fn build_epocher_default_is_single_epoch() {
+        let epocher = build_epocher(&ConsensusConfig::default()).expect("valid epoch length");
+        let info = epocher.containing(Height::new(1_000_000)).unwrap();
+        assert_eq!(info.epoch(), Epoch::new(0));
+    }
+
+    #[test]
+    fn marshal_config_uses_configured_epoch_length() {
+        let config = ConsensusConfig { epoch_length: 10, ..Default::default() };
+        let marshal = marshal_config(&config).expect("valid epoch length");
+        assert_eq!(marshal.blocks_per_epoch.get(), 10);
+        let zero = ConsensusConfig { epoch_length: 0, ..Default::default() };
+        assert!(marshal_config(&zero).is_err());
+    }
+
+    #[test]
+    fn build_epocher_rejects_zero_length() {
+        let config = ConsensusConfig { epoch_length: 0, ..Default::default() };
+        assert!(build_epocher(&config).is_err());
+    }
+}
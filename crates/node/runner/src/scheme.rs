@@ -17,7 +17,26 @@ const SIMPLEX_NAMESPACE: &[u8] = b"_COMMONWARE_MONMOUTH_SIMPLEX";
 /// Load a threshold signing scheme from DKG output files.
 pub fn load_threshold_scheme(data_dir: &Path) -> anyhow::Result<ThresholdScheme> {
     let output = DkgOutput::load(data_dir)?;
+    let (participants, group_poly, share) = decode_output(&output)?;
+    let participants_set = Set::from_iter_dedup(participants);
+
+    let scheme =
+        bls12381_threshold::Scheme::signer(SIMPLEX_NAMESPACE, participants_set, group_poly, share)
+            .ok_or_else(|| anyhow::anyhow!("failed to create signer: share public key mismatch"))?;
 
+    Ok(scheme)
+}
+
+/// Load this validator's key share and the group's public polynomial from DKG
+/// output files, for signing checkpoints.
+pub(crate) fn load_checkpoint_keys(data_dir: &Path) -> anyhow::Result<(Share, Sharing<MinSig>)> {
+    let (_, group_poly, share) = decode_output(&DkgOutput::load(data_dir)?)?;
+    Ok((share, group_poly))
+}
+
+fn decode_output(
+    output: &DkgOutput,
+) -> anyhow::Result<(Vec<ed25519::PublicKey>, Sharing<MinSig>, Share)> {
     let participants: Vec<ed25519::PublicKey> = output
         .participant_keys
         .iter()
@@ -31,17 +50,11 @@ pub fn load_threshold_scheme(data_dir: &Path) -> anyhow::Result<ThresholdScheme>
     let n_cfg =
         NonZeroU32::new(n as u32).ok_or_else(|| anyhow::anyhow!("participants cannot be empty"))?;
 
-    let participants_set = Set::from_iter_dedup(participants);
-
     let group_poly = Sharing::<MinSig>::read_cfg(&mut output.public_polynomial.as_slice(), &n_cfg)
         .map_err(|e| anyhow::anyhow!("failed to decode public polynomial: {:?}", e))?;
 
     let share = Share::read_cfg(&mut output.share_secret.as_slice(), &())
         .map_err(|e| anyhow::anyhow!("failed to decode share: {:?}", e))?;
 
-    let scheme =
-        bls12381_threshold::Scheme::signer(SIMPLEX_NAMESPACE, participants_set, group_poly, share)
-            .ok_or_else(|| anyhow::anyhow!("failed to create signer: share public key mismatch"))?;
-
-    Ok(scheme)
+    Ok((participants, group_poly, share))
 }
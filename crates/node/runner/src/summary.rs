@@ -0,0 +1,122 @@
+//! Structured startup summary.
+
+use std::{net::SocketAddr, path::PathBuf};
+
+use alloy_primitives::{Address, B256};
+use commonware_cryptography::ed25519;
+use commonware_utils::{Faults, N3f1};
+use tracing::info;
+
+/// Node configuration logged once at startup.
+///
+/// Collected into a single event so operators can confirm which chain, key,
+/// and validator set a node came up with from one log line.
+#[derive(Clone, Debug)]
+pub struct StartupSummary {
+    /// Chain ID.
+    pub chain_id: u64,
+    /// Hash of the genesis block.
+    pub genesis_hash: B256,
+    /// This node's validator public key.
+    pub validator: ed25519::PublicKey,
+    /// Number of validators in the consensus set.
+    pub participants: usize,
+    /// Signatures required to finalize a block.
+    pub threshold: usize,
+    /// Node data directory.
+    pub data_dir: PathBuf,
+    /// RPC bind address, if the RPC server is enabled.
+    pub rpc_addr: Option<SocketAddr>,
+    /// Metrics bind address, if the metrics server is enabled.
+    pub metrics_addr: Option<SocketAddr>,
+    /// Addresses of the enabled custom precompiles.
+    pub precompiles: Vec<Address>,
+}
+
+impl StartupSummary {
+    /// Quorum threshold for a validator set of `participants`.
+    pub fn threshold_for(participants: usize) -> usize {
+        N3f1::quorum(participants) as usize
+    }
+
+    /// Emit the summary as a single `info` event.
+    pub fn log(&self) {
+        info!(
+            chain_id = self.chain_id,
+            genesis_hash = %self.genesis_hash,
+            validator = ?self.validator,
+            participants = self.participants,
+            threshold = self.threshold,
+            data_dir = %self.data_dir.display(),
+            rpc = %endpoint(self.rpc_addr),
+            metrics = %endpoint(self.metrics_addr),
+            precompiles = ?self.precompiles,
+            "Starting production validator"
+        );
+    }
+}
+
+fn endpoint(addr: Option<SocketAddr>) -> String {
+    addr.map_or_else(|| "disabled".to_string(), |addr| addr.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io,
+        sync::{Arc, Mutex},
+    };
+
+    use monmouth_executor::MonmouthPrecompiles;
+
+    use super::*;
+
+    /// Log sink shared between the subscriber and the test.
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Captured {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    #[test]
+    fn summary_logs_chain_id_and_genesis_hash() {
+        let (participants, _) = monmouth_crypto::threshold_schemes(0, 4).unwrap();
+        let summary = StartupSummary {
+            chain_id: 7750,
+            genesis_hash: B256::repeat_byte(0x5a),
+            validator: participants[0].clone(),
+            participants: participants.len(),
+            threshold: StartupSummary::threshold_for(participants.len()),
+            data_dir: PathBuf::from("/var/lib/monmouth"),
+            rpc_addr: Some("127.0.0.1:8545".parse().unwrap()),
+            metrics_addr: None,
+            precompiles: MonmouthPrecompiles::CUSTOM_ADDRESSES.to_vec(),
+        };
+
+        let captured = Captured::default();
+        let writer = captured.clone();
+        let subscriber =
+            tracing_subscriber::fmt().with_writer(move || writer.clone()).with_ansi(false).finish();
+        tracing::subscriber::with_default(subscriber, || summary.log());
+
+        let output = captured.contents();
+        assert!(output.contains("chain_id=7750"), "{output}");
+        assert!(output.contains(&format!("genesis_hash={}", summary.genesis_hash)), "{output}");
+        assert!(output.contains("threshold=3"), "{output}");
+        assert!(output.contains("metrics=disabled"), "{output}");
+    }
+}
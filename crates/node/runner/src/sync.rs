@@ -0,0 +1,139 @@
+//! Catching-up versus live participation.
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use tracing::info;
+
+/// Whether a node is close enough to the network tip to propose.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SyncMode {
+    /// The local head trails the network tip by more than the threshold; blocks
+    /// are applied but not proposed.
+    CatchingUp,
+    /// The local head is within the threshold of the network tip.
+    #[default]
+    Live,
+}
+
+#[derive(Debug, Default)]
+struct Heights {
+    head: u64,
+    tip: u64,
+    mode: SyncMode,
+}
+
+/// Tracks the local head against the network tip to derive a [`SyncMode`].
+///
+/// The head is the highest block this node has applied. The tip is the highest
+/// block it has seen from the network. Both only move forward. Clones share
+/// the same heights.
+#[derive(Clone, Debug)]
+pub struct SyncTracker {
+    threshold: u64,
+    heights: Arc<Mutex<Heights>>,
+}
+
+impl SyncTracker {
+    /// Create a tracker that is live while the head trails the tip by at most
+    /// `threshold` blocks.
+    pub fn new(threshold: u64) -> Self {
+        Self { threshold, heights: Arc::new(Mutex::new(Heights::default())) }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Heights> {
+        self.heights.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Record that this node has applied the block at `height`.
+    pub fn observe_head(&self, height: u64) {
+        let mut heights = self.lock();
+        heights.head = heights.head.max(height);
+        heights.tip = heights.tip.max(height);
+        self.update(&mut heights);
+    }
+
+    /// Record that the network has produced a block at `height`.
+    pub fn observe_tip(&self, height: u64) {
+        let mut heights = self.lock();
+        heights.tip = heights.tip.max(height);
+        self.update(&mut heights);
+    }
+
+    fn update(&self, heights: &mut Heights) {
+        let mode = if heights.tip - heights.head > self.threshold {
+            SyncMode::CatchingUp
+        } else {
+            SyncMode::Live
+        };
+        if mode != heights.mode {
+            info!(?mode, head = heights.head, tip = heights.tip, "sync mode changed");
+            heights.mode = mode;
+        }
+    }
+
+    /// Returns the current mode.
+    pub fn mode(&self) -> SyncMode {
+        self.lock().mode
+    }
+
+    /// Returns `true` if the node may propose.
+    pub fn is_live(&self) -> bool {
+        self.mode() == SyncMode::Live
+    }
+
+    /// Returns the local head and network tip heights.
+    pub fn heights(&self) -> (u64, u64) {
+        let heights = self.lock();
+        (heights.head, heights.tip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lagging_node_proposes_only_once_caught_up() {
+        const THRESHOLD: u64 = 4;
+        let tracker = SyncTracker::new(THRESHOLD);
+        tracker.observe_head(10);
+
+        // The network has moved on; the node learns the tip from a proposal.
+        let mut tip = 50;
+        tracker.observe_tip(tip);
+        assert_eq!(tracker.mode(), SyncMode::CatchingUp);
+
+        // Apply backfilled blocks one at a time while the network keeps
+        // producing a block every fourth step.
+        let mut first_live = None;
+        for head in 11..=70 {
+            if head % 4 == 0 {
+                tip += 1;
+                tracker.observe_tip(tip);
+            }
+            tracker.observe_head(head);
+            let caught_up = tip.saturating_sub(head) <= THRESHOLD;
+            assert_eq!(tracker.is_live(), caught_up, "head {head}, tip {tip}");
+            if caught_up && first_live.is_none() {
+                first_live = Some(head);
+            }
+        }
+
+        let first_live = first_live.expect("node caught up");
+        assert!(first_live > 50, "resumed proposing at {first_live}, before reaching the tip");
+        assert!(tracker.is_live());
+    }
+
+    #[test]
+    fn heights_only_move_forward() {
+        let tracker = SyncTracker::new(2);
+        tracker.observe_tip(10);
+        tracker.observe_tip(5);
+        tracker.observe_head(7);
+        tracker.observe_head(3);
+        assert_eq!(tracker.heights(), (7, 10));
+        assert_eq!(tracker.mode(), SyncMode::CatchingUp);
+        tracker.observe_head(8);
+        assert!(tracker.is_live());
+    }
+}
@@ -10,7 +10,12 @@ pub use runner::{NodeRunContext, NodeRunner};
 mod service;
 pub use service::{LegacyNodeService, MonmouthNodeService};
 
+mod shutdown;
+pub use shutdown::{Shutdown, ShutdownTrigger};
+
+#[cfg(feature = "test-utils")]
 mod stubs;
+#[cfg(feature = "test-utils")]
 pub use stubs::{StubAutomaton, StubBlocker, StubDigest, StubPublicKey, StubRelay, StubReporter};
 
 mod traits;
@@ -1,6 +1,6 @@
 //! Monmouth node service implementation.
 
-use std::sync::Arc;
+use std::{pin::pin, sync::Arc};
 
 use commonware_cryptography::Signer;
 use commonware_p2p::Manager;
@@ -8,11 +8,11 @@ use commonware_runtime::{
     Runner,
     tokio::{self, Context},
 };
-use futures::future::try_join_all;
+use futures::future::{self, Either, try_join_all};
 use monmouth_config::NodeConfig;
 use monmouth_transport::NetworkConfigExt;
 
-use crate::{NodeRunContext, NodeRunner, TransportProvider};
+use crate::{NodeRunContext, NodeRunner, Shutdown, TransportProvider};
 
 /// Generic monmouth node service that delegates to a runner.
 ///
@@ -79,17 +79,32 @@ where
 
 /// Legacy monmouth node service for production use.
 ///
-/// This maintains backward compatibility with the existing production binary.
-/// For new implementations, prefer [`MonmouthNodeService`] with custom runner/provider.
+/// This maintains backward compatibility with the existing production binary,
+/// which falls back to it until a DKG ceremony has produced threshold shares.
+/// It only starts the transport and registers the configured validators; it does
+/// not run consensus. The simplex engine and marshal are wired by
+/// `monmouth_runner::ProductionRunner`, a [`NodeRunner`] driven through
+/// [`MonmouthNodeService`]. For new implementations, prefer [`MonmouthNodeService`]
+/// with custom runner/provider.
 #[derive(Debug)]
 pub struct LegacyNodeService {
     config: NodeConfig,
+    shutdown: Option<Shutdown>,
 }
 
 impl LegacyNodeService {
     /// Create a new legacy node service.
     pub const fn new(config: NodeConfig) -> Self {
-        Self { config }
+        Self { config, shutdown: None }
+    }
+
+    /// Stop the service cleanly once `shutdown` is triggered.
+    ///
+    /// Without a shutdown token the service runs until one of its tasks fails.
+    #[must_use]
+    pub fn with_shutdown(mut self, shutdown: Shutdown) -> Self {
+        self.shutdown = Some(shutdown);
+        self
     }
 
     /// Run the legacy node service.
@@ -99,6 +114,9 @@ impl LegacyNodeService {
     }
 
     /// Runs the legacy node service with context.
+    ///
+    /// Returns `Ok(())` when the shutdown token fires. The transport tasks stop
+    /// when the runtime that owns `context` exits.
     pub async fn run_with_context(self, context: Context) -> eyre::Result<()> {
         let validator_key = self.config.validator_key()?;
         let validator = validator_key.public_key();
@@ -122,12 +140,55 @@ impl LegacyNodeService {
 
         tracing::info!(chain_id = self.config.chain_id, "monmouth node initialized");
 
-        if let Err(e) = try_join_all(vec![transport.handle]).await {
-            tracing::error!(?e, "service task failed");
-            return Err(eyre::eyre!("service task failed: {:?}", e));
+        let tasks = pin!(try_join_all(vec![transport.handle]));
+        let shutdown = pin!(async {
+            match &self.shutdown {
+                Some(shutdown) => shutdown.wait().await,
+                None => future::pending().await,
+            }
+        });
+        match future::select(tasks, shutdown).await {
+            Either::Left((Err(e), _)) => {
+                tracing::error!(?e, "service task failed");
+                return Err(eyre::eyre!("service task failed: {:?}", e));
+            }
+            Either::Left((Ok(_), _)) => {}
+            Either::Right(((), _)) => tracing::info!("shutdown requested"),
         }
 
         tracing::info!("monmouth node shutdown");
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use commonware_runtime::{Clock as _, Spawner as _};
+
+    use super::*;
+
+    #[test]
+    fn legacy_service_returns_on_shutdown() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = NodeConfig { data_dir: dir.path().to_path_buf(), ..Default::default() };
+        config.network.listen_addr = "127.0.0.1:0".to_string();
+        let (trigger, shutdown) = Shutdown::channel();
+        let service = LegacyNodeService::new(config).with_shutdown(shutdown);
+
+        tokio::Runner::default().start(|context| async move {
+            context.clone().spawn(|context| async move {
+                context.sleep(Duration::from_millis(50)).await;
+                trigger.trigger();
+            });
+
+            let run = pin!(service.run_with_context(context.clone()));
+            let deadline = pin!(context.sleep(Duration::from_secs(5)));
+            match future::select(run, deadline).await {
+                Either::Left((result, _)) => result.expect("service stops cleanly"),
+                Either::Right(_) => panic!("service did not stop after shutdown"),
+            }
+        });
+    }
+}
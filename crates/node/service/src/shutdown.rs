@@ -0,0 +1,51 @@
+//! Shutdown token shared by node services.
+
+use std::fmt;
+
+use futures::{
+    channel::oneshot,
+    future::{self, FutureExt as _, Shared},
+};
+
+/// Fires the paired [`Shutdown`] token and every clone of it.
+#[derive(Debug)]
+pub struct ShutdownTrigger(oneshot::Sender<()>);
+
+impl ShutdownTrigger {
+    /// Signal shutdown to every holder of the paired token.
+    pub fn trigger(self) {
+        let _ = self.0.send(());
+    }
+}
+
+/// Cloneable token that resolves once its [`ShutdownTrigger`] fires.
+#[derive(Clone)]
+pub struct Shutdown(Shared<oneshot::Receiver<()>>);
+
+impl fmt::Debug for Shutdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shutdown").field("triggered", &self.is_triggered()).finish()
+    }
+}
+
+impl Shutdown {
+    /// Create a trigger and the token it fires.
+    pub fn channel() -> (ShutdownTrigger, Self) {
+        let (tx, rx) = oneshot::channel();
+        (ShutdownTrigger(tx), Self(rx.shared()))
+    }
+
+    /// Whether shutdown has been triggered.
+    pub fn is_triggered(&self) -> bool {
+        matches!(self.0.peek(), Some(Ok(())))
+    }
+
+    /// Wait until shutdown is triggered.
+    ///
+    /// Never resolves if the trigger is dropped without firing.
+    pub async fn wait(&self) {
+        if self.0.clone().await.is_err() {
+            future::pending::<()>().await;
+        }
+    }
+}
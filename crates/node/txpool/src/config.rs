@@ -11,6 +11,8 @@ pub struct PoolConfig {
     pub max_txs_per_sender: usize,
     /// Maximum transaction size in bytes.
     pub max_tx_size: usize,
+    /// Maximum calldata (or initcode) size in bytes, independent of the envelope size.
+    pub max_tx_calldata_bytes: usize,
     /// Minimum gas price required for transaction acceptance.
     pub min_gas_price: u128,
     /// Percentage bump required for replacement transactions.
@@ -24,6 +26,7 @@ impl Default for PoolConfig {
             max_queued_txs: 1024,
             max_txs_per_sender: 16,
             max_tx_size: 128 * 1024, // 128 KB
+            max_tx_calldata_bytes: 128 * 1024,
             min_gas_price: 0,
             replacement_bump_percent: 10,
         }
@@ -38,6 +41,7 @@ impl PoolConfig {
             max_queued_txs: 1024,
             max_txs_per_sender: 16,
             max_tx_size: 128 * 1024,
+            max_tx_calldata_bytes: 128 * 1024,
             min_gas_price: 0,
             replacement_bump_percent: 10,
         }
@@ -71,6 +75,13 @@ impl PoolConfig {
         self
     }
 
+    /// Sets the maximum calldata size in bytes.
+    #[must_use]
+    pub const fn with_max_tx_calldata_bytes(mut self, max: usize) -> Self {
+        self.max_tx_calldata_bytes = max;
+        self
+    }
+
     /// Sets the minimum gas price required.
     #[must_use]
     pub const fn with_min_gas_price(mut self, min: u128) -> Self {
@@ -97,6 +108,7 @@ mod tests {
         assert_eq!(config.max_queued_txs, 1024);
         assert_eq!(config.max_txs_per_sender, 16);
         assert_eq!(config.max_tx_size, 128 * 1024);
+        assert_eq!(config.max_tx_calldata_bytes, 128 * 1024);
         assert_eq!(config.min_gas_price, 0);
         assert_eq!(config.replacement_bump_percent, 10);
     }
@@ -109,6 +121,7 @@ mod tests {
         assert_eq!(new.max_queued_txs, default.max_queued_txs);
         assert_eq!(new.max_txs_per_sender, default.max_txs_per_sender);
         assert_eq!(new.max_tx_size, default.max_tx_size);
+        assert_eq!(new.max_tx_calldata_bytes, default.max_tx_calldata_bytes);
         assert_eq!(new.min_gas_price, default.min_gas_price);
         assert_eq!(new.replacement_bump_percent, default.replacement_bump_percent);
     }
@@ -139,6 +152,13 @@ mod tests {
         assert_eq!(config.max_tx_size, 256 * 1024);
     }
 
+    #[test]
+    fn builder_with_max_tx_calldata_bytes() {
+        let config = PoolConfig::new().with_max_tx_calldata_bytes(1024);
+        assert_eq!(config.max_tx_calldata_bytes, 1024);
+        assert_eq!(config.max_tx_size, 128 * 1024);
+    }
+
     #[test]
     fn builder_with_min_gas_price() {
         let config = PoolConfig::new().with_min_gas_price(1_000_000_000);
@@ -23,6 +23,15 @@ pub enum TxPoolError {
         max: usize,
     },
 
+    /// The transaction calldata exceeds the maximum allowed size.
+    #[error("calldata size {size} exceeds maximum {max}")]
+    CalldataTooLarge {
+        /// Actual calldata size.
+        size: usize,
+        /// Maximum allowed calldata size.
+        max: usize,
+    },
+
     /// The gas price is below the minimum required.
     #[error("gas price {price} below minimum {min}")]
     GasPriceTooLow {
@@ -127,6 +136,12 @@ mod tests {
         assert_eq!(err.to_string(), "transaction size 150000 exceeds maximum 131072");
     }
 
+    #[test]
+    fn test_calldata_too_large_display() {
+        let err = TxPoolError::CalldataTooLarge { size: 200000, max: 131072 };
+        assert_eq!(err.to_string(), "calldata size 200000 exceeds maximum 131072");
+    }
+
     #[test]
     fn test_gas_price_too_low_display() {
         let err = TxPoolError::GasPriceTooLow { price: 1_000_000_000, min: 2_000_000_000 };
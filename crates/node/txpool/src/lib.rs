@@ -20,4 +20,7 @@ mod pool;
 pub use pool::TransactionPool;
 
 mod validator;
-pub use validator::{TransactionValidator, ValidatedTransaction, recover_sender_from_envelope};
+pub use validator::{
+    ADMISSION_PIPELINE, AdmissionStage, TransactionValidator, ValidatedTransaction,
+    recover_sender_from_envelope,
+};
@@ -115,6 +115,21 @@ impl SenderQueue {
         }
     }
 
+    /// Returns the pending or queued transaction with the given nonce.
+    pub fn get(&self, nonce: u64) -> Option<&OrderedTransaction> {
+        self.pending.iter().chain(self.queued.iter()).find(|tx| tx.nonce == nonce)
+    }
+
+    /// Replaces the pending or queued transaction with the same nonce as `tx`.
+    ///
+    /// Returns the displaced transaction, or `None` (dropping `tx`) if no
+    /// transaction with that nonce is held.
+    pub fn replace(&mut self, tx: OrderedTransaction) -> Option<OrderedTransaction> {
+        let slot =
+            self.pending.iter_mut().chain(self.queued.iter_mut()).find(|t| t.nonce == tx.nonce)?;
+        Some(std::mem::replace(slot, tx))
+    }
+
     fn promote_queued(&mut self) {
         while let Some(first) = self.queued.first() {
             if first.nonce == self.next_nonce + self.pending.len() as u64 {
@@ -236,6 +251,20 @@ mod tests {
         assert_eq!(queue.queued_count(), 0);
     }
 
+    #[test]
+    fn sender_queue_replace_queued() {
+        let mut queue = SenderQueue::new(Address::ZERO, 0);
+        queue.insert(make_tx(0, 100));
+        queue.insert(make_tx(2, 100));
+
+        let bumped = make_tx(2, 200);
+        let old = queue.replace(bumped.clone()).unwrap();
+        assert_eq!(old.effective_gas_price, 100);
+        assert_eq!(queue.get(2).unwrap().hash, bumped.hash);
+        assert_eq!(queue.total_count(), 2);
+        assert!(queue.replace(make_tx(5, 300)).is_none());
+    }
+
     #[test]
     fn ordered_transaction_ordering() {
         let tx1 = make_tx(0, 100);
@@ -1,14 +1,15 @@
 //! Transaction pool implementation.
 
 use std::{
-    collections::{BTreeSet, HashMap},
+    cmp::Reverse,
+    collections::{BTreeSet, BinaryHeap, HashMap},
     time::{SystemTime, UNIX_EPOCH},
 };
 
 use alloy_consensus::{Transaction, TxEnvelope};
 use alloy_eips::eip2718::Decodable2718;
 use alloy_primitives::{Address, B256, Bytes};
-use monmouth_domain::{Tx, TxId};
+use monmouth_domain::{SenderCache, Tx, TxId};
 use parking_lot::RwLock;
 use tracing::{debug, trace, warn};
 
@@ -38,10 +39,61 @@ impl PoolInner {
         }
     }
 
+    fn remove_tx(&mut self, hash: &B256) -> Option<OrderedTransaction> {
+        let tx = self.by_hash.remove(hash)?;
+        let sender = tx.sender;
+
+        if let Some(queue) = self.by_sender.get_mut(&sender) {
+            queue.pending.retain(|t| t.hash != *hash);
+            queue.queued.retain(|t| t.hash != *hash);
+
+            if queue.is_empty() {
+                self.by_sender.remove(&sender);
+            }
+        }
+
+        self.update_counts();
+        Some(tx)
+    }
+
     fn update_counts(&mut self) {
         self.pending_count = self.by_sender.values().map(|q| q.pending_count()).sum();
         self.queued_count = self.by_sender.values().map(|q| q.queued_count()).sum();
     }
+
+    /// Pending transactions in block-building order.
+    ///
+    /// Each sender contributes only its lowest remaining nonce at a time, so the
+    /// highest-priced head across all senders is picked next. Excluded transactions
+    /// are treated as already included; unaffordable or oversized ones end their
+    /// sender's sequence.
+    fn best_ordered(
+        &self,
+        excluded: &BTreeSet<TxId>,
+        gas_limit: u64,
+        base_fee: u64,
+    ) -> Vec<OrderedTransaction> {
+        let mut heads: BinaryHeap<_> = self
+            .by_sender
+            .iter()
+            .filter_map(|(sender, queue)| Some((Reverse(queue.pending.first()?), *sender, 0)))
+            .collect();
+
+        let mut result = Vec::new();
+        while let Some((Reverse(tx), sender, index)) = heads.pop() {
+            if tx.effective_gas_price < u128::from(base_fee) || tx.envelope.gas_limit() > gas_limit
+            {
+                continue;
+            }
+            if !excluded.contains(&TxId(tx.hash)) {
+                result.push(tx.clone());
+            }
+            if let Some(next) = self.by_sender[&sender].pending.get(index + 1) {
+                heads.push((Reverse(next), sender, index + 1));
+            }
+        }
+        result
+    }
 }
 
 /// A thread-safe transaction pool with nonce ordering and fee prioritization.
@@ -49,17 +101,40 @@ impl PoolInner {
 pub struct TransactionPool {
     inner: RwLock<PoolInner>,
     config: PoolConfig,
+    sender_cache: SenderCache,
 }
 
 impl TransactionPool {
     /// Creates a new transaction pool with the given configuration.
     #[must_use]
     pub fn new(config: PoolConfig) -> Self {
-        Self { inner: RwLock::new(PoolInner::new()), config }
+        Self { inner: RwLock::new(PoolInner::new()), config, sender_cache: SenderCache::default() }
+    }
+
+    /// Use `cache` for senders recovered at admission.
+    #[must_use]
+    pub fn with_sender_cache(mut self, cache: SenderCache) -> Self {
+        self.sender_cache = cache;
+        self
+    }
+
+    /// Returns the sender cache populated at admission.
+    ///
+    /// Hand a clone to the executor so admitted transactions are not recovered twice.
+    pub const fn sender_cache(&self) -> &SenderCache {
+        &self.sender_cache
     }
 
     /// Adds a validated transaction to the pool.
     pub fn add(&self, tx: OrderedTransaction) -> Result<(), TxPoolError> {
+        let calldata_len = tx.envelope.input().len();
+        if calldata_len > self.config.max_tx_calldata_bytes {
+            return Err(TxPoolError::CalldataTooLarge {
+                size: calldata_len,
+                max: self.config.max_tx_calldata_bytes,
+            });
+        }
+
         let mut inner = self.inner.write();
 
         if inner.by_hash.contains_key(&tx.hash) {
@@ -70,16 +145,25 @@ impl TransactionPool {
         let queue =
             inner.by_sender.entry(sender).or_insert_with(|| SenderQueue::new(sender, tx.nonce));
 
-        if queue.total_count() >= self.config.max_txs_per_sender {
-            return Err(TxPoolError::SenderFull(sender));
-        }
-
-        if let Some(replaced) = queue.insert(tx.clone()) {
-            if replaced.hash == tx.hash {
+        // Replacing an existing nonce does not grow the sender's footprint, so it is
+        // exempt from the per-sender cap but must pay the configured fee bump.
+        if let Some(existing) = queue.get(tx.nonce) {
+            let min_price =
+                bumped_price(existing.effective_gas_price, self.config.replacement_bump_percent);
+            if tx.effective_gas_price < min_price {
+                return Err(TxPoolError::ReplacementUnderpriced);
+            }
+            if let Some(replaced) = queue.replace(tx.clone()) {
+                inner.by_hash.remove(&replaced.hash);
+                debug!(hash = ?replaced.hash, "replaced transaction");
+            }
+        } else {
+            if queue.total_count() >= self.config.max_txs_per_sender {
+                return Err(TxPoolError::SenderFull(sender));
+            }
+            if queue.insert(tx.clone()).is_some() {
                 return Err(TxPoolError::AlreadyExists);
             }
-            inner.by_hash.remove(&replaced.hash);
-            debug!(hash = ?replaced.hash, "replaced transaction");
         }
 
         inner.by_hash.insert(tx.hash, tx);
@@ -129,22 +213,7 @@ impl TransactionPool {
 
     /// Removes a transaction by its hash.
     pub fn remove(&self, hash: &B256) -> Option<OrderedTransaction> {
-        let mut inner = self.inner.write();
-
-        let tx = inner.by_hash.remove(hash)?;
-        let sender = tx.sender;
-
-        if let Some(queue) = inner.by_sender.get_mut(&sender) {
-            queue.pending.retain(|t| t.hash != *hash);
-            queue.queued.retain(|t| t.hash != *hash);
-
-            if queue.is_empty() {
-                inner.by_sender.remove(&sender);
-            }
-        }
-
-        inner.update_counts();
-        Some(tx)
+        self.inner.write().remove_tx(hash)
     }
 
     /// Removes confirmed transactions for a sender up to the given nonce.
@@ -203,6 +272,11 @@ impl TransactionPool {
         self.inner.read().by_sender.keys().cloned().collect()
     }
 
+    /// Returns a snapshot of every sender's pending and queued transactions.
+    pub fn sender_queues(&self) -> Vec<SenderQueue> {
+        self.inner.read().by_sender.values().cloned().collect()
+    }
+
     /// Checks if a transaction with the given hash exists in the pool.
     pub fn contains(&self, hash: &B256) -> bool {
         self.inner.read().by_hash.contains_key(hash)
@@ -229,6 +303,7 @@ impl Clone for TransactionPool {
                 queued_count: inner.queued_count,
             }),
             config: self.config.clone(),
+            sender_cache: self.sender_cache.clone(),
         }
     }
 }
@@ -237,9 +312,11 @@ fn current_timestamp() -> u64 {
     SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
 }
 
-fn tx_to_ordered(tx: &Tx) -> Option<OrderedTransaction> {
+fn tx_to_ordered(tx: &Tx, sender_cache: &SenderCache) -> Option<OrderedTransaction> {
     let envelope = TxEnvelope::decode_2718(&mut tx.bytes.as_ref()).ok()?;
-    let sender = recover_sender_from_envelope(&envelope).ok()?;
+    let sender = sender_cache
+        .get_or_recover(*envelope.tx_hash(), || recover_sender_from_envelope(&envelope))
+        .ok()?;
     let hash = alloy_primitives::keccak256(alloy_rlp::encode(&envelope));
     let nonce = envelope.nonce();
     let effective_gas_price = match &envelope {
@@ -262,7 +339,7 @@ fn tx_to_ordered(tx: &Tx) -> Option<OrderedTransaction> {
 
 impl Mempool for TransactionPool {
     fn insert(&self, tx: Tx) -> bool {
-        let Some(ordered) = tx_to_ordered(&tx) else {
+        let Some(ordered) = tx_to_ordered(&tx, &self.sender_cache) else {
             trace!("failed to decode transaction for mempool insert");
             return false;
         };
@@ -278,38 +355,17 @@ impl Mempool for TransactionPool {
 
     fn build(&self, max_txs: usize, excluded: &BTreeSet<TxId>) -> Vec<Tx> {
         let inner = self.inner.read();
+        inner
+            .best_ordered(excluded, u64::MAX, 0)
+            .iter()
+            .take(max_txs)
+            .map(|tx| Tx::new(Bytes::from(alloy_rlp::encode(&tx.envelope))))
+            .collect()
+    }
 
-        let mut candidates: Vec<_> = inner
-            .by_sender
-            .values()
-            .flat_map(|q| q.pending.iter())
-            .filter(|tx| !excluded.contains(&TxId(tx.hash)))
-            .cloned()
-            .collect();
-
-        candidates.sort();
-
-        let mut result = Vec::with_capacity(max_txs.min(candidates.len()));
-        let mut included_senders: HashMap<Address, u64> = HashMap::new();
-
-        for tx in candidates {
-            if result.len() >= max_txs {
-                break;
-            }
-
-            let expected_nonce = included_senders
-                .get(&tx.sender)
-                .copied()
-                .or_else(|| inner.by_sender.get(&tx.sender).map(|q| q.next_nonce))
-                .unwrap_or(0);
-
-            if tx.nonce == expected_nonce {
-                included_senders.insert(tx.sender, tx.nonce + 1);
-                result.push(Tx::new(Bytes::from(alloy_rlp::encode(&tx.envelope))));
-            }
-        }
-
-        result
+    fn best_transactions(&self, gas_limit: u64, base_fee: u64) -> impl Iterator<Item = Tx> {
+        let ordered = self.inner.read().best_ordered(&BTreeSet::new(), gas_limit, base_fee);
+        ordered.into_iter().map(|tx| Tx::new(Bytes::from(alloy_rlp::encode(&tx.envelope))))
     }
 
     fn prune(&self, tx_ids: &[TxId]) {
@@ -343,6 +399,11 @@ impl Mempool for TransactionPool {
     }
 }
 
+/// Minimum effective gas price for a transaction replacing one priced at `price`.
+const fn bumped_price(price: u128, bump_percent: u8) -> u128 {
+    price.saturating_add(price.saturating_mul(bump_percent as u128) / 100)
+}
+
 #[cfg(test)]
 mod tests {
     use alloy_consensus::{SignableTransaction as _, TxEip1559};
@@ -364,6 +425,15 @@ mod tests {
     }
 
     fn make_ordered_tx(sender: Address, nonce: u64, gas_price: u128) -> OrderedTransaction {
+        make_ordered_tx_with_input(sender, nonce, gas_price, Bytes::new())
+    }
+
+    fn make_ordered_tx_with_input(
+        sender: Address,
+        nonce: u64,
+        gas_price: u128,
+        input: Bytes,
+    ) -> OrderedTransaction {
         let inner = TxEip1559 {
             chain_id: 1,
             nonce,
@@ -373,7 +443,7 @@ mod tests {
             to: TxKind::Call(Address::ZERO),
             value: U256::ZERO,
             access_list: Default::default(),
-            input: Bytes::new(),
+            input,
         };
         let sig = Signature::from_scalars_and_parity(B256::ZERO, B256::ZERO, false);
         let signed = inner.into_signed(sig);
@@ -428,6 +498,52 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn pool_sender_limit_allows_replacements() {
+        let config = PoolConfig::default().with_max_txs_per_sender(2);
+        let pool = TransactionPool::new(config);
+
+        let sender = random_address();
+        pool.add(make_ordered_tx(sender, 0, 100)).unwrap();
+        pool.add(make_ordered_tx(sender, 5, 100)).unwrap();
+        assert!(matches!(
+            pool.add(make_ordered_tx(sender, 1, 100)),
+            Err(TxPoolError::SenderFull(_))
+        ));
+
+        // Replacements of pending and queued nonces bypass the cap with a 10% bump.
+        let pending_bump = make_ordered_tx(sender, 0, 110);
+        let queued_bump = make_ordered_tx(sender, 5, 110);
+        pool.add(pending_bump.clone()).unwrap();
+        pool.add(queued_bump.clone()).unwrap();
+        assert_eq!(pool.len(), 2);
+        assert!(pool.get(&pending_bump.hash).is_some());
+        assert!(pool.get(&queued_bump.hash).is_some());
+
+        // Replacements still need the fee bump.
+        assert!(matches!(
+            pool.add(make_ordered_tx(sender, 0, 115)),
+            Err(TxPoolError::ReplacementUnderpriced)
+        ));
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[test]
+    fn pool_calldata_limit() {
+        let config = PoolConfig::default().with_max_tx_calldata_bytes(64);
+        let pool = TransactionPool::new(config);
+
+        let sender = random_address();
+        let oversized = make_ordered_tx_with_input(sender, 0, 100, Bytes::from(vec![1u8; 65]));
+        assert!(matches!(
+            pool.add(oversized),
+            Err(TxPoolError::CalldataTooLarge { size: 65, max: 64 })
+        ));
+
+        pool.add(make_ordered_tx_with_input(sender, 0, 100, Bytes::from(vec![1u8; 64]))).unwrap();
+        assert_eq!(pool.len(), 1);
+    }
+
     #[test]
     fn pool_remove() {
         let config = PoolConfig::default();
@@ -444,6 +560,55 @@ mod tests {
         assert_eq!(pool.len(), 0);
     }
 
+    fn encoded(tx: &OrderedTransaction) -> Tx {
+        Tx::new(Bytes::from(alloy_rlp::encode(&tx.envelope)))
+    }
+
+    #[test]
+    fn best_transactions_follow_fee_and_nonce_order() {
+        let pool = TransactionPool::new(PoolConfig::default());
+
+        let alice = random_address();
+        let alice0 = make_ordered_tx(alice, 0, 10);
+        let alice1 = make_ordered_tx(alice, 1, 100);
+        let bob = random_address();
+        let bob0 = make_ordered_tx(bob, 0, 50);
+        let carol = random_address();
+        let carol0 = make_ordered_tx(carol, 0, 5);
+        let carol1 = make_ordered_tx(carol, 1, 200);
+
+        for tx in [&alice0, &alice1, &bob0, &carol0, &carol1] {
+            pool.add(tx.clone()).unwrap();
+        }
+
+        // Bob's head pays the most; Alice's high-fee nonce 1 must wait for nonce 0.
+        // Carol's nonce 0 is below the base fee, which also blocks her nonce 1.
+        let best: Vec<_> = pool.best_transactions(30_000_000, 8).collect();
+        assert_eq!(best, vec![encoded(&bob0), encoded(&alice0), encoded(&alice1)]);
+    }
+
+    #[test]
+    fn best_transactions_skip_txs_over_gas_limit() {
+        let pool = TransactionPool::new(PoolConfig::default());
+        pool.add(make_ordered_tx(random_address(), 0, 100)).unwrap();
+
+        assert_eq!(pool.best_transactions(20_000, 0).count(), 0);
+        assert_eq!(pool.best_transactions(21_000, 0).count(), 1);
+    }
+
+    #[test]
+    fn build_continues_sender_after_excluded_nonce() {
+        let pool = TransactionPool::new(PoolConfig::default());
+        let sender = random_address();
+        let tx0 = make_ordered_tx(sender, 0, 100);
+        let tx1 = make_ordered_tx(sender, 1, 100);
+        pool.add(tx0.clone()).unwrap();
+        pool.add(tx1.clone()).unwrap();
+
+        let excluded = BTreeSet::from([TxId(tx0.hash)]);
+        assert_eq!(pool.build(10, &excluded), vec![encoded(&tx1)]);
+    }
+
     #[test]
     fn pool_clear() {
         let config = PoolConfig::default();
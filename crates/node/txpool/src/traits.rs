@@ -19,6 +19,15 @@ pub trait Mempool: Clone + Send + Sync + 'static {
     /// `max_txs` limits the number of transactions returned.
     fn build(&self, max_txs: usize, excluded: &BTreeSet<TxId>) -> Vec<Tx>;
 
+    /// Executable transactions in block-building order.
+    ///
+    /// Transactions are yielded by descending effective gas price, ties broken by
+    /// arrival time and then hash, and a sender's transactions always follow their
+    /// nonce order. A transaction whose fee cap is below `base_fee` or whose gas
+    /// limit exceeds `gas_limit` is skipped together with the sender's later nonces.
+    /// The block builder consumes the iterator and stops once the block is full.
+    fn best_transactions(&self, gas_limit: u64, base_fee: u64) -> impl Iterator<Item = Tx>;
+
     /// Remove finalized transactions from the mempool.
     fn prune(&self, tx_ids: &[TxId]);
 
@@ -1,5 +1,7 @@
 //! Transaction validation.
 
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use alloy_consensus::{Transaction, TxEnvelope};
 use alloy_eips::eip2718::Decodable2718;
 use alloy_primitives::{Address, B256, U256, keccak256};
@@ -38,21 +40,100 @@ pub struct ValidatedTransaction {
     pub raw: Tx,
 }
 
+/// Stateless admission check run before signature recovery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdmissionStage {
+    /// Calldata does not exceed the configured maximum.
+    CalldataSize,
+    /// Chain ID matches the node's chain.
+    ChainId,
+    /// Gas price meets the pool's fee floor.
+    FeeFloor,
+    /// Gas limit covers the intrinsic gas.
+    IntrinsicGas,
+}
+
+/// Order in which [`TransactionValidator::validate`] runs the stateless stages.
+///
+/// Size and decoding checks run first, then these stages cheapest first.
+/// Signature recovery runs after all of them, followed by the nonce and
+/// balance checks, which need the recovered sender. The first failing check
+/// rejects the transaction.
+pub const ADMISSION_PIPELINE: [AdmissionStage; 4] = [
+    AdmissionStage::CalldataSize,
+    AdmissionStage::ChainId,
+    AdmissionStage::FeeFloor,
+    AdmissionStage::IntrinsicGas,
+];
+
+impl AdmissionStage {
+    /// Runs this stage against a decoded transaction.
+    pub fn check(
+        self,
+        envelope: &TxEnvelope,
+        chain_id: u64,
+        config: &PoolConfig,
+    ) -> Result<(), TxPoolError> {
+        match self {
+            Self::CalldataSize => {
+                let calldata_len = envelope.input().len();
+                if calldata_len > config.max_tx_calldata_bytes {
+                    return Err(TxPoolError::CalldataTooLarge {
+                        size: calldata_len,
+                        max: config.max_tx_calldata_bytes,
+                    });
+                }
+            }
+            Self::ChainId => {
+                let tx_chain_id = envelope.chain_id().unwrap_or(chain_id);
+                if tx_chain_id != chain_id {
+                    return Err(TxPoolError::InvalidChainId {
+                        got: tx_chain_id,
+                        expected: chain_id,
+                    });
+                }
+            }
+            Self::FeeFloor => {
+                let price = effective_gas_price(envelope);
+                if price < config.min_gas_price {
+                    return Err(TxPoolError::GasPriceTooLow { price, min: config.min_gas_price });
+                }
+            }
+            Self::IntrinsicGas => {
+                let intrinsic = intrinsic_gas(envelope);
+                let limit = envelope.gas_limit();
+                if limit < intrinsic {
+                    return Err(TxPoolError::IntrinsicGasTooLow { limit, intrinsic });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Validates transactions against chain state and protocol rules.
 #[derive(Debug)]
 pub struct TransactionValidator<S> {
     chain_id: u64,
     state: S,
     config: PoolConfig,
+    recoveries: AtomicU64,
 }
 
 impl<S: StateDbRead> TransactionValidator<S> {
     /// Creates a new transaction validator.
     pub const fn new(chain_id: u64, state: S, config: PoolConfig) -> Self {
-        Self { chain_id, state, config }
+        Self { chain_id, state, config, recoveries: AtomicU64::new(0) }
+    }
+
+    /// Number of signature recoveries performed by this validator.
+    pub fn recoveries(&self) -> u64 {
+        self.recoveries.load(Ordering::Relaxed)
     }
 
     /// Validates a raw transaction.
+    ///
+    /// Checks run in [`ADMISSION_PIPELINE`] order and stop at the first failure.
     pub async fn validate(&self, tx: Tx) -> Result<ValidatedTransaction, TxPoolError> {
         if tx.bytes.len() > self.config.max_tx_size {
             return Err(TxPoolError::TxTooLarge {
@@ -64,30 +145,15 @@ impl<S: StateDbRead> TransactionValidator<S> {
         let envelope = TxEnvelope::decode_2718(&mut tx.bytes.as_ref())
             .map_err(|e| TxPoolError::DecodeError(e.to_string()))?;
 
-        let tx_chain_id = envelope.chain_id().unwrap_or(self.chain_id);
-        if tx_chain_id != self.chain_id {
-            return Err(TxPoolError::InvalidChainId { got: tx_chain_id, expected: self.chain_id });
+        for stage in ADMISSION_PIPELINE {
+            stage.check(&envelope, self.chain_id, &self.config)?;
         }
 
+        self.recoveries.fetch_add(1, Ordering::Relaxed);
         let (sender, hash) = recover_sender_and_hash(&envelope)?;
 
         let effective_gas_price = effective_gas_price(&envelope);
-        if effective_gas_price < self.config.min_gas_price {
-            return Err(TxPoolError::GasPriceTooLow {
-                price: effective_gas_price,
-                min: self.config.min_gas_price,
-            });
-        }
-
-        let intrinsic_gas = intrinsic_gas(&envelope);
         let gas_limit = envelope.gas_limit();
-        if gas_limit < intrinsic_gas {
-            return Err(TxPoolError::IntrinsicGasTooLow {
-                limit: gas_limit,
-                intrinsic: intrinsic_gas,
-            });
-        }
-
         let nonce = envelope.nonce();
         let state_nonce =
             self.state.nonce(&sender).await.map_err(|e| TxPoolError::StateError(e.to_string()))?;
@@ -452,6 +518,46 @@ mod tests {
         assert!(matches!(result, Err(TxPoolError::InvalidChainId { got: 5, expected: 1 })));
     }
 
+    #[tokio::test]
+    async fn wrong_chain_id_skips_signature_recovery() {
+        let (sender, _, raw_tx) =
+            sign_eip1559_tx(5, 0, 21000, 1_000_000_000, U256::ZERO, Some(Address::ZERO));
+        let state =
+            MockState::new().with_account(sender, 0, U256::from(1_000_000_000_000_000_000u64));
+        let validator = TransactionValidator::new(1, state, PoolConfig::default());
+
+        let result = validator.validate(raw_tx).await;
+        assert!(matches!(result, Err(TxPoolError::InvalidChainId { .. })));
+        assert_eq!(validator.recoveries(), 0);
+
+        let (sender, _, raw_tx) =
+            sign_eip1559_tx(1, 0, 21000, 1_000_000_000, U256::ZERO, Some(Address::ZERO));
+        let state =
+            MockState::new().with_account(sender, 0, U256::from(1_000_000_000_000_000_000u64));
+        let validator = TransactionValidator::new(1, state, PoolConfig::default());
+        validator.validate(raw_tx).await.unwrap();
+        assert_eq!(validator.recoveries(), 1);
+    }
+
+    #[test]
+    fn admission_stages_check_independently() {
+        let config = PoolConfig::default();
+        let (_, envelope, _) =
+            sign_eip1559_tx(1, 0, 20_000, 1_000_000_000, U256::ZERO, Some(Address::ZERO));
+
+        assert!(AdmissionStage::CalldataSize.check(&envelope, 1, &config).is_ok());
+        assert!(AdmissionStage::ChainId.check(&envelope, 1, &config).is_ok());
+        assert!(matches!(
+            AdmissionStage::ChainId.check(&envelope, 2, &config),
+            Err(TxPoolError::InvalidChainId { got: 1, expected: 2 })
+        ));
+        assert!(AdmissionStage::FeeFloor.check(&envelope, 1, &config).is_ok());
+        assert!(matches!(
+            AdmissionStage::IntrinsicGas.check(&envelope, 1, &config),
+            Err(TxPoolError::IntrinsicGasTooLow { limit: 20_000, intrinsic: 21_000 })
+        ));
+    }
+
     #[tokio::test]
     async fn reject_gas_price_too_low() {
         let chain_id = 1u64;
@@ -1,23 +1,24 @@
 //! Account store bindings for commonware-storage.
 
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256};
 use commonware_cryptography::sha256::Digest as QmdbDigest;
 use commonware_storage::{kv::Batchable as _, qmdb::any::VariableConfig, translator::EightCap};
-use monmouth_qmdb::{AccountEncoding, QmdbBatchable, QmdbGettable};
+use monmouth_qmdb::{QmdbBatchable, QmdbGettable, QmdbRootable, QmdbScannable};
 
 use crate::{
     BackendError,
-    types::{AccountDb, AccountDbDirty, AccountKey, AccountValue, Context, StoreSlot},
+    types::{AccountDb, AccountDbDirty, AccountKey, AccountValue, Context, StoreSlot, scan_active},
 };
 
 /// Account partition backed by commonware-storage.
 ///
 /// Stores account state including nonce, balance, code hash, and generation number.
-/// Each account is keyed by its 20-byte address and encoded as a fixed 80-byte value
-/// using [`AccountEncoding`](monmouth_qmdb::AccountEncoding).
+/// Each account is keyed by its 20-byte address and stored as the bytes produced by
+/// the partition's [`AccountCodec`](monmouth_qmdb::AccountCodec), all of the same length.
 ///
-/// Implements [`QmdbGettable`] for reads and [`QmdbBatchable`] for batch writes.
-/// All writes are atomic and update the authenticated Merkle root.
+/// Implements [`QmdbGettable`] for reads, [`QmdbBatchable`] for batch writes, and
+/// [`QmdbScannable`] for enumerating accounts. All writes are atomic and update
+/// the authenticated Merkle root.
 pub struct AccountStore {
     inner: StoreSlot<AccountDb>,
 }
@@ -27,10 +28,10 @@ pub(crate) struct AccountStoreDirty {
 }
 
 impl AccountStore {
-    /// Initialize the account store.
+    /// Initialize the account store, reading values of `config.log_codec_config` bytes.
     pub async fn init(
         context: Context,
-        config: VariableConfig<EightCap, ()>,
+        config: VariableConfig<EightCap, usize>,
     ) -> Result<Self, BackendError> {
         let inner = AccountDb::init(context, config)
             .await
@@ -70,7 +71,7 @@ const fn account_key(address: Address) -> AccountKey {
 
 impl QmdbGettable for AccountStore {
     type Key = Address;
-    type Value = [u8; AccountEncoding::SIZE];
+    type Value = Vec<u8>;
     type Error = AccountStoreError;
 
     async fn get(&self, key: &Self::Key) -> Result<Option<Self::Value>, Self::Error> {
@@ -103,9 +104,37 @@ impl QmdbBatchable for AccountStore {
     }
 }
 
+impl QmdbScannable for AccountStore {
+    async fn scan(&self) -> Result<Vec<(Self::Key, Self::Value)>, Self::Error> {
+        let entries = scan_active(self.inner.get()?).await?;
+        Ok(entries
+            .into_iter()
+            .map(|(key, value)| (Address::from_slice(key.as_ref()), value.0))
+            .collect())
+    }
+}
+
+impl QmdbRootable for AccountStore {
+    fn root(&self) -> Result<B256, Self::Error> {
+        Ok(B256::from_slice(Self::root(self)?.as_ref()))
+    }
+
+    // A speculative root needs an uncommitted view of the partition, which only
+    // `CommonwareRootProvider` can open; handles over these stores always carry one.
+    async fn root_with<I>(&self, _ops: I) -> Result<B256, Self::Error>
+    where
+        I: IntoIterator<Item = (Self::Key, Option<Self::Value>)> + Send,
+        I::IntoIter: Send,
+    {
+        Err(BackendError::RootComputation(
+            "speculative roots require CommonwareRootProvider".to_string(),
+        ))
+    }
+}
+
 impl QmdbGettable for AccountStoreDirty {
     type Key = Address;
-    type Value = [u8; AccountEncoding::SIZE];
+    type Value = Vec<u8>;
     type Error = AccountStoreError;
 
     async fn get(&self, key: &Self::Key) -> Result<Option<Self::Value>, Self::Error> {
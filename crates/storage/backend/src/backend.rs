@@ -8,11 +8,12 @@ use commonware_runtime::{Metrics as _, buffer::PoolRef};
 use commonware_storage::{qmdb::any::VariableConfig, translator::EightCap};
 use commonware_utils::{NZU64, NZUsize};
 use monmouth_handlers::{HandleError, RootProvider};
-use monmouth_qmdb::{ChangeSet, QmdbStore, StateRoot};
+use monmouth_qmdb::{AccountCodec, ChangeSet, QmdbStore, StateRoot, V1, account_codec};
 
 use crate::{
     AccountStore, BackendError, CodeStore, QmdbBackendConfig, StorageStore,
-    accounts::AccountStoreDirty, code::CodeStoreDirty, storage::StorageStoreDirty, types::Context,
+    accounts::AccountStoreDirty, code::CodeStoreDirty, root_history::RootHistory,
+    storage::StorageStoreDirty, types::Context, version::ensure_format_version,
 };
 
 const CODE_MAX_BYTES: usize = 24_576;
@@ -25,20 +26,28 @@ pub struct CommonwareBackend {
     accounts: AccountStore,
     storage: StorageStore,
     code: CodeStore,
+    codec: &'static dyn AccountCodec,
     context: Context,
     config: QmdbBackendConfig,
 }
 
 /// Root provider that computes state roots from commonware-storage partitions.
+///
+/// Roots recorded with [`RootProvider::record_root`] are persisted in the
+/// `{prefix}-root-history` partition, keeping the last
+/// [`root_history_retention`](QmdbBackendConfig::root_history_retention) heights.
 #[derive(Clone)]
 pub struct CommonwareRootProvider {
     context: Context,
     config: QmdbBackendConfig,
+    codec: &'static dyn AccountCodec,
 }
 
 impl std::fmt::Debug for CommonwareBackend {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CommonwareBackend").finish_non_exhaustive()
+        f.debug_struct("CommonwareBackend")
+            .field("format_version", &self.codec.version())
+            .finish_non_exhaustive()
     }
 }
 
@@ -50,25 +59,51 @@ impl std::fmt::Debug for CommonwareRootProvider {
 
 impl CommonwareRootProvider {
     /// Create a new root provider from the given context and config.
+    ///
+    /// `codec` must be the account codec of the partitions, as returned by
+    /// [`CommonwareBackend::account_codec`].
     #[must_use]
-    pub const fn new(context: Context, config: QmdbBackendConfig) -> Self {
-        Self { context, config }
+    pub const fn new(
+        context: Context,
+        config: QmdbBackendConfig,
+        codec: &'static dyn AccountCodec,
+    ) -> Self {
+        Self { context, config, codec }
     }
 }
 
 impl CommonwareBackend {
     /// Open a backend with the given configuration.
+    ///
+    /// Fresh partitions are stamped with the format version of the configured
+    /// [`AccountFormat`](crate::AccountFormat) and partitions written before version
+    /// markers existed are migrated to it; partitions carrying a different version
+    /// are rejected with [`BackendError::UnsupportedFormatVersion`] before any store
+    /// is opened. Accounts are read and written with the [`AccountCodec`] of the
+    /// version on disk.
     pub async fn open(context: Context, config: QmdbBackendConfig) -> Result<Self, BackendError> {
-        let stores = open_stores(context.clone(), &config).await?;
+        let version = ensure_format_version(&context, &config).await?;
+        let codec = account_codec(version).map_err(|_| BackendError::UnsupportedFormatVersion {
+            found: version,
+            expected: config.account_format.version(),
+        })?;
+        let stores = open_stores(context.clone(), &config, codec).await?;
         Ok(Self {
             accounts: stores.accounts,
             storage: stores.storage,
             code: stores.code,
+            codec,
             context,
             config,
         })
     }
 
+    /// Codec of the account partition, selected from its format version.
+    #[must_use]
+    pub const fn account_codec(&self) -> &'static dyn AccountCodec {
+        self.codec
+    }
+
     /// Get a reference to the accounts store.
     #[must_use]
     pub const fn accounts(&self) -> &AccountStore {
@@ -106,13 +141,21 @@ impl CommonwareBackend {
     }
 
     /// Consume the backend and return the underlying stores.
+    ///
+    /// A [`QmdbStore`] built from them must use [`account_codec`](Self::account_codec);
+    /// [`into_store`](Self::into_store) does this.
     pub fn into_stores(self) -> (AccountStore, StorageStore, CodeStore) {
         (self.accounts, self.storage, self.code)
     }
 
+    /// Consume the backend and return a [`QmdbStore`] using its account codec.
+    pub fn into_store(self) -> QmdbStore<AccountStore, StorageStore, CodeStore> {
+        QmdbStore::new(self.accounts, self.storage, self.code).with_codec(self.codec)
+    }
+
     /// Build a root provider for this backend configuration.
     pub fn root_provider(&self) -> CommonwareRootProvider {
-        CommonwareRootProvider::new(self.context.clone(), self.config.clone())
+        CommonwareRootProvider::new(self.context.clone(), self.config.clone(), self.codec)
     }
 
     /// Get the current state root.
@@ -124,22 +167,23 @@ impl CommonwareBackend {
 #[async_trait]
 impl RootProvider for CommonwareRootProvider {
     async fn state_root(&self) -> Result<B256, HandleError> {
-        let stores = open_stores(self.context.clone(), &self.config)
+        let stores = open_stores(self.context.clone(), &self.config, self.codec)
             .await
             .map_err(|e| HandleError::RootComputation(e.to_string()))?;
         state_root_from_stores(&stores.accounts, &stores.storage, &stores.code)
             .map_err(|e| HandleError::RootComputation(e.to_string()))
     }
 
-    async fn compute_root(&mut self, changes: &ChangeSet) -> Result<B256, HandleError> {
+    async fn compute_root(&self, changes: &ChangeSet) -> Result<B256, HandleError> {
         if changes.is_empty() {
             return self.state_root().await;
         }
 
-        let stores = open_dirty_stores(self.context.clone(), &self.config)
+        let stores = open_dirty_stores(self.context.clone(), &self.config, self.codec)
             .await
             .map_err(|e| HandleError::RootComputation(e.to_string()))?;
-        let mut qmdb = QmdbStore::new(stores.accounts, stores.storage, stores.code);
+        let mut qmdb =
+            QmdbStore::new(stores.accounts, stores.storage, stores.code).with_codec(self.codec);
         qmdb.commit_changes(changes.clone())
             .await
             .map_err(|e| HandleError::RootComputation(e.to_string()))?;
@@ -153,6 +197,20 @@ impl RootProvider for CommonwareRootProvider {
     async fn commit_and_get_root(&mut self) -> Result<B256, HandleError> {
         self.state_root().await
     }
+
+    async fn record_root(&mut self, height: u64, root: B256) -> Result<(), HandleError> {
+        RootHistory::new(self.config.root_history_retention)
+            .record(&self.context, &self.config.partition_prefix, height, root)
+            .await
+            .map_err(|e| HandleError::RootComputation(e.to_string()))
+    }
+
+    async fn root_at(&self, height: u64) -> Result<Option<B256>, HandleError> {
+        RootHistory::new(self.config.root_history_retention)
+            .get(&self.context, &self.config.partition_prefix, height)
+            .await
+            .map_err(|e| HandleError::RootComputation(e.to_string()))
+    }
 }
 
 struct Stores {
@@ -167,18 +225,50 @@ struct DirtyStores {
     code: CodeStoreDirty,
 }
 
+/// Name of the partitions holding accounts in the layout of format `version`.
+///
+/// Layouts differ in value length, so each gets its own partitions; the classic
+/// layout keeps the original name.
+pub(crate) fn accounts_store_name(version: u32) -> String {
+    if version <= V1::VERSION { "accounts".to_string() } else { format!("accounts-v{version}") }
+}
+
+/// MMR journal, MMR metadata, and log partitions of store `name`.
+pub(crate) fn store_partitions(prefix: &str, name: &str) -> [String; 3] {
+    [
+        format!("{prefix}-{name}-mmr"),
+        format!("{prefix}-{name}-mmr-meta"),
+        format!("{prefix}-{name}-log"),
+    ]
+}
+
+/// Config of the account store read and written with `codec`.
+pub(crate) fn account_store_config(
+    config: &QmdbBackendConfig,
+    codec: &dyn AccountCodec,
+) -> VariableConfig<EightCap, usize> {
+    store_config(
+        &config.partition_prefix,
+        &accounts_store_name(codec.version()),
+        config.buffer_pool.clone(),
+        codec.encoded_len(),
+    )
+}
+
 fn store_config<C>(
     prefix: &str,
     name: &str,
     buffer_pool: PoolRef,
     log_codec_config: C,
 ) -> VariableConfig<EightCap, C> {
+    let [mmr_journal_partition, mmr_metadata_partition, log_partition] =
+        store_partitions(prefix, name);
     VariableConfig {
-        mmr_journal_partition: format!("{prefix}-{name}-mmr"),
-        mmr_metadata_partition: format!("{prefix}-{name}-mmr-meta"),
+        mmr_journal_partition,
+        mmr_metadata_partition,
         mmr_items_per_blob: NZU64!(128),
         mmr_write_buffer: NZUsize!(1024 * 1024),
-        log_partition: format!("{prefix}-{name}-log"),
+        log_partition,
         log_write_buffer: NZUsize!(1024 * 1024),
         log_compression: None,
         log_codec_config,
@@ -189,17 +279,24 @@ fn store_config<C>(
     }
 }
 
-async fn open_stores(context: Context, config: &QmdbBackendConfig) -> Result<Stores, BackendError> {
-    let accounts = AccountStore::init(
-        context.with_label("accounts"),
-        store_config(&config.partition_prefix, "accounts", config.buffer_pool.clone(), ()),
-    )
-    .await
-    .map_err(|e| BackendError::Storage(e.to_string()))?;
+async fn open_stores(
+    context: Context,
+    config: &QmdbBackendConfig,
+    codec: &dyn AccountCodec,
+) -> Result<Stores, BackendError> {
+    let accounts =
+        AccountStore::init(context.with_label("accounts"), account_store_config(config, codec))
+            .await
+            .map_err(|e| BackendError::Storage(e.to_string()))?;
 
     let storage = StorageStore::init(
         context.with_label("storage"),
-        store_config(&config.partition_prefix, "storage", config.buffer_pool.clone(), ()),
+        store_config(
+            &config.partition_prefix,
+            "storage",
+            config.buffer_pool.clone(),
+            config.storage_encoding,
+        ),
     )
     .await
     .map_err(|e| BackendError::Storage(e.to_string()))?;
@@ -222,8 +319,9 @@ async fn open_stores(context: Context, config: &QmdbBackendConfig) -> Result<Sto
 async fn open_dirty_stores(
     context: Context,
     config: &QmdbBackendConfig,
+    codec: &dyn AccountCodec,
 ) -> Result<DirtyStores, BackendError> {
-    let stores = open_stores(context, config).await?;
+    let stores = open_stores(context, config, codec).await?;
     Ok(DirtyStores {
         accounts: stores.accounts.into_dirty()?,
         storage: stores.storage.into_dirty()?,
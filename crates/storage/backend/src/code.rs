@@ -3,7 +3,7 @@
 use alloy_primitives::B256;
 use commonware_cryptography::sha256::Digest as QmdbDigest;
 use commonware_storage::{kv::Batchable as _, qmdb::any::VariableConfig, translator::EightCap};
-use monmouth_qmdb::{QmdbBatchable, QmdbGettable};
+use monmouth_qmdb::{QmdbBatchable, QmdbGettable, QmdbRootable};
 
 use crate::{
     BackendError,
@@ -99,6 +99,22 @@ impl QmdbBatchable for CodeStore {
     }
 }
 
+impl QmdbRootable for CodeStore {
+    fn root(&self) -> Result<B256, Self::Error> {
+        Ok(B256::from_slice(Self::root(self)?.as_ref()))
+    }
+
+    async fn root_with<I>(&self, _ops: I) -> Result<B256, Self::Error>
+    where
+        I: IntoIterator<Item = (Self::Key, Option<Self::Value>)> + Send,
+        I::IntoIter: Send,
+    {
+        Err(BackendError::RootComputation(
+            "speculative roots require CommonwareRootProvider".to_string(),
+        ))
+    }
+}
+
 impl QmdbGettable for CodeStoreDirty {
     type Key = B256;
     type Value = Vec<u8>;
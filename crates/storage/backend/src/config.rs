@@ -1,6 +1,113 @@
 //! Configuration types for the backend.
 
+use std::time::Duration;
+
 use commonware_runtime::buffer::PoolRef;
+use monmouth_qmdb::{V1, V2};
+
+/// Default number of times a transient commit failure is retried.
+pub const DEFAULT_COMMIT_RETRIES: u32 = 3;
+
+/// Default delay before the first commit retry.
+pub const DEFAULT_COMMIT_RETRY_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Default number of historical state roots kept by the root provider.
+pub const DEFAULT_ROOT_HISTORY_RETENTION: u64 = 1024;
+
+/// Retry policy for transient I/O failures while committing state.
+///
+/// The delay doubles after each failed attempt, starting at `backoff`. Only
+/// failures a store reports as transient I/O are retried; the commonware stores
+/// give up their partition handle when a batch write fails, so their write
+/// errors are never retried.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommitRetryPolicy {
+    /// Maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry.
+    pub backoff: Duration,
+}
+
+impl CommitRetryPolicy {
+    /// Create a policy with the given retry budget and initial backoff.
+    pub const fn new(max_retries: u32, backoff: Duration) -> Self {
+        Self { max_retries, backoff }
+    }
+
+    /// Policy that never retries.
+    pub const fn disabled() -> Self {
+        Self::new(0, Duration::ZERO)
+    }
+
+    /// Delay before retry number `retry` (zero-based).
+    pub fn delay(&self, retry: u32) -> Duration {
+        self.backoff.saturating_mul(1u32.checked_shl(retry).unwrap_or(u32::MAX))
+    }
+}
+
+impl Default for CommitRetryPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_COMMIT_RETRIES, DEFAULT_COMMIT_RETRY_BACKOFF)
+    }
+}
+
+/// On-disk encoding of values in the storage partition.
+///
+/// The values carry no marker distinguishing the two layouts, so the encoding
+/// is recorded in the partition prefix's format version marker and a partition
+/// opened with a different encoding is rejected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StorageValueEncoding {
+    /// Every value as a 32-byte big-endian word.
+    #[default]
+    Full,
+    /// A length byte followed by the value's minimal big-endian bytes, so small
+    /// values such as booleans and counters take one or two bytes.
+    Compact,
+}
+
+impl StorageValueEncoding {
+    /// Byte recording this encoding in the format version marker.
+    pub(crate) const fn tag(self) -> u8 {
+        match self {
+            Self::Full => 0,
+            Self::Compact => 1,
+        }
+    }
+
+    /// The encoding recorded as `tag`, if any.
+    pub(crate) const fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(Self::Full),
+            1 => Some(Self::Compact),
+            _ => None,
+        }
+    }
+}
+
+/// Layout of values in the account partition.
+///
+/// Each layout is one on-disk format version, recorded in the version marker when
+/// the partitions are created; its [`AccountCodec`](monmouth_qmdb::AccountCodec)
+/// is selected from that marker.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AccountFormat {
+    /// Nonce, balance, code hash, and storage generation, the [`V1`] layout.
+    #[default]
+    Classic,
+    /// The classic fields followed by optional agent metadata, the [`V2`] layout.
+    Extended,
+}
+
+impl AccountFormat {
+    /// Format version of partitions written with this layout.
+    pub const fn version(self) -> u32 {
+        match self {
+            Self::Classic => V1::VERSION,
+            Self::Extended => V2::VERSION,
+        }
+    }
+}
 
 /// Configuration for the full QMDB backend.
 #[derive(Clone)]
@@ -9,12 +116,63 @@ pub struct QmdbBackendConfig {
     pub partition_prefix: String,
     /// Buffer pool shared by underlying QMDB partitions.
     pub buffer_pool: PoolRef,
+    /// Retry policy for transient commit failures.
+    pub commit_retry: CommitRetryPolicy,
+    /// Layout of account partition values.
+    ///
+    /// Partitions in another format are rejected until [`migrate`](crate::migrate)d.
+    pub account_format: AccountFormat,
+    /// Encoding of storage partition values.
+    ///
+    /// Recorded when the partitions are created; reopening them with another
+    /// encoding fails with [`BackendError::StorageEncodingMismatch`](crate::BackendError::StorageEncodingMismatch).
+    pub storage_encoding: StorageValueEncoding,
+    /// Number of most recent block heights whose state roots are kept.
+    ///
+    /// Zero disables the root history. A partition must always be opened with
+    /// the retention it was created with.
+    pub root_history_retention: u64,
 }
 
 impl QmdbBackendConfig {
     /// Create a new backend config for the given partition prefix.
     pub fn new(partition_prefix: impl Into<String>, buffer_pool: PoolRef) -> Self {
-        Self { partition_prefix: partition_prefix.into(), buffer_pool }
+        Self {
+            partition_prefix: partition_prefix.into(),
+            buffer_pool,
+            commit_retry: CommitRetryPolicy::default(),
+            account_format: AccountFormat::Classic,
+            storage_encoding: StorageValueEncoding::Full,
+            root_history_retention: DEFAULT_ROOT_HISTORY_RETENTION,
+        }
+    }
+
+    /// Set the retry policy for transient commit failures.
+    #[must_use]
+    pub const fn with_commit_retry(mut self, commit_retry: CommitRetryPolicy) -> Self {
+        self.commit_retry = commit_retry;
+        self
+    }
+
+    /// Set the layout of account partition values.
+    #[must_use]
+    pub const fn with_account_format(mut self, account_format: AccountFormat) -> Self {
+        self.account_format = account_format;
+        self
+    }
+
+    /// Set the encoding of storage partition values.
+    #[must_use]
+    pub const fn with_storage_encoding(mut self, storage_encoding: StorageValueEncoding) -> Self {
+        self.storage_encoding = storage_encoding;
+        self
+    }
+
+    /// Set how many recent state roots the root provider keeps.
+    #[must_use]
+    pub const fn with_root_history_retention(mut self, root_history_retention: u64) -> Self {
+        self.root_history_retention = root_history_retention;
+        self
     }
 }
 
@@ -22,6 +180,37 @@ impl std::fmt::Debug for QmdbBackendConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("QmdbBackendConfig")
             .field("partition_prefix", &self.partition_prefix)
+            .field("commit_retry", &self.commit_retry)
+            .field("account_format", &self.account_format)
+            .field("storage_encoding", &self.storage_encoding)
+            .field("root_history_retention", &self.root_history_retention)
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_doubles() {
+        let policy = CommitRetryPolicy::new(3, Duration::from_millis(10));
+        assert_eq!(policy.delay(0), Duration::from_millis(10));
+        assert_eq!(policy.delay(1), Duration::from_millis(20));
+        assert_eq!(policy.delay(2), Duration::from_millis(40));
+    }
+
+    #[test]
+    fn storage_encoding_tag_roundtrip() {
+        for encoding in [StorageValueEncoding::Full, StorageValueEncoding::Compact] {
+            assert_eq!(StorageValueEncoding::from_tag(encoding.tag()), Some(encoding));
+        }
+        assert_eq!(StorageValueEncoding::from_tag(2), None);
+    }
+
+    #[test]
+    fn retry_delay_saturates() {
+        let policy = CommitRetryPolicy::new(u32::MAX, Duration::from_secs(1));
+        assert_eq!(policy.delay(64), Duration::from_secs(u64::from(u32::MAX)));
+    }
+}
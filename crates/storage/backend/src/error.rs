@@ -2,6 +2,8 @@
 
 use thiserror::Error;
 
+use crate::StorageValueEncoding;
+
 /// Error type for backend operations.
 #[derive(Debug, Error)]
 pub enum BackendError {
@@ -24,6 +26,24 @@ pub enum BackendError {
     /// State root computation failed.
     #[error("root computation failed: {0}")]
     RootComputation(String),
+
+    /// On-disk format version does not match this release.
+    #[error("unsupported format version: found {found}, expected {expected}")]
+    UnsupportedFormatVersion {
+        /// Version recorded on disk.
+        found: u32,
+        /// Version supported by this release.
+        expected: u32,
+    },
+
+    /// Storage values on disk use a different encoding than configured.
+    #[error("storage encoding mismatch: partitions use {found:?}, configured {expected:?}")]
+    StorageEncodingMismatch {
+        /// Encoding recorded on disk.
+        found: StorageValueEncoding,
+        /// Encoding the backend was configured with.
+        expected: StorageValueEncoding,
+    },
 }
 
 #[cfg(test)]
@@ -60,6 +80,24 @@ mod tests {
         assert_eq!(err.to_string(), "root computation failed: merkle tree failed");
     }
 
+    #[test]
+    fn test_unsupported_format_version_display() {
+        let err = BackendError::UnsupportedFormatVersion { found: 2, expected: 1 };
+        assert_eq!(err.to_string(), "unsupported format version: found 2, expected 1");
+    }
+
+    #[test]
+    fn test_storage_encoding_mismatch_display() {
+        let err = BackendError::StorageEncodingMismatch {
+            found: StorageValueEncoding::Compact,
+            expected: StorageValueEncoding::Full,
+        };
+        assert_eq!(
+            err.to_string(),
+            "storage encoding mismatch: partitions use Compact, configured Full"
+        );
+    }
+
     #[test]
     fn test_backend_error_debug() {
         let err = BackendError::NotInitialized;
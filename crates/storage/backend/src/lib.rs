@@ -17,13 +17,28 @@ mod code;
 pub use code::{CodeStore, CodeStoreError};
 
 mod config;
-pub use config::QmdbBackendConfig;
+pub use config::{
+    AccountFormat, CommitRetryPolicy, DEFAULT_COMMIT_RETRIES, DEFAULT_COMMIT_RETRY_BACKOFF,
+    DEFAULT_ROOT_HISTORY_RETENTION, QmdbBackendConfig, StorageValueEncoding,
+};
 
 mod error;
 pub use error::BackendError;
 
+mod migration;
+pub use migration::{
+    LEGACY_FORMAT_VERSION, MigrationReport, MigrationStep, migrate, migrate_to_current,
+};
+
 mod partition;
 pub use partition::PartitionState;
 
+mod root_history;
+
 mod storage;
 pub use storage::{StorageStore, StorageStoreError};
+
+mod version;
+pub use version::{
+    FORMAT_VERSION, FormatMarker, read_format_marker, read_format_version, write_format_version,
+};
@@ -0,0 +1,409 @@
+//! Migration between QMDB on-disk format versions.
+
+use commonware_runtime::{Error as RuntimeError, Metrics as _, Storage as _};
+use monmouth_qmdb::{AccountCodec, QmdbBatchable as _, QmdbScannable as _, V1, V2};
+
+use crate::{
+    AccountStore, BackendError, QmdbBackendConfig,
+    backend::{account_store_config, accounts_store_name, store_partitions},
+    types::Context,
+    version::{FORMAT_VERSION, check_storage_encoding, read_format_marker, write_format_version},
+};
+
+/// Format version assumed for partitions written before version markers existed.
+pub const LEGACY_FORMAT_VERSION: u32 = 0;
+
+/// A single upgrade step between adjacent format versions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MigrationStep {
+    /// Version the step upgrades from.
+    pub from: u32,
+    /// Version the step upgrades to.
+    pub to: u32,
+    /// Human-readable summary of the rewrite performed.
+    pub description: &'static str,
+}
+
+/// Registered upgrade steps, ordered by source version.
+///
+/// Each step must leave the partitions readable by the next step. The version
+/// marker is only advanced after a step's data rewrite completes, so an
+/// interrupted migration resumes from the last completed step; data the step
+/// replaced is only removed once the marker has advanced.
+const STEPS: &[MigrationStep] = &[
+    MigrationStep {
+        from: 0,
+        to: 1,
+        description: "record format version marker (partition layout unchanged)",
+    },
+    MigrationStep {
+        from: 1,
+        to: 2,
+        description: "rewrite accounts in the extended layout (changes the account partition root)",
+    },
+];
+
+/// Outcome of a migration run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// Version found on disk before migrating.
+    pub from: u32,
+    /// Version requested by the caller.
+    pub to: u32,
+    /// Steps applied, or that would be applied in dry-run mode.
+    pub steps: Vec<MigrationStep>,
+    /// Whether the run only reported the plan without writing.
+    pub dry_run: bool,
+}
+
+impl MigrationReport {
+    /// Returns `true` if the store was already at the requested version.
+    pub const fn is_noop(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+/// Upgrade the partitions under `config` to `to_version`.
+///
+/// Partitions without a version marker are treated as [`LEGACY_FORMAT_VERSION`].
+/// With `dry_run` set, the plan is returned without touching disk. Running the
+/// migration again after it completed (or after a crash) is safe.
+///
+/// # Errors
+///
+/// Returns [`BackendError::UnsupportedFormatVersion`] if the on-disk version is
+/// newer than `to_version`, [`BackendError::StorageEncodingMismatch`] if the
+/// marker records another storage encoding than `config`, or
+/// [`BackendError::Config`] if no upgrade path exists.
+pub async fn migrate(
+    context: &Context,
+    config: &QmdbBackendConfig,
+    to_version: u32,
+    dry_run: bool,
+) -> Result<MigrationReport, BackendError> {
+    let prefix = &config.partition_prefix;
+    let marker = read_format_marker(context, prefix).await?;
+    if let Some(marker) = marker {
+        check_storage_encoding(marker, config)?;
+    }
+    let from = marker.map_or(LEGACY_FORMAT_VERSION, |marker| marker.version);
+    let steps = plan(from, to_version)?;
+
+    if !dry_run {
+        for step in &steps {
+            apply(context, config, step).await?;
+            write_format_version(context, prefix, step.to, config.storage_encoding).await?;
+        }
+        // Also covers steps completed by an earlier run that stopped before cleaning up.
+        let current = steps.last().map_or(from, |step| step.to);
+        for step in STEPS.iter().filter(|step| step.to <= current) {
+            finish(context, config, step).await?;
+        }
+    }
+
+    Ok(MigrationReport { from, to: to_version, steps, dry_run })
+}
+
+/// Upgrade the partitions under `config` to the version of its configured
+/// [`AccountFormat`](crate::AccountFormat).
+pub async fn migrate_to_current(
+    context: &Context,
+    config: &QmdbBackendConfig,
+    dry_run: bool,
+) -> Result<MigrationReport, BackendError> {
+    migrate(context, config, config.account_format.version(), dry_run).await
+}
+
+fn plan(from: u32, to: u32) -> Result<Vec<MigrationStep>, BackendError> {
+    if from > to {
+        return Err(BackendError::UnsupportedFormatVersion { found: from, expected: to });
+    }
+
+    let mut steps = Vec::new();
+    let mut current = from;
+    while current < to {
+        let step = STEPS.iter().find(|step| step.from == current).ok_or_else(|| {
+            BackendError::Config(format!("no migration path from format version {current}"))
+        })?;
+        steps.push(*step);
+        current = step.to;
+    }
+    Ok(steps)
+}
+
+/// Rewrite the data of `step`, leaving the data it replaces in place.
+async fn apply(
+    context: &Context,
+    config: &QmdbBackendConfig,
+    step: &MigrationStep,
+) -> Result<(), BackendError> {
+    match (step.from, step.to) {
+        // Version 1 only introduced the marker; the data layout is unchanged.
+        (0, 1) => Ok(()),
+        (1, 2) => rewrite_accounts(context, config, &V1, &V2).await,
+        (from, to) => {
+            Err(BackendError::Config(format!("migration step {from} -> {to} is not implemented")))
+        }
+    }
+}
+
+/// Remove the data `step` replaced, once the marker records its target version.
+async fn finish(
+    context: &Context,
+    config: &QmdbBackendConfig,
+    step: &MigrationStep,
+) -> Result<(), BackendError> {
+    match (step.from, step.to) {
+        (1, 2) => remove_store(context, config, &accounts_store_name(V1::VERSION)).await,
+        _ => Ok(()),
+    }
+}
+
+/// Copy every account from the `from` layout's partitions into fresh partitions
+/// in the `to` layout.
+///
+/// The accounts are read into memory and written as one batch. A copy left
+/// behind by an interrupted run is discarded first.
+async fn rewrite_accounts(
+    context: &Context,
+    config: &QmdbBackendConfig,
+    from: &dyn AccountCodec,
+    to: &dyn AccountCodec,
+) -> Result<(), BackendError> {
+    remove_store(context, config, &accounts_store_name(to.version())).await?;
+
+    let source = AccountStore::init(
+        context.with_label("migrate_accounts"),
+        account_store_config(config, from),
+    )
+    .await?;
+    let accounts = source.scan().await?;
+    drop(source);
+
+    let mut rewritten = Vec::with_capacity(accounts.len());
+    for (address, bytes) in accounts {
+        let account = from.decode(&bytes).map_err(|e| BackendError::Storage(e.to_string()))?;
+        rewritten.push((address, Some(to.encode(&account))));
+    }
+    let mut target = AccountStore::init(
+        context.with_label("migrate_accounts"),
+        account_store_config(config, to),
+    )
+    .await?;
+    target.write_batch(rewritten).await
+}
+
+/// Remove every partition of store `name`, skipping ones that do not exist.
+async fn remove_store(
+    context: &Context,
+    config: &QmdbBackendConfig,
+    name: &str,
+) -> Result<(), BackendError> {
+    for partition in store_partitions(&config.partition_prefix, name) {
+        match context.remove(&partition, None).await {
+            Ok(()) | Err(RuntimeError::PartitionMissing(_)) => {}
+            Err(e) => return Err(BackendError::Storage(e.to_string())),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use alloy_primitives::{Address, B256, U256};
+    use commonware_runtime::{Runner as _, buffer::PoolRef, tokio};
+    use commonware_utils::{NZU16, NZUsize};
+    use monmouth_qmdb::{AccountUpdate, ChangeSet, StorageKey};
+
+    use super::*;
+    use crate::{AccountFormat, CommonwareBackend, version::read_format_version};
+
+    fn test_config(prefix: &str) -> QmdbBackendConfig {
+        QmdbBackendConfig::new(prefix, PoolRef::new(NZU16!(1024), NZUsize!(10)))
+    }
+
+    fn sample_changes() -> ChangeSet {
+        let mut changes = ChangeSet::new();
+        changes.insert(
+            Address::repeat_byte(0x11),
+            AccountUpdate {
+                created: true,
+                selfdestructed: false,
+                nonce: 3,
+                balance: U256::from(1_000u64),
+                code_hash: B256::ZERO,
+                code: None,
+                storage: BTreeMap::from([(U256::from(1u64), U256::from(42u64))]),
+            },
+        );
+        changes
+    }
+
+    /// Commit [`sample_changes`] under `config` and return the resulting state root.
+    async fn commit_sample(context: &Context, config: &QmdbBackendConfig) -> B256 {
+        let backend = CommonwareBackend::open(context.clone(), config.clone()).await.unwrap();
+        let mut store = backend.into_store();
+        store.commit_changes(sample_changes()).await.unwrap();
+        drop(store);
+
+        let backend = CommonwareBackend::open(context.clone(), config.clone()).await.unwrap();
+        backend.state_root().unwrap()
+    }
+
+    /// Write some state, then rewind the marker so the store looks like an old release wrote it.
+    async fn legacy_fixture(context: &Context, config: &QmdbBackendConfig) -> B256 {
+        let root = commit_sample(context, config).await;
+        write_format_version(
+            context,
+            &config.partition_prefix,
+            LEGACY_FORMAT_VERSION,
+            config.storage_encoding,
+        )
+        .await
+        .unwrap();
+        root
+    }
+
+    async fn partition_exists(context: &Context, partition: &str) -> bool {
+        match context.scan(partition).await {
+            Ok(blobs) => !blobs.is_empty(),
+            Err(RuntimeError::PartitionMissing(_)) => false,
+            Err(e) => panic!("scan failed: {e}"),
+        }
+    }
+
+    #[test]
+    fn plan_rejects_downgrade() {
+        let err = plan(FORMAT_VERSION + 1, FORMAT_VERSION).unwrap_err();
+        assert!(matches!(err, BackendError::UnsupportedFormatVersion { .. }));
+    }
+
+    #[test]
+    fn plan_without_path_errors() {
+        assert!(matches!(plan(0, FORMAT_VERSION + 1), Err(BackendError::Config(_))));
+    }
+
+    #[test]
+    fn migrate_legacy_store_preserves_state() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let config = test_config("migration-legacy");
+            let root_before = legacy_fixture(&context, &config).await;
+
+            assert!(matches!(
+                CommonwareBackend::open(context.clone(), config.clone()).await,
+                Err(BackendError::UnsupportedFormatVersion { found: LEGACY_FORMAT_VERSION, .. })
+            ));
+
+            let dry = migrate_to_current(&context, &config, true).await.unwrap();
+            assert!(dry.dry_run);
+            assert_eq!(dry.from, LEGACY_FORMAT_VERSION);
+            assert_eq!(dry.steps.len(), 1);
+            assert_eq!(
+                read_format_version(&context, &config.partition_prefix).await.unwrap(),
+                Some(LEGACY_FORMAT_VERSION)
+            );
+
+            let report = migrate_to_current(&context, &config, false).await.unwrap();
+            assert_eq!(report.steps, dry.steps);
+
+            let backend = CommonwareBackend::open(context.clone(), config.clone()).await.unwrap();
+            assert_eq!(backend.state_root().unwrap(), root_before);
+            drop(backend);
+
+            let rerun = migrate_to_current(&context, &config, false).await.unwrap();
+            assert!(rerun.is_noop());
+            assert_eq!(rerun.from, config.account_format.version());
+        });
+    }
+
+    #[test]
+    fn migrate_classic_accounts_to_extended() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let classic = test_config("migration-v1");
+            let classic_root = commit_sample(&context, &classic).await;
+            let extended = classic.clone().with_account_format(AccountFormat::Extended);
+
+            assert!(matches!(
+                CommonwareBackend::open(context.clone(), extended.clone()).await,
+                Err(BackendError::UnsupportedFormatVersion { found: 1, .. })
+            ));
+
+            let dry = migrate_to_current(&context, &extended, true).await.unwrap();
+            assert_eq!(dry.from, V1::VERSION);
+            assert_eq!(dry.steps.iter().map(|step| step.to).collect::<Vec<_>>(), [V2::VERSION]);
+
+            migrate_to_current(&context, &extended, false).await.unwrap();
+            assert_eq!(
+                read_format_version(&context, &extended.partition_prefix).await.unwrap(),
+                Some(V2::VERSION)
+            );
+            let [.., old_log] = store_partitions(&extended.partition_prefix, "accounts");
+            assert!(!partition_exists(&context, &old_log).await);
+
+            let backend = CommonwareBackend::open(context.clone(), extended.clone()).await.unwrap();
+            let migrated_root = backend.state_root().unwrap();
+            let store = backend.into_store();
+            let address = Address::repeat_byte(0x11);
+            let account = store.get_account(&address).await.unwrap().unwrap();
+            assert_eq!((account.nonce, account.balance), (3, U256::from(1_000u64)));
+            assert_eq!(account.agent, None);
+            let key = StorageKey::new(address, account.generation, U256::from(1u64));
+            assert_eq!(store.get_storage(&key).await.unwrap(), Some(U256::from(42u64)));
+            drop(store);
+
+            // The account partition root changes with the layout, and matches a
+            // store that was extended from the start.
+            let fresh =
+                test_config("migration-v2-fresh").with_account_format(AccountFormat::Extended);
+            assert_ne!(migrated_root, classic_root);
+            assert_eq!(migrated_root, commit_sample(&context, &fresh).await);
+        });
+    }
+
+    #[test]
+    fn interrupted_account_rewrite_resumes() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let classic = test_config("migration-resume");
+            commit_sample(&context, &classic).await;
+            let extended = classic.clone().with_account_format(AccountFormat::Extended);
+            let step = STEPS[1];
+
+            // Crash after the rewrite, before the marker advanced: the partial copy
+            // is discarded and the step runs again.
+            apply(&context, &extended, &step).await.unwrap();
+            let report = migrate_to_current(&context, &extended, false).await.unwrap();
+            assert_eq!(report.steps, [step]);
+
+            // Crash after the marker advanced, before the old partitions were
+            // removed: a rerun finishes the cleanup.
+            let stale = test_config("migration-resume-stale");
+            commit_sample(&context, &stale).await;
+            let stale = stale.with_account_format(AccountFormat::Extended);
+            apply(&context, &stale, &step).await.unwrap();
+            write_format_version(
+                &context,
+                &stale.partition_prefix,
+                step.to,
+                stale.storage_encoding,
+            )
+            .await
+            .unwrap();
+            let [.., old_log] = store_partitions(&stale.partition_prefix, "accounts");
+            assert!(partition_exists(&context, &old_log).await);
+
+            assert!(migrate_to_current(&context, &stale, false).await.unwrap().is_noop());
+            assert!(!partition_exists(&context, &old_log).await);
+
+            let backend = CommonwareBackend::open(context.clone(), extended).await.unwrap();
+            let resumed = backend.state_root().unwrap();
+            drop(backend);
+            let backend = CommonwareBackend::open(context.clone(), stale).await.unwrap();
+            assert_eq!(backend.state_root().unwrap(), resumed);
+        });
+    }
+}
@@ -0,0 +1,172 @@
+//! Height-indexed history of committed state roots.
+
+use alloy_primitives::B256;
+use commonware_runtime::{Blob as _, Storage as _};
+
+use crate::{BackendError, types::Context};
+
+const HISTORY_BLOB: &[u8] = b"roots";
+
+/// Presence flag, big-endian height, root.
+const RECORD_LEN: u64 = 1 + 8 + 32;
+
+fn history_partition(prefix: &str) -> String {
+    format!("{prefix}-root-history")
+}
+
+/// Ring of the last `retention` state roots, one fixed-size slot per height.
+///
+/// The root for height `h` lives in slot `h % retention`, so recording a new
+/// height overwrites the one `retention` blocks older. A partition must always
+/// be opened with the retention it was written with.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RootHistory {
+    retention: u64,
+}
+
+impl RootHistory {
+    /// History keeping the last `retention` roots; zero disables recording.
+    pub(crate) const fn new(retention: u64) -> Self {
+        Self { retention }
+    }
+
+    const fn offset(&self, height: u64) -> u64 {
+        (height % self.retention) * RECORD_LEN
+    }
+
+    /// Record `root` for `height`.
+    pub(crate) async fn record(
+        &self,
+        context: &Context,
+        partition_prefix: &str,
+        height: u64,
+        root: B256,
+    ) -> Result<(), BackendError> {
+        if self.retention == 0 {
+            return Ok(());
+        }
+        let (blob, _) = context
+            .open(&history_partition(partition_prefix), HISTORY_BLOB)
+            .await
+            .map_err(|e| BackendError::Storage(e.to_string()))?;
+        let mut record = Vec::with_capacity(RECORD_LEN as usize);
+        record.push(1);
+        record.extend_from_slice(&height.to_be_bytes());
+        record.extend_from_slice(root.as_slice());
+        blob.write_at(record, self.offset(height))
+            .await
+            .map_err(|e| BackendError::Storage(e.to_string()))?;
+        blob.sync().await.map_err(|e| BackendError::Storage(e.to_string()))
+    }
+
+    /// Root recorded for `height`, if it is still retained.
+    pub(crate) async fn get(
+        &self,
+        context: &Context,
+        partition_prefix: &str,
+        height: u64,
+    ) -> Result<Option<B256>, BackendError> {
+        if self.retention == 0 {
+            return Ok(None);
+        }
+        let (blob, len) = context
+            .open(&history_partition(partition_prefix), HISTORY_BLOB)
+            .await
+            .map_err(|e| BackendError::Storage(e.to_string()))?;
+        let offset = self.offset(height);
+        if len < offset + RECORD_LEN {
+            return Ok(None);
+        }
+        let buf = blob
+            .read_at(vec![0u8; RECORD_LEN as usize], offset)
+            .await
+            .map_err(|e| BackendError::Storage(e.to_string()))?;
+        let record = buf.as_ref();
+        let stored_height = u64::from_be_bytes(
+            record[1..9]
+                .try_into()
+                .map_err(|_| BackendError::Storage("malformed root history record".to_string()))?,
+        );
+        if record[0] != 1 || stored_height != height {
+            return Ok(None);
+        }
+        Ok(Some(B256::from_slice(&record[9..])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use ::tokio::sync::RwLock;
+    use alloy_primitives::{Address, KECCAK256_EMPTY, U256};
+    use commonware_runtime::{Runner as _, buffer::PoolRef, tokio};
+    use commonware_utils::{NZU16, NZUsize};
+    use monmouth_handlers::{QmdbHandle, RootProvider as _};
+    use monmouth_qmdb::{AccountUpdate, ChangeSet, V1};
+
+    use super::*;
+    use crate::{CommonwareBackend, CommonwareRootProvider, QmdbBackendConfig};
+
+    fn test_config(prefix: &str) -> QmdbBackendConfig {
+        QmdbBackendConfig::new(prefix, PoolRef::new(NZU16!(1024), NZUsize!(10)))
+    }
+
+    fn transfer(balance: u64) -> ChangeSet {
+        let mut changes = ChangeSet::new();
+        changes.insert(
+            Address::repeat_byte(0x01),
+            AccountUpdate {
+                created: false,
+                selfdestructed: false,
+                nonce: 0,
+                balance: U256::from(balance),
+                code_hash: KECCAK256_EMPTY,
+                code: None,
+                storage: Default::default(),
+            },
+        );
+        changes
+    }
+
+    #[test]
+    fn serves_root_for_each_committed_height() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let backend = CommonwareBackend::open(context, test_config("root-history-serve"))
+                .await
+                .expect("open");
+            let provider = backend.root_provider();
+            let handle = QmdbHandle::from_store(backend.into_store())
+                .with_root_provider(Arc::new(RwLock::new(provider)));
+
+            let mut roots = Vec::new();
+            for height in 1..=3u64 {
+                roots.push(handle.commit_at_height(height, transfer(height * 100)).await.unwrap());
+            }
+            assert_ne!(roots[0], roots[1]);
+
+            for (height, root) in (1u64..).zip(&roots) {
+                assert_eq!(handle.root_at(height).await.unwrap(), Some(*root));
+            }
+            assert_eq!(handle.root_at(0).await.unwrap(), None);
+            assert_eq!(handle.root_at(4).await.unwrap(), None);
+        });
+    }
+
+    #[test]
+    fn drops_roots_beyond_retention() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let config = test_config("root-history-retention").with_root_history_retention(2);
+            let mut provider = CommonwareRootProvider::new(context.clone(), config, &V1);
+            for height in 1..=3u64 {
+                provider.record_root(height, B256::repeat_byte(height as u8)).await.unwrap();
+            }
+
+            assert_eq!(provider.root_at(1).await.unwrap(), None);
+            assert!(provider.root_at(2).await.unwrap().is_some());
+            assert!(provider.root_at(3).await.unwrap().is_some());
+        });
+    }
+}
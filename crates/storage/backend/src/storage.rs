@@ -1,14 +1,15 @@
 //! Storage store bindings for commonware-storage.
 
-use alloy_primitives::U256;
+use alloy_primitives::{B256, U256};
 use commonware_cryptography::sha256::Digest as QmdbDigest;
 use commonware_storage::{kv::Batchable as _, qmdb::any::VariableConfig, translator::EightCap};
-use monmouth_qmdb::{QmdbBatchable, QmdbGettable, StorageKey};
+use monmouth_qmdb::{QmdbBatchable, QmdbGettable, QmdbRootable, QmdbScannable, StorageKey};
 
 use crate::{
-    BackendError,
+    BackendError, StorageValueEncoding,
     types::{
         Context, StorageDb, StorageDbDirty, StorageKey as StorageKeyBytes, StorageValue, StoreSlot,
+        scan_active_matching,
     },
 };
 
@@ -16,28 +17,33 @@ use crate::{
 ///
 /// Stores contract storage slots as key-value pairs. Keys are composite tuples of
 /// (address, generation, slot) encoded via [`StorageKey`], and values are 32-byte
-/// [`U256`] integers.
+/// [`U256`] integers, written as full 32-byte words or compactly per the
+/// [`StorageValueEncoding`] the store was opened with.
 ///
-/// Implements [`QmdbGettable`] for reads and [`QmdbBatchable`] for batch writes.
-/// All writes are atomic and update the authenticated Merkle root.
+/// Implements [`QmdbGettable`] for reads, [`QmdbBatchable`] for batch writes, and
+/// [`QmdbScannable`] for enumerating slots. All writes are atomic and update the
+/// authenticated Merkle root.
 pub struct StorageStore {
     inner: StoreSlot<StorageDb>,
+    encoding: StorageValueEncoding,
 }
 
 pub(crate) struct StorageStoreDirty {
     inner: StorageDbDirty,
+    encoding: StorageValueEncoding,
 }
 
 impl StorageStore {
-    /// Initialize the storage store.
+    /// Initialize the storage store, encoding values as `config.log_codec_config`.
     pub async fn init(
         context: Context,
-        config: VariableConfig<EightCap, ()>,
+        config: VariableConfig<EightCap, StorageValueEncoding>,
     ) -> Result<Self, BackendError> {
+        let encoding = config.log_codec_config;
         let inner = StorageDb::init(context, config)
             .await
             .map_err(|e| BackendError::Storage(e.to_string()))?;
-        Ok(Self { inner: StoreSlot::new(inner) })
+        Ok(Self { inner: StoreSlot::new(inner), encoding })
     }
 
     /// Return the current authenticated root for the storage partition.
@@ -47,7 +53,7 @@ impl StorageStore {
 
     pub(crate) fn into_dirty(self) -> Result<StorageStoreDirty, BackendError> {
         let inner = self.inner.into_inner()?;
-        Ok(StorageStoreDirty { inner: inner.into_mutable() })
+        Ok(StorageStoreDirty { inner: inner.into_mutable(), encoding: self.encoding })
     }
 }
 
@@ -70,6 +76,11 @@ fn storage_key(key: StorageKey) -> StorageKeyBytes {
     StorageKeyBytes::new(key.to_bytes())
 }
 
+fn decode_storage_key(key: &StorageKeyBytes) -> StorageKey {
+    let bytes: &[u8; 60] = key.as_ref().try_into().expect("storage keys are 60 bytes");
+    StorageKey::from_bytes(bytes)
+}
+
 impl QmdbGettable for StorageStore {
     type Key = StorageKey;
     type Value = U256;
@@ -94,8 +105,10 @@ impl QmdbBatchable for StorageStore {
     {
         let inner = self.inner.take()?;
         let mut dirty = inner.into_mutable();
-        let mapped =
-            ops.into_iter().map(|(key, value)| (storage_key(key), value.map(StorageValue)));
+        let encoding = self.encoding;
+        let mapped = ops.into_iter().map(move |(key, value)| {
+            (storage_key(key), value.map(|value| StorageValue(value, encoding)))
+        });
         dirty.write_batch(mapped).await.map_err(|e| BackendError::Storage(e.to_string()))?;
         let merkleized = dirty.into_merkleized();
         let (inner, _) =
@@ -105,6 +118,37 @@ impl QmdbBatchable for StorageStore {
     }
 }
 
+impl QmdbScannable for StorageStore {
+    async fn scan(&self) -> Result<Vec<(Self::Key, Self::Value)>, Self::Error> {
+        self.scan_matching(|_| true).await
+    }
+
+    async fn scan_matching<P>(&self, keep: P) -> Result<Vec<(Self::Key, Self::Value)>, Self::Error>
+    where
+        P: Fn(&Self::Key) -> bool + Send + Sync,
+    {
+        let entries =
+            scan_active_matching(self.inner.get()?, |key| keep(&decode_storage_key(key))).await?;
+        Ok(entries.into_iter().map(|(key, value)| (decode_storage_key(&key), value.0)).collect())
+    }
+}
+
+impl QmdbRootable for StorageStore {
+    fn root(&self) -> Result<B256, Self::Error> {
+        Ok(B256::from_slice(Self::root(self)?.as_ref()))
+    }
+
+    async fn root_with<I>(&self, _ops: I) -> Result<B256, Self::Error>
+    where
+        I: IntoIterator<Item = (Self::Key, Option<Self::Value>)> + Send,
+        I::IntoIter: Send,
+    {
+        Err(BackendError::RootComputation(
+            "speculative roots require CommonwareRootProvider".to_string(),
+        ))
+    }
+}
+
 impl QmdbGettable for StorageStoreDirty {
     type Key = StorageKey;
     type Value = U256;
@@ -126,8 +170,64 @@ impl QmdbBatchable for StorageStoreDirty {
         I: IntoIterator<Item = (Self::Key, Option<Self::Value>)> + Send,
         I::IntoIter: Send,
     {
-        let mapped =
-            ops.into_iter().map(|(key, value)| (storage_key(key), value.map(StorageValue)));
+        let encoding = self.encoding;
+        let mapped = ops.into_iter().map(move |(key, value)| {
+            (storage_key(key), value.map(|value| StorageValue(value, encoding)))
+        });
         self.inner.write_batch(mapped).await.map_err(|e| BackendError::Storage(e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use alloy_primitives::{Address, B256, U256};
+    use commonware_runtime::{Runner as _, buffer::PoolRef, tokio};
+    use commonware_utils::{NZU16, NZUsize};
+    use monmouth_qmdb::{AccountUpdate, ChangeSet};
+
+    use crate::{CommonwareBackend, QmdbBackendConfig};
+
+    fn update(slots: &[(u64, u64)]) -> AccountUpdate {
+        AccountUpdate {
+            created: true,
+            selfdestructed: false,
+            nonce: 1,
+            balance: U256::ZERO,
+            code_hash: B256::ZERO,
+            code: None,
+            storage: slots
+                .iter()
+                .map(|&(slot, value)| (U256::from(slot), U256::from(value)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn scan_storage_returns_only_the_accounts_slots() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let config = QmdbBackendConfig::new(
+                "storage-scan-matching",
+                PoolRef::new(NZU16!(1024), NZUsize!(10)),
+            );
+            let mut store = CommonwareBackend::open(context, config).await.unwrap().into_store();
+            let (alice, bob) = (Address::repeat_byte(0xA1), Address::repeat_byte(0xB0));
+            let mut changes = ChangeSet::new();
+            changes.insert(alice, update(&[(1, 10), (2, 20)]));
+            changes.insert(bob, update(&[(1, 99)]));
+            store.commit_changes(changes).await.unwrap();
+
+            let generation = store.get_account(&alice).await.unwrap().unwrap().generation;
+            assert_eq!(
+                store.scan_storage(&alice, generation).await.unwrap(),
+                BTreeMap::from([
+                    (U256::from(1u64), U256::from(10u64)),
+                    (U256::from(2u64), U256::from(20u64)),
+                ])
+            );
+            assert!(store.scan_storage(&alice, generation + 1).await.unwrap().is_empty());
+        });
+    }
+}
@@ -1,26 +1,31 @@
 //! Commonware QMDB type aliases and codecs.
 
+use std::{collections::BTreeSet, num::NonZeroU64};
+
 use alloy_primitives::U256;
 use bytes::{Buf, BufMut};
-use commonware_codec::{EncodeSize, Error as CodecError, Read, Write};
+use commonware_codec::{CodecShared, EncodeSize, Error as CodecError, Read, Write};
 use commonware_cryptography::sha256::Sha256 as QmdbHasher;
 use commonware_runtime::tokio;
 use commonware_storage::{
-    qmdb::{NonDurable, Unmerkleized, any},
+    qmdb::{NonDurable, Unmerkleized, any, any::unordered::variable::Operation},
     translator::EightCap,
 };
-use commonware_utils::sequence::FixedBytes;
-use monmouth_qmdb::AccountEncoding;
+use commonware_utils::{Array, NZU64, sequence::FixedBytes};
 
-use crate::BackendError;
+use crate::{BackendError, StorageValueEncoding};
 
 pub(crate) type Context = tokio::Context;
 pub(crate) type AccountKey = FixedBytes<20>;
 pub(crate) type StorageKey = FixedBytes<60>;
 pub(crate) type CodeKey = FixedBytes<32>;
 
+/// Account value as encoded by the partition's [`AccountCodec`](monmouth_qmdb::AccountCodec).
+///
+/// Every account in a partition has the codec's fixed length, so values are
+/// written without a length prefix and read back at the length given as config.
 #[derive(Clone, Debug)]
-pub(crate) struct AccountValue(pub [u8; AccountEncoding::SIZE]);
+pub(crate) struct AccountValue(pub Vec<u8>);
 
 impl Write for AccountValue {
     fn write(&self, buf: &mut impl BufMut) {
@@ -30,48 +35,83 @@ impl Write for AccountValue {
 
 impl EncodeSize for AccountValue {
     fn encode_size(&self) -> usize {
-        AccountEncoding::SIZE
+        self.0.len()
     }
 }
 
 impl Read for AccountValue {
-    type Cfg = ();
+    type Cfg = usize;
 
-    fn read_cfg(buf: &mut impl Buf, _: &Self::Cfg) -> Result<Self, CodecError> {
-        if buf.remaining() < AccountEncoding::SIZE {
+    fn read_cfg(buf: &mut impl Buf, len: &Self::Cfg) -> Result<Self, CodecError> {
+        if buf.remaining() < *len {
             return Err(CodecError::EndOfBuffer);
         }
-        let mut out = [0u8; AccountEncoding::SIZE];
+        let mut out = vec![0u8; *len];
         buf.copy_to_slice(&mut out);
         Ok(Self(out))
     }
 }
 
+/// Storage slot value, written with the partition's [`StorageValueEncoding`].
 #[derive(Clone, Copy, Debug)]
-pub(crate) struct StorageValue(pub U256);
+pub(crate) struct StorageValue(pub U256, pub StorageValueEncoding);
+
+impl StorageValue {
+    /// Number of significant big-endian bytes in the value.
+    const fn significant_bytes(&self) -> usize {
+        self.0.byte_len()
+    }
+}
 
 impl Write for StorageValue {
     fn write(&self, buf: &mut impl BufMut) {
-        buf.put_slice(&self.0.to_be_bytes::<32>());
+        let bytes = self.0.to_be_bytes::<32>();
+        match self.1 {
+            StorageValueEncoding::Full => buf.put_slice(&bytes),
+            StorageValueEncoding::Compact => {
+                let len = self.significant_bytes();
+                buf.put_u8(len as u8);
+                buf.put_slice(&bytes[32 - len..]);
+            }
+        }
     }
 }
 
 impl EncodeSize for StorageValue {
     fn encode_size(&self) -> usize {
-        32
+        match self.1 {
+            StorageValueEncoding::Full => 32,
+            StorageValueEncoding::Compact => 1 + self.significant_bytes(),
+        }
     }
 }
 
 impl Read for StorageValue {
-    type Cfg = ();
-
-    fn read_cfg(buf: &mut impl Buf, _: &Self::Cfg) -> Result<Self, CodecError> {
-        if buf.remaining() < 32 {
+    type Cfg = StorageValueEncoding;
+
+    fn read_cfg(buf: &mut impl Buf, encoding: &Self::Cfg) -> Result<Self, CodecError> {
+        let len = match encoding {
+            StorageValueEncoding::Full => 32,
+            StorageValueEncoding::Compact => {
+                if !buf.has_remaining() {
+                    return Err(CodecError::EndOfBuffer);
+                }
+                let len = usize::from(buf.get_u8());
+                if len > 32 {
+                    return Err(CodecError::InvalidLength(len));
+                }
+                len
+            }
+        };
+        if buf.remaining() < len {
             return Err(CodecError::EndOfBuffer);
         }
         let mut out = [0u8; 32];
-        buf.copy_to_slice(&mut out);
-        Ok(Self(U256::from_be_bytes(out)))
+        buf.copy_to_slice(&mut out[32 - len..]);
+        if *encoding == StorageValueEncoding::Compact && len > 0 && out[32 - len] == 0 {
+            return Err(CodecError::Invalid("StorageValue", "non-minimal compact encoding"));
+        }
+        Ok(Self(U256::from_be_bytes(out), *encoding))
     }
 }
 
@@ -134,52 +174,139 @@ impl<T> StoreSlot<T> {
     }
 }
 
+/// Operations read per batch when scanning a partition's log.
+const SCAN_BATCH: NonZeroU64 = NZU64!(1024);
+
+/// Every active key-value pair of `db`, in key order.
+pub(crate) async fn scan_active<K, V>(
+    db: &any::unordered::variable::Db<Context, K, V, QmdbHasher, EightCap>,
+) -> Result<Vec<(K, V)>, BackendError>
+where
+    K: Array,
+    V: CodecShared + Clone,
+{
+    scan_active_matching(db, |_| true).await
+}
+
+/// Every active key-value pair of `db` whose key satisfies `keep`, in key order.
+///
+/// Every active key was last updated at or above the inactivity floor, so the
+/// log is read from there; each updated key that `keep` accepts is then looked
+/// up to skip keys that were overwritten or deleted since. The log walk covers
+/// the whole partition whatever `keep` selects, since keys are unordered.
+pub(crate) async fn scan_active_matching<K, V, P>(
+    db: &any::unordered::variable::Db<Context, K, V, QmdbHasher, EightCap>,
+    keep: P,
+) -> Result<Vec<(K, V)>, BackendError>
+where
+    K: Array,
+    V: CodecShared + Clone,
+    P: Fn(&K) -> bool + Send + Sync,
+{
+    let storage = |e: commonware_storage::qmdb::Error| BackendError::Storage(e.to_string());
+    let end = db.op_count();
+    let mut loc = db.inactivity_floor_loc();
+    let mut keys = BTreeSet::new();
+    while loc < end {
+        let (_, ops) = db.proof(loc, SCAN_BATCH).await.map_err(storage)?;
+        if ops.is_empty() {
+            break;
+        }
+        loc = loc + ops.len() as u64;
+        keys.extend(ops.into_iter().filter_map(|op| match op {
+            Operation::Update(update) if keep(&update.0) => Some(update.0),
+            _ => None,
+        }));
+    }
+
+    let mut entries = Vec::with_capacity(keys.len());
+    for key in keys {
+        if let Some(value) = db.get(&key).await.map_err(storage)? {
+            entries.push((key, value));
+        }
+    }
+    Ok(entries)
+}
+
 #[cfg(test)]
 mod tests {
-    use commonware_codec::{DecodeExt, Encode};
+    use commonware_codec::{Decode as _, Encode};
+    use monmouth_qmdb::AccountEncoding;
 
     use super::*;
 
     #[test]
     fn test_account_value_roundtrip() {
-        let mut data = [0u8; AccountEncoding::SIZE];
+        let mut data = vec![0u8; AccountEncoding::SIZE];
         data[0] = 0x42;
         data[79] = 0xFF;
-        let value = AccountValue(data);
+        let value = AccountValue(data.clone());
 
         let encoded = value.encode();
-        let decoded = AccountValue::decode(encoded).unwrap();
+        let decoded = AccountValue::decode_cfg(encoded, &AccountEncoding::SIZE).unwrap();
         assert_eq!(decoded.0, data);
     }
 
     #[test]
     fn test_account_value_encode_size() {
-        let value = AccountValue([0u8; AccountEncoding::SIZE]);
+        let value = AccountValue(vec![0u8; AccountEncoding::SIZE]);
         assert_eq!(value.encode_size(), AccountEncoding::SIZE);
     }
 
+    #[test]
+    fn test_account_value_rejects_short_input() {
+        let value = AccountValue(vec![0u8; AccountEncoding::SIZE - 1]);
+        assert!(AccountValue::decode_cfg(value.encode(), &AccountEncoding::SIZE).is_err());
+    }
+
     #[test]
     fn test_storage_value_roundtrip() {
-        let value = StorageValue(U256::from(12345678u64));
+        let value = StorageValue(U256::from(12345678u64), StorageValueEncoding::Full);
         let encoded = value.encode();
-        let decoded = StorageValue::decode(encoded).unwrap();
+        let decoded = StorageValue::decode_cfg(encoded, &StorageValueEncoding::Full).unwrap();
         assert_eq!(decoded.0, value.0);
     }
 
     #[test]
     fn test_storage_value_max() {
-        let value = StorageValue(U256::MAX);
+        let value = StorageValue(U256::MAX, StorageValueEncoding::Full);
         let encoded = value.encode();
-        let decoded = StorageValue::decode(encoded).unwrap();
+        let decoded = StorageValue::decode_cfg(encoded, &StorageValueEncoding::Full).unwrap();
         assert_eq!(decoded.0, U256::MAX);
     }
 
     #[test]
     fn test_storage_value_encode_size() {
-        let value = StorageValue(U256::ZERO);
+        let value = StorageValue(U256::ZERO, StorageValueEncoding::Full);
         assert_eq!(value.encode_size(), 32);
     }
 
+    #[test]
+    fn test_compact_storage_value_roundtrip() {
+        for (value, size) in [
+            (U256::ZERO, 1),
+            (U256::from(1u64), 2),
+            (U256::from(0x1234u64), 3),
+            (U256::from(u64::MAX), 9),
+            (U256::MAX, 33),
+        ] {
+            let encoded = StorageValue(value, StorageValueEncoding::Compact).encode();
+            assert_eq!(encoded.len(), size);
+            let decoded =
+                StorageValue::decode_cfg(encoded, &StorageValueEncoding::Compact).unwrap();
+            assert_eq!(decoded.0, value);
+        }
+    }
+
+    #[test]
+    fn test_compact_storage_value_rejects_malformed() {
+        let compact = StorageValueEncoding::Compact;
+        assert!(StorageValue::decode_cfg(&[33u8][..], &compact).is_err());
+        assert!(StorageValue::decode_cfg(&[2u8, 0x01][..], &compact).is_err());
+        assert!(StorageValue::decode_cfg(&[1u8, 0x00][..], &compact).is_err());
+        assert!(StorageValue::decode_cfg(&[0u8; 0][..], &compact).is_err());
+    }
+
     #[test]
     fn test_store_slot_get_succeeds() {
         let slot = StoreSlot::new(42);
@@ -220,4 +347,52 @@ mod tests {
         slot.take().unwrap();
         assert!(slot.into_inner().is_err());
     }
+
+    #[test]
+    fn test_scan_returns_only_active_entries() {
+        use alloy_primitives::Address;
+        use commonware_runtime::{Runner as _, buffer::PoolRef};
+        use commonware_utils::{NZU16, NZUsize};
+        use monmouth_qmdb::{QmdbBatchable as _, QmdbScannable as _, StorageKey};
+
+        use crate::{CommonwareBackend, QmdbBackendConfig};
+
+        tokio::Runner::default().start(|context| async move {
+            let config =
+                QmdbBackendConfig::new("scan-active", PoolRef::new(NZU16!(1024), NZUsize!(10)));
+            let backend = CommonwareBackend::open(context, config).await.unwrap();
+            let (mut accounts, mut storage, _) = backend.into_stores();
+
+            let account = |byte| Some(vec![byte; AccountEncoding::SIZE]);
+            accounts
+                .write_batch([
+                    (Address::repeat_byte(1), account(1)),
+                    (Address::repeat_byte(2), account(2)),
+                    (Address::repeat_byte(3), account(3)),
+                ])
+                .await
+                .unwrap();
+            accounts
+                .write_batch([
+                    (Address::repeat_byte(2), account(4)),
+                    (Address::repeat_byte(3), None),
+                ])
+                .await
+                .unwrap();
+            let mut scanned = accounts.scan().await.unwrap();
+            scanned.sort();
+            assert_eq!(
+                scanned,
+                vec![
+                    (Address::repeat_byte(1), vec![1; AccountEncoding::SIZE]),
+                    (Address::repeat_byte(2), vec![4; AccountEncoding::SIZE]),
+                ]
+            );
+
+            let slot = StorageKey::new(Address::repeat_byte(1), 0, U256::from(7));
+            storage.write_batch([(slot, Some(U256::from(1)))]).await.unwrap();
+            storage.write_batch([(slot, Some(U256::from(2)))]).await.unwrap();
+            assert_eq!(storage.scan().await.unwrap(), vec![(slot, U256::from(2))]);
+        });
+    }
 }
@@ -0,0 +1,356 @@
+//! On-disk format version marker for QMDB partitions.
+
+use commonware_runtime::{Blob as _, Error as RuntimeError, Storage as _};
+
+use crate::{
+    BackendError, QmdbBackendConfig, StorageValueEncoding,
+    migration::{LEGACY_FORMAT_VERSION, migrate},
+    types::Context,
+};
+
+/// Newest on-disk format version this release reads and writes.
+///
+/// Format versions share one space with the account codecs: partitions are
+/// stamped with the version of their configured [`AccountFormat`](crate::AccountFormat),
+/// and the codec registered under that version reads them. Bump this whenever
+/// the encoding of any QMDB partition changes.
+pub const FORMAT_VERSION: u32 = monmouth_qmdb::V2::VERSION;
+
+const VERSION_BLOB: &[u8] = b"format-version";
+/// Marker length before the storage encoding was recorded: the version alone.
+const VERSION_ONLY_LEN: u64 = 4;
+/// Marker length: the version followed by the storage encoding tag.
+const MARKER_LEN: u64 = 5;
+
+/// Store names whose log partitions hold data once anything has been committed.
+const STORE_NAMES: [&str; 3] = ["accounts", "storage", "code"];
+
+/// Contents of a partition prefix's format version marker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormatMarker {
+    /// On-disk format version.
+    pub version: u32,
+    /// Encoding of the storage partition's values.
+    pub storage_encoding: StorageValueEncoding,
+}
+
+fn version_partition(prefix: &str) -> String {
+    format!("{prefix}-version")
+}
+
+/// Read the format version marker recorded for a partition prefix.
+///
+/// Returns `None` if no marker has been written yet. Markers written before
+/// the storage encoding was recorded read as [`StorageValueEncoding::Full`],
+/// the only encoding available then.
+pub async fn read_format_marker(
+    context: &Context,
+    partition_prefix: &str,
+) -> Result<Option<FormatMarker>, BackendError> {
+    let (blob, len) = context
+        .open(&version_partition(partition_prefix), VERSION_BLOB)
+        .await
+        .map_err(|e| BackendError::Storage(e.to_string()))?;
+    if len == 0 {
+        return Ok(None);
+    }
+    if len != VERSION_ONLY_LEN && len != MARKER_LEN {
+        return Err(BackendError::Storage(format!("malformed format version marker: {len} bytes")));
+    }
+    let buf = blob
+        .read_at(vec![0u8; len as usize], 0)
+        .await
+        .map_err(|e| BackendError::Storage(e.to_string()))?;
+    let (version, tag) = buf.as_ref().split_at(VERSION_ONLY_LEN as usize);
+    let version: [u8; 4] = version
+        .try_into()
+        .map_err(|_| BackendError::Storage("malformed format version marker".to_string()))?;
+    let storage_encoding = match tag.first() {
+        None => StorageValueEncoding::Full,
+        Some(&tag) => StorageValueEncoding::from_tag(tag).ok_or_else(|| {
+            BackendError::Storage(format!("unknown storage encoding tag {tag} in version marker"))
+        })?,
+    };
+    Ok(Some(FormatMarker { version: u32::from_be_bytes(version), storage_encoding }))
+}
+
+/// Read the format version recorded for a partition prefix.
+///
+/// Returns `None` if no marker has been written yet.
+pub async fn read_format_version(
+    context: &Context,
+    partition_prefix: &str,
+) -> Result<Option<u32>, BackendError> {
+    Ok(read_format_marker(context, partition_prefix).await?.map(|marker| marker.version))
+}
+
+/// Write the format version marker for a partition prefix.
+pub async fn write_format_version(
+    context: &Context,
+    partition_prefix: &str,
+    version: u32,
+    storage_encoding: StorageValueEncoding,
+) -> Result<(), BackendError> {
+    let (blob, _) = context
+        .open(&version_partition(partition_prefix), VERSION_BLOB)
+        .await
+        .map_err(|e| BackendError::Storage(e.to_string()))?;
+    let mut marker = version.to_be_bytes().to_vec();
+    marker.push(storage_encoding.tag());
+    blob.write_at(marker, 0).await.map_err(|e| BackendError::Storage(e.to_string()))?;
+    blob.sync().await.map_err(|e| BackendError::Storage(e.to_string()))
+}
+
+/// Check the format version marker, stamping partitions that carry none.
+///
+/// Fresh partitions are stamped with the version of the configured account
+/// format and the configured storage encoding. Partitions that already hold data
+/// but carry no marker were written before markers existed; they are upgraded
+/// from [`LEGACY_FORMAT_VERSION`] by [`migrate`], which stamps the marker once
+/// the registered steps have run. Returns the format version of the partitions.
+///
+/// # Errors
+///
+/// Returns [`BackendError::UnsupportedFormatVersion`] if the marker records
+/// another version than the configured account format, and
+/// [`BackendError::StorageEncodingMismatch`] if it records another storage
+/// encoding than `config`.
+pub(crate) async fn ensure_format_version(
+    context: &Context,
+    config: &QmdbBackendConfig,
+) -> Result<u32, BackendError> {
+    let partition_prefix = &config.partition_prefix;
+    let expected = config.account_format.version();
+    match read_format_marker(context, partition_prefix).await? {
+        None if has_store_data(context, partition_prefix).await? => {
+            migrate(context, config, expected, false).await.map(|report| report.to)
+        }
+        None => write_format_version(context, partition_prefix, expected, config.storage_encoding)
+            .await
+            .map(|()| expected),
+        Some(marker) if marker.version != expected => {
+            Err(BackendError::UnsupportedFormatVersion { found: marker.version, expected })
+        }
+        Some(marker) => check_storage_encoding(marker, config).map(|()| marker.version),
+    }
+}
+
+/// Reject a marker whose storage encoding differs from the configured one.
+pub(crate) fn check_storage_encoding(
+    marker: FormatMarker,
+    config: &QmdbBackendConfig,
+) -> Result<(), BackendError> {
+    if marker.storage_encoding == config.storage_encoding {
+        Ok(())
+    } else {
+        Err(BackendError::StorageEncodingMismatch {
+            found: marker.storage_encoding,
+            expected: config.storage_encoding,
+        })
+    }
+}
+
+/// Returns `true` if any store log partition under the prefix contains blobs.
+async fn has_store_data(context: &Context, partition_prefix: &str) -> Result<bool, BackendError> {
+    for name in STORE_NAMES {
+        match context.scan(&format!("{partition_prefix}-{name}-log")).await {
+            Ok(blobs) if !blobs.is_empty() => return Ok(true),
+            Ok(_) | Err(RuntimeError::PartitionMissing(_)) => {}
+            Err(e) => return Err(BackendError::Storage(e.to_string())),
+        }
+    }
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use alloy_primitives::{Address, B256, U256};
+    use commonware_runtime::{Runner as _, buffer::PoolRef, tokio};
+    use commonware_utils::{NZU16, NZUsize};
+    use monmouth_qmdb::{AccountUpdate, ChangeSet, V1, V2};
+
+    use super::*;
+    use crate::{AccountFormat, CommonwareBackend, QmdbBackendConfig};
+
+    fn test_config(prefix: &str) -> QmdbBackendConfig {
+        QmdbBackendConfig::new(prefix, PoolRef::new(NZU16!(1024), NZUsize!(10)))
+    }
+
+    #[test]
+    fn open_stamps_fresh_partitions() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let prefix = "format-version-fresh";
+            assert_eq!(read_format_version(&context, prefix).await.unwrap(), None);
+
+            let backend =
+                CommonwareBackend::open(context.clone(), test_config(prefix)).await.expect("open");
+
+            assert_eq!(read_format_version(&context, prefix).await.unwrap(), Some(V1::VERSION));
+            assert_eq!(backend.account_codec().version(), V1::VERSION);
+        });
+    }
+
+    #[test]
+    fn open_upgrades_unmarked_stores() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let prefix = "format-version-unmarked";
+            let backend =
+                CommonwareBackend::open(context.clone(), test_config(prefix)).await.expect("open");
+            let mut store = backend.into_store();
+            let mut changes = ChangeSet::new();
+            changes.insert(
+                Address::repeat_byte(0x11),
+                AccountUpdate {
+                    created: true,
+                    selfdestructed: false,
+                    nonce: 1,
+                    balance: U256::from(7u64),
+                    code_hash: B256::ZERO,
+                    code: None,
+                    storage: BTreeMap::new(),
+                },
+            );
+            store.commit_changes(changes).await.unwrap();
+            drop(store);
+            let backend =
+                CommonwareBackend::open(context.clone(), test_config(prefix)).await.expect("open");
+            let root = backend.state_root().unwrap();
+            drop(backend);
+
+            // Drop the marker, as a release predating markers would have left the store.
+            context.remove(&version_partition(prefix), None).await.unwrap();
+            assert_eq!(read_format_version(&context, prefix).await.unwrap(), None);
+
+            let backend = CommonwareBackend::open(context.clone(), test_config(prefix))
+                .await
+                .expect("unmarked store is upgraded on open");
+
+            assert_eq!(read_format_version(&context, prefix).await.unwrap(), Some(V1::VERSION));
+            assert_eq!(backend.state_root().unwrap(), root);
+        });
+    }
+
+    #[test]
+    fn open_rejects_mismatched_version() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let prefix = "format-version-mismatch";
+            write_format_version(&context, prefix, FORMAT_VERSION + 1, StorageValueEncoding::Full)
+                .await
+                .unwrap();
+
+            let err = CommonwareBackend::open(context.clone(), test_config(prefix))
+                .await
+                .expect_err("mismatched version must be rejected");
+
+            assert!(matches!(
+                err,
+                BackendError::UnsupportedFormatVersion { found, expected }
+                    if found == FORMAT_VERSION + 1 && expected == V1::VERSION
+            ));
+        });
+    }
+
+    #[test]
+    fn open_rejects_mismatched_storage_encoding() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let prefix = "format-version-encoding";
+            let compact = test_config(prefix).with_storage_encoding(StorageValueEncoding::Compact);
+            CommonwareBackend::open(context.clone(), compact.clone()).await.expect("open");
+            assert_eq!(
+                read_format_marker(&context, prefix).await.unwrap(),
+                Some(FormatMarker {
+                    version: V1::VERSION,
+                    storage_encoding: StorageValueEncoding::Compact
+                })
+            );
+
+            let err = CommonwareBackend::open(context.clone(), test_config(prefix))
+                .await
+                .expect_err("reopening with another encoding must be rejected");
+            assert!(matches!(
+                err,
+                BackendError::StorageEncodingMismatch {
+                    found: StorageValueEncoding::Compact,
+                    expected: StorageValueEncoding::Full,
+                }
+            ));
+
+            CommonwareBackend::open(context.clone(), compact).await.expect("matching encoding");
+        });
+    }
+
+    #[test]
+    fn version_only_marker_reads_as_full_encoding() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let prefix = "format-version-version-only";
+            let (blob, _) = context.open(&version_partition(prefix), VERSION_BLOB).await.unwrap();
+            blob.write_at(V1::VERSION.to_be_bytes().to_vec(), 0).await.unwrap();
+            blob.sync().await.unwrap();
+
+            assert_eq!(
+                read_format_marker(&context, prefix).await.unwrap(),
+                Some(FormatMarker {
+                    version: V1::VERSION,
+                    storage_encoding: StorageValueEncoding::Full
+                })
+            );
+            CommonwareBackend::open(context.clone(), test_config(prefix)).await.expect("open");
+        });
+    }
+
+    #[test]
+    fn open_selects_codec_of_account_format() {
+        let executor = tokio::Runner::default();
+        executor.start(|context| async move {
+            let prefix = "format-version-extended";
+            let extended = test_config(prefix).with_account_format(AccountFormat::Extended);
+            let backend =
+                CommonwareBackend::open(context.clone(), extended.clone()).await.expect("open");
+            assert_eq!(read_format_version(&context, prefix).await.unwrap(), Some(V2::VERSION));
+            assert_eq!(backend.account_codec().version(), V2::VERSION);
+
+            let address = Address::repeat_byte(0x22);
+            let mut store = backend.into_store();
+            let mut changes = ChangeSet::new();
+            changes.insert(
+                address,
+                AccountUpdate {
+                    created: true,
+                    selfdestructed: false,
+                    nonce: 2,
+                    balance: U256::from(9u64),
+                    code_hash: B256::ZERO,
+                    code: None,
+                    storage: BTreeMap::new(),
+                },
+            );
+            store.commit_changes(changes).await.unwrap();
+            drop(store);
+
+            let err = CommonwareBackend::open(context.clone(), test_config(prefix))
+                .await
+                .expect_err("extended partitions must not open as classic");
+            assert!(matches!(
+                err,
+                BackendError::UnsupportedFormatVersion {
+                    found: V2::VERSION,
+                    expected: V1::VERSION
+                }
+            ));
+
+            let store = CommonwareBackend::open(context.clone(), extended)
+                .await
+                .expect("reopen")
+                .into_store();
+            let account = store.get_account(&address).await.unwrap().expect("account");
+            assert_eq!((account.nonce, account.balance), (2, U256::from(9u64)));
+        });
+    }
+}
@@ -4,10 +4,12 @@
 //! to bridge the async QMDB traits into the sync REVM interface. This is acceptable for
 //! in-memory stores but may block the async runtime for I/O-bound stores.
 
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 use alloy_primitives::{Address, B256, Bytes, KECCAK256_EMPTY, U256};
-use monmouth_qmdb::{AccountEncoding, ChangeSet, QmdbBatchable, QmdbGettable, StorageKey};
+use monmouth_qmdb::{
+    ChangeSet, QmdbBatchable, QmdbGettable, QmdbScannable, StorageKey, StoredAccount,
+};
 use revm::{
     bytecode::Bytecode,
     database_interface::{
@@ -79,33 +81,135 @@ fn block_on<F: std::future::Future>(f: F) -> F::Output {
     futures::executor::block_on(f)
 }
 
+/// Convert a decoded account record into REVM account info.
+const fn account_info(account: StoredAccount) -> revm::state::AccountInfo {
+    revm::state::AccountInfo {
+        nonce: account.nonce,
+        balance: account.balance,
+        code_hash: account.code_hash,
+        code: None,
+        account_id: None,
+    }
+}
+
+impl<A, S, C> QmdbHandle<A, S, C>
+where
+    A: QmdbGettable<Key = Address, Value = Vec<u8>>,
+    S: QmdbGettable<Key = StorageKey, Value = U256>,
+    C: QmdbGettable<Key = B256, Value = Vec<u8>>,
+{
+    /// Fetch account info for many addresses under a single read lock.
+    ///
+    /// Intended for warming REVM's cache from an access list before execution.
+    /// Results are returned in input order, with `None` for missing accounts.
+    pub async fn basic_multi(
+        &self,
+        addresses: &[Address],
+    ) -> Result<Vec<Option<revm::state::AccountInfo>>, HandleError> {
+        let store = self.read().await?;
+        let mut accounts = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            accounts.push(store.get_account(address).await?.map(account_info));
+        }
+        Ok(accounts)
+    }
+
+    /// Synchronous variant of [`Self::basic_multi`] for REVM's prefetch path.
+    pub fn basic_multi_ref(
+        &self,
+        addresses: &[Address],
+    ) -> Result<Vec<Option<revm::state::AccountInfo>>, HandleError> {
+        block_on(self.basic_multi(addresses))
+    }
+}
+
+/// Complete state of one account, read from a single committed state.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountDump {
+    /// Account nonce.
+    pub nonce: u64,
+    /// Account balance.
+    pub balance: U256,
+    /// Hash of the account's code, [`KECCAK256_EMPTY`] for accounts without code.
+    pub code_hash: B256,
+    /// Account bytecode, empty for accounts without code.
+    pub code: Bytes,
+    /// Every non-zero storage slot, keyed by slot.
+    pub storage: BTreeMap<U256, U256>,
+}
+
+impl<A, S, C> QmdbHandle<A, S, C>
+where
+    A: QmdbGettable<Key = Address, Value = Vec<u8>>,
+    S: QmdbScannable<Key = StorageKey, Value = U256>,
+    C: QmdbGettable<Key = B256, Value = Vec<u8>>,
+{
+    /// Dump the balance, nonce, code, and storage of `address`.
+    ///
+    /// Everything is read under one [`consistent_read`](Self::consistent_read), so
+    /// the dump reflects a single committed head. Returns `None` if the account
+    /// does not exist. Intended for debugging and contract inspection; the whole
+    /// storage partition is scanned to collect the account's slots.
+    pub async fn dump_account(&self, address: Address) -> Result<Option<AccountDump>, HandleError> {
+        self.consistent_read(|store| {
+            Box::pin(async move {
+                let Some(StoredAccount { nonce, balance, code_hash, generation }) =
+                    store.get_account(&address).await?
+                else {
+                    return Ok(None);
+                };
+                let code = if code_hash == KECCAK256_EMPTY {
+                    Bytes::new()
+                } else {
+                    store
+                        .get_code(&code_hash)
+                        .await?
+                        .map(Bytes::from)
+                        .ok_or(HandleError::CodeNotFound(code_hash))?
+                };
+                let storage = store.scan_storage(&address, generation).await?;
+                Ok(Some(AccountDump { nonce, balance, code_hash, code, storage }))
+            })
+        })
+        .await
+    }
+}
+
+impl<A, S, C> QmdbHandle<A, S, C>
+where
+    A: QmdbScannable<Key = Address, Value = Vec<u8>>,
+{
+    /// Enumerate every account in the store, in unspecified order.
+    ///
+    /// Accounts are read under a single read lock, so the result is a consistent
+    /// snapshot. Intended for snapshot export and debugging; the whole account
+    /// partition is loaded into memory.
+    pub async fn iter_accounts(
+        &self,
+    ) -> Result<impl Iterator<Item = (Address, revm::state::AccountInfo)>, HandleError> {
+        let accounts = self.read().await?.scan_accounts().await?;
+        Ok(accounts.into_iter().map(|(address, account)| (address, account_info(account))))
+    }
+}
+
 impl<A, S, C> DatabaseRef for QmdbHandle<A, S, C>
 where
-    A: QmdbGettable<Key = Address, Value = [u8; AccountEncoding::SIZE]>,
+    A: QmdbGettable<Key = Address, Value = Vec<u8>>,
     S: QmdbGettable<Key = StorageKey, Value = U256>,
     C: QmdbGettable<Key = B256, Value = Vec<u8>>,
 {
     type Error = HandleError;
 
     fn basic_ref(&self, address: Address) -> Result<Option<revm::state::AccountInfo>, Self::Error> {
-        let store = block_on(self.read());
-        match block_on(store.get_account(&address))? {
-            Some((nonce, balance, code_hash, _gen)) => Ok(Some(revm::state::AccountInfo {
-                nonce,
-                balance,
-                code_hash,
-                code: None,
-                account_id: None,
-            })),
-            None => Ok(None),
-        }
+        let store = block_on(self.read())?;
+        Ok(block_on(store.get_account(&address))?.map(account_info))
     }
 
     fn code_by_hash_ref(&self, code_hash: B256) -> Result<Bytecode, Self::Error> {
         if code_hash == KECCAK256_EMPTY || code_hash == B256::ZERO {
             return Ok(Bytecode::default());
         }
-        let store = block_on(self.read());
+        let store = block_on(self.read())?;
         block_on(store.get_code(&code_hash))?.map_or_else(
             || Err(HandleError::CodeNotFound(code_hash)),
             |bytes| Ok(Bytecode::new_raw(Bytes::from(bytes))),
@@ -113,11 +217,11 @@ where
     }
 
     fn storage_ref(&self, address: Address, index: U256) -> Result<U256, Self::Error> {
-        let store = block_on(self.read());
+        let store = block_on(self.read())?;
 
         // Get account to find generation
         let generation = match block_on(store.get_account(&address))? {
-            Some((_, _, _, generation)) => generation,
+            Some(account) => account.generation,
             None => return Ok(U256::ZERO),
         };
 
@@ -126,13 +230,13 @@ where
     }
 
     fn block_hash_ref(&self, number: u64) -> Result<B256, Self::Error> {
-        Err(HandleError::BlockHashNotFound(number))
+        Ok(self.block_hashes().get(number).unwrap_or(B256::ZERO))
     }
 }
 
 impl<A, S, C> DatabaseAsyncRef for QmdbHandle<A, S, C>
 where
-    A: QmdbGettable<Key = Address, Value = [u8; AccountEncoding::SIZE]> + Send + Sync + 'static,
+    A: QmdbGettable<Key = Address, Value = Vec<u8>> + Send + Sync + 'static,
     S: QmdbGettable<Key = StorageKey, Value = U256> + Send + Sync + 'static,
     C: QmdbGettable<Key = B256, Value = Vec<u8>> + Send + Sync + 'static,
 {
@@ -145,17 +249,8 @@ where
     {
         let handle = self.clone();
         async move {
-            let store = handle.read().await;
-            match store.get_account(&address).await? {
-                Some((nonce, balance, code_hash, _gen)) => Ok(Some(revm::state::AccountInfo {
-                    nonce,
-                    balance,
-                    code_hash,
-                    code: None,
-                    account_id: None,
-                })),
-                None => Ok(None),
-            }
+            let store = handle.read().await?;
+            Ok(store.get_account(&address).await?.map(account_info))
         }
     }
 
@@ -168,7 +263,7 @@ where
             if code_hash == KECCAK256_EMPTY || code_hash == B256::ZERO {
                 return Ok(Bytecode::default());
             }
-            let store = handle.read().await;
+            let store = handle.read().await?;
             store.get_code(&code_hash).await?.map_or_else(
                 || Err(HandleError::CodeNotFound(code_hash)),
                 |bytes| Ok(Bytecode::new_raw(Bytes::from(bytes))),
@@ -183,9 +278,9 @@ where
     ) -> impl std::future::Future<Output = Result<U256, Self::Error>> + Send {
         let handle = self.clone();
         async move {
-            let store = handle.read().await;
+            let store = handle.read().await?;
             let generation = match store.get_account(&address).await? {
-                Some((_, _, _, generation)) => generation,
+                Some(account) => account.generation,
                 None => return Ok(U256::ZERO),
             };
             let key = StorageKey::new(address, generation, index);
@@ -197,51 +292,62 @@ where
         &self,
         number: u64,
     ) -> impl std::future::Future<Output = Result<B256, Self::Error>> + Send {
-        std::future::ready(Err(HandleError::BlockHashNotFound(number)))
+        std::future::ready(Ok(self.block_hashes().get(number).unwrap_or(B256::ZERO)))
     }
 }
 
 impl<A, S, C> DatabaseCommit for QmdbHandle<A, S, C>
 where
-    A: QmdbGettable<Key = Address, Value = [u8; AccountEncoding::SIZE]>
-        + QmdbBatchable<Key = Address, Value = [u8; AccountEncoding::SIZE]>,
+    A: QmdbGettable<Key = Address, Value = Vec<u8>> + QmdbBatchable<Key = Address, Value = Vec<u8>>,
     S: QmdbGettable<Key = StorageKey, Value = U256> + QmdbBatchable<Key = StorageKey, Value = U256>,
     C: QmdbGettable<Key = B256, Value = Vec<u8>> + QmdbBatchable<Key = B256, Value = Vec<u8>>,
 {
     fn commit(&mut self, changes: HashMap<Address, Account>) {
-        use std::collections::BTreeMap;
-
-        use monmouth_qmdb::AccountUpdate;
-
-        let mut changeset = ChangeSet::new();
-
-        for (address, account) in changes {
-            if !account.is_touched() {
-                continue;
-            }
-
-            let storage: BTreeMap<U256, U256> =
-                account.storage.iter().map(|(k, v)| (*k, v.present_value())).collect();
-
-            let code = account.info.code.as_ref().map(|c| c.bytes().to_vec());
+        // Ignore errors in DatabaseCommit (matches REVM's signature)
+        let _ = block_on(Self::commit(self, evm_changeset(changes)));
+    }
+}
 
-            changeset.accounts.insert(
-                address,
-                AccountUpdate {
-                    created: account.is_created(),
-                    selfdestructed: account.is_selfdestructed(),
-                    nonce: account.info.nonce,
-                    balance: account.info.balance,
-                    code_hash: account.info.code_hash,
-                    code,
-                    storage,
-                },
-            );
-        }
+/// Touched accounts of REVM's post-transaction state, ordered by address.
+///
+/// REVM hands over a `HashMap` whose iteration order varies between runs;
+/// processing accounts in address order keeps any order-sensitive side effect
+/// of a commit reproducible.
+fn touched_accounts(changes: HashMap<Address, Account>) -> BTreeMap<Address, Account> {
+    changes.into_iter().filter(|(_, account)| account.is_touched()).collect()
+}
 
-        // Ignore errors in DatabaseCommit (matches REVM's signature)
-        let _ = block_on(Self::commit(self, changeset));
+/// Convert REVM's post-transaction state into a [`ChangeSet`].
+///
+/// Accounts are processed in address order. Only persistent storage is carried over. REVM keeps EIP-1153 transient storage
+/// (TSTORE/TLOAD) in the journal rather than in [`Account::storage`] and discards
+/// it at the end of each transaction, so transient slots never reach QMDB.
+fn evm_changeset(changes: HashMap<Address, Account>) -> ChangeSet {
+    use monmouth_qmdb::AccountUpdate;
+
+    let mut changeset = ChangeSet::new();
+
+    for (address, account) in touched_accounts(changes) {
+        let storage: BTreeMap<U256, U256> =
+            account.storage.iter().map(|(k, v)| (*k, v.present_value())).collect();
+
+        let code = account.info.code.as_ref().map(|c| c.bytes().to_vec());
+
+        changeset.accounts.insert(
+            address,
+            AccountUpdate {
+                created: account.is_created(),
+                selfdestructed: account.is_selfdestructed(),
+                nonce: account.info.nonce,
+                balance: account.info.balance,
+                code_hash: account.info.code_hash,
+                code,
+                storage,
+            },
+        );
     }
+
+    changeset
 }
 
 #[cfg(test)]
@@ -310,8 +416,17 @@ mod tests {
         }
     }
 
+    impl<K: Clone + Eq + std::hash::Hash + Send + Sync, V: Clone + Send + Sync> QmdbScannable
+        for MemoryStore<K, V>
+    {
+        async fn scan(&self) -> Result<Vec<(Self::Key, Self::Value)>, Self::Error> {
+            let data = self.data.lock().unwrap();
+            Ok(data.iter().map(|(key, value)| (key.clone(), value.clone())).collect())
+        }
+    }
+
     type TestHandle = QmdbHandle<
-        MemoryStore<Address, [u8; 80]>,
+        MemoryStore<Address, Vec<u8>>,
         MemoryStore<StorageKey, U256>,
         MemoryStore<B256, Vec<u8>>,
     >;
@@ -342,9 +457,237 @@ mod tests {
     }
 
     #[test]
-    fn block_hash_returns_error() {
+    fn basic_multi_matches_basic_ref() {
+        use std::collections::BTreeMap;
+
+        use monmouth_qmdb::AccountUpdate;
+
+        let handle = create_test_handle();
+        let first = Address::repeat_byte(0x01);
+        let second = Address::repeat_byte(0x02);
+        let missing = Address::repeat_byte(0x03);
+
+        let mut changes = ChangeSet::new();
+        for (address, nonce, balance) in [(first, 1, 100u64), (second, 7, 250u64)] {
+            changes.insert(
+                address,
+                AccountUpdate {
+                    created: true,
+                    selfdestructed: false,
+                    nonce,
+                    balance: U256::from(balance),
+                    code_hash: KECCAK256_EMPTY,
+                    code: None,
+                    storage: BTreeMap::new(),
+                },
+            );
+        }
+        block_on(handle.commit(changes)).unwrap();
+
+        let addresses = [second, missing, first];
+        let bulk = handle.basic_multi_ref(&addresses).unwrap();
+        let individual: Vec<_> =
+            addresses.iter().map(|address| handle.basic_ref(*address).unwrap()).collect();
+
+        assert_eq!(bulk, individual);
+        assert!(bulk[1].is_none());
+        assert_eq!(bulk[0].as_ref().map(|info| info.nonce), Some(7));
+        assert_eq!(bulk[2].as_ref().map(|info| info.balance), Some(U256::from(100u64)));
+    }
+
+    #[test]
+    fn block_hash_returns_zero_outside_history() {
+        use crate::BLOCK_HASH_HISTORY;
+
+        let handle = create_test_handle();
+        assert_eq!(handle.block_hash_ref(100).unwrap(), B256::ZERO);
+
+        let total = BLOCK_HASH_HISTORY as u64 + 1;
+        for number in 1..=total {
+            handle.push_block_hash(number, B256::left_padding_from(&number.to_be_bytes()));
+        }
+        assert_eq!(handle.block_hash_ref(1).unwrap(), B256::ZERO);
+        assert_eq!(handle.block_hash_ref(2).unwrap(), B256::left_padding_from(&[2]));
+        assert_eq!(handle.block_hash_ref(total).unwrap(), B256::left_padding_from(&[1, 1]));
+    }
+
+    #[test]
+    fn dump_account_returns_code_and_every_slot() {
+        use monmouth_qmdb::AccountUpdate;
+
         let handle = create_test_handle();
-        let result = handle.block_hash_ref(100);
-        assert!(matches!(result, Err(HandleError::BlockHashNotFound(100))));
+        let contract = Address::repeat_byte(0x01);
+        let eoa = Address::repeat_byte(0x02);
+        let code = vec![0x60, 0x01, 0x60, 0x00, 0x55];
+        let code_hash = alloy_primitives::keccak256(&code);
+        let storage = BTreeMap::from([
+            (U256::from(0), U256::from(10)),
+            (U256::from(1), U256::from(20)),
+            (U256::from(7), U256::from(70)),
+        ]);
+
+        let mut changes = ChangeSet::new();
+        changes.insert(
+            contract,
+            AccountUpdate {
+                created: true,
+                selfdestructed: false,
+                nonce: 1,
+                balance: U256::from(500u64),
+                code_hash,
+                code: Some(code.clone()),
+                storage: storage.clone(),
+            },
+        );
+        changes.insert(
+            eoa,
+            AccountUpdate {
+                created: true,
+                selfdestructed: false,
+                nonce: 3,
+                balance: U256::from(9u64),
+                code_hash: KECCAK256_EMPTY,
+                code: None,
+                storage: BTreeMap::new(),
+            },
+        );
+        block_on(handle.commit(changes)).unwrap();
+
+        let dump = block_on(handle.dump_account(contract)).unwrap().expect("contract exists");
+        assert_eq!(
+            dump,
+            AccountDump {
+                nonce: 1,
+                balance: U256::from(500u64),
+                code_hash,
+                code: Bytes::from(code),
+                storage,
+            }
+        );
+
+        let dump = block_on(handle.dump_account(eoa)).unwrap().expect("eoa exists");
+        assert_eq!(dump.code, Bytes::new());
+        assert!(dump.storage.is_empty());
+
+        assert!(block_on(handle.dump_account(Address::repeat_byte(0x03))).unwrap().is_none());
+    }
+
+    #[test]
+    fn iter_accounts_visits_each_account_once() {
+        use std::collections::BTreeMap;
+
+        use monmouth_qmdb::AccountUpdate;
+
+        let handle = create_test_handle();
+        let accounts = [
+            (Address::repeat_byte(0x01), 1, 100u64),
+            (Address::repeat_byte(0x02), 2, 200u64),
+            (Address::repeat_byte(0x03), 3, 300u64),
+        ];
+
+        let mut changes = ChangeSet::new();
+        for (address, nonce, balance) in accounts {
+            changes.insert(
+                address,
+                AccountUpdate {
+                    created: true,
+                    selfdestructed: false,
+                    nonce,
+                    balance: U256::from(balance),
+                    code_hash: KECCAK256_EMPTY,
+                    code: None,
+                    storage: BTreeMap::new(),
+                },
+            );
+        }
+        block_on(handle.commit(changes)).unwrap();
+
+        let mut visited: StdHashMap<Address, usize> = StdHashMap::new();
+        for (address, info) in block_on(handle.iter_accounts()).unwrap() {
+            *visited.entry(address).or_default() += 1;
+            let (_, nonce, balance) =
+                accounts.iter().find(|(a, _, _)| *a == address).expect("unexpected account");
+            assert_eq!(info.nonce, *nonce);
+            assert_eq!(info.balance, U256::from(*balance));
+        }
+        assert_eq!(visited.len(), accounts.len());
+        assert!(visited.values().all(|count| *count == 1));
+    }
+
+    #[test]
+    fn transient_storage_is_not_committed() {
+        use std::collections::BTreeMap;
+
+        use alloy_primitives::keccak256;
+        use monmouth_qmdb::AccountUpdate;
+        use revm::{
+            Context, ExecuteEvm, MainBuilder, MainContext, context::TxEnv,
+            context_interface::ContextSetters, database_interface::WrapDatabaseRef,
+            primitives::TxKind,
+        };
+
+        // TSTORE 0x2a at slot 1, TLOAD slot 1, return the loaded word.
+        let code = Bytes::from_static(&[
+            0x60, 0x2a, 0x60, 0x01, 0x5d, 0x60, 0x01, 0x5c, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60,
+            0x00, 0xf3,
+        ]);
+        let contract = Address::repeat_byte(0xC0);
+        let caller = Address::repeat_byte(0xCA);
+
+        let mut handle = create_test_handle();
+        let mut changes = ChangeSet::new();
+        changes.insert(
+            contract,
+            AccountUpdate {
+                created: true,
+                selfdestructed: false,
+                nonce: 1,
+                balance: U256::ZERO,
+                code_hash: keccak256(&code),
+                code: Some(code.to_vec()),
+                storage: BTreeMap::new(),
+            },
+        );
+        block_on(handle.commit(changes)).unwrap();
+
+        let mut evm = Context::mainnet().with_db(WrapDatabaseRef(handle.clone())).build_mainnet();
+        evm.set_tx(
+            TxEnv::builder()
+                .caller(caller)
+                .kind(TxKind::Call(contract))
+                .gas_limit(100_000)
+                .build()
+                .unwrap(),
+        );
+        let result = evm.replay().unwrap();
+        let output = result.result.output().cloned().unwrap_or_default();
+        assert_eq!(U256::from_be_slice(&output), U256::from(0x2a), "TLOAD saw the TSTORE");
+
+        let changeset = evm_changeset(result.state.clone());
+        assert!(
+            changeset.accounts.values().all(|update| update.storage.is_empty()),
+            "transient slots leaked into the changeset"
+        );
+
+        DatabaseCommit::commit(&mut handle, result.state);
+        assert_eq!(handle.storage_ref(contract, U256::from(1)).unwrap(), U256::ZERO);
+    }
+
+    #[test]
+    fn touched_accounts_are_processed_in_address_order() {
+        let addresses: Vec<Address> =
+            [0x42, 0x07, 0xff, 0x01, 0x80].into_iter().map(Address::repeat_byte).collect();
+        let mut changes = HashMap::default();
+        for address in &addresses {
+            let mut account = Account::default();
+            account.mark_touch();
+            changes.insert(*address, account);
+        }
+        changes.insert(Address::repeat_byte(0x10), Account::default());
+
+        let mut expected = addresses;
+        expected.sort();
+        let order: Vec<Address> = touched_accounts(changes).into_keys().collect();
+        assert_eq!(order, expected);
     }
 }
@@ -0,0 +1,93 @@
+//! Recent block hashes for the BLOCKHASH opcode.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use alloy_primitives::B256;
+
+/// Number of recent block hashes the EVM can query.
+pub const BLOCK_HASH_HISTORY: usize = 256;
+
+/// Bounded ring buffer of finalized `(number, hash)` pairs.
+///
+/// Holds at most [`BLOCK_HASH_HISTORY`] entries; pushing past that evicts the
+/// oldest. Clones share the same buffer.
+#[derive(Clone, Debug, Default)]
+pub struct BlockHashes {
+    entries: Arc<Mutex<VecDeque<(u64, B256)>>>,
+}
+
+impl BlockHashes {
+    /// Create an empty buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VecDeque<(u64, B256)>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Record the hash of finalized block `number`.
+    ///
+    /// Numbers at or below the most recent entry are ignored.
+    pub fn push(&self, number: u64, hash: B256) {
+        let mut entries = self.lock();
+        if entries.back().is_some_and(|(latest, _)| number <= *latest) {
+            return;
+        }
+        if entries.len() == BLOCK_HASH_HISTORY {
+            entries.pop_front();
+        }
+        entries.push_back((number, hash));
+    }
+
+    /// Returns the hash of block `number` if it is still buffered.
+    pub fn get(&self, number: u64) -> Option<B256> {
+        self.lock().iter().rev().find(|(n, _)| *n == number).map(|(_, hash)| *hash)
+    }
+
+    /// Number of buffered hashes.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if no hashes are buffered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oldest_hash_is_evicted_past_capacity() {
+        let hashes = BlockHashes::new();
+        let hash = |n: u64| B256::left_padding_from(&n.to_be_bytes());
+        let total = BLOCK_HASH_HISTORY as u64 + 10;
+        for n in 1..=total {
+            hashes.push(n, hash(n));
+        }
+
+        assert_eq!(hashes.len(), BLOCK_HASH_HISTORY);
+        for n in 1..=10 {
+            assert_eq!(hashes.get(n), None, "block {n} should be evicted");
+        }
+        assert_eq!(hashes.get(11), Some(hash(11)));
+        assert_eq!(hashes.get(total), Some(hash(total)));
+        assert_eq!(hashes.get(total + 1), None);
+    }
+
+    #[test]
+    fn stale_pushes_are_ignored() {
+        let hashes = BlockHashes::new();
+        hashes.push(5, B256::repeat_byte(0x05));
+        hashes.push(5, B256::repeat_byte(0xff));
+        hashes.push(3, B256::repeat_byte(0x03));
+        assert_eq!(hashes.len(), 1);
+        assert_eq!(hashes.get(5), Some(B256::repeat_byte(0x05)));
+    }
+}
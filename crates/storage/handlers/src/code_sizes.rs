@@ -0,0 +1,102 @@
+//! Bounded index of contract code lengths for EXTCODESIZE.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use alloy_primitives::B256;
+
+/// Default number of code lengths kept in a [`CodeSizes`] index.
+pub const DEFAULT_CODE_SIZE_CAPACITY: usize = 16_384;
+
+#[derive(Debug, Default)]
+struct Inner {
+    sizes: HashMap<B256, usize>,
+    order: VecDeque<B256>,
+}
+
+/// Bounded map from code hash to code length.
+///
+/// Code is content-addressed, so an entry never goes stale. Once `capacity`
+/// lengths are held the oldest is evicted first; a missing entry only costs a
+/// code read. Clones share the same index.
+#[derive(Clone, Debug)]
+pub struct CodeSizes {
+    capacity: usize,
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl CodeSizes {
+    /// Create an empty index holding at most `capacity` lengths.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, inner: Arc::new(Mutex::new(Inner::default())) }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the indexed length of the code with `code_hash`.
+    pub fn get(&self, code_hash: &B256) -> Option<usize> {
+        self.lock().sizes.get(code_hash).copied()
+    }
+
+    /// Index the length of the code with `code_hash`.
+    pub fn insert(&self, code_hash: B256, size: usize) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut inner = self.lock();
+        if inner.sizes.contains_key(&code_hash) {
+            return;
+        }
+        while inner.sizes.len() >= self.capacity {
+            let Some(oldest) = inner.order.pop_front() else { break };
+            inner.sizes.remove(&oldest);
+        }
+        inner.order.push_back(code_hash);
+        inner.sizes.insert(code_hash, size);
+    }
+
+    /// Number of indexed lengths.
+    pub fn len(&self) -> usize {
+        self.lock().sizes.len()
+    }
+
+    /// Returns `true` if no lengths are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for CodeSizes {
+    fn default() -> Self {
+        Self::new(DEFAULT_CODE_SIZE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oldest_size_is_evicted_past_capacity() {
+        let sizes = CodeSizes::new(2);
+        sizes.insert(B256::repeat_byte(1), 10);
+        sizes.insert(B256::repeat_byte(2), 20);
+        sizes.insert(B256::repeat_byte(3), 30);
+
+        assert_eq!(sizes.len(), 2);
+        assert_eq!(sizes.get(&B256::repeat_byte(1)), None);
+        assert_eq!(sizes.get(&B256::repeat_byte(2)), Some(20));
+        assert_eq!(sizes.get(&B256::repeat_byte(3)), Some(30));
+    }
+
+    #[test]
+    fn zero_capacity_indexes_nothing() {
+        let sizes = CodeSizes::new(0);
+        sizes.insert(B256::repeat_byte(1), 10);
+        assert!(sizes.is_empty());
+    }
+}
@@ -15,6 +15,10 @@ pub enum HandleError {
     #[error("lock poisoned")]
     LockPoisoned,
 
+    /// A writer panicked while holding the store lock; the store may be inconsistent.
+    #[error("state corrupted: a writer panicked while holding the store lock")]
+    StateCorrupted,
+
     /// Code not found for hash.
     #[error("code not found: {0}")]
     CodeNotFound(B256),
@@ -58,6 +62,12 @@ mod tests {
         assert_eq!(err.to_string(), "lock poisoned");
     }
 
+    #[test]
+    fn test_state_corrupted_display() {
+        let err = HandleError::StateCorrupted;
+        assert!(err.to_string().starts_with("state corrupted"));
+    }
+
     #[test]
     fn test_code_not_found_display() {
         let hash = test_hash();
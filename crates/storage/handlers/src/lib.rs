@@ -6,12 +6,18 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
 mod adapter;
-pub use adapter::QmdbRefDb;
+pub use adapter::{AccountDump, QmdbRefDb};
+
+mod block_hashes;
+pub use block_hashes::{BLOCK_HASH_HISTORY, BlockHashes};
+
+mod code_sizes;
+pub use code_sizes::{CodeSizes, DEFAULT_CODE_SIZE_CAPACITY};
 
 mod error;
 pub use error::HandleError;
 
 mod qmdb;
-pub use qmdb::{QmdbHandle, RootProvider};
+pub use qmdb::{QmdbHandle, RootProvider, StoreWriteGuard};
 
 mod state;
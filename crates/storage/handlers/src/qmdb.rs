@@ -1,15 +1,23 @@
 //! Thread-safe QMDB handle.
 
-use std::sync::Arc;
+use std::{
+    ops::{Deref, DerefMut},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
 
 use alloy_primitives::{Address, B256, U256};
 use async_trait::async_trait;
+use futures::future::BoxFuture;
 use monmouth_qmdb::{
-    AccountEncoding, AccountUpdate, ChangeSet, QmdbBatchable, QmdbGettable, QmdbStore, StorageKey,
+    AccountUpdate, ChangeSet, QmdbBatchable, QmdbGettable, QmdbRootable, QmdbStore, StorageKey,
 };
 use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use tracing::error;
 
-use crate::error::HandleError;
+use crate::{block_hashes::BlockHashes, code_sizes::CodeSizes, error::HandleError};
 
 /// Trait for providing state root computation.
 ///
@@ -21,24 +29,95 @@ pub trait RootProvider: Send + Sync {
     async fn state_root(&self) -> Result<B256, HandleError>;
 
     /// Compute the state root without committing the provided changes.
-    async fn compute_root(&mut self, changes: &ChangeSet) -> Result<B256, HandleError>;
+    ///
+    /// [`QmdbHandle`] calls this while holding its store's read lock and the
+    /// provider's write lock, so providers may reopen the store's partitions
+    /// without racing a commit or another preview.
+    async fn compute_root(&self, changes: &ChangeSet) -> Result<B256, HandleError>;
 
     /// Commit changes and return the new state root.
     async fn commit_and_get_root(&mut self) -> Result<B256, HandleError>;
+
+    /// Record `root` as the state root after the block at `height`.
+    ///
+    /// Providers without a root history ignore the call.
+    async fn record_root(&mut self, _height: u64, _root: B256) -> Result<(), HandleError> {
+        Ok(())
+    }
+
+    /// State root recorded for the block at `height`.
+    ///
+    /// Returns `None` if no root was recorded for `height` or it has been
+    /// pruned by the provider's retention policy.
+    async fn root_at(&self, _height: u64) -> Result<Option<B256>, HandleError> {
+        Ok(None)
+    }
 }
 
 /// Thread-safe handle to QMDB stores.
 ///
 /// Wraps `QmdbStore` with `Arc<RwLock>` for safe concurrent access.
 /// Implements REVM database traits via the `adapter` module.
+///
+/// Tokio locks do not poison, so the handle tracks poisoning itself: if a task
+/// panics while holding the write lock, the store may be half-written and every
+/// later [`read`](Self::read) or [`write`](Self::write) fails with
+/// [`HandleError::StateCorrupted`] until [`clear_poison`](Self::clear_poison) is
+/// called.
 pub struct QmdbHandle<A, S, C> {
     inner: Arc<RwLock<QmdbStore<A, S, C>>>,
     root_provider: Option<Arc<RwLock<dyn RootProvider>>>,
+    code_sizes: CodeSizes,
+    block_hashes: BlockHashes,
+    poisoned: Arc<AtomicBool>,
+}
+
+/// Write access to the store behind a [`QmdbHandle`].
+///
+/// Marks the handle corrupted if dropped while the current thread is panicking.
+pub struct StoreWriteGuard<'a, A, S, C> {
+    guard: RwLockWriteGuard<'a, QmdbStore<A, S, C>>,
+    poisoned: &'a AtomicBool,
+}
+
+impl<A, S, C> Deref for StoreWriteGuard<'_, A, S, C> {
+    type Target = QmdbStore<A, S, C>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard
+    }
+}
+
+impl<A, S, C> DerefMut for StoreWriteGuard<'_, A, S, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.guard
+    }
+}
+
+impl<A, S, C> Drop for StoreWriteGuard<'_, A, S, C> {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            self.poisoned.store(true, Ordering::SeqCst);
+            error!("panic while holding the QMDB write lock; store state is now untrusted");
+        }
+    }
+}
+
+impl<A, S, C> std::fmt::Debug for StoreWriteGuard<'_, A, S, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StoreWriteGuard").finish_non_exhaustive()
+    }
 }
 
 impl<A, S, C> Clone for QmdbHandle<A, S, C> {
     fn clone(&self) -> Self {
-        Self { inner: Arc::clone(&self.inner), root_provider: self.root_provider.clone() }
+        Self {
+            inner: Arc::clone(&self.inner),
+            root_provider: self.root_provider.clone(),
+            code_sizes: self.code_sizes.clone(),
+            block_hashes: self.block_hashes.clone(),
+            poisoned: Arc::clone(&self.poisoned),
+        }
     }
 }
 
@@ -46,16 +125,19 @@ impl<A, S, C> QmdbHandle<A, S, C> {
     /// Create a new handle from stores.
     #[must_use]
     pub fn new(accounts: A, storage: S, code: C) -> Self {
-        Self {
-            inner: Arc::new(RwLock::new(QmdbStore::new(accounts, storage, code))),
-            root_provider: None,
-        }
+        Self::from_store(QmdbStore::new(accounts, storage, code))
     }
 
     /// Create from an existing `QmdbStore`.
     #[must_use]
     pub fn from_store(store: QmdbStore<A, S, C>) -> Self {
-        Self { inner: Arc::new(RwLock::new(store)), root_provider: None }
+        Self {
+            inner: Arc::new(RwLock::new(store)),
+            root_provider: None,
+            code_sizes: CodeSizes::default(),
+            block_hashes: BlockHashes::new(),
+            poisoned: Arc::new(AtomicBool::new(false)),
+        }
     }
 
     /// Set the root provider for state root computation.
@@ -70,31 +152,224 @@ impl<A, S, C> QmdbHandle<A, S, C> {
         self.root_provider.as_ref()
     }
 
+    /// Record the hash of a finalized block for the BLOCKHASH opcode.
+    pub fn push_block_hash(&self, number: u64, hash: B256) {
+        self.block_hashes.push(number, hash);
+    }
+
+    /// Recent finalized block hashes served to the EVM.
+    pub const fn block_hashes(&self) -> &BlockHashes {
+        &self.block_hashes
+    }
+
     /// Acquire read lock on the underlying store.
-    pub async fn read(&self) -> RwLockReadGuard<'_, QmdbStore<A, S, C>> {
-        self.inner.read().await
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HandleError::StateCorrupted`] if a writer panicked while holding the lock.
+    pub async fn read(&self) -> Result<RwLockReadGuard<'_, QmdbStore<A, S, C>>, HandleError> {
+        let guard = self.inner.read().await;
+        self.ensure_consistent()?;
+        Ok(guard)
     }
 
     /// Acquire write lock on the underlying store.
-    pub async fn write(&self) -> RwLockWriteGuard<'_, QmdbStore<A, S, C>> {
-        self.inner.write().await
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HandleError::StateCorrupted`] if a writer panicked while holding the lock.
+    pub async fn write(&self) -> Result<StoreWriteGuard<'_, A, S, C>, HandleError> {
+        let guard = self.inner.write().await;
+        self.ensure_consistent()?;
+        Ok(StoreWriteGuard { guard, poisoned: &self.poisoned })
+    }
+
+    /// Run `f` against the store while holding the read lock for its whole duration.
+    ///
+    /// Separate [`read`](Self::read) calls may straddle a commit; every read made
+    /// inside `f` observes the same committed state, so use this when reads must
+    /// be coherent across the account, storage, and code partitions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HandleError::StateCorrupted`] if a writer panicked while holding the
+    /// lock, or any error returned by `f`.
+    pub async fn consistent_read<T, F>(&self, f: F) -> Result<T, HandleError>
+    where
+        F: for<'a> FnOnce(&'a QmdbStore<A, S, C>) -> BoxFuture<'a, Result<T, HandleError>>,
+    {
+        let store = self.read().await?;
+        f(&store).await
+    }
+
+    /// Returns `true` if a writer panicked while holding the store lock.
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.load(Ordering::SeqCst)
+    }
+
+    /// Clear the poisoned flag, re-enabling access to the store.
+    ///
+    /// Only call this after the store's consistency has been re-validated, for
+    /// example by recomputing the state root and comparing it to the last
+    /// finalized root.
+    pub fn clear_poison(&self) {
+        self.poisoned.store(false, Ordering::SeqCst);
+    }
+
+    fn ensure_consistent(&self) -> Result<(), HandleError> {
+        if self.is_poisoned() {
+            error!("refusing QMDB access: a writer panicked and the store may be inconsistent");
+            return Err(HandleError::StateCorrupted);
+        }
+        Ok(())
+    }
+
+    /// Look up a code length in the size index.
+    pub(crate) fn cached_code_size(&self, code_hash: &B256) -> Option<usize> {
+        self.code_sizes.get(code_hash)
+    }
+
+    /// Record a code length in the size index.
+    pub(crate) fn record_code_size(&self, code_hash: B256, size: usize) {
+        self.code_sizes.insert(code_hash, size);
+    }
+
+    /// Index the lengths of all code written by a change set.
+    pub(crate) fn record_code_sizes(&self, changes: &ChangeSet) {
+        for update in changes.accounts.values() {
+            if let Some(code) = &update.code {
+                self.code_sizes.insert(update.code_hash, code.len());
+            }
+        }
     }
 }
 
 impl<A, S, C> QmdbHandle<A, S, C>
 where
-    A: QmdbGettable<Key = Address, Value = [u8; AccountEncoding::SIZE]>
-        + QmdbBatchable<Key = Address, Value = [u8; AccountEncoding::SIZE]>,
+    A: QmdbGettable<Key = Address, Value = Vec<u8>> + QmdbBatchable<Key = Address, Value = Vec<u8>>,
     S: QmdbGettable<Key = StorageKey, Value = U256> + QmdbBatchable<Key = StorageKey, Value = U256>,
     C: QmdbGettable<Key = B256, Value = Vec<u8>> + QmdbBatchable<Key = B256, Value = Vec<u8>>,
 {
     /// Commit changes atomically.
     pub async fn commit(&self, changes: ChangeSet) -> Result<(), HandleError> {
-        let mut store = self.write().await;
+        self.record_code_sizes(&changes);
+        let mut store = self.write().await?;
         store.commit_changes(changes).await?;
         Ok(())
     }
 
+    /// Commit changes on the runtime's blocking pool and return the new state root.
+    ///
+    /// Large commits write every touched partition while holding the write
+    /// lock; running them on a blocking thread keeps async callers such as the
+    /// consensus event loop responsive. Must be called within a Tokio runtime.
+    /// Without a root provider the root is read from the stores themselves.
+    pub async fn commit_async(&self, changes: ChangeSet) -> Result<B256, HandleError>
+    where
+        A: QmdbRootable<Key = Address, Value = Vec<u8>> + Send + Sync + 'static,
+        S: QmdbRootable<Key = StorageKey, Value = U256> + Send + Sync + 'static,
+        C: QmdbRootable<Key = B256, Value = Vec<u8>> + Send + Sync + 'static,
+    {
+        let handle = self.clone();
+        let runtime = tokio::runtime::Handle::current();
+        tokio::task::spawn_blocking(move || runtime.block_on(handle.commit_and_get_root(changes)))
+            .await
+            .map_err(|err| {
+                error!(%err, "blocking QMDB commit did not complete");
+                HandleError::StateCorrupted
+            })?
+    }
+
+    async fn commit_and_get_root(&self, changes: ChangeSet) -> Result<B256, HandleError>
+    where
+        A: QmdbRootable<Key = Address, Value = Vec<u8>>,
+        S: QmdbRootable<Key = StorageKey, Value = U256>,
+        C: QmdbRootable<Key = B256, Value = Vec<u8>>,
+    {
+        self.record_code_sizes(&changes);
+        let mut store = self.write().await?;
+        store.commit_changes(changes).await?;
+        match self.root_provider() {
+            Some(provider) => provider.write().await.commit_and_get_root().await,
+            None => Ok(store.state_root()?),
+        }
+    }
+
+    /// Commit the changes of the block at `height` and return the new state root.
+    ///
+    /// The root is recorded with the root provider so it can later be served by
+    /// [`root_at`](Self::root_at). The store's write lock is held until the root
+    /// is recorded.
+    pub async fn commit_at_height(
+        &self,
+        height: u64,
+        changes: ChangeSet,
+    ) -> Result<B256, HandleError>
+    where
+        A: QmdbRootable<Key = Address, Value = Vec<u8>>,
+        S: QmdbRootable<Key = StorageKey, Value = U256>,
+        C: QmdbRootable<Key = B256, Value = Vec<u8>>,
+    {
+        self.record_code_sizes(&changes);
+        let mut store = self.write().await?;
+        store.commit_changes(changes).await?;
+        match self.root_provider() {
+            Some(provider) => {
+                let mut provider = provider.write().await;
+                let root = provider.commit_and_get_root().await?;
+                provider.record_root(height, root).await?;
+                Ok(root)
+            }
+            None => Ok(store.state_root()?),
+        }
+    }
+
+    /// Record `root` as the state root after the block at `height`.
+    ///
+    /// Use this after committing through `StateDbWrite::commit`;
+    /// [`commit_at_height`](Self::commit_at_height) records its root itself.
+    /// Without a root provider this does nothing.
+    pub async fn record_root(&self, height: u64, root: B256) -> Result<(), HandleError> {
+        match self.root_provider() {
+            Some(provider) => provider.write().await.record_root(height, root).await,
+            None => Ok(()),
+        }
+    }
+
+    /// State root recorded for the block at `height`.
+    ///
+    /// Historical roots are kept by the root provider; without one this always
+    /// returns `None`.
+    pub async fn root_at(&self, height: u64) -> Result<Option<B256>, HandleError> {
+        match self.root_provider() {
+            Some(provider) => provider.read().await.root_at(height).await,
+            None => Ok(None),
+        }
+    }
+
+    /// State root that committing `changes` would produce, without committing them.
+    ///
+    /// The store's read lock is held throughout, so previews run alongside
+    /// readers but never overlap a commit. Without a root provider the root is
+    /// computed in memory and previews also run concurrently with each other. A
+    /// root provider may reopen the store's partitions, so its previews also hold
+    /// the provider's write lock and run one at a time.
+    /// Useful for checking a block's effect on state, e.g. during mempool admission.
+    pub async fn preview_root(&self, changes: &ChangeSet) -> Result<B256, HandleError>
+    where
+        A: QmdbRootable<Key = Address, Value = Vec<u8>>,
+        S: QmdbRootable<Key = StorageKey, Value = U256>,
+        C: QmdbRootable<Key = B256, Value = Vec<u8>>,
+    {
+        match self.root_provider() {
+            Some(provider) => {
+                let _store = self.read().await?;
+                provider.write().await.compute_root(changes).await
+            }
+            None => Ok(self.read().await?.compute_root(changes).await?),
+        }
+    }
+
     /// Initialize with genesis allocations.
     pub async fn init_genesis(&self, allocs: Vec<(Address, U256)>) -> Result<(), HandleError> {
         use std::collections::BTreeMap;
@@ -118,6 +393,22 @@ where
         }
         self.commit(changes).await
     }
+
+    /// Import a large genesis state with [`QmdbStore::bulk_import`].
+    ///
+    /// Unlike [`init_genesis`](Self::init_genesis) no change set is built, so
+    /// allocations such as a forked mainnet state can be imported account by
+    /// account. `accounts` must yield created accounts in ascending address order
+    /// each time it is called, and the store must be empty.
+    pub async fn import_genesis<F, I>(&self, accounts: F) -> Result<(), HandleError>
+    where
+        F: Fn() -> I + Send,
+        I: Iterator<Item = (Address, AccountUpdate)> + Send,
+    {
+        let mut store = self.write().await?;
+        store.bulk_import(accounts).await?;
+        Ok(())
+    }
 }
 
 impl<A, S, C> std::fmt::Debug for QmdbHandle<A, S, C> {
@@ -130,7 +421,7 @@ impl<A, S, C> std::fmt::Debug for QmdbHandle<A, S, C> {
 mod tests {
     use std::{collections::HashMap as StdHashMap, sync::Mutex};
 
-    use monmouth_qmdb::{QmdbBatchable, QmdbGettable};
+    use monmouth_qmdb::{QmdbBatchable, QmdbGettable, QmdbRootable, StoredAccount};
 
     use super::*;
 
@@ -191,8 +482,40 @@ mod tests {
         }
     }
 
+    impl<K, V> QmdbRootable for MemoryStore<K, V>
+    where
+        K: Clone + Eq + std::hash::Hash + std::fmt::Debug + Send + Sync,
+        V: Clone + std::fmt::Debug + Send + Sync,
+    {
+        fn root(&self) -> Result<B256, Self::Error> {
+            Ok(memory_root(&self.data.lock().unwrap()))
+        }
+
+        async fn root_with<I>(&self, ops: I) -> Result<B256, Self::Error>
+        where
+            I: IntoIterator<Item = (Self::Key, Option<Self::Value>)> + Send,
+            I::IntoIter: Send,
+        {
+            let mut data = self.data.lock().unwrap().clone();
+            for (key, value) in ops {
+                match value {
+                    Some(v) => data.insert(key, v),
+                    None => data.remove(&key),
+                };
+            }
+            Ok(memory_root(&data))
+        }
+    }
+
+    fn memory_root<K: std::fmt::Debug, V: std::fmt::Debug>(data: &StdHashMap<K, V>) -> B256 {
+        let mut entries: Vec<String> =
+            data.iter().map(|(key, value)| format!("{key:?}={value:?}")).collect();
+        entries.sort();
+        alloy_primitives::keccak256(entries.join(";"))
+    }
+
     type TestHandle = QmdbHandle<
-        MemoryStore<Address, [u8; 80]>,
+        MemoryStore<Address, Vec<u8>>,
         MemoryStore<StorageKey, U256>,
         MemoryStore<B256, Vec<u8>>,
     >;
@@ -217,11 +540,290 @@ mod tests {
         ];
         handle.init_genesis(allocs).await.unwrap();
 
-        let store = handle.read().await;
+        let store = handle.read().await.unwrap();
         let acc1 = store.get_account(&Address::repeat_byte(0x01)).await.unwrap().unwrap();
-        assert_eq!(acc1.1, U256::from(1000));
+        assert_eq!(acc1.balance, U256::from(1000));
 
         let acc2 = store.get_account(&Address::repeat_byte(0x02)).await.unwrap().unwrap();
-        assert_eq!(acc2.1, U256::from(2000));
+        assert_eq!(acc2.balance, U256::from(2000));
+    }
+
+    #[tokio::test]
+    async fn panic_while_writing_surfaces_state_corrupted() {
+        let handle = create_test_handle();
+        handle.init_genesis(vec![(Address::repeat_byte(0x01), U256::from(1000))]).await.unwrap();
+
+        let writer = handle.clone();
+        let result = tokio::spawn(async move {
+            let _store = writer.write().await.unwrap();
+            panic!("writer failed mid-commit");
+        })
+        .await;
+        assert!(result.unwrap_err().is_panic());
+
+        assert!(handle.is_poisoned());
+        assert!(matches!(handle.read().await, Err(HandleError::StateCorrupted)));
+        assert!(matches!(handle.write().await, Err(HandleError::StateCorrupted)));
+        assert!(matches!(handle.commit(ChangeSet::new()).await, Err(HandleError::StateCorrupted)));
+
+        handle.clear_poison();
+        assert!(handle.read().await.is_ok());
+    }
+
+    /// Root provider whose root changes with every commit.
+    #[derive(Default)]
+    struct SequenceRootProvider {
+        commits: u8,
+    }
+
+    #[async_trait]
+    impl RootProvider for SequenceRootProvider {
+        async fn state_root(&self) -> Result<B256, HandleError> {
+            Ok(B256::repeat_byte(self.commits))
+        }
+
+        async fn compute_root(&self, _changes: &ChangeSet) -> Result<B256, HandleError> {
+            Ok(B256::repeat_byte(self.commits + 1))
+        }
+
+        async fn commit_and_get_root(&mut self) -> Result<B256, HandleError> {
+            self.commits += 1;
+            Ok(B256::repeat_byte(self.commits))
+        }
+    }
+
+    #[tokio::test]
+    async fn commit_async_matches_inline_commit() {
+        use monmouth_traits::StateDbWrite;
+
+        let changes = || {
+            let mut changes = ChangeSet::new();
+            changes.insert(
+                Address::repeat_byte(0x01),
+                AccountUpdate {
+                    created: true,
+                    selfdestructed: false,
+                    nonce: 1,
+                    balance: U256::from(1000),
+                    code_hash: alloy_primitives::KECCAK256_EMPTY,
+                    code: None,
+                    storage: Default::default(),
+                },
+            );
+            changes
+        };
+        let with_provider = || {
+            create_test_handle()
+                .with_root_provider(Arc::new(RwLock::new(SequenceRootProvider::default())))
+        };
+
+        let inline = with_provider();
+        let inline_root = StateDbWrite::commit(&inline, changes()).await.unwrap();
+        let offloaded = with_provider();
+        let offloaded_root = offloaded.commit_async(changes()).await.unwrap();
+
+        assert_eq!(offloaded_root, inline_root);
+        let inline_account =
+            inline.read().await.unwrap().get_account(&Address::repeat_byte(0x01)).await.unwrap();
+        let offloaded_account =
+            offloaded.read().await.unwrap().get_account(&Address::repeat_byte(0x01)).await.unwrap();
+        assert_eq!(offloaded_account, inline_account);
+    }
+
+    #[tokio::test]
+    async fn preview_root_matches_commit() {
+        use std::collections::BTreeMap;
+
+        use monmouth_traits::{StateDb, StateDbWrite};
+
+        let handle = create_test_handle();
+        handle.init_genesis(vec![(Address::repeat_byte(0x01), U256::from(1000))]).await.unwrap();
+        let before = handle.state_root().await.unwrap();
+
+        let mut changes = ChangeSet::new();
+        changes.insert(
+            Address::repeat_byte(0x01),
+            AccountUpdate {
+                created: false,
+                selfdestructed: false,
+                nonce: 1,
+                balance: U256::from(900),
+                code_hash: alloy_primitives::KECCAK256_EMPTY,
+                code: None,
+                storage: BTreeMap::from([(U256::from(1), U256::from(5))]),
+            },
+        );
+
+        // Without a root provider previews are computed in memory under the read
+        // lock, so two run at once while a reader holds it.
+        let reader = handle.read().await.unwrap();
+        let (first, second) =
+            futures::join!(handle.preview_root(&changes), handle.preview_root(&changes));
+        drop(reader);
+        let preview = first.unwrap();
+        assert_eq!(second.unwrap(), preview);
+        assert_ne!(preview, before);
+        assert_eq!(handle.state_root().await.unwrap(), before);
+
+        assert_eq!(StateDbWrite::commit(&handle, changes).await.unwrap(), preview);
+    }
+
+    #[tokio::test]
+    async fn provider_preview_runs_alongside_readers() {
+        use futures::FutureExt as _;
+
+        let handle = create_test_handle()
+            .with_root_provider(Arc::new(RwLock::new(SequenceRootProvider::default())));
+
+        let reader = handle.read().await.unwrap();
+        let preview = handle.preview_root(&ChangeSet::new()).now_or_never();
+        drop(reader);
+        assert_eq!(
+            preview.expect("a provider preview waited for a reader").unwrap(),
+            B256::repeat_byte(1)
+        );
+    }
+
+    /// Root provider flagging while a preview is in progress.
+    struct BusyRootProvider {
+        busy: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl RootProvider for BusyRootProvider {
+        async fn state_root(&self) -> Result<B256, HandleError> {
+            Ok(B256::ZERO)
+        }
+
+        async fn compute_root(&self, _changes: &ChangeSet) -> Result<B256, HandleError> {
+            self.busy.store(true, Ordering::SeqCst);
+            for _ in 0..16 {
+                tokio::task::yield_now().await;
+            }
+            self.busy.store(false, Ordering::SeqCst);
+            Ok(B256::ZERO)
+        }
+
+        async fn commit_and_get_root(&mut self) -> Result<B256, HandleError> {
+            Ok(B256::ZERO)
+        }
+    }
+
+    #[tokio::test]
+    async fn provider_preview_excludes_writers() {
+        let busy = Arc::new(AtomicBool::new(false));
+        let handle = create_test_handle()
+            .with_root_provider(Arc::new(RwLock::new(BusyRootProvider { busy: busy.clone() })));
+
+        let (preview, overlapped) = tokio::join!(handle.preview_root(&ChangeSet::new()), async {
+            tokio::task::yield_now().await;
+            let _store = handle.write().await.unwrap();
+            busy.load(Ordering::SeqCst)
+        });
+        preview.unwrap();
+        assert!(!overlapped, "a writer acquired the store during a provider preview");
+    }
+
+    #[tokio::test]
+    async fn consistent_read_sees_coherent_snapshot() {
+        use std::collections::BTreeMap;
+
+        use alloy_primitives::keccak256;
+
+        const COMMITS: u64 = 50;
+        let address = Address::repeat_byte(0x01);
+        let update = |i: u64| {
+            let code = i.to_be_bytes().to_vec();
+            AccountUpdate {
+                created: false,
+                selfdestructed: false,
+                nonce: i,
+                balance: U256::from(i),
+                code_hash: keccak256(&code),
+                code: Some(code),
+                storage: BTreeMap::from([(U256::ZERO, U256::from(i))]),
+            }
+        };
+
+        let handle = create_test_handle();
+        let mut genesis = ChangeSet::new();
+        genesis.insert(address, AccountUpdate { created: true, ..update(0) });
+        handle.commit(genesis).await.unwrap();
+
+        let writer = handle.clone();
+        let writes = tokio::spawn(async move {
+            for i in 1..=COMMITS {
+                let mut changes = ChangeSet::new();
+                changes.insert(address, update(i));
+                writer.commit(changes).await.unwrap();
+                tokio::task::yield_now().await;
+            }
+        });
+
+        let mut observed = Vec::new();
+        while !writes.is_finished() {
+            let snapshot = handle
+                .consistent_read(|store| {
+                    Box::pin(async move {
+                        let StoredAccount { nonce, balance, code_hash, generation } =
+                            store.get_account(&address).await?.unwrap();
+                        tokio::task::yield_now().await;
+                        let slot = store
+                            .get_storage(&StorageKey::new(address, generation, U256::ZERO))
+                            .await?
+                            .unwrap();
+                        tokio::task::yield_now().await;
+                        let code = store.get_code(&code_hash).await?.unwrap();
+                        Ok((nonce, balance, slot, code))
+                    })
+                })
+                .await
+                .unwrap();
+            observed.push(snapshot);
+        }
+        writes.await.unwrap();
+
+        assert!(observed.len() > 1);
+        for (nonce, balance, slot, code) in observed {
+            assert_eq!(balance, U256::from(nonce));
+            assert_eq!(slot, U256::from(nonce));
+            assert_eq!(code, nonce.to_be_bytes().to_vec());
+        }
+    }
+
+    #[tokio::test]
+    async fn import_genesis_matches_init_genesis() {
+        use alloy_primitives::KECCAK256_EMPTY;
+
+        let allocs: Vec<_> = (1..=2_000u32)
+            .map(|index| (Address::left_padding_from(&index.to_be_bytes()), U256::from(index)))
+            .collect();
+
+        let committed = create_test_handle();
+        committed.init_genesis(allocs.clone()).await.unwrap();
+
+        let imported = create_test_handle();
+        imported
+            .import_genesis(|| {
+                allocs.iter().map(|(address, balance)| {
+                    let update = AccountUpdate {
+                        created: true,
+                        selfdestructed: false,
+                        nonce: 0,
+                        balance: *balance,
+                        code_hash: KECCAK256_EMPTY,
+                        code: None,
+                        storage: Default::default(),
+                    };
+                    (*address, update)
+                })
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            imported.read().await.unwrap().state_root().unwrap(),
+            committed.read().await.unwrap().state_root().unwrap()
+        );
     }
 }
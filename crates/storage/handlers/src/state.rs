@@ -1,39 +1,57 @@
 //! StateDb trait implementations for QmdbHandle.
 
 use alloy_primitives::{Address, B256, Bytes, KECCAK256_EMPTY, U256};
-use monmouth_qmdb::{
-    AccountEncoding, ChangeSet, QmdbBatchable, QmdbGettable, StateRoot, StorageKey,
-};
+use monmouth_qmdb::{ChangeSet, QmdbError, QmdbGettable, QmdbRootable, StorageKey};
 use monmouth_traits::{StateDb, StateDbError, StateDbRead, StateDbWrite};
 
-use crate::QmdbHandle;
+use crate::{HandleError, QmdbHandle};
+
+/// Map a store-lock failure to [`StateDbError::LockPoisoned`].
+///
+/// The lock only fails once a writer has panicked, so callers see a distinct
+/// signal instead of a generic storage error and can halt.
+const fn lock_error(_: HandleError) -> StateDbError {
+    StateDbError::LockPoisoned
+}
+
+/// Map a store commit failure to a [`StateDbError`].
+///
+/// Only transient I/O failures ([`QmdbError::Io`]) surface as
+/// [`StateDbError::Io`] for the commit path to retry; everything else, including
+/// generic backend storage errors, is reported as a non-transient storage error.
+fn commit_error(err: QmdbError) -> StateDbError {
+    match err {
+        QmdbError::Io(msg) => StateDbError::Io(msg),
+        other => StateDbError::Storage(other.to_string()),
+    }
+}
 
 impl<A, S, C> StateDbRead for QmdbHandle<A, S, C>
 where
-    A: QmdbGettable<Key = Address, Value = [u8; AccountEncoding::SIZE]> + Send + Sync + 'static,
+    A: QmdbGettable<Key = Address, Value = Vec<u8>> + Send + Sync + 'static,
     S: QmdbGettable<Key = StorageKey, Value = U256> + Send + Sync + 'static,
     C: QmdbGettable<Key = B256, Value = Vec<u8>> + Send + Sync + 'static,
 {
     async fn nonce(&self, address: &Address) -> Result<u64, StateDbError> {
-        let store = self.read().await;
+        let store = self.read().await.map_err(lock_error)?;
         match store.get_account(address).await.map_err(|e| StateDbError::Storage(e.to_string()))? {
-            Some((nonce, _, _, _)) => Ok(nonce),
+            Some(account) => Ok(account.nonce),
             None => Err(StateDbError::AccountNotFound(*address)),
         }
     }
 
     async fn balance(&self, address: &Address) -> Result<U256, StateDbError> {
-        let store = self.read().await;
+        let store = self.read().await.map_err(lock_error)?;
         match store.get_account(address).await.map_err(|e| StateDbError::Storage(e.to_string()))? {
-            Some((_, balance, _, _)) => Ok(balance),
+            Some(account) => Ok(account.balance),
             None => Err(StateDbError::AccountNotFound(*address)),
         }
     }
 
     async fn code_hash(&self, address: &Address) -> Result<B256, StateDbError> {
-        let store = self.read().await;
+        let store = self.read().await.map_err(lock_error)?;
         match store.get_account(address).await.map_err(|e| StateDbError::Storage(e.to_string()))? {
-            Some((_, _, code_hash, _)) => Ok(code_hash),
+            Some(account) => Ok(account.code_hash),
             None => Err(StateDbError::AccountNotFound(*address)),
         }
     }
@@ -42,7 +60,7 @@ where
         if *code_hash == KECCAK256_EMPTY || *code_hash == B256::ZERO {
             return Ok(Bytes::new());
         }
-        let store = self.read().await;
+        let store = self.read().await.map_err(lock_error)?;
         store
             .get_code(code_hash)
             .await
@@ -53,8 +71,20 @@ where
             )
     }
 
+    async fn code_size(&self, code_hash: &B256) -> Result<usize, StateDbError> {
+        if *code_hash == KECCAK256_EMPTY || *code_hash == B256::ZERO {
+            return Ok(0);
+        }
+        if let Some(size) = self.cached_code_size(code_hash) {
+            return Ok(size);
+        }
+        let size = self.code(code_hash).await?.len();
+        self.record_code_size(*code_hash, size);
+        Ok(size)
+    }
+
     async fn storage(&self, address: &Address, slot: &U256) -> Result<U256, StateDbError> {
-        let store = self.read().await;
+        let store = self.read().await.map_err(lock_error)?;
 
         // Get account to find generation
         let generation = match store
@@ -62,7 +92,7 @@ where
             .await
             .map_err(|e| StateDbError::Storage(e.to_string()))?
         {
-            Some((_, _, _, generation)) => generation,
+            Some(account) => account.generation,
             None => return Ok(U256::ZERO),
         };
 
@@ -77,27 +107,27 @@ where
 
 impl<A, S, C> StateDbWrite for QmdbHandle<A, S, C>
 where
-    A: QmdbGettable<Key = Address, Value = [u8; AccountEncoding::SIZE]>
-        + QmdbBatchable<Key = Address, Value = [u8; AccountEncoding::SIZE]>
+    A: QmdbGettable<Key = Address, Value = Vec<u8>>
+        + QmdbRootable<Key = Address, Value = Vec<u8>>
         + Send
         + Sync
         + 'static,
     S: QmdbGettable<Key = StorageKey, Value = U256>
-        + QmdbBatchable<Key = StorageKey, Value = U256>
+        + QmdbRootable<Key = StorageKey, Value = U256>
         + Send
         + Sync
         + 'static,
     C: QmdbGettable<Key = B256, Value = Vec<u8>>
-        + QmdbBatchable<Key = B256, Value = Vec<u8>>
+        + QmdbRootable<Key = B256, Value = Vec<u8>>
         + Send
         + Sync
         + 'static,
 {
     async fn commit(&self, changes: ChangeSet) -> Result<B256, StateDbError> {
-        let mut store = self.write().await;
-        store.commit_changes(changes).await.map_err(|e| StateDbError::Storage(e.to_string()))?;
+        self.record_code_sizes(&changes);
+        let mut store = self.write().await.map_err(lock_error)?;
+        store.commit_changes(changes).await.map_err(commit_error)?;
 
-        // If we have a root provider, commit and get the state root
         if let Some(provider) = self.root_provider() {
             let mut provider = provider.write().await;
             provider
@@ -105,23 +135,15 @@ where
                 .await
                 .map_err(|e| StateDbError::RootComputation(e.to_string()))
         } else {
-            // Return placeholder root when no provider is set
-            Ok(B256::ZERO)
+            store.state_root().map_err(|e| StateDbError::RootComputation(e.to_string()))
         }
     }
 
     async fn compute_root(&self, changes: &ChangeSet) -> Result<B256, StateDbError> {
-        // If we have a root provider, use it to compute the root
-        if let Some(provider) = self.root_provider() {
-            let mut provider = provider.write().await;
-            provider
-                .compute_root(changes)
-                .await
-                .map_err(|e| StateDbError::RootComputation(e.to_string()))
-        } else {
-            // Return placeholder root when no provider is set
-            Ok(StateRoot::compute(B256::ZERO, B256::ZERO, B256::ZERO))
-        }
+        self.preview_root(changes).await.map_err(|e| match e {
+            HandleError::StateCorrupted => StateDbError::LockPoisoned,
+            other => StateDbError::RootComputation(other.to_string()),
+        })
     }
 
     fn merge_changes(&self, mut older: ChangeSet, newer: ChangeSet) -> ChangeSet {
@@ -132,39 +154,42 @@ where
 
 impl<A, S, C> StateDb for QmdbHandle<A, S, C>
 where
-    A: QmdbGettable<Key = Address, Value = [u8; AccountEncoding::SIZE]>
-        + QmdbBatchable<Key = Address, Value = [u8; AccountEncoding::SIZE]>
+    A: QmdbGettable<Key = Address, Value = Vec<u8>>
+        + QmdbRootable<Key = Address, Value = Vec<u8>>
         + Send
         + Sync
         + 'static,
     S: QmdbGettable<Key = StorageKey, Value = U256>
-        + QmdbBatchable<Key = StorageKey, Value = U256>
+        + QmdbRootable<Key = StorageKey, Value = U256>
         + Send
         + Sync
         + 'static,
     C: QmdbGettable<Key = B256, Value = Vec<u8>>
-        + QmdbBatchable<Key = B256, Value = Vec<u8>>
+        + QmdbRootable<Key = B256, Value = Vec<u8>>
         + Send
         + Sync
         + 'static,
 {
     async fn state_root(&self) -> Result<B256, StateDbError> {
-        // If we have a root provider, use it to get the state root
         if let Some(provider) = self.root_provider() {
             let provider = provider.read().await;
             provider.state_root().await.map_err(|e| StateDbError::RootComputation(e.to_string()))
         } else {
-            // Return zero root when no provider is set
-            Ok(B256::ZERO)
+            let store = self.read().await.map_err(lock_error)?;
+            store.state_root().map_err(|e| StateDbError::RootComputation(e.to_string()))
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashMap as StdHashMap, sync::Mutex};
+    use std::{
+        collections::{BTreeMap, HashMap as StdHashMap},
+        sync::Mutex,
+    };
 
-    use monmouth_qmdb::{QmdbBatchable, QmdbGettable};
+    use alloy_primitives::keccak256;
+    use monmouth_qmdb::{AccountUpdate, QmdbBatchable, QmdbGettable, QmdbRootable};
 
     use super::*;
 
@@ -225,8 +250,41 @@ mod tests {
         }
     }
 
+    impl<K, V> QmdbRootable for MemoryStore<K, V>
+    where
+        K: Clone + Eq + std::hash::Hash + std::fmt::Debug + Send + Sync,
+        V: Clone + std::fmt::Debug + Send + Sync,
+    {
+        fn root(&self) -> Result<B256, Self::Error> {
+            Ok(memory_root(&self.data.lock().unwrap()))
+        }
+
+        async fn root_with<I>(&self, ops: I) -> Result<B256, Self::Error>
+        where
+            I: IntoIterator<Item = (Self::Key, Option<Self::Value>)> + Send,
+            I::IntoIter: Send,
+        {
+            let mut data = self.data.lock().unwrap().clone();
+            for (key, value) in ops {
+                match value {
+                    Some(v) => data.insert(key, v),
+                    None => data.remove(&key),
+                };
+            }
+            Ok(memory_root(&data))
+        }
+    }
+
+    /// Order-independent digest over a memory store's entries.
+    fn memory_root<K: std::fmt::Debug, V: std::fmt::Debug>(data: &StdHashMap<K, V>) -> B256 {
+        let mut entries: Vec<String> =
+            data.iter().map(|(key, value)| format!("{key:?}={value:?}")).collect();
+        entries.sort();
+        keccak256(entries.join(";"))
+    }
+
     type TestHandle = QmdbHandle<
-        MemoryStore<Address, [u8; 80]>,
+        MemoryStore<Address, Vec<u8>>,
         MemoryStore<StorageKey, U256>,
         MemoryStore<B256, Vec<u8>>,
     >;
@@ -256,6 +314,68 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    #[tokio::test]
+    async fn code_size_matches_code_len() {
+        let handle = create_test_handle();
+        let contracts: Vec<Vec<u8>> =
+            vec![vec![0x00], vec![0x60, 0x00, 0x60, 0x00, 0xf3], vec![0x5b; 24_576]];
+
+        let mut changes = ChangeSet::new();
+        for (i, code) in contracts.iter().enumerate() {
+            changes.accounts.insert(
+                Address::with_last_byte(i as u8 + 1),
+                AccountUpdate {
+                    created: true,
+                    selfdestructed: false,
+                    nonce: 1,
+                    balance: U256::ZERO,
+                    code_hash: keccak256(code),
+                    code: Some(code.clone()),
+                    storage: BTreeMap::new(),
+                },
+            );
+        }
+        StateDbWrite::commit(&handle, changes).await.unwrap();
+
+        for code in &contracts {
+            let hash = keccak256(code);
+            let expected = handle.code(&hash).await.unwrap().len();
+            assert_eq!(handle.code_size(&hash).await.unwrap(), expected);
+        }
+
+        // Code written behind the handle's back is not indexed yet and is measured on first read.
+        let unindexed = vec![0xfe; 100];
+        let mut changes = ChangeSet::new();
+        changes.accounts.insert(
+            Address::with_last_byte(0xff),
+            AccountUpdate {
+                created: true,
+                selfdestructed: false,
+                nonce: 1,
+                balance: U256::ZERO,
+                code_hash: keccak256(&unindexed),
+                code: Some(unindexed.clone()),
+                storage: BTreeMap::new(),
+            },
+        );
+        handle.write().await.unwrap().commit_changes(changes).await.unwrap();
+        assert_eq!(handle.code_size(&keccak256(&unindexed)).await.unwrap(), unindexed.len());
+    }
+
+    #[tokio::test]
+    async fn code_size_is_zero_for_empty_code() {
+        let handle = create_test_handle();
+        assert_eq!(handle.code_size(&KECCAK256_EMPTY).await.unwrap(), 0);
+        assert_eq!(handle.code_size(&B256::ZERO).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn code_size_errors_for_unknown_code() {
+        let handle = create_test_handle();
+        let result = handle.code_size(&B256::repeat_byte(0x11)).await;
+        assert!(matches!(result, Err(StateDbError::CodeNotFound(_))));
+    }
+
     #[tokio::test]
     async fn state_db_merge_changes() {
         let handle = create_test_handle();
@@ -264,4 +384,57 @@ mod tests {
         let merged = handle.merge_changes(older, newer);
         assert!(merged.is_empty());
     }
+
+    fn funding(address: Address, balance: u64) -> ChangeSet {
+        let mut changes = ChangeSet::new();
+        changes.accounts.insert(
+            address,
+            AccountUpdate {
+                created: true,
+                selfdestructed: false,
+                nonce: 0,
+                balance: U256::from(balance),
+                code_hash: KECCAK256_EMPTY,
+                code: None,
+                storage: BTreeMap::new(),
+            },
+        );
+        changes
+    }
+
+    #[tokio::test]
+    async fn commit_returns_state_root() {
+        let first = create_test_handle();
+        let empty = first.state_root().await.unwrap();
+        let root_a =
+            StateDbWrite::commit(&first, funding(Address::repeat_byte(0x01), 100)).await.unwrap();
+        let second = create_test_handle();
+        let root_b =
+            StateDbWrite::commit(&second, funding(Address::repeat_byte(0x02), 100)).await.unwrap();
+        assert_ne!(root_a, root_b);
+        assert_ne!(root_a, empty);
+
+        let repeat = create_test_handle();
+        let root_repeat =
+            StateDbWrite::commit(&repeat, funding(Address::repeat_byte(0x01), 100)).await.unwrap();
+        assert_eq!(root_repeat, root_a);
+        assert_eq!(repeat.state_root().await.unwrap(), root_a);
+    }
+
+    #[tokio::test]
+    async fn compute_root_does_not_persist() {
+        let handle = create_test_handle();
+        let before = handle.state_root().await.unwrap();
+        let changes = funding(Address::repeat_byte(0x01), 100);
+
+        let speculative = handle.compute_root(&changes).await.unwrap();
+        assert_ne!(speculative, before);
+        assert_eq!(handle.state_root().await.unwrap(), before);
+        assert!(matches!(
+            handle.nonce(&Address::repeat_byte(0x01)).await,
+            Err(StateDbError::AccountNotFound(_))
+        ));
+
+        assert_eq!(StateDbWrite::commit(&handle, changes).await.unwrap(), speculative);
+    }
 }
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use alloy_primitives::{Address, B256, Bytes, U256};
-use monmouth_qmdb::ChangeSet;
+use monmouth_qmdb::{ChangeSet, canonical_code_hash};
 use monmouth_traits::{StateDb, StateDbError, StateDbRead, StateDbWrite};
 
 /// State overlay that layers pending changes on top of a base state database.
@@ -73,7 +73,7 @@ impl<S: StateDbRead> StateDbRead for OverlayState<S> {
         let changes = Arc::clone(&self.changes);
         async move {
             if let Some(update) = changes.accounts.get(&address) {
-                return Ok(update.code_hash);
+                return Ok(canonical_code_hash(update.code_hash));
             }
             base.code_hash(&address).await
         }
@@ -98,6 +98,25 @@ impl<S: StateDbRead> StateDbRead for OverlayState<S> {
         }
     }
 
+    fn code_size(
+        &self,
+        code_hash: &B256,
+    ) -> impl std::future::Future<Output = Result<usize, StateDbError>> + Send {
+        let code_hash = *code_hash;
+        let base = self.base.clone();
+        let changes = Arc::clone(&self.changes);
+        async move {
+            for update in changes.accounts.values() {
+                if update.code_hash == code_hash
+                    && let Some(code) = &update.code
+                {
+                    return Ok(code.len());
+                }
+            }
+            base.code_size(&code_hash).await
+        }
+    }
+
     fn storage(
         &self,
         address: &Address,
@@ -297,6 +316,26 @@ mod tests {
         assert_eq!(overlay.balance(&addr).await.unwrap(), U256::from(2000));
     }
 
+    #[tokio::test]
+    async fn test_overlay_code_size_prefers_changes() {
+        let pending = B256::repeat_byte(0xaa);
+        let committed = B256::repeat_byte(0xbb);
+        let base = MockStateDb::new().with_account(
+            Address::repeat_byte(0x01),
+            AccountUpdate { code_hash: committed, code: Some(vec![0x60; 7]), ..test_account(0, 0) },
+        );
+
+        let mut changes = ChangeSet::new();
+        changes.accounts.insert(
+            Address::repeat_byte(0x02),
+            AccountUpdate { code_hash: pending, code: Some(vec![0x60; 3]), ..test_account(0, 0) },
+        );
+        let overlay = OverlayState::new(base, changes);
+
+        assert_eq!(overlay.code_size(&pending).await.unwrap(), 3);
+        assert_eq!(overlay.code_size(&committed).await.unwrap(), 7);
+    }
+
     #[tokio::test]
     async fn test_overlay_storage_from_changes() {
         let addr = Address::repeat_byte(0x02);
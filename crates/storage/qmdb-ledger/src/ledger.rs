@@ -1,16 +1,16 @@
 use std::sync::Arc;
 
-use alloy_primitives::{Address, U256};
+use alloy_primitives::{Address, B256, U256};
 use commonware_runtime::tokio::Context;
 use monmouth_backend::{
-    AccountStore, CodeStore, CommonwareBackend, CommonwareRootProvider, QmdbBackendConfig,
-    StorageStore,
+    AccountStore, CodeStore, CommitRetryPolicy, CommonwareBackend, QmdbBackendConfig, StorageStore,
 };
 use monmouth_domain::StateRoot;
 use monmouth_handlers::{HandleError, QmdbHandle, QmdbRefDb as HandlerQmdbRefDb};
-use monmouth_traits::{StateDb, StateDbWrite};
+use monmouth_traits::{StateDb, StateDbError, StateDbWrite};
 use thiserror::Error;
 use tokio::sync::RwLock;
+use tracing::warn;
 
 /// QMDB configuration for the backend.
 pub type QmdbConfig = QmdbBackendConfig;
@@ -27,6 +27,7 @@ type Handle = QmdbState;
 #[derive(Clone, Debug)]
 pub struct QmdbLedger {
     handle: Handle,
+    commit_retry: CommitRetryPolicy,
 }
 
 /// Errors for QMDB ledger operations.
@@ -53,13 +54,13 @@ impl QmdbLedger {
         config: QmdbConfig,
         genesis_alloc: Vec<(Address, U256)>,
     ) -> Result<Self, Error> {
-        let backend = CommonwareBackend::open(context.clone(), config.clone()).await?;
-        let root_provider = CommonwareRootProvider::new(context, config);
-        let (accounts, storage, code) = backend.into_stores();
-        let handle = Handle::new(accounts, storage, code)
+        let commit_retry = config.commit_retry;
+        let backend = CommonwareBackend::open(context, config).await?;
+        let root_provider = backend.root_provider();
+        let handle = Handle::from_store(backend.into_store())
             .with_root_provider(Arc::new(RwLock::new(root_provider)));
         handle.init_genesis(genesis_alloc).await?;
-        Ok(Self { handle })
+        Ok(Self { handle, commit_retry })
     }
 
     /// Exposes a synchronous REVM database view backed by QMDB.
@@ -79,14 +80,131 @@ impl QmdbLedger {
     }
 
     /// Commits the provided changes to QMDB and returns the resulting root.
+    ///
+    /// Transient I/O failures are retried according to the configured
+    /// [`CommitRetryPolicy`]; any other failure is returned immediately. A retry
+    /// resumes the failed attempt, writing only the partitions it left unwritten.
     pub async fn commit_changes(&self, changes: QmdbChangeSet) -> Result<StateRoot, Error> {
-        let root = StateDbWrite::commit(&self.handle, changes).await?;
+        let root = commit_with_retry(&self.handle, changes, self.commit_retry).await?;
         Ok(StateRoot(root))
     }
 
+    /// Records `root` as the state root after the block at `height`.
+    ///
+    /// Roots are kept in the backend's height-indexed root history, for the
+    /// configured retention.
+    pub async fn record_root(&self, height: u64, root: StateRoot) -> Result<(), Error> {
+        self.handle.record_root(height, root.0).await?;
+        Ok(())
+    }
+
+    /// Returns the state root recorded for the block at `height`, if retained.
+    pub async fn root_at(&self, height: u64) -> Result<Option<StateRoot>, Error> {
+        Ok(self.handle.root_at(height).await?.map(StateRoot))
+    }
+
     /// Returns the current authenticated root stored in QMDB.
     pub async fn root(&self) -> Result<StateRoot, Error> {
         let root = StateDb::state_root(&self.handle).await?;
         Ok(StateRoot(root))
     }
 }
+
+/// Commit `changes`, retrying transient I/O failures with exponential backoff.
+async fn commit_with_retry<S: StateDbWrite>(
+    state: &S,
+    changes: QmdbChangeSet,
+    policy: CommitRetryPolicy,
+) -> Result<B256, StateDbError> {
+    let mut retry = 0;
+    loop {
+        match state.commit(changes.clone()).await {
+            Err(err) if err.is_transient() && retry < policy.max_retries => {
+                let delay = policy.delay(retry);
+                retry += 1;
+                warn!(%err, retry, max_retries = policy.max_retries, ?delay, "retrying state commit");
+                tokio::time::sleep(delay).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicU32, Ordering},
+        time::Duration,
+    };
+
+    use super::*;
+
+    /// State whose first `failures` commits fail.
+    #[derive(Clone)]
+    struct FlakyState {
+        failures: u32,
+        transient: bool,
+        attempts: Arc<AtomicU32>,
+    }
+
+    impl FlakyState {
+        fn new(failures: u32, transient: bool) -> Self {
+            Self { failures, transient, attempts: Arc::new(AtomicU32::new(0)) }
+        }
+
+        fn attempts(&self) -> u32 {
+            self.attempts.load(Ordering::SeqCst)
+        }
+    }
+
+    impl StateDbWrite for FlakyState {
+        async fn commit(&self, _changes: QmdbChangeSet) -> Result<B256, StateDbError> {
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst);
+            if attempt < self.failures {
+                return Err(if self.transient {
+                    StateDbError::Io("device busy".to_string())
+                } else {
+                    StateDbError::Storage("corrupt page".to_string())
+                });
+            }
+            Ok(B256::repeat_byte(0xab))
+        }
+
+        async fn compute_root(&self, _changes: &QmdbChangeSet) -> Result<B256, StateDbError> {
+            Ok(B256::ZERO)
+        }
+
+        fn merge_changes(&self, mut older: QmdbChangeSet, newer: QmdbChangeSet) -> QmdbChangeSet {
+            older.merge(newer);
+            older
+        }
+    }
+
+    const fn policy(max_retries: u32) -> CommitRetryPolicy {
+        CommitRetryPolicy::new(max_retries, Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn transient_failures_are_retried() {
+        let state = FlakyState::new(2, true);
+        let root = commit_with_retry(&state, QmdbChangeSet::new(), policy(3)).await.unwrap();
+        assert_eq!(root, B256::repeat_byte(0xab));
+        assert_eq!(state.attempts(), 3);
+    }
+
+    #[tokio::test]
+    async fn retry_budget_is_bounded() {
+        let state = FlakyState::new(5, true);
+        let err = commit_with_retry(&state, QmdbChangeSet::new(), policy(2)).await.unwrap_err();
+        assert!(matches!(err, StateDbError::Io(_)));
+        assert_eq!(state.attempts(), 3);
+    }
+
+    #[tokio::test]
+    async fn non_transient_failures_are_fatal() {
+        let state = FlakyState::new(1, false);
+        let err = commit_with_retry(&state, QmdbChangeSet::new(), policy(3)).await.unwrap_err();
+        assert!(matches!(err, StateDbError::Storage(_)));
+        assert_eq!(state.attempts(), 1);
+    }
+}
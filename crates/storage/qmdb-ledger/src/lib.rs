@@ -8,3 +8,4 @@
 mod ledger;
 
 pub use ledger::{Error, QmdbChangeSet, QmdbConfig, QmdbLedger, QmdbRefDb, QmdbState};
+pub use monmouth_backend::CommitRetryPolicy;
@@ -8,7 +8,7 @@ use crate::encoding::StorageKey;
 #[derive(Debug, Default)]
 pub struct StoreBatches {
     /// Account operations: (address, encoded_account or None for deletion).
-    pub accounts: Vec<(Address, Option<[u8; 80]>)>,
+    pub accounts: Vec<(Address, Option<Vec<u8>>)>,
     /// Storage operations: (key, value or None for deletion).
     pub storage: Vec<(StorageKey, Option<U256>)>,
     /// Code operations: (hash, bytes or None for deletion).
@@ -54,7 +54,7 @@ mod tests {
     #[test]
     fn test_is_empty_with_accounts() {
         let mut batches = StoreBatches::new();
-        batches.accounts.push((Address::ZERO, Some([0u8; 80])));
+        batches.accounts.push((Address::ZERO, Some(vec![0u8; 80])));
         assert!(!batches.is_empty());
     }
 
@@ -77,7 +77,7 @@ mod tests {
     fn test_len_counts_all_operations() {
         let mut batches = StoreBatches::new();
 
-        batches.accounts.push((Address::ZERO, Some([0u8; 80])));
+        batches.accounts.push((Address::ZERO, Some(vec![0u8; 80])));
         batches.accounts.push((Address::repeat_byte(0x01), None));
 
         let key1 = StorageKey::new(Address::ZERO, 0, U256::from(1));
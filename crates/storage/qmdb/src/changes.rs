@@ -2,7 +2,7 @@
 
 use std::collections::BTreeMap;
 
-use alloy_primitives::{Address, B256, U256};
+use alloy_primitives::{Address, B256, KECCAK256_EMPTY, U256};
 
 /// Accumulated state changes that can be merged across blocks.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -49,6 +49,15 @@ impl ChangeSet {
     }
 }
 
+/// Canonical form of an account code hash.
+///
+/// Accounts without code are stored with [`KECCAK256_EMPTY`]. A zero hash is
+/// only ever a legacy or uninitialized marker and is read as empty code.
+#[must_use]
+pub fn canonical_code_hash(code_hash: B256) -> B256 {
+    if code_hash == B256::ZERO { KECCAK256_EMPTY } else { code_hash }
+}
+
 /// State changes for a single account.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AccountUpdate {
@@ -103,6 +112,14 @@ impl AccountUpdate {
 mod tests {
     use super::*;
 
+    #[test]
+    fn canonical_code_hash_maps_zero_to_empty() {
+        assert_eq!(canonical_code_hash(B256::ZERO), KECCAK256_EMPTY);
+        assert_eq!(canonical_code_hash(KECCAK256_EMPTY), KECCAK256_EMPTY);
+        let hash = B256::repeat_byte(0xAB);
+        assert_eq!(canonical_code_hash(hash), hash);
+    }
+
     #[test]
     fn merge_overwrites_nonce_and_balance() {
         let mut cs1 = ChangeSet::new();
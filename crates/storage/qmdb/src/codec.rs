@@ -0,0 +1,269 @@
+//! Versioned account codecs.
+
+use std::fmt;
+
+use alloy_primitives::{Address, B256, U256};
+use thiserror::Error;
+
+use crate::encoding::{AccountEncoding, AgentMetadata};
+
+/// Errors returned when selecting or applying an [`AccountCodec`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum AccountCodecError {
+    /// No codec is registered for the format marker.
+    #[error("unknown account encoding version {0}")]
+    UnknownVersion(u32),
+    /// The encoded account has the wrong length for the codec.
+    #[error("account encoding version {version} expects {expected} bytes, found {found}")]
+    InvalidLength {
+        /// Version of the codec that rejected the bytes.
+        version: u32,
+        /// Length the codec expects.
+        expected: usize,
+        /// Length that was provided.
+        found: usize,
+    },
+    /// The agent metadata flag of an extended account is neither 0 nor 1.
+    #[error("invalid agent metadata flag {0}")]
+    InvalidAgentFlag(u8),
+}
+
+/// An account as recorded in the account partition.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StoredAccount {
+    /// Account nonce.
+    pub nonce: u64,
+    /// Account balance.
+    pub balance: U256,
+    /// Hash of the account's code.
+    pub code_hash: B256,
+    /// Storage generation, bumped whenever the account is recreated.
+    pub generation: u64,
+    /// Agent identity, kept only by codecs with room for it such as [`V2`].
+    pub agent: Option<AgentMetadata>,
+}
+
+/// Encodes and decodes account values stored in the account partition.
+///
+/// Each implementation is one on-disk layout, identified by the format marker
+/// returned from [`version`](Self::version). Stores select a codec with
+/// [`account_codec`] so existing partitions keep decoding after new layouts are
+/// added.
+pub trait AccountCodec: fmt::Debug + Send + Sync {
+    /// Format marker identifying this layout.
+    fn version(&self) -> u32;
+
+    /// Length in bytes of every account encoded with this layout.
+    fn encoded_len(&self) -> usize;
+
+    /// Encode `account`.
+    fn encode(&self, account: &StoredAccount) -> Vec<u8>;
+
+    /// Decode an account written by [`encode`](Self::encode).
+    fn decode(&self, bytes: &[u8]) -> Result<StoredAccount, AccountCodecError>;
+}
+
+/// The original 80-byte account layout, format version 1.
+///
+/// See [`AccountEncoding`] for the field layout. The layout has no room for
+/// agent metadata, so [`StoredAccount::agent`] is dropped on encode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct V1;
+
+impl V1 {
+    /// Format marker for this layout.
+    pub const VERSION: u32 = 1;
+}
+
+impl AccountCodec for V1 {
+    fn version(&self) -> u32 {
+        Self::VERSION
+    }
+
+    fn encoded_len(&self) -> usize {
+        AccountEncoding::SIZE
+    }
+
+    fn encode(&self, account: &StoredAccount) -> Vec<u8> {
+        AccountEncoding::encode(
+            account.nonce,
+            account.balance,
+            account.code_hash,
+            account.generation,
+        )
+        .to_vec()
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<StoredAccount, AccountCodecError> {
+        let invalid = AccountCodecError::InvalidLength {
+            version: Self::VERSION,
+            expected: AccountEncoding::SIZE,
+            found: bytes.len(),
+        };
+        if bytes.len() != AccountEncoding::SIZE {
+            return Err(invalid);
+        }
+        let (nonce, balance, code_hash, generation) =
+            AccountEncoding::decode(bytes).ok_or(invalid)?;
+        Ok(StoredAccount { nonce, balance, code_hash, generation, agent: None })
+    }
+}
+
+/// The extended account layout carrying agent metadata, format version 2.
+///
+/// The [`V1`] fields are followed by a flag byte, 1 if the account has agent
+/// metadata and 0 otherwise, and the [`AgentMetadata`] fields: agent ID (32)
+/// and reputation registry (20), zeroed when the flag is 0.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct V2;
+
+impl V2 {
+    /// Format marker for this layout.
+    pub const VERSION: u32 = 2;
+
+    /// Encoded size in bytes.
+    pub const SIZE: usize = AccountEncoding::SIZE + 1 + AgentMetadata::SIZE;
+}
+
+impl AccountCodec for V2 {
+    fn version(&self) -> u32 {
+        Self::VERSION
+    }
+
+    fn encoded_len(&self) -> usize {
+        Self::SIZE
+    }
+
+    fn encode(&self, account: &StoredAccount) -> Vec<u8> {
+        let mut buf = V1.encode(account);
+        let agent = account.agent.unwrap_or_default();
+        buf.push(u8::from(account.agent.is_some()));
+        buf.extend_from_slice(&agent.agent_id.to_be_bytes::<32>());
+        buf.extend_from_slice(agent.reputation.as_slice());
+        buf
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<StoredAccount, AccountCodecError> {
+        if bytes.len() != Self::SIZE {
+            return Err(AccountCodecError::InvalidLength {
+                version: Self::VERSION,
+                expected: Self::SIZE,
+                found: bytes.len(),
+            });
+        }
+        let (classic, extension) = bytes.split_at(AccountEncoding::SIZE);
+        let account = V1.decode(classic)?;
+        let agent = match extension[0] {
+            0 => None,
+            1 => Some(AgentMetadata {
+                agent_id: U256::from_be_slice(&extension[1..33]),
+                reputation: Address::from_slice(&extension[33..53]),
+            }),
+            flag => return Err(AccountCodecError::InvalidAgentFlag(flag)),
+        };
+        Ok(StoredAccount { agent, ..account })
+    }
+}
+
+/// Returns the codec for the format marker `version`.
+pub fn account_codec(version: u32) -> Result<&'static dyn AccountCodec, AccountCodecError> {
+    match version {
+        V1::VERSION => Ok(&V1),
+        V2::VERSION => Ok(&V2),
+        other => Err(AccountCodecError::UnknownVersion(other)),
+    }
+}
+
+/// Decode `bytes` with the codec for the format marker `version`.
+pub fn decode_account(version: u32, bytes: &[u8]) -> Result<StoredAccount, AccountCodecError> {
+    account_codec(version)?.decode(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(123, U256::from(1_000_000u64), B256::repeat_byte(0xCD), 5)]
+    #[case(0, U256::ZERO, B256::ZERO, 0)]
+    #[case(u64::MAX, U256::MAX, B256::repeat_byte(0xFF), u64::MAX)]
+    fn v1_roundtrip(
+        #[case] nonce: u64,
+        #[case] balance: U256,
+        #[case] code_hash: B256,
+        #[case] generation: u64,
+    ) {
+        let codec = account_codec(V1::VERSION).unwrap();
+        assert_eq!(codec.version(), V1::VERSION);
+        let account = StoredAccount { nonce, balance, code_hash, generation, agent: None };
+        let encoded = codec.encode(&account);
+        assert_eq!(encoded.len(), codec.encoded_len());
+        assert_eq!(encoded, AccountEncoding::encode(nonce, balance, code_hash, generation));
+        assert_eq!(decode_account(V1::VERSION, &encoded), Ok(account));
+    }
+
+    #[test]
+    fn unknown_version_is_rejected() {
+        let encoded =
+            V1.encode(&StoredAccount { nonce: 1, balance: U256::from(1u64), ..Default::default() });
+        let err = decode_account(7, &encoded).unwrap_err();
+        assert_eq!(err, AccountCodecError::UnknownVersion(7));
+        assert_eq!(err.to_string(), "unknown account encoding version 7");
+    }
+
+    #[test]
+    fn v1_rejects_wrong_length() {
+        let encoded =
+            V1.encode(&StoredAccount { nonce: 1, balance: U256::from(1u64), ..Default::default() });
+        assert_eq!(
+            V1.decode(&encoded[..79]),
+            Err(AccountCodecError::InvalidLength { version: 1, expected: 80, found: 79 })
+        );
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some(AgentMetadata::default()))]
+    #[case(Some(AgentMetadata::new(U256::from(42u64), Address::repeat_byte(0x8a))))]
+    #[case(Some(AgentMetadata::new(U256::MAX, Address::repeat_byte(0xFF))))]
+    fn v2_roundtrip(#[case] agent: Option<AgentMetadata>) {
+        let codec = account_codec(V2::VERSION).unwrap();
+        assert_eq!(codec.version(), V2::VERSION);
+        let account = StoredAccount {
+            nonce: 123,
+            balance: U256::from(1_000_000u64),
+            code_hash: B256::repeat_byte(0xCD),
+            generation: 5,
+            agent,
+        };
+        let encoded = codec.encode(&account);
+        assert_eq!(encoded.len(), codec.encoded_len());
+        assert_eq!(decode_account(V2::VERSION, &encoded), Ok(account));
+
+        // The classic fields keep the V1 layout.
+        let classic = StoredAccount { agent: None, ..account };
+        assert_eq!(encoded[..AccountEncoding::SIZE], V1.encode(&classic)[..]);
+        assert_eq!(V1.decode(&encoded[..AccountEncoding::SIZE]), Ok(classic));
+    }
+
+    #[test]
+    fn v1_drops_agent_metadata() {
+        let agent = AgentMetadata::new(U256::from(7u64), Address::repeat_byte(0x01));
+        let account = StoredAccount { nonce: 1, agent: Some(agent), ..Default::default() };
+        let decoded = V1.decode(&V1.encode(&account)).unwrap();
+        assert_eq!(decoded, StoredAccount { agent: None, ..account });
+    }
+
+    #[test]
+    fn v2_rejects_malformed_accounts() {
+        let mut encoded = V2.encode(&StoredAccount::default());
+        assert_eq!(
+            V2.decode(&encoded[..AccountEncoding::SIZE]),
+            Err(AccountCodecError::InvalidLength { version: 2, expected: 133, found: 80 })
+        );
+        encoded[AccountEncoding::SIZE] = 0xff;
+        assert_eq!(V2.decode(&encoded), Err(AccountCodecError::InvalidAgentFlag(0xff)));
+    }
+}
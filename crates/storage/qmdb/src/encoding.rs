@@ -38,6 +38,29 @@ impl StorageKey {
     }
 }
 
+/// Agent metadata carried by the extended [`V2`](crate::V2) account layout.
+///
+/// Reflects an ERC-8004 identity at the state level: the agent's registry token
+/// ID and the reputation registry that scores it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AgentMetadata {
+    /// Agent ID in the identity registry.
+    pub agent_id: U256,
+    /// Reputation registry holding the agent's feedback.
+    pub reputation: Address,
+}
+
+impl AgentMetadata {
+    /// Encoded size in bytes.
+    pub const SIZE: usize = 52;
+
+    /// Create agent metadata.
+    #[must_use]
+    pub const fn new(agent_id: U256, reputation: Address) -> Self {
+        Self { agent_id, reputation }
+    }
+}
+
 /// Account encoding utility.
 ///
 /// Encodes account info as 80 bytes: nonce (8) + balance (32) + code_hash (32) + generation (8).
@@ -1,8 +1,10 @@
 //! Error types for QMDB operations.
 
-use alloy_primitives::B256;
+use alloy_primitives::{Address, B256};
 use thiserror::Error;
 
+use crate::codec::AccountCodecError;
+
 /// Error type for QMDB store operations.
 #[derive(Debug, Error)]
 pub enum QmdbError {
@@ -10,6 +12,15 @@ pub enum QmdbError {
     #[error("storage error: {0}")]
     Storage(String),
 
+    /// Transient storage I/O failure; retrying the operation may succeed.
+    #[error("storage i/o error: {0}")]
+    Io(String),
+
+    /// An earlier commit was only partly written and a different change set
+    /// was committed before it completed.
+    #[error("partial commit pending")]
+    PartialCommit,
+
     /// Stores unavailable during update.
     #[error("stores unavailable")]
     StoreUnavailable,
@@ -21,6 +32,32 @@ pub enum QmdbError {
     /// Code not found for hash.
     #[error("code not found: {0}")]
     CodeNotFound(B256),
+
+    /// Bulk import accounts were not in strictly ascending address order.
+    #[error("bulk import out of order at {0}")]
+    UnsortedImport(Address),
+
+    /// Account codec rejected the stored bytes.
+    #[error("account codec error: {0}")]
+    AccountCodec(#[from] AccountCodecError),
+}
+
+impl QmdbError {
+    /// Wrap a store error, keeping transient I/O failures apart.
+    ///
+    /// A store signals a failure worth retrying by reporting a
+    /// [`std::io::Error`] as the error or one of its sources; anything else is
+    /// a [`Storage`](Self::Storage) error.
+    pub fn from_store<E: std::error::Error + 'static>(err: E) -> Self {
+        let mut source: Option<&(dyn std::error::Error + 'static)> = Some(&err);
+        while let Some(current) = source {
+            if current.is::<std::io::Error>() {
+                return Self::Io(err.to_string());
+            }
+            source = current.source();
+        }
+        Self::Storage(err.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -33,6 +70,19 @@ mod tests {
         assert_eq!(err.to_string(), "storage error: disk full");
     }
 
+    #[test]
+    fn test_from_store_keeps_io_failures_transient() {
+        #[derive(Debug, Error)]
+        #[error("write failed")]
+        struct Wrapped(#[source] std::io::Error);
+
+        let io = Wrapped(std::io::Error::from(std::io::ErrorKind::TimedOut));
+        assert!(matches!(QmdbError::from_store(io), QmdbError::Io(_)));
+
+        let corrupt = QmdbError::DecodeError;
+        assert!(matches!(QmdbError::from_store(corrupt), QmdbError::Storage(_)));
+    }
+
     #[test]
     fn test_store_unavailable_display() {
         let err = QmdbError::StoreUnavailable;
@@ -9,10 +9,15 @@ mod batch;
 pub use batch::StoreBatches;
 
 mod changes;
-pub use changes::{AccountUpdate, ChangeSet};
+pub use changes::{AccountUpdate, ChangeSet, canonical_code_hash};
+
+mod codec;
+pub use codec::{
+    AccountCodec, AccountCodecError, StoredAccount, V1, V2, account_codec, decode_account,
+};
 
 mod encoding;
-pub use encoding::{AccountEncoding, StorageKey};
+pub use encoding::{AccountEncoding, AgentMetadata, StorageKey};
 
 mod error;
 pub use error::QmdbError;
@@ -24,4 +29,4 @@ mod store;
 pub use store::{QmdbStore, Stores};
 
 mod traits;
-pub use traits::{QmdbBatchable, QmdbGettable};
+pub use traits::{QmdbBatchable, QmdbGettable, QmdbRootable, QmdbScannable};
@@ -1,13 +1,17 @@
 //! QMDB store ownership and state transitions.
 
+use std::{collections::BTreeMap, sync::OnceLock};
+
 use alloy_primitives::{Address, B256, U256};
 
 use crate::{
     batch::StoreBatches,
-    changes::ChangeSet,
-    encoding::{AccountEncoding, StorageKey},
+    changes::{AccountUpdate, ChangeSet, canonical_code_hash},
+    codec::{AccountCodec, StoredAccount, V1},
+    encoding::{AgentMetadata, StorageKey},
     error::QmdbError,
-    traits::{QmdbBatchable, QmdbGettable},
+    root::StateRoot,
+    traits::{QmdbBatchable, QmdbGettable, QmdbRootable, QmdbScannable},
 };
 
 /// The three QMDB stores.
@@ -28,19 +32,46 @@ impl<A, S, C> Stores<A, S, C> {
     }
 }
 
+/// A commit whose batches were only partly written.
+#[derive(Debug)]
+struct PendingCommit {
+    /// Change set the batches were built from.
+    changes: ChangeSet,
+    /// Batches for the partitions not yet written.
+    batches: StoreBatches,
+}
+
 /// Layer 1: Owns QMDB stores, handles state transitions.
 ///
 /// NO synchronization - that's the caller's responsibility.
 /// Use `monmouth-handlers::QmdbHandle` for thread-safe access.
+///
+/// Account values are encoded with the active [`AccountCodec`], [`V1`] unless
+/// replaced with [`with_codec`](Self::with_codec).
 #[derive(Debug)]
 pub struct QmdbStore<A, S, C> {
     stores: Option<Stores<A, S, C>>,
+    codec: &'static dyn AccountCodec,
+    pending: Option<PendingCommit>,
 }
 
 impl<A, S, C> QmdbStore<A, S, C> {
     /// Create a new store from the three partitions.
     pub const fn new(accounts: A, storage: S, code: C) -> Self {
-        Self { stores: Some(Stores::new(accounts, storage, code)) }
+        Self { stores: Some(Stores::new(accounts, storage, code)), codec: &V1, pending: None }
+    }
+
+    /// Encode account values with `codec`, e.g. one selected by
+    /// [`account_codec`](crate::account_codec) from a partition's format marker.
+    #[must_use]
+    pub const fn with_codec(mut self, codec: &'static dyn AccountCodec) -> Self {
+        self.codec = codec;
+        self
+    }
+
+    /// Returns the active account codec.
+    pub const fn codec(&self) -> &'static dyn AccountCodec {
+        self.codec
     }
 
     /// Borrow stores for reading.
@@ -78,27 +109,25 @@ impl<A, S, C> QmdbStore<A, S, C> {
 
 impl<A, S, C> QmdbStore<A, S, C>
 where
-    A: QmdbGettable<Key = Address, Value = [u8; AccountEncoding::SIZE]>,
+    A: QmdbGettable<Key = Address, Value = Vec<u8>>,
     S: QmdbGettable<Key = StorageKey, Value = U256>,
     C: QmdbGettable<Key = B256, Value = Vec<u8>>,
 {
     /// Get account info.
     ///
+    /// A zero code hash left by older writes is returned as
+    /// [`KECCAK256_EMPTY`](alloy_primitives::KECCAK256_EMPTY).
+    ///
     /// # Errors
     ///
     /// Returns an error if stores are unavailable, the account encoding is invalid,
     /// or the underlying storage operation fails.
-    pub async fn get_account(
-        &self,
-        address: &Address,
-    ) -> Result<Option<(u64, U256, B256, u64)>, QmdbError> {
+    pub async fn get_account(&self, address: &Address) -> Result<Option<StoredAccount>, QmdbError> {
         let stores = self.stores()?;
         match stores.accounts.get(address).await {
-            Ok(Some(bytes)) => {
-                AccountEncoding::decode(&bytes).ok_or(QmdbError::DecodeError).map(Some)
-            }
+            Ok(Some(bytes)) => Ok(Some(canonical_account(self.codec.decode(&bytes)?))),
             Ok(None) => Ok(None),
-            Err(e) => Err(QmdbError::Storage(e.to_string())),
+            Err(e) => Err(QmdbError::from_store(e)),
         }
     }
 
@@ -109,7 +138,7 @@ where
     /// Returns an error if stores are unavailable or the underlying storage operation fails.
     pub async fn get_storage(&self, key: &StorageKey) -> Result<Option<U256>, QmdbError> {
         let stores = self.stores()?;
-        stores.storage.get(key).await.map_err(|e| QmdbError::Storage(e.to_string()))
+        stores.storage.get(key).await.map_err(QmdbError::from_store)
     }
 
     /// Get code by hash.
@@ -119,14 +148,62 @@ where
     /// Returns an error if stores are unavailable or the underlying storage operation fails.
     pub async fn get_code(&self, hash: &B256) -> Result<Option<Vec<u8>>, QmdbError> {
         let stores = self.stores()?;
-        stores.code.get(hash).await.map_err(|e| QmdbError::Storage(e.to_string()))
+        stores.code.get(hash).await.map_err(QmdbError::from_store)
+    }
+}
+
+impl<A, S, C> QmdbStore<A, S, C>
+where
+    A: QmdbScannable<Key = Address, Value = Vec<u8>>,
+{
+    /// Decode every stored account, in unspecified order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if stores are unavailable, the scan fails, or an account
+    /// cannot be decoded by the active codec.
+    pub async fn scan_accounts(&self) -> Result<Vec<(Address, StoredAccount)>, QmdbError> {
+        let stores = self.stores()?;
+        let entries = stores.accounts.scan().await.map_err(QmdbError::from_store)?;
+        entries
+            .into_iter()
+            .map(|(address, bytes)| Ok((address, canonical_account(self.codec.decode(&bytes)?))))
+            .collect()
     }
 }
 
 impl<A, S, C> QmdbStore<A, S, C>
 where
-    A: QmdbGettable<Key = Address, Value = [u8; AccountEncoding::SIZE]>
-        + QmdbBatchable<Key = Address, Value = [u8; AccountEncoding::SIZE]>,
+    S: QmdbScannable<Key = StorageKey, Value = U256>,
+{
+    /// Every storage slot of `address` written under `generation`, keyed by slot.
+    ///
+    /// Slots left behind by earlier generations of the account are skipped.
+    /// Storage partitions are unordered, so there is no range to seek to: the
+    /// scan still walks every key in the partition, but only the values of the
+    /// account's own slots are read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if stores are unavailable or the scan fails.
+    pub async fn scan_storage(
+        &self,
+        address: &Address,
+        generation: u64,
+    ) -> Result<BTreeMap<U256, U256>, QmdbError> {
+        let stores = self.stores()?;
+        let entries = stores
+            .storage
+            .scan_matching(|key| key.address == *address && key.generation == generation)
+            .await
+            .map_err(QmdbError::from_store)?;
+        Ok(entries.into_iter().map(|(key, value)| (key.slot, value)).collect())
+    }
+}
+
+impl<A, S, C> QmdbStore<A, S, C>
+where
+    A: QmdbGettable<Key = Address, Value = Vec<u8>> + QmdbBatchable<Key = Address, Value = Vec<u8>>,
     S: QmdbGettable<Key = StorageKey, Value = U256> + QmdbBatchable<Key = StorageKey, Value = U256>,
     C: QmdbGettable<Key = B256, Value = Vec<u8>> + QmdbBatchable<Key = B256, Value = Vec<u8>>,
 {
@@ -140,48 +217,18 @@ where
         let mut batches = StoreBatches::new();
 
         for (address, update) in &changes.accounts {
-            // Get current account to check generation
-            let current_gen = match stores.accounts.get(address).await {
-                Ok(Some(bytes)) => {
-                    AccountEncoding::decode(&bytes).map(|(_, _, _, g)| g).unwrap_or(0)
-                }
-                Ok(None) => 0,
-                Err(e) => return Err(QmdbError::Storage(e.to_string())),
+            // Get current account to check generation and keep its agent metadata
+            let current = match stores.accounts.get(address).await {
+                Ok(Some(bytes)) => self.codec.decode(&bytes).ok(),
+                Ok(None) => None,
+                Err(e) => return Err(QmdbError::from_store(e)),
             };
+            let new_gen = next_generation(current.map_or(0, |account| account.generation), update);
+            let agent = current.and_then(|account| account.agent);
 
-            // Increment generation on recreate or selfdestruct to invalidate old storage.
-            let new_gen = if update.created || update.selfdestructed {
-                current_gen.saturating_add(1)
-            } else {
-                current_gen
-            };
-
-            if update.selfdestructed {
-                batches.accounts.push((*address, None));
-            } else {
-                let encoded = AccountEncoding::encode(
-                    update.nonce,
-                    update.balance,
-                    update.code_hash,
-                    new_gen,
-                );
-                batches.accounts.push((*address, Some(encoded)));
-
-                // Add code if present
-                if let Some(ref code) = update.code {
-                    batches.code.push((update.code_hash, Some(code.clone())));
-                }
-            }
-
-            // Add storage changes
-            for (slot, value) in &update.storage {
-                let key = StorageKey::new(*address, new_gen, *slot);
-                if value.is_zero() {
-                    batches.storage.push((key, None));
-                } else {
-                    batches.storage.push((key, Some(*value)));
-                }
-            }
+            batches.accounts.push(account_op(self.codec, *address, update, new_gen, agent));
+            batches.code.extend(code_op(update));
+            batches.storage.extend(storage_ops(*address, update, new_gen));
         }
 
         Ok(batches)
@@ -189,66 +236,253 @@ where
 
     /// Apply batches to stores.
     ///
+    /// Partitions are written in turn and each batch is emptied once its
+    /// partition is written, so after a failure `batches` holds only the
+    /// writes still outstanding.
+    ///
     /// # Errors
     ///
-    /// Returns an error if stores are unavailable or any batch write operation fails.
-    pub async fn apply_batches(&mut self, batches: StoreBatches) -> Result<(), QmdbError> {
+    /// Returns an error if stores are unavailable or any batch write operation
+    /// fails; a transient I/O failure is reported as [`QmdbError::Io`].
+    pub async fn apply_batches(&mut self, batches: &mut StoreBatches) -> Result<(), QmdbError> {
         let stores = self.stores_mut()?;
 
-        stores
-            .accounts
-            .write_batch(batches.accounts)
-            .await
-            .map_err(|e| QmdbError::Storage(e.to_string()))?;
+        if !batches.accounts.is_empty() {
+            stores
+                .accounts
+                .write_batch(batches.accounts.clone())
+                .await
+                .map_err(QmdbError::from_store)?;
+            batches.accounts.clear();
+        }
 
-        stores
-            .storage
-            .write_batch(batches.storage)
-            .await
-            .map_err(|e| QmdbError::Storage(e.to_string()))?;
+        if !batches.storage.is_empty() {
+            stores
+                .storage
+                .write_batch(batches.storage.clone())
+                .await
+                .map_err(QmdbError::from_store)?;
+            batches.storage.clear();
+        }
 
-        stores
-            .code
-            .write_batch(batches.code)
-            .await
-            .map_err(|e| QmdbError::Storage(e.to_string()))?;
+        if !batches.code.is_empty() {
+            stores.code.write_batch(batches.code.clone()).await.map_err(QmdbError::from_store)?;
+            batches.code.clear();
+        }
+
+        Ok(())
+    }
+
+    /// Import accounts into empty partitions without building a [`ChangeSet`].
+    ///
+    /// `accounts` is called once per partition and must yield the same accounts
+    /// each time, in strictly ascending address order. Each partition receives a
+    /// single streamed batch, so only one account is held in memory at a time and
+    /// the resulting state root equals that of committing the same accounts as one
+    /// change set.
+    ///
+    /// The partitions must be empty: accounts already present keep their storage
+    /// generation instead of having it bumped as a commit would.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QmdbError::UnsortedImport`] if an address is not greater than the
+    /// one before it. The accounts written up to that point stay committed, so the
+    /// store should be discarded. Returns an error if stores are unavailable or any
+    /// batch write fails.
+    pub async fn bulk_import<F, I>(&mut self, accounts: F) -> Result<(), QmdbError>
+    where
+        F: Fn() -> I,
+        I: Iterator<Item = (Address, AccountUpdate)> + Send,
+    {
+        let codec = self.codec;
+        let stores = self.stores_mut()?;
+
+        let unsorted = OnceLock::new();
+        let mut previous = None;
+        let account_ops = accounts().map_while(|(address, update)| {
+            if previous.is_some_and(|previous| previous >= address) {
+                let _ = unsorted.set(address);
+                return None;
+            }
+            previous = Some(address);
+            Some(account_op(codec, address, &update, next_generation(0, &update), None))
+        });
+        stores.accounts.write_batch(account_ops).await.map_err(QmdbError::from_store)?;
+        if let Some(address) = unsorted.get() {
+            return Err(QmdbError::UnsortedImport(*address));
+        }
+
+        let storage_ops = accounts().flat_map(|(address, update)| {
+            let generation = next_generation(0, &update);
+            storage_ops(address, &update, generation).collect::<Vec<_>>()
+        });
+        stores.storage.write_batch(storage_ops).await.map_err(QmdbError::from_store)?;
+
+        let code_ops = accounts().filter_map(|(_, update)| code_op(&update));
+        stores.code.write_batch(code_ops).await.map_err(QmdbError::from_store)?;
 
         Ok(())
     }
 
     /// Commit a change set to stores.
     ///
+    /// Committing the same change set again after a failure resumes the earlier
+    /// attempt: the generations chosen then are kept and partitions already
+    /// written are not written twice.
+    ///
     /// # Errors
     ///
-    /// Returns an error if stores are unavailable or any storage operation fails.
+    /// Returns [`QmdbError::PartialCommit`] if an earlier commit of a different
+    /// change set was only partly written. Returns an error if stores are
+    /// unavailable or any storage operation fails.
     pub async fn commit_changes(&mut self, changes: ChangeSet) -> Result<(), QmdbError> {
+        let mut batches = match self.pending.take() {
+            Some(pending) if pending.changes == changes => pending.batches,
+            Some(pending) => {
+                self.pending = Some(pending);
+                return Err(QmdbError::PartialCommit);
+            }
+            None if changes.is_empty() => return Ok(()),
+            None => self.build_batches(&changes).await?,
+        };
+        if let Err(err) = self.apply_batches(&mut batches).await {
+            self.pending = Some(PendingCommit { changes, batches });
+            return Err(err);
+        }
+        Ok(())
+    }
+}
+
+impl<A, S, C> QmdbStore<A, S, C>
+where
+    A: QmdbRootable<Key = Address, Value = Vec<u8>>,
+    S: QmdbRootable<Key = StorageKey, Value = U256>,
+    C: QmdbRootable<Key = B256, Value = Vec<u8>>,
+{
+    /// State root over the committed contents of the three partitions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if stores are unavailable or a partition root cannot be read.
+    pub fn state_root(&self) -> Result<B256, QmdbError> {
+        let stores = self.stores()?;
+        Ok(StateRoot::compute(
+            stores.accounts.root().map_err(QmdbError::from_store)?,
+            stores.storage.root().map_err(QmdbError::from_store)?,
+            stores.code.root().map_err(QmdbError::from_store)?,
+        ))
+    }
+
+    /// State root after applying `changes`, without persisting them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if stores are unavailable or any storage operation fails.
+    pub async fn compute_root(&self, changes: &ChangeSet) -> Result<B256, QmdbError> {
         if changes.is_empty() {
-            return Ok(());
+            return self.state_root();
         }
-        let batches = self.build_batches(&changes).await?;
-        self.apply_batches(batches).await
+        let batches = self.build_batches(changes).await?;
+        let stores = self.stores()?;
+        let accounts =
+            stores.accounts.root_with(batches.accounts).await.map_err(QmdbError::from_store)?;
+        let storage =
+            stores.storage.root_with(batches.storage).await.map_err(QmdbError::from_store)?;
+        let code = stores.code.root_with(batches.code).await.map_err(QmdbError::from_store)?;
+        Ok(StateRoot::compute(accounts, storage, code))
+    }
+}
+
+/// Storage generation after `update`, given the account's `current` generation.
+///
+/// Recreating or self-destructing an account bumps the generation, which
+/// invalidates all storage written under the previous one.
+const fn next_generation(current: u64, update: &AccountUpdate) -> u64 {
+    if update.created || update.selfdestructed { current.saturating_add(1) } else { current }
+}
+
+/// Decoded account with its code hash in canonical form.
+fn canonical_account(account: StoredAccount) -> StoredAccount {
+    StoredAccount { code_hash: canonical_code_hash(account.code_hash), ..account }
+}
+
+/// Account partition write for `update`, keeping the account's `agent` metadata.
+///
+/// Empty code is always written as [`KECCAK256_EMPTY`](alloy_primitives::KECCAK256_EMPTY),
+/// so a zero code hash never reaches the account partition or the state root.
+fn account_op(
+    codec: &dyn AccountCodec,
+    address: Address,
+    update: &AccountUpdate,
+    generation: u64,
+    agent: Option<AgentMetadata>,
+) -> (Address, Option<Vec<u8>>) {
+    if update.selfdestructed {
+        (address, None)
+    } else {
+        let account = StoredAccount {
+            nonce: update.nonce,
+            balance: update.balance,
+            code_hash: canonical_code_hash(update.code_hash),
+            generation,
+            agent,
+        };
+        (address, Some(codec.encode(&account)))
     }
 }
 
+/// Code partition write for `update`, if it deploys code.
+fn code_op(update: &AccountUpdate) -> Option<(B256, Option<Vec<u8>>)> {
+    if update.selfdestructed {
+        return None;
+    }
+    update.code.as_ref().map(|code| (update.code_hash, Some(code.clone())))
+}
+
+/// Storage partition writes for `update`, deleting zeroed slots.
+fn storage_ops(
+    address: Address,
+    update: &AccountUpdate,
+    generation: u64,
+) -> impl Iterator<Item = (StorageKey, Option<U256>)> + '_ {
+    update.storage.iter().map(move |(slot, value)| {
+        let key = StorageKey::new(address, generation, *slot);
+        (key, (!value.is_zero()).then_some(*value))
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashMap as StdHashMap, sync::Mutex};
+    use std::{
+        collections::HashMap as StdHashMap,
+        sync::{
+            Mutex,
+            atomic::{AtomicU32, Ordering},
+        },
+    };
 
     use super::*;
 
     #[derive(Debug, Default)]
     struct MemoryStore<K, V> {
         data: Mutex<StdHashMap<K, V>>,
+        /// Number of upcoming batch writes to fail with a transient I/O error.
+        failures: AtomicU32,
     }
 
     impl<K, V> MemoryStore<K, V> {
         fn new() -> Self {
-            Self { data: Mutex::new(StdHashMap::new()) }
+            Self { data: Mutex::new(StdHashMap::new()), failures: AtomicU32::new(0) }
+        }
+
+        fn fail_next_writes(&self, count: u32) {
+            self.failures.store(count, Ordering::SeqCst);
         }
     }
 
     #[derive(Debug)]
-    struct MemoryError;
+    struct MemoryError(std::io::Error);
 
     impl std::fmt::Display for MemoryError {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -256,7 +490,11 @@ mod tests {
         }
     }
 
-    impl std::error::Error for MemoryError {}
+    impl std::error::Error for MemoryError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
 
     impl<K: Clone + Eq + std::hash::Hash + Send + Sync, V: Clone + Send + Sync> QmdbGettable
         for MemoryStore<K, V>
@@ -278,6 +516,12 @@ mod tests {
             I: IntoIterator<Item = (Self::Key, Option<Self::Value>)> + Send,
             I::IntoIter: Send,
         {
+            let failing = self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1));
+            if failing.is_ok() {
+                return Err(MemoryError(std::io::ErrorKind::TimedOut.into()));
+            }
             let mut data = self.data.lock().unwrap();
             for (key, value) in ops {
                 match value {
@@ -293,8 +537,46 @@ mod tests {
         }
     }
 
+    impl<K, V> MemoryStore<K, V>
+    where
+        K: std::fmt::Debug,
+        V: std::fmt::Debug,
+    {
+        fn digest(data: &StdHashMap<K, V>) -> B256 {
+            let mut entries: Vec<String> =
+                data.iter().map(|(key, value)| format!("{key:?}={value:?}")).collect();
+            entries.sort();
+            alloy_primitives::keccak256(entries.join(";"))
+        }
+    }
+
+    impl<K, V> QmdbRootable for MemoryStore<K, V>
+    where
+        K: Clone + Eq + std::hash::Hash + std::fmt::Debug + Send + Sync,
+        V: Clone + std::fmt::Debug + Send + Sync,
+    {
+        fn root(&self) -> Result<B256, Self::Error> {
+            Ok(Self::digest(&self.data.lock().unwrap()))
+        }
+
+        async fn root_with<I>(&self, ops: I) -> Result<B256, Self::Error>
+        where
+            I: IntoIterator<Item = (Self::Key, Option<Self::Value>)> + Send,
+            I::IntoIter: Send,
+        {
+            let mut data = self.data.lock().unwrap().clone();
+            for (key, value) in ops {
+                match value {
+                    Some(v) => data.insert(key, v),
+                    None => data.remove(&key),
+                };
+            }
+            Ok(Self::digest(&data))
+        }
+    }
+
     type TestStore = QmdbStore<
-        MemoryStore<Address, [u8; 80]>,
+        MemoryStore<Address, Vec<u8>>,
         MemoryStore<StorageKey, U256>,
         MemoryStore<B256, Vec<u8>>,
     >;
@@ -317,4 +599,225 @@ mod tests {
         let mut store = create_test_store();
         store.commit_changes(ChangeSet::new()).await.unwrap();
     }
+
+    /// Change set creating one account with a storage slot.
+    fn created_account(address: Address) -> ChangeSet {
+        use std::collections::BTreeMap;
+
+        let update = AccountUpdate {
+            created: true,
+            selfdestructed: false,
+            nonce: 1,
+            balance: U256::from(100),
+            code_hash: B256::ZERO,
+            code: None,
+            storage: BTreeMap::from([(U256::from(1), U256::from(7))]),
+        };
+        ChangeSet { accounts: BTreeMap::from([(address, update)]) }
+    }
+
+    #[tokio::test]
+    async fn retried_commit_bumps_generation_once() {
+        let address = Address::with_last_byte(1);
+        let changes = created_account(address);
+
+        let mut store = create_test_store();
+        store.stores().unwrap().storage.fail_next_writes(1);
+        let err = store.commit_changes(changes.clone()).await.unwrap_err();
+        assert!(matches!(err, QmdbError::Io(_)));
+        store.commit_changes(changes.clone()).await.unwrap();
+
+        let account = store.get_account(&address).await.unwrap().unwrap();
+        assert_eq!(account.generation, 1);
+        let mut clean = create_test_store();
+        clean.commit_changes(changes).await.unwrap();
+        assert_eq!(store.state_root().unwrap(), clean.state_root().unwrap());
+    }
+
+    #[tokio::test]
+    async fn other_commit_after_partial_write_is_refused() {
+        let mut store = create_test_store();
+        store.stores().unwrap().storage.fail_next_writes(1);
+        store.commit_changes(created_account(Address::with_last_byte(1))).await.unwrap_err();
+
+        let err =
+            store.commit_changes(created_account(Address::with_last_byte(2))).await.unwrap_err();
+        assert!(matches!(err, QmdbError::PartialCommit));
+    }
+
+    #[tokio::test]
+    async fn resumed_commit_then_later_changes_match_clean_commits() {
+        let first = created_account(Address::with_last_byte(1));
+        let mut second = created_account(Address::with_last_byte(1));
+        second.accounts.values_mut().for_each(|update| update.created = false);
+        second.merge(created_account(Address::with_last_byte(2)));
+
+        let mut store = create_test_store();
+        store.stores().unwrap().storage.fail_next_writes(1);
+        store.commit_changes(first.clone()).await.unwrap_err();
+        store.commit_changes(first.clone()).await.unwrap();
+        store.commit_changes(second.clone()).await.unwrap();
+
+        let mut clean = create_test_store();
+        clean.commit_changes(first).await.unwrap();
+        clean.commit_changes(second).await.unwrap();
+        assert_eq!(store.state_root().unwrap(), clean.state_root().unwrap());
+    }
+
+    #[tokio::test]
+    async fn commit_keeps_agent_metadata() {
+        use crate::V2;
+
+        let address = Address::with_last_byte(1);
+        let agent = AgentMetadata::new(U256::from(8004u64), Address::with_last_byte(0x8a));
+        let mut store = create_test_store().with_codec(&V2);
+        let existing = StoredAccount { nonce: 1, agent: Some(agent), ..Default::default() };
+        store
+            .stores_mut()
+            .unwrap()
+            .accounts
+            .write_batch([(address, Some(V2.encode(&existing)))])
+            .await
+            .unwrap();
+
+        let mut changes = created_account(address);
+        changes.accounts.values_mut().for_each(|update| update.created = false);
+        store.commit_changes(changes).await.unwrap();
+
+        let account = store.get_account(&address).await.unwrap().unwrap();
+        assert_eq!(account.nonce, 1);
+        assert_eq!(account.balance, U256::from(100));
+        assert_eq!(account.agent, Some(agent));
+    }
+
+    #[tokio::test]
+    async fn compute_root_matches_committed_root() {
+        use std::collections::BTreeMap;
+
+        use crate::AccountUpdate;
+
+        let mut store = create_test_store();
+        let empty_root = store.state_root().unwrap();
+
+        let mut changes = ChangeSet::new();
+        changes.accounts.insert(
+            Address::with_last_byte(1),
+            AccountUpdate {
+                created: true,
+                selfdestructed: false,
+                nonce: 1,
+                balance: U256::from(100),
+                code_hash: B256::ZERO,
+                code: None,
+                storage: BTreeMap::from([(U256::from(1), U256::from(7))]),
+            },
+        );
+
+        let speculative = store.compute_root(&changes).await.unwrap();
+        assert_ne!(speculative, empty_root);
+        assert_eq!(store.state_root().unwrap(), empty_root);
+
+        store.commit_changes(changes).await.unwrap();
+        assert_eq!(store.state_root().unwrap(), speculative);
+    }
+
+    #[tokio::test]
+    async fn cleared_code_is_stored_as_empty_hash() {
+        use std::collections::BTreeMap;
+
+        use alloy_primitives::{KECCAK256_EMPTY, keccak256};
+
+        let address = Address::with_last_byte(1);
+        let code = vec![0x60, 0x00];
+        let account = |code_hash, code| AccountUpdate {
+            created: false,
+            selfdestructed: false,
+            nonce: 1,
+            balance: U256::from(100),
+            code_hash,
+            code,
+            storage: BTreeMap::new(),
+        };
+        let change = |update| ChangeSet { accounts: BTreeMap::from([(address, update)]) };
+
+        // Deploy code, then clear it once with a zero hash and once canonically.
+        let mut zeroed = create_test_store();
+        let mut canonical = create_test_store();
+        for store in [&mut zeroed, &mut canonical] {
+            let deploy = change(account(keccak256(&code), Some(code.clone())));
+            store.commit_changes(deploy).await.unwrap();
+        }
+        let clear_zero = change(account(B256::ZERO, None));
+        let speculative = zeroed.compute_root(&clear_zero).await.unwrap();
+        zeroed.commit_changes(clear_zero).await.unwrap();
+        canonical.commit_changes(change(account(KECCAK256_EMPTY, None))).await.unwrap();
+
+        let account = zeroed.get_account(&address).await.unwrap().unwrap();
+        assert_eq!(account.code_hash, KECCAK256_EMPTY);
+        assert_eq!(zeroed.state_root().unwrap(), speculative);
+        assert_eq!(zeroed.state_root().unwrap(), canonical.state_root().unwrap());
+    }
+
+    /// Genesis account `index` of a bulk import test, with storage and code on some.
+    fn genesis_account(index: u32) -> (Address, AccountUpdate) {
+        use std::collections::BTreeMap;
+
+        let mut address = [0u8; 20];
+        address[16..].copy_from_slice(&index.to_be_bytes());
+        let code = (index % 7 == 0).then(|| vec![0x60, (index % 3) as u8]);
+        let storage = (0..index % 4)
+            .map(|slot| (U256::from(slot), U256::from(index * 10 + slot)))
+            .collect::<BTreeMap<_, _>>();
+        let update = AccountUpdate {
+            created: true,
+            selfdestructed: false,
+            nonce: u64::from(index % 5),
+            balance: U256::from(index) * U256::from(1_000_000_000u64),
+            code_hash: code.as_ref().map_or(B256::ZERO, alloy_primitives::keccak256),
+            code,
+            storage,
+        };
+        (Address::from(address), update)
+    }
+
+    #[tokio::test]
+    async fn bulk_import_matches_commit() {
+        const ACCOUNTS: u32 = 3_000;
+
+        let mut committed = create_test_store();
+        let mut changes = ChangeSet::new();
+        for index in 0..ACCOUNTS {
+            let (address, update) = genesis_account(index);
+            changes.accounts.insert(address, update);
+        }
+        committed.commit_changes(changes).await.unwrap();
+
+        let mut imported = create_test_store();
+        imported.bulk_import(|| (0..ACCOUNTS).map(genesis_account)).await.unwrap();
+
+        assert_eq!(imported.state_root().unwrap(), committed.state_root().unwrap());
+        let (address, _) = genesis_account(14);
+        assert_eq!(
+            imported.get_account(&address).await.unwrap(),
+            committed.get_account(&address).await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn bulk_import_rejects_unsorted_accounts() {
+        let mut store = create_test_store();
+        let err =
+            store.bulk_import(|| [2, 1, 3].into_iter().map(genesis_account)).await.unwrap_err();
+        assert!(
+            matches!(err, QmdbError::UnsortedImport(address) if address == genesis_account(1).0)
+        );
+    }
+
+    #[tokio::test]
+    async fn bulk_import_reports_transient_failures_as_io() {
+        let mut store = create_test_store();
+        store.stores().unwrap().storage.fail_next_writes(1);
+        let err = store.bulk_import(|| (1..=2).map(genesis_account)).await.unwrap_err();
+        assert!(matches!(err, QmdbError::Io(_)));
+    }
 }
@@ -2,6 +2,8 @@
 
 use std::future::Future;
 
+use alloy_primitives::B256;
+
 /// Trait for reading values from a QMDB store.
 pub trait QmdbGettable: Send + Sync {
     /// The key type for lookups.
@@ -26,3 +28,40 @@ pub trait QmdbBatchable: QmdbGettable {
         I: IntoIterator<Item = (Self::Key, Option<Self::Value>)> + Send,
         I::IntoIter: Send;
 }
+
+/// Trait for enumerating every entry of a QMDB store.
+pub trait QmdbScannable: QmdbGettable {
+    /// Every key-value pair currently stored, in unspecified order.
+    fn scan(
+        &self,
+    ) -> impl Future<Output = Result<Vec<(Self::Key, Self::Value)>, Self::Error>> + Send;
+
+    /// Every key-value pair whose key satisfies `keep`, in unspecified order.
+    ///
+    /// The default filters [`scan`](Self::scan); stores that can skip reading
+    /// the values of rejected keys should override it.
+    fn scan_matching<P>(
+        &self,
+        keep: P,
+    ) -> impl Future<Output = Result<Vec<(Self::Key, Self::Value)>, Self::Error>> + Send
+    where
+        P: Fn(&Self::Key) -> bool + Send + Sync,
+    {
+        async move {
+            let entries = self.scan().await?;
+            Ok(entries.into_iter().filter(|(key, _)| keep(key)).collect())
+        }
+    }
+}
+
+/// Trait for stores that maintain an authenticated root over their contents.
+pub trait QmdbRootable: QmdbBatchable {
+    /// Root over the committed contents.
+    fn root(&self) -> Result<B256, Self::Error>;
+
+    /// Root the store would have after applying `ops`, without applying them.
+    fn root_with<I>(&self, ops: I) -> impl Future<Output = Result<B256, Self::Error>> + Send
+    where
+        I: IntoIterator<Item = (Self::Key, Option<Self::Value>)> + Send,
+        I::IntoIter: Send;
+}
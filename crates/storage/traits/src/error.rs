@@ -18,6 +18,10 @@ pub enum StateDbError {
     #[error("storage error: {0}")]
     Storage(String),
 
+    /// Transient I/O failure from the underlying store; the operation may be retried.
+    #[error("storage I/O error: {0}")]
+    Io(String),
+
     /// Lock was poisoned.
     #[error("lock poisoned")]
     LockPoisoned,
@@ -27,6 +31,16 @@ pub enum StateDbError {
     RootComputation(String),
 }
 
+impl StateDbError {
+    /// Returns `true` if the operation may succeed when retried.
+    ///
+    /// Only [`Self::Io`] is transient; every other variant indicates missing data
+    /// or a corrupted store and must be treated as fatal.
+    pub const fn is_transient(&self) -> bool {
+        matches!(self, Self::Io(_))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloy_primitives::Address;
@@ -53,6 +67,13 @@ mod tests {
         assert_eq!(err.to_string(), "storage error: disk full");
     }
 
+    #[test]
+    fn only_io_is_transient() {
+        assert!(StateDbError::Io("timeout".to_string()).is_transient());
+        assert!(!StateDbError::Storage("bad page".to_string()).is_transient());
+        assert!(!StateDbError::LockPoisoned.is_transient());
+    }
+
     #[test]
     fn lock_poisoned_display() {
         let err = StateDbError::LockPoisoned;
@@ -27,6 +27,18 @@ pub trait StateDbRead: Clone + Send + Sync + 'static {
     /// Get account code by hash.
     fn code(&self, code_hash: &B256) -> impl Future<Output = Result<Bytes, StateDbError>> + Send;
 
+    /// Get the length of an account's code by hash.
+    ///
+    /// Equivalent to `code(code_hash).len()`. Implementations should override this
+    /// when the length is available without materializing the code.
+    fn code_size(
+        &self,
+        code_hash: &B256,
+    ) -> impl Future<Output = Result<usize, StateDbError>> + Send {
+        let code_hash = *code_hash;
+        async move { Ok(self.code(&code_hash).await?.len()) }
+    }
+
     /// Get storage slot value.
     fn storage(
         &self,
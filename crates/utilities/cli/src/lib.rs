@@ -12,3 +12,8 @@ pub use backtrace::Backtracing;
 mod sigsegv;
 #[cfg(unix)]
 pub use sigsegv::SigsegvHandler;
+
+#[cfg(unix)]
+mod shutdown;
+#[cfg(unix)]
+pub use shutdown::ShutdownHandler;
@@ -0,0 +1,54 @@
+//! SIGINT/SIGTERM handling for graceful shutdown.
+
+use std::{mem, ptr};
+
+/// The shutdown signal handler.
+#[derive(Debug, Clone, Copy)]
+pub struct ShutdownHandler;
+
+impl ShutdownHandler {
+    /// Installs a SIGINT/SIGTERM handler that calls `on_shutdown` once.
+    ///
+    /// Both signals are blocked in the calling thread, and so in every thread it
+    /// spawns afterwards, and are instead received by a dedicated thread. The
+    /// first signal calls `on_shutdown` with its number on that thread, so the
+    /// callback is not restricted to async-signal-safe operations; a second
+    /// signal exits the process immediately.
+    ///
+    /// Must be called before any other threads are created.
+    pub fn install<F>(on_shutdown: F)
+    where
+        F: FnOnce(i32) + Send + 'static,
+    {
+        // SAFETY: `set` is a zero-initialized sigset_t populated only through
+        // sigemptyset()/sigaddset(), and pthread_sigmask() only changes the calling
+        // thread's mask. This is called at program startup before any threads are
+        // created, so every later thread inherits the blocked mask.
+        let set = unsafe {
+            let mut set: libc::sigset_t = mem::zeroed();
+            libc::sigemptyset(&mut set);
+            libc::sigaddset(&mut set, libc::SIGINT);
+            libc::sigaddset(&mut set, libc::SIGTERM);
+            libc::pthread_sigmask(libc::SIG_BLOCK, &set, ptr::null_mut());
+            set
+        };
+
+        std::thread::Builder::new()
+            .name("shutdown-signal".to_string())
+            .spawn(move || {
+                on_shutdown(wait_for(&set));
+                let signal = wait_for(&set);
+                std::process::exit(128 + signal);
+            })
+            .expect("failed to spawn shutdown signal thread");
+    }
+}
+
+/// Block until a signal in `set` is pending and return its number.
+fn wait_for(set: &libc::sigset_t) -> i32 {
+    let mut signal = 0;
+    // SAFETY: `set` is a valid, initialized signal set and `signal` is a valid
+    // out-pointer. Every signal in `set` is blocked, as sigwait() requires.
+    while unsafe { libc::sigwait(set, &mut signal) } != 0 {}
+    signal
+}